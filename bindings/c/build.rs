@@ -0,0 +1,14 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml")).expect("invalid cbindgen.toml");
+
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("failed to generate the C header")
+        .write_to_file(format!("{crate_dir}/include/iota_sdk.h"));
+}
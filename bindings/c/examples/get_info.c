@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// Prints the info of a node.
+//
+// cargo build --release -p iota-sdk-c
+// cc examples/get_info.c -I include -L ../../target/release -liota_sdk -o get_info && ./get_info
+
+#include <stdio.h>
+
+#include "iota_sdk.h"
+
+int main(void) {
+    if (iota_abi_version() != IOTA_SDK_ABI_VERSION) {
+        fprintf(stderr, "the library doesn't match the header\n");
+        return 1;
+    }
+
+    IotaClient *client = iota_create_client("{\"nodes\":[\"https://api.testnet.shimmer.network\"]}");
+    if (client == NULL) {
+        char *error = iota_last_error();
+        fprintf(stderr, "%s\n", error);
+        iota_destroy_string(error);
+        return 1;
+    }
+
+    char *response = iota_call_client_method(client, "{\"name\":\"getInfo\"}");
+    printf("%s\n", response);
+
+    iota_destroy_string(response);
+    iota_destroy_client(client);
+    return 0;
+}
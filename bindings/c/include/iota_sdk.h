@@ -0,0 +1,172 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#ifndef IOTA_SDK_H
+#define IOTA_SDK_H
+
+// This file is generated by cbindgen from bindings/c/src, don't edit it manually.
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The version of the ABI, it is increased with every change that isn't backwards compatible.
+#define IOTA_SDK_ABI_VERSION 1
+
+// A client handle.
+typedef struct IotaClient IotaClient;
+
+// A secret manager handle.
+typedef struct IotaSecretManager IotaSecretManager;
+
+// A wallet handle.
+typedef struct IotaWallet IotaWallet;
+
+// A function that receives events, `event` is a JSON string that is only valid during the call and `context` is the
+// pointer passed when registering the callback. It is called from threads of the library, so the context must be
+// safe to use from any thread.
+typedef void (*IotaEventCallback)(const char *event, void *context);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a client from JSON client options, NULL uses the default options. The client must be freed with
+// `iota_destroy_client()`.
+//
+// # Safety
+//
+// `options` must be NULL or a nul-terminated string.
+IotaClient *iota_create_client(const char *options);
+
+// Frees a client, NULL is ignored.
+//
+// # Safety
+//
+// The client must have been returned by the library and must not be used afterwards.
+void iota_destroy_client(IotaClient *client);
+
+// Calls a client method, returns the JSON response.
+//
+// # Safety
+//
+// `client` must be a valid client and `method` a nul-terminated string.
+char *iota_call_client_method(const IotaClient *client, const char *method);
+
+// Subscribes to MQTT topics, `topics` is a JSON array of topic strings. The callback receives the events until the
+// topics are unsubscribed with the `unsubscribe` client method.
+//
+// # Safety
+//
+// `client` must be a valid client, `topics` a nul-terminated string and `context` safe to use from any thread until
+// the topics are unsubscribed.
+bool iota_listen_mqtt(const IotaClient *client,
+                      const char *topics,
+                      IotaEventCallback callback,
+                      void *context);
+
+// Returns the message of the last error that occurred on the calling thread, or NULL if there was none. It is only
+// meaningful right after a function signalled a failure. The string must be freed with `iota_destroy_string()`.
+char *iota_last_error(void);
+
+// Creates a secret manager from its JSON options. The secret manager must be freed with
+// `iota_destroy_secret_manager()`.
+//
+// # Safety
+//
+// `options` must be a nul-terminated string.
+IotaSecretManager *iota_create_secret_manager(const char *options);
+
+// Frees a secret manager, NULL is ignored.
+//
+// # Safety
+//
+// The secret manager must have been returned by the library and must not be used afterwards.
+void iota_destroy_secret_manager(IotaSecretManager *secret_manager);
+
+// Calls a secret manager method, returns the JSON response.
+//
+// # Safety
+//
+// `secret_manager` must be a valid secret manager and `method` a nul-terminated string.
+char *iota_call_secret_manager_method(const IotaSecretManager *secret_manager, const char *method);
+
+// Creates a wallet from JSON wallet options. The wallet must be freed with `iota_destroy_wallet()`.
+//
+// # Safety
+//
+// `options` must be a nul-terminated string.
+IotaWallet *iota_create_wallet(const char *options);
+
+// Frees a wallet, NULL is ignored.
+//
+// # Safety
+//
+// The wallet must have been returned by the library and must not be used afterwards.
+void iota_destroy_wallet(IotaWallet *wallet);
+
+// Calls a wallet method, returns the JSON response.
+//
+// # Safety
+//
+// `wallet` must be a valid wallet and `method` a nul-terminated string.
+char *iota_call_wallet_method(const IotaWallet *wallet, const char *method);
+
+// Listens to wallet events, `events` is a JSON array of event type numbers, an empty array listens to all events.
+// The callback receives the events until the listeners are cleared with the `clearListeners` wallet method.
+//
+// # Safety
+//
+// `wallet` must be a valid wallet, `events` a nul-terminated string and `context` safe to use from any thread until
+// the listeners are cleared.
+bool iota_listen_wallet(const IotaWallet *wallet,
+                        const char *events,
+                        IotaEventCallback callback,
+                        void *context);
+
+// Returns the client of the wallet, it must be freed with `iota_destroy_client()`.
+//
+// # Safety
+//
+// `wallet` must be a valid wallet.
+IotaClient *iota_get_client_from_wallet(const IotaWallet *wallet);
+
+// Returns the secret manager of the wallet, it must be freed with `iota_destroy_secret_manager()`.
+//
+// # Safety
+//
+// `wallet` must be a valid wallet.
+IotaSecretManager *iota_get_secret_manager_from_wallet(const IotaWallet *wallet);
+
+// Returns the ABI version of the library, callers should check that it matches `IOTA_SDK_ABI_VERSION` of the header
+// they were compiled with.
+uint32_t iota_abi_version(void);
+
+// Frees a string returned by the library, NULL is ignored.
+//
+// # Safety
+//
+// The string must have been returned by the library and must not be used afterwards.
+void iota_destroy_string(char *string);
+
+// Init the Rust logger with a JSON logger output config.
+//
+// # Safety
+//
+// `config` must be a nul-terminated string.
+bool iota_init_logger(const char *config);
+
+// Calls a utils method, returns the JSON response.
+//
+// # Safety
+//
+// `method` must be a nul-terminated string.
+char *iota_call_utils_method(const char *method);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif // IOTA_SDK_H
@@ -0,0 +1,107 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{ffi::c_void, os::raw::c_char};
+
+use iota_sdk_bindings_core::{
+    call_client_method as rust_call_client_method,
+    iota_sdk::client::{mqtt::Topic, Client, ClientBuilder},
+    listen_mqtt as rust_listen_mqtt, ClientMethod,
+};
+
+use crate::{
+    error::{guard, Result},
+    response_to_ptr, str_from_ptr, EventHandler, IotaEventCallback,
+};
+
+/// A client handle.
+pub struct IotaClient {
+    pub(crate) client: Client,
+}
+
+/// Creates a client from JSON client options, NULL uses the default options. The client must be freed with
+/// `iota_destroy_client()`.
+///
+/// # Safety
+///
+/// `options` must be NULL or a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_client(options: *const c_char) -> *mut IotaClient {
+    guard(std::ptr::null_mut(), || {
+        let builder = if options.is_null() {
+            ClientBuilder::new()
+        } else {
+            ClientBuilder::new().from_json(str_from_ptr(options)?)?
+        };
+        let client = crate::block_on(builder.finish())?;
+
+        Ok(Box::into_raw(Box::new(IotaClient { client })))
+    })
+}
+
+/// Frees a client, NULL is ignored.
+///
+/// # Safety
+///
+/// The client must have been returned by the library and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_client(client: *mut IotaClient) {
+    if !client.is_null() {
+        let client = Box::from_raw(client);
+        crate::block_on(async move { drop(client) });
+    }
+}
+
+/// Calls a client method, returns the JSON response.
+///
+/// # Safety
+///
+/// `client` must be a valid client and `method` a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_client_method(client: *const IotaClient, method: *const c_char) -> *mut c_char {
+    guard(std::ptr::null_mut(), || {
+        let client = client_from_ptr(client)?;
+        let method = serde_json::from_str::<ClientMethod>(str_from_ptr(method)?)?;
+        let response = crate::block_on(rust_call_client_method(&client.client, method));
+
+        response_to_ptr(&response)
+    })
+}
+
+/// Subscribes to MQTT topics, `topics` is a JSON array of topic strings. The callback receives the events until the
+/// topics are unsubscribed with the `unsubscribe` client method.
+///
+/// # Safety
+///
+/// `client` must be a valid client, `topics` a nul-terminated string and `context` safe to use from any thread until
+/// the topics are unsubscribed.
+#[no_mangle]
+pub unsafe extern "C" fn iota_listen_mqtt(
+    client: *const IotaClient,
+    topics: *const c_char,
+    callback: IotaEventCallback,
+    context: *mut c_void,
+) -> bool {
+    guard(false, || {
+        let client = client_from_ptr(client)?;
+        let topics = serde_json::from_str::<Vec<String>>(str_from_ptr(topics)?)?
+            .iter()
+            .map(Topic::new)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+        let handler = EventHandler::new(callback, context);
+        crate::block_on(rust_listen_mqtt(&client.client, topics, move |event| {
+            handler.call(event)
+        }));
+
+        Ok(true)
+    })
+}
+
+/// Borrows the client behind a handle.
+///
+/// # Safety
+///
+/// The pointer must be NULL or point to a valid client.
+unsafe fn client_from_ptr<'a>(client: *const IotaClient) -> Result<&'a IotaClient> {
+    Ok(client.as_ref().ok_or("unexpected NULL client")?)
+}
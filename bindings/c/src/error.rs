@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt::Display,
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+thread_local! {
+    /// The error of the last call on this thread that failed.
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// The `Result` structure to wrap the error type for the C binding.
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+/// The Error type, only its message crosses the FFI boundary.
+#[derive(Debug)]
+pub(crate) struct Error(String);
+
+impl<E: Display> From<E> for Error {
+    fn from(err: E) -> Self {
+        Self(err.to_string())
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    panic.downcast_ref::<String>().map_or_else(
+        || {
+            panic.downcast_ref::<&str>().map_or_else(
+                || "Internal error".to_string(),
+                |message| format!("Internal error: {message}"),
+            )
+        },
+        |message| format!("Internal error: {message}"),
+    )
+}
+
+/// Runs a function called from C, panics must not unwind into the caller. If it fails or panics, the error is stored
+/// for `iota_last_error()` and `default` is returned.
+pub(crate) fn guard<T>(default: T, f: impl FnOnce() -> Result<T>) -> T {
+    let message = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(Error(message))) => message,
+        Err(panic) => panic_message(panic),
+    };
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+    default
+}
+
+/// Returns the message of the last error that occurred on the calling thread, or NULL if there was none. It is only
+/// meaningful right after a function signalled a failure. The string must be freed with `iota_destroy_string()`.
+#[no_mangle]
+pub extern "C" fn iota_last_error() -> *mut c_char {
+    LAST_ERROR
+        .with(|last_error| last_error.borrow().clone())
+        .map_or(std::ptr::null_mut(), crate::string_to_ptr)
+}
@@ -0,0 +1,272 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # C binding implementation for the iota-sdk library.
+//!
+//! The API is flat and only passes opaque handles, C strings and callbacks: methods and their responses are JSON
+//! strings with the same schema as the other bindings, so new methods don't change the ABI. Strings returned by the
+//! library are owned by the caller and have to be freed with `iota_destroy_string()`. Functions that return a pointer
+//! return NULL and functions that return a `bool` return `false` on failure, the error message is then available
+//! from `iota_last_error()`.
+//!
+//! Calls block the calling thread until they are done. They can be made from any thread that isn't driving an async
+//! runtime: calls from inside a callback or from a worker thread of a Tokio runtime, for example when the library is
+//! used from Rust, fail with an error.
+
+mod client;
+mod error;
+mod secret_manager;
+mod wallet;
+
+use std::{
+    ffi::{c_void, CStr, CString},
+    os::raw::c_char,
+};
+
+use iota_sdk_bindings_core::{
+    call_utils_method as rust_call_utils_method, init_logger as rust_init_logger, Response, UtilsMethod,
+};
+use once_cell::sync::OnceCell;
+use tokio::runtime::Runtime;
+
+use self::error::{guard, Error, Result};
+pub use self::{client::*, error::iota_last_error, secret_manager::*, wallet::*};
+
+/// The version of the ABI, it is increased with every change that isn't backwards compatible.
+pub const IOTA_SDK_ABI_VERSION: u32 = 1;
+
+/// A function that receives events, `event` is a JSON string that is only valid during the call and `context` is the
+/// pointer passed when registering the callback. It is called from threads of the library, so the context must be
+/// safe to use from any thread.
+pub type IotaEventCallback = extern "C" fn(event: *const c_char, context: *mut c_void);
+
+/// A callback with its context.
+#[derive(Clone, Copy)]
+pub(crate) struct EventHandler {
+    callback: IotaEventCallback,
+    context: *mut c_void,
+}
+
+// The caller guarantees that the context can be used from any thread.
+unsafe impl Send for EventHandler {}
+unsafe impl Sync for EventHandler {}
+
+impl EventHandler {
+    pub(crate) fn new(callback: IotaEventCallback, context: *mut c_void) -> Self {
+        Self { callback, context }
+    }
+
+    pub(crate) fn call(&self, event: String) {
+        match CString::new(event) {
+            Ok(event) => (self.callback)(event.as_ptr(), self.context),
+            Err(err) => log::warn!("[C] event not passed to the callback: {err}"),
+        }
+    }
+}
+
+/// Use one runtime. Panics if called from a thread that is driving a runtime, `guard()` turns this into an error.
+pub(crate) fn block_on<C: std::future::Future>(cb: C) -> C::Output {
+    static INSTANCE: OnceCell<Runtime> = OnceCell::new();
+    let runtime = INSTANCE.get_or_init(|| Runtime::new().unwrap());
+    runtime.block_on(cb)
+}
+
+/// Reads a string passed by the caller.
+///
+/// # Safety
+///
+/// The pointer must be NULL or point to a nul-terminated string that stays valid for the lifetime `'a`.
+pub(crate) unsafe fn str_from_ptr<'a>(ptr: *const c_char) -> Result<&'a str> {
+    if ptr.is_null() {
+        return Err(Error::from("unexpected NULL string"));
+    }
+    Ok(CStr::from_ptr(ptr).to_str()?)
+}
+
+/// Hands a string over to the caller, who has to free it with `iota_destroy_string()`.
+pub(crate) fn string_to_ptr(string: String) -> *mut c_char {
+    // JSON never contains a nul character, it is escaped
+    CString::new(string).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Serializes a response and hands it over to the caller.
+pub(crate) fn response_to_ptr(response: &Response) -> Result<*mut c_char> {
+    Ok(string_to_ptr(serde_json::to_string(response)?))
+}
+
+/// Returns the ABI version of the library, callers should check that it matches `IOTA_SDK_ABI_VERSION` of the header
+/// they were compiled with.
+#[no_mangle]
+pub extern "C" fn iota_abi_version() -> u32 {
+    IOTA_SDK_ABI_VERSION
+}
+
+/// Frees a string returned by the library, NULL is ignored.
+///
+/// # Safety
+///
+/// The string must have been returned by the library and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Init the Rust logger with a JSON logger output config.
+///
+/// # Safety
+///
+/// `config` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_init_logger(config: *const c_char) -> bool {
+    guard(false, || {
+        rust_init_logger(str_from_ptr(config)?.to_string()).map_err(|err| Error::from(format!("{err:?}")))?;
+        Ok(true)
+    })
+}
+
+/// Calls a utils method, returns the JSON response.
+///
+/// # Safety
+///
+/// `method` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_utils_method(method: *const c_char) -> *mut c_char {
+    guard(std::ptr::null_mut(), || {
+        let method = serde_json::from_str::<UtilsMethod>(str_from_ptr(method)?)?;
+        response_to_ptr(&rust_call_utils_method(method))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::{null, null_mut};
+
+    use super::*;
+
+    const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+    /// Takes over a string returned by the library.
+    unsafe fn take_string(string: *mut c_char) -> Option<String> {
+        if string.is_null() {
+            return None;
+        }
+        let owned = CStr::from_ptr(string).to_str().unwrap().to_string();
+        iota_destroy_string(string);
+        Some(owned)
+    }
+
+    fn last_error() -> String {
+        unsafe { take_string(iota_last_error()) }.expect("no last error")
+    }
+
+    fn c_string(string: &str) -> CString {
+        CString::new(string).unwrap()
+    }
+
+    #[test]
+    fn null_handles_and_strings() {
+        let method = c_string(r#"{"name":"getInfo"}"#);
+        unsafe {
+            assert!(iota_call_client_method(null(), method.as_ptr()).is_null());
+            assert_eq!(last_error(), "unexpected NULL client");
+            assert!(iota_call_secret_manager_method(null(), method.as_ptr()).is_null());
+            assert_eq!(last_error(), "unexpected NULL secret manager");
+            assert!(iota_call_wallet_method(null(), method.as_ptr()).is_null());
+            assert_eq!(last_error(), "unexpected NULL wallet");
+            assert!(iota_get_client_from_wallet(null()).is_null());
+            assert_eq!(last_error(), "unexpected NULL wallet");
+
+            assert!(iota_call_utils_method(null()).is_null());
+            assert_eq!(last_error(), "unexpected NULL string");
+            assert!(iota_create_secret_manager(null()).is_null());
+            assert_eq!(last_error(), "unexpected NULL string");
+
+            // Destroying NULL is a no-op
+            iota_destroy_string(null_mut());
+            iota_destroy_client(null_mut());
+            iota_destroy_secret_manager(null_mut());
+            iota_destroy_wallet(null_mut());
+        }
+    }
+
+    #[test]
+    fn invalid_json() {
+        unsafe {
+            assert!(iota_call_utils_method(c_string("{").as_ptr()).is_null());
+            assert!(last_error().contains("EOF"));
+            assert!(iota_call_utils_method(c_string(r#"{"name":"unknownMethod"}"#).as_ptr()).is_null());
+            assert!(last_error().contains("unknownMethod"));
+            assert!(iota_create_secret_manager(c_string(r#"{"unknown":true}"#).as_ptr()).is_null());
+            assert!(last_error().contains("unknown"));
+        }
+    }
+
+    #[test]
+    fn method_errors_are_responses() {
+        unsafe {
+            let method = c_string(
+                r#"{"name":"bech32ToHex","data":{"bech32":"rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"}}"#,
+            );
+            let response = take_string(iota_call_utils_method(method.as_ptr())).unwrap();
+            assert!(response.starts_with(r#"{"type":"bech32ToHex","payload":"0x"#));
+
+            // The method is valid JSON but fails, so the error is in the response and not the last error
+            let response = take_string(iota_call_utils_method(
+                c_string(r#"{"name":"hexToBech32","data":{"hex":"zz","bech32Hrp":"rms"}}"#).as_ptr(),
+            ))
+            .unwrap();
+            assert!(response.starts_with(r#"{"type":"error","#));
+        }
+    }
+
+    #[test]
+    fn last_error_is_per_thread() {
+        std::thread::spawn(|| {
+            assert!(unsafe { take_string(iota_last_error()) }.is_none());
+            assert!(unsafe { iota_call_utils_method(null()) }.is_null());
+            assert_eq!(last_error(), "unexpected NULL string");
+            // Reading it doesn't clear it
+            assert_eq!(last_error(), "unexpected NULL string");
+        })
+        .join()
+        .unwrap();
+
+        // Errors of other threads aren't visible
+        assert!(unsafe { take_string(iota_last_error()) }.is_none());
+    }
+
+    #[test]
+    fn calls_from_a_runtime_fail() {
+        let options = c_string(&format!(r#"{{"mnemonic":"{MNEMONIC}"}}"#));
+        let method = c_string(
+            r#"{"name":"generateEd25519Addresses","data":{"options":{"range":{"start":0,"end":1},"bech32Hrp":"rms"}}}"#,
+        );
+        unsafe {
+            let secret_manager = iota_create_secret_manager(options.as_ptr());
+            assert!(!secret_manager.is_null());
+            let response = take_string(iota_call_secret_manager_method(secret_manager, method.as_ptr())).unwrap();
+            assert!(response.starts_with(r#"{"type":"generatedEd25519Addresses","#));
+
+            // Blocking on the runtime of the library from a worker thread of another runtime panics, which is
+            // returned as error instead of unwinding into the caller
+            let runtime = Runtime::new().unwrap();
+            // Raw pointers aren't `Send`
+            let (secret_manager_addr, method_addr) = (secret_manager as usize, method.as_ptr() as usize);
+            let (response, error) = runtime
+                .block_on(runtime.spawn(async move {
+                    let response = iota_call_secret_manager_method(
+                        secret_manager_addr as *const IotaSecretManager,
+                        method_addr as *const c_char,
+                    );
+                    (response.is_null(), last_error())
+                }))
+                .unwrap();
+            assert!(response);
+            assert!(error.starts_with("Internal error: "));
+
+            iota_destroy_secret_manager(secret_manager);
+        }
+    }
+}
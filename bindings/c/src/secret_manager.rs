@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{os::raw::c_char, sync::Arc};
+
+use iota_sdk_bindings_core::{
+    call_secret_manager_method as rust_call_secret_manager_method,
+    iota_sdk::client::secret::{SecretManager, SecretManagerDto},
+    SecretManagerMethod,
+};
+use tokio::sync::RwLock;
+
+use crate::{error::guard, response_to_ptr, str_from_ptr};
+
+/// A secret manager handle.
+pub struct IotaSecretManager {
+    pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+}
+
+/// Creates a secret manager from its JSON options. The secret manager must be freed with
+/// `iota_destroy_secret_manager()`.
+///
+/// # Safety
+///
+/// `options` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_secret_manager(options: *const c_char) -> *mut IotaSecretManager {
+    guard(std::ptr::null_mut(), || {
+        let secret_manager_dto = serde_json::from_str::<SecretManagerDto>(str_from_ptr(options)?)?;
+        let secret_manager = SecretManager::try_from(secret_manager_dto)?;
+
+        Ok(Box::into_raw(Box::new(IotaSecretManager {
+            secret_manager: Arc::new(RwLock::new(secret_manager)),
+        })))
+    })
+}
+
+/// Frees a secret manager, NULL is ignored.
+///
+/// # Safety
+///
+/// The secret manager must have been returned by the library and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_secret_manager(secret_manager: *mut IotaSecretManager) {
+    if !secret_manager.is_null() {
+        let secret_manager = Box::from_raw(secret_manager);
+        crate::block_on(async move { drop(secret_manager) });
+    }
+}
+
+/// Calls a secret manager method, returns the JSON response.
+///
+/// # Safety
+///
+/// `secret_manager` must be a valid secret manager and `method` a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_secret_manager_method(
+    secret_manager: *const IotaSecretManager,
+    method: *const c_char,
+) -> *mut c_char {
+    guard(std::ptr::null_mut(), || {
+        let secret_manager = secret_manager.as_ref().ok_or("unexpected NULL secret manager")?;
+        let method = serde_json::from_str::<SecretManagerMethod>(str_from_ptr(method)?)?;
+        let response = crate::block_on(rust_call_secret_manager_method(&secret_manager.secret_manager, method));
+
+        response_to_ptr(&response)
+    })
+}
@@ -0,0 +1,135 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{ffi::c_void, os::raw::c_char};
+
+use iota_sdk_bindings_core::{
+    call_wallet_method as rust_call_wallet_method,
+    iota_sdk::wallet::{events::types::WalletEventType, Wallet},
+    WalletMethod, WalletOptions,
+};
+
+use crate::{
+    error::{guard, Result},
+    response_to_ptr, str_from_ptr, EventHandler, IotaClient, IotaEventCallback, IotaSecretManager,
+};
+
+/// A wallet handle.
+pub struct IotaWallet {
+    pub(crate) wallet: Wallet,
+}
+
+/// Creates a wallet from JSON wallet options. The wallet must be freed with `iota_destroy_wallet()`.
+///
+/// # Safety
+///
+/// `options` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_wallet(options: *const c_char) -> *mut IotaWallet {
+    guard(std::ptr::null_mut(), || {
+        let wallet_options = serde_json::from_str::<WalletOptions>(str_from_ptr(options)?)?;
+        let wallet = crate::block_on(wallet_options.build())?;
+
+        Ok(Box::into_raw(Box::new(IotaWallet { wallet })))
+    })
+}
+
+/// Frees a wallet, NULL is ignored.
+///
+/// # Safety
+///
+/// The wallet must have been returned by the library and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_wallet(wallet: *mut IotaWallet) {
+    if !wallet.is_null() {
+        let wallet = Box::from_raw(wallet);
+        crate::block_on(async move { drop(wallet) });
+    }
+}
+
+/// Calls a wallet method, returns the JSON response.
+///
+/// # Safety
+///
+/// `wallet` must be a valid wallet and `method` a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_wallet_method(wallet: *const IotaWallet, method: *const c_char) -> *mut c_char {
+    guard(std::ptr::null_mut(), || {
+        let wallet = wallet_from_ptr(wallet)?;
+        let method = serde_json::from_str::<WalletMethod>(str_from_ptr(method)?)?;
+        let response = crate::block_on(rust_call_wallet_method(&wallet.wallet, method));
+
+        response_to_ptr(&response)
+    })
+}
+
+/// Listens to wallet events, `events` is a JSON array of event type numbers, an empty array listens to all events.
+/// The callback receives the events until the listeners are cleared with the `clearListeners` wallet method.
+///
+/// # Safety
+///
+/// `wallet` must be a valid wallet, `events` a nul-terminated string and `context` safe to use from any thread until
+/// the listeners are cleared.
+#[no_mangle]
+pub unsafe extern "C" fn iota_listen_wallet(
+    wallet: *const IotaWallet,
+    events: *const c_char,
+    callback: IotaEventCallback,
+    context: *mut c_void,
+) -> bool {
+    guard(false, || {
+        let wallet = wallet_from_ptr(wallet)?;
+        let events = serde_json::from_str::<Vec<u8>>(str_from_ptr(events)?)?
+            .into_iter()
+            .map(WalletEventType::try_from)
+            .collect::<core::result::Result<Vec<_>, _>>()?;
+        let handler = EventHandler::new(callback, context);
+        crate::block_on(
+            wallet
+                .wallet
+                .listen(events, move |event| match serde_json::to_string(event) {
+                    Ok(event) => handler.call(event),
+                    Err(err) => log::warn!("[C] event not serialized: {err}"),
+                }),
+        );
+
+        Ok(true)
+    })
+}
+
+/// Returns the client of the wallet, it must be freed with `iota_destroy_client()`.
+///
+/// # Safety
+///
+/// `wallet` must be a valid wallet.
+#[no_mangle]
+pub unsafe extern "C" fn iota_get_client_from_wallet(wallet: *const IotaWallet) -> *mut IotaClient {
+    guard(std::ptr::null_mut(), || {
+        let client = wallet_from_ptr(wallet)?.wallet.client().clone();
+
+        Ok(Box::into_raw(Box::new(IotaClient { client })))
+    })
+}
+
+/// Returns the secret manager of the wallet, it must be freed with `iota_destroy_secret_manager()`.
+///
+/// # Safety
+///
+/// `wallet` must be a valid wallet.
+#[no_mangle]
+pub unsafe extern "C" fn iota_get_secret_manager_from_wallet(wallet: *const IotaWallet) -> *mut IotaSecretManager {
+    guard(std::ptr::null_mut(), || {
+        let secret_manager = wallet_from_ptr(wallet)?.wallet.get_secret_manager().clone();
+
+        Ok(Box::into_raw(Box::new(IotaSecretManager { secret_manager })))
+    })
+}
+
+/// Borrows the wallet behind a handle.
+///
+/// # Safety
+///
+/// The pointer must be NULL or point to a valid wallet.
+unsafe fn wallet_from_ptr<'a>(wallet: *const IotaWallet) -> Result<&'a IotaWallet> {
+    Ok(wallet.as_ref().ok_or("unexpected NULL wallet")?)
+}
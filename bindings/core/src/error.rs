@@ -39,6 +39,12 @@ impl From<iota_sdk::client::stronghold::Error> for Error {
     }
 }
 
+impl From<iota_sdk::utils::parse::ParseError> for Error {
+    fn from(error: iota_sdk::utils::parse::ParseError) -> Self {
+        Self::Client(iota_sdk::client::Error::Parse(error))
+    }
+}
+
 #[cfg(feature = "mqtt")]
 impl From<iota_sdk::client::node_api::mqtt::Error> for Error {
     fn from(error: iota_sdk::client::node_api::mqtt::Error) -> Self {
@@ -46,6 +52,17 @@ impl From<iota_sdk::client::node_api::mqtt::Error> for Error {
     }
 }
 
+impl Error {
+    /// Returns the client error causing this error, if any.
+    fn client_error(&self) -> Option<&iota_sdk::client::Error> {
+        match self {
+            Self::Client(error) => Some(error),
+            Self::Wallet(iota_sdk::wallet::Error::Client(error)) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
 // Serialize type with Display error.
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -63,6 +80,11 @@ impl Serialize for Error {
         let kind = kind_dbg.split([' ', '(']).next().unwrap();
         seq.serialize_entry("type", &kind)?;
         seq.serialize_entry("error", &self.to_string())?;
+        // Add the stable code and category of client errors, so they can be handled without parsing the message
+        if let Some(error) = self.client_error() {
+            seq.serialize_entry("code", &error.code())?;
+            seq.serialize_entry("category", &error.category())?;
+        }
         seq.end()
     }
 }
@@ -11,18 +11,23 @@ use iota_sdk::{
     client::{
         api::{input_selection::BurnDto, PreparedTransactionDataDto, SignedTransactionDataDto},
         secret::GenerateAddressOptions,
+        sign_in::SignInChallenge,
     },
-    types::block::{
-        address::Bech32Address,
-        output::{dto::OutputDto, OutputId, TokenId},
-        payload::transaction::TransactionId,
+    types::{
+        block::{
+            address::Bech32Address,
+            output::{OutputId, TokenId},
+            payload::transaction::TransactionId,
+        },
+        dto::OutputDto,
     },
     wallet::{
         account::{
-            ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions, MintNftParams,
-            OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            AutoClaimPolicy, ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions,
+            MintNftParams, OutputParams, OutputsToClaim, SendAllOptions, SyncOptions, TransactionFilterOptions,
+            TransactionOptionsDto,
         },
-        SendNativeTokensParams, SendNftParams, SendParams,
+        ClientOptions, SendNativeTokensParams, SendNftParams, SendParams,
     },
     U256,
 };
@@ -40,6 +45,9 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
+    /// Claim the outputs that match the policy, in batches.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    AutoClaimOutputs { policy: AutoClaimPolicy },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
@@ -54,12 +62,33 @@ pub enum AccountMethod {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Selects the inputs and builds the transaction essence without signing or submitting it.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    DryRunTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Exports the public derivation material of the account, a signed derivation proof for each generated address.
+    /// Expected response: [`PublicAccount`](crate::Response::PublicAccount)
+    ExportPublicAccount,
+    /// Returns the sent and incoming transactions of the account which match the filter, newest first
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    FilterTransactions { filter_options: TransactionFilterOptions },
     /// Generate new Ed25519 addresses.
     /// Expected response: [`GeneratedEd25519Addresses`](crate::Response::GeneratedEd25519Addresses)
     GenerateEd25519Addresses {
         amount: u32,
         options: Option<GenerateAddressOptions>,
     },
+    /// Signs an ownership statement for the challenge with the key controlling each of the unspent outputs.
+    /// Expected response: [`OwnershipProofs`](crate::Response::OwnershipProofs)
+    #[serde(rename_all = "camelCase")]
+    GenerateOwnershipProof {
+        output_ids: Vec<OutputId>,
+        /// The challenge of the verifier, hex encoded String
+        challenge: String,
+    },
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::Response::Balance)
     GetBalance,
@@ -210,6 +239,12 @@ pub enum AccountMethod {
         params: Vec<SendParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare to send all funds of the account to an address, without a remainder.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareSendAll {
+        address: Bech32Address,
+        options: Option<SendAllOptions>,
+    },
     /// Prepare to send native tokens.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     PrepareSendNativeTokens {
@@ -252,6 +287,18 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Remove a metadata entry of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RemoveMetadata { key: String },
+    /// Replaces a pending transaction with a transaction that spends the same inputs, sending its transfers to the
+    /// destination or back to the account.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    ReplaceTransaction {
+        transaction_id: TransactionId,
+        destination: Option<Bech32Address>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -272,6 +319,18 @@ pub enum AccountMethod {
         address: Bech32Address,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send all funds of the account to an address, without a remainder.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    SendAll {
+        address: Bech32Address,
+        options: Option<SendAllOptions>,
+    },
+    /// Send base coins to many addresses in as few transactions as possible.
+    /// Expected response: [`PayoutReport`](crate::Response::PayoutReport)
+    SendBatch {
+        params: Vec<SendParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send base coins to multiple addresses, or with additional parameters.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendWithParams {
@@ -284,19 +343,41 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Freeze or unfreeze an address of the account. Outputs of frozen addresses aren't used as inputs.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetAddressFrozen { address: Bech32Address, frozen: bool },
+    /// Set or remove the label of an address of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetAddressLabel {
+        address: Bech32Address,
+        label: Option<String>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
+    /// Set the archived state of the account. Archived accounts aren't synced in the background.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetArchived { archived: bool },
+    /// Set the client options of an account bound to another network than the wallet.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetClientOptions { client_options: Box<ClientOptions> },
     /// Set the fallback SyncOptions for account syncing.
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Set a metadata entry of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetMetadata { key: String, value: String },
     /// Validate the transaction, sign it, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     SignAndSubmitTransaction {
         prepared_transaction_data: PreparedTransactionDataDto,
     },
+    /// Sign a challenge of a web service to sign in with an address of the account.
+    /// Expected response: [`SignInResponse`](crate::Response::SignInResponse)
+    SignIn { challenge: SignInChallenge },
     /// Sign a prepared transaction.
     /// Expected response: [`SignedTransactionData`](crate::Response::SignedTransactionData)
     #[serde(rename_all = "camelCase")]
@@ -8,23 +8,22 @@ use iota_sdk::{
     client::{
         api::{
             ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            PreparedTransactionDataDto, SendAmountOptions,
         },
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
         secret::SecretManagerDto,
     },
-    types::block::{
-        address::{Bech32Address, Hrp},
-        output::{
-            dto::{OutputDto, TokenSchemeDto},
-            feature::dto::FeatureDto,
-            unlock_condition::dto::UnlockConditionDto,
-            AliasId, FoundryId, NativeToken, NftId, OutputId,
+    types::{
+        block::{
+            address::{Bech32Address, Hrp},
+            output::{AliasId, FoundryId, NativeToken, NftId, OutputId},
+            payload::{milestone::MilestoneId, transaction::TransactionId},
+            BlockId,
         },
-        payload::{dto::PayloadDto, milestone::MilestoneId, transaction::TransactionId},
-        BlockDto, BlockId,
+        dto::{BlockDto, FeatureDto, OutputDto, PayloadDto, TokenSchemeDto, UnlockConditionDto},
     },
+    utils::parse::ParseMode,
 };
 use serde::{Deserialize, Serialize};
 
@@ -135,6 +134,7 @@ pub enum ClientMethod {
         method: String,
         endpoint: String,
         query_params: Vec<String>,
+        #[serde(alias = "request")]
         request_object: Option<String>,
     },
     /// Prepare a transaction for signing
@@ -155,6 +155,13 @@ pub enum ClientMethod {
         /// Prepared transaction data
         prepared_transaction_data: PreparedTransactionDataDto,
     },
+    /// Describe a prepared transaction in a human-readable form, to confirm it before signing.
+    /// Expected response: [`TransactionDescription`](crate::Response::TransactionDescription)
+    #[serde(rename_all = "camelCase")]
+    DescribeTransaction {
+        /// Prepared transaction data
+        prepared_transaction_data: PreparedTransactionDataDto,
+    },
     /// Build a block containing the specified payload and post it to the network.
     PostBlockPayload {
         /// The payload to send
@@ -192,6 +199,11 @@ pub enum ClientMethod {
         /// Block
         block_bytes: Vec<u8>,
     },
+    /// Post a block with finished PoW to all healthy nodes at once
+    BroadcastBlock {
+        /// Block
+        block: BlockDto,
+    },
     /// Get block
     #[serde(rename_all = "camelCase")]
     GetBlock {
@@ -216,6 +228,14 @@ pub enum ClientMethod {
         /// Output ID
         output_id: OutputId,
     },
+    /// Get an output with its metadata, the block that created it, the milestone that booked it and, if a node runs
+    /// the PoI plugin, the merkle audit path from the block to the milestone.
+    /// Expected response: [`OutputWithMetadataProof`](crate::Response::OutputWithMetadataProof)
+    #[serde(rename_all = "camelCase")]
+    GetOutputWithMetadataProof {
+        /// Output ID
+        output_id: OutputId,
+    },
     /// Get output metadata
     #[serde(rename_all = "camelCase")]
     GetOutputMetadata {
@@ -265,6 +285,15 @@ pub enum ClientMethod {
     },
     /// Get the treasury output.
     GetTreasury,
+    /// List the mutations of the treasury by the receipts of the milestones in the range, ordered by milestone index.
+    /// Expected response: [`TreasuryMutations`](crate::Response::TreasuryMutations)
+    #[serde(rename_all = "camelCase")]
+    GetTreasuryMutations {
+        /// The index of the first milestone of the range
+        start_milestone_index: u32,
+        /// The index of the last milestone of the range
+        end_milestone_index: u32,
+    },
     /// Returns the included block of the transaction.
     #[serde(rename_all = "camelCase")]
     GetIncludedBlock {
@@ -381,6 +410,27 @@ pub enum ClientMethod {
         /// Addresses generation options
         generate_addresses_options: GenerateAddressesOptions,
     },
+    /// Send an amount to an address, with inputs from the secret manager, and retry until the block is included.
+    /// Expected response: [`SentAmount`](crate::Response::SentAmount)
+    #[serde(rename_all = "camelCase")]
+    SendAmount {
+        /// Secret manager
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        secret_manager: SecretManagerDto,
+        /// The address to send the amount to
+        address: Bech32Address,
+        /// The amount to send
+        amount: u64,
+        /// Options for the input selection and the inclusion checks
+        #[serde(default)]
+        options: Option<SendAmountOptions>,
+    },
+    /// Get the confirmed balances of addresses with the number of outputs of each kind they own.
+    /// Expected response: [`AddressBalances`](crate::Response::AddressBalances)
+    GetAddressBalances {
+        /// Addresses
+        addresses: Vec<Bech32Address>,
+    },
     /// Function to find inputs from addresses for a provided amount (useful for offline signing)
     FindInputs {
         /// Addresses
@@ -450,6 +500,14 @@ pub enum ClientMethod {
         /// Human readable part
         bech32_hrp: Option<Hrp>,
     },
+    /// Parses a user-provided bech32 address of the network, or a hex Ed25519 address if the mode is lenient
+    ParseAddress {
+        /// Address
+        address: String,
+        /// How strictly the address is parsed
+        #[serde(default)]
+        mode: ParseMode,
+    },
     /// Calculate the minimum required storage deposit for an output.
     /// Expected response:
     /// [`MinimumRequiredStorageDeposit`](crate::Response::MinimumRequiredStorageDeposit)
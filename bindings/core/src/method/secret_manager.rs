@@ -9,7 +9,6 @@ use iota_sdk::{
 };
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "stronghold")]
 use crate::OmittedDebug;
 
 /// Each public secret manager method.
@@ -69,6 +68,24 @@ pub enum SecretManagerMethod {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
     },
+    /// Zeroize the secrets of the secret manager until it's unlocked again.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    Lock,
+    /// Unlock the secret manager with the Stronghold password, the mnemonic, the hex seed or the hex private key it was
+    /// created with.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    Unlock {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        secret: String,
+    },
+    /// Returns whether the secret manager is locked.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    IsLocked,
+    /// Set the time after which the secret manager locks itself once it was unlocked, `None` disables it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(rename_all = "camelCase")]
+    SetAutoLock { interval_in_milliseconds: Option<u64> },
 }
 
 #[cfg(test)]
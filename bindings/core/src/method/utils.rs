@@ -2,18 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use derivative::Derivative;
-use iota_sdk::types::block::{
-    address::{Bech32Address, Hrp},
-    output::{dto::OutputDto, AliasId, NftId, OutputId, RentStructure},
-    payload::{
-        dto::MilestonePayloadDto,
-        transaction::{
-            dto::{TransactionEssenceDto, TransactionPayloadDto},
-            TransactionId,
+use iota_sdk::{
+    types::{
+        block::{
+            address::{Bech32Address, Hrp},
+            output::{AliasId, NftId, OutputId, RentStructure},
+            payload::transaction::TransactionId,
+        },
+        dto::{
+            BlockDto, Ed25519SignatureDto, MilestonePayloadDto, OutputDto, TransactionEssenceDto, TransactionPayloadDto,
         },
     },
-    signature::dto::Ed25519SignatureDto,
-    BlockDto,
+    utils::{mnemonic::MnemonicLanguage, parse::ParseMode},
 };
 use serde::{Deserialize, Serialize};
 
@@ -67,18 +67,45 @@ pub enum UtilsMethod {
         /// Address
         address: Bech32Address,
     },
+    /// Parses a user-provided bech32 address, or a hex Ed25519 address if the mode is lenient
+    #[serde(rename_all = "camelCase")]
+    ParseAddress {
+        /// Address
+        address: String,
+        /// Human readable part of the network, bech32 addresses with another one are rejected and hex addresses are
+        /// converted with it
+        bech32_hrp: Option<Hrp>,
+        /// How strictly the address is parsed
+        #[serde(default)]
+        mode: ParseMode,
+    },
     /// Checks if a String is a valid bech32 encoded address.
     IsAddressValid {
         /// Address
         address: String,
     },
     /// Generates a new mnemonic.
-    GenerateMnemonic,
+    #[serde(rename_all = "camelCase")]
+    GenerateMnemonic {
+        /// The number of words, 24 by default
+        #[serde(default)]
+        word_count: Option<usize>,
+        /// The language of the wordlist, English by default
+        #[serde(default)]
+        language: Option<MnemonicLanguage>,
+    },
     /// Returns a hex encoded seed for a mnemonic.
     MnemonicToHexSeed {
         /// Mnemonic
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Passphrase, also known as the 25th word
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
+        /// The language of the wordlist, English by default
+        #[serde(default)]
+        language: Option<MnemonicLanguage>,
     },
     /// Returns a block ID (Blake2b256 hash of block bytes) from a block
     BlockId {
@@ -140,6 +167,9 @@ pub enum UtilsMethod {
     VerifyMnemonic {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// The language of the wordlist, English by default
+        #[serde(default)]
+        language: Option<MnemonicLanguage>,
     },
     /// Verify an ed25519 signature against a message.
     VerifyEd25519Signature {
@@ -13,9 +13,9 @@ use iota_sdk::{
     wallet::{
         account::{
             types::{AccountAddress, AccountIdentifier},
-            SyncOptions,
+            AccountFilterOptions, AutoClaimPolicy, PublicAccountDto, RetryOptions, SyncOptions,
         },
-        ClientOptions,
+        ClientOptions, RemoveAccountOptions,
     },
     Url,
 };
@@ -41,6 +41,21 @@ pub enum WalletMethod {
         bech32_hrp: Option<Hrp>,
         /// Account addresses.
         addresses: Option<Vec<AccountAddress>>,
+        /// The coin type the addresses are derived with, the coin type of the wallet if not set.
+        coin_type: Option<u32>,
+        /// The BIP44 account index the addresses are derived with, the account index if not set.
+        bip44_account_index: Option<u32>,
+        /// Client options binding the account to another network than the wallet.
+        client_options: Option<Box<ClientOptions>>,
+    },
+    /// Creates a watch-only account from the public export of an account.
+    /// Expected response: [`Account`](crate::Response::Account)
+    #[serde(rename_all = "camelCase")]
+    CreateWatchOnlyAccount {
+        /// The exported public account.
+        public_account: PublicAccountDto,
+        /// The account alias.
+        alias: Option<String>,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::Response::Account)
@@ -52,6 +67,10 @@ pub enum WalletMethod {
     /// Read accounts.
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     GetAccounts,
+    /// Read the accounts which match the filter options, archived accounts are only returned if requested.
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    #[serde(rename_all = "camelCase")]
+    FilterAccounts { filter_options: AccountFilterOptions },
     /// Get historic chrysalis data.
     /// Expected response: [`ChrysalisData`](crate::Response::ChrysalisData)
     #[cfg(feature = "storage")]
@@ -114,6 +133,9 @@ pub enum WalletMethod {
         /// fields will be overwritten to skip existing addresses.
         sync_options: Option<SyncOptions>,
     },
+    /// Recover the transactions that were signed with a request ID, but not stored.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    RecoverPendingSubmissions,
     /// Restore a backup from a Stronghold file
     /// Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already
     /// created If Stronghold is used as secret_manager, the existing Stronghold file will be overwritten. If a
@@ -141,10 +163,18 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<Hrp>,
     },
+    /// Removes an account and all records stored for it, refused for accounts with funds or pending transactions
+    /// unless forced.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveAccount {
+        account_id: AccountIdentifier,
+        options: Option<RemoveAccountOptions>,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
-    /// Updates the client options for all accounts.
+    /// Updates the client options for all accounts, except the ones bound to another network.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[serde(rename_all = "camelCase")]
     SetClientOptions { client_options: Box<ClientOptions> },
@@ -200,6 +230,46 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Sync all accounts that aren't archived concurrently, the most recently active accounts first.
+    /// Expected response: [`Balance`](crate::Response::Balance)
+    SyncAll {
+        /// Sync options
+        options: Option<SyncOptions>,
+    },
+    /// Start claiming outputs that match the policy in the background.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    StartBackgroundClaim {
+        /// Auto claim policy
+        policy: Option<AutoClaimPolicy>,
+        /// Interval in milliseconds
+        interval_in_milliseconds: Option<u64>,
+    },
+    /// Stop claiming outputs in the background.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    StopBackgroundClaim,
+    /// Start promoting and reattaching pending transactions in the background.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    StartBackgroundRetry {
+        /// Retry options
+        options: Option<RetryOptions>,
+        /// Interval in milliseconds
+        interval_in_milliseconds: Option<u64>,
+    },
+    /// Stop promoting and reattaching pending transactions in the background.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    StopBackgroundRetry,
+    /// Start applying the UTXO changes of confirmed milestones to the accounts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "mqtt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
+    StartLedgerFollower,
+    /// Stop applying the UTXO changes of confirmed milestones to the accounts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "mqtt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
+    StopLedgerFollower,
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
@@ -211,6 +281,18 @@ pub enum WalletMethod {
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     #[serde(rename_all = "camelCase")]
     ClearListeners { event_types: Vec<WalletEventType> },
+    /// Returns the persisted events with a sequence number of at least `from_seq`, to resume processing them.
+    /// Expected response: [`Events`](crate::Response::Events)
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    #[serde(rename_all = "camelCase")]
+    ReplayEvents { from_seq: u64 },
+    /// Removes the persisted events with a sequence number lower than `before_seq`.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    #[serde(rename_all = "camelCase")]
+    PruneEvents { before_seq: u64 },
     /// Update the authentication for the provided node.
     /// Expected response: [`Ok`](crate::Response::Ok)
     UpdateNodeAuth {
@@ -5,15 +5,17 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    client::api::{
-        PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto,
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
+        sign_in::SignInResponseDto,
     },
     types::{
-        block::output::{dto::OutputDto, Output},
-        TryFromDto,
+        block::output::Output,
+        dto::{OutputDto, TryFromDto},
     },
     wallet::account::{
-        types::TransactionDto, Account, OutputDataDto, PreparedCreateNativeTokenTransactionDto, TransactionOptions,
+        types::TransactionDto, Account, OutputDataDto, OwnershipProofDto, PreparedCreateNativeTokenTransactionDto,
+        PublicAccountDto, TransactionOptions,
     },
 };
 
@@ -29,6 +31,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
+        AccountMethod::AutoClaimOutputs { policy } => {
+            let transactions = account.auto_claim_outputs(&policy).await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::ClaimableOutputs { outputs_to_claim } => {
             let output_ids = account.claimable_outputs(outputs_to_claim).await?;
             Response::OutputIds(output_ids)
@@ -42,10 +48,36 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::DryRunTransaction { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let data = account
+                .dry_run_transaction(
+                    outputs
+                        .into_iter()
+                        .map(|o| Ok(Output::try_from_dto_with_params(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::FilterTransactions { filter_options } => {
+            let transactions = account.filter_transactions(filter_options).await;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
+        AccountMethod::ExportPublicAccount => {
+            let public_account = account.export_public_account().await?;
+            Response::PublicAccount(PublicAccountDto::from(&public_account))
+        }
         AccountMethod::GenerateEd25519Addresses { amount, options } => {
             let address = account.generate_ed25519_addresses(amount, options).await?;
             Response::GeneratedAccountAddresses(address)
         }
+        AccountMethod::GenerateOwnershipProof { output_ids, challenge } => {
+            let challenge: Vec<u8> = prefix_hex::decode(challenge)?;
+            let proofs = account.generate_ownership_proof(&output_ids, &challenge).await?;
+            Response::OwnershipProofs(proofs.iter().map(OwnershipProofDto::from).collect())
+        }
         AccountMethod::GetBalance => Response::Balance(account.balance().await?),
         AccountMethod::GetFoundryOutput { token_id } => {
             let output = account.get_foundry_output(token_id).await?;
@@ -201,6 +233,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareSendAll { address, options } => {
+            let data = account.prepare_send_all(address, options).await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareSendNativeTokens { params, options } => {
             let data = account
                 .prepare_send_native_tokens(
@@ -247,6 +283,24 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let events = account.register_participation_events(&options).await?;
             Response::ParticipationEvents(events)
         }
+        AccountMethod::RemoveMetadata { key } => {
+            account.remove_metadata(&key).await?;
+            Response::Ok
+        }
+        AccountMethod::ReplaceTransaction {
+            transaction_id,
+            destination,
+            options,
+        } => {
+            let transaction = account
+                .replace_transaction(
+                    &transaction_id,
+                    destination,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -271,6 +325,16 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SendAll { address, options } => {
+            let transaction = account.send_all(address, options).await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::SendBatch { params, options } => {
+            let report = account
+                .send_batch(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::PayoutReport(report)
+        }
         AccountMethod::SendWithParams { params, options } => {
             let transaction = account
                 .send_with_params(params, options.map(TransactionOptions::try_from_dto).transpose()?)
@@ -290,14 +354,34 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SetAddressFrozen { address, frozen } => {
+            account.set_address_frozen(address, frozen).await?;
+            Response::Ok
+        }
+        AccountMethod::SetAddressLabel { address, label } => {
+            account.set_address_label(address, label).await?;
+            Response::Ok
+        }
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
         }
+        AccountMethod::SetArchived { archived } => {
+            account.set_archived(archived).await?;
+            Response::Ok
+        }
+        AccountMethod::SetClientOptions { client_options } => {
+            account.set_client_options(*client_options).await?;
+            Response::Ok
+        }
         AccountMethod::SetDefaultSyncOptions { options } => {
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::SetMetadata { key, value } => {
+            account.set_metadata(&key, &value).await?;
+            Response::Ok
+        }
         AccountMethod::SignAndSubmitTransaction {
             prepared_transaction_data,
         } => {
@@ -312,6 +396,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SignIn { challenge } => {
+            let response = account.sign_in(challenge).await?;
+            Response::SignInResponse(SignInResponseDto::from(&response))
+        }
         AccountMethod::SignTransactionEssence {
             prepared_transaction_data,
         } => {
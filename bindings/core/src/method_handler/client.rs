@@ -1,25 +1,26 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::str::FromStr;
+
 #[cfg(feature = "mqtt")]
 use iota_sdk::client::mqtt::{MqttPayload, Topic};
 use iota_sdk::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto},
+        node_api::{error::Error as NodeApiError, plugin::Method},
         request_funds_from_faucet, Client,
     },
     types::{
-        api::core::response::OutputWithMetadataResponse,
         block::{
-            input::dto::UtxoInputDto,
-            output::{
-                dto::{OutputBuilderAmountDto, OutputDto},
-                AliasOutput, BasicOutput, FoundryOutput, NftOutput, Output, Rent,
-            },
-            payload::{dto::MilestonePayloadDto, Payload},
-            Block, BlockDto,
+            output::{AliasOutput, BasicOutput, FoundryOutput, NftOutput, Output, Rent},
+            payload::Payload,
+            Block,
+        },
+        dto::{
+            response::{OutputWithMetadataResponse, ReceiptResponse},
+            BlockDto, MilestonePayloadDto, OutputBuilderAmountDto, OutputDto, TryFromDto, UtxoInputDto,
         },
-        TryFromDto,
     },
 };
 
@@ -245,6 +246,15 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                     .into(),
             )
         }
+        ClientMethod::DescribeTransaction {
+            prepared_transaction_data,
+        } => {
+            let protocol_parameters = client.get_protocol_parameters().await?;
+            let prepared_transaction_data =
+                PreparedTransactionData::try_from_dto_with_params(prepared_transaction_data, &protocol_parameters)?;
+
+            Response::TransactionDescription(prepared_transaction_data.describe(&protocol_parameters))
+        }
         ClientMethod::PostBlockPayload { payload } => {
             let block_builder = client.build_block();
 
@@ -282,6 +292,14 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 )?)
                 .await?,
         ),
+        ClientMethod::BroadcastBlock { block } => Response::BroadcastResult(
+            client
+                .broadcast_block(&Block::try_from_dto_with_params(
+                    block,
+                    client.get_protocol_parameters().await?,
+                )?)
+                .await?,
+        ),
         ClientMethod::GetBlock { block_id } => Response::Block(BlockDto::from(&client.get_block(&block_id).await?)),
         ClientMethod::GetBlockMetadata { block_id } => {
             Response::BlockMetadata(client.get_block_metadata(&block_id).await?)
@@ -293,6 +311,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 .await
                 .map(OutputWithMetadataResponse::from)?,
         ),
+        ClientMethod::GetOutputWithMetadataProof { output_id } => {
+            Response::OutputWithMetadataProof(client.get_output_with_metadata_proof(&output_id).await?)
+        }
         ClientMethod::GetOutputMetadata { output_id } => {
             Response::OutputMetadata(client.get_output_metadata(&output_id).await?)
         }
@@ -314,11 +335,26 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetUtxoChangesByIndex { index } => {
             Response::MilestoneUtxoChanges(client.get_utxo_changes_by_index(index).await?)
         }
-        ClientMethod::GetReceipts => Response::Receipts(client.get_receipts().await?),
-        ClientMethod::GetReceiptsMigratedAt { milestone_index } => {
-            Response::Receipts(client.get_receipts_migrated_at(milestone_index).await?)
+        ClientMethod::GetReceipts => {
+            Response::Receipts(client.get_receipts().await?.iter().map(ReceiptResponse::from).collect())
         }
+        ClientMethod::GetReceiptsMigratedAt { milestone_index } => Response::Receipts(
+            client
+                .get_receipts_migrated_at(milestone_index)
+                .await?
+                .iter()
+                .map(ReceiptResponse::from)
+                .collect(),
+        ),
         ClientMethod::GetTreasury => Response::Treasury(client.get_treasury().await?),
+        ClientMethod::GetTreasuryMutations {
+            start_milestone_index,
+            end_milestone_index,
+        } => Response::TreasuryMutations(
+            client
+                .get_treasury_mutations(start_milestone_index..=end_milestone_index)
+                .await?,
+        ),
         ClientMethod::GetIncludedBlock { transaction_id } => {
             Response::Block(BlockDto::from(&client.get_included_block(&transaction_id).await?))
         }
@@ -396,6 +432,18 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                     .await?,
             )
         }
+        ClientMethod::SendAmount {
+            secret_manager,
+            address,
+            amount,
+            options,
+        } => {
+            let secret_manager = secret_manager.try_into()?;
+            Response::SentAmount(client.send_amount(&secret_manager, address, amount, options).await?)
+        }
+        ClientMethod::GetAddressBalances { addresses } => {
+            Response::AddressBalances(client.get_address_balances(&addresses).await?)
+        }
         ClientMethod::FindInputs { addresses, amount } => Response::Inputs(
             client
                 .find_inputs(addresses, amount)
@@ -432,6 +480,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::HexPublicKeyToBech32Address { hex, bech32_hrp } => {
             Response::Bech32Address(client.hex_public_key_to_bech32_address(&hex, bech32_hrp).await?)
         }
+        ClientMethod::ParseAddress { address, mode } => {
+            Response::Bech32Address(client.input_parser(mode).await?.parse_address(&address)?)
+        }
         ClientMethod::MinimumRequiredStorageDeposit { output } => {
             let output = Output::try_from_dto_with_params(output, client.get_token_supply().await?)?;
             let rent_structure = client.get_rent_structure().await?;
@@ -450,9 +501,17 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
             query_params,
             request_object,
         } => {
-            let data: serde_json::Value = client
-                .call_plugin_route(&base_plugin_path, &method, &endpoint, query_params, request_object)
-                .await?;
+            let method = Method::from_str(&method.to_ascii_uppercase())
+                .map_err(|_| iota_sdk::client::Error::Node(NodeApiError::NotSupported(method)))?;
+            let mut path = format!("{base_plugin_path}{endpoint}");
+            if !query_params.is_empty() {
+                path = format!("{path}?{}", query_params.join("&"));
+            }
+            // The request object is JSON, other strings are sent as JSON strings
+            let request_object = request_object.map(|request_object| {
+                serde_json::from_str(&request_object).unwrap_or(serde_json::Value::String(request_object))
+            });
+            let data: serde_json::Value = client.call_plugin_route(method, &path, request_object.as_ref()).await?;
             Response::CustomJson(data)
         }
     };
@@ -7,8 +7,8 @@ use iota_sdk::{
         secret::{SecretManage, SecretManager},
     },
     types::{
-        block::{signature::dto::Ed25519SignatureDto, unlock::Unlock},
-        TryFromDto,
+        block::unlock::Unlock,
+        dto::{Ed25519SignatureDto, TryFromDto},
     },
 };
 use tokio::sync::RwLock;
@@ -17,10 +17,10 @@ use crate::{method::SecretManagerMethod, response::Response, Result};
 
 /// Call a secret manager method.
 pub(crate) async fn call_secret_manager_method_internal(
-    secret_manager: &RwLock<SecretManager>,
+    secret_manager_lock: &RwLock<SecretManager>,
     method: SecretManagerMethod,
 ) -> Result<Response> {
-    let secret_manager = secret_manager.read().await;
+    let secret_manager = secret_manager_lock.read().await;
     let response = match method {
         SecretManagerMethod::GenerateEd25519Addresses { options } => {
             let addresses = secret_manager.generate_ed25519_addresses(options).await?;
@@ -80,6 +80,28 @@ pub(crate) async fn call_secret_manager_method_internal(
                 return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
             }
         }
+        SecretManagerMethod::Lock => {
+            secret_manager.lock().await;
+            Response::Ok
+        }
+        SecretManagerMethod::Unlock { secret } => {
+            secret_manager.unlock(secret).await?;
+            Response::Ok
+        }
+        SecretManagerMethod::IsLocked => Response::Bool(secret_manager.is_locked().await),
+        #[cfg(not(target_family = "wasm"))]
+        SecretManagerMethod::SetAutoLock {
+            interval_in_milliseconds,
+        } => {
+            // Changing the auto-lock of a Stronghold needs write access
+            drop(secret_manager);
+            secret_manager_lock
+                .write()
+                .await
+                .set_auto_lock(interval_in_milliseconds.map(std::time::Duration::from_millis))
+                .await;
+            Response::Ok
+        }
     };
     Ok(response)
 }
@@ -1,19 +1,23 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crypto::keys::bip39::Mnemonic;
+use crypto::keys::bip39::{Mnemonic, Passphrase};
 use iota_sdk::{
-    client::{hex_public_key_to_bech32_address, hex_to_bech32, verify_mnemonic, Client},
+    client::{hex_public_key_to_bech32_address, hex_to_bech32, Client},
     types::{
         block::{
-            address::{dto::AddressDto, Address, AliasAddress, ToBech32Ext},
+            address::{Address, AliasAddress, ToBech32Ext},
             input::UtxoInput,
             output::{AliasId, FoundryId, InputsCommitment, NftId, Output, OutputId, Rent, TokenId},
             payload::{transaction::TransactionEssence, MilestonePayload, TransactionPayload},
             signature::Ed25519Signature,
             Block,
         },
-        TryFromDto,
+        dto::{AddressDto, TryFromDto},
+    },
+    utils::{
+        mnemonic::{generate_mnemonic, mnemonic_to_seed, verify_mnemonic, DEFAULT_MNEMONIC_WORD_COUNT},
+        parse::InputParser,
     },
 };
 use packable::PackableExt;
@@ -33,11 +37,32 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             Response::Bech32Address(hex_public_key_to_bech32_address(&hex, bech32_hrp)?)
         }
         UtilsMethod::ParseBech32Address { address } => Response::ParsedBech32Address(AddressDto::from(address.inner())),
+        UtilsMethod::ParseAddress {
+            address,
+            bech32_hrp,
+            mode,
+        } => Response::Bech32Address(
+            InputParser::new(mode)
+                .with_bech32_hrp(bech32_hrp)
+                .parse_address(&address)?,
+        ),
         UtilsMethod::IsAddressValid { address } => Response::Bool(Address::is_valid_bech32(&address)),
-        UtilsMethod::GenerateMnemonic => Response::GeneratedMnemonic(Client::generate_mnemonic()?.to_string()),
-        UtilsMethod::MnemonicToHexSeed { mnemonic } => {
+        UtilsMethod::GenerateMnemonic { word_count, language } => Response::GeneratedMnemonic(
+            generate_mnemonic(
+                word_count.unwrap_or(DEFAULT_MNEMONIC_WORD_COUNT),
+                language.unwrap_or_default(),
+            )?
+            .to_string(),
+        ),
+        UtilsMethod::MnemonicToHexSeed {
+            mnemonic,
+            passphrase,
+            language,
+        } => {
             let mnemonic = Mnemonic::from(mnemonic);
-            Response::MnemonicHexSeed(Client::mnemonic_to_hex_seed(mnemonic)?)
+            let passphrase = passphrase.map(Passphrase::from);
+            let seed = mnemonic_to_seed(mnemonic, language.unwrap_or_default(), passphrase.as_ref())?;
+            Response::MnemonicHexSeed(prefix_hex::encode(seed.as_ref()))
         }
         UtilsMethod::BlockId { block } => {
             let block = Block::try_from_dto(block)?;
@@ -85,9 +110,9 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let out = Output::try_from_dto(output)?;
             Response::MinimumRequiredStorageDeposit(out.rent_cost(&rent).to_string())
         }
-        UtilsMethod::VerifyMnemonic { mnemonic } => {
+        UtilsMethod::VerifyMnemonic { mnemonic, language } => {
             let mnemonic = Mnemonic::from(mnemonic);
-            verify_mnemonic(mnemonic)?;
+            verify_mnemonic(mnemonic, language.unwrap_or_default())?;
             Response::Ok
         }
         UtilsMethod::VerifyEd25519Signature { signature, message } => {
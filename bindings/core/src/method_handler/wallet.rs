@@ -5,7 +5,10 @@ use std::time::Duration;
 
 use iota_sdk::{
     types::block::address::ToBech32Ext,
-    wallet::{account::AccountDetailsDto, Wallet},
+    wallet::{
+        account::{types::TransactionDto, AccountDetailsDto, PublicAccount},
+        Wallet,
+    },
 };
 
 use super::account::call_account_method_internal;
@@ -18,8 +21,15 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             alias,
             bech32_hrp,
             addresses,
+            coin_type,
+            bip44_account_index,
+            client_options,
         } => {
-            let mut builder = wallet.create_account();
+            let mut builder = wallet
+                .create_account()
+                .with_coin_type(coin_type)
+                .with_bip44_account_index(bip44_account_index)
+                .with_client_options(client_options.map(|options| *options));
 
             if let Some(alias) = alias {
                 builder = builder.with_alias(alias);
@@ -41,6 +51,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 Err(e) => return Err(e.into()),
             }
         }
+        WalletMethod::CreateWatchOnlyAccount { public_account, alias } => {
+            let account = wallet
+                .create_watch_only_account(PublicAccount::try_from(public_account)?, alias)
+                .await?;
+            let account = account.details().await;
+            Response::Account(AccountDetailsDto::from(&*account))
+        }
         WalletMethod::GetAccount { account_id } => {
             let account = wallet.get_account(account_id.clone()).await?;
             let account = account.details().await;
@@ -63,6 +80,15 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        WalletMethod::FilterAccounts { filter_options } => {
+            let accounts = wallet.filter_accounts(filter_options).await?;
+            let mut account_dtos = Vec::with_capacity(accounts.len());
+            for account in accounts {
+                let account = account.details().await;
+                account_dtos.push(AccountDetailsDto::from(&*account));
+            }
+            Response::Accounts(account_dtos)
+        }
         WalletMethod::GetChrysalisData => Response::ChrysalisData(wallet.get_chrysalis_data().await?),
         WalletMethod::CallAccountMethod { account_id, method } => {
             let account = wallet.get_account(account_id).await?;
@@ -109,6 +135,14 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        WalletMethod::RecoverPendingSubmissions => {
+            let transactions = wallet.recover_pending_submissions().await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
+        WalletMethod::RemoveAccount { account_id, options } => {
+            wallet.remove_account(account_id, options).await?;
+            Response::Ok
+        }
         WalletMethod::RemoveLatestAccount => {
             wallet.remove_latest_account().await?;
             Response::Ok
@@ -186,6 +220,41 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::SyncAll { options } => Response::Balance(wallet.sync_all(options).await?),
+        WalletMethod::StartBackgroundClaim {
+            policy,
+            interval_in_milliseconds,
+        } => {
+            let duration = interval_in_milliseconds.map(Duration::from_millis);
+            wallet.start_background_claiming(policy, duration).await?;
+            Response::Ok
+        }
+        WalletMethod::StopBackgroundClaim => {
+            wallet.stop_background_claiming().await?;
+            Response::Ok
+        }
+        WalletMethod::StartBackgroundRetry {
+            options,
+            interval_in_milliseconds,
+        } => {
+            let duration = interval_in_milliseconds.map(Duration::from_millis);
+            wallet.start_background_retrying(options, duration).await?;
+            Response::Ok
+        }
+        WalletMethod::StopBackgroundRetry => {
+            wallet.stop_background_retrying().await?;
+            Response::Ok
+        }
+        #[cfg(feature = "mqtt")]
+        WalletMethod::StartLedgerFollower => {
+            wallet.start_ledger_follower().await?;
+            Response::Ok
+        }
+        #[cfg(feature = "mqtt")]
+        WalletMethod::StopLedgerFollower => {
+            wallet.stop_ledger_follower().await?;
+            Response::Ok
+        }
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await;
@@ -196,6 +265,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.clear_listeners(event_types).await;
             Response::Ok
         }
+        #[cfg(all(feature = "events", feature = "storage"))]
+        WalletMethod::ReplayEvents { from_seq } => Response::Events(wallet.replay_events(from_seq).await?),
+        #[cfg(all(feature = "events", feature = "storage"))]
+        WalletMethod::PruneEvents { before_seq } => {
+            wallet.prune_events(before_seq).await?;
+            Response::Ok
+        }
         WalletMethod::UpdateNodeAuth { url, auth } => {
             wallet.update_node_auth(url, auth).await?;
             Response::Ok
@@ -8,38 +8,43 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(all(feature = "events", feature = "storage"))]
+use iota_sdk::wallet::events::types::Event;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{
+            AddressBalances, BroadcastResult, PreparedTransactionDataDto, SendAmountResult, SignedTransactionDataDto,
+            TreasuryMutation,
+        },
         node_manager::node::Node,
+        poi::OutputWithMetadataProof,
+        sign_in::SignInResponseDto,
         NetworkInfo, NodeInfoWrapper,
     },
     types::{
-        api::{
-            core::response::{
-                BlockMetadataResponse, InfoResponse as NodeInfo, OutputWithMetadataResponse, PeerResponse,
-                ReceiptResponse, TreasuryResponse, UtxoChangesResponse as MilestoneUTXOChanges,
-            },
-            plugins::indexer::OutputIdsResponse,
-        },
+        api::plugins::indexer::OutputIdsResponse,
         block::{
-            address::{dto::AddressDto, Bech32Address, Hrp},
-            input::dto::UtxoInputDto,
-            output::{dto::OutputDto, AliasId, FoundryId, NftId, OutputId, OutputMetadata, TokenId},
+            address::{Bech32Address, Hrp},
+            output::{AliasId, FoundryId, NftId, OutputId, OutputMetadata, TokenId},
             payload::{
-                dto::{MilestonePayloadDto, TransactionPayloadDto},
                 milestone::MilestoneId,
-                transaction::TransactionId,
+                transaction::{TransactionDescription, TransactionId},
             },
             protocol::ProtocolParameters,
-            signature::dto::Ed25519SignatureDto,
-            unlock::dto::UnlockDto,
-            BlockDto, BlockId,
+            BlockId,
+        },
+        dto::{
+            response::{
+                BlockMetadataResponse, InfoResponse as NodeInfo, OutputWithMetadataResponse, PeerResponse,
+                ReceiptResponse, TreasuryResponse, UtxoChangesResponse as MilestoneUTXOChanges,
+            },
+            AddressDto, BlockDto, Ed25519SignatureDto, MilestonePayloadDto, OutputDto, TransactionPayloadDto,
+            UnlockDto, UtxoInputDto,
         },
     },
     wallet::account::{
         types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
-        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+        AccountDetailsDto, OwnershipProofDto, PayoutReport, PreparedCreateNativeTokenTransactionDto, PublicAccountDto,
     },
 };
 use serde::Serialize;
@@ -91,6 +96,9 @@ pub enum Response {
     /// - [`SignTransaction`](crate::method::ClientMethod::SignTransaction)
     SignedTransaction(TransactionPayloadDto),
     /// Response for:
+    /// - [`DescribeTransaction`](crate::method::ClientMethod::DescribeTransaction)
+    TransactionDescription(TransactionDescription),
+    /// Response for:
     /// - [`SignatureUnlock`](crate::method::SecretManagerMethod::SignatureUnlock)
     SignatureUnlock(UnlockDto),
     /// Response for:
@@ -137,6 +145,9 @@ pub enum Response {
     /// - [`GetOutput`](crate::method::ClientMethod::GetOutput)
     OutputWithMetadataResponse(OutputWithMetadataResponse),
     /// Response for:
+    /// - [`GetOutputWithMetadataProof`](crate::method::ClientMethod::GetOutputWithMetadataProof)
+    OutputWithMetadataProof(OutputWithMetadataProof),
+    /// Response for:
     /// - [`GetOutputMetadata`](crate::method::ClientMethod::GetOutputMetadata)
     OutputMetadata(OutputMetadata),
     /// Response for:
@@ -159,6 +170,9 @@ pub enum Response {
     /// - [`GetTreasury`](crate::method::ClientMethod::GetTreasury)
     Treasury(TreasuryResponse),
     /// Response for:
+    /// - [`GetTreasuryMutations`](crate::method::ClientMethod::GetTreasuryMutations)
+    TreasuryMutations(Vec<TreasuryMutation>),
+    /// Response for:
     /// - [`AliasOutputId`](crate::method::ClientMethod::AliasOutputId)
     /// - [`FoundryOutputId`](crate::method::ClientMethod::FoundryOutputId)
     /// - [`NftOutputId`](crate::method::ClientMethod::NftOutputId)
@@ -180,6 +194,12 @@ pub enum Response {
     /// - [`ConsolidateFunds`](crate::method::ClientMethod::ConsolidateFunds)
     ConsolidatedFunds(Bech32Address),
     /// Response for:
+    /// - [`SendAmount`](crate::method::ClientMethod::SendAmount)
+    SentAmount(SendAmountResult),
+    /// Response for:
+    /// - [`GetAddressBalances`](crate::method::ClientMethod::GetAddressBalances)
+    AddressBalances(AddressBalances),
+    /// Response for:
     /// - [`FindInputs`](crate::method::ClientMethod::FindInputs)
     Inputs(Vec<UtxoInputDto>),
     /// Response for:
@@ -247,6 +267,8 @@ pub enum Response {
     /// - [`HexPublicKeyToBech32Address`](crate::method::ClientMethod::HexPublicKeyToBech32Address)
     /// - [`HexToBech32`](crate::method::ClientMethod::HexToBech32)
     /// - [`NftIdToBech32`](crate::method::ClientMethod::NftIdToBech32)
+    /// - [`ParseAddress`](crate::method::ClientMethod::ParseAddress)
+    /// - [`ParseAddress`](crate::method::UtilsMethod::ParseAddress)
     Bech32Address(Bech32Address),
     /// - [`Faucet`](crate::method::ClientMethod::RequestFundsFromFaucet)
     Faucet(String),
@@ -265,12 +287,16 @@ pub enum Response {
     /// - [`RetryTransactionUntilIncluded`](crate::method::AccountMethod::RetryTransactionUntilIncluded)
     BlockId(BlockId),
     /// Response for:
+    /// - [`BroadcastBlock`](crate::method::ClientMethod::BroadcastBlock)
+    BroadcastResult(BroadcastResult),
+    /// Response for:
     /// - [`GetLocalPow`](crate::method::ClientMethod::GetLocalPow)
     /// - [`GetFallbackToLocalPow`](crate::method::ClientMethod::GetFallbackToLocalPow)
     /// - [`GetHealth`](crate::method::ClientMethod::GetHealth)
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
     /// - [`VerifyEd25519Signature`](crate::method::UtilsMethod::VerifyEd25519Signature)
     /// - [`VerifySecp256k1EcdsaSignature`](crate::method::UtilsMethod::VerifySecp256k1EcdsaSignature)
+    /// - [`IsLocked`](crate::method::SecretManagerMethod::IsLocked)
     Bool(bool),
     /// Response for:
     /// - [`Backup`](crate::method::WalletMethod::Backup),
@@ -278,15 +304,28 @@ pub enum Response {
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
+    /// - [`Lock`](crate::method::SecretManagerMethod::Lock),
+    /// - [`PruneEvents`](crate::method::WalletMethod::PruneEvents),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
+    /// - [`RemoveMetadata`](crate::method::AccountMethod::RemoveMetadata),
+    /// - [`SetAddressFrozen`](crate::method::AccountMethod::SetAddressFrozen),
+    /// - [`SetAddressLabel`](crate::method::AccountMethod::SetAddressLabel),
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
+    /// - [`SetArchived`](crate::method::AccountMethod::SetArchived),
+    /// - [`SetAutoLock`](crate::method::SecretManagerMethod::SetAutoLock),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
+    /// - [`SetMetadata`](crate::method::AccountMethod::SetMetadata),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
+    /// - [`StartBackgroundClaim`](crate::method::WalletMethod::StartBackgroundClaim),
+    /// - [`StartBackgroundRetry`](crate::method::WalletMethod::StartBackgroundRetry),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic),
+    /// - [`StopBackgroundClaim`](crate::method::WalletMethod::StopBackgroundClaim),
+    /// - [`StopBackgroundRetry`](crate::method::WalletMethod::StopBackgroundRetry),
     /// - [`StopBackgroundSync`](crate::method::WalletMethod::StopBackgroundSync),
+    /// - [`Unlock`](crate::method::SecretManagerMethod::Unlock),
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
@@ -296,6 +335,7 @@ pub enum Response {
     // wallet responses
     /// Response for:
     /// - [`CreateAccount`](crate::method::WalletMethod::CreateAccount),
+    /// - [`CreateWatchOnlyAccount`](crate::method::WalletMethod::CreateWatchOnlyAccount),
     /// - [`GetAccount`](crate::method::WalletMethod::GetAccount)
     Account(AccountDetailsDto),
     /// Response for:
@@ -303,6 +343,7 @@ pub enum Response {
     AccountIndexes(Vec<u32>),
     /// Response for:
     /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
+    /// - [`FilterAccounts`](crate::method::WalletMethod::FilterAccounts)
     Accounts(Vec<AccountDetailsDto>),
     /// Response for:
     /// - [`Addresses`](crate::method::AccountMethod::Addresses)
@@ -328,6 +369,13 @@ pub enum Response {
     /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for:
+    /// - [`GenerateOwnershipProof`](crate::method::AccountMethod::GenerateOwnershipProof)
+    OwnershipProofs(Vec<OwnershipProofDto>),
+    /// Response for:
+    /// - [`SendBatch`](crate::method::AccountMethod::SendBatch)
+    PayoutReport(PayoutReport),
+    /// Response for:
+    /// - [`DryRunTransaction`](crate::method::AccountMethod::DryRunTransaction)
     /// - [`PrepareBurn`](crate::method::AccountMethod::PrepareBurn),
     /// - [`PrepareConsolidateOutputs`](crate::method::AccountMethod::PrepareConsolidateOutputs)
     /// - [`PrepareCreateAliasOutput`](crate::method::AccountMethod::PrepareCreateAliasOutput)
@@ -337,6 +385,7 @@ pub enum Response {
     /// - [`PrepareMintNativeToken`](crate::method::AccountMethod::PrepareMintNativeToken),
     /// - [`PrepareMintNfts`](crate::method::AccountMethod::PrepareMintNfts),
     /// - [`PrepareSend`](crate::method::AccountMethod::PrepareSend),
+    /// - [`PrepareSendAll`](crate::method::AccountMethod::PrepareSendAll),
     /// - [`PrepareSendNativeTokens`](crate::method::AccountMethod::PrepareSendNativeTokens),
     /// - [`PrepareSendNft`](crate::method::AccountMethod::PrepareSendNft),
     /// - [`PrepareStopParticipating`](crate::method::AccountMethod::PrepareStopParticipating)
@@ -347,33 +396,50 @@ pub enum Response {
     /// - [`PrepareCreateNativeToken`](crate::method::AccountMethod::PrepareCreateNativeToken),
     PreparedCreateNativeTokenTransaction(PreparedCreateNativeTokenTransactionDto),
     /// Response for:
+    /// - [`ExportPublicAccount`](crate::method::AccountMethod::ExportPublicAccount)
+    PublicAccount(PublicAccountDto),
+    /// Response for:
     /// - [`GetIncomingTransaction`](crate::method::AccountMethod::GetIncomingTransaction)
     /// - [`GetTransaction`](crate::method::AccountMethod::GetTransaction),
     Transaction(Option<Box<TransactionDto>>),
     /// Response for:
+    /// - [`AutoClaimOutputs`](crate::method::AccountMethod::AutoClaimOutputs),
+    /// - [`FilterTransactions`](crate::method::AccountMethod::FilterTransactions),
     /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions)
     /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
+    /// - [`RecoverPendingSubmissions`](crate::method::WalletMethod::RecoverPendingSubmissions),
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     Transactions(Vec<TransactionDto>),
     /// Response for:
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     SignedTransactionData(SignedTransactionDataDto),
+    /// Response for:
+    /// - [`SignIn`](crate::method::AccountMethod::SignIn)
+    SignInResponse(SignInResponseDto),
     /// GenerateAddress response.
     /// Response for:
     /// - [`GenerateEd25519Addresses`](crate::method::AccountMethod::GenerateEd25519Addresses)
     GeneratedAccountAddresses(Vec<AccountAddress>),
     /// Response for:
     /// - [`GetBalance`](crate::method::AccountMethod::GetBalance),
-    /// - [`Sync`](crate::method::AccountMethod::Sync)
+    /// - [`Sync`](crate::method::AccountMethod::Sync),
+    /// - [`SyncAll`](crate::method::WalletMethod::SyncAll)
     Balance(Balance),
     /// Response for:
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
+    /// - [`ReplaceTransaction`](crate::method::AccountMethod::ReplaceTransaction)
     /// - [`Send`](crate::method::AccountMethod::Send)
+    /// - [`SendAll`](crate::method::AccountMethod::SendAll)
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
     /// - [`SignAndSubmitTransaction`](crate::method::AccountMethod::SignAndSubmitTransaction)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     SentTransaction(TransactionDto),
     /// Response for:
+    /// - [`ReplayEvents`](crate::method::WalletMethod::ReplayEvents)
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    Events(Vec<Event>),
+    /// Response for:
     /// - [`GetParticipationEvent`](crate::method::AccountMethod::GetParticipationEvent)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -29,16 +29,22 @@ fn method_interface_secrets_debug() {
 
     let client_method = UtilsMethod::MnemonicToHexSeed {
         mnemonic: "mnemonic".to_string(),
+        passphrase: Some("passphrase".to_string()),
+        language: None,
     };
     assert_eq!(
         format!("{:?}", client_method),
-        "MnemonicToHexSeed { mnemonic: <omitted> }"
+        "MnemonicToHexSeed { mnemonic: <omitted>, passphrase: Some(<omitted>), language: None }"
     );
 
     let wallet_method = UtilsMethod::VerifyMnemonic {
         mnemonic: "mnemonic".to_string(),
+        language: None,
     };
-    assert_eq!(format!("{:?}", wallet_method), "VerifyMnemonic { mnemonic: <omitted> }");
+    assert_eq!(
+        format!("{:?}", wallet_method),
+        "VerifyMnemonic { mnemonic: <omitted>, language: None }"
+    );
 
     let response = Response::GeneratedMnemonic("mnemonic".to_string());
     assert_eq!(format!("{:?}", response), "GeneratedMnemonic(<omitted>)");
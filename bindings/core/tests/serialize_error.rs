@@ -9,7 +9,7 @@ fn custom_error_serialization() {
     let error = Error::Client(ClientError::HealthyNodePoolEmpty);
     assert_eq!(
         serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"client\",\"error\":\"no healthy node available\"}"
+        "{\"type\":\"client\",\"error\":\"no healthy node available\",\"code\":1002,\"category\":\"network\"}"
     );
     let error = Error::Wallet(WalletError::AccountNotFound("Alice".to_string()));
     assert_eq!(
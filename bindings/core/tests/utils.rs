@@ -6,7 +6,10 @@ use iota_sdk_bindings_core::{call_utils_method, Response, Result, UtilsMethod};
 
 #[tokio::test]
 async fn utils() -> Result<()> {
-    let response = call_utils_method(UtilsMethod::GenerateMnemonic);
+    let response = call_utils_method(UtilsMethod::GenerateMnemonic {
+        word_count: None,
+        language: None,
+    });
     match response {
         Response::GeneratedMnemonic(mnemonic) => println!("{:?}", serde_json::to_string(&mnemonic)?),
         _ => panic!("Unexpected response type"),
@@ -80,7 +80,10 @@ const listenWalletAsync = (
         eventTypes,
         function (err: any, data: string) {
             const parsed = JSON.parse(data);
-            callback(err, new Event(parsed.accountIndex, parsed.event));
+            callback(
+                err,
+                new Event(parsed.accountIndex, parsed.event, parsed.seq),
+            );
         },
         handler,
     );
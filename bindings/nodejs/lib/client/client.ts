@@ -8,6 +8,7 @@ import {
     IBuildBlockOptions,
     QueryParameter,
     PreparedTransactionData,
+    ITransactionDescription,
     INetworkInfo,
     INode,
     IAuth,
@@ -19,6 +20,10 @@ import {
     NftQueryParameter,
     AliasQueryParameter,
     GenericQueryParameter,
+    SendAmountOptions,
+    SendAmountResult,
+    AddressBalances,
+    BroadcastResult,
 } from '../types/client';
 import type { INodeInfoWrapper } from '../types/client/nodeInfo';
 import {
@@ -36,7 +41,6 @@ import {
     Payload,
     TransactionPayload,
     MilestonePayload,
-    TreasuryOutput,
     Output,
     MilestoneId,
     AliasId,
@@ -54,12 +58,16 @@ import {
     OutputId,
     TransactionId,
     Bech32Address,
+    ParseMode,
 } from '../types';
 import {
     IMilestoneUtxoChangesResponse,
     OutputResponse,
+    OutputProofResponse,
     ReceiptsResponse,
     IOutputsResponse,
+    ITreasuryResponse,
+    ITreasuryMutation,
 } from '../types/models/api';
 
 import { plainToInstance } from 'class-transformer';
@@ -148,6 +156,26 @@ export class Client {
         return plainToInstance(OutputResponse, parsed.payload);
     }
 
+    /**
+     * Get an output with its metadata, the block that created it, the milestone that booked it and, if a node runs
+     * the PoI plugin, the merkle audit path from the block to the milestone, to verify the output offline.
+     *
+     * @param outputId The ID of the output.
+     */
+    async getOutputWithMetadataProof(
+        outputId: OutputId,
+    ): Promise<OutputProofResponse> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getOutputWithMetadataProof',
+            data: {
+                outputId,
+            },
+        });
+
+        const parsed = JSON.parse(response) as Response<OutputProofResponse>;
+        return plainToInstance(OutputProofResponse, parsed.payload);
+    }
+
     /**
      * Fetch OutputResponse from given output IDs. Requests are sent in parallel.
      */
@@ -230,6 +258,23 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Post a block with finished PoW to all healthy nodes at once, so a single slow node can't delay its propagation.
+     *
+     * @param block The block to post.
+     * @returns The block ID and the response of each node.
+     */
+    async broadcastBlock(block: Block): Promise<BroadcastResult> {
+        const response = await this.methodHandler.callMethod({
+            name: 'broadcastBlock',
+            data: {
+                block,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get a block in JSON format.
      *
@@ -265,6 +310,24 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the confirmed balances of addresses with the number of outputs of
+     * each kind they own. The addresses don't have to belong to a wallet.
+     *
+     * @param addresses The Bech32 addresses.
+     * @returns The balances, in the order of the addresses.
+     */
+    async getAddressBalances(addresses: string[]): Promise<AddressBalances> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAddressBalances',
+            data: {
+                addresses,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Find inputs from addresses for a given amount (useful for offline signing).
      *
@@ -336,6 +399,25 @@ export class Client {
         return plainToInstance(TransactionPayload, parsed.payload);
     }
 
+    /**
+     * Describe a prepared transaction in a human-readable form, to confirm it before signing.
+     *
+     * @param preparedTransactionData An instance of `PreparedTransactionData`.
+     * @returns The inputs, outputs and payload of the transaction, with the remainder marked as change.
+     */
+    async describeTransaction(
+        preparedTransactionData: PreparedTransactionData,
+    ): Promise<ITransactionDescription> {
+        const response = await this.methodHandler.callMethod({
+            name: 'describeTransaction',
+            data: {
+                preparedTransactionData,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Create a signature unlock using the given secret manager.
      *
@@ -655,14 +737,35 @@ export class Client {
     }
 
     /**
-     * Get the treasury output.
+     * Get the ID of the milestone that created the treasury output and its amount.
      */
-    async getTreasury(): Promise<TreasuryOutput> {
+    async getTreasury(): Promise<ITreasuryResponse> {
         const response = await this.methodHandler.callMethod({
             name: 'getTreasury',
         });
 
-        return Output.parse(JSON.parse(response).payload) as TreasuryOutput;
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List the mutations of the treasury by the receipts of the milestones in the range, ordered by milestone index.
+     *
+     * @param startMilestoneIndex The index of the first milestone of the range.
+     * @param endMilestoneIndex The index of the last milestone of the range.
+     */
+    async getTreasuryMutations(
+        startMilestoneIndex: number,
+        endMilestoneIndex: number,
+    ): Promise<ITreasuryMutation[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getTreasuryMutations',
+            data: {
+                startMilestoneIndex,
+                endMilestoneIndex,
+            },
+        });
+
+        return JSON.parse(response).payload;
     }
 
     /**
@@ -789,6 +892,28 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Parse a user-provided Bech32 address and check that it belongs to the network.
+     *
+     * @param address A Bech32 address, or in lenient mode also a hex encoded Ed25519 address.
+     * @param mode How strictly the address is parsed, lenient by default.
+     * @returns The Bech32 address.
+     */
+    async parseAddress(
+        address: string,
+        mode?: ParseMode,
+    ): Promise<Bech32Address> {
+        const response = await this.methodHandler.callMethod({
+            name: 'parseAddress',
+            data: {
+                address,
+                mode,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the corresponding output IDs given a list of Alias query parameters.
      *
@@ -1007,6 +1132,35 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send an amount to an address in a basic output. The inputs are selected from the addresses of the secret
+     * manager, a remainder output is created if needed and the block is retried until it's included.
+     *
+     * @param secretManager One of supported secret managers.
+     * @param address The Bech32 address to send the amount to.
+     * @param amount The amount to send.
+     * @param options Options for the input selection and the inclusion checks.
+     * @returns The IDs of the transaction, the blocks and the created outputs.
+     */
+    async sendAmount(
+        secretManager: SecretManagerType,
+        address: string,
+        amount: bigint,
+        options?: SendAmountOptions,
+    ): Promise<SendAmountResult> {
+        const response = await this.methodHandler.callMethod({
+            name: 'sendAmount',
+            data: {
+                secretManager,
+                address,
+                amount: Number(amount),
+                options,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Reattach a block.
      *
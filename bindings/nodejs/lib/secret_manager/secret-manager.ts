@@ -181,4 +181,53 @@ export class SecretManager {
 
         return JSON.parse(response).payload;
     }
+
+    /**
+     * Zeroize the secrets of the secret manager, until it's unlocked again it can't be used for
+     * address generation or signing.
+     */
+    async lock(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'lock',
+        });
+    }
+
+    /**
+     * Unlock the secret manager with the secret it was created with.
+     *
+     * @param secret The Stronghold password, the mnemonic, the hex seed or the hex private key.
+     */
+    async unlock(secret: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'unlock',
+            data: {
+                secret,
+            },
+        });
+    }
+
+    /**
+     * Check whether the secret manager is locked.
+     */
+    async isLocked(): Promise<boolean> {
+        const response = await this.methodHandler.callMethod({
+            name: 'isLocked',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set the time after which the secret manager locks itself once it was unlocked.
+     *
+     * @param intervalInMilliseconds The auto-lock interval, `undefined` disables it.
+     */
+    async setAutoLock(intervalInMilliseconds?: number): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setAutoLock',
+            data: {
+                intervalInMilliseconds,
+            },
+        });
+    }
 }
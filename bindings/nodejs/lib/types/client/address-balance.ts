@@ -0,0 +1,29 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { INativeToken } from '../models';
+import type { NumericString } from '../utils';
+
+/** The confirmed balance of an address. */
+export interface AddressBalance {
+    /** The Bech32 address. */
+    address: string;
+    /** The base coin amount of the outputs owned by the address. */
+    baseCoin: NumericString;
+    /** The native tokens of the outputs owned by the address. */
+    nativeTokens: INativeToken[];
+    /** The number of basic outputs with the address in their address unlock condition. */
+    basicOutputs: number;
+    /** The number of alias outputs with the address as state controller. */
+    aliasOutputs: number;
+    /** The number of NFT outputs with the address in their address unlock condition. */
+    nftOutputs: number;
+}
+
+/** The result of `Client.getAddressBalances()`. */
+export interface AddressBalances {
+    /** The ledger index at which the output IDs were queried. */
+    ledgerIndex: number;
+    /** The balances, in the order of the requested addresses. */
+    balances: AddressBalance[];
+}
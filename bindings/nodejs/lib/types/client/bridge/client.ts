@@ -27,7 +27,9 @@ import type {
     QueryParameter,
 } from '../query-parameters';
 import type { IAuth } from '../network';
+import type { SendAmountOptions } from '../send-amount';
 import type { BasicOutputBuilderParams } from '../output_builder_params/basic-output-params';
+import type { ParseMode } from '../../utils/parse-mode';
 import type { AliasOutputBuilderParams } from '../output_builder_params/alias-output-params';
 import type { FoundryOutputBuilderParams } from '../output_builder_params/foundry-output-params';
 import type { NftOutputBuilderParams } from '../output_builder_params/nft-output-params';
@@ -45,6 +47,13 @@ export interface __GetOutputMethod__ {
     };
 }
 
+export interface __GetOutputWithMetadataProofMethod__ {
+    name: 'getOutputWithMetadataProof';
+    data: {
+        outputId: OutputId;
+    };
+}
+
 export interface __GetOutputIdsMethod__ {
     name: 'outputIds';
     data: {
@@ -73,6 +82,13 @@ export interface __PostBlockMethod__ {
     };
 }
 
+export interface __BroadcastBlockMethod__ {
+    name: 'broadcastBlock';
+    data: {
+        block: Block;
+    };
+}
+
 export interface __BuildAndPostBlockMethod__ {
     name: 'buildAndPostBlock';
     data: {
@@ -103,6 +119,23 @@ export interface __GetBlockMetadataMethod__ {
     };
 }
 
+export interface __SendAmountMethod__ {
+    name: 'sendAmount';
+    data: {
+        secretManager: SecretManagerType;
+        address: string;
+        amount: number;
+        options?: SendAmountOptions;
+    };
+}
+
+export interface __GetAddressBalancesMethod__ {
+    name: 'getAddressBalances';
+    data: {
+        addresses: string[];
+    };
+}
+
 export interface __FindInputsMethod__ {
     name: 'findInputs';
     data: {
@@ -127,6 +160,13 @@ export interface __SignTransactionMethod__ {
     };
 }
 
+export interface __DescribeTransactionMethod__ {
+    name: 'describeTransaction';
+    data: {
+        preparedTransactionData: PreparedTransactionData;
+    };
+}
+
 export interface __SignatureUnlockMethod__ {
     name: 'signatureUnlock';
     data: {
@@ -250,6 +290,14 @@ export interface __GetTreasuryMethod__ {
     name: 'getTreasury';
 }
 
+export interface __GetTreasuryMutationsMethod__ {
+    name: 'getTreasuryMutations';
+    data: {
+        startMilestoneIndex: number;
+        endMilestoneIndex: number;
+    };
+}
+
 export interface __GetIncludedBlockMethod__ {
     name: 'getIncludedBlock';
     data: {
@@ -296,6 +344,14 @@ export interface __HexPublicKeyToBech32AddressMethod__ {
     };
 }
 
+export interface __ParseAddressMethod__ {
+    name: 'parseAddress';
+    data: {
+        address: string;
+        mode?: ParseMode;
+    };
+}
+
 export interface __AliasOutputIdsMethod__ {
     name: 'aliasOutputIds';
     data: {
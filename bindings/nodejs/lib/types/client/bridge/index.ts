@@ -6,16 +6,20 @@ import type {
     __GetOutputIdsMethod__,
     __GetBasicOutputIdsMethod__,
     __GetOutputMethod__,
+    __GetOutputWithMetadataProofMethod__,
     __GetOutputsMethod__,
     __PostBlockMethod__,
+    __BroadcastBlockMethod__,
     __BuildAndPostBlockMethod__,
     __GetTipsMethod__,
     __GetNetworkInfoMethod__,
     __GetBlockMethod__,
     __GetBlockMetadataMethod__,
+    __GetAddressBalancesMethod__,
     __FindInputsMethod__,
     __PrepareTransactionMethod__,
     __SignTransactionMethod__,
+    __DescribeTransactionMethod__,
     __PostBlockPayloadMethod__,
     __GetNodeMethod__,
     __GetNetworkIdMethod__,
@@ -33,12 +37,14 @@ import type {
     __GetReceiptsMethod__,
     __GetReceiptsMigratedAtMethod__,
     __GetTreasuryMethod__,
+    __GetTreasuryMutationsMethod__,
     __GetIncludedBlockMethod__,
     __GetIncludedBlockMetadataMethod__,
     __HexToBech32Method__,
     __AliasIdToBech32Method__,
     __NftIdToBech32Method__,
     __HexPublicKeyToBech32AddressMethod__,
+    __ParseAddressMethod__,
     __AliasOutputIdsMethod__,
     __AliasOutputIdMethod__,
     __NftOutputIdsMethod__,
@@ -50,6 +56,7 @@ import type {
     __RetryMethod__,
     __RetryUntilIncludedMethod__,
     __ConsolidateFundsMethod__,
+    __SendAmountMethod__,
     __ReattachMethod__,
     __ReattachUncheckedMethod__,
     __PromoteMethod__,
@@ -73,18 +80,22 @@ import type {
 export type __ClientMethods__ =
     | __GetInfoMethod__
     | __GetOutputMethod__
+    | __GetOutputWithMetadataProofMethod__
     | __GetOutputIdsMethod__
     | __GetBasicOutputIdsMethod__
     | __GetOutputsMethod__
     | __PostBlockMethod__
+    | __BroadcastBlockMethod__
     | __BuildAndPostBlockMethod__
     | __GetTipsMethod__
     | __GetNetworkInfoMethod__
     | __GetBlockMethod__
     | __GetBlockMetadataMethod__
+    | __GetAddressBalancesMethod__
     | __FindInputsMethod__
     | __PrepareTransactionMethod__
     | __SignTransactionMethod__
+    | __DescribeTransactionMethod__
     | __SignatureUnlockMethod__
     | __PostBlockPayloadMethod__
     | __GetNodeMethod__
@@ -107,12 +118,14 @@ export type __ClientMethods__ =
     | __GetReceiptsMethod__
     | __GetReceiptsMigratedAtMethod__
     | __GetTreasuryMethod__
+    | __GetTreasuryMutationsMethod__
     | __GetIncludedBlockMethod__
     | __GetIncludedBlockMetadataMethod__
     | __HexToBech32Method__
     | __AliasIdToBech32Method__
     | __NftIdToBech32Method__
     | __HexPublicKeyToBech32AddressMethod__
+    | __ParseAddressMethod__
     | __AliasOutputIdsMethod__
     | __AliasOutputIdMethod__
     | __NftOutputIdsMethod__
@@ -124,6 +137,7 @@ export type __ClientMethods__ =
     | __RetryMethod__
     | __RetryUntilIncludedMethod__
     | __ConsolidateFundsMethod__
+    | __SendAmountMethod__
     | __ReattachMethod__
     | __ReattachUncheckedMethod__
     | __PromoteMethod__
@@ -0,0 +1,20 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { BlockId } from '../block';
+
+/** The response of a single node to a block posted with `Client.broadcastBlock()`. */
+export interface NodeBroadcastResult {
+    /** The origin of the node URL, without credentials. */
+    url: string;
+    /** Why the node didn't accept the block, not set if it did. */
+    error?: string;
+}
+
+/** The result of `Client.broadcastBlock()`. */
+export interface BroadcastResult {
+    /** The ID of the block. */
+    blockId: BlockId;
+    /** The response of each node the block was posted to. */
+    nodes: NodeBroadcastResult[];
+}
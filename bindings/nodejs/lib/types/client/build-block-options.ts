@@ -35,10 +35,23 @@ export interface IBuildBlockOptions {
     data?: string;
     /** Some parent block IDs. */
     parents?: string[];
+    /** The strategy to select the parents if none are set. */
+    tipSelection?: TipSelectionStrategy;
+    /** The URL of the node to get the tips from if no parents are set. */
+    tipsFrom?: string;
     /** Parameters for explicit burning of aliases, nfts, foundries and native tokens. */
     burn?: Burn;
 }
 
+/** Strategy to select the parents of a block. */
+export type TipSelectionStrategy =
+    /** Use the tips of the node. */
+    | { type: 'default' }
+    /** Leave out the tips the node reports as semi-lazy. */
+    | { type: 'semiLazyAvoidance' }
+    /** Reference the previous block of the issuer in addition to the tips of the node. */
+    | { type: 'ownChain'; previousBlockId: string };
+
 /** Address with base coin amount. */
 export interface IClientBlockBuilderOutputAddress {
     /** An address. */
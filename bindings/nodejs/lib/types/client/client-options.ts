@@ -1,6 +1,12 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
-import type { IMqttBrokerOptions, INetworkInfo, INode } from './network';
+import type {
+    IMqttBrokerOptions,
+    INetworkInfo,
+    INode,
+    IPowServerOptions,
+} from './network';
+import type { INodeInfoProtocol } from '../models/info';
 
 /** Options for the client builder */
 export interface IClientOptions {
@@ -14,6 +20,8 @@ export interface IClientOptions {
     permanodes?: Array<string | INode>;
     /** If the node health status should be ignored */
     ignoreNodeHealth?: boolean;
+    /** If no node should be contacted, requires `protocolParameters` and `fixedProtocolParameters` */
+    offline?: boolean;
     /** Interval in which nodes will be checked for their sync status and the NetworkInfo gets updated */
     nodeSyncInterval?: IDuration;
     /** If node quorum is enabled. Will compare the responses from multiple nodes and only returns the
@@ -24,6 +32,12 @@ export interface IClientOptions {
     minQuorumSize?: number;
     /** % of nodes that have to return the same response so it gets accepted */
     quorumThreshold?: number;
+    /** If blocks with finished PoW are posted to all healthy nodes at once instead of one node after another */
+    broadcast?: boolean;
+    /** Protocol parameters of the network */
+    protocolParameters?: INodeInfoProtocol;
+    /** Whether the protocol parameters are used as they are, instead of being updated from the nodes */
+    fixedProtocolParameters?: boolean;
     /** Data related to the used network */
     networkInfo?: INetworkInfo;
     /** Options for the MQTT broker */
@@ -32,14 +46,38 @@ export interface IClientOptions {
     apiTimeout?: IDuration;
     /** Timeout when sending a block that requires remote proof of work */
     remotePowTimeout?: IDuration;
+    /** How failed requests are retried */
+    retryPolicy?: IRetryPolicy;
+    /** PoW server the PoW is delegated to */
+    powServer?: IPowServerOptions;
     /** The amount of threads to be used for proof of work */
     powWorkerCount?: number;
+    /** The niceness of the threads used for proof of work, from -20 (highest priority) to 19 (lowest priority), only applied on Linux */
+    powWorkerNiceness?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
     /** The maximum parallel API requests. */
     maxParallelApiRequests?: number;
 }
 
+/** Decides if and when a failed request is attempted again */
+export interface IRetryPolicy {
+    /** The maximum number of attempts, including the first one. 1 disables retrying. */
+    maxAttempts?: number;
+    /** The delay before the first retry */
+    initialDelay?: IDuration;
+    /** The upper bound of the delay between two attempts */
+    maxDelay?: IDuration;
+    /** How the delay grows with every retry */
+    backoff?: 'constant' | 'linear' | 'exponential';
+    /** The categories of errors that are retried */
+    retryable?: Array<
+        'network' | 'protocol' | 'signing' | 'storage' | 'validation'
+    >;
+    /** The time after the first attempt after which no further attempt is started */
+    deadline?: IDuration;
+}
+
 /** Time duration */
 export interface IDuration {
     /** Seconds. */
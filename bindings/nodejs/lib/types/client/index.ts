@@ -1,6 +1,7 @@
 export * from './output_builder_params';
 
 export * from './bridge';
+export * from './broadcast';
 export * from './build-block-options';
 export * from './burn';
 export * from './client-options';
@@ -12,3 +13,6 @@ export * from './nodeInfo';
 export * from './prepared-transaction-data';
 export * from './query-parameters';
 export * from './range';
+export * from './send-amount';
+export * from './address-balance';
+export * from './transaction-description';
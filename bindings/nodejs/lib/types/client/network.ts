@@ -21,6 +21,10 @@ export interface IAuth {
     jwt?: string;
     /** Basic authentication parameters. */
     basicAuthNamePwd?: [string, string];
+    /** Additional headers sent with every request. */
+    headers?: { [name: string]: string };
+    /** Path of a PEM file with the client certificate and private key for mutual TLS. */
+    clientCertificate?: string;
 }
 
 /**
@@ -39,6 +43,18 @@ export interface IMqttBrokerOptions {
     maxReconnectionAttempts?: number;
 }
 
+/**
+ * A PoW server the PoW is delegated to, instead of doing it locally or by the node.
+ */
+export interface IPowServerOptions {
+    /** The URL of the PoW endpoint. */
+    url: string;
+    /** The authentication parameters, the client certificate isn't used. */
+    auth?: IAuth;
+    /** The maximum number of PoW jobs sent to the server at the same time. */
+    maxConcurrentJobs?: number;
+}
+
 /**
  * A node object for the client.
  */
@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { BlockId, OutputId } from '../block';
+import type { TransactionId } from '../wallet/event';
+import type { IRange } from './range';
+
+/** Options for `Client.sendAmount()`. */
+export interface SendAmountOptions {
+    /** The coin type of the addresses the inputs are selected from, Shimmer by default. */
+    coinType?: number;
+    /** The account index of the addresses the inputs are selected from, 0 by default. */
+    accountIndex?: number;
+    /** The address indexes of the addresses the inputs are selected from, `0..100` by default. */
+    inputRange?: IRange;
+    /** The address the remainder is sent to, by default the first address of the input range. */
+    remainderAddress?: string;
+    /** The interval in seconds between the checks if the block was included. */
+    retryInterval?: number;
+    /** The maximum number of checks if the block was included. */
+    maxRetryAttempts?: number;
+}
+
+/** The result of `Client.sendAmount()`. */
+export interface SendAmountResult {
+    /** The ID of the transaction. */
+    transactionId: TransactionId;
+    /** The ID of the block the transaction was posted with. */
+    blockId: BlockId;
+    /** The ID of the block that got included, the posted block or one of its reattachments. */
+    includedBlockId: BlockId;
+    /** The IDs of the blocks the transaction was reattached with. */
+    reattachedBlockIds: BlockId[];
+    /** The IDs of the spent outputs. */
+    inputs: OutputId[];
+    /** The ID of the output that holds the sent amount. */
+    outputId: OutputId;
+    /** The ID of the output that holds the remainder, if the inputs had more funds than the amount. */
+    remainderOutputId?: OutputId;
+}
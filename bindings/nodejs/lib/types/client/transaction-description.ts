@@ -0,0 +1,75 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { Bech32Address } from '../block/address';
+import type { OutputId } from '../block/output/output';
+import type { INativeToken } from '../models/native-token';
+import type { HexEncodedString } from '../utils/hex-encoding';
+
+/**
+ * A human-readable breakdown of a transaction, to be displayed before signing it.
+ */
+export interface ITransactionDescription {
+    /** Whether the transaction is for the network the client is connected to. */
+    isNetworkMatching: boolean;
+    /** The IDs of the consumed outputs. */
+    inputs: OutputId[];
+    /** The consumed outputs. */
+    consumedOutputs: IOutputDescription[];
+    /** The created outputs. */
+    outputs: IOutputDescription[];
+    /** The tag of the tagged data payload. */
+    tag?: IDescribedData;
+    /** The data of the tagged data payload. */
+    data?: IDescribedData;
+}
+
+/**
+ * A human-readable breakdown of an output.
+ */
+export interface IOutputDescription {
+    /** The kind of the output, e.g. "Basic". */
+    kind: string;
+    /** Whether the output returns the remaining funds to the sender. */
+    isChange: boolean;
+    /** The address that can unlock the output. */
+    recipient?: Bech32Address;
+    /** The governor of an alias output. */
+    governor?: Bech32Address;
+    /** The amount of base tokens. */
+    amount: string;
+    /** The native tokens. */
+    nativeTokens: INativeToken[];
+    /** The alias, foundry or NFT ID, unset if the output creates the alias or NFT. */
+    chainId?: HexEncodedString;
+    /** The amount that must be returned when the output is claimed. */
+    storageDepositReturn?: {
+        returnAddress: Bech32Address;
+        amount: string;
+    };
+    /** The unix timestamp until which the output can't be unlocked. */
+    timelock?: number;
+    /** The unix timestamp from which on the output can only be unlocked by the return address. */
+    expiration?: {
+        returnAddress: Bech32Address;
+        timestamp: number;
+    };
+    /** The sender feature. */
+    sender?: Bech32Address;
+    /** The issuer feature. */
+    issuer?: Bech32Address;
+    /** The metadata feature. */
+    metadata?: IDescribedData;
+    /** The tag feature. */
+    tag?: IDescribedData;
+}
+
+/**
+ * Bytes as hex and as text if they're valid UTF-8.
+ */
+export interface IDescribedData {
+    /** The hex encoded bytes. */
+    hex: HexEncodedString;
+    /** The bytes as text. */
+    utf8?: string;
+}
@@ -6,7 +6,9 @@ export * from './plugins';
 export * from './block-id-response';
 export * from './milestone-utxo-changes-response';
 export * from './output-metadata-response';
+export * from './output-proof-response';
 export * from './output-response';
 export * from './receipts-response';
 export * from './response';
 export * from './tips-response';
+export * from './treasury-response';
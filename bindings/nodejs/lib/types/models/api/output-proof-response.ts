@@ -0,0 +1,55 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { HexEncodedString } from '../../utils/hex-encoding';
+import type { BlockId } from '../../block/id';
+import { Block } from '../../block/block';
+import { MilestonePayload } from '../../block/payload/milestone';
+import { OutputResponse } from './output-response';
+
+import { Type } from 'class-transformer';
+
+/**
+ * A merkle audit path, in the JSON format of the PoI plugin.
+ */
+export type MerkleProof =
+    | {
+          /** The left subtree. */
+          l: MerkleProof;
+          /** The right subtree. */
+          r: MerkleProof;
+      }
+    | {
+          /** The hash of a subtree that isn't on the path. */
+          h: HexEncodedString;
+      }
+    | {
+          /** The ID of the proven block. */
+          value: BlockId;
+      };
+
+/**
+ * An output with its metadata and what's needed to verify its existence without trusting the node.
+ */
+export class OutputProofResponse {
+    /**
+     * The output and its metadata.
+     */
+    @Type(() => OutputResponse)
+    output!: OutputResponse;
+    /**
+     * The block of the transaction that created the output.
+     */
+    @Type(() => Block)
+    block!: Block;
+    /**
+     * The milestone that booked the output.
+     */
+    @Type(() => MilestonePayload)
+    milestone!: MilestonePayload;
+    /**
+     * The merkle audit path from the block to the inclusion merkle root of the
+     * milestone, if a node runs the PoI plugin.
+     */
+    proof?: MerkleProof;
+}
@@ -0,0 +1,48 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { HexEncodedString } from '../../utils/hex-encoding';
+
+/**
+ * Response from the treasury endpoint.
+ */
+export interface ITreasuryResponse {
+    /**
+     * The ID of the milestone that created the treasury output.
+     */
+    milestoneId: HexEncodedString;
+    /**
+     * The amount of the treasury.
+     */
+    amount: string;
+}
+
+/**
+ * A change of the treasury by the receipt of a milestone.
+ */
+export interface ITreasuryMutation {
+    /**
+     * The index of the milestone that contained the receipt.
+     */
+    milestoneIndex: number;
+    /**
+     * The index of the legacy milestone at which the funds were migrated.
+     */
+    migratedAt: number;
+    /**
+     * Whether the receipt is the last one for the legacy milestone.
+     */
+    last: boolean;
+    /**
+     * The ID of the milestone that created the consumed treasury output.
+     */
+    consumedMilestoneId: HexEncodedString;
+    /**
+     * The amount that was migrated out of the treasury.
+     */
+    migratedAmount: string;
+    /**
+     * The amount left in the treasury.
+     */
+    remainingAmount: string;
+}
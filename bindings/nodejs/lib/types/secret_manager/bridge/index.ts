@@ -7,6 +7,10 @@ import type {
     __SignatureUnlockMethod__,
     __SignEd25519Method__,
     __SignSecp256k1EcdsaMethod__,
+    __LockMethod__,
+    __UnlockMethod__,
+    __IsLockedMethod__,
+    __SetAutoLockMethod__,
 } from './secret-manager';
 
 export type __SecretManagerMethods__ =
@@ -17,4 +21,8 @@ export type __SecretManagerMethods__ =
     | __SignatureUnlockMethod__
     | __StoreMnemonicMethod__
     | __SignEd25519Method__
-    | __SignSecp256k1EcdsaMethod__;
+    | __SignSecp256k1EcdsaMethod__
+    | __LockMethod__
+    | __UnlockMethod__
+    | __IsLockedMethod__
+    | __SetAutoLockMethod__;
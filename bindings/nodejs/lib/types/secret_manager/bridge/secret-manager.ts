@@ -61,3 +61,25 @@ export interface __SignSecp256k1EcdsaMethod__ {
 export interface __GetLedgerNanoStatusMethod__ {
     name: 'getLedgerNanoStatus';
 }
+
+export interface __LockMethod__ {
+    name: 'lock';
+}
+
+export interface __UnlockMethod__ {
+    name: 'unlock';
+    data: {
+        secret: string;
+    };
+}
+
+export interface __IsLockedMethod__ {
+    name: 'isLocked';
+}
+
+export interface __SetAutoLockMethod__ {
+    name: 'setAutoLock';
+    data: {
+        intervalInMilliseconds?: number;
+    };
+}
@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import { HexEncodedString } from '../utils';
+import { HexEncodedString, MnemonicLanguage } from '../utils';
 
 /** Secret manager that uses a Ledger Nano hardware wallet or Speculos simulator. */
 export interface LedgerNanoSecretManager {
@@ -15,6 +15,18 @@ export interface MnemonicSecretManager {
     mnemonic: string;
 }
 
+/** Secret manager that uses a mnemonic with a passphrase, also known as the 25th word. */
+export interface MnemonicWithPassphraseSecretManager {
+    mnemonicWithPassphrase: {
+        /** The underlying mnemonic. */
+        mnemonic: string;
+        /** The passphrase. */
+        passphrase: string;
+        /** The language of the mnemonic, English by default. */
+        language?: MnemonicLanguage;
+    };
+}
+
 /** Secret manager that uses a seed. */
 export interface SeedSecretManager {
     /** The underlying seed. */
@@ -42,6 +54,7 @@ export interface PrivateKeySecretManager {
 export type SecretManagerType =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
+    | MnemonicWithPassphraseSecretManager
     | SeedSecretManager
     | StrongholdSecretManager
     | PrivateKeySecretManager
@@ -9,6 +9,7 @@ import type {
     __ComputeInputsCommitmentMethod__,
     __ComputeStorageDepositMethod__,
     __ParseBech32AddressMethod__,
+    __ParseAddressMethod__,
     __BlockIdMethod__,
     __MilestoneIdMethod__,
     __TransactionIdMethod__,
@@ -38,6 +39,7 @@ export type __UtilsMethods__ =
     | __ComputeInputsCommitmentMethod__
     | __ComputeStorageDepositMethod__
     | __ParseBech32AddressMethod__
+    | __ParseAddressMethod__
     | __BlockIdMethod__
     | __MilestoneIdMethod__
     | __TransactionIdMethod__
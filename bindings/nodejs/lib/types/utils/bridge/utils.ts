@@ -14,15 +14,23 @@ import {
     Bech32Address,
 } from '../../';
 import { AliasId } from '../../block/id';
+import { MnemonicLanguage } from '../mnemonic-language';
+import { ParseMode } from '../parse-mode';
 
 export interface __GenerateMnemonicMethod__ {
     name: 'generateMnemonic';
+    data: {
+        wordCount?: number;
+        language?: MnemonicLanguage;
+    };
 }
 
 export interface __MnemonicToHexSeedMethod__ {
     name: 'mnemonicToHexSeed';
     data: {
         mnemonic: string;
+        passphrase?: string;
+        language?: MnemonicLanguage;
     };
 }
 
@@ -88,6 +96,15 @@ export interface __ParseBech32AddressMethod__ {
     };
 }
 
+export interface __ParseAddressMethod__ {
+    name: 'parseAddress';
+    data: {
+        address: string;
+        bech32Hrp?: string;
+        mode?: ParseMode;
+    };
+}
+
 export interface __BlockIdMethod__ {
     name: 'blockId';
     data: {
@@ -181,7 +198,7 @@ export interface __VerifySecp256k1EcdsaSignatureMethod__ {
 
 export type __VerifyMnemonicMethod__ = {
     name: 'verifyMnemonic';
-    data: { mnemonic: string };
+    data: { mnemonic: string; language?: MnemonicLanguage };
 };
 
 export type __FaucetMethod__ = {
@@ -3,4 +3,6 @@
 
 export * from './bridge';
 export * from './hex-encoding';
+export * from './mnemonic-language';
 export * from './numeric';
+export * from './parse-mode';
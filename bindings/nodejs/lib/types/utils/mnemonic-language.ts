@@ -0,0 +1,7 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/**
+ * The language of a BIP39 wordlist. Japanese words are separated by ideographic spaces.
+ */
+export declare type MnemonicLanguage = 'english' | 'japanese';
@@ -0,0 +1,9 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/**
+ * How strictly user-provided addresses and IDs are parsed.
+ * `lenient` accepts hex without the `0x` prefix, uppercase hex and bech32, surrounding whitespace and hex Ed25519
+ * addresses. `strict` only accepts `0x`-prefixed lowercase hex and lowercase bech32 addresses.
+ */
+export declare type ParseMode = 'lenient' | 'strict';
@@ -4,8 +4,8 @@
 import type { AccountAddress, AddressWithUnspentOutputs } from './address';
 import { AliasId, FoundryId, NftId } from '../block/id';
 import type { OutputData } from './output';
-import type { Transaction } from './transaction';
-import { CoinType } from '../../client';
+import type { InclusionState, Transaction } from './transaction';
+import { CoinType, IClientOptions } from '../../client';
 import { HexEncodedString } from '../utils';
 import { Bech32Address } from '../block/address';
 
@@ -34,6 +34,10 @@ export interface Balance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /** Amounts of outputs that can only be unlocked in a certain time frame, they're not part of the total */
+    timeBoundAmounts: TimeBoundAmount[];
+    /** Outputs of frozen addresses, they're part of the total, but not available */
+    frozenOutputs: string[];
 }
 
 /** The balance of the base coin */
@@ -42,10 +46,30 @@ export interface BaseCoinBalance {
     total: bigint;
     /** The amount of the outputs that aren't used in a transaction */
     available: bigint;
+    /** The amount that can only be unlocked at a later time, because of a timelock or an expiration */
+    timelocked: bigint;
+    /** The claimable amount that will expire back to the sender within a day */
+    expiringSoon: bigint;
+    /** The claimable amount that needs to be returned because of a storage deposit return unlock condition */
+    storageDepositReturn: bigint;
+    /** The amount of outputs of frozen addresses */
+    frozen: bigint;
     /** Voting power */
     votingPower: string;
 }
 
+/** The base coin amount of an output that can only be unlocked in a certain time frame */
+export interface TimeBoundAmount {
+    /** The output id */
+    outputId: string;
+    /** The amount without a storage deposit that needs to be returned */
+    amount: bigint;
+    /** The milestone timestamp from which on the output can be unlocked */
+    unlockableFrom?: number;
+    /** The milestone timestamp from which on the output can't be unlocked anymore */
+    unlockableUntil?: number;
+}
+
 /** The required storage deposit per output type */
 export interface RequiredStorageDeposit {
     /** The required amount for Alias outputs. */
@@ -93,11 +117,21 @@ export interface SyncOptions {
      * When this to true, we will sync anyways, even if it's called 0ms after the las sync finished. Default: false.
      */
     forceSyncing?: boolean;
+    /**
+     * Ignore the sync checkpoint and query all outputs of the addresses again, instead of only the ones created since
+     * the last sync. The checkpoint is replaced afterwards. Default: false.
+     */
+    forceFullSync?: boolean;
     /// Try to sync transactions from incoming outputs with their inputs. Some data may not be obtained if it has been
     /// pruned.
     syncIncomingTransactions?: boolean;
     /** Checks pending transactions and promotes/reattaches them if necessary. Default: true. */
     syncPendingTransactions?: boolean;
+    /**
+     * Checks if transactions of recently received outputs are still included, and rolls back the ones that aren't,
+     * e.g. after switching to a node with another history. Default: true.
+     */
+    syncReorgs?: boolean;
     /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
     account?: AccountSyncOptions;
     /** Specifies what outputs should be synced for the address of an alias output. */
@@ -142,12 +176,24 @@ export interface NftSyncOptions {
     nftOutputs?: boolean;
 }
 
+/** Thresholds to decide when pending transactions get promoted or reattached in the background. */
+export interface RetryOptions {
+    /** Seconds after the creation of a transaction from which its block gets promoted. */
+    promoteAfter?: number;
+    /** Seconds after the creation of a transaction from which its block gets reattached. */
+    reattachAfter?: number;
+    /** Amount of milestones a block can stay unreferenced before it gets reattached. */
+    maxMilestoneLag?: number;
+}
+
 /** The account object. */
 export interface AccountMeta {
     /** The account index. */
     index: number;
     /** The type of coin managed with the account. */
     coinType: CoinType;
+    /** The BIP44 account index the addresses are derived with. */
+    bip44AccountIndex?: number;
     /** The alias name of the account. */
     alias: string;
     /** All public addresses. */
@@ -170,6 +216,14 @@ export interface AccountMeta {
     incomingTransactions: {
         [transactionId: string]: [Transaction];
     };
+    /** Arbitrary key-value metadata set by the user. */
+    metadata?: { [key: string]: string };
+    /** Whether the account is archived. */
+    archived: boolean;
+    /** Whether the account is watch-only, it can be synced but can't sign. */
+    watchOnly: boolean;
+    /** The client options of an account bound to another network than the wallet. */
+    clientOptions?: IClientOptions;
 }
 
 /** The account metadata. */
@@ -190,6 +244,12 @@ export interface CreateAccountPayload {
     bech32Hrp?: string;
     /** Account addresses to use. */
     addresses?: AccountAddress[];
+    /** The coin type to derive the addresses with, defaults to the coin type of the wallet. */
+    coinType?: number;
+    /** The BIP44 account index to derive the addresses with, defaults to the account index. */
+    bip44AccountIndex?: number;
+    /** Client options binding the account to another network than the wallet. */
+    clientOptions?: IClientOptions;
 }
 
 /** Options to filter outputs */
@@ -207,3 +267,35 @@ export interface FilterOptions {
     /** Return all NFT outputs matching these IDs. */
     nftIds?: NftId[];
 }
+
+/** Options to filter transactions */
+export interface TransactionFilterOptions {
+    /** Return only incoming (true) or only outgoing (false) transactions. */
+    incoming?: boolean;
+    /** Return only transactions with one of these inclusion states. */
+    inclusionStates?: InclusionState[];
+    /** Filter all transactions where the timestamp is below the specified timestamp in milliseconds */
+    lowerBoundTimestamp?: number;
+    /** Filter all transactions where the timestamp is above the specified timestamp in milliseconds */
+    upperBoundTimestamp?: number;
+    /** Filter all transactions where the summed amount of the outputs is below the specified amount */
+    minAmount?: string;
+    /** Filter all transactions where the summed amount of the outputs is above the specified amount */
+    maxAmount?: string;
+    /** Return only transactions with (true) or without (false) a tagged data payload. */
+    hasTaggedData?: boolean;
+    /** Return only transactions with a tagged data payload whose tag starts with these bytes. */
+    tagPrefix?: HexEncodedString;
+    /** Return only transactions with a note containing this text, ignoring case. */
+    note?: string;
+}
+
+/** Options to filter accounts */
+export interface AccountFilterOptions {
+    /** Also return archived accounts. */
+    includeArchived?: boolean;
+    /** Return only accounts with an alias containing this text, ignoring case. */
+    alias?: string;
+    /** Return only accounts that have all of these metadata entries. */
+    metadata?: { [key: string]: string };
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { Bech32Address, NftId, OutputId, TokenId } from '../block';
-import { NumericString } from '../utils';
+import { HexEncodedString, NumericString } from '../utils';
 
 /** An Address of the Account */
 export interface AccountAddress {
@@ -14,6 +14,10 @@ export interface AccountAddress {
     internal: boolean;
     /** Whether the address was already used before. */
     used: boolean;
+    /** The label of the address. */
+    label?: string;
+    /** Whether the address is frozen, its outputs aren't used as inputs then. */
+    frozen: boolean;
 }
 
 /** Address with a base token amount */
@@ -34,6 +38,10 @@ export interface SendParams {
      * expiration is needed but not provided, it will default to one day.
      */
     expiration?: number;
+    /** Hex encoded tag feature of the output, e.g. to identify a withdrawal at the receiver. */
+    tag?: HexEncodedString;
+    /** Hex encoded metadata feature of the output, e.g. a reference to an invoice. */
+    metadata?: HexEncodedString;
 }
 
 /** Address with unspent outputs */
@@ -1,7 +1,11 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import type { SyncOptions, FilterOptions } from '../account';
+import type {
+    SyncOptions,
+    FilterOptions,
+    TransactionFilterOptions,
+} from '../account';
 import type {
     SendParams,
     SendNativeTokensParams,
@@ -14,15 +18,21 @@ import type {
     BuildFoundryOutputData,
     BuildNftOutputData,
 } from '../build-output-data';
-import type { Burn, INode, PreparedTransactionData } from '../../client';
+import type {
+    Burn,
+    IClientOptions,
+    INode,
+    PreparedTransactionData,
+} from '../../client';
 import type { OutputParams } from '../output-params';
-import type { OutputsToClaim } from '../output';
+import type { AutoClaimPolicy, OutputsToClaim } from '../output';
 import type { SignedTransactionEssence } from '../signed-transaction-essence';
 import type {
     AliasOutputParams,
     CreateNativeTokenParams,
     TransactionOptions,
     MintNftParams,
+    SendAllOptions,
 } from '../transaction-options';
 import type {
     ParticipationEventId,
@@ -30,8 +40,11 @@ import type {
     ParticipationEventType,
 } from '../participation';
 import type { ConsolidationParams } from '../consolidation-params';
+import type { SignInChallenge } from '../sign-in';
 import {
+    Bech32Address,
     HexEncodedAmount,
+    HexEncodedString,
     NumericString,
     Output,
     OutputId,
@@ -67,6 +80,13 @@ export type __PrepareBurnMethod__ = {
     };
 };
 
+export type __AutoClaimOutputsMethod__ = {
+    name: 'autoClaimOutputs';
+    data: {
+        policy: AutoClaimPolicy;
+    };
+};
+
 export type __ClaimOutputsMethod__ = {
     name: 'claimOutputs';
     data: {
@@ -105,6 +125,14 @@ export type __DeregisterParticipationEventMethod__ = {
     };
 };
 
+export type __DryRunTransactionMethod__ = {
+    name: 'dryRunTransaction';
+    data: {
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __GenerateEd25519AddressesMethod__ = {
     name: 'generateEd25519Addresses';
     data: {
@@ -113,6 +141,18 @@ export type __GenerateEd25519AddressesMethod__ = {
     };
 };
 
+export type __ExportPublicAccountMethod__ = {
+    name: 'exportPublicAccount';
+};
+
+export type __GenerateOwnershipProofMethod__ = {
+    name: 'generateOwnershipProof';
+    data: {
+        outputIds: OutputId[];
+        challenge: HexEncodedString;
+    };
+};
+
 export type __GetBalanceMethod__ = {
     name: 'getBalance';
 };
@@ -175,6 +215,13 @@ export type __IncomingTransactionsMethod__ = {
     name: 'incomingTransactions';
 };
 
+export type __FilterTransactionsMethod__ = {
+    name: 'filterTransactions';
+    data: {
+        filterOptions: TransactionFilterOptions;
+    };
+};
+
 export type __TransactionsMethod__ = {
     name: 'transactions';
 };
@@ -242,6 +289,22 @@ export type __RegisterParticipationEventsMethod__ = {
     };
 };
 
+export type __RemoveMetadataMethod__ = {
+    name: 'removeMetadata';
+    data: {
+        key: string;
+    };
+};
+
+export type __ReplaceTransactionMethod__ = {
+    name: 'replaceTransaction';
+    data: {
+        transactionId: TransactionId;
+        destination?: string;
+        options?: TransactionOptions;
+    };
+};
+
 export type __RetryTransactionUntilIncludedMethod__ = {
     name: 'retryTransactionUntilIncluded';
     data: {
@@ -260,6 +323,22 @@ export type __SendMethod__ = {
     };
 };
 
+export type __SendAllMethod__ = {
+    name: 'sendAll';
+    data: {
+        address: string;
+        options?: SendAllOptions;
+    };
+};
+
+export type __SendBatchMethod__ = {
+    name: 'sendBatch';
+    data: {
+        params: SendParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __SendWithParamsMethod__ = {
     name: 'sendWithParams';
     data: {
@@ -268,6 +347,14 @@ export type __SendWithParamsMethod__ = {
     };
 };
 
+export type __PrepareSendAllMethod__ = {
+    name: 'prepareSendAll';
+    data: {
+        address: string;
+        options?: SendAllOptions;
+    };
+};
+
 export type __PrepareSendNativeTokensMethod__ = {
     name: 'prepareSendNativeTokens';
     data: {
@@ -292,6 +379,22 @@ export type __SendOutputsMethod__ = {
     };
 };
 
+export type __SetAddressFrozenMethod__ = {
+    name: 'setAddressFrozen';
+    data: {
+        address: Bech32Address;
+        frozen: boolean;
+    };
+};
+
+export type __SetAddressLabelMethod__ = {
+    name: 'setAddressLabel';
+    data: {
+        address: Bech32Address;
+        label?: string;
+    };
+};
+
 export type __SetAliasMethod__ = {
     name: 'setAlias';
     data: {
@@ -299,11 +402,33 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetArchivedMethod__ = {
+    name: 'setArchived';
+    data: {
+        archived: boolean;
+    };
+};
+
+export type __SetAccountClientOptionsMethod__ = {
+    name: 'setClientOptions';
+    data: {
+        clientOptions: IClientOptions;
+    };
+};
+
 export type __SetDefaultSyncOptionsMethod__ = {
     name: 'setDefaultSyncOptions';
     data: {
         options: SyncOptions;
     };
+
+export type __SetMetadataMethod__ = {
+    name: 'setMetadata';
+    data: {
+        key: string;
+        value: string;
+    };
+};
 };
 
 export type __SignTransactionEssenceMethod__ = {
@@ -313,6 +438,13 @@ export type __SignTransactionEssenceMethod__ = {
     };
 };
 
+export type __SignInMethod__ = {
+    name: 'signIn';
+    data: {
+        challenge: SignInChallenge;
+    };
+};
+
 export type __SignAndSubmitTransactionMethod__ = {
     name: 'signAndSubmitTransaction';
     data: {
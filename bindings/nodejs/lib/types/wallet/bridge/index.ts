@@ -5,11 +5,15 @@ import type {
     __BuildFoundryOutputMethod__,
     __BuildNftOutputMethod__,
     __PrepareBurnMethod__,
+    __AutoClaimOutputsMethod__,
     __ClaimOutputsMethod__,
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __DeregisterParticipationEventMethod__,
+    __DryRunTransactionMethod__,
+    __ExportPublicAccountMethod__,
     __GenerateEd25519AddressesMethod__,
+    __GenerateOwnershipProofMethod__,
     __GetBalanceMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
@@ -20,6 +24,7 @@ import type {
     __OutputsMethod__,
     __PendingTransactionsMethod__,
     __IncomingTransactionsMethod__,
+    __FilterTransactionsMethod__,
     __TransactionsMethod__,
     __UnspentOutputsMethod__,
     __PrepareCreateNativeTokenMethod__,
@@ -30,16 +35,27 @@ import type {
     __PrepareSendMethod__,
     __PrepareTransactionMethod__,
     __RegisterParticipationEventsMethod__,
+    __RemoveMetadataMethod__,
+    __ReplaceTransactionMethod__,
     __RetryTransactionUntilIncludedMethod__,
     __SendMethod__,
+    __SendAllMethod__,
+    __SendBatchMethod__,
     __SendWithParamsMethod__,
+    __PrepareSendAllMethod__,
     __PrepareSendNativeTokensMethod__,
     __PrepareSendNftMethod__,
     __SendOutputsMethod__,
+    __SetAddressFrozenMethod__,
+    __SetAddressLabelMethod__,
     __SetAliasMethod__,
+    __SetArchivedMethod__,
+    __SetAccountClientOptionsMethod__,
     __SetDefaultSyncOptionsMethod__,
+    __SetMetadataMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
+    __SignInMethod__,
     __SubmitAndStoreTransactionMethod__,
     __SyncAccountMethod__,
     __PrepareVoteMethod__,
@@ -59,16 +75,22 @@ import type {
     __ClearStrongholdPasswordMethod__,
     __ClearListenersMethod__,
     __CreateAccountMethod__,
+    __CreateWatchOnlyAccountMethod__,
     __EmitTestEventMethod__,
+    __ReplayEventsMethod__,
+    __PruneEventsMethod__,
     __GenerateMnemonicMethod__,
     __GetAccountMethod__,
     __GetAccountIndexesMethod__,
     __GetAccountsMethod__,
+    __FilterAccountsMethod__,
     __GetChrysalisDataMethod__,
     __GetLedgerNanoStatusMethod__,
     __GenerateEd25519AddressMethod__,
     __IsStrongholdPasswordAvailableMethod__,
     __RecoverAccountsMethod__,
+    __RecoverPendingSubmissionsMethod__,
+    __RemoveAccountMethod__,
     __RemoveLatestAccountMethod__,
     __RestoreBackupMethod__,
     __SetClientOptionsMethod__,
@@ -76,6 +98,13 @@ import type {
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
     __StopBackgroundSyncMethod__,
+    __SyncAllMethod__,
+    __StartBackgroundClaimMethod__,
+    __StopBackgroundClaimMethod__,
+    __StartBackgroundRetryMethod__,
+    __StopBackgroundRetryMethod__,
+    __StartLedgerFollowerMethod__,
+    __StopLedgerFollowerMethod__,
     __StoreMnemonicMethod__,
     __UpdateNodeAuthMethod__,
 } from './wallet';
@@ -86,11 +115,15 @@ export type __AccountMethod__ =
     | __BuildFoundryOutputMethod__
     | __BuildNftOutputMethod__
     | __PrepareBurnMethod__
+    | __AutoClaimOutputsMethod__
     | __ClaimOutputsMethod__
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
+    | __DryRunTransactionMethod__
+    | __ExportPublicAccountMethod__
     | __GenerateEd25519AddressesMethod__
+    | __GenerateOwnershipProofMethod__
     | __GetBalanceMethod__
     | __GetOutputMethod__
     | __GetIncomingTransactionMethod__
@@ -106,6 +139,7 @@ export type __AccountMethod__ =
     | __OutputsMethod__
     | __PendingTransactionsMethod__
     | __IncomingTransactionsMethod__
+    | __FilterTransactionsMethod__
     | __TransactionsMethod__
     | __UnspentOutputsMethod__
     | __PrepareCreateNativeTokenMethod__
@@ -116,16 +150,27 @@ export type __AccountMethod__ =
     | __PrepareSendMethod__
     | __PrepareTransactionMethod__
     | __RegisterParticipationEventsMethod__
+    | __RemoveMetadataMethod__
+    | __ReplaceTransactionMethod__
     | __RetryTransactionUntilIncludedMethod__
     | __SendMethod__
+    | __SendAllMethod__
+    | __SendBatchMethod__
     | __SendWithParamsMethod__
+    | __PrepareSendAllMethod__
     | __PrepareSendNativeTokensMethod__
     | __PrepareSendNftMethod__
     | __SendOutputsMethod__
+    | __SetAddressFrozenMethod__
+    | __SetAddressLabelMethod__
     | __SetAliasMethod__
+    | __SetArchivedMethod__
+    | __SetAccountClientOptionsMethod__
     | __SetDefaultSyncOptionsMethod__
+    | __SetMetadataMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
+    | __SignInMethod__
     | __SubmitAndStoreTransactionMethod__
     | __SyncAccountMethod__
     | __PrepareVoteMethod__
@@ -149,16 +194,22 @@ export type __Method__ =
     | __ClearListenersMethod__
     | __ClearStrongholdPasswordMethod__
     | __CreateAccountMethod__
+    | __CreateWatchOnlyAccountMethod__
     | __EmitTestEventMethod__
+    | __ReplayEventsMethod__
+    | __PruneEventsMethod__
     | __GenerateMnemonicMethod__
     | __GetAccountMethod__
     | __GetAccountIndexesMethod__
     | __GetAccountsMethod__
+    | __FilterAccountsMethod__
     | __GetChrysalisDataMethod__
     | __GetLedgerNanoStatusMethod__
     | __GenerateEd25519AddressMethod__
     | __IsStrongholdPasswordAvailableMethod__
     | __RecoverAccountsMethod__
+    | __RecoverPendingSubmissionsMethod__
+    | __RemoveAccountMethod__
     | __RemoveLatestAccountMethod__
     | __RestoreBackupMethod__
     | __SetClientOptionsMethod__
@@ -166,5 +217,12 @@ export type __Method__ =
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
     | __StopBackgroundSyncMethod__
+    | __SyncAllMethod__
+    | __StartBackgroundClaimMethod__
+    | __StopBackgroundClaimMethod__
+    | __StartBackgroundRetryMethod__
+    | __StopBackgroundRetryMethod__
+    | __StartLedgerFollowerMethod__
+    | __StopLedgerFollowerMethod__
     | __StoreMnemonicMethod__
     | __UpdateNodeAuthMethod__;
@@ -1,5 +1,14 @@
-import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
+import type {
+    AccountFilterOptions,
+    AccountId,
+    CreateAccountPayload,
+    RetryOptions,
+    SyncOptions,
+} from '../account';
 import type { GenerateAddressOptions } from '../address';
+import type { AutoClaimPolicy } from '../output';
+import type { PublicAccount } from '../public-account';
+import type { RemoveAccountOptions } from '../wallet';
 import type { WalletEventType, WalletEvent } from '../event';
 import type { IAuth, IClientOptions } from '../../client';
 
@@ -33,11 +42,26 @@ export type __CreateAccountMethod__ = {
     data: CreateAccountPayload;
 };
 
+export type __CreateWatchOnlyAccountMethod__ = {
+    name: 'createWatchOnlyAccount';
+    data: { publicAccount: PublicAccount; alias?: string };
+};
+
 export type __EmitTestEventMethod__ = {
     name: 'emitTestEvent';
     data: { event: WalletEvent };
 };
 
+export type __ReplayEventsMethod__ = {
+    name: 'replayEvents';
+    data: { fromSeq: number };
+};
+
+export type __PruneEventsMethod__ = {
+    name: 'pruneEvents';
+    data: { beforeSeq: number };
+};
+
 export type __GenerateMnemonicMethod__ = {
     name: 'generateMnemonic';
 };
@@ -50,6 +74,11 @@ export type __GetAccountsMethod__ = {
     name: 'getAccounts';
 };
 
+export type __FilterAccountsMethod__ = {
+    name: 'filterAccounts';
+    data: { filterOptions: AccountFilterOptions };
+};
+
 export type __GetAccountMethod__ = {
     name: 'getAccount';
     data: { accountId: AccountId };
@@ -87,6 +116,18 @@ export type __RecoverAccountsMethod__ = {
     };
 };
 
+export type __RecoverPendingSubmissionsMethod__ = {
+    name: 'recoverPendingSubmissions';
+};
+
+export type __RemoveAccountMethod__ = {
+    name: 'removeAccount';
+    data: {
+        accountId: AccountId;
+        options?: RemoveAccountOptions;
+    };
+};
+
 export type __RemoveLatestAccountMethod__ = {
     name: 'removeLatestAccount';
 };
@@ -128,6 +169,45 @@ export type __StopBackgroundSyncMethod__ = {
     name: 'stopBackgroundSync';
 };
 
+export type __SyncAllMethod__ = {
+    name: 'syncAll';
+    data: {
+        options?: SyncOptions;
+    };
+};
+
+export type __StartBackgroundClaimMethod__ = {
+    name: 'startBackgroundClaim';
+    data: {
+        policy?: AutoClaimPolicy;
+        intervalInMilliseconds?: number;
+    };
+};
+
+export type __StopBackgroundClaimMethod__ = {
+    name: 'stopBackgroundClaim';
+};
+
+export type __StartBackgroundRetryMethod__ = {
+    name: 'startBackgroundRetry';
+    data: {
+        options?: RetryOptions;
+        intervalInMilliseconds?: number;
+    };
+};
+
+export type __StopBackgroundRetryMethod__ = {
+    name: 'stopBackgroundRetry';
+};
+
+export type __StartLedgerFollowerMethod__ = {
+    name: 'startLedgerFollower';
+};
+
+export type __StopLedgerFollowerMethod__ = {
+    name: 'stopLedgerFollower';
+};
+
 export type __StoreMnemonicMethod__ = {
     name: 'storeMnemonic';
     data: { mnemonic: string };
@@ -3,9 +3,10 @@
 
 import type { OutputData } from './output';
 import { InclusionState } from './transaction';
-import { InputSigningData, Remainder } from '../client';
-import { TransactionEssence, TransactionPayload } from '../block';
+import { IDuration, InputSigningData, Remainder } from '../client';
+import { BlockId, TransactionEssence, TransactionPayload } from '../block';
 import { OutputResponse } from '../models';
+import type { PaymentRequest } from './payment-request';
 
 /**
  * A Transaction ID represented as hex-encoded string.
@@ -24,14 +25,20 @@ class Event {
      * The wallet event.
      */
     event: WalletEvent;
+    /**
+     * The sequence number of the event in the event log of the storage, to replay events from it.
+     */
+    seq?: number;
 
     /**
      * @param accountIndex The account index.
      * @param event The wallet event.
+     * @param seq The sequence number of the event, if it was persisted.
      */
-    constructor(accountIndex: number, event: WalletEvent) {
+    constructor(accountIndex: number, event: WalletEvent, seq?: number) {
         this.accountIndex = accountIndex;
         this.event = event;
+        this.seq = seq;
     }
 }
 
@@ -51,6 +58,14 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** A progress update while syncing all accounts. */
+    SyncProgress = 6,
+    /** A registered payment request was paid. */
+    PaymentReceived = 7,
+    /** What a sync of an account did and how long it took. */
+    SyncReport = 8,
+    /** A transaction of received outputs isn't included anymore and was rolled back. */
+    Reorg = 9,
 }
 
 /**
@@ -149,6 +164,134 @@ class TransactionInclusionWalletEvent extends WalletEvent {
     }
 }
 
+/**
+ * A 'sync progress' wallet event, emitted with the index of the account that was just synced.
+ */
+class SyncProgressWalletEvent extends WalletEvent {
+    syncedAccounts: number;
+    failedAccounts: number;
+    totalAccounts: number;
+
+    /**
+     * @param syncedAccounts The number of accounts that were synced.
+     * @param failedAccounts The number of accounts that failed to sync.
+     * @param totalAccounts The number of accounts to sync.
+     */
+    constructor(
+        syncedAccounts: number,
+        failedAccounts: number,
+        totalAccounts: number,
+    ) {
+        super(WalletEventType.SyncProgress);
+        this.syncedAccounts = syncedAccounts;
+        this.failedAccounts = failedAccounts;
+        this.totalAccounts = totalAccounts;
+    }
+}
+
+/** The time spent in each phase of a sync. */
+interface ISyncPhaseDurations {
+    /** Querying the output IDs of the addresses and requesting the outputs. */
+    outputs: IDuration;
+    /** Requesting the metadata of outputs that are spent or weren't synced. */
+    spentOutputs: IDuration;
+    /** Requesting the transactions that created new outputs. */
+    incomingTransactions: IDuration;
+    /** Requesting the foundries of native tokens. */
+    foundries: IDuration;
+    /** Updating and storing the account. */
+    accountUpdate: IDuration;
+    /** Checking the inclusion state of pending transactions. */
+    pendingTransactions: IDuration;
+    /** Checking if the transactions of recently received outputs are still included. */
+    reorgs: IDuration;
+}
+
+/**
+ * A 'sync report' wallet event, emitted after an account was synced.
+ */
+class SyncReportWalletEvent extends WalletEvent {
+    addressesScanned: number;
+    requests: number;
+    cacheHits: number;
+    outputsDiscovered: number;
+    reorgs: number;
+    phases: ISyncPhaseDurations;
+    duration: IDuration;
+
+    /**
+     * @param addressesScanned The number of addresses of which the outputs were queried.
+     * @param requests The number of requests sent to nodes.
+     * @param cacheHits The number of outputs that were already known and not requested again.
+     * @param outputsDiscovered The number of outputs that were requested from nodes.
+     * @param reorgs The number of transactions that were rolled back, because they weren't included anymore.
+     * @param phases The time spent in each phase.
+     * @param duration The total time of the sync.
+     */
+    constructor(
+        addressesScanned: number,
+        requests: number,
+        cacheHits: number,
+        outputsDiscovered: number,
+        reorgs: number,
+        phases: ISyncPhaseDurations,
+        duration: IDuration,
+    ) {
+        super(WalletEventType.SyncReport);
+        this.addressesScanned = addressesScanned;
+        this.requests = requests;
+        this.cacheHits = cacheHits;
+        this.outputsDiscovered = outputsDiscovered;
+        this.reorgs = reorgs;
+        this.phases = phases;
+        this.duration = duration;
+    }
+}
+
+/**
+ * A 'reorg' wallet event, emitted when a transaction of received outputs isn't included anymore, e.g. after
+ * switching to a node with another history, and was rolled back.
+ */
+class ReorgWalletEvent extends WalletEvent {
+    transactionId: TransactionId;
+    removedOutputs: OutputData[];
+    restoredOutputs: OutputData[];
+
+    /**
+     * @param transactionId The transaction that was rolled back.
+     * @param removedOutputs The outputs created by the transaction, which were removed.
+     * @param restoredOutputs The inputs of the transaction, which are unspent again.
+     */
+    constructor(
+        transactionId: TransactionId,
+        removedOutputs: OutputData[],
+        restoredOutputs: OutputData[],
+    ) {
+        super(WalletEventType.Reorg);
+        this.transactionId = transactionId;
+        this.removedOutputs = removedOutputs;
+        this.restoredOutputs = restoredOutputs;
+    }
+}
+
+/**
+ * A 'payment received' wallet event.
+ */
+class PaymentReceivedWalletEvent extends WalletEvent {
+    request: PaymentRequest;
+    output: OutputData;
+
+    /**
+     * @param request The request that was paid.
+     * @param output The output that paid the request.
+     */
+    constructor(request: PaymentRequest, output: OutputData) {
+        super(WalletEventType.PaymentReceived);
+        this.request = request;
+        this.output = output;
+    }
+}
+
 /**
  * All of the transaction progress types.
  */
@@ -167,6 +310,10 @@ enum TransactionProgressType {
     PerformingPow = 5,
     /** Broadcasting. */
     Broadcasting = 6,
+    /** Reattached the transaction in a new block. */
+    Reattached = 7,
+    /** Promoted the block of the transaction. */
+    Promoted = 8,
 }
 
 /**
@@ -289,6 +436,42 @@ class BroadcastingProgress extends TransactionProgress {
     }
 }
 
+/**
+ * A 'reattached' progress.
+ */
+class ReattachedProgress extends TransactionProgress {
+    transactionId: TransactionId;
+    blockId: BlockId;
+
+    /**
+     * @param transactionId The ID of the reattached transaction.
+     * @param blockId The ID of the new block containing the transaction.
+     */
+    constructor(transactionId: TransactionId, blockId: BlockId) {
+        super(TransactionProgressType.Reattached);
+        this.transactionId = transactionId;
+        this.blockId = blockId;
+    }
+}
+
+/**
+ * A 'promoted' progress.
+ */
+class PromotedProgress extends TransactionProgress {
+    transactionId: TransactionId;
+    blockId: BlockId;
+
+    /**
+     * @param transactionId The ID of the promoted transaction.
+     * @param blockId The ID of the promotion block.
+     */
+    constructor(transactionId: TransactionId, blockId: BlockId) {
+        super(TransactionProgressType.Promoted);
+        this.transactionId = transactionId;
+        this.blockId = blockId;
+    }
+}
+
 export {
     Event,
     WalletEventType,
@@ -296,7 +479,12 @@ export {
     ConsolidationRequiredWalletEvent,
     LedgerAddressGenerationWalletEvent,
     NewOutputWalletEvent,
+    PaymentReceivedWalletEvent,
+    ReorgWalletEvent,
     SpentOutputWalletEvent,
+    SyncProgressWalletEvent,
+    SyncReportWalletEvent,
+    ISyncPhaseDurations,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
     TransactionProgress,
@@ -307,5 +495,7 @@ export {
     SigningTransactionProgress,
     PerformingPowProgress,
     BroadcastingProgress,
+    ReattachedProgress,
+    PromotedProgress,
     TransactionProgressType,
 };
@@ -9,10 +9,14 @@ export * from './build-output-data';
 export * from './event';
 export * from './output';
 export * from './output-params';
+export * from './ownership-proof';
+export * from './public-account';
 export * from './participation';
+export * from './payment-request';
 export * from './prepared-create-token-transaction';
 export * from './prepared-transaction';
 export * from './signed-transaction-essence';
+export * from './sign-in';
 export * from './transaction';
 export * from './transaction-options';
 export * from './prepared-create-token-transaction-data';
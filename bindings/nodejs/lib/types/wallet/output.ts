@@ -5,6 +5,7 @@ import { Type } from 'class-transformer';
 import { Address, AddressDiscriminator } from '../block/address';
 import { Output, OutputDiscriminator, OutputId } from '../block/output';
 import { IOutputMetadataResponse } from '../models/api';
+import { NumericString } from '../utils';
 
 /** Output to claim */
 export enum OutputsToClaim {
@@ -15,6 +16,14 @@ export enum OutputsToClaim {
     All = 'All',
 }
 
+/** Policy for claiming outputs automatically. */
+export interface AutoClaimPolicy {
+    /** Outputs are only claimed if at least this amount remains after returning their storage deposit. */
+    minAmount?: bigint | NumericString;
+    /** The maximum number of outputs claimed in one transaction. */
+    batchSize?: number;
+}
+
 /** An output with metadata */
 export class OutputData {
     /** The identifier of an Output */
@@ -0,0 +1,15 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Type } from 'class-transformer';
+import { Ed25519Signature } from '../block/signature';
+import { OutputId } from '../block/output';
+
+/** A signed statement that the key of an Ed25519 address controls an output, for the challenge of a verifier. */
+export class OwnershipProof {
+    /** The ID of the output. */
+    outputId!: OutputId;
+    /** The signature of the statement, made with the key controlling the output. */
+    @Type(() => Ed25519Signature)
+    signature!: Ed25519Signature;
+}
@@ -0,0 +1,21 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { HexEncodedString } from '../utils';
+
+/** The token a payment is requested in, `baseToken` or a native token by its ID. */
+export type PaymentUnit = 'baseToken' | { nativeToken: HexEncodedString };
+
+/** A request for a payment to an address of an account. */
+export interface PaymentRequest {
+    /** The address to pay to. */
+    address: string;
+    /** The requested amount, any amount if not set. */
+    amount?: string;
+    /** The token of the amount. */
+    unit: PaymentUnit;
+    /** The tag the output of the payment must have. */
+    tag?: HexEncodedString;
+    /** The unix timestamp in seconds after which the request isn't valid anymore. */
+    expiresAt?: number;
+}
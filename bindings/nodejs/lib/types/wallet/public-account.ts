@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Type } from 'class-transformer';
+import { Ed25519Signature } from '../block/signature';
+import { Bech32Address } from '../block/address';
+
+/**
+ * A signed statement that the key of the BIP44 path
+ * `m/44'/coinType'/accountIndex'/internal'/keyIndex'` controls an Ed25519 address.
+ */
+export class AddressDerivationProof {
+    /** The address. */
+    address!: Bech32Address;
+    /** The address key index. */
+    keyIndex!: number;
+    /** Determines if an address is a public or an internal (change) address. */
+    internal!: boolean;
+    /** The signature of the statement, made with the key of the address. */
+    @Type(() => Ed25519Signature)
+    signature!: Ed25519Signature;
+}
+
+/** The public derivation material of an account: a derivation proof for each of its generated addresses. */
+export class PublicAccount {
+    /** The coin type of the BIP44 paths. */
+    coinType!: number;
+    /** The account index of the BIP44 paths. */
+    accountIndex!: number;
+    /** The derivation proofs of the public and internal addresses. */
+    @Type(() => AddressDerivationProof)
+    addresses!: AddressDerivationProof[];
+}
@@ -0,0 +1,32 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Type } from 'class-transformer';
+import { Ed25519Signature } from '../block/signature';
+import { Bech32Address } from '../block/address';
+
+/** A challenge of a web service to sign in with an address. */
+export class SignInChallenge {
+    /** The domain of the service the user signs in to. */
+    domain!: string;
+    /** The address the user signs in with. */
+    address!: Bech32Address;
+    /** A statement shown to the user, e.g. the terms of service being accepted. */
+    statement?: string;
+    /** A random nonce, the service only accepts a response with it once. */
+    nonce!: string;
+    /** The UNIX timestamp in seconds the challenge was created at. */
+    issuedAt!: number;
+    /** The UNIX timestamp in seconds after which the challenge can't be used anymore. */
+    expiresAt!: number;
+}
+
+/** A challenge signed with the key of its address. */
+export class SignInResponse {
+    /** The signed challenge. */
+    @Type(() => SignInChallenge)
+    challenge!: SignInChallenge;
+    /** The signature of the message of the challenge. */
+    @Type(() => Ed25519Signature)
+    signature!: Ed25519Signature;
+}
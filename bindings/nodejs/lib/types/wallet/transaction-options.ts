@@ -21,12 +21,19 @@ export interface TransactionOptions {
     customInputs?: string[];
     /** Inputs that must be used for the transaction. */
     mandatoryInputs?: string[];
+    /** Outputs that must not be used for the transaction, e.g. frozen ones. */
+    excludedOutputs?: string[];
     /** Specifies what needs to be burned during input selection. */
     burn?: Burn;
     /** Optional note, that is only stored locally. */
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount?: boolean;
+    /**
+     * Client generated ID of the request. Only one transaction is created per request ID,
+     * also if the wallet stops before the transaction is stored.
+     */
+    requestId?: string;
 }
 
 /** The possible remainder value strategies. */
@@ -105,3 +112,21 @@ export interface AliasOutputParams {
     /** Hex encoded bytes */
     stateMetadata?: HexEncodedString;
 }
+
+/** What happens with the native tokens or NFTs of the account when sending all funds. */
+export enum CarryoverPolicy {
+    /** They're sent to the target address too. */
+    Transfer = 'transfer',
+    /** They stay in the account, the outputs holding them aren't spent. */
+    Keep = 'keep',
+}
+
+/** Options for sending all funds of an account. */
+export interface SendAllOptions {
+    /** What happens with the native tokens of basic outputs, `Transfer` by default. Native tokens of NFT outputs always stay with the NFT. */
+    nativeTokens?: CarryoverPolicy;
+    /** What happens with the NFTs, `Transfer` by default. */
+    nfts?: CarryoverPolicy;
+    /** A note stored with the transaction. */
+    note?: string;
+}
@@ -42,4 +42,66 @@ export class Transaction {
      */
     @Type(() => OutputResponse)
     inputs!: OutputResponse[];
+    /** Who owns the inputs and outputs, set once the transaction is confirmed */
+    ownership?: TransactionOwnership;
+    /** The base coin amounts the transaction moved, derived from the ownership */
+    amounts?: TransactionAmounts;
+    /** The blocks the wallet posted for the transaction, including failed attempts */
+    attachmentHistory?: TransactionAttachment[];
+}
+
+/** Why a block was posted for a transaction */
+export type AttachmentKind = 'submission' | 'reattachment' | 'promotion';
+
+/** A block the wallet posted for a transaction, or tried to */
+export interface TransactionAttachment {
+    /** Why the block was posted */
+    kind: AttachmentKind;
+    /** The id of the posted block, not set if posting failed */
+    blockId?: string;
+    /** The unix timestamp in milliseconds at which the block was posted */
+    timestamp: string;
+    /** Why posting the block failed */
+    error?: string;
+}
+
+/** Who owns an input or output of a transaction, from the view of an account */
+export type AddressOwnership = 'internal' | 'external' | 'foreign';
+
+/** The ownership of the inputs and outputs of a transaction, in the order of its essence */
+export interface TransactionOwnership {
+    inputs: AddressOwnership[];
+    outputs: AddressOwnership[];
+}
+
+/** The base coin amounts a transaction moved, from the view of an account */
+export interface TransactionAmounts {
+    /** The amount sent to foreign addresses, without the remainder */
+    sent: string;
+    /** The amount received from foreign addresses */
+    received: string;
+    /** The amount moved between the account's own addresses, if it didn't send to anyone else */
+    selfTransfer: string;
+}
+
+/** A transaction sent by `Account.sendBatch()` */
+export interface PayoutTransaction {
+    /** The transaction id */
+    transactionId: string;
+    /** The id of the block that contains the transaction */
+    blockId?: string;
+    /** The indexes of the params that are paid out by the transaction */
+    payouts: number[];
+    /** The amount sent to the receivers, including storage deposits that will be returned */
+    amount: string;
+}
+
+/** The result of `Account.sendBatch()` */
+export interface PayoutReport {
+    /** The sent transactions, in the order of the params they pay out */
+    transactions: PayoutTransaction[];
+    /** The indexes of the params that weren't paid out, because a transaction failed */
+    unsent: number[];
+    /** The error of the failed transaction, if not all params were paid out */
+    error?: string;
 }
@@ -1,6 +1,14 @@
 import { IClientOptions, CoinType } from '../client';
 import { SecretManagerType } from '../secret_manager/secret-manager';
 
+/** Options to remove an account. */
+export interface RemoveAccountOptions {
+    /** Remove the account even if it holds funds or has pending transactions. */
+    force?: boolean;
+    /** Clear the Stronghold key from memory after the account was removed. */
+    clearStrongholdKey?: boolean;
+}
+
 /** Options for the Wallet builder. */
 export interface WalletOptions {
     /** The path to the wallet database. */
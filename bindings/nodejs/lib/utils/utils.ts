@@ -17,6 +17,8 @@ import {
     IRent,
     OutputId,
     Bech32Address,
+    ParseMode,
+    MnemonicLanguage,
 } from '../types';
 import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 
@@ -24,10 +26,20 @@ import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 export class Utils {
     /**
      * Generate a new mnemonic.
+     *
+     * @param wordCount The number of words: 12, 15, 18, 21 or 24, which is the default.
+     * @param language The language of the wordlist, English by default.
      */
-    static generateMnemonic(): string {
+    static generateMnemonic(
+        wordCount?: number,
+        language?: MnemonicLanguage,
+    ): string {
         return callUtilsMethod({
             name: 'generateMnemonic',
+            data: {
+                wordCount,
+                language,
+            },
         });
     }
 
@@ -35,13 +47,21 @@ export class Utils {
      * Convert a mnemonic to a hex encoded seed.
      *
      * @param mnemonic A mnemonic string.
+     * @param passphrase An optional passphrase, also known as the 25th word, which derives another seed.
+     * @param language The language of the wordlist, English by default.
      * @returns The seed as hex-encoded string.
      */
-    static mnemonicToHexSeed(mnemonic: string): HexEncodedString {
+    static mnemonicToHexSeed(
+        mnemonic: string,
+        passphrase?: string,
+        language?: MnemonicLanguage,
+    ): HexEncodedString {
         return callUtilsMethod({
             name: 'mnemonicToHexSeed',
             data: {
                 mnemonic,
+                passphrase,
+                language,
             },
         });
     }
@@ -188,6 +208,29 @@ export class Utils {
         return Address.parse(addr);
     }
 
+    /**
+     * Parse a user-provided Bech32 address.
+     *
+     * @param address A Bech32 address, or in lenient mode also a hex encoded Ed25519 address.
+     * @param bech32Hrp The Bech32 HRP (human readable part) of the network, addresses with another HRP are rejected.
+     * @param mode How strictly the address is parsed, lenient by default.
+     * @returns The Bech32 address.
+     */
+    static parseAddress(
+        address: string,
+        bech32Hrp?: string,
+        mode?: ParseMode,
+    ): Bech32Address {
+        return callUtilsMethod({
+            name: 'parseAddress',
+            data: {
+                address,
+                bech32Hrp,
+                mode,
+            },
+        });
+    }
+
     /**
      * Compute the block ID (Blake2b256 hash of the block bytes) of a block.
      *
@@ -394,14 +437,15 @@ export class Utils {
     }
 
     /**
-     * Verify if a mnemonic is a valid BIP39 mnemonic.
+     * Verify if a mnemonic is a valid BIP39 mnemonic, with known words and a valid checksum.
      *
      * @param mnemonic A mnemonic string.
+     * @param language The language of the wordlist, English by default.
      */
-    static verifyMnemonic(mnemonic: string): void {
+    static verifyMnemonic(mnemonic: string, language?: MnemonicLanguage): void {
         return callUtilsMethod({
             name: 'verifyMnemonic',
-            data: { mnemonic },
+            data: { mnemonic, language },
         });
     }
 
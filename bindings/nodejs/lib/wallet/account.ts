@@ -14,11 +14,13 @@ import {
     AddressWithUnspentOutputs,
     AliasOutputParams,
     FilterOptions,
+    TransactionFilterOptions,
     GenerateAddressOptions,
     CreateNativeTokenParams,
     MintNftParams,
     OutputData,
     OutputParams,
+    AutoClaimPolicy,
     OutputsToClaim,
     Transaction,
     TransactionOptions,
@@ -37,8 +39,14 @@ import {
     PreparedTransaction,
     PreparedCreateNativeTokenTransactionData,
     ConsolidationParams,
+    OwnershipProof,
+    PayoutReport,
+    PublicAccount,
+    SendAllOptions,
+    SignInChallenge,
+    SignInResponse,
 } from '../types/wallet';
-import { INode, Burn, PreparedTransactionData } from '../client';
+import { INode, Burn, IClientOptions, PreparedTransactionData } from '../client';
 import {
     AliasOutput,
     NftOutput,
@@ -55,6 +63,7 @@ import {
     TransactionId,
     NumericString,
     Bech32Address,
+    HexEncodedString,
 } from '../types';
 import { plainToInstance } from 'class-transformer';
 import { bigIntToHex, hexToBigInt } from '../types/utils/hex-encoding';
@@ -235,6 +244,24 @@ export class Account {
         );
     }
 
+    /**
+     * Claim the outputs that can be claimed now and match the policy, in batches.
+     * Outputs that expire first are claimed first.
+     * @param policy The policy deciding which outputs get claimed.
+     * @returns The resulting transactions.
+     */
+    async autoClaimOutputs(policy: AutoClaimPolicy): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'autoClaimOutputs',
+                data: { policy },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction[]>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
     /**
      * Claim basic or nft outputs that have additional unlock conditions
      * to their `AddressUnlockCondition` from the account.
@@ -510,6 +537,51 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Sign an ownership statement for the challenge with the key controlling
+     * each of the unspent outputs, to prove the ownership without moving them.
+     *
+     * @param outputIds The IDs of the outputs.
+     * @param challenge The hex encoded challenge of the verifier.
+     * @returns The ownership proofs.
+     */
+    async generateOwnershipProof(
+        outputIds: OutputId[],
+        challenge: HexEncodedString,
+    ): Promise<OwnershipProof[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'generateOwnershipProof',
+                data: {
+                    outputIds,
+                    challenge,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<OwnershipProof[]>;
+        return plainToInstance(OwnershipProof, parsed.payload);
+    }
+
+    /**
+     * Export the public derivation material of the account, a signed
+     * derivation proof for each of its generated addresses. With it an
+     * auditor can verify that addresses belong to the account, without
+     * access to its private keys.
+     *
+     * @returns The public account.
+     */
+    async exportPublicAccount(): Promise<PublicAccount> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'exportPublicAccount',
+            },
+        );
+        const parsed = JSON.parse(response) as Response<PublicAccount>;
+        return plainToInstance(PublicAccount, parsed.payload);
+    }
+
     /**
      * Get the account balance.
      *
@@ -523,14 +595,16 @@ export class Account {
             },
         );
         const payload = JSON.parse(response).payload;
-        return this.adjustBalancePayload(payload);
+        return Account.adjustBalancePayload(payload);
     }
 
     /**
      * Converts hex encoded or decimal strings of amounts to `bigint`
      * for the balance payload.
+     *
+     * @internal
      */
-    private adjustBalancePayload(payload: any): Balance {
+    static adjustBalancePayload(payload: any): Balance {
         for (let i = 0; i < payload.nativeTokens.length; i++) {
             payload.nativeTokens[i].total = hexToBigInt(
                 payload.nativeTokens[i].total,
@@ -834,6 +908,26 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * List the sent and incoming transactions of the account which match the filter, newest first.
+     *
+     * @param filterOptions Options to filter the to be returned transactions.
+     * @returns The transactions.
+     */
+    async filterTransactions(
+        filterOptions: TransactionFilterOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'filterTransactions',
+                data: { filterOptions },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction[]>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
     /**
      * List all the unspent outputs of the account.
      *
@@ -1146,6 +1240,36 @@ export class Account {
         );
     }
 
+    /**
+     * Select the inputs and build the transaction essence without signing or
+     * submitting it, to preview a transaction. The inputs stay available for
+     * other transactions.
+     *
+     * @param outputs Outputs to use in the transaction.
+     * @param options Additional transaction options
+     * or custom inputs.
+     * @returns The prepared transaction data.
+     */
+    async dryRunTransaction(
+        outputs: Output[],
+        options?: TransactionOptions,
+    ): Promise<PreparedTransactionData> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'dryRunTransaction',
+                data: {
+                    outputs,
+                    options,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return plainToInstance(PreparedTransactionData, parsed.payload);
+    }
+
     /**
      * Register participation events.
      *
@@ -1167,6 +1291,35 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Replace a pending transaction with a transaction that spends the same inputs, so at most one of them gets
+     * confirmed.
+     *
+     * @param transactionId The ID of the pending transaction.
+     * @param destination The address to send the transfers of the transaction to, the account if not provided.
+     * @param transactionOptions Additional transaction options.
+     * @returns The sent transaction.
+     */
+    async replaceTransaction(
+        transactionId: TransactionId,
+        destination?: Bech32Address,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'replaceTransaction',
+                data: {
+                    transactionId,
+                    destination,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
     /**
      * Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
      * included (referenced by a milestone). Returns the included block id.
@@ -1222,6 +1375,36 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Send base coins to many addresses in as few transactions as possible, e.g. for withdrawals.
+     * The total amount is checked against the available balance before anything is sent.
+     *
+     * @param params Addresses with amounts and optional tags.
+     * @param transactionOptions Additional transaction options, custom and mandatory inputs are ignored.
+     * @returns The sent transactions and the params that weren't sent because a transaction failed.
+     */
+    async sendBatch(
+        params: SendParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<PayoutReport> {
+        for (let i = 0; i < params.length; i++) {
+            if (typeof params[i].amount === 'bigint') {
+                params[i].amount = params[i].amount.toString(10);
+            }
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendBatch',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Send base coins with amounts from input addresses.
      *
@@ -1253,6 +1436,64 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Send all funds of the account to an address, without a remainder. Outputs
+     * with further unlock conditions, of frozen addresses or used in pending
+     * transactions aren't spent.
+     *
+     * @param address The Bech32 address to send the funds to.
+     * @param options Whether native tokens and NFTs are sent too.
+     * @returns The sent transaction.
+     */
+    async sendAll(
+        address: string,
+        options?: SendAllOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendAll',
+                data: {
+                    address,
+                    options,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
+    /**
+     * Prepare to send all funds of the account to an address, without a
+     * remainder.
+     *
+     * @param address The Bech32 address to send the funds to.
+     * @param options Whether native tokens and NFTs are sent too.
+     * @returns The prepared transaction.
+     */
+    async prepareSendAll(
+        address: string,
+        options?: SendAllOptions,
+    ): Promise<PreparedTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareSendAll',
+                data: {
+                    address,
+                    options,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
     /**
      * Send native tokens.
      *
@@ -1374,6 +1615,45 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Freeze or unfreeze an address of the account. Outputs of frozen addresses aren't used as inputs of
+     * transactions and are reported as frozen in the balance.
+     *
+     * @param address The address to freeze or unfreeze.
+     * @param frozen Whether the address is frozen.
+     */
+    async setAddressFrozen(
+        address: Bech32Address,
+        frozen: boolean,
+    ): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setAddressFrozen',
+            data: {
+                address,
+                frozen,
+            },
+        });
+    }
+
+    /**
+     * Set or remove the label of an address of the account.
+     *
+     * @param address The address to label.
+     * @param label The label, or undefined to remove it.
+     */
+    async setAddressLabel(
+        address: Bech32Address,
+        label?: string,
+    ): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setAddressLabel',
+            data: {
+                address,
+                label,
+            },
+        });
+    }
+
     /**
      * Set the alias for the account
      *
@@ -1388,6 +1668,66 @@ export class Account {
         });
     }
 
+    /**
+     * Set a metadata entry of the account, replacing the previous value of the key.
+     *
+     * @param key The key of the entry.
+     * @param value The value of the entry.
+     */
+    async setMetadata(key: string, value: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setMetadata',
+            data: {
+                key,
+                value,
+            },
+        });
+    }
+
+    /**
+     * Remove a metadata entry of the account.
+     *
+     * @param key The key of the entry.
+     */
+    async removeMetadata(key: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeMetadata',
+            data: {
+                key,
+            },
+        });
+    }
+
+    /**
+     * Archive or restore the account. Archived accounts keep their data, but
+     * are hidden when filtering accounts and aren't synced in the background.
+     *
+     * @param archived Whether the account should be archived.
+     */
+    async setArchived(archived: boolean): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setArchived',
+            data: {
+                archived,
+            },
+        });
+    }
+
+    /**
+     * Set the client options of an account bound to another network than the
+     * wallet.
+     *
+     * @param clientOptions The client options to set.
+     */
+    async setClientOptions(clientOptions: IClientOptions): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setClientOptions',
+            data: {
+                clientOptions,
+            },
+        });
+    }
+
     /**
      * Set the fallback SyncOptions for account syncing.
      * If storage is enabled, will persist during restarts.
@@ -1403,6 +1743,27 @@ export class Account {
         });
     }
 
+    /**
+     * Sign a challenge of a web service to sign in with an address of the
+     * account. The message of the challenge should be shown to the user before.
+     *
+     * @param challenge The challenge of the web service.
+     * @returns The signed challenge, to be verified by the web service.
+     */
+    async signIn(challenge: SignInChallenge): Promise<SignInResponse> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'signIn',
+                data: {
+                    challenge,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<SignInResponse>;
+        return plainToInstance(SignInResponse, parsed.payload);
+    }
+
     /**
      * Sign a prepared transaction, useful for offline signing.
      *
@@ -1490,7 +1851,7 @@ export class Account {
             },
         );
         const payload = JSON.parse(response).payload;
-        return this.adjustBalancePayload(payload);
+        return Account.adjustBalancePayload(payload);
     }
 
     /**
@@ -5,18 +5,25 @@ import { WalletMethodHandler } from './wallet-method-handler';
 import { Account } from './account';
 
 import type {
+    AccountFilterOptions,
     AccountId,
+    AutoClaimPolicy,
     WalletOptions,
     CreateAccountPayload,
+    PublicAccount,
     WalletEventType,
     GenerateAddressOptions,
+    RemoveAccountOptions,
+    RetryOptions,
+    Balance,
     SyncOptions,
     WalletEvent,
-    Event,
 } from '../types/wallet';
+import { Event, Transaction } from '../types/wallet';
 import { IAuth, IClientOptions, LedgerNanoStatus } from '../types/client';
 import { Client } from '../client';
 import { SecretManager } from '../secret_manager';
+import { plainToInstance } from 'class-transformer';
 
 /** The Wallet class. */
 export class Wallet {
@@ -78,6 +85,24 @@ export class Wallet {
         return new Account(JSON.parse(response).payload, this.methodHandler);
     }
 
+    /**
+     * Create a watch-only account from the public export of an account, e.g. to monitor it without access to its
+     * keys. It can be synced, but signing fails.
+     *
+     * @param publicAccount The exported public account.
+     * @param alias The account alias, the account index if not set.
+     */
+    async createWatchOnlyAccount(
+        publicAccount: PublicAccount,
+        alias?: string,
+    ): Promise<Account> {
+        const response = await this.methodHandler.callMethod({
+            name: 'createWatchOnlyAccount',
+            data: { publicAccount, alias },
+        });
+        return new Account(JSON.parse(response).payload, this.methodHandler);
+    }
+
     /**
      * Destroy the Wallet and drop its database connection.
      */
@@ -141,6 +166,29 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Get the accounts which match the filter options, archived accounts are only returned if requested.
+     *
+     * @param filterOptions The options to filter the accounts.
+     */
+    async filterAccounts(
+        filterOptions: AccountFilterOptions,
+    ): Promise<Account[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'filterAccounts',
+            data: { filterOptions },
+        });
+
+        const { payload } = JSON.parse(response);
+
+        const accounts: Account[] = [];
+
+        for (const account of payload) {
+            accounts.push(new Account(account, this.methodHandler));
+        }
+        return accounts;
+    }
+
     /**
      * Get client.
      */
@@ -217,6 +265,86 @@ export class Wallet {
         return this.methodHandler.listen(eventTypes, callback);
     }
 
+    /**
+     * Listen to wallet events with an async iterator. An empty array will listen to all possible events.
+     *
+     * Events are buffered until they're consumed. Breaking out of the `for await` loop stops the buffering and
+     * clears the listeners of the event types with `clearListeners()`, so also other listeners of these types, or
+     * of all types if the array is empty, are removed.
+     *
+     * @example
+     * for await (const event of wallet.events([WalletEventType.NewOutput])) {
+     *     console.log(event);
+     * }
+     */
+    events(eventTypes: WalletEventType[]): AsyncIterableIterator<Event> {
+        // eslint-disable-next-line @typescript-eslint/no-this-alias
+        const wallet = this;
+        const buffer: Event[] = [];
+        const waiting: {
+            resolve: (result: IteratorResult<Event>) => void;
+            reject: (error: Error) => void;
+        }[] = [];
+        let failure: Error | undefined;
+        let done = false;
+
+        const registered = this.listen(eventTypes, (error, event) => {
+            if (done) {
+                return;
+            }
+            if (error) {
+                failure = error;
+                waiting.splice(0).forEach(({ reject }) => reject(error));
+                return;
+            }
+            const next = waiting.shift();
+            if (next) {
+                next.resolve({ value: event, done: false });
+            } else {
+                buffer.push(event);
+            }
+        });
+
+        const finish = (): IteratorResult<Event> => {
+            done = true;
+            buffer.length = 0;
+            waiting
+                .splice(0)
+                .forEach(({ resolve }) =>
+                    resolve({ value: undefined, done: true }),
+                );
+            return { value: undefined, done: true };
+        };
+
+        return {
+            async next(): Promise<IteratorResult<Event>> {
+                await registered;
+                if (failure) {
+                    throw failure;
+                }
+                const event = buffer.shift();
+                if (event) {
+                    return { value: event, done: false };
+                }
+                if (done) {
+                    return { value: undefined, done: true };
+                }
+                return new Promise((resolve, reject) =>
+                    waiting.push({ resolve, reject }),
+                );
+            },
+            async return(): Promise<IteratorResult<Event>> {
+                const result = finish();
+                await registered;
+                await wallet.clearListeners(eventTypes);
+                return result;
+            },
+            [Symbol.asyncIterator]() {
+                return this;
+            },
+        };
+    }
+
     /**
      * Clear the callbacks for provided events. An empty array will clear all listeners.
      */
@@ -228,6 +356,32 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the persisted events with a sequence number of at least `fromSeq`, in the order they were emitted.
+     * A consumer can store the sequence number of the last event it processed, to resume after a restart.
+     */
+    async replayEvents(fromSeq: number): Promise<Event[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'replayEvents',
+            data: { fromSeq },
+        });
+        return JSON.parse(response).payload.map(
+            (event: Event) =>
+                new Event(event.accountIndex, event.event, event.seq),
+        );
+    }
+
+    /**
+     * Remove the persisted events with a sequence number lower than `beforeSeq`.
+     */
+    async pruneEvents(beforeSeq: number): Promise<void> {
+        const response = await this.methodHandler.callMethod({
+            name: 'pruneEvents',
+            data: { beforeSeq },
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Find accounts with unspent outputs.
      */
@@ -254,6 +408,37 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Recover the transactions that were signed with a request ID, but not
+     * stored, because the wallet stopped in between.
+     * Should be called after restarting the wallet.
+     * @returns The recovered transactions.
+     */
+    async recoverPendingSubmissions(): Promise<Transaction[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'recoverPendingSubmissions',
+        });
+        const parsed = JSON.parse(response) as Response<Transaction[]>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
+    /**
+     * Remove an account and all records stored for it. Accounts that hold funds or have pending transactions are only
+     * removed if `force` is set.
+     *
+     * @param accountId The index or alias of the account.
+     * @param options Options to force the removal and to clear the Stronghold key.
+     */
+    async removeAccount(
+        accountId: AccountId,
+        options?: RemoveAccountOptions,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'removeAccount',
+            data: { accountId, options },
+        });
+    }
+
     /**
      * Delete the latest account.
      */
@@ -347,6 +532,95 @@ export class Wallet {
         });
     }
 
+    /**
+     * Sync all accounts that aren't archived concurrently, the most recently
+     * active accounts first. A `SyncProgress` event is emitted after each
+     * account.
+     *
+     * @param options Optional synchronization options.
+     * @returns The balance of all synced accounts added together.
+     */
+    async syncAll(options?: SyncOptions): Promise<Balance> {
+        const response = await this.methodHandler.callMethod({
+            name: 'syncAll',
+            data: {
+                options,
+            },
+        });
+        const payload = JSON.parse(response).payload;
+        return Account.adjustBalancePayload(payload);
+    }
+
+    /**
+     * Start claiming the outputs of all accounts that match the policy in the background.
+     * Uses the outputs of the last sync, so it should run together with background syncing.
+     */
+    async startBackgroundClaim(
+        policy?: AutoClaimPolicy,
+        intervalInMilliseconds?: number,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'startBackgroundClaim',
+            data: {
+                policy,
+                intervalInMilliseconds,
+            },
+        });
+    }
+
+    /**
+     * Stop claiming outputs in the background.
+     */
+    async stopBackgroundClaim(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'stopBackgroundClaim',
+        });
+    }
+
+    /**
+     * Start promoting and reattaching pending transactions of all accounts in the background.
+     */
+    async startBackgroundRetry(
+        options?: RetryOptions,
+        intervalInMilliseconds?: number,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'startBackgroundRetry',
+            data: {
+                options,
+                intervalInMilliseconds,
+            },
+        });
+    }
+
+    /**
+     * Stop promoting and reattaching pending transactions in the background.
+     */
+    async stopBackgroundRetry(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'stopBackgroundRetry',
+        });
+    }
+
+    /**
+     * Start applying the UTXO changes of confirmed milestones received with MQTT to the accounts, so their balances
+     * update without syncing. The accounts should be synced before.
+     */
+    async startLedgerFollower(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'startLedgerFollower',
+        });
+    }
+
+    /**
+     * Stop applying the UTXO changes of confirmed milestones to the accounts.
+     */
+    async stopLedgerFollower(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'stopLedgerFollower',
+        });
+    }
+
     /**
      * Store a mnemonic in the Stronghold snapshot.
      */
@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import 'reflect-metadata';
+
+import { describe, it, expect, jest } from '@jest/globals';
+import { Event, Wallet, WalletEventType } from '../../lib/';
+
+describe('Wallet events', () => {
+    it('clears the listeners when the iteration stops', async () => {
+        const event = new Event(0, { type: WalletEventType.ConsolidationRequired });
+        // Only the listener methods are used by the iterator
+        const wallet = {
+            listen: jest.fn(
+                async (
+                    _eventTypes: WalletEventType[],
+                    callback: (error: Error, event: Event) => void,
+                ) => {
+                    callback(undefined as unknown as Error, event);
+                    callback(undefined as unknown as Error, event);
+                },
+            ),
+            clearListeners: jest.fn(async (_eventTypes: WalletEventType[]) => {}),
+        };
+        const eventTypes = [WalletEventType.ConsolidationRequired];
+
+        const received: Event[] = [];
+        for await (const next of Wallet.prototype.events.call(
+            wallet,
+            eventTypes,
+        )) {
+            received.push(next);
+            break;
+        }
+
+        expect(received).toStrictEqual([event]);
+        expect(wallet.listen).toHaveBeenCalledTimes(1);
+        expect(wallet.clearListeners).toHaveBeenCalledWith(eventTypes);
+    });
+});
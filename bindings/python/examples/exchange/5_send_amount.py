@@ -21,17 +21,17 @@ wallet = Wallet(os.environ.get('WALLET_DB_PATH'))
 
 account = wallet.get_account('Alice')
 
-wallet.set_stronghold_password(os.environ["STRONGHOLD_PASSWORD"])
-
 # Set sync_only_most_basic_outputs to True if not interested in outputs that are timelocked,
 # have a storage deposit return, expiration or are nft/alias/foundry outputs.
 balance = account.sync(SyncOptions(sync_only_most_basic_outputs=True))
 print('Balance', balance)
 
-transaction = account.send(
-    1000000,
-    "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
-)
+# The password is only kept while sending
+with wallet.stronghold_unlocked(os.environ["STRONGHOLD_PASSWORD"]):
+    transaction = account.send(
+        1000000,
+        "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+    )
 print(transaction)
 print(
     f'Check your block on: {os.environ["EXPLORER_URL"]}/block/{transaction.blockId}')
@@ -31,6 +31,8 @@ from .types.output_data import *
 from .types.output_id import *
 from .types.output_params import *
 from .types.payload import *
+from .types.send_amount import *
+from .types.address_balance import *
 from .types.send_params import *
 from .types.token_scheme import *
 from .types.transaction import *
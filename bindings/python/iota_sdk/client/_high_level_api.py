@@ -7,7 +7,10 @@ from iota_sdk.types.common import HexStr
 from iota_sdk.types.output import OutputWithMetadata
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.common import CoinType
-from typing import List, Optional, Union
+from iota_sdk.types.send_amount import SendAmountOptions, SendAmountResult
+from iota_sdk.types.address_balance import AddressBalances
+from itertools import islice
+from typing import Iterable, Iterator, List, Optional, Union
 from dacite import from_dict
 
 
@@ -114,6 +117,21 @@ class HighLevelAPI():
         })
         return [from_dict(OutputWithMetadata, o) for o in outputs]
 
+    def iter_outputs(self, output_ids: Iterable[OutputId],
+                     chunk_size: int = 1000) -> Iterator[OutputWithMetadata]:
+        """Iterate over the OutputWithMetadata of the provided OutputIds, e.g. from `iter_basic_output_ids()`.
+        The outputs are requested in chunks while iterating, so not all of them are kept in memory.
+
+        Args:
+            output_ids: The output ids.
+            chunk_size: The number of outputs requested at once.
+        """
+        output_ids = iter(output_ids)
+        chunk = list(islice(output_ids, chunk_size))
+        while chunk:
+            yield from self.get_outputs(chunk)
+            chunk = list(islice(output_ids, chunk_size))
+
     def find_blocks(self, block_ids: List[HexStr]) -> List[Block]:
         """Find all blocks by provided block IDs.
 
@@ -128,6 +146,21 @@ class HighLevelAPI():
         })
         return [Block.from_dict(block) for block in blocks]
 
+    def iter_blocks(self, block_ids: Iterable[HexStr],
+                    chunk_size: int = 1000) -> Iterator[Block]:
+        """Iterate over the blocks of the provided block IDs.
+        The blocks are requested in chunks while iterating, so not all of them are kept in memory.
+
+        Args:
+            block_ids: The block ids.
+            chunk_size: The number of blocks requested at once.
+        """
+        block_ids = iter(block_ids)
+        chunk = list(islice(block_ids, chunk_size))
+        while chunk:
+            yield from self.find_blocks(chunk)
+            chunk = list(islice(block_ids, chunk_size))
+
     def retry(self, block_id: HexStr) -> List[Union[HexStr, Block]]:
         """Retries (promotes or reattaches) a block for provided block id. Block should only be
         retried only if they are valid and haven't been confirmed for a while.
@@ -186,6 +219,40 @@ class HighLevelAPI():
             'generateAddressesOptions': generate_addresses_options.as_dict(),
         })
 
+    def send_amount(self, secret_manager: Union[LedgerNanoSecretManager, MnemonicSecretManager, SeedSecretManager,
+                    StrongholdSecretManager], address: str, amount: int,
+                    options: Optional[SendAmountOptions] = None) -> SendAmountResult:
+        """Send an amount to an address in a basic output. The inputs are selected from the addresses of the secret
+        manager, a remainder output is created if needed and the block is retried until it's included.
+
+        Args:
+            secret_manager: A supported secret manager.
+            address: The Bech32 address to send the amount to.
+            amount: The amount to send.
+            options: Options for the input selection and the inclusion checks.
+
+        Returns:
+            The IDs of the transaction, the blocks and the created outputs.
+        """
+        result = self._call_method('sendAmount', {
+            'secretManager': secret_manager,
+            'address': address,
+            'amount': amount,
+            'options': options.as_dict() if options else None
+        })
+        return from_dict(SendAmountResult, result)
+
+    def get_address_balances(self, addresses: List[str]) -> AddressBalances:
+        """Get the confirmed balances of addresses with the number of outputs of each kind they own.
+
+        Args:
+            addresses: The Bech32 addresses, which don't have to belong to a wallet.
+        """
+        result = self._call_method('getAddressBalances', {
+            'addresses': addresses
+        })
+        return from_dict(AddressBalances, result)
+
     def find_inputs(self, addresses: List[str], amount: int):
         """Function to find inputs from addresses for a provided amount(useful for offline signing).
 
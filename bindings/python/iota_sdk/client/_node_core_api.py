@@ -8,6 +8,7 @@ from iota_sdk.types.output import OutputWithMetadata, OutputMetadata
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.payload import MilestonePayload
 from iota_sdk.types.utxo_changes import UtxoChanges
+from iota_sdk import post_block_raw
 from typing import List, Union
 from dacite import from_dict
 
@@ -66,6 +67,19 @@ class NodeCoreAPI():
             'block': block.__dict__
         })
 
+    def broadcast_block(self, block: Block):
+        """Post a block with finished PoW to all healthy nodes at once, so a single slow node can't delay its propagation.
+
+        Args:
+            block: The block to post.
+
+        Returns:
+            The block id and the response of each node, with an `error` if the node didn't accept the block.
+        """
+        return self._call_method('broadcastBlock', {
+            'block': block.__dict__
+        })
+
     def get_block_data(self, block_id: HexStr) -> Block:
         """Get the block corresponding to the given block id.
         """
@@ -80,22 +94,23 @@ class NodeCoreAPI():
             'blockId': block_id
         }))
 
-    def get_block_raw(self, block_id: HexStr) -> List[int]:
+    def get_block_raw(self, block_id: HexStr) -> bytes:
         """Get the raw bytes of the block corresponding to the given block id.
         """
-        return self._call_method('getBlockRaw', {
+        return self._call_method_raw('getBlockRaw', {
             'blockId': block_id
         })
 
-    def post_block_raw(self, block_bytes: List[int]) -> HexStr:
-        """Post a block as raw bytes.
+    def post_block_raw(
+            self, block_bytes: Union[bytes, bytearray, memoryview, List[int]]) -> HexStr:
+        """Post a block as raw bytes. The bytes of a `bytes` object are passed to the Rust library without copying them.
 
         Returns:
             The corresponding block id of the block.
         """
-        return self._call_method('postBlockRaw', {
-            'blockBytes': block_bytes
-        })
+        if isinstance(block_bytes, list):
+            block_bytes = bytes(block_bytes)
+        return post_block_raw(self.handle, block_bytes)
 
     def get_output(
             self, output_id: Union[OutputId, HexStr]) -> OutputWithMetadata:
@@ -110,6 +125,20 @@ class NodeCoreAPI():
             'outputId': output_id_str
         }))
 
+    def get_output_with_metadata_proof(
+            self, output_id: Union[OutputId, HexStr]):
+        """Get an output with its metadata, the block that created it, the milestone that booked it and, if a node runs
+        the PoI plugin, the merkle audit path from the block to the milestone, to verify the output offline.
+
+        Args:
+            output_id: The ID of the output.
+        """
+        output_id_str = output_id.output_id if isinstance(
+            output_id, OutputId) else output_id
+        return self._call_method('getOutputWithMetadataProof', {
+            'outputId': output_id_str
+        })
+
     def get_output_metadata(
             self, output_id: Union[OutputId, HexStr]) -> OutputMetadata:
         """Get the output metadata corresponding to the given output id.
@@ -134,13 +163,13 @@ class NodeCoreAPI():
         })
         return MilestonePayload.from_dict(result)
 
-    def get_milestone_by_id_raw(self, milestone_id: HexStr) -> List[int]:
+    def get_milestone_by_id_raw(self, milestone_id: HexStr) -> bytes:
         """Get the raw bytes of the milestone corresponding to the given milestone id.
 
         Returns:
             The raw bytes of the milestone.
         """
-        return self._call_method('getMilestoneByIdRaw', {
+        return self._call_method_raw('getMilestoneByIdRaw', {
             'milestoneId': milestone_id
         })
 
@@ -155,13 +184,13 @@ class NodeCoreAPI():
         })
         return MilestonePayload.from_dict(result)
 
-    def get_milestone_by_index_raw(self, index: int) -> List[int]:
+    def get_milestone_by_index_raw(self, index: int) -> bytes:
         """Get the raw bytes of the milestone corresponding to the given milestone index.
 
         Returns:
             The raw bytes of the milestone.
         """
-        return self._call_method('getMilestoneByIndexRaw', {
+        return self._call_method_raw('getMilestoneByIndexRaw', {
             'index': index
         })
 
@@ -196,6 +225,19 @@ class NodeCoreAPI():
         """
         return self._call_method('getTreasury')
 
+    def get_treasury_mutations(
+            self, start_milestone_index: int, end_milestone_index: int):
+        """List the mutations of the treasury by the receipts of the milestones in the range, ordered by milestone index.
+
+        Args:
+            start_milestone_index: The index of the first milestone of the range.
+            end_milestone_index: The index of the last milestone of the range.
+        """
+        return self._call_method('getTreasuryMutations', {
+            'startMilestoneIndex': start_milestone_index,
+            'endMilestoneIndex': end_milestone_index
+        })
+
     def get_included_block(self, transaction_id: HexStr) -> Block:
         """Returns the included block of the given transaction.
 
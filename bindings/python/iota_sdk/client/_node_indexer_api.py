@@ -1,10 +1,12 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
+from iota_sdk import output_ids_pages
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.output_id import OutputId
 from dataclasses import dataclass
-from typing import Dict, Optional
+from json import dumps, loads
+from typing import Dict, Iterator, Optional
 import humps
 
 
@@ -218,3 +220,47 @@ class NodeIndexerAPI():
         return OutputId.from_string(self._call_method('foundryOutputId', {
             'foundryId': foundry_id
         }))
+
+    def iter_output_ids(
+            self, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        """Iterate over alias/basic/NFT/foundry output IDs from the given query parameters, see `output_ids()`.
+        The pages of output IDs are requested one after the other while iterating, so not all of them are kept in memory.
+        """
+        return self._iter_output_ids('outputIds', query_parameters)
+
+    def iter_basic_output_ids(
+            self, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        """Iterate over basic output IDs from the given query parameters, see `basic_output_ids()`.
+        The pages of output IDs are requested one after the other while iterating, so not all of them are kept in memory.
+        """
+        return self._iter_output_ids('basicOutputIds', query_parameters)
+
+    def iter_alias_output_ids(
+            self, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        """Iterate over alias output IDs from the given query parameters, see `alias_output_ids()`.
+        The pages of output IDs are requested one after the other while iterating, so not all of them are kept in memory.
+        """
+        return self._iter_output_ids('aliasOutputIds', query_parameters)
+
+    def iter_nft_output_ids(
+            self, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        """Iterate over NFT output IDs from the given query parameters, see `nft_output_ids()`.
+        The pages of output IDs are requested one after the other while iterating, so not all of them are kept in memory.
+        """
+        return self._iter_output_ids('nftOutputIds', query_parameters)
+
+    def iter_foundry_output_ids(
+            self, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        """Iterate over foundry output IDs from the given query parameters, see `foundry_output_ids()`.
+        The pages of output IDs are requested one after the other while iterating, so not all of them are kept in memory.
+        """
+        return self._iter_output_ids('foundryOutputIds', query_parameters)
+
+    def _iter_output_ids(
+            self, method: str, query_parameters: QueryParameters) -> Iterator[OutputId]:
+        pages = output_ids_pages(
+            self.handle, method, dumps(
+                query_parameters.as_dict()))
+        for page in pages:
+            for output_id in loads(page)["items"]:
+                yield OutputId.from_string(output_id)
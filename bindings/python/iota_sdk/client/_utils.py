@@ -1,7 +1,7 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from iota_sdk.types.common import HexStr
+from iota_sdk.types.common import HexStr, ParseMode
 from iota_sdk.types.output import Output
 from typing import Optional
 
@@ -43,6 +43,15 @@ class ClientUtils():
             'bech32Hrp': bech32_hrp
         })
 
+    def parse_address(self, address: str,
+                      mode: ParseMode = ParseMode.Lenient) -> str:
+        """Parses a user-provided bech32 address, or in lenient mode also a hex encoded Ed25519 address, and checks that it belongs to the network.
+        """
+        return self._call_method('parseAddress', {
+            'address': address,
+            'mode': mode
+        })
+
     def minimum_required_storage_deposit(self, output: Output) -> int:
         """Minimum required storage deposit.
         """
@@ -2,13 +2,14 @@
 # SPDX-License-Identifier: Apache-2.0
 
 import iota_sdk
-from iota_sdk import call_client_method, listen_mqtt
+from iota_sdk import call_client_method, call_client_method_raw, listen_mqtt
 from iota_sdk.client._node_core_api import NodeCoreAPI
 from iota_sdk.client._node_indexer_api import NodeIndexerAPI
 from iota_sdk.client._high_level_api import HighLevelAPI
 from iota_sdk.client._utils import ClientUtils
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
 from iota_sdk.types.block import Block
+from iota_sdk.types.client_options import RetryPolicy
 from iota_sdk.types.common import HexStr, Node, AddressAndAmount
 from iota_sdk.types.feature import Feature
 from iota_sdk.types.native_token import NativeToken
@@ -47,6 +48,7 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         api_timeout: Optional[timedelta] = None,
         node_sync_interval: Optional[timedelta] = None,
         remote_pow_timeout: Optional[timedelta] = None,
+        retry_policy: Optional[RetryPolicy] = None,
         tips_interval: Optional[int] = None,
         quorum: Optional[bool] = None,
         min_quorum_size: Optional[int] = None,
@@ -55,6 +57,7 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
+        pow_worker_niceness: Optional[int] = None,
         client_handle=None
     ):
         """Initialize the IOTA Client.
@@ -76,6 +79,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::NetworkInfo) gets updated.
         remote_pow_timeout :
             Timeout when sending a block that requires remote proof of work.
+        retry_policy :
+            How failed requests are retried.
         tips_interval :
             Tips request interval during PoW in seconds.
         quorum :
@@ -92,6 +97,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Fallback to local proof of work if the node doesn't support remote PoW.
         pow_worker_count :
             The amount of threads to be used for proof of work.
+        pow_worker_niceness :
+            The niceness of the threads used for proof of work, from -20 (highest priority) to 19 (lowest priority), only applied on Linux.
         client_handle :
             An instance of a node client.
         """
@@ -129,6 +136,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'remote_pow_timeout' in client_config:
             client_config['remote_pow_timeout'] = {'secs': int(client_config['remote_pow_timeout'].total_seconds(
             )), 'nanos': get_remaining_nano_seconds(client_config['remote_pow_timeout'])}
+        if 'retry_policy' in client_config:
+            client_config['retry_policy'] = client_config['retry_policy'].as_dict()
 
         client_config = humps.camelize(client_config)
         client_config_str = dumps(client_config)
@@ -163,6 +172,29 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         else:
             return response
 
+    def _call_method_raw(self, name, data=None) -> bytes:
+        """Dumps json string and calls `call_client_method_raw()` for methods that return raw bytes
+        """
+        message = {
+            'name': name
+        }
+        if data:
+            message['data'] = data
+        message = dumps(message)
+
+        # Send message to the Rust library, the bytes are returned as they are
+        response = call_client_method_raw(self.handle, message)
+        if isinstance(response, bytes):
+            return response
+
+        json_response = loads(response)
+
+        if "type" in json_response:
+            if json_response["type"] == "error":
+                raise ClientError(json_response['payload'])
+
+        return json_response['payload']
+
     def get_handle(self):
         """Get the client handle.
 
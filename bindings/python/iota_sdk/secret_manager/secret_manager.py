@@ -41,6 +41,26 @@ class MnemonicSecretManager(dict):
         dict.__init__(self, mnemonic=mnemonic)
 
 
+class MnemonicWithPassphraseSecretManager(dict):
+    """Secret manager that uses a mnemonic with a passphrase, also known as the 25th word, held in memory.
+    This is not recommended in production. Use LedgerNano or Stronghold instead.
+    """
+
+    def __init__(self, mnemonic, passphrase, language=None):
+        """Initialize a mnemonic secret manager with a passphrase.
+
+        Args:
+            mnemonic: The mnemonic.
+            passphrase: The passphrase, another one derives another seed.
+            language: The language of the mnemonic, English by default.
+        """
+
+        secret = {'mnemonic': mnemonic, 'passphrase': passphrase}
+        if language is not None:
+            secret['language'] = language
+        dict.__init__(self, mnemonicWithPassphrase=secret)
+
+
 class SeedSecretManager(dict):
     """Secret manager that uses a seed.
     """
@@ -282,3 +302,34 @@ class SecretManager():
             'transactionEssenceHash': transaction_essence_hash,
             'chain': chain.__dict__,
         })
+
+    def lock(self):
+        """Zeroize the secrets of the secret manager, until it's unlocked again it can't be used for address generation
+        or signing.
+        """
+        return self._call_method('lock')
+
+    def unlock(self, secret: str):
+        """Unlock the secret manager with the secret it was created with.
+
+        Args:
+            secret: The Stronghold password, the mnemonic, the hex seed or the hex private key.
+        """
+        return self._call_method('unlock', {
+            'secret': secret,
+        })
+
+    def is_locked(self) -> bool:
+        """Returns whether the secret manager is locked.
+        """
+        return self._call_method('isLocked')
+
+    def set_auto_lock(self, interval_in_milliseconds: Optional[int] = None):
+        """Set the time after which the secret manager locks itself once it was unlocked.
+
+        Args:
+            interval_in_milliseconds: The auto-lock interval, `None` disables it.
+        """
+        return self._call_method('setAutoLock', {
+            'intervalInMilliseconds': interval_in_milliseconds,
+        })
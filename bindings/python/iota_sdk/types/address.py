@@ -4,6 +4,7 @@
 from iota_sdk.types.common import HexStr
 from dataclasses import dataclass, field
 from enum import IntEnum
+from typing import Dict, Optional, Union
 
 
 class AddressType(IntEnum):
@@ -28,6 +29,19 @@ class Address():
     def as_dict(self):
         return {k: v for k, v in self.__dict__.items() if v is not None}
 
+    def to_bech32(self, bech32_hrp: str) -> str:
+        """Convert the address to a Bech32 encoded address with the given human readable part.
+        """
+        # Imported here to prevent a circular import
+        from iota_sdk.utils import Utils
+
+        address_type = AddressType(self.type)
+        if address_type == AddressType.ED25519:
+            return Utils.hex_to_bech32(self.pubKeyHash, bech32_hrp)
+        if address_type == AddressType.ALIAS:
+            return Utils.alias_id_to_bech32(self.aliasId, bech32_hrp)
+        return Utils.nft_id_to_bech32(self.nftId, bech32_hrp)
+
 
 @dataclass
 class Ed25519Address(Address):
@@ -65,6 +79,18 @@ class NFTAddress(Address):
     type: int = field(default_factory=lambda: int(AddressType.NFT), init=False)
 
 
+def address_from_dict(address: Dict[str, any]) -> Union[Ed25519Address, AliasAddress, NFTAddress]:
+    """Create the typed address class from its dictionary representation.
+    """
+    address_type = AddressType(address['type'])
+
+    if address_type == AddressType.ED25519:
+        return Ed25519Address(address['pubKeyHash'])
+    if address_type == AddressType.ALIAS:
+        return AliasAddress(address['aliasId'])
+    return NFTAddress(address['nftId'])
+
+
 @dataclass
 class AccountAddress():
     """An Address of the Account.
@@ -73,6 +99,8 @@ class AccountAddress():
     keyIndex: int
     internal: bool
     used: bool
+    frozen: bool = False
+    label: Optional[str] = None
 
 
 @dataclass
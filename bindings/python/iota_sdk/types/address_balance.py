@@ -0,0 +1,38 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from dataclasses import dataclass
+from typing import List
+from iota_sdk.types.native_token import NativeToken
+
+
+@dataclass
+class AddressBalance():
+    """The confirmed balance of an address.
+
+    Attributes:
+        address: The Bech32 address.
+        baseCoin: The base coin amount of the outputs owned by the address.
+        nativeTokens: The native tokens of the outputs owned by the address.
+        basicOutputs: The number of basic outputs with the address in their address unlock condition.
+        aliasOutputs: The number of alias outputs with the address as state controller.
+        nftOutputs: The number of NFT outputs with the address in their address unlock condition.
+    """
+    address: str
+    baseCoin: str
+    nativeTokens: List[NativeToken]
+    basicOutputs: int
+    aliasOutputs: int
+    nftOutputs: int
+
+
+@dataclass
+class AddressBalances():
+    """The result of `Client.get_address_balances()`.
+
+    Attributes:
+        ledgerIndex: The ledger index at which the output IDs were queried.
+        balances: The balances, in the order of the requested addresses.
+    """
+    ledgerIndex: int
+    balances: List[AddressBalance]
@@ -2,7 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from __future__ import annotations
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import List, Optional
 from iota_sdk.types.common import HexStr
 
@@ -14,9 +14,39 @@ class BaseCoinBalance:
     Attributes:
         total: The total balance.
         available: The available amount of the total balance.
+        timelocked: The amount that can only be unlocked at a later time because of a timelock or an expiration.
+        expiringSoon: The claimable amount that will expire back to the sender within a day.
+        storageDepositReturn: The claimable amount that needs to be returned because of a storage deposit return.
+        frozen: The amount of outputs of frozen addresses.
     """
     total: str
     available: str
+    timelocked: str
+    expiringSoon: str
+    storageDepositReturn: str
+    frozen: str = '0'
+
+
+@dataclass
+class TimeBoundAmount:
+    """Base coin amount of an output that can only be unlocked in a certain time frame.
+
+    Attributes:
+        outputId: The output id.
+        amount: The amount without a storage deposit that needs to be returned.
+        unlockableFrom: The milestone timestamp from which on the output can be unlocked.
+        unlockableUntil: The milestone timestamp from which on the output can't be unlocked anymore.
+    """
+    outputId: HexStr
+    amount: str
+    unlockableFrom: Optional[int] = None
+    unlockableUntil: Optional[int] = None
+
+    def is_unlockable_at(self, timestamp: int) -> bool:
+        """Returns whether the output can be unlocked at the given milestone timestamp.
+        """
+        return (self.unlockableFrom is None or timestamp >= self.unlockableFrom) and (
+            self.unlockableUntil is None or timestamp < self.unlockableUntil)
 
 
 @dataclass
@@ -63,6 +93,8 @@ class Balance:
         aliases: All owned aliases.
         foundries: All owned foundries.
         potentiallyLockedOutputs: A list of potentially locked outputs.
+        timeBoundAmounts: Amounts of outputs that can only be unlocked in a certain time frame.
+        frozenOutputs: Outputs of frozen addresses, they're part of the total, but not available.
     """
     baseCoin: BaseCoinBalance
     requiredStorageDeposit: RequiredStorageDeposit
@@ -71,6 +103,15 @@ class Balance:
     aliases: List[HexStr]
     foundries: List[HexStr]
     potentiallyLockedOutputs: dict[HexStr, bool]
+    timeBoundAmounts: List[TimeBoundAmount]
+    frozenOutputs: List[HexStr] = field(default_factory=list)
+
+    def available_at(self, timestamp: int) -> int:
+        """Returns the base coin amount that is expected to be available at the given milestone timestamp.
+        """
+        return int(self.baseCoin.available) + sum(int(time_bound_amount.amount)
+                                                  for time_bound_amount in self.timeBoundAmounts
+                                                  if time_bound_amount.is_unlockable_at(timestamp))
 
     def as_dict(self):
         config = {k: v for k, v in self.__dict__.items()}
@@ -79,5 +120,7 @@ class Balance:
         config['requiredStorageDeposit'] = config['requiredStorageDeposit'].__dict__
         config['nativeTokens'] = [nt.__dict__
                                   for nt in config['nativeTokens']]
+        config['timeBoundAmounts'] = [amount.__dict__
+                                      for amount in config['timeBoundAmounts']]
 
         return config
@@ -41,6 +41,36 @@ class MqttBrokerOptions:
         return {k: v for k, v in self.__dict__.items() if v is not None}
 
 
+@dataclass
+class RetryPolicy:
+    """Decides if and when a failed request is attempted again.
+
+        Attributes:
+        maxAttempts (int):
+            The maximum number of attempts, including the first one. 1 disables retrying.
+        initialDelay (Duration):
+            The delay before the first retry.
+        maxDelay (Duration):
+            The upper bound of the delay between two attempts.
+        backoff (str):
+            How the delay grows with every retry, one of `constant`, `linear` and `exponential`.
+        retryable (List[str]):
+            The categories of errors that are retried, of `network`, `protocol`, `signing`, `storage` and `validation`.
+        deadline (Duration):
+            The time after the first attempt after which no further attempt is started.
+    """
+    maxAttempts: Optional[int] = None
+    initialDelay: Optional[Duration] = None
+    maxDelay: Optional[Duration] = None
+    backoff: Optional[str] = None
+    retryable: Optional[List[str]] = None
+    deadline: Optional[Duration] = None
+
+    def as_dict(self):
+        return {k: v.__dict__ if isinstance(v, Duration) else v for k,
+                v in self.__dict__.items() if v is not None}
+
+
 @dataclass
 class ClientOptions:
     """Client options.
@@ -57,6 +87,8 @@ class ClientOptions:
             Permanode URL.
         ignoreNodeHealth (bool):
             If the node health should be ignored.
+        offline (bool):
+            If no node should be contacted, requires `protocolParameters` and `fixedProtocolParameters`.
         nodeSyncInterval (Duration):
             Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::NetworkInfo) gets updated.
         quorum (bool):
@@ -66,12 +98,16 @@ class ClientOptions:
             Minimum amount of nodes required for request when quorum is enabled.
         quorumThreshold (int):
             % of nodes that have to return the same response so it gets accepted.
+        broadcast (bool):
+            If blocks with finished PoW are posted to all healthy nodes at once instead of one node after another.
         userAgent (str):
             The User-Agent header for requests.
         brokerOptions (MqttBrokerOptions):
             Options for the MQTT broker.
         protocolParameters (NodeInfoProtocol):
             Protocol parameters.
+        fixedProtocolParameters (bool):
+            Whether the protocol parameters are used as they are, instead of being updated from the nodes.
         localPow (bool):
             Local proof of work.
         fallbackToLocalPow (bool):
@@ -82,8 +118,12 @@ class ClientOptions:
             Timeout for API requests.
         remotePowTimeout (Duration):
             Timeout when sending a block that requires remote proof of work.
+        retryPolicy (RetryPolicy):
+            How failed requests are retried.
         powWorkerCount (int):
             The amount of threads to be used for proof of work.
+        powWorkerNiceness (int):
+            The niceness of the threads used for proof of work, from -20 (highest priority) to 19 (lowest priority), only applied on Linux.
         maxParallelApiRequests (int):
             The maximum parallel API requests.
     """
@@ -92,19 +132,24 @@ class ClientOptions:
     nodes: Optional[List[str]] = None
     permanodes: Optional[List[str]] = None
     ignoreNodeHealth: Optional[bool] = None
+    offline: Optional[bool] = None
     nodeSyncInterval: Optional[Duration] = None
     quorum: Optional[bool] = None
     minQuorumSize: Optional[int] = None
     quorumThreshold: Optional[int] = None
+    broadcast: Optional[bool] = None
     userAgent: Optional[str] = None
     brokerOptions: Optional[MqttBrokerOptions] = None
     protocolParameters: Optional[NodeInfoProtocol] = None
+    fixedProtocolParameters: Optional[bool] = None
     localPow: Optional[bool] = None
     fallbackToLocalPow: Optional[bool] = None
     tipsInterval: Optional[int] = None
     apiTimeout: Optional[Duration] = None
     remotePowTimeout: Optional[Duration] = None
+    retryPolicy: Optional[RetryPolicy] = None
     powWorkerCount: Optional[int] = None
+    powWorkerNiceness: Optional[int] = None
     maxParallelApiRequests: Optional[int] = None
 
     def as_dict(self):
@@ -112,5 +157,7 @@ class ClientOptions:
 
         if 'brokerOptions' in config:
             config['brokerOptions'] = config['brokerOptions'].as_dict()
+        if 'retryPolicy' in config:
+            config['retryPolicy'] = config['retryPolicy'].as_dict()
 
         return config
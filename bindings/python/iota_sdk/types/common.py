@@ -1,7 +1,7 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from enum import IntEnum
+from enum import Enum, IntEnum
 from typing import NewType
 
 HexStr = NewType("HexStr", str)
@@ -23,6 +23,28 @@ class CoinType(IntEnum):
         return self.value
 
 
+class ParseMode(str, Enum):
+    """How strictly user-provided addresses and IDs are parsed.
+
+    Attributes:
+        Lenient: Accepts hex without the `0x` prefix, uppercase hex and bech32, surrounding whitespace and hex Ed25519 addresses.
+        Strict: Only accepts `0x`-prefixed lowercase hex and lowercase bech32 addresses.
+    """
+    Lenient = 'lenient'
+    Strict = 'strict'
+
+
+class MnemonicLanguage(str, Enum):
+    """The language of a BIP39 wordlist.
+
+    Attributes:
+        English: English.
+        Japanese: Japanese, the words are separated by ideographic spaces.
+    """
+    English = 'english'
+    Japanese = 'japanese'
+
+
 class Node():
     """Represents a node in the network.
     """
@@ -14,6 +14,10 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        SyncProgress (6): A progress update while syncing all accounts.
+        PaymentReceived (7): A registered payment request was paid.
+        SyncReport (8): What a sync of an account did and how long it took.
+        Reorg (9): A transaction of received outputs isn't included anymore and was rolled back.
     """
     ConsolidationRequired = 0,
     LedgerAddressGeneration = 1,
@@ -21,3 +25,7 @@ class WalletEventType(IntEnum):
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    SyncProgress = 6,
+    PaymentReceived = 7,
+    SyncReport = 8,
+    Reorg = 9,
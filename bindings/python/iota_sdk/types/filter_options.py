@@ -4,6 +4,8 @@
 from __future__ import annotations
 from dataclasses import dataclass
 from typing import List, Optional
+from iota_sdk.types.common import HexStr
+from iota_sdk.types.transaction import InclusionState
 
 
 @dataclass
@@ -17,3 +19,39 @@ class FilterOptions:
     aliasIds: Optional[List[str]] = None
     foundryIds: Optional[List[str]] = None
     nftIds: Optional[List[str]] = None
+
+
+@dataclass
+class TransactionFilterOptions:
+    """Options to filter the sent and incoming transactions of an account.
+
+    Attributes:
+        incoming: Return only incoming (True) or only outgoing (False) transactions.
+        inclusionStates: Return only transactions with one of these inclusion states.
+        lowerBoundTimestamp: Filter all transactions where the timestamp is below the specified timestamp in milliseconds.
+        upperBoundTimestamp: Filter all transactions where the timestamp is above the specified timestamp in milliseconds.
+        minAmount: Filter all transactions where the summed amount of the outputs is below the specified amount.
+        maxAmount: Filter all transactions where the summed amount of the outputs is above the specified amount.
+        hasTaggedData: Return only transactions with (True) or without (False) a tagged data payload.
+        tagPrefix: Return only transactions with a tagged data payload whose tag starts with these hex encoded bytes.
+        note: Return only transactions with a note containing this text, ignoring case.
+    """
+
+    incoming: Optional[bool] = None
+    inclusionStates: Optional[List[InclusionState]] = None
+    lowerBoundTimestamp: Optional[int] = None
+    upperBoundTimestamp: Optional[int] = None
+    minAmount: Optional[int] = None
+    maxAmount: Optional[int] = None
+    hasTaggedData: Optional[bool] = None
+    tagPrefix: Optional[HexStr] = None
+    note: Optional[str] = None
+
+    def as_dict(self):
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+
+        for amount in ['minAmount', 'maxAmount']:
+            if amount in config:
+                config[amount] = str(config[amount])
+
+        return config
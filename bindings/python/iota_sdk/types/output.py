@@ -36,6 +36,11 @@ class Output():
     """
     type: int
 
+    def get_type(self) -> OutputType:
+        """Returns the output type variant.
+        """
+        return OutputType(self.type)
+
     def as_dict(self):
         config = {k: v for k, v in self.__dict__.items() if v is not None}
 
@@ -243,6 +248,11 @@ class OutputMetadata:
             setattr(obj, k, v)
         return obj
 
+    def is_spent(self) -> bool:
+        """Whether the output is already spent.
+        """
+        return self.isSpent
+
     def as_dict(self):
         return {k: v for k, v in self.__dict__.items() if v is not None}
 
@@ -267,6 +277,11 @@ class OutputWithMetadata:
             setattr(obj, k, v)
         return obj
 
+    def is_spent(self) -> bool:
+        """Whether the output is already spent.
+        """
+        return self.metadata.isSpent
+
     def as_dict(self):
         config = dict()
 
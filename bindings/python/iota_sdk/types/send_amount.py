@@ -0,0 +1,59 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from dataclasses import dataclass
+from typing import List, Optional
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class SendAmountOptions():
+    """Options for sending an amount with `Client.send_amount()`.
+
+    Attributes:
+        coinType: The coin type of the addresses the inputs are selected from, Shimmer by default.
+        accountIndex: The account index of the addresses the inputs are selected from, 0 by default.
+        inputRange: The address indexes the inputs are selected from, `range(0, 100)` by default.
+        remainderAddress: The address the remainder is sent to, by default the first address of the input range.
+        retryInterval: The interval in seconds between the checks if the block was included.
+        maxRetryAttempts: The maximum number of checks if the block was included.
+    """
+    coinType: Optional[int] = None
+    accountIndex: Optional[int] = None
+    inputRange: Optional[range] = None
+    remainderAddress: Optional[str] = None
+    retryInterval: Optional[int] = None
+    maxRetryAttempts: Optional[int] = None
+
+    def as_dict(self):
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+
+        if 'inputRange' in config:
+            config['inputRange'] = {
+                'start': config['inputRange'].start,
+                'end': config['inputRange'].stop
+            }
+
+        return config
+
+
+@dataclass
+class SendAmountResult():
+    """The result of `Client.send_amount()`.
+
+    Attributes:
+        transactionId: The ID of the transaction.
+        blockId: The ID of the block the transaction was posted with.
+        includedBlockId: The ID of the block that got included, the posted block or one of its reattachments.
+        reattachedBlockIds: The IDs of the blocks the transaction was reattached with.
+        inputs: The IDs of the spent outputs.
+        outputId: The ID of the output that holds the sent amount.
+        remainderOutputId: The ID of the output that holds the remainder, if the inputs had more funds than the amount.
+    """
+    transactionId: HexStr
+    blockId: HexStr
+    includedBlockId: HexStr
+    reattachedBlockIds: List[HexStr]
+    inputs: List[HexStr]
+    outputId: HexStr
+    remainderOutputId: Optional[HexStr] = None
@@ -3,6 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
+from enum import Enum
 from typing import Optional, List
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.native_token import NativeToken
@@ -19,11 +20,15 @@ class SendParams():
         expiration: Expiration in seconds, after which the output will be available for the sender again, if not spent by the
         receiver already. The expiration will only be used if one is necessary given the provided amount. If an
         expiration is needed but not provided, it will default to one day.
+        tag: Hex encoded tag feature of the output, e.g. to identify a withdrawal at the receiver.
+        metadata: Hex encoded metadata feature of the output, e.g. a reference to an invoice.
     """
     address: str
     amount: str
     returnAddress: Optional[str] = None
     expiration: Optional[int] = None
+    tag: Optional[HexStr] = None
+    metadata: Optional[HexStr] = None
 
     def as_dict(self):
         config = {k: v for k, v in self.__dict__.items() if v is not None}
@@ -122,3 +127,31 @@ class CreateAliasOutputParams():
     immutableMetadata: Optional[str] = None
     metadata: Optional[str] = None
     stateMetadata: Optional[str] = None
+
+
+class CarryoverPolicy(str, Enum):
+    """What happens with the native tokens or NFTs of the account when sending all funds.
+
+    Attributes:
+        Transfer: They're sent to the target address too.
+        Keep: They stay in the account, the outputs holding them aren't spent.
+    """
+    Transfer = 'transfer'
+    Keep = 'keep'
+
+
+@dataclass
+class SendAllOptions():
+    """Options for sending all funds of an account.
+
+    Attributes:
+        nativeTokens: What happens with the native tokens of basic outputs, native tokens of NFT outputs always stay with the NFT.
+        nfts: What happens with the NFTs.
+        note: A note stored with the transaction.
+    """
+    nativeTokens: Optional[CarryoverPolicy] = None
+    nfts: Optional[CarryoverPolicy] = None
+    note: Optional[str] = None
+
+    def as_dict(self):
+        return {k: v for k, v in self.__dict__.items() if v is not None}
@@ -25,6 +25,74 @@ class InclusionState(str, Enum):
     UnknownPruned = 'unknownPruned'
 
 
+class AddressOwnership(str, Enum):
+    """Who owns an input or output of a transaction, from the view of an account.
+
+    Attributes:
+        Internal: An internal address of the account, used for remainders.
+        External: A public address of the account.
+        Foreign: An address that isn't generated by the account.
+    """
+    Internal = 'internal'
+    External = 'external'
+    Foreign = 'foreign'
+
+
+@dataclass
+class TransactionOwnership:
+    """The ownership of the inputs and outputs of a transaction, in the order of its essence.
+
+    Attributes:
+        inputs: The ownership of the inputs.
+        outputs: The ownership of the outputs.
+    """
+    inputs: List[AddressOwnership]
+    outputs: List[AddressOwnership]
+
+
+@dataclass
+class TransactionAmounts:
+    """The base coin amounts a transaction moved, from the view of an account.
+
+    Attributes:
+        sent: The amount sent to foreign addresses, without the remainder.
+        received: The amount received from foreign addresses.
+        selfTransfer: The amount moved between the account's own addresses, if it didn't send to anyone else.
+    """
+    sent: str
+    received: str
+    selfTransfer: str
+
+
+class AttachmentKind(str, Enum):
+    """Why a block was posted for a transaction.
+
+    Attributes:
+        Submission: The transaction was posted for the first time.
+        Reattachment: The transaction was posted in a new block, because the previous one wasn't included.
+        Promotion: An empty block referencing the block of the transaction was posted, so it gets referenced sooner.
+    """
+    Submission = 'submission'
+    Reattachment = 'reattachment'
+    Promotion = 'promotion'
+
+
+@dataclass
+class TransactionAttachment:
+    """A block the wallet posted for a transaction, or tried to.
+
+    Attributes:
+        kind: Why the block was posted.
+        timestamp: The unix timestamp in milliseconds at which the block was posted.
+        blockId: The id of the posted block, not set if posting failed.
+        error: Why posting the block failed.
+    """
+    kind: AttachmentKind
+    timestamp: str
+    blockId: Optional[HexStr] = None
+    error: Optional[str] = None
+
+
 @dataclass
 class Transaction:
     """A transaction with some metadata.
@@ -39,6 +107,9 @@ class Transaction:
         inputs: The inputs of the transaction.
         note: A note attached to the transaction.
         blockId: The ID of the block that holds the transaction.
+        ownership: Who owns the inputs and outputs, set once the transaction is confirmed.
+        amounts: The base coin amounts the transaction moved, derived from the ownership.
+        attachmentHistory: The blocks the wallet posted for the transaction, including failed attempts.
     """
     payload: TransactionPayload
     inclusionState: InclusionState
@@ -49,6 +120,9 @@ class Transaction:
     inputs = List[OutputWithMetadata]
     note: Optional[str] = None
     blockId: Optional[HexStr] = None
+    ownership: Optional[TransactionOwnership] = None
+    amounts: Optional[TransactionAmounts] = None
+    attachmentHistory: Optional[List[TransactionAttachment]] = None
 
     @classmethod
     def from_dict(cls, dict: Dict) -> Transaction:
@@ -57,3 +131,33 @@ class Transaction:
         for k, v in dict.items():
             setattr(obj, k, v)
         return obj
+
+
+@dataclass
+class PayoutTransaction:
+    """A transaction sent by `Account.send_batch()`.
+
+    Attributes:
+        transactionId: The ID of the transaction.
+        payouts: The indexes of the params that are paid out by the transaction.
+        amount: The amount sent to the receivers, including storage deposits that will be returned.
+        blockId: The ID of the block that holds the transaction.
+    """
+    transactionId: HexStr
+    payouts: List[int]
+    amount: str
+    blockId: Optional[HexStr] = None
+
+
+@dataclass
+class PayoutReport:
+    """The result of `Account.send_batch()`.
+
+    Attributes:
+        transactions: The sent transactions, in the order of the params they pay out.
+        unsent: The indexes of the params that weren't paid out, because a transaction failed.
+        error: The error of the failed transaction, if not all params were paid out.
+    """
+    transactions: List[PayoutTransaction]
+    unsent: List[int]
+    error: Optional[str] = None
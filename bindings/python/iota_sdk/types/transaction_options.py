@@ -54,27 +54,33 @@ class TransactionOptions():
         tagged_data_payload: An optional tagged data payload.
         custom_inputs: If custom inputs are provided only those are used. If also other additional inputs should be used, `mandatory_inputs` should be used instead.
         mandatory_inputs: Inputs that must be used for the transaction.
+        excluded_outputs: Outputs that must not be used for the transaction, e.g. frozen ones.
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        request_id: An ID to make the submission idempotent, sending again with the same ID returns the already signed transaction.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
                  tagged_data_payload: Optional[TaggedDataPayload] = None,
                  custom_inputs: Optional[List[OutputId]] = None,
                  mandatory_inputs: Optional[List[OutputId]] = None,
+                 excluded_outputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 request_id: Optional[str] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
         self.tagged_data_payload = tagged_data_payload
         self.custom_inputs = custom_inputs
         self.mandatory_inputs = mandatory_inputs
+        self.excluded_outputs = excluded_outputs
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.request_id = request_id
 
     def as_dict(self):
         return dict(self.__dict__)
@@ -5,11 +5,11 @@ from __future__ import annotations
 from iota_sdk import call_utils_method
 from iota_sdk.types.signature import Ed25519Signature
 from iota_sdk.types.address import Address, AddressType, Ed25519Address, AliasAddress, NFTAddress
-from iota_sdk.types.common import HexStr
+from iota_sdk.types.common import HexStr, MnemonicLanguage, ParseMode
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import Output
 from json import dumps, loads
-from typing import TYPE_CHECKING, List
+from typing import TYPE_CHECKING, List, Optional
 from dacite import from_dict
 
 # Required to prevent circular import
@@ -65,6 +65,17 @@ class Utils():
             'bech32Hrp': bech32_hrp
         })
 
+    @staticmethod
+    def parse_address(address: str, bech32_hrp: Optional[str] = None,
+                      mode: ParseMode = ParseMode.Lenient) -> str:
+        """Parse a user-provided bech32 address, or in lenient mode also a hex encoded Ed25519 address. Addresses with another bech32 HRP than the given one are rejected.
+        """
+        return _call_method('parseAddress', {
+            'address': address,
+            'bech32Hrp': bech32_hrp,
+            'mode': mode
+        })
+
     @staticmethod
     def parse_bech32_address(address: str) -> Address:
         """Parse a string into a valid address.
@@ -91,17 +102,47 @@ class Utils():
         })
 
     @staticmethod
-    def generate_mnemonic() -> str:
+    def generate_mnemonic(word_count: Optional[int] = None,
+                          language: Optional[MnemonicLanguage] = None) -> str:
         """Generate a new mnemonic.
+
+        Args:
+            word_count: The number of words: 12, 15, 18, 21 or 24, which is the default.
+            language: The language of the wordlist, English by default.
         """
-        return _call_method('generateMnemonic')
+        return _call_method('generateMnemonic', {
+            'wordCount': word_count,
+            'language': language
+        })
 
     @staticmethod
-    def mnemonic_to_hex_seed(mnemonic: str) -> HexStr:
+    def mnemonic_to_hex_seed(mnemonic: str, passphrase: Optional[str] = None,
+                             language: Optional[MnemonicLanguage] = None) -> HexStr:
         """Convert a mnemonic to a hex encoded seed.
+
+        Args:
+            mnemonic: The mnemonic.
+            passphrase: An optional passphrase, also known as the 25th word, which derives another seed.
+            language: The language of the wordlist, English by default.
         """
         return _call_method('mnemonicToHexSeed', {
-            'mnemonic': mnemonic
+            'mnemonic': mnemonic,
+            'passphrase': passphrase,
+            'language': language
+        })
+
+    @staticmethod
+    def verify_mnemonic(mnemonic: str,
+                        language: Optional[MnemonicLanguage] = None):
+        """Verify that the words of a mnemonic are in the wordlist and that its checksum is valid.
+
+        Args:
+            mnemonic: The mnemonic.
+            language: The language of the wordlist, English by default.
+        """
+        _call_method('verifyMnemonic', {
+            'mnemonic': mnemonic,
+            'language': language
         })
 
     @staticmethod
@@ -6,17 +6,18 @@ from iota_sdk.wallet.prepared_transaction import PreparedTransaction, PreparedCr
 from iota_sdk.wallet.sync_options import SyncOptions
 from iota_sdk.types.address import AccountAddress, AddressWithUnspentOutputs
 from iota_sdk.types.balance import Balance
+from iota_sdk.types.client_options import ClientOptions
 from iota_sdk.types.burn import Burn
 from iota_sdk.types.common import HexStr
-from iota_sdk.types.filter_options import FilterOptions
+from iota_sdk.types.filter_options import FilterOptions, TransactionFilterOptions
 from iota_sdk.types.native_token import NativeToken
 from iota_sdk.types.output_data import OutputData
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import BasicOutput, NftOutput, Output, output_from_dict
 from iota_sdk.types.output_params import OutputParams
 from iota_sdk.types.transaction_data import PreparedTransactionData, SignedTransactionData
-from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams
-from iota_sdk.types.transaction import Transaction
+from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendAllOptions, SendNativeTokensParams, SendNftParams, SendParams
+from iota_sdk.types.transaction import PayoutReport, Transaction
 from iota_sdk.types.transaction_options import TransactionOptions
 from iota_sdk.types.consolidation_params import ConsolidationParams
 from typing import List, Optional, Union
@@ -200,6 +201,16 @@ class Account:
         )
         return [from_dict(AccountAddress, address) for address in addresses]
 
+    def auto_claim_outputs(self, policy=None) -> List[Transaction]:
+        """Claim the outputs that match the policy, in batches.
+        """
+        transactions = self._call_account_method(
+            'autoClaimOutputs', {
+                'policy': policy if policy is not None else {}
+            }
+        )
+        return [Transaction.from_dict(tx) for tx in transactions]
+
     def claimable_outputs(self, outputs_to_claim: List[OutputId]):
         """Get outputs with additional unlock conditions.
         """
@@ -218,6 +229,24 @@ class Account:
             }
         ))
 
+    def get_incoming_transaction(self, transaction_id: HexStr) -> Transaction:
+        """Get the transaction with inputs of an incoming transaction stored in the account.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'getIncomingTransaction', {
+                'transactionId': transaction_id
+            }
+        ))
+
+    def get_foundry_output(self, token_id: HexStr):
+        """Get the foundry output that minted a native token.
+        """
+        return output_from_dict(self._call_account_method(
+            'getFoundryOutput', {
+                'tokenId': token_id
+            }
+        ))
+
     def get_transaction(self, transaction_id: HexStr) -> Transaction:
         """Get transaction.
         """
@@ -283,6 +312,17 @@ class Account:
         )
         return [Transaction.from_dict(tx) for tx in transactions]
 
+    def filter_transactions(
+            self, filter_options: TransactionFilterOptions) -> List[Transaction]:
+        """Returns the sent and incoming transactions of the account which match the filter, newest first.
+        """
+        transactions = self._call_account_method(
+            'filterTransactions', {
+                'filterOptions': filter_options
+            }
+        )
+        return [Transaction.from_dict(tx) for tx in transactions]
+
     def pending_transactions(self):
         """Returns all pending transactions of the account.
         """
@@ -427,6 +467,19 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def replace_transaction(self, transaction_id: HexStr, destination: Optional[str] = None,
+                            options: Optional[TransactionOptions] = None) -> Transaction:
+        """Replaces a pending transaction with a transaction that spends the same inputs, sending its transfers to the
+        destination or back to the account.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'replaceTransaction', {
+                'transactionId': transaction_id,
+                'destination': destination,
+                'options': options
+            }
+        ))
+
     def retry_transaction_until_included(
             self, transaction_id: HexStr, interval=None, max_attempts=None) -> HexStr:
         """Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
@@ -463,6 +516,40 @@ class Account:
             }
         ))
 
+    def send_all(self, address: str, options: Optional[SendAllOptions] = None) -> Transaction:
+        """Send all funds of the account to an address, without a remainder.
+        Outputs with further unlock conditions, of frozen addresses or used in pending transactions aren't spent.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'sendAll', {
+                'address': address,
+                'options': options
+            }
+        ))
+
+    def prepare_send_all(self, address: str, options: Optional[SendAllOptions] = None) -> PreparedTransaction:
+        """Prepare to send all funds of the account to an address, without a remainder.
+        """
+        prepared = self._call_account_method(
+            'prepareSendAll', {
+                'address': address,
+                'options': options
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def send_batch(
+            self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> PayoutReport:
+        """Send base coins to many addresses in as few transactions as possible.
+        The total amount is checked against the available balance before anything is sent.
+        """
+        return from_dict(PayoutReport, self._call_account_method(
+            'sendBatch', {
+                'params': params,
+                'options': options
+            }
+        ))
+
     def send_with_params(
             self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> Transaction:
         """Send base coins to multiple addresses or with additional parameters.
@@ -510,6 +597,26 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def set_address_frozen(self, address: str, frozen: bool):
+        """Freeze or unfreeze an address of the account. Outputs of frozen addresses aren't used as inputs.
+        """
+        return self._call_account_method(
+            'setAddressFrozen', {
+                'address': address,
+                'frozen': frozen
+            }
+        )
+
+    def set_address_label(self, address: str, label: Optional[str] = None):
+        """Set or remove the label of an address of the account.
+        """
+        return self._call_account_method(
+            'setAddressLabel', {
+                'address': address,
+                'label': label
+            }
+        )
+
     def set_alias(self, alias: str):
         """Set alias.
         """
@@ -519,6 +626,43 @@ class Account:
             }
         )
 
+    def set_archived(self, archived: bool):
+        """Set the archived state of the account. Archived accounts aren't synced in the background.
+        """
+        return self._call_account_method(
+            'setArchived', {
+                'archived': archived
+            }
+        )
+
+    def set_client_options(self, client_options: ClientOptions):
+        """Set the client options of an account bound to another network than the wallet.
+        """
+        return self._call_account_method(
+            'setClientOptions', {
+                'clientOptions': client_options.as_dict()
+            }
+        )
+
+    def set_metadata(self, key: str, value: str):
+        """Set a metadata entry of the account.
+        """
+        return self._call_account_method(
+            'setMetadata', {
+                'key': key,
+                'value': value
+            }
+        )
+
+    def remove_metadata(self, key: str):
+        """Remove a metadata entry of the account.
+        """
+        return self._call_account_method(
+            'removeMetadata', {
+                'key': key
+            }
+        )
+
     def set_default_sync_options(self, options: SyncOptions):
         """Set the fallback SyncOptions for account syncing.
         If storage is enabled, will persist during restarts.
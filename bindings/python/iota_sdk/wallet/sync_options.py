@@ -84,11 +84,17 @@ class SyncOptions():
         changes every milestone and calling it twice "at the same time" will not return new data.
         When this is set to true, we will sync anyways, even if it's called 0ms after the last sync
         finished.
+    force_full_sync :
+        Ignore the sync checkpoint and query all outputs of the addresses again, instead of only the
+        ones created since the last sync. The checkpoint is replaced afterwards.
     sync_incoming_transactions :
         Try to sync transactions from incoming outputs with their inputs. Some data may not be obtained
         if it has been pruned.
     sync_pending_transactions :
         Checks pending transactions and promotes/reattaches them if necessary.
+    sync_reorgs :
+        Checks if transactions of recently received outputs are still included, and rolls back the
+        ones that aren't, e.g. after switching to a node with another history.
     account :
         Specifies what outputs should be synced for the Ed25519 addresses from the account.
     alias :
@@ -107,8 +113,10 @@ class SyncOptions():
                  address_start_index: Optional[int] = None,
                  address_start_index_internal: Optional[int] = None,
                  force_syncing: Optional[bool] = None,
+                 force_full_sync: Optional[bool] = None,
                  sync_incoming_transactions: Optional[bool] = None,
                  sync_pending_transactions: Optional[bool] = None,
+                 sync_reorgs: Optional[bool] = None,
                  account: Optional[AccountSyncOptions] = None,
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
@@ -120,8 +128,10 @@ class SyncOptions():
         self.addressStartIndex = address_start_index
         self.addressStartIndexInternal = address_start_index_internal
         self.forceSyncing = force_syncing
+        self.forceFullSync = force_full_sync
         self.syncIncomingTransactions = sync_incoming_transactions
         self.syncPendingTransactions = sync_pending_transactions
+        self.syncReorgs = sync_reorgs
         self.account = account
         self.alias = alias
         self.nft = nft
@@ -5,8 +5,12 @@ from iota_sdk import destroy_wallet, create_wallet, listen_wallet, get_client_fr
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager, SecretManager
 from iota_sdk.types.client_options import ClientOptions
 from iota_sdk.types.address import AccountAddress
+from iota_sdk.types.balance import Balance
 from iota_sdk.wallet.account import Account, _call_method_routine
 from iota_sdk.wallet.sync_options import SyncOptions
+from iota_sdk.types.transaction import Transaction
+from contextlib import contextmanager
+from dacite import from_dict
 from json import dumps
 from typing import Any, Dict, List, Optional, Union
 
@@ -43,12 +47,17 @@ class Wallet():
         return self.handle
 
     def create_account(self, alias: Optional[str] = None, bech32_hrp: Optional[str]
-                       = None, addresses: Optional[AccountAddress] = None) -> Account:
+                       = None, addresses: Optional[AccountAddress] = None, coin_type: Optional[int] = None,
+                       bip44_account_index: Optional[int] = None,
+                       client_options: Optional[ClientOptions] = None) -> Account:
         """Create a new account.
 
         Args:
             alias: The alias of the newaccount.
             bech32_hrp: The Bech32 HRP of the new account.
+            coin_type: The coin type to derive the addresses with, defaults to the coin type of the wallet.
+            bip44_account_index: The BIP44 account index to derive the addresses with, defaults to the account index.
+            client_options: Client options binding the account to another network than the wallet.
 
         Returns:
             An account object.
@@ -58,6 +67,9 @@ class Wallet():
                 'alias': self.__return_str_or_none(alias),
                 'bech32Hrp': self.__return_str_or_none(bech32_hrp),
                 'addresses': addresses,
+                'coinType': coin_type,
+                'bip44AccountIndex': bip44_account_index,
+                'clientOptions': client_options.as_dict() if client_options else None,
             }
         )
         return Account(account_data, self.handle)
@@ -72,6 +84,13 @@ class Wallet():
         )
         return Account(account_data, self.handle)
 
+    def get_account_indexes(self) -> List[int]:
+        """Get the indexes of all accounts.
+        """
+        return self._call_method(
+            'getAccountIndexes'
+        )
+
     def get_client(self):
         """Get the client associated with the wallet.
         """
@@ -120,13 +139,13 @@ class Wallet():
             }
         )
 
-    def change_stronghold_password(self, password: str):
+    def change_stronghold_password(self, current_password: str, new_password: str):
         """Change stronghold password.
         """
         return self._call_method(
             'changeStrongholdPassword', {
-                'currentPassword': password,
-                'newPassword': password
+                'currentPassword': current_password,
+                'newPassword': new_password
             }
         )
 
@@ -157,6 +176,30 @@ class Wallet():
             }
         )
 
+    def recover_pending_submissions(self) -> List[Transaction]:
+        """Recover the transactions that were signed with a request ID, but not stored, because the wallet stopped in
+        between. Should be called after restarting the wallet.
+        """
+        transactions = self._call_method(
+            'recoverPendingSubmissions'
+        )
+        return [Transaction.from_dict(tx) for tx in transactions]
+
+    def remove_account(self, account_id: Union[str, int], force: bool = False,
+                       clear_stronghold_key: bool = False):
+        """Remove an account and all records stored for it.
+        Accounts with funds or pending transactions are only removed if `force` is set.
+        """
+        return self._call_method(
+            'removeAccount', {
+                'accountId': account_id,
+                'options': {
+                    'force': force,
+                    'clearStrongholdKey': clear_stronghold_key,
+                }
+            }
+        )
+
     def remove_latest_account(self):
         """Remove latest account.
         """
@@ -179,7 +222,7 @@ class Wallet():
         )
 
     def set_client_options(self, client_options):
-        """Update the client options for all accounts.
+        """Update the client options for all accounts, except the ones bound to another network.
         """
         return self._call_method(
             'setClientOptions',
@@ -212,6 +255,21 @@ class Wallet():
 
         )
 
+    @contextmanager
+    def stronghold_unlocked(self, password: str):
+        """Set the stronghold password for the duration of a `with` block and clear it afterwards, also if an
+        exception was raised.
+
+        Example:
+            with wallet.stronghold_unlocked(password):
+                account.send(amount, address)
+        """
+        self.set_stronghold_password(password)
+        try:
+            yield self
+        finally:
+            self.clear_stronghold_password()
+
     def set_stronghold_password_clear_interval(
             self, interval_in_milliseconds: int):
         """Set stronghold password clear interval.
@@ -250,6 +308,84 @@ class Wallet():
             'stopBackgroundSync',
         )
 
+    def sync_all(self, options: Optional[SyncOptions] = None) -> Balance:
+        """Sync all accounts that aren't archived concurrently, the most recently active accounts first.
+        A SyncProgress event is emitted after each account.
+        """
+        return from_dict(Balance, self._call_method(
+            'syncAll', {
+                'options': options,
+            }
+        ))
+
+    def start_background_retry(
+            self, options=None, interval_in_milliseconds: Optional[int] = None):
+        """Start promoting and reattaching pending transactions in the background.
+        """
+        return self._call_method(
+            'startBackgroundRetry', {
+                'options': options,
+                'intervalInMilliseconds': interval_in_milliseconds
+            }
+        )
+
+    def stop_background_retry(self):
+        """Stop promoting and reattaching pending transactions in the background.
+        """
+        return self._call_method(
+            'stopBackgroundRetry',
+        )
+
+    def start_ledger_follower(self):
+        """Start applying the UTXO changes of confirmed milestones received with MQTT to the accounts.
+        The accounts should be synced before.
+        """
+        return self._call_method(
+            'startLedgerFollower',
+        )
+
+    def stop_ledger_follower(self):
+        """Stop applying the UTXO changes of confirmed milestones to the accounts.
+        """
+        return self._call_method(
+            'stopLedgerFollower',
+        )
+
+    def start_background_claim(
+            self, policy=None, interval_in_milliseconds: Optional[int] = None):
+        """Start claiming outputs that match the policy in the background.
+        """
+        return self._call_method(
+            'startBackgroundClaim', {
+                'policy': policy,
+                'intervalInMilliseconds': interval_in_milliseconds
+            }
+        )
+
+    def stop_background_claim(self):
+        """Stop claiming outputs in the background.
+        """
+        return self._call_method(
+            'stopBackgroundClaim',
+        )
+
+    def get_ledger_nano_status(self):
+        """Get the status of the Ledger Nano.
+        """
+        return self._call_method(
+            'getLedgerNanoStatus'
+        )
+
+    def update_node_auth(self, url: str, auth=None):
+        """Update the authentication for the provided node.
+        """
+        return self._call_method(
+            'updateNodeAuth', {
+                'url': url,
+                'auth': auth
+            }
+        )
+
     def listen(self, handler, events: Optional[List[int]] = None):
         """Listen to wallet events, empty array or None will listen to all events.
         The default value for events is None.
@@ -273,6 +409,12 @@ class Wallet():
         """
         return destroy_wallet(self.handle)
 
+    def __enter__(self):
+        return self
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        self.destroy()
+
     @staticmethod
     def __return_str_or_none(str):
         if str:
@@ -3,15 +3,23 @@
 
 use iota_sdk_bindings_core::{
     call_client_method as rust_call_client_method,
-    iota_sdk::client::{
-        mqtt::{Error as MqttError, Topic},
-        Client as RustClient, ClientBuilder,
+    iota_sdk::{
+        client::{
+            mqtt::{Error as MqttError, Topic},
+            node_api::indexer::{query_parameters::QueryParameter, OutputIdsPages as RustOutputIdsPages},
+            Client as RustClient, ClientBuilder, Error as ClientError,
+        },
+        types::block::Block,
     },
-    listen_mqtt as rust_listen_mqtt, ClientMethod,
+    listen_mqtt as rust_listen_mqtt, ClientMethod, Response,
+};
+use pyo3::{
+    buffer::PyBuffer,
+    prelude::*,
+    types::{PyBytes, PyTuple},
 };
-use pyo3::{prelude::*, types::PyTuple};
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 #[pyclass]
 pub struct Client {
@@ -40,6 +48,79 @@ pub fn call_client_method(client: &Client, method: String) -> Result<String> {
     Ok(serde_json::to_string(&response)?)
 }
 
+/// Calls a client method that responds with raw bytes, e.g. `getBlockRaw`, and returns them as `bytes` instead of a
+/// list of integers in JSON. Other responses, e.g. errors, are returned as JSON.
+#[pyfunction]
+pub fn call_client_method_raw(py: Python<'_>, client: &Client, method: String) -> Result<PyObject> {
+    let method = serde_json::from_str::<ClientMethod>(&method)?;
+    let response = crate::block_on(async { rust_call_client_method(&client.client, method).await });
+
+    Ok(match response {
+        Response::Raw(bytes) => PyBytes::new(py, &bytes).into(),
+        response => serde_json::to_string(&response)?.into_py(py),
+    })
+}
+
+/// Posts a block given as `bytes`, `bytearray` or `memoryview`. The bytes of a `bytes` object are borrowed instead of
+/// copied.
+#[pyfunction]
+pub fn post_block_raw(client: &Client, block_bytes: &PyAny) -> Result<String> {
+    let copied_bytes;
+    let block_bytes = match block_bytes.downcast::<PyBytes>() {
+        Ok(bytes) => bytes.as_bytes(),
+        Err(_) => {
+            copied_bytes = PyBuffer::<u8>::get(block_bytes)?.to_vec(block_bytes.py())?;
+            &copied_bytes[..]
+        }
+    };
+
+    let block_id = crate::block_on(async {
+        let protocol_parameters = client.client.get_protocol_parameters().await?;
+        let block = Block::unpack_strict(block_bytes, &protocol_parameters).map_err(ClientError::from)?;
+        client.client.post_block_raw(&block).await
+    })?;
+
+    Ok(block_id.to_string())
+}
+
+/// The pages of output IDs of an indexer query, iterating over it requests one page at a time.
+#[pyclass]
+pub struct OutputIdsPages {
+    pages: RustOutputIdsPages,
+}
+
+#[pymethods]
+impl OutputIdsPages {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Returns the next page as JSON.
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Result<Option<String>> {
+        let page = crate::block_on(async { slf.pages.next_page().await })?;
+
+        Ok(page.map(|page| serde_json::to_string(&page)).transpose()?)
+    }
+}
+
+/// Returns the pages of output IDs of an indexer query. The method is the name of the client method that would return
+/// all output IDs at once, e.g. `basicOutputIds`.
+#[pyfunction]
+pub fn output_ids_pages(client: &Client, method: &str, query_parameters: String) -> Result<OutputIdsPages> {
+    let query_parameters = serde_json::from_str::<Vec<QueryParameter>>(&query_parameters)?;
+
+    let pages = match method {
+        "outputIds" => client.client.output_ids_pages(query_parameters)?,
+        "basicOutputIds" => client.client.basic_output_ids_pages(query_parameters)?,
+        "aliasOutputIds" => client.client.alias_output_ids_pages(query_parameters)?,
+        "foundryOutputIds" => client.client.foundry_output_ids_pages(query_parameters)?,
+        "nftOutputIds" => client.client.nft_output_ids_pages(query_parameters)?,
+        _ => return Err(Error::from(format!("unknown output IDs method: {method}"))),
+    };
+
+    Ok(OutputIdsPages { pages })
+}
+
 #[pyfunction]
 pub fn listen_mqtt(client: &Client, topics: Vec<String>, handler: PyObject) -> Result<()> {
     let topics = topics
@@ -4,7 +4,8 @@
 
 import json
 import unittest
-from iota_sdk import Block, Client, MnemonicSecretManager, Utils, SecretManager, OutputId, hex_to_utf8, utf8_to_hex, Bip44, CoinType, Irc27Metadata, Irc30Metadata
+from iota_sdk import Block, Client, MnemonicSecretManager, Utils, SecretManager, OutputId, hex_to_utf8, utf8_to_hex, Bip44, CoinType, Irc27Metadata, Irc30Metadata, Ed25519Address, address_from_dict, ParseMode, MnemonicLanguage
+from iota_sdk.utils import UtilsError
 
 
 # Read the test vector
@@ -88,6 +89,41 @@ class TestTypes(unittest.TestCase):
         with self.assertRaises(ValueError):
             OutputId.from_string(output_id_invalid_hex_prefix)
 
+    def test_parse_address(self):
+        address = Ed25519Address(
+            '0xe594f9a895c0e0a6760dd12cffc2c3d1e1cbf7269b328091f96ce3d0dd550b75')
+        bech32_address = address.to_bech32('rms')
+        assert Utils.parse_address(
+            address.pubKeyHash[2:], 'rms') == bech32_address
+        assert Utils.parse_address(bech32_address.upper()) == bech32_address
+        with self.assertRaises(UtilsError):
+            Utils.parse_address(address.pubKeyHash, 'rms', ParseMode.Strict)
+        with self.assertRaises(UtilsError):
+            Utils.parse_address(bech32_address, 'smr')
+
+    def test_mnemonic(self):
+        mnemonic = Utils.generate_mnemonic(12, MnemonicLanguage.Japanese)
+        Utils.verify_mnemonic(mnemonic, MnemonicLanguage.Japanese)
+        with self.assertRaises(UtilsError):
+            Utils.verify_mnemonic(mnemonic)
+
+        # The last word doesn't match the checksum
+        with self.assertRaises(UtilsError):
+            Utils.verify_mnemonic(' '.join(['abandon'] * 12))
+
+        # Test vector of BIP39
+        mnemonic = ' '.join(['abandon'] * 11 + ['about'])
+        assert Utils.mnemonic_to_hex_seed(mnemonic, 'TREZOR') == '0xc55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04'
+
+
+def test_address_types():
+    address = Ed25519Address(
+        '0xe594f9a895c0e0a6760dd12cffc2c3d1e1cbf7269b328091f96ce3d0dd550b75')
+    assert address.to_bech32(
+        'rms') == Utils.hex_to_bech32(address.pubKeyHash, 'rms')
+    assert address_from_dict(address.as_dict()) == address
+    assert Utils.parse_bech32_address(address.to_bech32('rms')) == address
+
 
 def test_hex_utf8():
     utf8_data = "Don't panic!"
@@ -0,0 +1,71 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+import json
+from unittest.mock import patch
+from iota_sdk.client._high_level_api import HighLevelAPI
+from iota_sdk.client._node_indexer_api import NodeIndexerAPI
+
+TRANSACTION_ID = '0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649'
+
+
+class RecordingHighLevelAPI(HighLevelAPI):
+    """Returns the requested ids instead of sending requests and records them."""
+
+    def __init__(self):
+        self.requests = []
+
+    def get_outputs(self, output_ids):
+        self.requests.append(output_ids)
+        return output_ids
+
+    def find_blocks(self, block_ids):
+        self.requests.append(block_ids)
+        return block_ids
+
+
+class Indexer(NodeIndexerAPI):
+    handle = None
+
+
+def test_iter_outputs_requests_chunks():
+    api = RecordingHighLevelAPI()
+    output_ids = (f'{TRANSACTION_ID}{index:02x}00' for index in range(5))
+
+    outputs = api.iter_outputs(output_ids, chunk_size=2)
+    # Nothing is requested before iterating
+    assert api.requests == []
+    assert next(outputs).endswith('0000')
+    assert len(api.requests) == 1
+
+    assert len(list(outputs)) == 4
+    assert [len(chunk) for chunk in api.requests] == [2, 2, 1]
+
+
+def test_iter_blocks_requests_chunks():
+    api = RecordingHighLevelAPI()
+    block_ids = [f'0x{index:064x}' for index in range(3)]
+
+    assert list(api.iter_blocks(block_ids, chunk_size=3)) == block_ids
+    assert api.requests == [block_ids]
+    assert list(api.iter_blocks([])) == []
+    assert len(api.requests) == 1
+
+
+def test_iter_output_ids_over_pages():
+    output_ids = [f'{TRANSACTION_ID}{index:02x}00' for index in range(3)]
+    pages = [
+        json.dumps({'ledgerIndex': 1, 'cursor': 'c1', 'items': output_ids[:2]}),
+        json.dumps({'ledgerIndex': 1, 'cursor': None, 'items': output_ids[2:]}),
+    ]
+
+    query_parameters = NodeIndexerAPI.QueryParameters(
+        address='rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy')
+    with patch('iota_sdk.client._node_indexer_api.output_ids_pages',
+               return_value=iter(pages)) as output_ids_pages:
+        iterated = [
+            output_id.output_id for output_id in Indexer().iter_basic_output_ids(query_parameters)]
+
+    assert iterated == output_ids
+    output_ids_pages.assert_called_once_with(
+        None, 'basicOutputIds', json.dumps(query_parameters.as_dict()))
@@ -14,9 +14,10 @@ use crate::{
             decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, faucet_command,
             increase_voting_power_command, melt_native_token_command, mint_native_token, mint_nft_command,
             new_address_command, node_info_command, output_command, outputs_command, participation_overview_command,
-            send_command, send_native_token_command, send_nft_command, stop_participating_command, sync_command,
-            transaction_command, transactions_command, unspent_outputs_command, vote_command, voting_output_command,
-            voting_power_command, AccountCli, AccountCommand,
+            prepare_send_command, send_command, send_native_token_command, send_nft_command, sign_transaction_command,
+            stop_participating_command, submit_transaction_command, sync_command, transaction_command,
+            transactions_command, unspent_outputs_command, vote_command, voting_output_command, voting_power_command,
+            AccountCli, AccountCommand,
         },
         account_completion::AccountPromptHelper,
     },
@@ -166,6 +167,11 @@ pub async fn account_prompt_internal(
                         AccountCommand::NodeInfo => node_info_command(account).await,
                         AccountCommand::Output { output_id } => output_command(account, output_id).await,
                         AccountCommand::Outputs => outputs_command(account).await,
+                        AccountCommand::PrepareSend {
+                            address,
+                            amount,
+                            prepared_transaction_path,
+                        } => prepare_send_command(account, address, amount, &prepared_transaction_path).await,
                         AccountCommand::Send {
                             address,
                             amount,
@@ -195,6 +201,16 @@ pub async fn account_prompt_internal(
                             gift_storage_deposit,
                         } => send_native_token_command(account, address, token_id, amount, gift_storage_deposit).await,
                         AccountCommand::SendNft { address, nft_id } => send_nft_command(account, address, nft_id).await,
+                        AccountCommand::SignTransaction {
+                            prepared_transaction_path,
+                            signed_transaction_path,
+                        } => {
+                            sign_transaction_command(account, &prepared_transaction_path, &signed_transaction_path)
+                                .await
+                        }
+                        AccountCommand::SubmitTransaction {
+                            signed_transaction_path,
+                        } => submit_transaction_command(account, &signed_transaction_path).await,
                         AccountCommand::Switch { account_id } => {
                             return Ok(AccountPromptResponse::Switch(wallet.get_account(account_id).await?));
                         }
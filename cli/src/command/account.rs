@@ -5,7 +5,10 @@ use std::str::FromStr;
 
 use clap::{CommandFactory, Parser, Subcommand};
 use iota_sdk::{
-    client::request_funds_from_faucet,
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
+        request_funds_from_faucet,
+    },
     types::{
         api::plugins::participation::types::ParticipationEventId,
         block::{
@@ -17,6 +20,7 @@ use iota_sdk::{
             payload::transaction::TransactionId,
             ConvertTo,
         },
+        TryFromDto,
     },
     wallet::{
         account::{
@@ -162,6 +166,16 @@ pub enum AccountCommand {
     },
     /// List all outputs.
     Outputs,
+    /// Prepare sending an amount and write the prepared transaction to a JSON file, to sign it with
+    /// `sign-transaction`, e.g. on an offline device.
+    PrepareSend {
+        /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// Amount to send, e.g. 1000000.
+        amount: u64,
+        /// Path of the created prepared transaction file, e.g. ./prepared_transaction.json.
+        prepared_transaction_path: String,
+    },
     /// Send an amount.
     Send {
         /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
@@ -203,6 +217,19 @@ pub enum AccountCommand {
         /// NFT ID to be sent, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
         nft_id: String,
     },
+    /// Sign a prepared transaction read from a JSON file and write the signed transaction to a JSON file, to submit
+    /// it with `submit-transaction`.
+    SignTransaction {
+        /// Path of the prepared transaction file, e.g. ./prepared_transaction.json.
+        prepared_transaction_path: String,
+        /// Path of the created signed transaction file, e.g. ./signed_transaction.json.
+        signed_transaction_path: String,
+    },
+    /// Submit a signed transaction read from a JSON file.
+    SubmitTransaction {
+        /// Path of the signed transaction file, e.g. ./signed_transaction.json.
+        signed_transaction_path: String,
+    },
     /// Switch to a different account.
     Switch {
         /// The identifier (alias or index) of the account you want to switch to.
@@ -702,6 +729,67 @@ pub async fn send_command(
     Ok(())
 }
 
+// `prepare-send` command
+pub async fn prepare_send_command(
+    account: &Account,
+    address: impl ConvertTo<Bech32Address>,
+    amount: u64,
+    prepared_transaction_path: &str,
+) -> Result<(), Error> {
+    let prepared_transaction = account.prepare_send([SendParams::new(amount, address)?], None).await?;
+
+    let json = serde_json::to_string_pretty(&PreparedTransactionDataDto::from(&prepared_transaction))?;
+    tokio::fs::write(prepared_transaction_path, json).await?;
+
+    println_log_info!("Prepared transaction written to {prepared_transaction_path}");
+
+    Ok(())
+}
+
+// `sign-transaction` command
+pub async fn sign_transaction_command(
+    account: &Account,
+    prepared_transaction_path: &str,
+    signed_transaction_path: &str,
+) -> Result<(), Error> {
+    let json = tokio::fs::read_to_string(prepared_transaction_path).await?;
+    let prepared_transaction = PreparedTransactionData::try_from_dto_with_params(
+        serde_json::from_str::<PreparedTransactionDataDto>(&json)?,
+        account.client().get_protocol_parameters().await?,
+    )?;
+
+    let signed_transaction = account.sign_transaction_essence(&prepared_transaction).await?;
+
+    let json = serde_json::to_string_pretty(&SignedTransactionDataDto::from(&signed_transaction))?;
+    tokio::fs::write(signed_transaction_path, json).await?;
+
+    println_log_info!(
+        "Transaction {} signed and written to {signed_transaction_path}",
+        signed_transaction.transaction_payload.id()
+    );
+
+    Ok(())
+}
+
+// `submit-transaction` command
+pub async fn submit_transaction_command(account: &Account, signed_transaction_path: &str) -> Result<(), Error> {
+    let json = tokio::fs::read_to_string(signed_transaction_path).await?;
+    let signed_transaction = SignedTransactionData::try_from_dto_with_params(
+        serde_json::from_str::<SignedTransactionDataDto>(&json)?,
+        account.client().get_protocol_parameters().await?,
+    )?;
+
+    let transaction = account.submit_and_store_transaction(signed_transaction, None).await?;
+
+    println_log_info!(
+        "Transaction sent:\n{:?}\n{:?}",
+        transaction.transaction_id,
+        transaction.block_id
+    );
+
+    Ok(())
+}
+
 // `send-native-token` command
 pub async fn send_native_token_command(
     account: &Account,
@@ -815,7 +903,14 @@ pub async fn transactions_command(account: &Account, show_details: bool) -> Resu
                 let transaction_time = to_utc_date_time(tx.timestamp)?;
                 let formatted_time = transaction_time.format("%Y-%m-%d %H:%M:%S").to_string();
 
-                println_log_info!("{:<5}{}\t{}", i, tx.transaction_id, formatted_time);
+                let amounts = match tx.amounts() {
+                    Some(amounts) if amounts.self_transfer > 0 => format!("self-transfer {}", amounts.self_transfer),
+                    Some(amounts) if amounts.received > 0 => format!("received {}", amounts.received),
+                    Some(amounts) => format!("sent {}", amounts.sent),
+                    None => String::new(),
+                };
+
+                println_log_info!("{:<5}{}\t{}\t{}", i, tx.transaction_id, formatted_time, amounts);
             }
         }
     }
@@ -992,3 +1087,112 @@ async fn print_address(account: &Account, address: &AccountAddress) -> Result<()
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        wallet::{ClientOptions, Wallet},
+    };
+
+    use super::*;
+
+    const ADDRESS: &str = "rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3";
+
+    fn parse(command: &str) -> AccountCommand {
+        // Parsed like in the account prompt
+        AccountCli::try_parse_from(format!("Account: {command}").split_whitespace())
+            .unwrap()
+            .command
+    }
+
+    #[test]
+    fn offline_signing_commands() {
+        match parse(&format!("prepare-send {ADDRESS} 1000000 ./prepared.json")) {
+            AccountCommand::PrepareSend {
+                address,
+                amount,
+                prepared_transaction_path,
+            } => {
+                assert_eq!(address.to_string(), ADDRESS);
+                assert_eq!(amount, 1_000_000);
+                assert_eq!(prepared_transaction_path, "./prepared.json");
+            }
+            command => panic!("unexpected command {command:?}"),
+        }
+        assert!(matches!(
+            parse("sign-transaction ./prepared.json ./signed.json"),
+            AccountCommand::SignTransaction {
+                prepared_transaction_path,
+                signed_transaction_path,
+            } if prepared_transaction_path == "./prepared.json" && signed_transaction_path == "./signed.json"
+        ));
+        assert!(matches!(
+            parse("submit-transaction ./signed.json"),
+            AccountCommand::SubmitTransaction { signed_transaction_path } if signed_transaction_path == "./signed.json"
+        ));
+        // The paths are required
+        assert!(AccountCli::try_parse_from(["Account:", "sign-transaction", "./prepared.json"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn invalid_transaction_files() {
+        let storage_path = "test-storage/cli_invalid_transaction_files";
+        std::fs::remove_dir_all(storage_path).ok();
+        let wallet = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(
+                MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic().unwrap()).unwrap(),
+            ))
+            .with_client_options(
+                ClientOptions::new()
+                    .with_node("http://localhost:14265")
+                    .unwrap()
+                    .with_ignore_node_health(),
+            )
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path)
+            .finish()
+            .await
+            .unwrap();
+        let account = wallet.create_account().finish().await.unwrap();
+
+        let prepared_transaction_path = format!("{storage_path}/prepared.json");
+        let signed_transaction_path = format!("{storage_path}/signed.json");
+        // Missing files aren't signed or submitted
+        assert!(
+            sign_transaction_command(&account, &prepared_transaction_path, &signed_transaction_path)
+                .await
+                .is_err()
+        );
+        assert!(
+            submit_transaction_command(&account, &signed_transaction_path)
+                .await
+                .is_err()
+        );
+
+        // Neither are files that don't contain a transaction
+        tokio::fs::write(&prepared_transaction_path, "{}").await.unwrap();
+        tokio::fs::write(&signed_transaction_path, "{}").await.unwrap();
+        assert!(
+            sign_transaction_command(&account, &prepared_transaction_path, &prepared_transaction_path)
+                .await
+                .is_err()
+        );
+        assert!(
+            submit_transaction_command(&account, &signed_transaction_path)
+                .await
+                .is_err()
+        );
+        // The file isn't overwritten if signing fails
+        assert_eq!(
+            tokio::fs::read_to_string(&prepared_transaction_path).await.unwrap(),
+            "{}"
+        );
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
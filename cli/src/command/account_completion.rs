@@ -34,9 +34,12 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "node-info",
     "output",
     "outputs",
+    "prepare-send",
     "send",
     "send-native-token",
     "send-nft",
+    "sign-transaction",
+    "submit-transaction",
     "switch",
     "sync",
     "transaction",
@@ -62,6 +62,8 @@ pub enum WalletCommand {
     },
     /// Change the stronghold password.
     ChangePassword,
+    /// Check which migrations would be applied to the wallet database, without applying them.
+    CheckMigrations,
     /// Initialize the wallet.
     Init(InitParameters),
     /// Migrate a stronghold snapshot v2 to v3.
@@ -103,6 +105,9 @@ pub enum WalletCommand {
         /// The amount of workers that should be used for PoW, default is num_cpus::get().
         #[arg(short, long)]
         worker_count: Option<usize>,
+        /// The niceness of the PoW threads, from -20 to 19, only applied on Linux.
+        #[arg(short, long, allow_negative_numbers = true)]
+        niceness: Option<i32>,
     },
     /// Synchronize all accounts.
     Sync,
@@ -167,6 +172,27 @@ pub async fn change_password_command(storage_path: &Path, snapshot_path: &Path)
     Ok(wallet)
 }
 
+pub async fn check_migrations_command(storage_path: &Path) -> Result<(), Error> {
+    let status = Wallet::builder()
+        .with_storage_path(storage_path.to_str().expect("invalid unicode"))
+        .check_storage_migrations()
+        .await?;
+
+    match &status.current {
+        Some(version) => println_log_info!("Current migration version: {version}"),
+        None => println_log_info!("The wallet database wasn't migrated yet."),
+    }
+    if status.is_up_to_date() {
+        println_log_info!("No migrations pending.");
+    } else {
+        for version in &status.pending {
+            println_log_info!("Pending migration: {version}");
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn init_command(
     storage_path: &Path,
     snapshot_path: &Path,
@@ -295,6 +321,7 @@ pub async fn set_pow_command(
     snapshot_path: &Path,
     local_pow: bool,
     worker_count: Option<usize>,
+    niceness: Option<i32>,
 ) -> Result<Wallet, Error> {
     let password = get_password("Stronghold password", !snapshot_path.exists())?;
     let wallet = unlock_wallet(storage_path, snapshot_path, password).await?;
@@ -303,7 +330,8 @@ pub async fn set_pow_command(
     let client_options = ClientOptions::new()
         .with_node(node.url.as_ref())?
         .with_local_pow(local_pow)
-        .with_pow_worker_count(worker_count);
+        .with_pow_worker_count(worker_count)
+        .with_pow_worker_niceness(niceness);
     wallet.set_client_options(client_options).await?;
 
     Ok(wallet)
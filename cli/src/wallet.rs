@@ -7,7 +7,7 @@ use iota_sdk::wallet::{account::types::AccountIdentifier, Wallet};
 
 use crate::{
     command::wallet::{
-        accounts_command, add_account, backup_command, change_password_command, init_command,
+        accounts_command, add_account, backup_command, change_password_command, check_migrations_command, init_command,
         migrate_stronghold_snapshot_v2_to_v3_command, mnemonic_command, new_account_command, node_info_command,
         restore_command, set_node_url_command, set_pow_command, sync_command, unlock_wallet, InitParameters, WalletCli,
         WalletCommand,
@@ -43,6 +43,10 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<Accoun
                 let wallet = change_password_command(storage_path, snapshot_path).await?;
                 (Some(wallet), None)
             }
+            WalletCommand::CheckMigrations => {
+                check_migrations_command(storage_path).await?;
+                return Ok((None, None));
+            }
             WalletCommand::MigrateStrongholdSnapshotV2ToV3 { path } => {
                 migrate_stronghold_snapshot_v2_to_v3_command(path).await?;
                 return Ok((None, None));
@@ -58,8 +62,9 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<Accoun
             WalletCommand::SetPow {
                 local_pow,
                 worker_count,
+                niceness,
             } => {
-                let wallet = set_pow_command(storage_path, snapshot_path, local_pow, worker_count).await?;
+                let wallet = set_pow_command(storage_path, snapshot_path, local_pow, worker_count, niceness).await?;
                 (Some(wallet), None)
             }
             WalletCommand::Sync => {
@@ -0,0 +1,13 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The gRPC service definitions are only compiled when the server is enabled, this requires `protoc` on the `PATH`
+    // or set with the `PROTOC` environment variable.
+    #[cfg(feature = "grpc-server")]
+    tonic_build::configure()
+        .build_client(false)
+        .compile(&["proto/wallet.proto"], &["proto"])?;
+
+    Ok(())
+}
@@ -38,9 +38,9 @@ async fn main() -> Result<()> {
     client
         .subscribe(
             [
-                Topic::new("milestone-info/latest")?,
-                Topic::new("blocks")?,
-                Topic::new(format!("outputs/unlock/address/{address}"))?,
+                Topic::LatestMilestoneInfo,
+                Topic::Blocks,
+                Topic::AddressOutputs(address),
             ],
             move |event| {
                 println!("> Topic: {}", event.topic);
@@ -62,9 +62,9 @@ async fn main() -> Result<()> {
             _ = rx.recv() => {
                 event_count += 1;
                 if event_count == num_events {
-                    client.unsubscribe([Topic::new("milestone-info/latest")?]).await?;
-                    client.unsubscribe([Topic::new("blocks")?]).await?;
-                    client.unsubscribe([Topic::new(format!("outputs/unlock/address/{address}"))?]).await?;
+                    client.unsubscribe([Topic::LatestMilestoneInfo]).await?;
+                    client.unsubscribe([Topic::Blocks]).await?;
+                    client.unsubscribe([Topic::AddressOutputs(address)]).await?;
                     break;
                 }
             }
@@ -0,0 +1,63 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In this example we will spam the network with tagged data blocks and value transactions of our first address.
+//!
+//! Rename `.env.example` to `.env` first, then run the command:
+//! ```sh
+//! cargo run --release --features spammer --example client_spammer [BLOCKS PER SECOND] [SECONDS]
+//! ```
+
+use std::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
+use iota_sdk::client::{
+    constants::SHIMMER_COIN_TYPE,
+    secret::SecretManager,
+    spammer::{SpammerOptions, ValueSpam},
+    Client, Result,
+};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // This example uses secrets in environment variables for simplicity which should not be done in production.
+    // The first address of the mnemonic `MNEMONIC` needs funds for the value transactions.
+    dotenvy::dotenv().ok();
+
+    let blocks_per_second = std::env::args()
+        .nth(1)
+        .map(|s| s.parse::<NonZeroU32>().unwrap())
+        .unwrap_or(NonZeroU32::new(10).unwrap());
+    let seconds = std::env::args().nth(2).map(|s| s.parse::<u64>().unwrap()).unwrap_or(60);
+
+    // Create a node client.
+    let client = Client::builder()
+        .with_node(&std::env::var("NODE_URL").unwrap())?
+        .finish()
+        .await?;
+
+    let secret_manager = SecretManager::try_from_mnemonic(std::env::var("MNEMONIC").unwrap())?;
+
+    let statistics = client
+        .spam(
+            SpammerOptions {
+                blocks_per_second,
+                workers: NonZeroUsize::new(4).unwrap(),
+                duration: Some(Duration::from_secs(seconds)),
+                ..Default::default()
+            },
+            Some(ValueSpam {
+                secret_manager: &secret_manager,
+                coin_type: SHIMMER_COIN_TYPE,
+                account_index: 0,
+            }),
+        )
+        .await?;
+
+    println!("{statistics:#?}");
+    println!("{:.2} blocks per second", statistics.blocks_per_second());
+
+    Ok(())
+}
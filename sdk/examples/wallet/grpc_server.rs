@@ -0,0 +1,52 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In this example we'll serve the wallet over gRPC, so it can be used from other languages. Requests need to provide
+//! the `GRPC_TOKEN` from the environment as bearer token.
+//!
+//! Rename `.env.example` to `.env` first, then run the command:
+//! ```sh
+//! cargo run --release --all-features --example grpc_server
+//! ```
+
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    },
+    wallet::{
+        grpc::{bearer_token_interceptor, GrpcServer},
+        ClientOptions, Result, Wallet,
+    },
+};
+
+// The address the server listens on
+const GRPC_ADDRESS: &str = "127.0.0.1:50051";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // This example uses secrets in environment variables for simplicity which should not be done in production.
+    dotenvy::dotenv().ok();
+
+    let client_options = ClientOptions::new().with_node(&std::env::var("NODE_URL").unwrap())?;
+
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(std::env::var("MNEMONIC").unwrap())?;
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_storage_path(&std::env::var("WALLET_DB_PATH").unwrap())
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .finish()
+        .await?;
+
+    println!("Serving the wallet on {GRPC_ADDRESS}");
+
+    GrpcServer::new(wallet)
+        .await
+        .serve_with_interceptor(
+            GRPC_ADDRESS.parse().unwrap(),
+            bearer_token_interceptor(std::env::var("GRPC_TOKEN").unwrap()),
+        )
+        .await
+}
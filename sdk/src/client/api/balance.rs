@@ -0,0 +1,176 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        node_api::indexer::{
+            query_parameters::QueryParameter,
+            routes::{ALIAS_OUTPUTS_ROUTE, BASIC_OUTPUTS_ROUTE, NFT_OUTPUTS_ROUTE},
+            QueryParameters,
+        },
+        Client, Result,
+    },
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, NativeTokensBuilder, Output, OutputId},
+    },
+};
+
+/// The confirmed balance of an address, returned by [`Client::get_address_balances()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalance {
+    /// The address.
+    pub address: Bech32Address,
+    /// The base coin amount of the outputs owned by the address.
+    #[serde(with = "crate::utils::serde::string")]
+    pub base_coin: u64,
+    /// The native tokens of the outputs owned by the address.
+    pub native_tokens: Vec<NativeToken>,
+    /// The number of basic outputs with the address in their address unlock condition.
+    pub basic_outputs: usize,
+    /// The number of alias outputs with the address as state controller.
+    pub alias_outputs: usize,
+    /// The number of NFT outputs with the address in their address unlock condition.
+    pub nft_outputs: usize,
+}
+
+/// The balances of several addresses, returned by [`Client::get_address_balances()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBalances {
+    /// The ledger index at which the output IDs were queried.
+    pub ledger_index: u32,
+    /// The balances, in the order of the requested addresses.
+    pub balances: Vec<AddressBalance>,
+}
+
+impl Client {
+    /// Returns the confirmed balances of addresses, which don't have to belong to a wallet, with the number of outputs
+    /// of each kind they own. Outputs with additional unlock conditions, e.g. an expiration, are included.
+    ///
+    /// If the node has the indexer plugin, the output IDs of all addresses are queried first and their outputs are
+    /// then requested as a single batch, so outputs shared by several addresses are only requested once. Otherwise
+    /// every address is queried on its own, preferring the permanodes, with the queries running in parallel.
+    pub async fn get_address_balances(&self, addresses: &[Bech32Address]) -> Result<AddressBalances> {
+        // If the routes can't be requested, the node is assumed to have the indexer
+        let has_indexer = self.get_routes().await.map_or(true, |routes| {
+            routes.routes.iter().any(|route| route.starts_with("indexer/"))
+        });
+        let max_parallel_requests = self.request_pool.size().await.max(1);
+
+        let (ledger_index, outputs) = if has_indexer {
+            let address_output_ids = futures::stream::iter(addresses)
+                .map(|address| self.address_output_ids(*address, false))
+                .buffered(max_parallel_requests)
+                .try_collect::<Vec<_>>()
+                .await?;
+            let ledger_index = address_output_ids
+                .iter()
+                .map(|(index, _)| *index)
+                .max()
+                .unwrap_or_default();
+            let output_ids = address_output_ids
+                .iter()
+                .flat_map(|(_, output_ids)| output_ids.iter().copied())
+                .collect::<Vec<_>>();
+            let outputs = self
+                .get_outputs(&output_ids)
+                .await?
+                .into_iter()
+                .map(|output| (*output.metadata().output_id(), output.into_output()))
+                .collect::<HashMap<_, _>>();
+            let outputs = address_output_ids
+                .into_iter()
+                .map(|(_, output_ids)| {
+                    output_ids
+                        .iter()
+                        .filter_map(|output_id| outputs.get(output_id).cloned())
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>();
+            (ledger_index, outputs)
+        } else {
+            let address_outputs = futures::stream::iter(addresses)
+                .map(|address| async move {
+                    let (ledger_index, output_ids) = self.address_output_ids(*address, true).await?;
+                    let outputs = self.get_outputs(&output_ids).await?;
+                    Result::Ok((
+                        ledger_index,
+                        outputs
+                            .into_iter()
+                            .map(|output| output.into_output())
+                            .collect::<Vec<_>>(),
+                    ))
+                })
+                .buffered(max_parallel_requests)
+                .try_collect::<Vec<_>>()
+                .await?;
+            let ledger_index = address_outputs
+                .iter()
+                .map(|(index, _)| *index)
+                .max()
+                .unwrap_or_default();
+            (
+                ledger_index,
+                address_outputs.into_iter().map(|(_, outputs)| outputs).collect(),
+            )
+        };
+
+        let balances = addresses
+            .iter()
+            .zip(outputs)
+            .map(|(address, outputs)| {
+                let mut balance = AddressBalance {
+                    address: *address,
+                    base_coin: 0,
+                    native_tokens: Vec::new(),
+                    basic_outputs: 0,
+                    alias_outputs: 0,
+                    nft_outputs: 0,
+                };
+                let mut native_tokens = NativeTokensBuilder::new();
+                for output in outputs {
+                    balance.base_coin += output.amount();
+                    if let Some(output_native_tokens) = output.native_tokens() {
+                        native_tokens.add_native_tokens(output_native_tokens.clone())?;
+                    }
+                    match output {
+                        Output::Basic(_) => balance.basic_outputs += 1,
+                        Output::Alias(_) => balance.alias_outputs += 1,
+                        Output::Nft(_) => balance.nft_outputs += 1,
+                        Output::Treasury(_) | Output::Foundry(_) => {}
+                    }
+                }
+                balance.native_tokens = native_tokens.finish_vec()?;
+                Ok(balance)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(AddressBalances { ledger_index, balances })
+    }
+
+    /// Returns the ledger index and the IDs of the basic and NFT outputs with the address in their address unlock
+    /// condition and of the alias outputs with the address as state controller.
+    async fn address_output_ids(&self, address: Bech32Address, prefer_permanode: bool) -> Result<(u32, Vec<OutputId>)> {
+        let mut ledger_index = 0;
+        let mut output_ids = Vec::new();
+        for (route, query_parameter) in [
+            (BASIC_OUTPUTS_ROUTE, QueryParameter::Address(address)),
+            (ALIAS_OUTPUTS_ROUTE, QueryParameter::StateController(address)),
+            (NFT_OUTPUTS_ROUTE, QueryParameter::Address(address)),
+        ] {
+            let response = self
+                .get_output_ids(route, QueryParameters::new([query_parameter]), true, prefer_permanode)
+                .await?;
+            ledger_index = ledger_index.max(response.ledger_index);
+            output_ids.extend(response.items);
+        }
+        Ok((ledger_index, output_ids))
+    }
+}
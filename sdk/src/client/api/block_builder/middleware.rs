@@ -0,0 +1,102 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks into the stages of building and submitting a block.
+//!
+//! A [`BlockBuilderMiddleware`] is added to a client with [`ClientInner::add_block_builder_middleware()`] and is called
+//! for every block built by the [`ClientBlockBuilder`](crate::client::api::ClientBlockBuilder) or the wallet, for
+//! example to log transactions, check them against a policy or add a payload. Middleware is called in the order it
+//! was added, the first error aborts the block.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    client::{
+        api::{input_selection::Selected, PreparedTransactionData},
+        ClientInner, Error, Result,
+    },
+    types::block::{parent::Parents, payload::Payload, BlockId},
+};
+
+/// Hooks that are called at the stages of building and submitting a block. All of them do nothing by default.
+#[async_trait]
+pub trait BlockBuilderMiddleware: Send + Sync {
+    /// Called after the inputs of a transaction were selected, returning an error aborts the transaction.
+    async fn after_input_selection(&self, _selected: &Selected) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after the transaction essence was built and before it's signed. The essence can be changed, but the
+    /// inputs must stay the same. Returning an error aborts the transaction.
+    async fn after_essence_build(&self, _prepared_transaction_data: &mut PreparedTransactionData) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called before the PoW of a block, also if it's done by the node. The payload can be changed or added, for
+    /// example a tagged data payload to a block without one. Returning an error aborts the block.
+    async fn before_pow(&self, _parents: Option<&Parents>, _payload: &mut Option<Payload>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called after a block was accepted by the node. The block can't be aborted anymore, so it can't fail.
+    async fn after_submission(&self, _block_id: &BlockId, _payload: Option<&Payload>) {}
+}
+
+impl ClientInner {
+    /// Adds middleware that is called while building and submitting blocks, after the one that was added before.
+    pub async fn add_block_builder_middleware(&self, middleware: impl BlockBuilderMiddleware + 'static) {
+        self.block_builder_middleware.write().await.push(Arc::new(middleware));
+    }
+
+    /// Removes all middleware.
+    pub async fn clear_block_builder_middleware(&self) {
+        self.block_builder_middleware.write().await.clear();
+    }
+
+    /// Returns the middleware without holding the lock while it's called.
+    async fn block_builder_middleware(&self) -> Vec<Arc<dyn BlockBuilderMiddleware>> {
+        self.block_builder_middleware.read().await.clone()
+    }
+
+    pub(crate) async fn run_after_input_selection(&self, selected: &Selected) -> Result<()> {
+        for middleware in self.block_builder_middleware().await {
+            middleware.after_input_selection(selected).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn run_after_essence_build(
+        &self,
+        prepared_transaction_data: &mut PreparedTransactionData,
+    ) -> Result<()> {
+        for middleware in self.block_builder_middleware().await {
+            let inputs = prepared_transaction_data.essence.as_regular().inputs().to_vec();
+            let inputs_data = prepared_transaction_data.inputs_data.clone();
+            middleware.after_essence_build(prepared_transaction_data).await?;
+            // The inputs were selected and locked for this transaction
+            if prepared_transaction_data.essence.as_regular().inputs() != inputs.as_slice()
+                || prepared_transaction_data.inputs_data != inputs_data
+            {
+                return Err(Error::BlockBuilderMiddleware(
+                    "the inputs of the transaction essence were changed".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn run_before_pow(&self, parents: Option<&Parents>, payload: &mut Option<Payload>) -> Result<()> {
+        for middleware in self.block_builder_middleware().await {
+            middleware.before_pow(parents, payload).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn run_after_submission(&self, block_id: &BlockId, payload: Option<&Payload>) {
+        for middleware in self.block_builder_middleware().await {
+            middleware.after_submission(block_id, payload).await;
+        }
+    }
+}
@@ -2,16 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod input_selection;
+pub mod middleware;
 pub mod pow;
+pub mod tips;
 pub mod transaction;
 
 use std::ops::Range;
 
 use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use self::input_selection::BurnDto;
-pub use self::transaction::verify_semantic;
+pub use self::{middleware::BlockBuilderMiddleware, tips::TipSelectionStrategy, transaction::verify_semantic};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -48,6 +51,8 @@ pub struct ClientBlockBuilder<'a> {
     tag: Option<Vec<u8>>,
     data: Option<Vec<u8>>,
     parents: Option<Parents>,
+    tip_selection: TipSelectionStrategy,
+    tips_from: Option<Url>,
     burn: Option<Burn>,
 }
 
@@ -90,6 +95,10 @@ pub struct ClientBlockBuilderOptions {
     pub data: Option<String>,
     /// Parents
     pub parents: Option<Vec<BlockId>>,
+    /// Tip selection strategy, if no parents are set
+    pub tip_selection: Option<TipSelectionStrategy>,
+    /// Url of the node to get the tips from, if no parents are set
+    pub tips_from: Option<String>,
     /// Explicit burning of aliases, nfts, foundries and native tokens
     pub burn: Option<BurnDto>,
 }
@@ -110,6 +119,8 @@ impl<'a> ClientBlockBuilder<'a> {
             tag: None,
             data: None,
             parents: None,
+            tip_selection: TipSelectionStrategy::Default,
+            tips_from: None,
             burn: None,
         }
     }
@@ -225,12 +236,24 @@ impl<'a> ClientBlockBuilder<'a> {
         self
     }
 
-    /// Set 1-8 custom parent block ids
+    /// Set 1-8 custom parent block ids, the tip selection is skipped then
     pub fn with_parents(mut self, parent_ids: impl Into<Option<Vec<BlockId>>>) -> Result<Self> {
         self.parents = parent_ids.into().map(Parents::from_vec).transpose()?;
         Ok(self)
     }
 
+    /// Set the strategy to select the parents if none are set
+    pub fn with_tip_selection(mut self, strategy: TipSelectionStrategy) -> Self {
+        self.tip_selection = strategy;
+        self
+    }
+
+    /// Get the tips from the node with the given url instead of the node pool
+    pub fn with_tips_from(mut self, node_url: &str) -> Result<Self> {
+        self.tips_from.replace(Url::parse(node_url)?);
+        Ok(self)
+    }
+
     /// Set multiple options from client block builder options type
     /// Useful for bindings
     pub async fn set_options(mut self, options: ClientBlockBuilderOptions) -> Result<ClientBlockBuilder<'a>> {
@@ -306,6 +329,12 @@ impl<'a> ClientBlockBuilder<'a> {
         if let Some(parents) = options.parents {
             self = self.with_parents(parents)?;
         }
+        if let Some(tip_selection) = options.tip_selection {
+            self = self.with_tip_selection(tip_selection);
+        }
+        if let Some(tips_from) = options.tips_from {
+            self = self.with_tips_from(&tips_from)?;
+        }
         if let Some(burn) = options.burn {
             self = self.with_burn(Burn::from(burn));
         }
@@ -358,9 +387,19 @@ impl<'a> ClientBlockBuilder<'a> {
 
     /// Builds the final block and posts it to the node
     pub async fn finish_block(self, payload: Option<Payload>) -> Result<Block> {
-        // Do not replace parents with the latest tips if they are set explicitly,
-        // necessary for block promotion.
-        let final_block = self.client.finish_block_builder(self.parents, payload).await?;
+        // Only select the parents here if the tips need to be processed or come from another node, otherwise
+        // `finish_block_builder()` keeps fetching the latest tips while doing PoW.
+        let parents = match self.parents {
+            None if self.tip_selection != TipSelectionStrategy::Default || self.tips_from.is_some() => Some(
+                self.client
+                    .select_parents(&self.tip_selection, self.tips_from.as_ref())
+                    .await?,
+            ),
+            // Do not replace parents with the latest tips if they are set explicitly,
+            // necessary for block promotion.
+            parents => parents,
+        };
+        let final_block = self.client.finish_block_builder(parents, payload).await?;
 
         let block_id = self.client.post_block_raw(&final_block).await?;
         // Get block if we use remote PoW, because the node will change parents and nonce
@@ -374,7 +413,7 @@ impl<'a> ClientBlockBuilder<'a> {
                     return Ok(block);
                 }
                 #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(std::time::Duration::from_millis(time * 50)).await;
+                crate::client::runtime::sleep(std::time::Duration::from_millis(time * 50)).await;
                 #[cfg(target_family = "wasm")]
                 gloo_timers::future::TimeoutFuture::new((time * 50).try_into().unwrap()).await;
             }
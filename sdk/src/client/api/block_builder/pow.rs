@@ -3,19 +3,158 @@
 
 //! PoW functions.
 
+use std::sync::Arc;
+
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use url::Url;
+
 #[cfg(not(target_family = "wasm"))]
 use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
 #[cfg(target_family = "wasm")]
 use crate::pow::wasm_miner::{SingleThreadedMiner, SingleThreadedMinerBuilder};
 use crate::{
-    client::{ClientInner, Error, Result},
-    types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
+    client::{
+        constants::DEFAULT_MAX_CONCURRENT_POW_JOBS,
+        node_manager::node::{Node, NodeAuth},
+        ClientInner, Error, Result,
+    },
+    pow::score::PowScorer,
+    types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, BlockId, Error as BlockError},
 };
 
+/// A PoW server the client delegates the PoW to, instead of doing it itself or letting the node do it.
+///
+/// The server receives `POST` requests with the JSON body `{"powBytes": "0x...", "targetScore": 1500}`, where the bytes
+/// are the packed block without its nonce, and answers with `{"nonce": "12345"}`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowServerOptions {
+    /// The URL of the PoW endpoint.
+    pub url: Url,
+    /// Authentication for the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<NodeAuth>,
+    /// The maximum number of PoW jobs sent to the server at the same time.
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: usize,
+}
+
+fn default_max_concurrent_jobs() -> usize {
+    DEFAULT_MAX_CONCURRENT_POW_JOBS
+}
+
+impl PowServerOptions {
+    /// Creates options for a PoW server with the default job limit.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            auth: None,
+            max_concurrent_jobs: DEFAULT_MAX_CONCURRENT_POW_JOBS,
+        }
+    }
+
+    /// Sets the authentication for the server.
+    pub fn with_auth(mut self, auth: impl Into<Option<NodeAuth>>) -> Self {
+        self.auth = auth.into();
+        self
+    }
+
+    /// Sets the maximum number of PoW jobs sent to the server at the same time.
+    pub fn with_max_concurrent_jobs(mut self, max_concurrent_jobs: usize) -> Self {
+        self.max_concurrent_jobs = max_concurrent_jobs;
+        self
+    }
+}
+
+/// A thread pool the local PoW is done on, instead of spawning threads for it.
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[derive(Clone)]
+pub struct PowThreadPool(Arc<rayon::ThreadPool>);
+
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+impl PowThreadPool {
+    /// Creates a new [`PowThreadPool`].
+    pub fn new(thread_pool: impl Into<Arc<rayon::ThreadPool>>) -> Self {
+        Self(thread_pool.into())
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+impl std::fmt::Debug for PowThreadPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PowThreadPool").field(&self.0).finish()
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+impl PartialEq for PowThreadPool {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+impl Eq for PowThreadPool {}
+
+/// A PoW server with the permits for its concurrent jobs.
+#[derive(Clone, Debug)]
+pub(crate) struct PowServer {
+    pub(crate) options: PowServerOptions,
+    jobs: Arc<Semaphore>,
+}
+
+impl From<PowServerOptions> for PowServer {
+    fn from(options: PowServerOptions) -> Self {
+        Self {
+            jobs: Arc::new(Semaphore::new(options.max_concurrent_jobs.max(1))),
+            options,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PowServerRequest {
+    pow_bytes: String,
+    target_score: u32,
+}
+
+/// Verifies that the payload fits into a block with the given parents, or with the maximum number of parents if they
+/// aren't selected yet, so no PoW is done for a block the node would reject.
+pub fn validate_payload_length(payload: &Payload, parents: Option<&Parents>) -> Result<()> {
+    let parents_count = parents.map_or(*Parents::COUNT_RANGE.end() as usize, Parents::len);
+    // The minimum length of a block includes a single parent and an empty payload
+    let max = Block::LENGTH_MAX - Block::LENGTH_MIN - (parents_count - 1) * BlockId::LENGTH;
+    let actual = payload.packed_len();
+
+    if actual > max {
+        return Err(Error::PayloadTooLarge { max, actual });
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct PowServerResponse {
+    nonce: String,
+}
+
 impl ClientInner {
-    /// Finishes the block with local PoW if needed.
+    /// Finishes the block with local PoW if needed, after calling the block builder middleware.
     /// Without local PoW, it will finish the block with a 0 nonce.
-    pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+    pub async fn finish_block_builder(&self, parents: Option<Parents>, mut payload: Option<Payload>) -> Result<Block> {
+        self.run_before_pow(parents.as_ref(), &mut payload).await?;
+
+        if let Some(payload) = &payload {
+            validate_payload_length(payload, parents.as_ref())?;
+            if let Payload::Transaction(transaction) = payload {
+                self.check_transaction_network(transaction).await?;
+            }
+        }
+
         if self.get_local_pow().await {
             self.finish_pow(parents, payload).await
         } else {
@@ -29,8 +168,13 @@ impl ClientInner {
         }
     }
 
-    /// Calls the appropriate PoW function depending whether the compilation is for wasm or not.
+    /// Calls the appropriate PoW function depending whether a PoW server is used and the compilation is for wasm or
+    /// not.
     pub async fn finish_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        if let Some(pow_server) = self.pow_server.read().await.clone() {
+            return self.finish_pow_server_pow(&pow_server, parents, payload).await;
+        }
+
         #[cfg(not(target_family = "wasm"))]
         let block = self.finish_multi_threaded_pow(parents, payload).await?;
         #[cfg(target_family = "wasm")]
@@ -39,12 +183,84 @@ impl ClientInner {
         Ok(block)
     }
 
+    /// Lets the PoW server find the nonce, waits if the maximum number of concurrent jobs is reached. The nonce is
+    /// verified before the block is returned.
+    async fn finish_pow_server_pow(
+        &self,
+        pow_server: &PowServer,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+    ) -> Result<Block> {
+        let (offline, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.offline, node_manager.http_client.clone())
+        };
+        if offline {
+            return Err(Error::Offline);
+        }
+
+        let _permit = pow_server
+            .jobs
+            .acquire()
+            .await
+            .map_err(|_| Error::PowServer("the job queue is closed".to_string()))?;
+        let min_pow_score = self.get_min_pow_score().await?;
+        let parents = match parents {
+            Some(parents) => parents,
+            None => Parents::from_vec(self.get_tips().await?)?,
+        };
+        let block = BlockBuilder::new(parents.clone())
+            .with_payload(payload.clone())
+            .finish()?;
+        let bytes = block.pack_to_vec();
+        let pow_bytes = &bytes[..bytes.len() - core::mem::size_of::<u64>()];
+
+        // The server is requested like a node, so it gets the same auth handling, transport and user agent
+        let mut server = Node::from(pow_server.options.url.clone());
+        server.auth = pow_server.options.auth.clone();
+        if let Some((name, password)) = server.auth.as_ref().and_then(|auth| auth.basic_auth_name_pwd.as_ref()) {
+            server.url.set_username(name).map_err(|_| Error::UrlAuth("username"))?;
+            server
+                .url
+                .set_password(Some(password))
+                .map_err(|_| Error::UrlAuth("password"))?;
+        }
+        let request = serde_json::to_value(PowServerRequest {
+            pow_bytes: prefix_hex::encode(pow_bytes),
+            target_score: min_pow_score,
+        })?;
+        let nonce = http_client
+            .post_json(server, self.get_remote_pow_timeout().await, request)
+            .await?
+            .into_json::<PowServerResponse>()
+            .await?
+            .nonce
+            .parse::<u64>()
+            .map_err(|_| Error::PowServer("invalid nonce".to_string()))?;
+
+        let block = BlockBuilder::new(parents)
+            .with_payload(payload)
+            .with_nonce(nonce)
+            .finish()?;
+        let score = PowScorer::new().score(&block.pack_to_vec());
+        if score < min_pow_score as f64 {
+            return Err(Error::PowServer(format!(
+                "score {score} of the nonce is below the minimum PoW score {min_pow_score}"
+            )));
+        }
+
+        Ok(block)
+    }
+
     /// Performs multi-threaded proof-of-work.
     ///
     /// Always fetches new tips after each tips interval elapses if no parents are provided.
     #[cfg(not(target_family = "wasm"))]
     async fn finish_multi_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
         let pow_worker_count = *self.pow_worker_count.read().await;
+        let pow_worker_niceness = *self.pow_worker_niceness.read().await;
+        #[cfg(feature = "rayon")]
+        let pow_thread_pool = self.pow_thread_pool.read().await.clone();
         let min_pow_score = self.get_min_pow_score().await?;
         let tips_interval = self.get_tips_interval().await;
 
@@ -52,16 +268,24 @@ impl ClientInner {
             let cancel = MinerCancel::new();
             let cancel_2 = cancel.clone();
             let payload_ = payload.clone();
+            #[cfg(feature = "rayon")]
+            let pow_thread_pool = pow_thread_pool.clone();
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
             let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
             let pow_thread = std::thread::spawn(move || {
-                let mut client_miner = MinerBuilder::new().with_cancel(cancel_2);
+                let mut client_miner = MinerBuilder::new()
+                    .with_cancel(cancel_2)
+                    .with_niceness(pow_worker_niceness);
                 if let Some(worker_count) = pow_worker_count {
                     client_miner = client_miner.with_num_workers(worker_count);
                 }
+                #[cfg(feature = "rayon")]
+                if let Some(PowThreadPool(thread_pool)) = pow_thread_pool {
+                    client_miner = client_miner.with_thread_pool(thread_pool);
+                }
                 do_pow(client_miner.finish(), min_pow_score, payload_, parents).map(Some)
             });
 
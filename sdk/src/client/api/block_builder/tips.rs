@@ -0,0 +1,74 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tip selection for blocks without explicitly set parents.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    client::{ClientInner, Result},
+    types::block::{parent::Parents, BlockId},
+};
+
+/// Strategy to select the parents of a block, if they aren't set explicitly.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TipSelectionStrategy {
+    /// Use the tips of the node.
+    #[default]
+    Default,
+    /// Use the tips of the node, but leave out the ones the node reports as semi-lazy, which should be promoted or
+    /// reattached. Falls back to all tips if every tip is semi-lazy.
+    SemiLazyAvoidance,
+    /// Reference the given block in addition to the tips of the node, so the blocks of an issuer form a chain.
+    #[serde(rename_all = "camelCase")]
+    OwnChain {
+        /// The previous block of the issuer.
+        previous_block_id: BlockId,
+    },
+}
+
+impl ClientInner {
+    /// Selects the parents of a block with the given strategy, using the tips of the node at `tips_from` or of the
+    /// node pool.
+    pub async fn select_parents(&self, strategy: &TipSelectionStrategy, tips_from: Option<&Url>) -> Result<Parents> {
+        let tips = match tips_from {
+            Some(url) => self.get_tips_from(url).await?,
+            None => self.get_tips().await?,
+        };
+
+        let parents = match strategy {
+            TipSelectionStrategy::Default => tips,
+            TipSelectionStrategy::SemiLazyAvoidance => {
+                let mut non_lazy_tips = Vec::with_capacity(tips.len());
+                for tip in &tips {
+                    match self.get_block_metadata(tip).await {
+                        Ok(metadata) => {
+                            if metadata.should_promote != Some(true) && metadata.should_reattach != Some(true) {
+                                non_lazy_tips.push(*tip);
+                            }
+                        }
+                        // The tip may not be known by the node pool yet if the tips are from another node
+                        Err(err) => {
+                            log::debug!("[select_parents] couldn't get metadata of tip {tip}: {err}");
+                            non_lazy_tips.push(*tip);
+                        }
+                    }
+                }
+                if non_lazy_tips.is_empty() { tips } else { non_lazy_tips }
+            }
+            TipSelectionStrategy::OwnChain { previous_block_id } => {
+                let mut parents = vec![*previous_block_id];
+                parents.extend(
+                    tips.into_iter()
+                        .filter(|tip| tip != previous_block_id)
+                        .take(*Parents::COUNT_RANGE.end() as usize - 1),
+                );
+                parents
+            }
+        };
+
+        Ok(Parents::from_vec(parents)?)
+    }
+}
@@ -3,21 +3,29 @@
 
 //! Transaction preparation and signing
 
+use std::collections::HashSet;
+
 use packable::PackableExt;
 
 use crate::{
     client::{
-        api::{types::PreparedTransactionData, ClientBlockBuilder},
+        api::{
+            input_selection::{is_alias_transition, Error as InputSelectionError},
+            types::PreparedTransactionData,
+            ClientBlockBuilder,
+        },
         secret::{types::InputSigningData, SecretManage},
         Error, Result,
     },
     types::block::{
+        address::Address,
         input::{Input, UtxoInput},
         output::{InputsCommitment, Output, OutputId},
         payload::{
             transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
             TaggedDataPayload,
         },
+        protocol::ProtocolParameters,
         semantic::{semantic_validation, ConflictReason, ValidationContext},
         signature::Ed25519Signature,
         Block, BlockId,
@@ -49,6 +57,9 @@ impl<'a> ClientBlockBuilder<'a> {
         } else {
             self.get_inputs(&protocol_parameters).await?
         };
+        self.client
+            .run_after_input_selection(&selected_transaction_data)
+            .await?;
 
         // Build transaction payload
         let inputs_commitment = InputsCommitment::new(selected_transaction_data.inputs.iter().map(|i| &i.output));
@@ -76,15 +87,18 @@ impl<'a> ClientBlockBuilder<'a> {
 
         let regular_essence = essence.finish_with_params(self.client.get_protocol_parameters().await?)?;
 
-        validate_regular_transaction_essence_length(&regular_essence)?;
-
-        let essence = TransactionEssence::Regular(regular_essence);
-
-        Ok(PreparedTransactionData {
-            essence,
+        let mut prepared_transaction_data = PreparedTransactionData {
+            essence: TransactionEssence::Regular(regular_essence),
             inputs_data: selected_transaction_data.inputs,
             remainder: selected_transaction_data.remainder,
-        })
+        };
+        self.client
+            .run_after_essence_build(&mut prepared_transaction_data)
+            .await?;
+
+        validate_regular_transaction_essence_length(prepared_transaction_data.essence.as_regular())?;
+
+        Ok(prepared_transaction_data)
     }
 
     /// Sign the transaction
@@ -94,6 +108,7 @@ impl<'a> ClientBlockBuilder<'a> {
     ) -> Result<TransactionPayload> {
         log::debug!("[sign_transaction] {:?}", prepared_transaction_data);
         let secret_manager = self.secret_manager.ok_or(Error::MissingParameter("secret manager"))?;
+        self.client.check_protocol_parameters().await?;
         let current_time = self.client.get_time_checked().await?;
 
         let unlocks = secret_manager
@@ -112,6 +127,29 @@ impl<'a> ClientBlockBuilder<'a> {
 
         Ok(tx_payload)
     }
+
+    /// Selects the inputs and builds the transaction essence like [`finish()`](Self::finish), but returns it without
+    /// signing or posting it. Everything that can be checked without signatures is verified, so the returned essence
+    /// can be used in tests or to preview a transaction.
+    pub async fn dry_run(&self) -> Result<PreparedTransactionData> {
+        if self.data.is_some() && self.tag.is_none() {
+            return Err(Error::MissingParameter("tag"));
+        }
+        if self.outputs.is_empty() {
+            return Err(Error::MissingParameter("output"));
+        }
+        if self.secret_manager.is_none() && self.inputs.is_none() {
+            return Err(Error::MissingParameter("seed"));
+        }
+
+        let prepared_transaction_data = self.prepare_transaction().await?;
+        let protocol_parameters = self.client.get_protocol_parameters().await?;
+        let current_time = self.client.get_time_checked().await?;
+
+        verify_prepared_transaction(&prepared_transaction_data, &protocol_parameters, current_time)?;
+
+        Ok(prepared_transaction_data)
+    }
 }
 
 /// Verifies the semantic of a prepared transaction.
@@ -175,3 +213,53 @@ pub fn validate_regular_transaction_essence_length(
     }
     Ok(())
 }
+
+/// Verifies a prepared transaction without signing it: the storage deposits of the outputs and that every input can be
+/// unlocked, either with a signature of a known BIP32 chain or by referencing a previous input.
+pub fn verify_prepared_transaction(
+    prepared_transaction_data: &PreparedTransactionData,
+    protocol_parameters: &ProtocolParameters,
+    current_time: u32,
+) -> Result<()> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+
+    for output in essence.outputs() {
+        output.verify_storage_deposit(
+            *protocol_parameters.rent_structure(),
+            protocol_parameters.token_supply(),
+        )?;
+    }
+
+    // Same order as when the unlocks are created, so references only point to previous inputs
+    let mut unlockable_addresses = HashSet::<Address>::new();
+
+    for input in &prepared_transaction_data.inputs_data {
+        let alias_transition = is_alias_transition(&input.output, *input.output_id(), essence.outputs(), None);
+        let (input_address, _) =
+            input
+                .output
+                .required_and_unlocked_address(current_time, input.output_id(), alias_transition)?;
+
+        if !unlockable_addresses.contains(&input_address) {
+            if !input_address.is_ed25519() {
+                Err(InputSelectionError::MissingInputWithEd25519Address)?;
+            }
+            if input.chain.is_none() {
+                return Err(Error::MissingBip32Chain);
+            }
+            unlockable_addresses.insert(input_address);
+        }
+
+        match &input.output {
+            Output::Alias(alias_output) => {
+                unlockable_addresses.insert(Address::Alias(alias_output.alias_address(input.output_id())));
+            }
+            Output::Nft(nft_output) => {
+                unlockable_addresses.insert(Address::Nft(nft_output.nft_address(input.output_id())));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
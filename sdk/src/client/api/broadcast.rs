@@ -0,0 +1,111 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_manager::score::node_key, ClientInner, Error, Result},
+    pow::score::PowScorer,
+    types::{
+        api::core::response::SubmitBlockResponse,
+        block::{payload::Payload, Block, BlockId},
+    },
+};
+
+/// The responses of the nodes to a block posted with [`ClientInner::broadcast_block()`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastResult {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The response of each node the block was posted to.
+    pub nodes: Vec<NodeBroadcastResult>,
+}
+
+impl BroadcastResult {
+    /// Returns the number of nodes that accepted the block.
+    pub fn accepted(&self) -> usize {
+        self.nodes.iter().filter(|node| node.error.is_none()).count()
+    }
+}
+
+/// The response of a single node to a broadcast block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeBroadcastResult {
+    /// The origin of the node URL, without credentials.
+    pub url: String,
+    /// Why the node didn't accept the block, `None` if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ClientInner {
+    /// Posts a block to all healthy nodes at the same time instead of one node after another, so a single slow node
+    /// can't delay the propagation of e.g. a time-critical payment, and returns the response of each node. The PoW of
+    /// the block has to be done already, with remote PoW every node would create another block. Fails if no node
+    /// accepted the block.
+    pub async fn broadcast_block(&self, block: &Block) -> Result<BroadcastResult> {
+        if let Some(Payload::Transaction(transaction)) = block.payload() {
+            self.check_transaction_network(transaction).await?;
+        }
+        let block_id = block.id();
+        let bytes = block.pack_to_vec();
+        let min_pow_score = self.get_min_pow_score().await?;
+        if min_pow_score > 0 {
+            let score = PowScorer::new().score(&bytes);
+            if score < min_pow_score as f64 {
+                return Err(Error::InsufficientPow {
+                    block_id,
+                    score,
+                    min_pow_score,
+                });
+            }
+        }
+
+        let timeout = self.get_timeout().await;
+        let responses = self
+            .node_manager
+            .read()
+            .await
+            .broadcast_request_bytes::<SubmitBlockResponse>("api/core/v2/blocks", timeout, &bytes)
+            .await?;
+
+        let mut nodes = Vec::with_capacity(responses.len());
+        for (node, response) in responses {
+            let url = node_key(&node.url);
+            let error = match response {
+                Ok(response) if response.block_id == block_id => None,
+                Ok(response) => Some(format!("node returned block id {}", response.block_id)),
+                Err(e) => Some(e.to_string()),
+            };
+            if let Some(error) = &error {
+                log::debug!("[broadcast_block] {url} didn't accept {block_id}: {error}");
+            }
+            nodes.push(NodeBroadcastResult { url, error });
+        }
+        let result = BroadcastResult { block_id, nodes };
+
+        if result.accepted() == 0 {
+            let errors = result
+                .nodes
+                .iter()
+                .map(|node| format!("{}: {}", node.url, node.error.as_deref().unwrap_or_default()))
+                .collect::<Vec<_>>();
+            return Err(Error::BroadcastFailed(format!("{block_id}, {}", errors.join("; "))));
+        }
+        self.run_after_submission(&block_id, block.payload()).await;
+
+        Ok(result)
+    }
+
+    // Returns whether a block can be broadcast instead of being posted to a single node
+    pub(crate) async fn should_broadcast(&self, block: &Block) -> Result<bool> {
+        if !self.node_manager.read().await.broadcast {
+            return Ok(false);
+        }
+        let min_pow_score = self.get_min_pow_score().await?;
+        Ok(min_pow_score == 0 || PowScorer::new().score(&block.pack_to_vec()) >= min_pow_score as f64)
+    }
+}
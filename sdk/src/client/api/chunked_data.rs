@@ -0,0 +1,274 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upload and reassembly of data that doesn't fit into a single tagged data block.
+//!
+//! The data is split into chunks which are sent as tagged data blocks in order. Every chunk starts with a small header
+//! containing its index and the block id of the previous chunk, so the chunks form a chain. The last chunk, the head,
+//! additionally commits to the total length and the Blake2b-256 hash of the data. The block id of the head is all
+//! that's needed to retrieve the data again.
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use futures::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    client::{constants::CHUNKED_DATA_CHUNK_LENGTH, Client, Error, Result},
+    types::block::{payload::Payload, Block, BlockId},
+};
+
+const CHUNK_VERSION: u8 = 1;
+const FLAG_HAS_PREVIOUS: u8 = 1 << 0;
+const FLAG_IS_HEAD: u8 = 1 << 1;
+
+/// The header prepended to the data of every chunk.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ChunkHeader {
+    index: u32,
+    previous: Option<BlockId>,
+    /// The total length and hash of the data, only set for the head chunk.
+    head: Option<(u64, [u8; 32])>,
+}
+
+impl ChunkHeader {
+    fn encode(&self, chunk: &[u8]) -> Vec<u8> {
+        let mut flags = 0;
+        if self.previous.is_some() {
+            flags |= FLAG_HAS_PREVIOUS;
+        }
+        if self.head.is_some() {
+            flags |= FLAG_IS_HEAD;
+        }
+
+        let mut bytes = vec![CHUNK_VERSION, flags];
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        if let Some(previous) = &self.previous {
+            bytes.extend_from_slice(&previous[..]);
+        }
+        if let Some((total_length, hash)) = &self.head {
+            bytes.extend_from_slice(&total_length.to_le_bytes());
+            bytes.extend_from_slice(hash);
+        }
+        bytes.extend_from_slice(chunk);
+
+        bytes
+    }
+
+    /// Splits the data of a chunk into its header and content.
+    fn decode(bytes: &[u8]) -> Result<(Self, &[u8])> {
+        fn take<'a>(bytes: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+            if bytes.len() < length {
+                return Err(Error::ChunkedData("truncated chunk header".to_string()));
+            }
+            let (taken, rest) = bytes.split_at(length);
+            *bytes = rest;
+            Ok(taken)
+        }
+
+        let mut bytes = bytes;
+        let (version, flags) = match take(&mut bytes, 2)? {
+            [version, flags] => (*version, *flags),
+            _ => unreachable!(),
+        };
+        if version != CHUNK_VERSION {
+            return Err(Error::ChunkedData(format!("unsupported chunk version {version}")));
+        }
+
+        let index = u32::from_le_bytes(take(&mut bytes, 4)?.try_into().unwrap());
+        let previous = if flags & FLAG_HAS_PREVIOUS != 0 {
+            Some(BlockId::new(take(&mut bytes, BlockId::LENGTH)?.try_into().unwrap()))
+        } else {
+            None
+        };
+        let head = if flags & FLAG_IS_HEAD != 0 {
+            let total_length = u64::from_le_bytes(take(&mut bytes, 8)?.try_into().unwrap());
+            let hash = take(&mut bytes, 32)?.try_into().unwrap();
+            Some((total_length, hash))
+        } else {
+            None
+        };
+
+        Ok((Self { index, previous, head }, bytes))
+    }
+}
+
+/// Reads from `reader` until `buffer` is full or the end of the stream is reached, returns the number of read bytes.
+async fn read_chunk(reader: &mut (impl AsyncRead + Unpin), buffer: &mut [u8]) -> Result<usize> {
+    let mut length = 0;
+
+    while length < buffer.len() {
+        match reader
+            .read(&mut buffer[length..])
+            .await
+            .map_err(|e| Error::ChunkedData(e.to_string()))?
+        {
+            0 => break,
+            read => length += read,
+        }
+    }
+
+    Ok(length)
+}
+
+impl Client {
+    /// Sends data of arbitrary length as a chain of tagged data blocks with the given tag. The data is read from
+    /// `reader` chunk by chunk, so it doesn't need to be held in memory. Returns the sent blocks in order, the id of
+    /// the last one is needed to retrieve the data with [`Client::retrieve_data_chunked()`].
+    pub async fn send_data_chunked(
+        &self,
+        tag: Vec<u8>,
+        mut reader: impl AsyncRead + Unpin + Send,
+    ) -> Result<Vec<Block>> {
+        log::debug!("[send_data_chunked]");
+        let mut hasher = Blake2b256::new();
+        let mut total_length = 0u64;
+        let mut blocks: Vec<Block> = Vec::new();
+
+        let mut chunk = vec![0; CHUNKED_DATA_CHUNK_LENGTH];
+        let mut chunk_length = read_chunk(&mut reader, &mut chunk).await?;
+        let mut next_chunk = vec![0; CHUNKED_DATA_CHUNK_LENGTH];
+
+        loop {
+            // Read ahead to know if the current chunk is the head.
+            let next_chunk_length = if chunk_length == CHUNKED_DATA_CHUNK_LENGTH {
+                read_chunk(&mut reader, &mut next_chunk).await?
+            } else {
+                0
+            };
+
+            let data = &chunk[..chunk_length];
+            hasher.update(data);
+            total_length += chunk_length as u64;
+
+            let header = ChunkHeader {
+                index: blocks.len() as u32,
+                previous: blocks.last().map(Block::id),
+                head: (next_chunk_length == 0).then(|| (total_length, hasher.clone().finalize().into())),
+            };
+            let block = self
+                .build_block()
+                .with_tag(tag.clone())
+                .with_data(header.encode(data))
+                .finish()
+                .await?;
+            log::debug!(
+                "[send_data_chunked] sent chunk {} in block {}",
+                header.index,
+                block.id()
+            );
+            blocks.push(block);
+
+            if next_chunk_length == 0 {
+                break;
+            }
+            std::mem::swap(&mut chunk, &mut next_chunk);
+            chunk_length = next_chunk_length;
+        }
+
+        Ok(blocks)
+    }
+
+    /// Retrieves data that was sent with [`Client::send_data_chunked()`], starting from the block id of its last
+    /// chunk. Every chunk needs to have the given tag, the reassembled data is checked against the length and hash
+    /// committed to in the last chunk.
+    pub async fn retrieve_data_chunked(&self, tag: &[u8], head_block_id: &BlockId) -> Result<Vec<u8>> {
+        log::debug!("[retrieve_data_chunked]");
+        let mut chunks = Vec::new();
+        let mut expected_head = None;
+        let mut next_block_id = Some(*head_block_id);
+
+        while let Some(block_id) = next_block_id {
+            let block = self.get_block(&block_id).await?;
+            let Some(Payload::TaggedData(tagged_data)) = block.payload() else {
+                return Err(Error::ChunkedData(format!(
+                    "block {block_id} has no tagged data payload"
+                )));
+            };
+            if tagged_data.tag() != tag {
+                return Err(Error::ChunkedData(format!("block {block_id} has a different tag")));
+            }
+
+            let (header, data) = ChunkHeader::decode(tagged_data.data())?;
+            match (chunks.is_empty(), header.head) {
+                (true, Some(head)) => expected_head = Some(head),
+                (true, None) => return Err(Error::ChunkedData(format!("block {block_id} is not the last chunk"))),
+                (false, Some(_)) => {
+                    return Err(Error::ChunkedData(format!(
+                        "block {block_id} is an unexpected last chunk"
+                    )));
+                }
+                (false, None) => {}
+            }
+            let expected_index = match chunks.last() {
+                Some((index, _)) => *index - 1,
+                None => header.index,
+            };
+            if header.index != expected_index || (header.index == 0) != header.previous.is_none() {
+                return Err(Error::ChunkedData(format!(
+                    "block {block_id} has an invalid chunk index"
+                )));
+            }
+
+            chunks.push((header.index, data.to_vec()));
+            next_block_id = header.previous;
+        }
+
+        let data = chunks.into_iter().rev().flat_map(|(_, data)| data).collect::<Vec<_>>();
+        // Safe to unwrap, the head is always the first retrieved chunk.
+        let (total_length, hash) = expected_head.unwrap();
+        if data.len() as u64 != total_length {
+            return Err(Error::ChunkedData(format!(
+                "data length {} doesn't match the expected length {total_length}",
+                data.len()
+            )));
+        }
+        if Blake2b256::digest(&data).as_slice() != hash {
+            return Err(Error::ChunkedData("data hash mismatch".to_string()));
+        }
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_header_roundtrip() {
+        let headers = [
+            ChunkHeader {
+                index: 0,
+                previous: None,
+                head: None,
+            },
+            ChunkHeader {
+                index: 3,
+                previous: Some(BlockId::new([7; 32])),
+                head: Some((42, [9; 32])),
+            },
+        ];
+
+        for header in headers {
+            let bytes = header.encode(b"chunk");
+            let (decoded, data) = ChunkHeader::decode(&bytes).unwrap();
+
+            assert_eq!(decoded, header);
+            assert_eq!(data, b"chunk");
+        }
+    }
+
+    #[test]
+    fn truncated_chunk_header() {
+        let header = ChunkHeader {
+            index: 1,
+            previous: Some(BlockId::new([7; 32])),
+            head: None,
+        };
+        let bytes = header.encode(&[]);
+
+        assert!(matches!(
+            ChunkHeader::decode(&bytes[..bytes.len() - 1]),
+            Err(Error::ChunkedData(_))
+        ));
+    }
+}
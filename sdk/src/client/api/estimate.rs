@@ -0,0 +1,94 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Estimating the costs of a transaction before it gets signed.
+
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Result},
+    types::block::{
+        output::Rent, parent::Parents, payload::transaction::TransactionEssence, signature::Ed25519Signature, Block,
+        BlockId,
+    },
+};
+
+/// Length of a block without the payload: protocol version, parents count, payload length and nonce.
+const BLOCK_LENGTH_WITHOUT_PARENTS_AND_PAYLOAD: usize = 1 + 1 + 4 + 8;
+/// Length of a transaction payload without the essence: payload kind and unlocks count.
+const TRANSACTION_PAYLOAD_LENGTH_WITHOUT_ESSENCE_AND_UNLOCKS: usize = 4 + 2;
+/// Length of a signature unlock with an Ed25519 signature: unlock kind, signature kind, public key and signature.
+const SIGNATURE_UNLOCK_LENGTH: usize = 1 + 1 + Ed25519Signature::PUBLIC_KEY_LENGTH + Ed25519Signature::SIGNATURE_LENGTH;
+
+/// The estimated costs of a transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEstimate {
+    /// The maximum size of the block in bytes, assuming a signature unlock for every input and the maximum number of
+    /// parents.
+    pub block_size: usize,
+    /// Whether the block would exceed the maximum block size.
+    pub exceeds_max_block_size: bool,
+    /// The minimum storage deposit required by each output of the essence, in the same order.
+    pub storage_deposits: Vec<u64>,
+    /// The minimum PoW score of the network.
+    pub min_pow_score: u32,
+    /// The number of trailing zero trits the PoW hash of the block needs, 0 if no PoW is needed.
+    pub pow_target_trailing_zeros: u32,
+    /// Whether the client does the PoW itself.
+    pub local_pow: bool,
+    /// Whether the node allows doing the PoW remotely.
+    pub remote_pow_allowed: bool,
+}
+
+impl Client {
+    /// Estimates the block size, storage deposits and PoW difficulty of a transaction, without signing or sending it.
+    pub async fn estimate_transaction(&self, essence: &TransactionEssence) -> Result<TransactionEstimate> {
+        let TransactionEssence::Regular(regular) = essence;
+        let rent_structure = self.get_rent_structure().await?;
+        let min_pow_score = self.get_min_pow_score().await?;
+        let remote_pow_allowed = self
+            .get_info()
+            .await?
+            .node_info
+            .features
+            .iter()
+            .any(|feature| feature == "pow");
+
+        let block_size = estimate_block_size(essence);
+
+        Ok(TransactionEstimate {
+            block_size,
+            exceeds_max_block_size: block_size > Block::LENGTH_MAX,
+            storage_deposits: regular
+                .outputs()
+                .iter()
+                .map(|output| output.rent_cost(&rent_structure))
+                .collect(),
+            min_pow_score,
+            pow_target_trailing_zeros: pow_target_trailing_zeros(block_size, min_pow_score),
+            local_pow: self.get_local_pow().await,
+            remote_pow_allowed,
+        })
+    }
+}
+
+fn estimate_block_size(essence: &TransactionEssence) -> usize {
+    let TransactionEssence::Regular(regular) = essence;
+
+    BLOCK_LENGTH_WITHOUT_PARENTS_AND_PAYLOAD
+        + *Parents::COUNT_RANGE.end() as usize * BlockId::LENGTH
+        + TRANSACTION_PAYLOAD_LENGTH_WITHOUT_ESSENCE_AND_UNLOCKS
+        + essence.packed_len()
+        + regular.inputs().len() * SIGNATURE_UNLOCK_LENGTH
+}
+
+/// Same computation as the miner: the score of a block is 3^trailing_zeros / block_size.
+fn pow_target_trailing_zeros(block_size: usize, min_pow_score: u32) -> u32 {
+    if min_pow_score == 0 {
+        return 0;
+    }
+
+    (((block_size as f64).ln() + (min_pow_score as f64).ln()) / 3f64.ln()).ceil() as u32
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Requesting funds from a faucet, for development and test networks.
+//!
+//! Faucets answer with `429 Too Many Requests` while they're busy or the address was funded recently, such requests are
+//! retried after the interval the faucet asks for in its `Retry-After` header.
+
+use std::time::Duration;
+
+use crate::{
+    client::{
+        node_api::indexer::query_parameters::QueryParameter,
+        node_manager::{http_client::HttpClient, node::Node},
+        Client, ClientInner, Error, Result,
+    },
+    types::block::{address::Bech32Address, output::OutputId},
+    Url,
+};
+
+/// Options for requesting funds from a faucet and waiting for them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FaucetOptions {
+    /// How often a rate limited request is retried.
+    pub max_retries: u32,
+    /// The time to wait before retrying a rate limited request, if the faucet doesn't send a `Retry-After` header.
+    pub retry_interval: Duration,
+    /// The time between checks whether the funds arrived.
+    pub poll_interval: Duration,
+    /// How often to check whether the funds arrived before giving up.
+    pub max_poll_attempts: u32,
+}
+
+impl Default for FaucetOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            retry_interval: Duration::from_secs(5),
+            poll_interval: Duration::from_secs(2),
+            max_poll_attempts: 30,
+        }
+    }
+}
+
+impl Client {
+    /// Requests funds from a faucet, rate limited requests are retried. Returns the response of the faucet.
+    pub async fn request_funds_from_faucet(&self, faucet_url: &str, bech32_address: &Bech32Address) -> Result<String> {
+        self.request_funds(faucet_url, bech32_address, &FaucetOptions::default())
+            .await
+    }
+
+    /// Waits until outputs that aren't in `known_output_ids` arrive on the address, only considering outputs that can
+    /// be unlocked by the address alone. Returns the IDs of the new outputs.
+    pub async fn wait_for_funds(
+        &self,
+        bech32_address: &Bech32Address,
+        known_output_ids: &[OutputId],
+        options: &FaucetOptions,
+    ) -> Result<Vec<OutputId>> {
+        for _ in 0..options.max_poll_attempts {
+            sleep(options.poll_interval).await;
+
+            let new_output_ids = self
+                .spendable_output_ids(bech32_address)
+                .await?
+                .into_iter()
+                .filter(|output_id| !known_output_ids.contains(output_id))
+                .collect::<Vec<_>>();
+
+            if !new_output_ids.is_empty() {
+                return Ok(new_output_ids);
+            }
+        }
+
+        Err(Error::FundsNotReceived(bech32_address.to_string()))
+    }
+
+    /// Requests funds from a faucet and waits until they arrived on the address. Returns the IDs of the new outputs.
+    pub async fn request_funds_from_faucet_and_wait(
+        &self,
+        faucet_url: &str,
+        bech32_address: &Bech32Address,
+        options: &FaucetOptions,
+    ) -> Result<Vec<OutputId>> {
+        let known_output_ids = self.spendable_output_ids(bech32_address).await?;
+
+        self.request_funds(faucet_url, bech32_address, options).await?;
+
+        self.wait_for_funds(bech32_address, &known_output_ids, options).await
+    }
+
+    async fn spendable_output_ids(&self, bech32_address: &Bech32Address) -> Result<Vec<OutputId>> {
+        Ok(self
+            .basic_output_ids([
+                QueryParameter::Address(*bech32_address),
+                QueryParameter::HasExpiration(false),
+                QueryParameter::HasTimelock(false),
+                QueryParameter::HasStorageDepositReturn(false),
+            ])
+            .await?
+            .items)
+    }
+}
+
+impl ClientInner {
+    /// Requests funds from a faucet with the HTTP client of the client, so a custom transport and user agent are used
+    /// for it too.
+    async fn request_funds(
+        &self,
+        faucet_url: &str,
+        bech32_address: &Bech32Address,
+        options: &FaucetOptions,
+    ) -> Result<String> {
+        let (offline, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.offline, node_manager.http_client.clone())
+        };
+        if offline {
+            return Err(Error::Offline);
+        }
+
+        request_funds(
+            &http_client,
+            self.get_timeout().await,
+            faucet_url,
+            bech32_address,
+            options,
+        )
+        .await
+    }
+}
+
+/// Requests funds from a faucet, retrying rate limited requests. Responses with an unsuccessful status code are
+/// returned as error.
+pub(crate) async fn request_funds(
+    http_client: &HttpClient,
+    timeout: Duration,
+    faucet_url: &str,
+    bech32_address: &Bech32Address,
+    options: &FaucetOptions,
+) -> Result<String> {
+    let faucet = Node::from(Url::parse(faucet_url)?);
+    let json = serde_json::json!({ "address": bech32_address.to_string() });
+    let mut retries = 0;
+
+    loop {
+        let response = http_client
+            .post_json_unchecked(faucet.clone(), timeout, json.clone())
+            .await?;
+
+        if response.status() == 429 && retries < options.max_retries {
+            let retry_after = response
+                .header("retry-after")
+                .and_then(|value| value.parse().ok())
+                .map_or(options.retry_interval, Duration::from_secs);
+            log::debug!("[request_funds_from_faucet] rate limited, retrying in {retry_after:?}");
+
+            retries += 1;
+            sleep(retry_after).await;
+            continue;
+        }
+
+        let response = HttpClient::parse_response(response, &faucet.url)?;
+        return Ok(String::from_utf8_lossy(&response.into_bytes().await?).into_owned());
+    }
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(target_family = "wasm")]
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+
+    #[cfg(not(target_family = "wasm"))]
+    crate::client::runtime::sleep(duration).await;
+}
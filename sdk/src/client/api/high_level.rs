@@ -8,12 +8,10 @@ use futures::{StreamExt, TryStreamExt};
 use crate::{
     client::{
         api::{input_selection::Error as InputSelectionError, ClientBlockBuilder},
-        constants::{
-            DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT, FIVE_MINUTES_IN_SECONDS,
-        },
+        constants::FIVE_MINUTES_IN_SECONDS,
         error::{Error, Result},
         node_api::indexer::query_parameters::QueryParameter,
-        Client,
+        Client, RetryPolicy,
     },
     types::{
         api::core::response::LedgerInclusionState,
@@ -84,8 +82,8 @@ impl Client {
     }
 
     /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
-    /// milestone). Default interval is 5 seconds and max attempts is 40. Returns the included block at first position
-    /// and additional reattached blocks
+    /// milestone). The interval and max attempts default to the ones of [`RetryPolicy::until_included()`]. Returns
+    /// the included block at first position and additional reattached blocks
     pub async fn retry_until_included(
         &self,
         block_id: &BlockId,
@@ -97,20 +95,14 @@ impl Client {
         let mut block_ids = vec![*block_id];
         // Reattached Blocks that get returned
         let mut blocks_with_id = Vec::new();
-        for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+        let policy = RetryPolicy::until_included();
+        let duration = interval.map_or(policy.initial_delay, std::time::Duration::from_secs);
+        for _ in 0..max_attempts.unwrap_or(policy.max_attempts as u64) {
             #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(
-                (interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL) * 1000)
-                    .try_into()
-                    .unwrap(),
-            )
-            .await;
+            gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
 
             #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_secs(
-                interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL),
-            ))
-            .await;
+            crate::client::runtime::sleep(duration).await;
 
             // Check inclusion state for each attachment
             let block_ids_len = block_ids.len();
@@ -4,11 +4,37 @@
 //! High level APIs
 
 mod address;
+mod balance;
 mod block_builder;
+mod broadcast;
+mod chunked_data;
 mod consolidation;
+mod estimate;
+mod faucet;
 mod high_level;
+mod send;
+mod simulation;
+mod tagged_data;
+mod treasury;
 mod types;
+#[cfg(not(target_family = "wasm"))]
+mod watch;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub(crate) use self::faucet::request_funds;
+#[cfg(not(target_family = "wasm"))]
+pub use self::watch::{AddressWatch, BalanceChange};
+pub use self::{
+    address::*,
+    balance::{AddressBalance, AddressBalances},
+    block_builder::*,
+    broadcast::{BroadcastResult, NodeBroadcastResult},
+    estimate::TransactionEstimate,
+    faucet::FaucetOptions,
+    send::{SendAmountOptions, SendAmountResult},
+    simulation::simulate_transaction,
+    tagged_data::{JsonTagCodec, TagCodec},
+    treasury::TreasuryMutation,
+    types::*,
+};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
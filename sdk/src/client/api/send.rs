@@ -0,0 +1,236 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sending an amount to an address in one call, for scripts that don't need the accounts of the wallet.
+
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{secret::SecretManager, Client, Result},
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{Output, OutputId},
+        payload::{
+            transaction::{TransactionEssence, TransactionId},
+            Payload,
+        },
+        BlockId, ConvertTo,
+    },
+};
+
+/// Options for [`Client::send_amount()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAmountOptions {
+    /// The coin type of the addresses the inputs are selected from, Shimmer by default.
+    pub coin_type: Option<u32>,
+    /// The account index of the addresses the inputs are selected from, 0 by default.
+    pub account_index: Option<u32>,
+    /// The address indexes of the addresses the inputs are selected from, `0..100` by default.
+    pub input_range: Option<Range<u32>>,
+    /// The address the remainder is sent to, by default the first address of the input range.
+    pub remainder_address: Option<Bech32Address>,
+    /// The interval in seconds between the checks if the block was included.
+    pub retry_interval: Option<u64>,
+    /// The maximum number of checks if the block was included, before [`Error::TangleInclusion`] is returned.
+    ///
+    /// [`Error::TangleInclusion`]: crate::client::Error::TangleInclusion
+    pub max_retry_attempts: Option<u64>,
+}
+
+/// The result of [`Client::send_amount()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAmountResult {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+    /// The ID of the block the transaction was posted with.
+    pub block_id: BlockId,
+    /// The ID of the block that got included, the posted block or one of its reattachments.
+    pub included_block_id: BlockId,
+    /// The IDs of the blocks the transaction was reattached with.
+    pub reattached_block_ids: Vec<BlockId>,
+    /// The IDs of the spent outputs.
+    pub inputs: Vec<OutputId>,
+    /// The ID of the output that holds the sent amount.
+    pub output_id: OutputId,
+    /// The ID of the output that holds the remainder, if the inputs had more funds than the amount.
+    pub remainder_output_id: Option<OutputId>,
+}
+
+impl Client {
+    /// Sends an amount to an address, in a basic output without further unlock conditions. The inputs are selected
+    /// from the addresses of the secret manager and a remainder output is created if needed. The block is posted with
+    /// PoW and retried until it's included, so the returned outputs can be spent right away.
+    pub async fn send_amount(
+        &self,
+        secret_manager: &SecretManager,
+        address: impl ConvertTo<Bech32Address>,
+        amount: u64,
+        options: impl Into<Option<SendAmountOptions>> + Send,
+    ) -> Result<SendAmountResult> {
+        log::debug!("[send_amount]");
+        let options = options.into().unwrap_or_default();
+        let address = address.convert()?;
+
+        let mut block_builder = self
+            .build_block()
+            .with_secret_manager(secret_manager)
+            .with_output(address.clone(), amount)
+            .await?;
+        if let Some(coin_type) = options.coin_type {
+            block_builder = block_builder.with_coin_type(coin_type);
+        }
+        if let Some(account_index) = options.account_index {
+            block_builder = block_builder.with_account_index(account_index);
+        }
+        if let Some(input_range) = options.input_range {
+            block_builder = block_builder.with_input_range(input_range);
+        }
+        if let Some(remainder_address) = options.remainder_address {
+            block_builder = block_builder.with_custom_remainder_address(remainder_address)?;
+        }
+
+        let prepared_transaction_data = block_builder.prepare_transaction().await?;
+        let inputs = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| *input.output_id())
+            .collect::<Vec<_>>();
+        let remainder = prepared_transaction_data
+            .remainder
+            .as_ref()
+            .map(|remainder| remainder.output.clone());
+
+        let transaction_payload = block_builder.sign_transaction(prepared_transaction_data).await?;
+        let transaction_id = transaction_payload.id();
+        let TransactionEssence::Regular(essence) = transaction_payload.essence();
+        let to_output_id = |index: usize| OutputId::new(transaction_id, index as u16);
+
+        let (output_index, remainder_index) =
+            sent_output_indexes(essence.outputs(), remainder.as_ref(), address.inner(), amount);
+        let output_id = to_output_id(output_index)?;
+        let remainder_output_id = remainder_index.map(to_output_id).transpose()?;
+
+        let block_id = block_builder
+            .finish_block(Some(Payload::from(transaction_payload)))
+            .await?
+            .id();
+        let blocks = self
+            .retry_until_included(&block_id, options.retry_interval, options.max_retry_attempts)
+            .await?;
+        // The included block is at the first position
+        let included_block_id = blocks[0].0;
+
+        Ok(SendAmountResult {
+            transaction_id,
+            block_id,
+            included_block_id,
+            reattached_block_ids: blocks
+                .into_iter()
+                .map(|(block_id, _)| block_id)
+                .filter(|id| id != &block_id)
+                .collect(),
+            inputs,
+            output_id,
+            remainder_output_id,
+        })
+    }
+}
+
+/// Returns the index of the output with the sent amount and the index of the remainder output, if there is one.
+fn sent_output_indexes(
+    outputs: &[Output],
+    remainder: Option<&Output>,
+    address: &Address,
+    amount: u64,
+) -> (usize, Option<usize>) {
+    let remainder_index = remainder.and_then(|remainder| outputs.iter().position(|output| output == remainder));
+    // The remainder can be sent to the same address, so it's excluded
+    let (output_index, _) = outputs
+        .iter()
+        .enumerate()
+        .find(|(index, output)| {
+            Some(*index) != remainder_index && output.amount() == amount && output_address(output) == Some(address)
+        })
+        .expect("the block builder adds the output to the address");
+
+    (output_index, remainder_index)
+}
+
+fn output_address(output: &Output) -> Option<&Address> {
+    output
+        .unlock_conditions()
+        .and_then(|unlock_conditions| unlock_conditions.address())
+        .map(|unlock_condition| unlock_condition.address())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        protocol::ProtocolParameters,
+    };
+
+    fn basic_output(address: Address, amount: u64) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(ProtocolParameters::default().token_supply())
+            .unwrap()
+    }
+
+    #[test]
+    fn sent_output_and_remainder() {
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let remainder_address = Address::from(Ed25519Address::new([2; 32]));
+
+        // Without a remainder
+        let outputs = [basic_output(address, 1_000_000)];
+        assert_eq!(sent_output_indexes(&outputs, None, &address, 1_000_000), (0, None));
+
+        // The remainder can be placed before the sent output
+        let remainder = basic_output(remainder_address, 500_000);
+        let outputs = [remainder.clone(), basic_output(address, 1_000_000)];
+        assert_eq!(
+            sent_output_indexes(&outputs, Some(&remainder), &address, 1_000_000),
+            (1, Some(0))
+        );
+
+        // A remainder with the same amount to the same address isn't taken for the sent output
+        let remainder = basic_output(address, 1_000_000);
+        let outputs = [remainder.clone(), basic_output(address, 1_000_000)];
+        assert_eq!(
+            sent_output_indexes(&outputs, Some(&remainder), &address, 1_000_000),
+            (1, Some(0))
+        );
+    }
+
+    #[test]
+    fn options_json() {
+        let json = serde_json::json!({
+            "coinType": 4219,
+            "accountIndex": 1,
+            "inputRange": { "start": 0, "end": 10 },
+            "retryInterval": 2,
+        });
+        let options = serde_json::from_value::<SendAmountOptions>(json).unwrap();
+        assert_eq!(
+            options,
+            SendAmountOptions {
+                coin_type: Some(4219),
+                account_index: Some(1),
+                input_range: Some(0..10),
+                retry_interval: Some(2),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            serde_json::from_value::<SendAmountOptions>(serde_json::json!({})).unwrap(),
+            SendAmountOptions::default()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Simulating transactions against the ledger state, to get the reason a transaction would conflict before it's signed
+//! and submitted.
+//!
+//! The semantic validation of the node is applied to the transaction: the unlock conditions of the inputs, the balance
+//! of base coins and native tokens and the state transitions of aliases, foundries and NFTs. Signatures can't be
+//! checked before the transaction is signed, so the inputs that need a signature are assumed to be signed correctly.
+
+use std::collections::HashMap;
+
+use crypto::signatures::ed25519;
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, Client, Error, Result},
+    types::block::{
+        output::{Output, OutputId},
+        payload::transaction::{RegularTransactionEssence, TransactionId},
+        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        signature::{Ed25519Signature, Signature},
+        unlock::{plan_unlocks, InputUnlock, SignatureUnlock, Unlock, Unlocks},
+    },
+};
+
+impl Client {
+    /// Simulates a transaction against the ledger state and returns the reason it would conflict, or
+    /// [`ConflictReason::None`] if it would be accepted. The inputs are taken from `local_outputs` if they're in it,
+    /// e.g. outputs of transactions that weren't submitted yet, and otherwise requested from the node.
+    pub async fn simulate_transaction(
+        &self,
+        essence: &RegularTransactionEssence,
+        local_outputs: impl IntoIterator<Item = (OutputId, Output)> + Send,
+    ) -> Result<ConflictReason> {
+        log::debug!("[simulate_transaction]");
+        let mut local_outputs = local_outputs.into_iter().collect::<HashMap<_, _>>();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .map(|input| *input.as_utxo().output_id())
+            .collect::<Vec<_>>();
+        let requested_ids = input_ids
+            .iter()
+            .filter(|output_id| !local_outputs.contains_key(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for (output_id, output) in self.get_outputs_with_results(&requested_ids).await {
+            match output {
+                Ok(output) if output.metadata().is_spent() => return Ok(ConflictReason::InputUtxoAlreadySpent),
+                Ok(output) => {
+                    local_outputs.insert(output_id, output.into_output());
+                }
+                Err(Error::Node(NodeApiError::NotFound(_))) => return Ok(ConflictReason::InputUtxoNotFound),
+                Err(e) => return Err(e),
+            }
+        }
+
+        let inputs = input_ids
+            .into_iter()
+            .map(|output_id| {
+                local_outputs
+                    .get(&output_id)
+                    .map(|output| (output_id, output.clone()))
+                    .ok_or_else(|| Error::NoOutput(output_id.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        simulate_transaction(essence, &inputs, self.get_time_checked().await?)
+    }
+}
+
+/// Simulates a transaction with the given inputs, in the order of the inputs of the essence, and returns the reason it
+/// would conflict, or [`ConflictReason::None`] if it would be accepted. Signatures aren't checked.
+pub fn simulate_transaction(
+    essence: &RegularTransactionEssence,
+    inputs: &[(OutputId, Output)],
+    current_time: u32,
+) -> Result<ConflictReason> {
+    let inputs = inputs
+        .iter()
+        .map(|(output_id, output)| (output_id, output))
+        .collect::<Vec<_>>();
+    let unlocks = Unlocks::new(
+        plan_unlocks(inputs.iter().copied(), essence.outputs(), current_time)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, input_unlock)| match input_unlock {
+                InputUnlock::Signature(_) => placeholder_signature_unlock(index as u16),
+                InputUnlock::Unlock(unlock) => unlock,
+            })
+            .collect::<Vec<_>>(),
+    )?;
+    // The ID of a transaction depends on its unlocks, outputs are only identified by it to validate chain transitions,
+    // which don't depend on the exact ID.
+    let transaction_id = TransactionId::null();
+
+    let mut context = ValidationContext::new(
+        &transaction_id,
+        essence,
+        inputs.iter().map(|(output_id, output)| (*output_id, *output)),
+        &unlocks,
+        current_time,
+    );
+    context.verify_signatures = false;
+
+    Ok(semantic_validation(context, &inputs, &unlocks)?)
+}
+
+/// Signature unlocks must be unique, so the index of the input is encoded into the signature.
+fn placeholder_signature_unlock(index: u16) -> Unlock {
+    let public_key = ed25519::SecretKey::from_bytes(&[0; ed25519::SecretKey::LENGTH]).public_key();
+    let mut signature = [0; Ed25519Signature::SIGNATURE_LENGTH];
+    signature[..2].copy_from_slice(&index.to_le_bytes());
+
+    Unlock::Signature(SignatureUnlock::new(Signature::from(Ed25519Signature::new(
+        public_key,
+        ed25519::Signature::from_bytes(signature),
+    ))))
+}
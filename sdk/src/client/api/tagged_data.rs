@@ -0,0 +1,133 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed values in tagged data payloads.
+//!
+//! A [`TagCodec`] turns values into the data of tagged data payloads and back, for example as JSON, CBOR or protobuf.
+//! Codecs are registered on a client for a tag namespace with [`ClientInner::register_tag_codec()`]. The namespace is a
+//! prefix of the tags the codec is used for, if several namespaces match a tag the longest one wins. Tags without a
+//! registered namespace use [`JsonTagCodec`].
+
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{Client, ClientInner, Error, Result},
+    types::block::{payload::TaggedDataPayload, Block},
+};
+
+/// Encodes values to the data of tagged data payloads and decodes them again. The values are passed as JSON values, so
+/// a codec works for every serializable type.
+pub trait TagCodec: Send + Sync {
+    /// Encodes a value to the data of a tagged data payload.
+    fn encode(&self, value: &Value) -> Result<Vec<u8>>;
+
+    /// Decodes the data of a tagged data payload to a value.
+    fn decode(&self, data: &[u8]) -> Result<Value>;
+}
+
+/// Encodes values as JSON, used for tags without a registered namespace.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonTagCodec;
+
+impl TagCodec for JsonTagCodec {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<Value> {
+        serde_json::from_slice(data).map_err(|e| Error::TagCodec(e.to_string()))
+    }
+}
+
+impl ClientInner {
+    /// Registers a codec for the tags starting with the namespace, replacing the codec registered for the same
+    /// namespace before.
+    pub async fn register_tag_codec(&self, namespace: impl Into<Vec<u8>> + Send, codec: impl TagCodec + 'static) {
+        let namespace = namespace.into();
+        let mut tag_codecs = self.tag_codecs.write().await;
+        tag_codecs.retain(|(registered, _)| registered != &namespace);
+        tag_codecs.push((namespace, Arc::new(codec)));
+    }
+
+    /// Removes the codec registered for the namespace, the tags starting with it use the codec of the next shorter
+    /// matching namespace again.
+    pub async fn unregister_tag_codec(&self, namespace: &[u8]) {
+        self.tag_codecs
+            .write()
+            .await
+            .retain(|(registered, _)| registered != namespace);
+    }
+
+    /// Returns the codec of the longest registered namespace the tag starts with, or [`JsonTagCodec`].
+    pub async fn tag_codec(&self, tag: &[u8]) -> Arc<dyn TagCodec> {
+        self.tag_codecs
+            .read()
+            .await
+            .iter()
+            .filter(|(namespace, _)| tag.starts_with(namespace))
+            .max_by_key(|(namespace, _)| namespace.len())
+            .map_or_else(|| Arc::new(JsonTagCodec) as _, |(_, codec)| codec.clone())
+    }
+
+    /// Encodes a value with the codec of the tag.
+    pub async fn encode_tagged_data<T: Serialize + Sync>(&self, tag: &[u8], value: &T) -> Result<Vec<u8>> {
+        self.tag_codec(tag).await.encode(&serde_json::to_value(value)?)
+    }
+
+    /// Decodes the data of a tagged data payload with the codec of its tag.
+    pub async fn decode_tagged_data<T: DeserializeOwned>(&self, payload: &TaggedDataPayload) -> Result<T> {
+        decode_tagged_data(self.tag_codec(payload.tag()).await.as_ref(), payload.data())
+    }
+}
+
+impl Client {
+    /// Sends a block with a tagged data payload holding the value, encoded with the codec of the tag.
+    pub async fn publish<T: Serialize + Sync>(&self, tag: impl Into<Vec<u8>> + Send, value: &T) -> Result<Block> {
+        let tag = tag.into();
+        let data = self.encode_tagged_data(&tag, value).await?;
+
+        self.build_block().with_tag(tag).with_data(data).finish().await
+    }
+
+    /// Subscribes to the blocks with a tagged data payload with the tag, and calls the callback with their values
+    /// decoded with the codec of the tag. Returns the subscribed topic, to unsubscribe with [`Client::unsubscribe()`].
+    #[cfg(feature = "mqtt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
+    pub async fn subscribe_tagged_data<T, C>(
+        &self,
+        tag: impl Into<Vec<u8>> + Send,
+        callback: C,
+    ) -> Result<crate::client::node_api::mqtt::Topic>
+    where
+        T: DeserializeOwned,
+        C: Fn(Result<T>) + Send + Sync + 'static,
+    {
+        use crate::{
+            client::node_api::mqtt::{MqttPayload, Topic},
+            types::block::{output::feature::TagFeature, payload::dto::PayloadDto},
+        };
+
+        let tag = tag.into();
+        let codec = self.tag_codec(&tag).await;
+        let topic = Topic::TaggedDataBlocks(Some(TagFeature::new(tag)?));
+
+        self.subscribe([topic.clone()], move |event| {
+            // Blocks are the only payload of tagged data topics
+            if let MqttPayload::Block(block) = &event.payload {
+                if let Some(PayloadDto::TaggedData(payload)) = &block.payload {
+                    callback(decode_tagged_data(codec.as_ref(), &payload.data));
+                }
+            }
+        })
+        .await?;
+
+        Ok(topic)
+    }
+}
+
+fn decode_tagged_data<T: DeserializeOwned>(codec: &dyn TagCodec, data: &[u8]) -> Result<T> {
+    serde_json::from_value(codec.decode(data)?).map_err(|e| Error::TagCodec(e.to_string()))
+}
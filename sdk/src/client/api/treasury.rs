@@ -0,0 +1,82 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Auditing the treasury, which holds the funds of the legacy network that weren't migrated yet.
+
+use core::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Result},
+    types::{
+        api::core::response::Receipt,
+        block::{payload::milestone::MilestoneId, Error as BlockError},
+    },
+};
+
+/// A change of the treasury by the receipt of a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryMutation {
+    /// The index of the milestone that contained the receipt.
+    pub milestone_index: u32,
+    /// The index of the legacy milestone at which the funds were migrated.
+    pub migrated_at: u32,
+    /// Whether the receipt is the last one for the legacy milestone.
+    pub last: bool,
+    /// The ID of the milestone that created the consumed treasury output.
+    pub consumed_milestone_id: MilestoneId,
+    /// The amount that was migrated out of the treasury.
+    #[serde(with = "crate::utils::serde::string")]
+    pub migrated_amount: u64,
+    /// The amount left in the treasury.
+    #[serde(with = "crate::utils::serde::string")]
+    pub remaining_amount: u64,
+}
+
+impl TreasuryMutation {
+    /// Returns the amount of the treasury before the mutation.
+    pub fn previous_amount(&self) -> u64 {
+        self.remaining_amount + self.migrated_amount
+    }
+}
+
+impl From<&Receipt> for TreasuryMutation {
+    fn from(receipt: &Receipt) -> Self {
+        Self {
+            milestone_index: receipt.milestone_index,
+            migrated_at: *receipt.migrated_at(),
+            last: receipt.receipt.last(),
+            consumed_milestone_id: *receipt.consumed_treasury_milestone_id(),
+            migrated_amount: receipt.receipt.amount(),
+            remaining_amount: receipt.remaining_treasury(),
+        }
+    }
+}
+
+impl Client {
+    /// Lists the mutations of the treasury by the receipts of the milestones in the range, ordered by milestone index.
+    /// Every mutation is verified to start with the amount the previous one left in the treasury.
+    pub async fn get_treasury_mutations(&self, milestone_range: RangeInclusive<u32>) -> Result<Vec<TreasuryMutation>> {
+        let mut receipts = self
+            .get_receipts()
+            .await?
+            .into_iter()
+            .filter(|receipt| milestone_range.contains(&receipt.milestone_index))
+            .collect::<Vec<_>>();
+        receipts.sort_by_key(|receipt| receipt.milestone_index);
+
+        let mutations = receipts.iter().map(TreasuryMutation::from).collect::<Vec<_>>();
+        for pair in mutations.windows(2) {
+            if pair[1].previous_amount() != pair[0].remaining_amount {
+                return Err(BlockError::TreasuryAmountMismatch {
+                    expected: pair[0].remaining_amount,
+                    actual: pair[1].previous_amount(),
+                })?;
+            }
+        }
+
+        Ok(mutations)
+    }
+}
@@ -13,10 +13,11 @@ use crate::{
             payload::{
                 transaction::{
                     dto::{TransactionEssenceDto, TransactionPayloadDto},
-                    TransactionEssence,
+                    OutputDescription, TransactionDescription, TransactionEssence,
                 },
                 TransactionPayload,
             },
+            protocol::ProtocolParameters,
             Error,
         },
         TryFromDto, ValidationParams,
@@ -35,6 +36,35 @@ pub struct PreparedTransactionData {
     pub remainder: Option<RemainderData>,
 }
 
+impl PreparedTransactionData {
+    /// Returns a human-readable breakdown of the transaction, to be displayed before signing it. Unlike
+    /// [`TransactionEssence::describe()`], it also describes the consumed outputs and marks the remainder as change.
+    pub fn describe(&self, protocol_parameters: &ProtocolParameters) -> TransactionDescription {
+        let hrp = *protocol_parameters.bech32_hrp();
+        let mut description = self.essence.describe(protocol_parameters);
+
+        description.consumed_outputs = self
+            .inputs_data
+            .iter()
+            .map(|input| OutputDescription::new(&input.output, hrp))
+            .collect();
+
+        if let Some(remainder) = &self.remainder {
+            if let Some(position) = self
+                .essence
+                .as_regular()
+                .outputs()
+                .iter()
+                .position(|output| output == &remainder.output)
+            {
+                description.outputs[position].is_change = true;
+            }
+        }
+
+        description
+    }
+}
+
 /// PreparedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
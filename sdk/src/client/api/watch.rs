@@ -0,0 +1,362 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::collections::{HashMap, HashSet};
+
+use futures::{
+    future::{self, Either},
+    Stream,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    client::{
+        constants::DEFAULT_ADDRESS_WATCH_INTERVAL,
+        node_api::indexer::query_parameters::QueryParameter,
+        runtime::{self, JoinHandle},
+        Client, Result,
+    },
+    types::block::{address::Bech32Address, output::OutputId},
+};
+
+/// A change of the basic outputs on a watched address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    /// The watched address.
+    pub address: Bech32Address,
+    /// The base coin amount of the basic outputs on the address after the change.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The outputs that were created on the address.
+    pub created_outputs: Vec<OutputId>,
+    /// The outputs on the address that were spent.
+    pub spent_outputs: Vec<OutputId>,
+}
+
+/// A stream of the [`BalanceChange`]s of watched addresses, returned by [`Client::watch_addresses()`]. Dropping it
+/// stops the watching.
+#[derive(Debug)]
+pub struct AddressWatch {
+    receiver: UnboundedReceiver<BalanceChange>,
+    task: JoinHandle<()>,
+}
+
+impl Stream for AddressWatch {
+    type Item = BalanceChange;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for AddressWatch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Client {
+    /// Watches the basic outputs on addresses, which don't have to belong to a wallet, e.g. to monitor deposit
+    /// addresses. The first change of an address reports the outputs it already has.
+    ///
+    /// With the `mqtt` feature the addresses are checked as soon as a node reports a new or spent output on them,
+    /// additionally all addresses are polled every [`DEFAULT_ADDRESS_WATCH_INTERVAL`], so changes are also found
+    /// without MQTT or if an event got lost. A change is only reported once, no matter how it was found.
+    ///
+    /// The MQTT subscriptions of the addresses aren't removed when the watch is dropped, see [`Client::unsubscribe()`].
+    pub fn watch_addresses(&self, addresses: impl IntoIterator<Item = Bech32Address>) -> AddressWatch {
+        let addresses = addresses.into_iter().collect::<HashSet<_>>();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let client = self.clone();
+        let task = runtime::spawn(async move { client.watch_addresses_task(addresses, sender).await });
+
+        AddressWatch { receiver, task }
+    }
+
+    async fn watch_addresses_task(&self, addresses: HashSet<Bech32Address>, sender: UnboundedSender<BalanceChange>) {
+        // Addresses to check before the next poll, the sender is kept here so the channel stays open without MQTT
+        #[cfg_attr(not(feature = "mqtt"), allow(unused_variables))]
+        let (trigger_sender, mut triggers) = mpsc::unbounded_channel::<Bech32Address>();
+
+        #[cfg(feature = "mqtt")]
+        for address in &addresses {
+            self.subscribe_address_changes(*address, trigger_sender.clone()).await;
+        }
+
+        let mut outputs = HashMap::<Bech32Address, HashMap<OutputId, u64>>::new();
+        let mut addresses_to_check = addresses.clone();
+
+        loop {
+            for address in addresses_to_check.drain() {
+                match self
+                    .address_balance_change(address, outputs.entry(address).or_default())
+                    .await
+                {
+                    Ok(Some(change)) => {
+                        // The watch was dropped
+                        if sender.send(change).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(err) => log::warn!("Couldn't check the outputs of {address}: {err}"),
+                }
+            }
+
+            let sleep = Box::pin(runtime::sleep(DEFAULT_ADDRESS_WATCH_INTERVAL));
+            let triggered_address = match future::select(sleep, Box::pin(triggers.recv())).await {
+                Either::Left(_) => None,
+                Either::Right((address, _)) => address,
+            };
+
+            match triggered_address {
+                Some(address) => {
+                    addresses_to_check.insert(address);
+                    // Several events of an address result in a single check
+                    while let Ok(address) = triggers.try_recv() {
+                        addresses_to_check.insert(address);
+                    }
+                }
+                None => addresses_to_check = addresses.clone(),
+            }
+
+            if sender.is_closed() {
+                return;
+            }
+        }
+    }
+
+    #[cfg(feature = "mqtt")]
+    async fn subscribe_address_changes(&self, address: Bech32Address, trigger_sender: UnboundedSender<Bech32Address>) {
+        use crate::client::node_api::mqtt::Topic;
+
+        let topics = [Topic::AddressOutputs(address), Topic::SpentAddressOutputs(address)];
+        let result = self
+            .subscribe(topics, move |_| {
+                trigger_sender.send(address).ok();
+            })
+            .await;
+
+        if let Err(err) = result {
+            log::debug!("Watching {address} by polling only, couldn't subscribe to its MQTT topics: {err}");
+        }
+    }
+
+    /// Updates the known outputs of the address and returns how they changed.
+    async fn address_balance_change(
+        &self,
+        address: Bech32Address,
+        outputs: &mut HashMap<OutputId, u64>,
+    ) -> Result<Option<BalanceChange>> {
+        let output_ids = self
+            .basic_output_ids([QueryParameter::Address(address)])
+            .await?
+            .items
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        let spent_outputs = outputs
+            .keys()
+            .filter(|output_id| !output_ids.contains(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let created_outputs = output_ids
+            .into_iter()
+            .filter(|output_id| !outputs.contains_key(output_id))
+            .collect::<Vec<_>>();
+
+        if spent_outputs.is_empty() && created_outputs.is_empty() {
+            return Ok(None);
+        }
+
+        for output in self.get_outputs(&created_outputs).await? {
+            outputs.insert(*output.metadata().output_id(), output.output().amount());
+        }
+        for output_id in &spent_outputs {
+            outputs.remove(output_id);
+        }
+
+        Ok(Some(BalanceChange {
+            address,
+            amount: outputs.values().sum(),
+            created_outputs,
+            spent_outputs,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{
+        client::{
+            constants::SHIMMER_TESTNET_BECH32_HRP,
+            node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+        },
+        types::{
+            api::core::response::OutputWithMetadataResponse,
+            block::{
+                address::{Address, Ed25519Address, ToBech32Ext},
+                output::{
+                    dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata,
+                },
+                payload::transaction::TransactionId,
+                protocol::ProtocolParameters,
+                BlockId,
+            },
+        },
+    };
+
+    /// Serves the basic outputs of addresses, which can be changed while the client is used.
+    struct OutputsTransport {
+        // address, output id and amount of every unspent output
+        outputs: Arc<Mutex<Vec<(Bech32Address, OutputId, u64)>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpTransport for OutputsTransport {
+        async fn send(&self, request: HttpRequest) -> crate::client::node_api::error::Result<HttpResponse> {
+            let outputs = self.outputs.lock().unwrap();
+            let path = request.url.path();
+            let body = if path == "/api/routes" {
+                serde_json::json!({ "routes": ["core/v2", "indexer/v1"] }).to_string()
+            } else if path == "/api/indexer/v1/outputs/basic" {
+                let (_, address) = request.url.query_pairs().find(|(name, _)| name == "address").unwrap();
+                let items = outputs
+                    .iter()
+                    .filter(|(output_address, ..)| output_address.to_string() == address)
+                    .map(|(_, output_id, _)| output_id.to_string())
+                    .collect::<Vec<_>>();
+                serde_json::json!({ "ledgerIndex": 1, "cursor": null, "items": items }).to_string()
+            } else if let Some(output_id) = path.strip_prefix("/api/core/v2/outputs/") {
+                let output_id = output_id.parse::<OutputId>().unwrap();
+                let (address, _, amount) = outputs.iter().find(|(_, id, _)| id == &output_id).unwrap();
+                let output = BasicOutputBuilder::new_with_amount(*amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(*address))
+                    .finish_output(ProtocolParameters::default().token_supply())
+                    .unwrap();
+                serde_json::to_string(&OutputWithMetadataResponse {
+                    metadata: OutputMetadata::new(BlockId::new([0; 32]), output_id, false, None, None, None, 1, 0, 0),
+                    output: OutputDto::from(&output),
+                })
+                .unwrap()
+            } else {
+                return Ok(HttpResponse {
+                    status: 404,
+                    headers: Vec::new(),
+                    body: Vec::new(),
+                });
+            };
+
+            Ok(HttpResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: body.into_bytes(),
+            })
+        }
+    }
+
+    fn address(byte: u8) -> Bech32Address {
+        Address::from(Ed25519Address::new([byte; 32])).to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+    }
+
+    fn output_id(index: u16) -> OutputId {
+        OutputId::new(TransactionId::new([1; 32]), index).unwrap()
+    }
+
+    async fn client(outputs: Arc<Mutex<Vec<(Bech32Address, OutputId, u64)>>>) -> Client {
+        Client::builder()
+            .with_node("http://localhost:14265")
+            .unwrap()
+            .with_ignore_node_health()
+            .with_protocol_parameters(ProtocolParameters::default())
+            .with_http_client(OutputsTransport { outputs })
+            .finish()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn balance_changes() {
+        let watched = address(1);
+        let outputs = Arc::new(Mutex::new(vec![
+            (watched, output_id(0), 1_000_000),
+            (watched, output_id(1), 2_000_000),
+            (address(2), output_id(2), 5_000_000),
+        ]));
+        let client = client(outputs.clone()).await;
+        let mut known_outputs = HashMap::new();
+
+        // The first change reports the outputs the address already has
+        let mut change = client
+            .address_balance_change(watched, &mut known_outputs)
+            .await
+            .unwrap()
+            .unwrap();
+        change.created_outputs.sort();
+        assert_eq!(change.address, watched);
+        assert_eq!(change.amount, 3_000_000);
+        assert_eq!(change.created_outputs, [output_id(0), output_id(1)]);
+        assert!(change.spent_outputs.is_empty());
+
+        // Nothing changed
+        assert_eq!(
+            client
+                .address_balance_change(watched, &mut known_outputs)
+                .await
+                .unwrap(),
+            None
+        );
+
+        // One output was spent and a new one created
+        outputs.lock().unwrap()[0] = (watched, output_id(3), 500_000);
+        assert_eq!(
+            client
+                .address_balance_change(watched, &mut known_outputs)
+                .await
+                .unwrap(),
+            Some(BalanceChange {
+                address: watched,
+                amount: 2_500_000,
+                created_outputs: vec![output_id(3)],
+                spent_outputs: vec![output_id(0)],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_reports_changed_addresses() {
+        let funded = address(1);
+        let outputs = Arc::new(Mutex::new(vec![(funded, output_id(0), 1_000_000)]));
+        let client = client(outputs).await;
+
+        let mut watch = client.watch_addresses([funded, address(2)]);
+        let change = tokio::time::timeout(Duration::from_secs(5), watch.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(change.address, funded);
+        assert_eq!(change.amount, 1_000_000);
+        assert_eq!(change.created_outputs, [output_id(0)]);
+
+        // The address without outputs has no change to report
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), watch.next())
+                .await
+                .is_err()
+        );
+    }
+}
@@ -7,16 +7,24 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 use serde::{Deserialize, Serialize};
 
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+use crate::client::api::pow::PowThreadPool;
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
-        constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
+        api::pow::PowServerOptions,
+        constants::{
+            DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL,
+            PROTOCOL_PARAMETERS_UPDATES_CAPACITY,
+        },
         error::Result,
         node_manager::{
             builder::validate_url,
-            node::{Node, NodeAuth},
+            http_client::{HttpTransport, SharedHttpTransport},
+            node::{JwtRefresh, Node, NodeAuth},
         },
+        retry_policy::RetryPolicy,
         Client,
     },
     types::block::protocol::ProtocolParameters,
@@ -44,10 +52,25 @@ pub struct ClientBuilder {
     /// Timeout when sending a block that requires remote proof of work
     #[serde(default = "default_remote_pow_timeout")]
     pub remote_pow_timeout: Duration,
+    /// How failed requests are retried
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// PoW server the PoW is delegated to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pow_server: Option<PowServerOptions>,
     /// The amount of threads to be used for proof of work
     #[cfg(not(target_family = "wasm"))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pow_worker_count: Option<usize>,
+    /// The niceness of the threads used for proof of work
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pow_worker_niceness: Option<i32>,
+    /// Thread pool the proof of work is done on, instead of spawning threads for it
+    #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[serde(skip)]
+    pub pow_thread_pool: Option<PowThreadPool>,
     /// The maximum parallel API requests
     #[cfg(not(target_family = "wasm"))]
     #[serde(default = "default_max_parallel_api_requests")]
@@ -76,6 +99,8 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            fixed_protocol_parameters: false,
+            protocol_parameters_updated_at: None,
         }
     }
 }
@@ -89,9 +114,15 @@ impl Default for ClientBuilder {
             network_info: NetworkInfo::default(),
             api_timeout: DEFAULT_API_TIMEOUT,
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
+            retry_policy: RetryPolicy::default(),
+            pow_server: None,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
+            pow_worker_niceness: None,
+            #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+            pow_thread_pool: None,
+            #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: super::constants::MAX_PARALLEL_API_REQUESTS,
         }
     }
@@ -152,12 +183,29 @@ impl ClientBuilder {
         Ok(self)
     }
 
-    /// Adds an IOTA node by its URL with optional jwt and or basic authentication
+    /// Adds an IOTA node by its URL with optional authentication: a jwt, basic authentication, additional headers and
+    /// a client certificate for mutual TLS
     pub fn with_node_auth(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
         self.node_manager_builder = self.node_manager_builder.with_node_auth(url, auth)?;
         Ok(self)
     }
 
+    /// Sets a callback to get a new jwt when a node rejects a request as unauthorized, the request is then retried once
+    pub fn with_jwt_refresh(mut self, jwt_refresh: JwtRefresh) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_jwt_refresh(jwt_refresh);
+        self
+    }
+
+    /// Sets the transport that sends the requests to nodes, instead of the built-in HTTP client. Can be used to route
+    /// requests through a proxy or to instrument them. Client certificates of nodes are only used by the built-in
+    /// client, MQTT and faucet requests don't use the transport.
+    pub fn with_http_client(mut self, http_client: impl HttpTransport + 'static) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_http_transport(SharedHttpTransport::new(http_client));
+        self
+    }
+
     /// Adds a list of IOTA nodes by their URLs.
     pub fn with_nodes(mut self, urls: &[&str]) -> Result<Self> {
         self.node_manager_builder = self.node_manager_builder.with_nodes(urls)?;
@@ -170,6 +218,21 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the protocol parameters of the network, they're then not updated from the nodes. Nodes of other networks
+    /// are ignored, can be used for private tangles or together with [`ClientBuilder::with_offline_mode()`].
+    pub fn with_protocol_parameters(mut self, protocol_parameters: impl Into<ProtocolParameters>) -> Self {
+        self.network_info = self.network_info.with_protocol_parameters(protocol_parameters);
+        self.network_info.fixed_protocol_parameters = true;
+        self
+    }
+
+    /// Never contacts a node, requests to nodes fail with [`Error::Offline`](crate::client::Error::Offline). Requires
+    /// the protocol parameters to be set with [`ClientBuilder::with_protocol_parameters()`].
+    pub fn with_offline_mode(mut self) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_offline_mode();
+        self
+    }
+
     /// Ignores the node health status.
     /// Every node will be considered healthy and ready to use.
     pub fn with_ignore_node_health(mut self) -> Self {
@@ -196,6 +259,13 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets whether blocks with finished PoW should be posted to all healthy nodes at once, see
+    /// [`ClientInner::broadcast_block()`](crate::client::ClientInner::broadcast_block).
+    pub fn with_broadcast(mut self, broadcast: bool) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_broadcast(broadcast);
+        self
+    }
+
     /// Sets the MQTT broker options.
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
@@ -217,6 +287,23 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the niceness of the threads used for PoW, from -20 (highest priority) to 19 (lowest priority), so local PoW
+    /// doesn't slow down other processes of the host. It's only applied on Linux.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_pow_worker_niceness(mut self, niceness: impl Into<Option<i32>>) -> Self {
+        self.pow_worker_niceness = niceness.into();
+        self
+    }
+
+    /// Sets a thread pool the PoW is done on, instead of spawning threads for it. The worker count defaults to the
+    /// number of threads of the pool and the niceness isn't applied to them.
+    #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn with_pow_thread_pool(mut self, thread_pool: impl Into<Arc<rayon::ThreadPool>>) -> Self {
+        self.pow_thread_pool = Some(PowThreadPool::new(thread_pool));
+        self
+    }
+
     /// Sets whether the PoW should be done locally in case a node doesn't support remote PoW.
     pub fn with_fallback_to_local_pow(mut self, fallback_to_local_pow: bool) -> Self {
         self.network_info.fallback_to_local_pow = fallback_to_local_pow;
@@ -241,6 +328,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets how failed requests are retried, by default they aren't.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Delegates the PoW to a dedicated PoW server instead of doing it locally or by the node. The nonce found by the
+    /// server is verified before the block is sent. Requests to the server use the remote PoW timeout.
+    pub fn with_pow_server(mut self, options: PowServerOptions) -> Self {
+        self.pow_server.replace(options);
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -260,6 +360,8 @@ impl ClientBuilder {
     pub async fn finish(self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self.validate_offline_mode()?;
+        let offline = self.node_manager_builder.offline;
         let node_sync_interval = self.node_manager_builder.node_sync_interval;
         let ignore_node_health = self.node_manager_builder.ignore_node_health;
         let nodes = self
@@ -278,30 +380,43 @@ impl ClientBuilder {
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+            retry_policy: RwLock::new(self.retry_policy),
+            pow_server: RwLock::new(self.pow_server.map(Into::into)),
+            block_builder_middleware: Default::default(),
+            tag_codecs: Default::default(),
+            protocol_parameters_updates: tokio::sync::broadcast::channel(PROTOCOL_PARAMETERS_UPDATES_CAPACITY).0,
             pow_worker_count: RwLock::new(self.pow_worker_count),
+            pow_worker_niceness: RwLock::new(self.pow_worker_niceness),
+            #[cfg(feature = "rayon")]
+            pow_thread_pool: RwLock::new(self.pow_thread_pool),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
-                client: Default::default(),
+                connection: Default::default(),
                 topic_handlers: Default::default(),
                 broker_options: RwLock::new(self.broker_options),
                 sender: RwLock::new(mqtt_event_tx),
                 receiver: RwLock::new(mqtt_event_rx),
+                hrp_follower: Default::default(),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
         });
 
-        client_inner.sync_nodes(&nodes, ignore_node_health).await?;
-        let client_clone = client_inner.clone();
-
-        let sync_handle = tokio::spawn(async move {
-            client_clone
-                .start_sync_process(nodes, node_sync_interval, ignore_node_health)
-                .await
-        });
+        let sync_handle = if offline {
+            None
+        } else {
+            client_inner.sync_nodes(&nodes, ignore_node_health).await?;
+            let client_clone = client_inner.clone();
+
+            Some(crate::client::runtime::spawn(async move {
+                client_clone
+                    .start_sync_process(nodes, node_sync_interval, ignore_node_health)
+                    .await
+            }))
+        };
 
         let client = Client {
             inner: client_inner,
-            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(Some(sync_handle)))),
+            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(sync_handle))),
         };
 
         Ok(client)
@@ -312,6 +427,8 @@ impl ClientBuilder {
     pub async fn finish(self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self.validate_offline_mode()?;
+
         #[cfg(feature = "mqtt")]
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
@@ -321,13 +438,19 @@ impl ClientBuilder {
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                retry_policy: RwLock::new(self.retry_policy),
+                pow_server: RwLock::new(self.pow_server.map(Into::into)),
+                block_builder_middleware: Default::default(),
+                tag_codecs: Default::default(),
+                protocol_parameters_updates: tokio::sync::broadcast::channel(PROTOCOL_PARAMETERS_UPDATES_CAPACITY).0,
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
-                    client: Default::default(),
+                    connection: Default::default(),
                     topic_handlers: Default::default(),
                     broker_options: RwLock::new(self.broker_options),
                     sender: RwLock::new(mqtt_event_tx),
                     receiver: RwLock::new(mqtt_event_rx),
+                    hrp_follower: Default::default(),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
             }),
@@ -336,6 +459,15 @@ impl ClientBuilder {
         Ok(client)
     }
 
+    fn validate_offline_mode(&self) -> Result<()> {
+        if self.node_manager_builder.offline && !self.network_info.fixed_protocol_parameters {
+            return Err(crate::client::Error::MissingParameter(
+                "protocol parameters for offline mode",
+            ));
+        }
+        Ok(())
+    }
+
     pub async fn from_client(client: &Client) -> Self {
         Self {
             node_manager_builder: NodeManagerBuilder::from(&*client.node_manager.read().await),
@@ -344,9 +476,15 @@ impl ClientBuilder {
             network_info: client.network_info.read().await.clone(),
             api_timeout: client.get_timeout().await,
             remote_pow_timeout: client.get_remote_pow_timeout().await,
+            retry_policy: client.retry_policy().await,
+            pow_server: client.get_pow_server().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
+            pow_worker_niceness: *client.pow_worker_niceness.read().await,
+            #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+            pow_thread_pool: client.pow_thread_pool.read().await.clone(),
+            #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: client.request_pool.size().await,
         }
     }
@@ -371,9 +509,43 @@ pub struct NetworkInfo {
     /// The latest cached milestone timestamp.
     #[serde(skip)]
     pub latest_milestone_timestamp: Option<u32>,
+    /// Whether the protocol parameters were set by the user and aren't updated from the nodes.
+    #[serde(default)]
+    pub fixed_protocol_parameters: bool,
+    /// When the protocol parameters were last received from a node, in seconds since the Unix epoch.
+    #[serde(skip)]
+    pub protocol_parameters_updated_at: Option<u64>,
 }
 
 impl NetworkInfo {
+    /// Sets the protocol parameters received from a node at a milestone, if they aren't fixed. Returns the update if
+    /// they changed since they were received the last time.
+    pub(crate) fn update_protocol_parameters(
+        &mut self,
+        protocol_parameters: &ProtocolParameters,
+        milestone_index: u32,
+    ) -> Option<ProtocolParametersUpdate> {
+        if self.fixed_protocol_parameters {
+            return None;
+        }
+
+        let previously_updated = self
+            .protocol_parameters_updated_at
+            .replace(crate::utils::unix_timestamp_now().as_secs())
+            .is_some();
+        if &self.protocol_parameters == protocol_parameters {
+            return None;
+        }
+
+        let previous = std::mem::replace(&mut self.protocol_parameters, protocol_parameters.clone());
+        // The first parameters received from a node replace the default ones, that's not an update of the network
+        previously_updated.then(|| ProtocolParametersUpdate {
+            milestone_index,
+            previous,
+            current: protocol_parameters.clone(),
+        })
+    }
+
     pub fn with_protocol_parameters(mut self, protocol_parameters: impl Into<ProtocolParameters>) -> Self {
         self.protocol_parameters = protocol_parameters.into();
         self
@@ -400,6 +572,18 @@ impl NetworkInfo {
     }
 }
 
+/// A change of the protocol parameters of the network, e.g. a protocol upgrade, noticed while syncing the nodes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersUpdate {
+    /// The index of the latest milestone when the change was noticed.
+    pub milestone_index: u32,
+    /// The protocol parameters before the change.
+    pub previous: ProtocolParameters,
+    /// The protocol parameters after the change.
+    pub current: ProtocolParameters,
+}
+
 fn default_local_pow() -> bool {
     #[cfg(not(target_family = "wasm"))]
     {
@@ -10,20 +10,33 @@ use crate::types::block::address::Hrp;
 /// Default timeout for all API requests apart from post_block with remote PoW
 pub(crate) const DEFAULT_API_TIMEOUT: Duration = Duration::from_secs(15);
 pub(crate) const DEFAULT_REMOTE_POW_API_TIMEOUT: Duration = Duration::from_secs(100);
-pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
-pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 /// Interval in seconds when new tips will be requested during PoW, so the final block always will be attached to a
 /// new part of the Tangle
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+/// Interval in which all addresses watched with `Client::watch_addresses()` are checked for changes
+pub const DEFAULT_ADDRESS_WATCH_INTERVAL: Duration = Duration::from_secs(10);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+/// Number of recent requests per node that are taken into account for its health score
+pub(crate) const NODE_SCORE_SAMPLE_SIZE: usize = 100;
+/// Error rate above which a node is considered flaky and gets demoted
+pub(crate) const NODE_SCORE_FLAKY_ERROR_RATE: f64 = 0.5;
+/// Max length of the data of a chunk sent with `Client::send_data_chunked()`, leaves room for the chunk header and
+/// the largest possible tag and parents in a block
+pub(crate) const CHUNKED_DATA_CHUNK_LENGTH: usize = 31 * 1024;
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
+/// Default maximum number of PoW jobs sent to a PoW server at the same time
+pub(crate) const DEFAULT_MAX_CONCURRENT_POW_JOBS: usize = 4;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
+/// Number of protocol parameters updates that are kept for subscribers which didn't receive them yet
+pub(crate) const PROTOCOL_PARAMETERS_UPDATES_CAPACITY: usize = 16;
+/// Number of node sync intervals after which protocol parameters that weren't received again are outdated
+pub(crate) const PROTOCOL_PARAMETERS_MAX_AGE_IN_SYNC_INTERVALS: u32 = 5;
 /// Delay for caching a node info response in WASM runtime
 #[cfg(target_family = "wasm")]
 pub(crate) const CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS: u32 = 60;
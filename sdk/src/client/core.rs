@@ -8,20 +8,26 @@ use std::{sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 #[cfg(feature = "mqtt")]
 use {
-    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandlerMap},
-    rumqttc::AsyncClient as MqttClient,
+    crate::client::node_api::mqtt::{BrokerOptions, MqttConnection, MqttEvent, TopicHandlerMap},
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 
 #[cfg(not(target_family = "wasm"))]
 use super::request_pool::RequestPool;
+#[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+use crate::client::api::pow::PowThreadPool;
 #[cfg(target_family = "wasm")]
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
-        builder::{ClientBuilder, NetworkInfo},
+        api::{
+            pow::{PowServer, PowServerOptions},
+            BlockBuilderMiddleware, TagCodec,
+        },
+        builder::{ClientBuilder, NetworkInfo, ProtocolParametersUpdate},
         error::Result,
         node_manager::NodeManager,
+        retry_policy::RetryPolicy,
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
@@ -51,9 +57,25 @@ pub struct ClientInner {
     pub(crate) api_timeout: RwLock<Duration>,
     /// HTTP request timeout for remote PoW API call.
     pub(crate) remote_pow_timeout: RwLock<Duration>,
+    /// How failed requests are retried.
+    pub(crate) retry_policy: RwLock<RetryPolicy>,
+    /// PoW server that does the PoW instead of the client or the node.
+    pub(crate) pow_server: RwLock<Option<PowServer>>,
+    /// Middleware called while building and submitting blocks.
+    pub(crate) block_builder_middleware: RwLock<Vec<Arc<dyn BlockBuilderMiddleware>>>,
+    /// Codecs of tagged data, with the tag namespaces they're registered for.
+    pub(crate) tag_codecs: RwLock<Vec<(Vec<u8>, Arc<dyn TagCodec>)>>,
+    /// Sender of the protocol parameters updates noticed while syncing the nodes.
+    pub(crate) protocol_parameters_updates: tokio::sync::broadcast::Sender<ProtocolParametersUpdate>,
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// Niceness of the threads of local PoW.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) pow_worker_niceness: RwLock<Option<i32>>,
+    /// Thread pool local PoW is done on.
+    #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+    pub(crate) pow_thread_pool: RwLock<Option<PowThreadPool>>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
     #[cfg(target_family = "wasm")]
@@ -63,7 +85,7 @@ pub struct ClientInner {
 }
 
 #[derive(Default)]
-pub(crate) struct SyncHandle(pub(crate) Option<tokio::task::JoinHandle<()>>);
+pub(crate) struct SyncHandle(pub(crate) Option<crate::client::runtime::JoinHandle<()>>);
 
 impl Drop for SyncHandle {
     fn drop(&mut self) {
@@ -76,12 +98,15 @@ impl Drop for SyncHandle {
 
 #[cfg(feature = "mqtt")]
 pub(crate) struct MqttInner {
-    /// A MQTT client to subscribe/unsubscribe to topics.
-    pub(crate) client: RwLock<Option<MqttClient>>,
+    /// The broker connection to subscribe/unsubscribe to topics, shared with the other clients connected to the
+    /// broker.
+    pub(crate) connection: RwLock<Option<Arc<MqttConnection>>>,
     pub(crate) topic_handlers: RwLock<TopicHandlerMap>,
     pub(crate) broker_options: RwLock<BrokerOptions>,
     pub(crate) sender: RwLock<WatchSender<MqttEvent>>,
     pub(crate) receiver: RwLock<WatchReceiver<MqttEvent>>,
+    /// Whether the task that moves the address topics to the hrp of a new network was started.
+    pub(crate) hrp_follower: std::sync::atomic::AtomicBool,
 }
 
 impl std::fmt::Debug for Client {
@@ -91,6 +116,7 @@ impl std::fmt::Debug for Client {
         #[cfg(feature = "mqtt")]
         d.field("broker_options", &self.mqtt.broker_options);
         d.field("network_info", &self.network_info);
+        d.field("pow_server", &self.pow_server);
         #[cfg(not(target_family = "wasm"))]
         d.field("request_pool", &self.request_pool);
         d.finish()
@@ -112,7 +138,7 @@ impl ClientInner {
         // difficulty or the byte cost could change via a milestone, so we request the node info every time, so we don't
         // create invalid transactions/blocks.
         #[cfg(target_family = "wasm")]
-        {
+        if !self.network_info.read().await.fixed_protocol_parameters {
             let current_time = crate::utils::unix_timestamp_now().as_secs() as u32;
             if let Some(last_sync) = *self.last_sync.lock().await {
                 if current_time < last_sync {
@@ -121,13 +147,24 @@ impl ClientInner {
             }
             let info = self.get_info().await?.node_info;
             let mut client_network_info = self.network_info.write().await;
-            client_network_info.protocol_parameters = info.protocol.clone();
+            if let Some(update) =
+                client_network_info.update_protocol_parameters(&info.protocol, info.status.latest_milestone.index)
+            {
+                // Sending only fails if there are no subscribers
+                self.protocol_parameters_updates.send(update).ok();
+            }
             *self.last_sync.lock().await = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
 
         Ok(self.network_info.read().await.clone())
     }
 
+    /// Subscribes to the updates of the protocol parameters, e.g. protocol upgrades, which are noticed while syncing
+    /// the nodes. Updates that aren't received in time are dropped, the receiver then gets a `Lagged` error.
+    pub fn subscribe_protocol_parameters_updates(&self) -> tokio::sync::broadcast::Receiver<ProtocolParametersUpdate> {
+        self.protocol_parameters_updates.subscribe()
+    }
+
     /// Gets the protocol parameters of the node we're connecting to.
     pub async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
         Ok(self.get_network_info().await?.protocol_parameters)
@@ -178,9 +215,23 @@ impl ClientInner {
         self.network_info.read().await.tips_interval
     }
 
-    /// returns if local pow should be used or not
+    /// returns if local pow should be used or not, the PoW is also done by the client if it uses a PoW server
     pub async fn get_local_pow(&self) -> bool {
-        self.network_info.read().await.local_pow
+        self.network_info.read().await.local_pow || self.pow_server.read().await.is_some()
+    }
+
+    /// Returns the options of the PoW server, if one is used.
+    pub async fn get_pow_server(&self) -> Option<PowServerOptions> {
+        self.pow_server
+            .read()
+            .await
+            .as_ref()
+            .map(|pow_server| pow_server.options.clone())
+    }
+
+    /// Sets the PoW server the PoW is delegated to, or stops using one.
+    pub async fn set_pow_server(&self, options: impl Into<Option<PowServerOptions>>) {
+        *self.pow_server.write().await = options.into().map(PowServer::from);
     }
 
     pub(crate) async fn get_timeout(&self) -> Duration {
@@ -191,6 +242,20 @@ impl ClientInner {
         *self.remote_pow_timeout.read().await
     }
 
+    /// Returns the policy for retrying failed requests, the one set with [`RetryPolicy::scope()`] for the current
+    /// call or the one of the client.
+    pub async fn retry_policy(&self) -> RetryPolicy {
+        match RetryPolicy::scoped() {
+            Some(retry_policy) => retry_policy,
+            None => self.retry_policy.read().await.clone(),
+        }
+    }
+
+    /// Sets the policy for retrying failed requests.
+    pub async fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        *self.retry_policy.write().await = retry_policy;
+    }
+
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
         self.network_info.read().await.fallback_to_local_pow
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 use packable::error::UnexpectedEOF;
 use serde::{
     ser::{SerializeMap, Serializer},
-    Serialize,
+    Deserialize, Serialize,
 };
 
 use crate::{
@@ -39,6 +39,9 @@ pub enum Error {
     /// Block types error
     #[error("{0}")]
     Block(#[from] crate::types::block::Error),
+    /// Chunked data couldn't be sent or reassembled
+    #[error("chunked data error: {0}")]
+    ChunkedData(String),
     /// The wallet account has enough funds, but split on too many outputs
     #[error("the wallet account has enough funds, but split on too many outputs: {0}, max. is 128, consolidate them")]
     ConsolidationRequired(usize),
@@ -56,6 +59,12 @@ pub enum Error {
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
+    /// The milestone returned by a node couldn't be verified
+    #[error("invalid milestone: {0}")]
+    InvalidMilestone(String),
+    /// A proof of inclusion couldn't be validated
+    #[error("invalid proof of inclusion: {0}")]
+    InvalidProofOfInclusion(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
@@ -78,9 +87,23 @@ pub enum Error {
     /// JSON error
     #[error("{0}")]
     Json(#[from] serde_json::Error),
+    /// The payload is too large to fit into a block
+    #[error(
+        "the payload is too large to fit into a block. Its length is {actual}, max length is {max}. Data can be split \
+         into chunks with `Client::send_data_chunked()`, outputs can be sent in several transactions"
+    )]
+    PayloadTooLarge {
+        /// The max length.
+        max: usize,
+        /// The found length.
+        actual: usize,
+    },
     /// Missing required parameters
     #[error("must provide required parameter: {0}")]
     MissingParameter(&'static str),
+    /// A required signer didn't provide a signature
+    #[error("missing signature from required signer: {0}")]
+    MissingSignature(String),
     /// Error on API request
     #[error("node error: {0}")]
     Node(#[from] crate::client::node_api::error::Error),
@@ -90,6 +113,9 @@ pub enum Error {
     /// Requested output id not found for this type
     #[error("No output found for {0}")]
     NoOutput(String),
+    /// Error on parsing a user-provided address or ID
+    #[error("{0}")]
+    Parse(#[from] crate::utils::parse::ParseError),
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
@@ -115,22 +141,78 @@ pub enum Error {
         /// The minimum quorum threshold.
         minimum_threshold: usize,
     },
+    /// A signing interceptor rejected the transaction
+    #[error("signing rejected: {0}")]
+    SigningRejected(String),
     /// Specifically used for `TryInfo` implementations for `SecretManager`.
     #[error("cannot unwrap a SecretManager: type mismatch!")]
     SecretManagerMismatch,
+    /// The secret manager is locked and needs to be unlocked before it can be used
+    #[error("the secret manager is locked")]
+    SecretManagerLocked,
+    /// The secret used to unlock a secret manager isn't the one it was created with
+    #[error("the secret doesn't match the one of the secret manager")]
+    SecretMismatch,
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
+    /// No node accepted a broadcast block
+    #[error("no node accepted block {0}")]
+    BroadcastFailed(String),
+    /// Error of the PoW server
+    #[error("PoW server error: {0}")]
+    PowServer(String),
+    /// Block builder middleware aborted a block
+    #[error("block builder middleware error: {0}")]
+    BlockBuilderMiddleware(String),
+    /// The client is in offline mode
+    #[error("the client is in offline mode and doesn't contact nodes")]
+    Offline,
+    /// A transaction or its addresses are for another network than the one of the nodes
+    #[error("network mismatch: {subject} is {provided}, but the network of the nodes has {expected}")]
+    NetworkMismatch {
+        /// What belongs to another network.
+        subject: &'static str,
+        /// The network id or bech32 HRP it has.
+        provided: String,
+        /// The network id or bech32 HRP of the network of the nodes.
+        expected: String,
+    },
+    /// The PoW of a block that can only be posted with finished PoW isn't done
+    #[error("PoW score {score} of block {block_id} is below the minimum PoW score {min_pow_score}")]
+    InsufficientPow {
+        /// The id of the block.
+        block_id: crate::types::block::BlockId,
+        /// The PoW score of the block.
+        score: f64,
+        /// The minimum PoW score of the network.
+        min_pow_score: u32,
+    },
+    /// The protocol parameters weren't received from a node recently, so they could be outdated
+    #[error("outdated protocol parameters: {0}")]
+    OutdatedProtocolParameters(String),
+    /// A value couldn't be encoded to or decoded from the data of a tagged data payload
+    #[error("tag codec error: {0}")]
+    TagCodec(String),
     /// Error when building tagged_data blocks
     #[error("error when building tagged_data block: {0}")]
     TaggedData(String),
+    /// A partial signature was provided for an input that doesn't require a signature unlock
+    #[error("unexpected partial signature for input {0}")]
+    UnexpectedPartialSignature(u16),
+    /// A signing request doesn't match the transaction it contains
+    #[error("invalid signing request: {0}")]
+    InvalidSigningRequest(String),
+    /// The funds requested from a faucet didn't arrive in time
+    #[error("funds didn't arrive on address {0}")]
+    FundsNotReceived(String),
     /// The block cannot be included into the Tangle
     #[error("block ID `{0}` couldn't get included into the Tangle")]
     TangleInclusion(String),
     #[cfg(not(target_family = "wasm"))]
-    /// Tokio task join error
+    /// Task join error
     #[error("{0}")]
-    TaskJoin(#[from] tokio::task::JoinError),
+    TaskJoin(#[from] crate::client::runtime::JoinError),
     /// Local time doesn't match the time of the latest milestone timestamp
     #[error(
         "local time {current_time} doesn't match the time of the latest milestone timestamp: {milestone_timestamp}"
@@ -142,7 +224,7 @@ pub enum Error {
         milestone_timestamp: u32,
     },
     /// The semantic validation of a transaction failed.
-    #[error("the semantic validation of a transaction failed with conflict reason: {} - {0:?}", *.0 as u8)]
+    #[error("the semantic validation of a transaction failed with conflict reason {}: {0}", *.0 as u8)]
     TransactionSemantic(ConflictReason),
     /// An indexer API request contains a query parameter not supported by the endpoint.
     #[error("an indexer API request contains a query parameter not supported by the endpoint: {0}.")]
@@ -165,6 +247,9 @@ pub enum Error {
     /// Missing BIP32 chain to sign with.
     #[error("missing BIP32 chain to sign with")]
     MissingBip32Chain,
+    /// No node provides the API
+    #[error("no node provides the {0} API")]
+    MissingCapability(crate::client::node_manager::capability::NodeCapability),
 
     /// Participation error
     #[cfg(feature = "participation")]
@@ -172,6 +257,10 @@ pub enum Error {
     #[error("{0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
 
+    /// Hardware signer error
+    #[error("{0}")]
+    HardwareSigner(#[from] crate::client::secret::hardware::Error),
+
     /// Ledger error
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
@@ -191,13 +280,123 @@ pub enum Error {
     Stronghold(#[from] crate::client::stronghold::Error),
 }
 
+/// The layer an [`Error`] originates from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCategory {
+    /// Node connection, request and quorum errors.
+    Network,
+    /// Errors of protocol types, their encoding and their acceptance by the network.
+    Protocol,
+    /// Key management and signing errors.
+    Signing,
+    /// Errors of the secret storage.
+    Storage,
+    /// Invalid user input.
+    Validation,
+}
+
+impl Error {
+    /// Returns a stable numeric code for the error, that can be used by bindings to distinguish errors without parsing
+    /// messages. Codes are never reused, the thousands digit is the [`ErrorCategory`].
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Node(_) => 1001,
+            Self::HealthyNodePoolEmpty => 1002,
+            Self::QuorumPoolSizeError { .. } => 1003,
+            Self::QuorumThresholdError { .. } => 1004,
+            Self::TimeNotSynced { .. } => 1005,
+            Self::Url(_) => 1006,
+            Self::UrlAuth(_) => 1007,
+            Self::UrlValidation(_) => 1008,
+            #[cfg(not(target_family = "wasm"))]
+            Self::TaskJoin(_) => 1009,
+            #[cfg(feature = "mqtt")]
+            Self::Mqtt(_) => 1010,
+            Self::FundsNotReceived(_) => 1011,
+            Self::Offline => 1012,
+            Self::PowServer(_) => 1013,
+            Self::MissingCapability(_) => 1014,
+            Self::BroadcastFailed(_) => 1015,
+            Self::ApiTypes(_) => 2001,
+            Self::Block(_) => 2002,
+            Self::Unpack(_) => 2003,
+            Self::TransactionSemantic(_) => 2004,
+            Self::TangleInclusion(_) => 2005,
+            Self::NoNeedPromoteOrReattach(_) => 2006,
+            Self::Bech32HrpMismatch { .. } => 2007,
+            Self::ChunkedData(_) => 2008,
+            #[cfg(feature = "participation")]
+            Self::Participation(_) => 2009,
+            Self::InvalidMilestone(_) => 2010,
+            Self::InvalidProofOfInclusion(_) => 2011,
+            Self::OutdatedProtocolParameters(_) => 2012,
+            Self::TagCodec(_) => 2013,
+            Self::NetworkMismatch { .. } => 2014,
+            Self::InsufficientPow { .. } => 2015,
+            Self::Blake2b256(_) => 3001,
+            Self::Crypto(_) => 3002,
+            Self::InvalidMnemonic(_) => 3003,
+            Self::InputAddressNotFound { .. } => 3004,
+            Self::MissingSignature(_) => 3005,
+            Self::UnexpectedPartialSignature(_) => 3006,
+            Self::MissingBip32Chain => 3007,
+            Self::PlaceholderSecretManager => 3008,
+            Self::SecretManagerMismatch => 3009,
+            Self::HardwareSigner(_) => 3010,
+            #[cfg(feature = "ledger_nano")]
+            Self::Ledger(_) => 3011,
+            Self::SigningRejected(_) => 3012,
+            Self::SecretManagerLocked => 3013,
+            Self::SecretMismatch => 3014,
+            Self::InvalidSigningRequest(_) => 3015,
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(_) => 4001,
+            Self::PoisonError => 4002,
+            Self::ConsolidationRequired(_) => 5001,
+            Self::InvalidAmount(_) => 5002,
+            Self::InvalidRegularTransactionEssenceLength { .. } => 5003,
+            Self::InvalidTransactionPayloadLength { .. } => 5004,
+            Self::MissingParameter(_) => 5005,
+            Self::NoOutput(_) => 5006,
+            Self::TaggedData(_) => 5007,
+            Self::UnsupportedQueryParameter(_) => 5008,
+            Self::InputSelection(_) => 5009,
+            Self::Json(_) => 5010,
+            Self::PrefixHex(_) => 5011,
+            Self::BlockBuilderMiddleware(_) => 5012,
+            Self::PayloadTooLarge { .. } => 5013,
+            Self::Parse(_) => 5014,
+        }
+    }
+
+    /// Returns the layer the error originates from.
+    pub fn category(&self) -> ErrorCategory {
+        match self.code() / 1000 {
+            1 => ErrorCategory::Network,
+            2 => ErrorCategory::Protocol,
+            3 => ErrorCategory::Signing,
+            4 => ErrorCategory::Storage,
+            _ => ErrorCategory::Validation,
+        }
+    }
+
+    /// Returns the conflict reason if the error is caused by a semantically invalid transaction.
+    pub fn conflict_reason(&self) -> Option<ConflictReason> {
+        match self {
+            Self::TransactionSemantic(conflict_reason) => Some(*conflict_reason),
+            _ => None,
+        }
+    }
+}
+
 // Serialize type with Display error
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_map(Some(2))?;
+        let mut seq = serializer.serialize_map(Some(4))?;
         let mut kind_dbg = format!("{self:?}");
         // Convert first char to lowercase
         if let Some(r) = kind_dbg.get_mut(0..1) {
@@ -208,6 +407,8 @@ impl Serialize for Error {
         let kind = kind_dbg.split([' ', '(']).next().unwrap();
         seq.serialize_entry("type", &kind)?;
         seq.serialize_entry("error", &self.to_string())?;
+        seq.serialize_entry("code", &self.code())?;
+        seq.serialize_entry("category", &self.category())?;
         seq.end()
     }
 }
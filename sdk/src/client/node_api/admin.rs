@@ -0,0 +1,130 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Node management API routes, to manage the peers, the database and the snapshots of a node.
+//!
+//! These routes change the state of a single node, so they're only sent to the primary node, or the first node if no
+//! primary node is set. Nodes usually protect them with a JWT, which can be set in the [`NodeAuth`] of the node.
+//!
+//! [`NodeAuth`]: crate::client::node_manager::node::NodeAuth
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{node_manager::http_client::Response, ClientInner, Error, Result},
+    types::api::core::response::{CreateSnapshotsResponse, PeerResponse, PruneDatabaseResponse},
+};
+
+/// How the database of a node should be pruned.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PruneDatabaseOptions {
+    /// Prunes up to the milestone with this index.
+    Index(u32),
+    /// Prunes all milestones older than this number of milestones.
+    Depth(u32),
+    /// Prunes until the database is smaller than this size, for example "30GB".
+    TargetDatabaseSize(String),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AddPeerRequest<'a> {
+    multi_address: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    alias: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateSnapshotsRequest {
+    index: u32,
+}
+
+impl ClientInner {
+    // Peers routes.
+
+    /// Returns information about a peer of the node.
+    /// GET /api/core/v2/peers/{peerId}
+    pub async fn get_peer(&self, peer_id: &str) -> Result<PeerResponse> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
+
+        Ok(self.admin_request(Method::GET, path, None).await?.into_json().await?)
+    }
+
+    /// Adds a peer to the node by its multiaddress, e.g. `/ip4/127.0.0.1/tcp/15600/p2p/12D3Koo...`.
+    /// POST /api/core/v2/peers
+    pub async fn add_peer(&self, multi_address: &str, alias: Option<&str>) -> Result<PeerResponse> {
+        let path = "api/core/v2/peers";
+        let request = AddPeerRequest { multi_address, alias };
+
+        Ok(self
+            .admin_request(Method::POST, path, Some(serde_json::to_value(request)?))
+            .await?
+            .into_json()
+            .await?)
+    }
+
+    /// Removes a peer from the node.
+    /// DELETE /api/core/v2/peers/{peerId}
+    pub async fn remove_peer(&self, peer_id: &str) -> Result<()> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
+
+        self.admin_request(Method::DELETE, path, None).await?;
+
+        Ok(())
+    }
+
+    // Control routes.
+
+    /// Prunes the database of the node.
+    /// POST /api/core/v2/control/database/prune
+    pub async fn prune_database(&self, options: PruneDatabaseOptions) -> Result<PruneDatabaseResponse> {
+        let path = "api/core/v2/control/database/prune";
+
+        Ok(self
+            .admin_request(Method::POST, path, Some(serde_json::to_value(options)?))
+            .await?
+            .into_json()
+            .await?)
+    }
+
+    /// Creates a full snapshot file of the ledger at the milestone with the given index.
+    /// POST /api/core/v2/control/snapshots/create
+    pub async fn create_snapshot(&self, index: u32) -> Result<CreateSnapshotsResponse> {
+        let path = "api/core/v2/control/snapshots/create";
+        let request = CreateSnapshotsRequest { index };
+
+        Ok(self
+            .admin_request(Method::POST, path, Some(serde_json::to_value(request)?))
+            .await?
+            .into_json()
+            .await?)
+    }
+
+    /// Sends a request to the node the management routes are sent to.
+    async fn admin_request(&self, method: Method, path: &str, json: Option<Value>) -> Result<Response> {
+        let (offline, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.offline, node_manager.http_client.clone())
+        };
+        if offline {
+            return Err(Error::Offline);
+        }
+
+        let mut node = self.get_node().await?;
+        node.url.set_path(path);
+        if let Some((name, password)) = node.auth.as_ref().and_then(|auth| auth.basic_auth_name_pwd.as_ref()) {
+            node.url.set_username(name).map_err(|_| Error::UrlAuth("username"))?;
+            node.url
+                .set_password(Some(password))
+                .map_err(|_| Error::UrlAuth("password"))?;
+        }
+
+        Ok(http_client
+            .request_json(method, node, self.get_timeout().await, json.as_ref())
+            .await?)
+    }
+}
@@ -5,27 +5,56 @@
 
 pub mod routes;
 
+use std::collections::HashSet;
+
+use futures::StreamExt;
+
 use crate::{
     client::{Client, Result},
     types::block::output::{OutputId, OutputMetadata, OutputWithMetadata},
 };
 
 impl Client {
-    /// Request outputs by their output ID in parallel
+    /// Request outputs by their output ID in parallel, duplicated IDs are only requested once
     pub async fn get_outputs(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
-        futures::future::try_join_all(output_ids.iter().map(|id| self.get_output(id))).await
+        self.get_outputs_with_results(output_ids)
+            .await
+            .into_iter()
+            .map(|(_, output)| output)
+            .collect()
     }
 
     /// Request outputs by their output ID in parallel, ignoring failed requests
     /// Useful to get data about spent outputs, that might not be pruned yet
     pub async fn get_outputs_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
-        Ok(
-            futures::future::join_all(output_ids.iter().map(|id| self.get_output(id)))
-                .await
-                .into_iter()
-                .filter_map(Result::ok)
-                .collect(),
-        )
+        Ok(self
+            .get_outputs_with_results(output_ids)
+            .await
+            .into_iter()
+            .filter_map(|(_, output)| output.ok())
+            .collect())
+    }
+
+    /// Request outputs by their output ID, with at most as many parallel requests as the request pool allows.
+    /// Duplicated IDs are only requested once. Returns the result for every unique output ID, in the order of their
+    /// first occurrence, so missing outputs don't make the other requests fail.
+    pub async fn get_outputs_with_results(
+        &self,
+        output_ids: &[OutputId],
+    ) -> Vec<(OutputId, Result<OutputWithMetadata>)> {
+        let mut unique_output_ids = HashSet::new();
+        let output_ids = output_ids
+            .iter()
+            .filter(|output_id| unique_output_ids.insert(**output_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let max_parallel_requests = self.request_pool.size().await.max(1);
+
+        futures::stream::iter(output_ids)
+            .map(|output_id| async move { (output_id, self.get_output(&output_id).await) })
+            .buffered(max_parallel_requests)
+            .collect()
+            .await
     }
 
     /// Requests metadata for outputs by their output ID in parallel, ignoring failed requests
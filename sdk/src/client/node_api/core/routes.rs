@@ -4,25 +4,29 @@
 //! Node core API routes.
 
 use packable::PackableExt;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use url::Url;
 
 use crate::{
     client::{
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+        },
         Client, ClientInner, Error, Result,
     },
     types::{
         api::core::response::{
-            BlockMetadataResponse, InfoResponse, OutputWithMetadataResponse, PeerResponse, ReceiptResponse,
-            ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse, TreasuryResponse, UtxoChangesResponse,
+            BlockMetadataResponse, InfoResponse, OutputWithMetadataResponse, PeerResponse, Receipt, ReceiptsResponse,
+            RoutesResponse, SubmitBlockResponse, TipsResponse, TreasuryResponse, UtxoChangesResponse,
         },
         block::{
             output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
             payload::{
-                milestone::{dto::MilestonePayloadDto, MilestoneId, MilestonePayload},
+                milestone::{dto::MilestonePayloadDto, MilestoneId, MilestoneKeyRange, MilestonePayload},
                 transaction::TransactionId,
+                Payload,
             },
             Block, BlockDto, BlockId,
         },
@@ -53,7 +57,14 @@ impl ClientInner {
 
         let mut url = Url::parse(url)?;
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+        let (offline, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.offline, node_manager.http_client.clone())
+        };
+        if offline {
+            return Err(Error::Offline);
+        }
+        let status = http_client
             .get(
                 Node {
                     url,
@@ -97,11 +108,52 @@ impl ClientInner {
         Ok(response.tips)
     }
 
+    /// Returns the tips of a specific node, which doesn't have to be part of the node pool. The route is appended to
+    /// the path of the url, so nodes behind a path prefix can be used.
+    /// GET /api/core/v2/tips
+    pub async fn get_tips_from(&self, url: &Url) -> Result<Vec<BlockId>> {
+        let path = "api/core/v2/tips";
+
+        let mut url = url.clone();
+        url.path_segments_mut()
+            .map_err(|_| Error::UrlValidation("url can't be a base".to_owned()))?
+            .pop_if_empty()
+            .extend(path.split('/'));
+        let (offline, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.offline, node_manager.http_client.clone())
+        };
+        if offline {
+            return Err(Error::Offline);
+        }
+        let response: TipsResponse = http_client
+            .get(
+                Node {
+                    url,
+                    auth: None,
+                    disabled: false,
+                },
+                self.get_timeout().await,
+            )
+            .await?
+            .into_json()
+            .await?;
+
+        Ok(response.tips)
+    }
+
     // Blocks routes.
 
     /// Returns the BlockId of the submitted block.
     /// POST JSON to /api/core/v2/blocks
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
+        // Blocks without finished PoW are posted to a single node, which does the PoW
+        if self.should_broadcast(block).await? {
+            return Ok(self.broadcast_block(block).await?.block_id);
+        }
+        if let Some(Payload::Transaction(transaction)) = block.payload() {
+            self.check_transaction_network(transaction).await?;
+        }
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
         let timeout = if local_pow {
@@ -151,6 +203,7 @@ impl ClientInner {
             }
             Err(e) => return Err(e),
         };
+        self.run_after_submission(&response.block_id, block.payload()).await;
 
         Ok(response.block_id)
     }
@@ -158,6 +211,12 @@ impl ClientInner {
     /// Returns the BlockId of the submitted block.
     /// POST /api/core/v2/blocks
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
+        if self.should_broadcast(block).await? {
+            return Ok(self.broadcast_block(block).await?.block_id);
+        }
+        if let Some(Payload::Transaction(transaction)) = block.payload() {
+            self.check_transaction_network(transaction).await?;
+        }
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
         let timeout = if local_pow {
@@ -203,6 +262,7 @@ impl ClientInner {
             }
             Err(e) => return Err(e),
         };
+        self.run_after_submission(&response.block_id, block.payload()).await;
 
         Ok(response.block_id)
     }
@@ -269,22 +329,32 @@ impl ClientInner {
 
     /// Gets all stored receipts.
     /// GET /api/core/v2/receipts
-    pub async fn get_receipts(&self) -> Result<Vec<ReceiptResponse>> {
+    pub async fn get_receipts(&self) -> Result<Vec<Receipt>> {
         let path = &"api/core/v2/receipts";
 
         let resp = self.get_request::<ReceiptsResponse>(path, None, false, false).await?;
 
-        Ok(resp.receipts)
+        self.receipts_from_response(resp).await
     }
 
     /// Gets the receipts by the given milestone index.
     /// GET /api/core/v2/receipts/{migratedAt}
-    pub async fn get_receipts_migrated_at(&self, milestone_index: u32) -> Result<Vec<ReceiptResponse>> {
+    pub async fn get_receipts_migrated_at(&self, milestone_index: u32) -> Result<Vec<Receipt>> {
         let path = &format!("api/core/v2/receipts/{milestone_index}");
 
         let resp = self.get_request::<ReceiptsResponse>(path, None, false, false).await?;
 
-        Ok(resp.receipts)
+        self.receipts_from_response(resp).await
+    }
+
+    async fn receipts_from_response(&self, resp: ReceiptsResponse) -> Result<Vec<Receipt>> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        Ok(resp
+            .receipts
+            .into_iter()
+            .map(|receipt| Receipt::try_from_dto_with_params(receipt, &protocol_parameters))
+            .collect::<core::result::Result<_, _>>()?)
     }
 
     /// Gets the current treasury output.
@@ -369,6 +439,32 @@ impl ClientInner {
         )?)
     }
 
+    /// Gets the milestone by the given milestone index and verifies it locally: it has to have the requested index and
+    /// be signed by at least `min_threshold` of the keys that are applicable at this index.
+    /// The merkle roots aren't recomputed, that would require the white flag ordered blocks referenced by the
+    /// milestone.
+    /// GET /api/core/v2/milestones/{index}
+    pub async fn get_verified_milestone_by_index(
+        &self,
+        index: u32,
+        key_ranges: &[MilestoneKeyRange],
+        min_threshold: usize,
+    ) -> Result<MilestonePayload> {
+        let milestone = self.get_milestone_by_index(index).await?;
+
+        if *milestone.essence().index() != index {
+            return Err(Error::InvalidMilestone(format!(
+                "requested index {index}, got {}",
+                milestone.essence().index()
+            )));
+        }
+        milestone
+            .verify(key_ranges, min_threshold)
+            .map_err(|e| Error::InvalidMilestone(format!("{e:?}")))?;
+
+        Ok(milestone)
+    }
+
     /// Gets the milestone by the given milestone index.
     /// GET /api/core/v2/milestones/{index}
     pub async fn get_milestone_by_index_raw(&self, index: u32) -> Result<Vec<u8>> {
@@ -396,30 +492,39 @@ impl ClientInner {
         Ok(resp)
     }
 
-    // // RoutePeer is the route for getting peers by their peerID.
-    // // GET returns the peer
-    // // DELETE deletes the peer.
-    // RoutePeer = "/peers/:" + restapipkg.ParameterPeerID
-
-    // // RoutePeers is the route for getting all peers of the node.
-    // // GET returns a list of all peers.
-    // // POST adds a new peer.
-    // RoutePeers = "/peers"
-
-    // Control routes.
-
-    // // RouteControlDatabasePrune is the control route to manually prune the database.
-    // // POST prunes the database.
-    // RouteControlDatabasePrune = "/control/database/prune"
-
-    // // RouteControlSnapshotsCreate is the control route to manually create a snapshot files.
-    // // POST creates a snapshot (full, delta or both).
-    // RouteControlSnapshotsCreate = "/control/snapshots/create"
+    // The other peers routes and the control routes are in the admin module, since they're only sent to one node.
 }
 
 impl Client {
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        Self::get_node_info_with(&HttpClient::new(DEFAULT_USER_AGENT.to_string()), url, auth).await
+    }
+
+    /// GET /api/core/v2/info endpoint, sent with the given HTTP client.
+    pub(crate) async fn get_node_info_with(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+    ) -> Result<InfoResponse> {
+        Self::get_from_node_with(http_client, url, auth, INFO_PATH).await
+    }
+
+    /// GET /api/routes endpoint, sent with the given HTTP client.
+    pub(crate) async fn get_routes_with(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+    ) -> Result<RoutesResponse> {
+        Self::get_from_node_with(http_client, url, auth, "api/routes").await
+    }
+
+    async fn get_from_node_with<T: DeserializeOwned>(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+        path: &str,
+    ) -> Result<T> {
         let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
@@ -429,22 +534,20 @@ impl Client {
                     .map_err(|_| crate::client::Error::UrlAuth("password"))?;
             }
         }
-        let path = "api/core/v2/info";
         url.set_path(path);
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp = http_client
+            .get(
+                Node {
+                    url,
+                    auth,
+                    disabled: false,
+                },
+                DEFAULT_API_TIMEOUT,
+            )
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
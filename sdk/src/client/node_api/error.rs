@@ -13,6 +13,12 @@ pub enum Error {
     /// Reqwest error
     #[error("{0}")]
     Reqwest(#[from] reqwest::Error),
+    /// Error of a custom HTTP transport
+    #[error("HTTP transport error: {0}")]
+    Transport(String),
+    /// JSON error
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
     /// Error from RestAPI calls with unexpected status code response
     #[error("Response error with status code {code}: {text}, URL: {url}")]
     ResponseError {
@@ -6,10 +6,20 @@
 pub mod query_parameters;
 pub mod routes;
 
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+use std::collections::VecDeque;
+
+use futures::{Future, Stream};
+use instant::Instant;
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
-    client::{ClientInner, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    client::{Client, ClientInner, Result},
+    types::{api::plugins::indexer::OutputIdsResponse, block::output::OutputId},
 };
 
 impl ClientInner {
@@ -57,3 +67,177 @@ impl ClientInner {
         Ok(merged_output_ids_response)
     }
 }
+
+/// The pages of output IDs of an indexer query. They are requested one at a time, so not all output IDs have to be kept
+/// in memory.
+#[derive(Debug)]
+pub struct OutputIdsPages {
+    client: Client,
+    route: &'static str,
+    query_parameters: QueryParameters,
+    finished: bool,
+}
+
+impl OutputIdsPages {
+    pub(crate) fn new(client: Client, route: &'static str, query_parameters: QueryParameters) -> Self {
+        Self {
+            client,
+            route,
+            query_parameters,
+            finished: false,
+        }
+    }
+
+    /// Requests the next page, the first one starts at the `QueryParameter::Cursor(_)` if one is provided. Returns
+    /// `None` after the last page.
+    pub async fn next_page(&mut self) -> Result<Option<OutputIdsResponse>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let page = self
+            .client
+            .get_request::<OutputIdsResponse>(
+                self.route,
+                self.query_parameters.to_query_string().as_deref(),
+                true,
+                false,
+            )
+            .await?;
+
+        match &page.cursor {
+            Some(cursor) => self
+                .query_parameters
+                .replace(QueryParameter::Cursor(cursor.to_string())),
+            None => self.finished = true,
+        }
+
+        Ok(Some(page))
+    }
+
+    /// Returns a stream of the output IDs of the remaining pages.
+    pub fn into_stream(self) -> OutputIdStream {
+        OutputIdStream {
+            page_cursor: self.query_parameters.cursor().map(ToOwned::to_owned),
+            pages: Some(self),
+            request: None,
+            output_ids: VecDeque::new(),
+            min_request_interval: Duration::ZERO,
+            last_request: None,
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+type PageRequest = Pin<Box<dyn Future<Output = (OutputIdsPages, Result<Option<OutputIdsResponse>>)> + Send>>;
+#[cfg(target_family = "wasm")]
+type PageRequest = Pin<Box<dyn Future<Output = (OutputIdsPages, Result<Option<OutputIdsResponse>>)>>>;
+
+/// A stream of the output IDs of an indexer query, returned by [`OutputIdsPages::into_stream()`]. The pages are
+/// requested when the output IDs of the previous one were consumed, so the stream can be stopped early, e.g. with
+/// `StreamExt::take_while()`, without requesting the remaining pages.
+///
+/// If a page request fails, the error is returned and the next poll requests the same page again. To resume the stream
+/// after a restart, persist its [`cursor()`](OutputIdStream::cursor) and pass it as `QueryParameter::Cursor` to the
+/// query.
+pub struct OutputIdStream {
+    /// `None` while a page is requested.
+    pages: Option<OutputIdsPages>,
+    request: Option<PageRequest>,
+    /// The output IDs of the current page that weren't returned yet.
+    output_ids: VecDeque<OutputId>,
+    /// The cursor the current page was requested with.
+    page_cursor: Option<String>,
+    min_request_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl OutputIdStream {
+    /// Sets the minimum time between two page requests, to not exceed the rate limit of a node.
+    pub fn with_min_request_interval(mut self, min_request_interval: Duration) -> Self {
+        self.min_request_interval = min_request_interval;
+        self
+    }
+
+    /// Returns the cursor to resume the stream from, `None` if it starts at the first page. Output IDs of the current
+    /// page that were already returned are returned again by a resumed stream.
+    pub fn cursor(&self) -> Option<&str> {
+        match &self.pages {
+            // The current page was consumed, so the stream continues at the next one
+            Some(pages) if self.output_ids.is_empty() => pages.query_parameters.cursor(),
+            _ => self.page_cursor.as_deref(),
+        }
+    }
+
+    /// Returns whether all output IDs were returned.
+    pub fn is_finished(&self) -> bool {
+        self.output_ids.is_empty() && self.pages.as_ref().map_or(false, |pages| pages.finished)
+    }
+}
+
+impl Stream for OutputIdStream {
+    type Item = Result<OutputId>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(output_id) = this.output_ids.pop_front() {
+                return Poll::Ready(Some(Ok(output_id)));
+            }
+
+            if let Some(request) = &mut this.request {
+                let (pages, page) = ready!(request.as_mut().poll(cx));
+                this.request = None;
+                this.pages = Some(pages);
+
+                match page {
+                    Ok(Some(page)) => this.output_ids.extend(page.items),
+                    Ok(None) => return Poll::Ready(None),
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+                continue;
+            }
+
+            let pages = match this.pages.take() {
+                Some(pages) if !pages.finished => pages,
+                pages => {
+                    this.pages = pages;
+                    return Poll::Ready(None);
+                }
+            };
+
+            this.page_cursor = pages.query_parameters.cursor().map(ToOwned::to_owned);
+            let delay = this.last_request.map_or(Duration::ZERO, |last_request| {
+                this.min_request_interval.saturating_sub(last_request.elapsed())
+            });
+            this.last_request = Some(Instant::now() + delay);
+            this.request = Some(Box::pin(request_page(pages, delay)));
+        }
+    }
+}
+
+impl core::fmt::Debug for OutputIdStream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OutputIdStream")
+            .field("cursor", &self.cursor())
+            .field("min_request_interval", &self.min_request_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+async fn request_page(
+    mut pages: OutputIdsPages,
+    delay: Duration,
+) -> (OutputIdsPages, Result<Option<OutputIdsResponse>>) {
+    if !delay.is_zero() {
+        #[cfg(target_family = "wasm")]
+        gloo_timers::future::TimeoutFuture::new(delay.as_millis() as u32).await;
+
+        #[cfg(not(target_family = "wasm"))]
+        crate::client::runtime::sleep(delay).await;
+    }
+
+    let page = pages.next_page().await;
+    (pages, page)
+}
@@ -56,6 +56,14 @@ impl QueryParameters {
         self.0.iter().any(f)
     }
 
+    /// Returns the cursor, if one is set.
+    pub(crate) fn cursor(&self) -> Option<&str> {
+        self.0.iter().find_map(|query_parameter| match query_parameter {
+            QueryParameter::Cursor(cursor) => Some(cursor.as_str()),
+            _ => None,
+        })
+    }
+
     /// Converts parameters to a single String.
     pub fn to_query_string(&self) -> Option<String> {
         if self.0.is_empty() {
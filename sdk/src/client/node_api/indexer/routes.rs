@@ -11,9 +11,9 @@ use crate::{
                 verify_query_parameters_foundry_outputs, verify_query_parameters_nft_outputs,
                 verify_query_parameters_outputs, QueryParameter,
             },
-            QueryParameters,
+            OutputIdsPages, QueryParameters,
         },
-        ClientInner, Error, Result,
+        Client, ClientInner, Error, Result,
     },
     types::{
         api::plugins::indexer::OutputIdsResponse,
@@ -23,6 +23,12 @@ use crate::{
 
 // hornet: https://github.com/gohornet/hornet/blob/develop/plugins/indexer/routes.go
 
+const OUTPUTS_ROUTE: &str = "api/indexer/v1/outputs";
+pub(crate) const BASIC_OUTPUTS_ROUTE: &str = "api/indexer/v1/outputs/basic";
+pub(crate) const ALIAS_OUTPUTS_ROUTE: &str = "api/indexer/v1/outputs/alias";
+const FOUNDRY_OUTPUTS_ROUTE: &str = "api/indexer/v1/outputs/foundry";
+pub(crate) const NFT_OUTPUTS_ROUTE: &str = "api/indexer/v1/outputs/nft";
+
 impl ClientInner {
     /// Get basic, alias, nft and foundry outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
@@ -34,11 +40,9 @@ impl ClientInner {
         &self,
         query_parameters: impl Into<Vec<QueryParameter>> + Send,
     ) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs";
-
         let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
 
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.get_output_ids(OUTPUTS_ROUTE, query_parameters, true, false).await
     }
 
     /// Get basic outputs filtered by the given parameters.
@@ -52,11 +56,10 @@ impl ClientInner {
         &self,
         query_parameters: impl Into<Vec<QueryParameter>> + Send,
     ) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/basic";
-
         let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
 
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.get_output_ids(BASIC_OUTPUTS_ROUTE, query_parameters, true, false)
+            .await
     }
 
     /// Get alias outputs filtered by the given parameters.
@@ -68,11 +71,10 @@ impl ClientInner {
         &self,
         query_parameters: impl Into<Vec<QueryParameter>> + Send,
     ) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/alias";
-
         let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into())?;
 
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.get_output_ids(ALIAS_OUTPUTS_ROUTE, query_parameters, true, false)
+            .await
     }
 
     /// Get alias output by its aliasID.
@@ -96,11 +98,10 @@ impl ClientInner {
         &self,
         query_parameters: impl Into<Vec<QueryParameter>> + Send,
     ) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/foundry";
-
         let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into())?;
 
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.get_output_ids(FOUNDRY_OUTPUTS_ROUTE, query_parameters, true, false)
+            .await
     }
 
     /// Get foundry output by its foundryID.
@@ -125,11 +126,10 @@ impl ClientInner {
         &self,
         query_parameters: impl Into<Vec<QueryParameter>> + Send,
     ) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/nft";
-
         let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into())?;
 
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.get_output_ids(NFT_OUTPUTS_ROUTE, query_parameters, true, false)
+            .await
     }
 
     /// Get NFT output by its nftID.
@@ -144,3 +144,49 @@ impl ClientInner {
             .ok_or_else(|| Error::NoOutput(format!("{nft_id:?}")))?))
     }
 }
+
+impl Client {
+    /// Get the pages of basic, alias, nft and foundry output IDs filtered by the given parameters, see
+    /// [`output_ids()`](ClientInner::output_ids).
+    pub fn output_ids_pages(&self, query_parameters: impl Into<Vec<QueryParameter>>) -> Result<OutputIdsPages> {
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+
+        Ok(OutputIdsPages::new(self.clone(), OUTPUTS_ROUTE, query_parameters))
+    }
+
+    /// Get the pages of basic output IDs filtered by the given parameters, see
+    /// [`basic_output_ids()`](ClientInner::basic_output_ids).
+    pub fn basic_output_ids_pages(&self, query_parameters: impl Into<Vec<QueryParameter>>) -> Result<OutputIdsPages> {
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+
+        Ok(OutputIdsPages::new(self.clone(), BASIC_OUTPUTS_ROUTE, query_parameters))
+    }
+
+    /// Get the pages of alias output IDs filtered by the given parameters, see
+    /// [`alias_output_ids()`](ClientInner::alias_output_ids).
+    pub fn alias_output_ids_pages(&self, query_parameters: impl Into<Vec<QueryParameter>>) -> Result<OutputIdsPages> {
+        let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into())?;
+
+        Ok(OutputIdsPages::new(self.clone(), ALIAS_OUTPUTS_ROUTE, query_parameters))
+    }
+
+    /// Get the pages of foundry output IDs filtered by the given parameters, see
+    /// [`foundry_output_ids()`](ClientInner::foundry_output_ids).
+    pub fn foundry_output_ids_pages(&self, query_parameters: impl Into<Vec<QueryParameter>>) -> Result<OutputIdsPages> {
+        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into())?;
+
+        Ok(OutputIdsPages::new(
+            self.clone(),
+            FOUNDRY_OUTPUTS_ROUTE,
+            query_parameters,
+        ))
+    }
+
+    /// Get the pages of NFT output IDs filtered by the given parameters, see
+    /// [`nft_output_ids()`](ClientInner::nft_output_ids).
+    pub fn nft_output_ids_pages(&self, query_parameters: impl Into<Vec<QueryParameter>>) -> Result<OutputIdsPages> {
+        let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into())?;
+
+        Ok(OutputIdsPages::new(self.clone(), NFT_OUTPUTS_ROUTE, query_parameters))
+    }
+}
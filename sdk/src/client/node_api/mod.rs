@@ -3,6 +3,7 @@
 
 //! node API modules
 
+pub mod admin;
 pub mod core;
 pub mod error;
 pub mod indexer;
@@ -0,0 +1,336 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Broker connections shared by the clients of the process.
+//!
+//! Clients that connect to the same broker use the same connection, so services with many clients or accounts don't
+//! open a connection per client. Events are routed to the clients that are subscribed to their topic, and a topic is
+//! only subscribed to at the broker while at least one client is subscribed to it. The connection is closed when the
+//! last client disconnects, or when all its clients are dropped. The broker options of the client that opened the
+//! connection apply to it.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock, Weak,
+    },
+};
+
+use rumqttc::{AsyncClient, QoS, SubscribeFilter};
+use tokio::sync::Mutex;
+
+use super::{Error, Topic};
+use crate::client::ClientInner;
+
+/// The connections by the broker they're connected to.
+static CONNECTIONS: OnceLock<Mutex<HashMap<String, Weak<MqttConnection>>>> = OnceLock::new();
+
+fn connections() -> &'static Mutex<HashMap<String, Weak<MqttConnection>>> {
+    CONNECTIONS.get_or_init(Default::default)
+}
+
+/// A connection to a MQTT broker, shared by the clients that connect to it.
+pub(crate) struct MqttConnection {
+    /// The broker the connection is registered for.
+    broker: String,
+    client: AsyncClient,
+    max_reconnection_attempts: usize,
+    subscriptions: Mutex<Subscriptions<ClientInner>>,
+    closed: AtomicBool,
+}
+
+impl MqttConnection {
+    /// Returns the open connection to the broker, if there is one.
+    pub(crate) async fn get(broker: &str) -> Option<Arc<Self>> {
+        connections()
+            .lock()
+            .await
+            .get(broker)
+            .and_then(Weak::upgrade)
+            .filter(|connection| !connection.is_closed())
+    }
+
+    /// Registers a new connection to the broker, which is then returned by [`MqttConnection::get()`].
+    pub(crate) async fn register(broker: String, client: AsyncClient, max_reconnection_attempts: usize) -> Arc<Self> {
+        let connection = Arc::new(Self {
+            broker: broker.clone(),
+            client,
+            max_reconnection_attempts,
+            subscriptions: Default::default(),
+            closed: AtomicBool::new(false),
+        });
+        connections().lock().await.insert(broker, Arc::downgrade(&connection));
+
+        connection
+    }
+
+    /// The maximum number of reconnection attempts, 0 is unlimited.
+    pub(crate) fn max_reconnection_attempts(&self) -> usize {
+        self.max_reconnection_attempts
+    }
+
+    /// Returns whether the connection was closed, it can't be used anymore then.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Marks the connection as closed and removes it from the registered connections.
+    pub(crate) async fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+
+        let mut connections = connections().lock().await;
+        // A new connection to the broker could have been registered already
+        if connections
+            .get(&self.broker)
+            .map_or(false, |connection| std::ptr::eq(connection.as_ptr(), self))
+        {
+            connections.remove(&self.broker);
+        }
+    }
+
+    /// Returns the clients of the connection that weren't dropped.
+    pub(crate) async fn clients(&self) -> Vec<Arc<ClientInner>> {
+        self.subscriptions.lock().await.clients().collect()
+    }
+
+    /// Subscribes again to all topics at the broker, after the subscriptions were lost on a reconnection.
+    pub(crate) async fn resubscribe(&self) -> Result<(), Error> {
+        let topics = self
+            .subscriptions
+            .lock()
+            .await
+            .topics
+            .keys()
+            .map(|topic| SubscribeFilter::new(topic.to_string(), QoS::AtLeastOnce))
+            .collect::<Vec<_>>();
+        if !topics.is_empty() {
+            self.client.subscribe_many(topics).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Subscribes a client to topics. Only the topics no other client is subscribed to are subscribed to at the broker.
+    pub(crate) async fn subscribe(
+        &self,
+        client: &Arc<ClientInner>,
+        topics: impl IntoIterator<Item = Topic> + Send,
+    ) -> Result<(), Error> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let released = subscriptions.prune();
+        self.unsubscribe_at_broker(&released).await?;
+
+        let added = subscriptions.add(client, topics);
+        if !added.is_empty() {
+            if let Err(e) = self
+                .client
+                .subscribe_many(
+                    added
+                        .iter()
+                        .map(|topic| SubscribeFilter::new(topic.to_string(), QoS::AtLeastOnce)),
+                )
+                .await
+            {
+                subscriptions.remove(client, added);
+                return Err(e.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unsubscribes a client from topics. Only the topics no other client is subscribed to are unsubscribed from at the
+    /// broker.
+    pub(crate) async fn unsubscribe(
+        &self,
+        client: &Arc<ClientInner>,
+        topics: impl IntoIterator<Item = Topic> + Send,
+    ) -> Result<(), Error> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let mut released = subscriptions.prune();
+        released.extend(subscriptions.remove(client, topics));
+
+        self.unsubscribe_at_broker(&released).await
+    }
+
+    /// Unsubscribes a client from all its topics and detaches it from the connection. The connection is closed if it
+    /// was the last client.
+    pub(crate) async fn detach(&self, client: &Arc<ClientInner>) -> Result<(), Error> {
+        let mut subscriptions = self.subscriptions.lock().await;
+        let mut released = subscriptions.prune();
+        released.extend(subscriptions.detach(client));
+
+        if subscriptions.clients.is_empty() {
+            self.close().await;
+            self.client.disconnect().await?;
+        } else {
+            self.unsubscribe_at_broker(&released).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn unsubscribe_at_broker(&self, topics: &[Topic]) -> Result<(), Error> {
+        for topic in topics {
+            self.client.unsubscribe(topic.to_string()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The clients attached to a connection and the number of clients subscribed to each topic.
+struct Subscriptions<T> {
+    /// The clients with the topics they're subscribed to.
+    clients: Vec<(Weak<T>, HashSet<Topic>)>,
+    topics: HashMap<Topic, usize>,
+}
+
+impl<T> Default for Subscriptions<T> {
+    fn default() -> Self {
+        Self {
+            clients: Vec::new(),
+            topics: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Subscriptions<T> {
+    fn clients(&self) -> impl Iterator<Item = Arc<T>> + '_ {
+        self.clients.iter().filter_map(|(client, _)| client.upgrade())
+    }
+
+    fn position(&self, client: &Arc<T>) -> Option<usize> {
+        self.clients
+            .iter()
+            .position(|(c, _)| std::ptr::eq(c.as_ptr(), Arc::as_ptr(client)))
+    }
+
+    /// Adds topics to a client, and returns the topics no other client was subscribed to.
+    fn add(&mut self, client: &Arc<T>, topics: impl IntoIterator<Item = Topic>) -> Vec<Topic> {
+        let index = self.position(client).unwrap_or_else(|| {
+            self.clients.push((Arc::downgrade(client), HashSet::new()));
+            self.clients.len() - 1
+        });
+
+        let mut added = Vec::new();
+        for topic in topics {
+            if self.clients[index].1.insert(topic.clone()) {
+                let count = self.topics.entry(topic.clone()).or_default();
+                *count += 1;
+                if *count == 1 {
+                    added.push(topic);
+                }
+            }
+        }
+
+        added
+    }
+
+    /// Removes topics from a client, and returns the topics no client is subscribed to anymore.
+    fn remove(&mut self, client: &Arc<T>, topics: impl IntoIterator<Item = Topic>) -> Vec<Topic> {
+        let Some(index) = self.position(client) else {
+            return Vec::new();
+        };
+
+        let removed = topics
+            .into_iter()
+            .filter(|topic| self.clients[index].1.remove(topic))
+            .collect::<Vec<_>>();
+        self.release(removed)
+    }
+
+    /// Removes a client, and returns the topics no client is subscribed to anymore.
+    fn detach(&mut self, client: &Arc<T>) -> Vec<Topic> {
+        match self.position(client) {
+            Some(index) => {
+                let (_, topics) = self.clients.swap_remove(index);
+                self.release(topics)
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Removes the clients that were dropped, and returns the topics no client is subscribed to anymore.
+    fn prune(&mut self) -> Vec<Topic> {
+        let mut dropped = Vec::new();
+        self.clients.retain_mut(|(client, topics)| {
+            let alive = client.strong_count() > 0;
+            if !alive {
+                dropped.extend(topics.drain());
+            }
+            alive
+        });
+
+        self.release(dropped)
+    }
+
+    fn release(&mut self, topics: impl IntoIterator<Item = Topic>) -> Vec<Topic> {
+        topics
+            .into_iter()
+            .filter(|topic| match self.topics.get_mut(topic) {
+                Some(count) if *count > 1 => {
+                    *count -= 1;
+                    false
+                }
+                Some(_) => {
+                    self.topics.remove(topic);
+                    true
+                }
+                None => false,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(topic: &str) -> Topic {
+        Topic::new(topic).unwrap()
+    }
+
+    #[test]
+    fn reference_counted_topics() {
+        let mut subscriptions = Subscriptions::default();
+        let first = Arc::new(1u8);
+        let second = Arc::new(2u8);
+
+        assert_eq!(
+            subscriptions.add(&first, [topic("blocks"), topic("milestones")]),
+            [topic("blocks"), topic("milestones")]
+        );
+        // Only the topic no client was subscribed to has to be subscribed to at the broker
+        assert_eq!(
+            subscriptions.add(&second, [topic("blocks"), topic("receipts")]),
+            [topic("receipts")]
+        );
+        // Subscribing twice doesn't count the client twice
+        assert!(subscriptions.add(&second, [topic("receipts")]).is_empty());
+        assert_eq!(subscriptions.topics[&topic("blocks")], 2);
+
+        assert!(subscriptions.remove(&first, [topic("blocks")]).is_empty());
+        assert_eq!(subscriptions.remove(&second, [topic("blocks")]), [topic("blocks")]);
+        assert!(subscriptions.remove(&second, [topic("blocks")]).is_empty());
+
+        assert_eq!(subscriptions.detach(&second), [topic("receipts")]);
+        assert_eq!(subscriptions.clients().count(), 1);
+    }
+
+    #[test]
+    fn prune_dropped_clients() {
+        let mut subscriptions = Subscriptions::default();
+        let first = Arc::new(1u8);
+        let second = Arc::new(2u8);
+        subscriptions.add(&first, [topic("blocks")]);
+        subscriptions.add(&second, [topic("blocks"), topic("milestones")]);
+
+        drop(second);
+
+        assert_eq!(subscriptions.prune(), [topic("milestones")]);
+        assert_eq!(subscriptions.clients().collect::<Vec<_>>(), [first]);
+        assert_eq!(subscriptions.topics[&topic("blocks")], 1);
+    }
+}
@@ -16,4 +16,7 @@ pub enum Error {
     /// Invalid topic.
     #[error("invalid topic {0}")]
     InvalidTopic(String),
+    /// No node has the MQTT plugin enabled.
+    #[error("no node provides the MQTT API")]
+    MissingCapability,
 }
@@ -3,22 +3,29 @@
 
 //! IOTA node MQTT API
 
+mod connection;
 mod error;
 pub mod types;
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::{atomic::Ordering, Arc},
+    time::Instant,
+};
 
 use crypto::utils;
 use log::warn;
 use packable::PackableExt;
-use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
-use tokio::sync::watch::Receiver as WatchReceiver;
+use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, Publish, Transport};
+use tokio::sync::{broadcast::error::RecvError, watch::Receiver as WatchReceiver};
 
+pub(crate) use self::connection::MqttConnection;
 pub use self::{error::Error, types::*};
 use crate::{
-    client::{Client, ClientInner},
+    client::{node_manager::capability::NodeCapability, Client, ClientInner},
     types::block::{
+        address::Hrp,
         payload::{milestone::ReceiptMilestoneOption, Payload},
+        protocol::ProtocolParameters,
         Block,
     },
 };
@@ -52,13 +59,13 @@ impl ClientInner {
 }
 
 async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
-    // if the client was disconnected, we clear it so we can start over
-    if *client.mqtt_event_receiver().await.borrow() == MqttEvent::Disconnected {
-        *client.mqtt.client.write().await = None;
+    let mut connection = client.mqtt.connection.write().await;
+    // if the connection was closed, we clear it so we can start over
+    if connection.as_ref().map_or(false, |connection| connection.is_closed()) {
+        *connection = None;
     }
-    let exists = client.mqtt.client.read().await.is_some();
 
-    if !exists {
+    if connection.is_none() {
         let node_manager = client.node_manager.read().await;
         let nodes = if !node_manager.ignore_node_health {
             #[cfg(not(target_family = "wasm"))]
@@ -77,43 +84,56 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
         } else {
             node_manager.nodes.clone()
         };
+        // Skip the nodes that are known to not have the MQTT plugin enabled
+        let nodes = nodes
+            .into_iter()
+            .filter(|node| node_manager.supports(node, NodeCapability::Mqtt).unwrap_or(true))
+            .collect::<Vec<_>>();
+        if nodes.is_empty() && !node_manager.nodes.is_empty() {
+            return Err(Error::MissingCapability);
+        }
+        let broker_options = *client.mqtt.broker_options.read().await;
         for node in &nodes {
             let host = node.url.host_str().expect("can't get host from URL");
-            let mut entropy = [0u8; 8];
-            utils::rand::fill(&mut entropy)?;
-            let id = format!("iotasdk{}", prefix_hex::encode(entropy));
-            let broker_options = client.mqtt.broker_options.read().await;
             let port = broker_options.port;
             let secure = node.url.scheme() == "https";
-            let mqtt_options = if broker_options.use_ws {
-                let uri = format!(
+            let uri = if broker_options.use_ws {
+                format!(
                     "{}://{host}:{}/api/mqtt/v1",
                     if secure { "wss" } else { "ws" },
                     node.url.port_or_known_default().unwrap_or(port)
-                );
-                let mut mqtt_options = MqttOptions::new(id, uri, port);
+                )
+            } else {
+                host.to_string()
+            };
+            let broker = format!("{}|{uri}|{port}", if secure { "tls" } else { "tcp" });
+
+            // Clients connecting to the same broker share its connection
+            if let Some(shared) = MqttConnection::get(&broker).await {
+                connection.replace(shared);
+                break;
+            }
+
+            let mut entropy = [0u8; 8];
+            utils::rand::fill(&mut entropy)?;
+            let id = format!("iotasdk{}", prefix_hex::encode(entropy));
+            let mut mqtt_options = MqttOptions::new(id, uri, port);
+            if broker_options.use_ws {
                 if secure {
                     mqtt_options.set_transport(Transport::wss_with_default_config());
                 } else {
                     mqtt_options.set_transport(Transport::ws());
                 }
-                mqtt_options
-            } else {
-                let uri = host.to_string();
-                let mut mqtt_options = MqttOptions::new(id, uri, port);
-                if secure {
-                    mqtt_options.set_transport(Transport::tls_with_default_config());
-                }
-                mqtt_options
-            };
-            let (_, mut connection) = AsyncClient::new(mqtt_options.clone(), 10);
-            connection
-                .set_network_options(*NetworkOptions::new().set_connection_timeout(broker_options.timeout.as_secs()));
+            } else if secure {
+                mqtt_options.set_transport(Transport::tls_with_default_config());
+            }
+            let (_, mut probe) = AsyncClient::new(mqtt_options.clone(), 10);
+            probe.set_network_options(*NetworkOptions::new().set_connection_timeout(broker_options.timeout.as_secs()));
             // poll the event loop until we find a ConnAck event,
             // which means that the mqtt client is ready to be used on this host
             // if the event loop returns an error, we check the next node
             let mut got_ack = false;
-            while let Ok(event) = connection.poll().await {
+            while let Ok(event) = probe.poll().await {
                 if let Event::Incoming(Incoming::ConnAck(_)) = event {
                     got_ack = true;
                     break;
@@ -122,17 +142,89 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
 
             // if we found a valid mqtt connection, loop it on a separate thread
             if got_ack {
-                let (mqtt_client, connection) = AsyncClient::new(mqtt_options, 10);
-                client.mqtt.client.write().await.replace(mqtt_client);
-                poll_mqtt(client, connection);
+                let (mqtt_client, event_loop) = AsyncClient::new(mqtt_options, 10);
+                let shared =
+                    MqttConnection::register(broker, mqtt_client, broker_options.max_reconnection_attempts).await;
+                poll_mqtt(&shared, event_loop);
+                connection.replace(shared);
+                break;
             }
         }
+
+        if let Some(connection) = &*connection {
+            // Subscribe again to the topics the client had handlers for before its connection was closed
+            let topics = client
+                .mqtt
+                .topic_handlers
+                .read()
+                .await
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>();
+            connection.subscribe(&client.inner, topics).await?;
+            follow_hrp(client);
+        }
     }
     Ok(())
 }
 
-fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
-    let client = client.clone();
+/// Starts a task that moves the subscriptions to address topics to the hrp of the network after it changed with the
+/// protocol parameters, so their handlers keep receiving events.
+fn follow_hrp(client: &Client) {
+    if client.mqtt.hrp_follower.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    let mut updates = client.subscribe_protocol_parameters_updates();
+    let weak_client = Arc::downgrade(&client.inner);
+    crate::client::runtime::spawn(async move {
+        loop {
+            match updates.recv().await {
+                Ok(update) if update.previous.bech32_hrp() == update.current.bech32_hrp() => continue,
+                // A missed update could have changed the hrp
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            }
+            let Some(client) = weak_client.upgrade() else {
+                break;
+            };
+            let hrp = *client.network_info.read().await.protocol_parameters.bech32_hrp();
+            if let Err(err) = update_topics_hrp(&client, hrp).await {
+                warn!("Cannot move the MQTT topics to hrp {hrp}: {err}");
+            }
+        }
+    });
+}
+
+async fn update_topics_hrp(client: &Arc<ClientInner>, hrp: Hrp) -> Result<(), Error> {
+    let connection = client.mqtt.connection.read().await;
+    let mut topic_handlers = client.mqtt.topic_handlers.write().await;
+    let outdated = topic_handlers
+        .keys()
+        .filter(|topic| topic.clone().with_hrp(hrp) != **topic)
+        .cloned()
+        .collect::<Vec<_>>();
+    if outdated.is_empty() {
+        return Ok(());
+    }
+
+    let mut updated = Vec::with_capacity(outdated.len());
+    for topic in &outdated {
+        if let Some(handlers) = topic_handlers.remove(topic) {
+            let topic = topic.clone().with_hrp(hrp);
+            topic_handlers.entry(topic.clone()).or_default().extend(handlers);
+            updated.push(topic);
+        }
+    }
+    if let Some(connection) = &*connection {
+        connection.unsubscribe(client, outdated).await?;
+        connection.subscribe(client, updated).await?;
+    }
+
+    Ok(())
+}
+
+fn poll_mqtt(connection: &Arc<MqttConnection>, mut event_loop: EventLoop) {
+    let weak_connection = Arc::downgrade(connection);
     std::thread::spawn(move || {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_all()
@@ -151,110 +243,52 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
             loop {
                 let event = event_loop.poll().await;
 
+                // The connection is dropped once all its clients are, or closed once the last one disconnects
+                let Some(connection) = weak_connection.upgrade().filter(|connection| !connection.is_closed()) else {
+                    break;
+                };
+
                 match event {
                     Ok(Event::Incoming(Incoming::ConnAck(_))) => {
-                        let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
-                        if !is_subscribed {
-                            is_subscribed = true;
-                            // resubscribe topics
-                            let topics = client
-                                .inner
-                                .mqtt
-                                .topic_handlers
-                                .read()
-                                .await
-                                .keys()
-                                .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce))
-                                .collect::<Vec<SubscribeFilter>>();
-                            if !topics.is_empty() {
-                                let _ = client
-                                    .inner
-                                    .mqtt
-                                    .client
-                                    .write()
-                                    .await
-                                    .as_mut()
-                                    .unwrap()
-                                    .subscribe_many(topics)
-                                    .await;
+                        let resubscribe = !is_subscribed;
+                        is_subscribed = true;
+                        // The subscriptions are locked by clients while they wait for the event loop, so they're only
+                        // accessed in a separate task
+                        crate::client::runtime::spawn(async move {
+                            for client in connection.clients().await {
+                                let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
                             }
-                        }
+                            if resubscribe {
+                                let _ = connection.resubscribe().await;
+                            }
+                        });
                     }
                     Ok(Event::Incoming(Incoming::Publish(p))) => {
-                        let client = client.clone();
-
-                        crate::client::async_runtime::spawn(async move {
-                            let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
-
-                            if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(&p.topic)) {
-                                let event = {
-                                    if p.topic.contains("blocks") || p.topic.contains("included-block") {
-                                        let payload = &*p.payload;
+                        crate::client::runtime::spawn(async move {
+                            let Ok(topic) = Topic::new(p.topic.as_str()) else {
+                                return;
+                            };
+                            // The event is only parsed once, with the protocol parameters of the first client that
+                            // handles it
+                            let mut event = None;
+
+                            for client in connection.clients().await {
+                                let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
+
+                                if let Some(handlers) = mqtt_topic_handlers.get(&topic) {
+                                    if event.is_none() {
                                         let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Block::unpack_verified(payload, protocol_parameters) {
-                                            Ok(block) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Block((&block).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Block unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("milestones") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Payload::unpack_verified(payload, protocol_parameters) {
-                                            Ok(Payload::Milestone(milestone)) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::MilestonePayload(milestone.as_ref().into()),
-                                            }),
-                                            Ok(p) => {
-                                                warn!(
-                                                    "'milestone' topic returned non-milestone payload, kind: {:?}",
-                                                    p.kind()
-                                                );
-                                                Err(())
-                                            }
-                                            Err(e) => {
-                                                warn!("MilestonePayload unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("receipts") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
-                                            Ok(receipt) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Receipt((&receipt).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Receipt unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else {
-                                        match serde_json::from_slice(&p.payload) {
-                                            Ok(value) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Json(value),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Cannot parse JSON: {:?}", e);
-                                                Err(())
-                                            }
+                                        match parse_event(&p, protocol_parameters) {
+                                            Some(parsed) => event = Some(parsed),
+                                            None => return,
                                         }
                                     }
-                                };
-                                if let Ok(event) = event {
-                                    for handler in handlers {
-                                        handler(&event);
+                                    if let Some(event) = &event {
+                                        for handler in handlers {
+                                            handler(event);
+                                        }
                                     }
-                                };
+                                }
                             }
                         });
                     }
@@ -264,9 +298,11 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                         } else {
                             connection_failure_count = 1;
                         }
-                        if connection_failure_count == client.mqtt.broker_options.read().await.max_reconnection_attempts
-                        {
-                            let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
+                        if connection_failure_count == connection.max_reconnection_attempts() {
+                            connection.close().await;
+                            for client in connection.clients().await {
+                                let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
+                            }
                             break;
                         }
                         error_instant = Instant::now();
@@ -279,6 +315,58 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     });
 }
 
+fn parse_event(p: &Publish, protocol_parameters: &ProtocolParameters) -> Option<TopicEvent> {
+    if p.topic.contains("blocks") || p.topic.contains("included-block") {
+        match Block::unpack_verified(&*p.payload, protocol_parameters) {
+            Ok(block) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Block((&block).into()),
+            }),
+            Err(e) => {
+                warn!("Block unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else if p.topic.contains("milestones") {
+        match Payload::unpack_verified(&*p.payload, protocol_parameters) {
+            Ok(Payload::Milestone(milestone)) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::MilestonePayload(milestone.as_ref().into()),
+            }),
+            Ok(p) => {
+                warn!("'milestone' topic returned non-milestone payload, kind: {:?}", p.kind());
+                None
+            }
+            Err(e) => {
+                warn!("MilestonePayload unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else if p.topic.contains("receipts") {
+        match ReceiptMilestoneOption::unpack_verified(&*p.payload, protocol_parameters) {
+            Ok(receipt) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Receipt((&receipt).into()),
+            }),
+            Err(e) => {
+                warn!("Receipt unpacking failed: {:?}", e);
+                None
+            }
+        }
+    } else {
+        match serde_json::from_slice(&p.payload) {
+            Ok(value) => Some(TopicEvent {
+                topic: p.topic.clone(),
+                payload: MqttPayload::Json(value),
+            }),
+            Err(e) => {
+                warn!("Cannot parse JSON: {:?}", e);
+                None
+            }
+        }
+    }
+}
+
 /// MQTT subscriber.
 pub struct MqttManager<'a> {
     client: &'a Client,
@@ -306,15 +394,15 @@ impl<'a> MqttManager<'a> {
     }
 
     /// Disconnects the broker.
-    /// This will clear the stored topic handlers and close the MQTT connection.
+    /// This will clear the stored topic handlers and detach the client from the MQTT connection, which is closed if no
+    /// other client uses it.
     pub async fn disconnect(self) -> Result<(), Error> {
-        if let Some(client) = &*self.client.mqtt.client.write().await {
-            client.disconnect().await?;
+        let connection = self.client.mqtt.connection.write().await.take();
+        if let Some(connection) = connection {
             self.client.mqtt.topic_handlers.write().await.clear();
+            connection.detach(&self.client.inner).await?;
         }
 
-        *self.client.mqtt.client.write().await = None;
-
         Ok(())
     }
 }
@@ -359,18 +447,13 @@ impl<'a> MqttTopicManager<'a> {
                 >);
         set_mqtt_client(self.client).await?;
         self.client
-            .inner
             .mqtt
-            .client
-            .write()
+            .connection
+            .read()
             .await
             .as_ref()
             .ok_or(Error::ConnectionNotFound)?
-            .subscribe_many(
-                self.topics
-                    .iter()
-                    .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce)),
-            )
+            .subscribe(&self.client.inner, self.topics.iter().cloned())
             .await?;
         {
             let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
@@ -393,10 +476,10 @@ impl<'a> MqttTopicManager<'a> {
             }
         };
 
-        if let Some(client) = &*self.client.mqtt.client.write().await {
-            for topic in &topics {
-                client.unsubscribe(topic.as_str()).await?;
-            }
+        if let Some(connection) = &*self.client.mqtt.connection.read().await {
+            connection
+                .unsubscribe(&self.client.inner, topics.iter().cloned())
+                .await?;
         }
 
         let empty_topic_handlers = {
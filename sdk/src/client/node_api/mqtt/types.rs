@@ -5,14 +5,17 @@
 
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use regex::RegexSet;
 use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use super::Error;
 use crate::types::block::{
-    payload::{dto::MilestonePayloadDto, milestone::option::dto::ReceiptMilestoneOptionDto},
-    BlockDto,
+    address::{Bech32Address, Hrp},
+    output::{feature::TagFeature, AliasId, FoundryId, NftId, OutputId},
+    payload::{
+        dto::MilestonePayloadDto, milestone::option::dto::ReceiptMilestoneOptionDto, transaction::TransactionId,
+    },
+    BlockDto, BlockId,
 };
 
 type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
@@ -141,9 +144,106 @@ impl BrokerOptions {
     }
 }
 
-/// A MQTT topic.
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
-pub struct Topic(String);
+/// A MQTT topic of the node event API. Topics are built from typed parameters, so they are always valid, or parsed
+/// from their string representation with [`Topic::new()`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum Topic {
+    /// `milestone-info/latest`
+    LatestMilestoneInfo,
+    /// `milestone-info/confirmed`
+    ConfirmedMilestoneInfo,
+    /// `milestones`
+    Milestones,
+    /// `blocks`
+    Blocks,
+    /// `blocks/transaction`
+    TransactionBlocks,
+    /// `blocks/transaction/tagged-data`, or `blocks/transaction/tagged-data/{tag}` for a single tag
+    TransactionTaggedDataBlocks(Option<TagFeature>),
+    /// `blocks/tagged-data`, or `blocks/tagged-data/{tag}` for a single tag
+    TaggedDataBlocks(Option<TagFeature>),
+    /// `block-metadata/{block id}`
+    BlockMetadata(BlockId),
+    /// `block-metadata/referenced`
+    ReferencedBlockMetadata,
+    /// `transactions/{transaction id}/included-block`
+    TransactionIncludedBlock(TransactionId),
+    /// `outputs/{output id}`
+    Outputs(OutputId),
+    /// `outputs/alias/{alias id}`
+    AliasOutputs(AliasId),
+    /// `outputs/nft/{nft id}`
+    NftOutputs(NftId),
+    /// `outputs/foundry/{foundry id}`
+    FoundryOutputs(FoundryId),
+    /// `outputs/unlock/address/{bech32 address}`
+    AddressOutputs(Bech32Address),
+    /// `outputs/unlock/address/{bech32 address}/spent`
+    SpentAddressOutputs(Bech32Address),
+    /// `outputs/unlock/{condition}/{bech32 address}`, or `.../spent` for spent outputs, for the unlock conditions
+    /// other than the address one, which has [`Topic::AddressOutputs`] and [`Topic::SpentAddressOutputs`]
+    UnlockConditionOutputs {
+        /// The unlock condition the address is in.
+        unlock_condition: TopicUnlockCondition,
+        /// The address.
+        address: Bech32Address,
+        /// Whether the spent outputs are published instead of the created ones.
+        spent: bool,
+    },
+    /// `receipts`
+    Receipts,
+}
+
+/// The unlock condition of a [`Topic::UnlockConditionOutputs`].
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum TopicUnlockCondition {
+    /// Any unlock condition, `+`.
+    Any,
+    /// `storage-return`
+    StorageReturn,
+    /// `expiration`
+    Expiration,
+    /// `state-controller`
+    StateController,
+    /// `governor`
+    Governor,
+    /// `immutable-alias`
+    ImmutableAlias,
+}
+
+impl TopicUnlockCondition {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "+",
+            Self::StorageReturn => "storage-return",
+            Self::Expiration => "expiration",
+            Self::StateController => "state-controller",
+            Self::Governor => "governor",
+            Self::ImmutableAlias => "immutable-alias",
+        }
+    }
+
+    fn from_str(value: &str) -> Option<Self> {
+        Some(match value {
+            "+" => Self::Any,
+            "storage-return" => Self::StorageReturn,
+            "expiration" => Self::Expiration,
+            "state-controller" => Self::StateController,
+            "governor" => Self::Governor,
+            "immutable-alias" => Self::ImmutableAlias,
+            _ => return None,
+        })
+    }
+}
+
+impl Serialize for Topic {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
 
 impl<'de> Deserialize<'de> for Topic {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
@@ -156,54 +256,111 @@ impl<'de> Deserialize<'de> for Topic {
 }
 
 impl Topic {
-    /// Creates a new topic and checks if it's valid.
+    /// Parses a topic from its string representation. Only the canonical form is accepted, e.g. lowercase hex.
     pub fn new(topic: impl Into<String>) -> Result<Self, Error> {
-        let topic = Self::new_unchecked(topic);
+        let topic = topic.into();
 
-        if topic.is_valid() {
-            Ok(topic)
-        } else {
-            Err(Error::InvalidTopic(topic.0))
+        match Self::parse(&topic) {
+            Some(parsed) if parsed.to_string() == topic => Ok(parsed),
+            _ => Err(Error::InvalidTopic(topic)),
         }
     }
 
-    /// Creates a new topic without checking if the given string represents a valid topic.
-    pub(crate) fn new_unchecked(value: impl Into<String>) -> Self {
-        Self(value.into())
+    fn parse(topic: &str) -> Option<Self> {
+        let parse_tag = |tag: &str| TagFeature::new(prefix_hex::decode::<Vec<u8>>(tag).ok()?).ok();
+        let parse_address = |address: &str| Bech32Address::try_from_str(address).ok();
+
+        Some(match topic.split('/').collect::<Vec<_>>().as_slice() {
+            ["milestone-info", "latest"] => Self::LatestMilestoneInfo,
+            ["milestone-info", "confirmed"] => Self::ConfirmedMilestoneInfo,
+            ["milestones"] => Self::Milestones,
+            ["blocks"] => Self::Blocks,
+            ["blocks", "transaction"] => Self::TransactionBlocks,
+            ["blocks", "transaction", "tagged-data"] => Self::TransactionTaggedDataBlocks(None),
+            ["blocks", "transaction", "tagged-data", tag] => Self::TransactionTaggedDataBlocks(Some(parse_tag(tag)?)),
+            ["blocks", "tagged-data"] => Self::TaggedDataBlocks(None),
+            ["blocks", "tagged-data", tag] => Self::TaggedDataBlocks(Some(parse_tag(tag)?)),
+            ["block-metadata", "referenced"] => Self::ReferencedBlockMetadata,
+            ["block-metadata", block_id] => Self::BlockMetadata(block_id.parse().ok()?),
+            ["transactions", transaction_id, "included-block"] => {
+                Self::TransactionIncludedBlock(transaction_id.parse().ok()?)
+            }
+            ["outputs", "alias", alias_id] => Self::AliasOutputs(alias_id.parse().ok()?),
+            ["outputs", "nft", nft_id] => Self::NftOutputs(nft_id.parse().ok()?),
+            ["outputs", "foundry", foundry_id] => Self::FoundryOutputs(foundry_id.parse().ok()?),
+            ["outputs", "unlock", "address", address] => Self::AddressOutputs(parse_address(address)?),
+            ["outputs", "unlock", "address", address, "spent"] => Self::SpentAddressOutputs(parse_address(address)?),
+            ["outputs", "unlock", unlock_condition, address, spent @ ..] if spent.len() <= 1 => {
+                Self::UnlockConditionOutputs {
+                    unlock_condition: TopicUnlockCondition::from_str(unlock_condition)?,
+                    address: parse_address(address)?,
+                    spent: match spent {
+                        [] => false,
+                        ["spent"] => true,
+                        _ => return None,
+                    },
+                }
+            }
+            ["outputs", output_id] => Self::Outputs(output_id.parse().ok()?),
+            ["receipts"] => Self::Receipts,
+            _ => return None,
+        })
     }
 
-    pub(crate) fn is_valid(&self) -> bool {
-        let valid_topics = lazy_static!(
-            RegexSet::new([
-                // Milestone topics.
-                r"^milestone-info/latest$",
-                r"^milestone-info/confirmed$",
-                r"^milestones$",
-                // Block topics.
-                r"^blocks$",
-                r"^blocks/transaction$",
-                r"^blocks/transaction/tagged-data$",
-                r"^blocks/transaction/tagged-data/0x((?:[a-f0-9]{2}){1,64})$",
-                r"^blocks/tagged-data$",
-                r"^blocks/tagged-data/0x((?:[a-f0-9]{2}){1,64})$",
-                r"^block-metadata/0x([a-f0-9]{64})$",
-                r"^block-metadata/referenced$",
-                // Transaction topics.
-                r"^transactions/0x([a-f0-9]{64})/included-block$",
-                // Output topics.
-                r"^outputs/0x([a-f0-9]{64})(\d{4})$",
-                r"^outputs/alias/0x([a-f0-9]{64})$",
-                r"^outputs/nft/0x([a-f0-9]{64})$",
-                r"^outputs/foundry/0x([a-f0-9]{76})$",
-                r"^outputs/unlock/(\+|address|storage-return|expiration|state-controller|governor|immutable-alias)/[\x21-\x7E]{1,30}1[A-Za-z0-9]+(?:/spent)?$",
-                // Receipt topics.
-                r"^receipts$",
-            ]).expect("cannot build regex set") => RegexSet);
-        valid_topics.is_match(&self.0)
+    /// Returns the topic with the addresses encoded with the given hrp, e.g. after the network of the node changed.
+    pub fn with_hrp(self, hrp: Hrp) -> Self {
+        match self {
+            Self::AddressOutputs(address) => Self::AddressOutputs(Bech32Address::new(hrp, address.into_inner())),
+            Self::SpentAddressOutputs(address) => {
+                Self::SpentAddressOutputs(Bech32Address::new(hrp, address.into_inner()))
+            }
+            Self::UnlockConditionOutputs {
+                unlock_condition,
+                address,
+                spent,
+            } => Self::UnlockConditionOutputs {
+                unlock_condition,
+                address: Bech32Address::new(hrp, address.into_inner()),
+                spent,
+            },
+            topic => topic,
+        }
     }
+}
 
-    /// Returns the topic as a str.
-    pub fn as_str(&self) -> &str {
-        &self.0
+impl core::fmt::Display for Topic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::LatestMilestoneInfo => write!(f, "milestone-info/latest"),
+            Self::ConfirmedMilestoneInfo => write!(f, "milestone-info/confirmed"),
+            Self::Milestones => write!(f, "milestones"),
+            Self::Blocks => write!(f, "blocks"),
+            Self::TransactionBlocks => write!(f, "blocks/transaction"),
+            Self::TransactionTaggedDataBlocks(None) => write!(f, "blocks/transaction/tagged-data"),
+            Self::TransactionTaggedDataBlocks(Some(tag)) => write!(f, "blocks/transaction/tagged-data/{tag}"),
+            Self::TaggedDataBlocks(None) => write!(f, "blocks/tagged-data"),
+            Self::TaggedDataBlocks(Some(tag)) => write!(f, "blocks/tagged-data/{tag}"),
+            Self::BlockMetadata(block_id) => write!(f, "block-metadata/{block_id}"),
+            Self::ReferencedBlockMetadata => write!(f, "block-metadata/referenced"),
+            Self::TransactionIncludedBlock(transaction_id) => write!(f, "transactions/{transaction_id}/included-block"),
+            Self::Outputs(output_id) => write!(f, "outputs/{output_id}"),
+            Self::AliasOutputs(alias_id) => write!(f, "outputs/alias/{alias_id}"),
+            Self::NftOutputs(nft_id) => write!(f, "outputs/nft/{nft_id}"),
+            Self::FoundryOutputs(foundry_id) => write!(f, "outputs/foundry/{foundry_id}"),
+            Self::AddressOutputs(address) => write!(f, "outputs/unlock/address/{address}"),
+            Self::SpentAddressOutputs(address) => write!(f, "outputs/unlock/address/{address}/spent"),
+            Self::UnlockConditionOutputs {
+                unlock_condition,
+                address,
+                spent,
+            } => {
+                write!(f, "outputs/unlock/{}/{address}", unlock_condition.as_str())?;
+                if *spent {
+                    write!(f, "/spent")?;
+                }
+                Ok(())
+            }
+            Self::Receipts => write!(f, "receipts"),
+        }
     }
 }
@@ -3,38 +3,41 @@
 
 //! Custom plugin call
 
-use core::str::FromStr;
-
-use reqwest::Method;
+pub use reqwest::Method;
+use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(not(target_family = "wasm"))]
+use crate::client::request_pool::RateLimitExt;
 use crate::client::{ClientInner, Result};
 
 impl ClientInner {
-    /// Extension method which provides request methods for plugins.
-    pub async fn call_plugin_route<T>(
-        &self,
-        base_plugin_path: &str,
-        method: &str,
-        endpoint: &str,
-        query_params: Vec<String>,
-        request_object: Option<String>,
-    ) -> Result<T>
+    /// Sends a request to a route of a node that isn't covered by the client, e.g. of a custom plugin or indexer. The
+    /// path is relative to the node url and can contain a query, e.g. `api/my-plugin/v1/items?limit=10`. The body is
+    /// sent as JSON, the response is deserialized from JSON, an empty response as `null`.
+    ///
+    /// Like the other requests, it's sent with the authentication of the node, to the next node if a node fails and
+    /// retried according to the [`RetryPolicy`](crate::client::RetryPolicy). Without a body, the request type still
+    /// needs to be named, e.g. `None::<&()>`.
+    pub async fn call_plugin_route<Req, Res>(&self, method: Method, path: &str, body: Option<&Req>) -> Result<Res>
     where
-        T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize,
+        Req: Serialize + ?Sized + Sync,
+        Res: DeserializeOwned,
     {
-        let mut method = method.to_string();
-        method.make_ascii_uppercase();
-
-        let req_method = reqwest::Method::from_str(&method);
-
-        let path = format!("{}{}{}", base_plugin_path, endpoint, query_params.join("&"));
-
-        match req_method {
-            Ok(Method::GET) => self.get_request(&path, None, false, false).await,
-            Ok(Method::POST) => self.post_request_json(&path, request_object.into(), true).await,
-            _ => Err(crate::client::Error::Node(
-                crate::client::node_api::error::Error::NotSupported(method.to_string()),
-            )),
-        }
+        let json = body.map(serde_json::to_value).transpose()?;
+        let (path, query) = path
+            .split_once('?')
+            .map_or((path, None), |(path, query)| (path, Some(query)));
+
+        self.retry_policy()
+            .await
+            .run(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request =
+                    node_manager.request_json(method.clone(), path, query, self.get_timeout().await, json.as_ref());
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
     }
 }
@@ -5,7 +5,7 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::RwLock,
+    sync::{atomic::AtomicUsize, RwLock},
     time::Duration,
 };
 
@@ -17,8 +17,8 @@ use crate::{
         constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
         error::{Error, Result},
         node_manager::{
-            http_client::HttpClient,
-            node::{Node, NodeAuth, NodeDto},
+            http_client::{HttpClient, SharedHttpTransport},
+            node::{JwtRefresh, Node, NodeAuth, NodeDto},
             NodeManager,
         },
     },
@@ -44,6 +44,9 @@ pub struct NodeManagerBuilder {
     /// If the node health should be ignored
     #[serde(default)]
     pub ignore_node_health: bool,
+    /// If no node should be contacted, requests to the nodes fail with [`Error::Offline`]
+    #[serde(default)]
+    pub offline: bool,
     /// Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::client::NetworkInfo)
     /// gets updated
     #[serde(default = "default_node_sync_interval")]
@@ -58,9 +61,19 @@ pub struct NodeManagerBuilder {
     /// % of nodes that have to return the same response so it gets accepted
     #[serde(default = "default_quorum_threshold")]
     pub quorum_threshold: usize,
+    /// If finished blocks are posted to all healthy nodes at once instead of one node after another, so a single slow
+    /// node can't delay their propagation
+    #[serde(default)]
+    pub broadcast: bool,
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Callback to get a new JWT when a node rejects a request as unauthorized
+    #[serde(skip)]
+    pub jwt_refresh: Option<JwtRefresh>,
+    /// Transport to send the requests to nodes with, instead of the built-in HTTP client
+    #[serde(skip)]
+    pub http_transport: Option<SharedHttpTransport>,
 }
 
 fn default_user_agent() -> String {
@@ -155,6 +168,11 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_offline_mode(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
     pub(crate) fn with_node_auth(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
         let mut url = validate_url(Url::parse(url)?)?;
         let auth = auth.into();
@@ -206,11 +224,26 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_broadcast(mut self, broadcast: bool) -> Self {
+        self.broadcast = broadcast;
+        self
+    }
+
     pub(crate) fn with_user_agent(mut self, user_agent: String) -> Self {
         self.user_agent = user_agent;
         self
     }
 
+    pub(crate) fn with_jwt_refresh(mut self, jwt_refresh: JwtRefresh) -> Self {
+        self.jwt_refresh.replace(jwt_refresh);
+        self
+    }
+
+    pub(crate) fn with_http_transport(mut self, http_transport: SharedHttpTransport) -> Self {
+        self.http_transport.replace(http_transport);
+        self
+    }
+
     pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
         NodeManager {
             primary_node: self.primary_node.map(Into::into),
@@ -218,12 +251,19 @@ impl NodeManagerBuilder {
             nodes: self.nodes.into_iter().map(Into::into).collect(),
             permanodes: self.permanodes.into_iter().map(Into::into).collect(),
             ignore_node_health: self.ignore_node_health,
+            offline: self.offline,
             node_sync_interval: self.node_sync_interval,
             healthy_nodes: RwLock::new(healthy_nodes),
+            node_stats: RwLock::new(HashMap::new()),
+            request_count: AtomicUsize::new(0),
+            node_routes: RwLock::new(HashMap::new()),
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
+            broadcast: self.broadcast,
+            http_client: HttpClient::new(self.user_agent)
+                .with_jwt_refresh(self.jwt_refresh)
+                .with_transport(self.http_transport),
         }
     }
 }
@@ -236,11 +276,15 @@ impl Default for NodeManagerBuilder {
             nodes: HashSet::new(),
             permanodes: HashSet::new(),
             ignore_node_health: false,
+            offline: false,
             node_sync_interval: NODE_SYNC_INTERVAL,
             quorum: false,
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
+            broadcast: false,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            jwt_refresh: None,
+            http_transport: None,
         }
     }
 }
@@ -261,11 +305,15 @@ impl From<&NodeManager> for NodeManagerBuilder {
             nodes: value.nodes.iter().cloned().map(NodeDto::Node).collect(),
             permanodes: value.permanodes.iter().cloned().map(NodeDto::Node).collect(),
             ignore_node_health: value.ignore_node_health,
+            offline: value.offline,
             node_sync_interval: value.node_sync_interval,
             quorum: value.quorum,
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
+            broadcast: value.broadcast,
             user_agent: value.http_client.user_agent.clone(),
+            jwt_refresh: value.http_client.jwt_refresh.clone(),
+            http_transport: value.http_client.transport.clone(),
         }
     }
 }
@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// An API that a node only provides if the plugin of it is enabled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeCapability {
+    /// The indexer API, to query outputs by their properties.
+    Indexer,
+    /// The participation API, for staking and voting.
+    Participation,
+    /// The MQTT API, to subscribe to events.
+    Mqtt,
+    /// The proof of inclusion API, to prove that a block is referenced by a milestone.
+    Poi,
+}
+
+impl NodeCapability {
+    /// Returns the route group of `GET /api/routes` that provides the API.
+    pub fn route(&self) -> &'static str {
+        match self {
+            Self::Indexer => "indexer/v1",
+            Self::Participation => "participation/v1",
+            Self::Mqtt => "mqtt/v1",
+            Self::Poi => "poi/v1",
+        }
+    }
+
+    /// Returns the capability a node needs to answer requests to the path.
+    pub(crate) fn required_by(path: &str) -> Option<Self> {
+        let route = path.strip_prefix("api/")?;
+
+        [Self::Indexer, Self::Participation, Self::Mqtt, Self::Poi]
+            .into_iter()
+            .find(|capability| route.starts_with(capability.route()))
+    }
+}
+
+impl core::fmt::Display for NodeCapability {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.route())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_by() {
+        assert_eq!(
+            NodeCapability::required_by("api/indexer/v1/outputs/basic"),
+            Some(NodeCapability::Indexer)
+        );
+        assert_eq!(
+            NodeCapability::required_by("api/participation/v1/events"),
+            Some(NodeCapability::Participation)
+        );
+        assert_eq!(
+            NodeCapability::required_by("api/poi/v1/create/0x"),
+            Some(NodeCapability::Poi)
+        );
+        assert_eq!(NodeCapability::required_by("api/core/v2/info"), None);
+        assert_eq!(NodeCapability::required_by("health"), None);
+    }
+}
@@ -1,42 +1,129 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
+//! The HTTP client that sends the requests to nodes, with a replaceable transport
 
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
-use reqwest::RequestBuilder;
+use async_trait::async_trait;
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use url::Url;
 
 use crate::client::{
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::{
+        node::{JwtRefresh, Node},
+        score::node_key,
+    },
 };
-pub(crate) struct Response(reqwest::Response);
+
+/// A request to a node, sent by an [`HttpTransport`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The url of the request, it contains the credentials if the node uses basic authentication.
+    pub url: Url,
+    /// The headers of the request, including the user agent and the JWT of the node.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request.
+    pub body: Option<Vec<u8>>,
+    /// The time after which the request should be aborted.
+    pub timeout: Duration,
+}
+
+/// The response of a node to an [`HttpRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    /// The status code of the response.
+    pub status: u16,
+    /// The headers of the response, only the ones the SDK reads, like `Retry-After`, have to be set.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+/// Transport that sends the requests to nodes, to use another HTTP client than the built-in one, for example to
+/// route requests through a proxy.
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    /// Sends a request. Responses with an unsuccessful status code are returned as responses, only failures to get a
+    /// response are errors, for which [`Error::Transport`] can be used.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// An [`HttpTransport`] that can be shared between clients.
+#[derive(Clone)]
+pub struct SharedHttpTransport(Arc<dyn HttpTransport>);
+
+impl SharedHttpTransport {
+    /// Creates a new [`SharedHttpTransport`].
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self(Arc::new(transport))
+    }
+}
+
+impl std::fmt::Debug for SharedHttpTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedHttpTransport").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for SharedHttpTransport {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SharedHttpTransport {}
+
+pub(crate) struct Response(HttpResponse);
 
 impl Response {
     pub(crate) fn status(&self) -> u16 {
-        self.0.status().as_u16()
+        self.0.status
+    }
+
+    /// Returns the value of a header, the name is case-insensitive.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.0
+            .headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
     }
 
     pub(crate) async fn into_json<T: DeserializeOwned>(self) -> Result<T> {
-        self.0.json().await.map_err(Into::into)
+        serde_json::from_slice(&self.0.body).map_err(Into::into)
     }
 
     #[cfg(not(target_family = "wasm"))]
     pub(crate) async fn into_text(self) -> Result<String> {
-        self.0.text().await.map_err(Into::into)
+        String::from_utf8(self.0.body).map_err(|e| Error::Transport(e.to_string()))
     }
 
     pub(crate) async fn into_bytes(self) -> Result<Vec<u8>> {
-        self.0.bytes().await.map(|b| b.to_vec()).map_err(Into::into)
+        Ok(self.0.body)
     }
 }
 
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: reqwest::Client,
+    /// Clients with a client certificate for mutual TLS, by the path of the PEM file.
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    identity_clients: Arc<RwLock<HashMap<std::path::PathBuf, reqwest::Client>>>,
+    /// JWTs provided by `jwt_refresh`, by node.
+    refreshed_jwts: Arc<RwLock<HashMap<String, String>>>,
+    pub(crate) jwt_refresh: Option<JwtRefresh>,
+    /// Sends the requests instead of `client` if set.
+    pub(crate) transport: Option<SharedHttpTransport>,
     pub(crate) user_agent: String,
 }
 
@@ -44,16 +131,31 @@ impl HttpClient {
     pub(crate) fn new(user_agent: String) -> Self {
         Self {
             client: reqwest::Client::new(),
+            #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+            identity_clients: Default::default(),
+            refreshed_jwts: Default::default(),
+            jwt_refresh: None,
+            transport: None,
             user_agent,
         }
     }
 
-    async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
+    pub(crate) fn with_jwt_refresh(mut self, jwt_refresh: impl Into<Option<JwtRefresh>>) -> Self {
+        self.jwt_refresh = jwt_refresh.into();
+        self
+    }
+
+    pub(crate) fn with_transport(mut self, transport: impl Into<Option<SharedHttpTransport>>) -> Self {
+        self.transport = transport.into();
+        self
+    }
+
+    pub(crate) fn parse_response(response: Response, url: &Url) -> Result<Response> {
         let status = response.status();
-        if status.is_success() {
-            Ok(Response(response))
+        if (200..300).contains(&status) {
+            Ok(response)
         } else {
-            let text = response.text().await?;
+            let text = String::from_utf8_lossy(&response.0.body).into_owned();
             // Different urls, nodes and versions give different replies
             if text == *"no available nodes with remote Pow"
                 || text.contains("proof of work is not available on this node")
@@ -61,11 +163,11 @@ impl HttpClient {
                 || text.contains("`Pow` not enabled")
             {
                 Err(Error::UnavailablePow)
-            } else if status.as_u16() == 404 {
+            } else if status == 404 {
                 Err(Error::NotFound(url.to_string()))
             } else {
                 Err(Error::ResponseError {
-                    code: status.as_u16(),
+                    code: status,
                     text,
                     url: url.to_string(),
                 })
@@ -73,54 +175,208 @@ impl HttpClient {
         }
     }
 
-    fn build_request(&self, request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
-        let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
-
-        if let Some(node_auth) = &node.auth {
-            if let Some(jwt) = &node_auth.jwt {
-                request_builder = request_builder.bearer_auth(jwt);
+    /// Returns the client to use for a node, which holds the client certificate of the node if it has one. The
+    /// certificate file is only read for the first request.
+    async fn client(&self, _node: &Node) -> Result<reqwest::Client> {
+        #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+        if let Some(path) = _node.auth.as_ref().and_then(|auth| auth.client_certificate.as_ref()) {
+            if let Some(client) = self
+                .identity_clients
+                .read()
+                .ok()
+                .and_then(|identity_clients| identity_clients.get(path).cloned())
+            {
+                return Ok(client);
+            }
+            let file_path = path.clone();
+            let pem = crate::client::runtime::spawn_blocking(move || std::fs::read(file_path))
+                .await
+                .map_err(|e| Error::Transport(e.to_string()))?
+                .map_err(|e| Error::Transport(format!("can't read client certificate {}: {e}", path.display())))?;
+            let client = reqwest::Client::builder()
+                .identity(reqwest::Identity::from_pem(&pem)?)
+                .build()?;
+            if let Ok(mut identity_clients) = self.identity_clients.write() {
+                identity_clients.insert(path.clone(), client.clone());
             }
+            return Ok(client);
+        }
+
+        Ok(self.client.clone())
+    }
+
+    fn build_request(
+        &self,
+        method: Method,
+        node: &Node,
+        timeout: Duration,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> HttpRequest {
+        let mut request_headers = vec![(reqwest::header::USER_AGENT.to_string(), self.user_agent.clone())];
+
+        let refreshed_jwt = self
+            .refreshed_jwts
+            .read()
+            .ok()
+            .and_then(|refreshed_jwts| refreshed_jwts.get(&node_key(&node.url)).cloned());
+        if let Some(jwt) = refreshed_jwt
+            .as_ref()
+            .or_else(|| node.auth.as_ref().and_then(|auth| auth.jwt.as_ref()))
+        {
+            request_headers.push((reqwest::header::AUTHORIZATION.to_string(), format!("Bearer {jwt}")));
+        }
+        if let Some(node_headers) = node.auth.as_ref().and_then(|auth| auth.headers.as_ref()) {
+            request_headers.extend(node_headers.iter().map(|(name, value)| (name.clone(), value.clone())));
+        }
+        request_headers.extend(
+            headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+        );
+
+        HttpRequest {
+            method,
+            url: node.url.clone(),
+            headers: request_headers,
+            body: body.map(<[u8]>::to_vec),
+            timeout,
+        }
+    }
+
+    /// Sends a request with the transport if one is set, otherwise with the built-in client.
+    async fn send_request(&self, node: &Node, request: HttpRequest) -> Result<Response> {
+        if let Some(transport) = &self.transport {
+            return transport.0.send(request).await.map(Response);
+        }
+
+        let mut request_builder = self.client(node).await?.request(request.method, request.url);
+        for (name, value) in request.headers {
+            request_builder = request_builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            request_builder = request_builder.body(body);
         }
         #[cfg(not(target_family = "wasm"))]
         {
-            request_builder = request_builder.timeout(_timeout);
+            request_builder = request_builder.timeout(request.timeout);
+        }
+
+        let response = request_builder.send().await?;
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        Ok(Response(HttpResponse {
+            status: response.status().as_u16(),
+            headers,
+            body: response.bytes().await?.to_vec(),
+        }))
+    }
+
+    /// Sends a request, if the node rejects it as unauthorized and a new JWT can be obtained, it's sent once more.
+    async fn send(
+        &self,
+        method: Method,
+        node: &Node,
+        timeout: Duration,
+        headers: &[(&str, &str)],
+        body: Option<&[u8]>,
+    ) -> Result<Response> {
+        let response = self
+            .send_request(node, self.build_request(method.clone(), node, timeout, headers, body))
+            .await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED.as_u16() {
+            if let Some(jwt_refresh) = &self.jwt_refresh {
+                if let Some(jwt) = jwt_refresh.refresh(node.url.clone()).await {
+                    log::debug!("refreshed JWT for {}", node_key(&node.url));
+                    if let Ok(mut refreshed_jwts) = self.refreshed_jwts.write() {
+                        refreshed_jwts.insert(node_key(&node.url), jwt);
+                    }
+                    return self
+                        .send_request(node, self.build_request(method, node, timeout, headers, body))
+                        .await;
+                }
+            }
         }
-        request_builder
+
+        Ok(response)
     }
 
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
         let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
+        let resp = self.send(Method::GET, &node, timeout, &[], None).await?;
         log::debug!(
             "GET: {:?} ms for {} {}",
             start_time.elapsed().as_millis(),
             resp.status(),
             node.url
         );
-        Self::parse_response(resp, &node.url).await
+        Self::parse_response(resp, &node.url)
     }
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+        let resp = self
+            .send(
+                Method::GET,
+                &node,
+                timeout,
+                &[("accept", "application/vnd.iota.serializer-v1")],
+                None,
+            )
+            .await?;
+        Self::parse_response(resp, &node.url)
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        let url = node.url.clone();
+        Self::parse_response(self.post_json_unchecked(node, timeout, json).await?, &url)
+    }
+
+    /// Posts JSON and returns the response whatever its status code is.
+    pub(crate) async fn post_json_unchecked(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
+        let body = serde_json::to_vec(&json)?;
+        self.send(
+            Method::POST,
+            &node,
+            timeout,
+            &[("Content-Type", "application/json")],
+            Some(&body),
+        )
+        .await
+    }
+
+    /// Sends a request with any method, with the JSON body if there is one.
+    pub(crate) async fn request_json(
+        &self,
+        method: Method,
+        node: Node,
+        timeout: Duration,
+        json: Option<&Value>,
+    ) -> Result<Response> {
+        let body = json.map(serde_json::to_vec).transpose()?;
+        let headers: &[(&str, &str)] = if body.is_some() {
+            &[("Content-Type", "application/json")]
+        } else {
+            &[]
+        };
+        let resp = self.send(method, &node, timeout, headers, body.as_deref()).await?;
+        Self::parse_response(resp, &node.url)
     }
 
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        let resp = self
+            .send(
+                Method::POST,
+                &node,
+                timeout,
+                &[("Content-Type", "application/vnd.iota.serializer-v1")],
+                Some(body),
+            )
+            .await?;
+        Self::parse_response(resp, &node.url)
     }
 }
@@ -4,22 +4,33 @@
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
 pub mod builder;
-pub(crate) mod http_client;
+/// Optional APIs of nodes
+pub mod capability;
+/// HTTP client and transport for requests to nodes
+pub mod http_client;
 /// Structs for nodes
 pub mod node;
+/// Health scoring of nodes
+pub mod score;
 pub(crate) mod syncing;
 
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    sync::RwLock,
+    sync::{atomic::AtomicUsize, RwLock},
     time::Duration,
 };
 
+use reqwest::Method;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{
+    capability::NodeCapability,
+    http_client::HttpClient,
+    node::Node,
+    score::{node_key, NodeStats},
+};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
@@ -39,11 +50,18 @@ pub struct NodeManager {
     pub(crate) nodes: HashSet<Node>,
     permanodes: HashSet<Node>,
     pub(crate) ignore_node_health: bool,
+    pub(crate) offline: bool,
     node_sync_interval: Duration,
     pub(crate) healthy_nodes: RwLock<HashMap<Node, InfoResponse>>,
+    pub(crate) node_stats: RwLock<HashMap<String, NodeStats>>,
+    // the number of requests sent to nodes
+    pub(crate) request_count: AtomicUsize,
+    // the route groups of `GET /api/routes` of the nodes, by node key
+    pub(crate) node_routes: RwLock<HashMap<String, HashSet<String>>>,
     quorum: bool,
     min_quorum_size: usize,
     quorum_threshold: usize,
+    pub(crate) broadcast: bool,
     pub(crate) http_client: HttpClient,
 }
 
@@ -55,11 +73,16 @@ impl Debug for NodeManager {
         d.field("nodes", &self.nodes);
         d.field("permanodes", &self.permanodes);
         d.field("ignore_node_health", &self.ignore_node_health);
+        d.field("offline", &self.offline);
         d.field("node_sync_interval", &self.node_sync_interval);
         d.field("healthy_nodes", &self.healthy_nodes);
+        d.field("node_stats", &self.node_stats);
+        d.field("request_count", &self.request_count);
+        d.field("node_routes", &self.node_routes);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("broadcast", &self.broadcast).finish()
     }
 }
 
@@ -71,19 +94,48 @@ impl ClientInner {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request(path, query, self.get_timeout().await, need_quorum, prefer_permanode);
+        let request = || async {
+            self.retry_policy()
+                .await
+                .run(|| async {
+                    let node_manager = self.node_manager.read().await;
+                    let request =
+                        node_manager.get_request(path, query, self.get_timeout().await, need_quorum, prefer_permanode);
+                    #[cfg(not(target_family = "wasm"))]
+                    let request = request.rate_limit(&self.request_pool);
+                    request.await
+                })
+                .await
+        };
+
+        // Concurrent identical requests are sent only once, as the same parameters select the same nodes
         #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        return self
+            .request_pool
+            .coalesce(
+                format!(
+                    "{}|{path}?{}|{need_quorum}|{prefer_permanode}",
+                    std::any::type_name::<T>(),
+                    query.unwrap_or_default()
+                ),
+                request,
+            )
+            .await;
+        #[cfg(target_family = "wasm")]
+        request().await
     }
 
     pub(crate) async fn get_request_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
-        #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        self.retry_policy()
+            .await
+            .run(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
     }
 
     pub(crate) async fn post_request_json<T: DeserializeOwned>(
@@ -92,11 +144,17 @@ impl ClientInner {
         json: Value,
         local_pow: bool,
     ) -> Result<T> {
-        let node_manager = self.node_manager.read().await;
-        let request = node_manager.post_request_json(path, self.get_timeout().await, json, local_pow);
-        #[cfg(not(target_family = "wasm"))]
-        let request = request.rate_limit(&self.request_pool);
-        request.await
+        // Posting the same data again is fine, as nodes answer with the same id
+        self.retry_policy()
+            .await
+            .run(|| async {
+                let node_manager = self.node_manager.read().await;
+                let request = node_manager.post_request_json(path, self.get_timeout().await, json.clone(), local_pow);
+                #[cfg(not(target_family = "wasm"))]
+                let request = request.rate_limit(&self.request_pool);
+                request.await
+            })
+            .await
     }
 }
 
@@ -112,6 +170,10 @@ impl NodeManager {
         use_pow_nodes: bool,
         prefer_permanode: bool,
     ) -> Result<Vec<Node>> {
+        if self.offline {
+            return Err(Error::Offline);
+        }
+
         let mut nodes_with_modified_url: Vec<Node> = Vec::new();
 
         if prefer_permanode || (path == "api/core/v2/blocks" && query.is_some()) {
@@ -136,8 +198,8 @@ impl NodeManager {
             }
         }
 
-        // Add other nodes in random order, so they are not always used in the same order
-        let nodes_random_order = if !self.ignore_node_health {
+        // Collect the other candidate nodes
+        let candidate_nodes: HashSet<Node> = if !self.ignore_node_health {
             #[cfg(not(target_family = "wasm"))]
             {
                 self.healthy_nodes
@@ -168,8 +230,14 @@ impl NodeManager {
             self.nodes.clone()
         };
 
-        // Add remaining nodes in random order
-        for node in nodes_random_order {
+        // Add remaining nodes ordered by their health score, best first
+        let mut nodes_by_score = candidate_nodes
+            .into_iter()
+            .map(|node| (self.node_score(&node), node))
+            .collect::<Vec<_>>();
+        nodes_by_score.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        for (_, node) in nodes_by_score {
             if !nodes_with_modified_url.iter().any(|n| n.url == node.url) {
                 nodes_with_modified_url.push(node);
             }
@@ -178,6 +246,14 @@ impl NodeManager {
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
 
+        // Only use nodes that provide the API of the path, nodes of which the routes aren't known yet are tried
+        if let Some(capability) = NodeCapability::required_by(path) {
+            nodes_with_modified_url.retain(|n| self.supports(n, capability).unwrap_or(true));
+            if nodes_with_modified_url.is_empty() {
+                return Err(Error::MissingCapability(capability));
+            }
+        }
+
         if nodes_with_modified_url.is_empty() {
             if use_pow_nodes {
                 return Err(crate::client::Error::Node(
@@ -206,6 +282,15 @@ impl NodeManager {
         Ok(nodes_with_modified_url)
     }
 
+    /// Returns whether the node provides the API of the capability, if its routes are known.
+    pub(crate) fn supports(&self, node: &Node, capability: NodeCapability) -> Option<bool> {
+        self.node_routes
+            .read()
+            .ok()?
+            .get(&node_key(&node.url))
+            .map(|routes| routes.contains(capability.route()))
+    }
+
     pub(crate) async fn get_request<T: DeserializeOwned + Debug + Serialize>(
         &self,
         path: &str,
@@ -240,22 +325,33 @@ impl NodeManager {
                 for (index, node) in nodes.into_iter().enumerate() {
                     if index < self.min_quorum_size {
                         let client_ = self.http_client.clone();
-                        tasks.push(async move { tokio::spawn(async move { client_.get(node, timeout).await }).await });
+                        tasks.push(async move {
+                            crate::client::runtime::spawn(async move {
+                                let start_time = instant::Instant::now();
+                                let res = client_.get(node.clone(), timeout).await;
+                                (node, start_time.elapsed(), res)
+                            })
+                            .await
+                        });
                     }
                 }
-                for res in futures::future::try_join_all(tasks).await? {
+                for (node, latency, res) in futures::future::try_join_all(tasks).await? {
                     match res {
-                        Ok(res) => (res.into_text().await).map_or_else(
-                            |_| {
-                                log::warn!("couldn't convert node response to text");
-                            },
-                            |res_text| {
-                                let counters = result.entry(res_text).or_insert(0);
-                                *counters += 1;
-                                result_counter += 1;
-                            },
-                        ),
+                        Ok(res) => {
+                            self.record_success(&node, latency);
+                            (res.into_text().await).map_or_else(
+                                |_| {
+                                    log::warn!("couldn't convert node response to text");
+                                },
+                                |res_text| {
+                                    let counters = result.entry(res_text).or_insert(0);
+                                    *counters += 1;
+                                    result_counter += 1;
+                                },
+                            )
+                        }
                         Err(err) => {
+                            self.record_failure(&node, &err);
                             error.replace(err.into());
                         }
                     }
@@ -264,8 +360,10 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
+                let start_time = instant::Instant::now();
                 match self.http_client.get(node.clone(), timeout).await {
                     Ok(res) => {
+                        self.record_success(&node, start_time.elapsed());
                         // Handle node_info extra because we also want to return the url
                         if path == crate::client::node_api::core::routes::INFO_PATH {
                             let node_info: InfoResponse = res.into_json().await?;
@@ -298,6 +396,7 @@ impl NodeManager {
                         }
                     }
                     Err(err) => {
+                        self.record_failure(&node, &err);
                         error.replace(err.into());
                     }
                 }
@@ -337,14 +436,17 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.get_bytes(node, timeout).await {
+            let start_time = instant::Instant::now();
+            match self.http_client.get_bytes(node.clone(), timeout).await {
                 Ok(res) => {
+                    self.record_success(&node, start_time.elapsed());
                     match res.into_bytes().await {
                         Ok(res_text) => return Ok(res_text),
                         Err(e) => error.replace(e.into()),
                     };
                 }
                 Err(err) => {
+                    self.record_failure(&node, &err);
                     error.replace(err.into());
                 }
             }
@@ -366,14 +468,17 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_bytes(node, timeout, body).await {
+            let start_time = instant::Instant::now();
+            match self.http_client.post_bytes(node.clone(), timeout, body).await {
                 Ok(res) => {
+                    self.record_success(&node, start_time.elapsed());
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
                         Err(e) => error.replace(e.into()),
                     };
                 }
                 Err(e) => {
+                    self.record_failure(&node, &e);
                     error.replace(Error::Node(e));
                 }
             }
@@ -383,6 +488,31 @@ impl NodeManager {
         Err(error.unwrap())
     }
 
+    // Posts the body to all nodes at the same time and returns the result of each node
+    pub(crate) async fn broadcast_request_bytes<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+        body: &[u8],
+    ) -> Result<Vec<(Node, Result<T>)>> {
+        let nodes = self.get_nodes(path, None, false, false)?;
+        let requests = nodes.into_iter().map(|node| async move {
+            let start_time = instant::Instant::now();
+            let result = match self.http_client.post_bytes(node.clone(), timeout, body).await {
+                Ok(res) => {
+                    self.record_success(&node, start_time.elapsed());
+                    res.into_json::<T>().await.map_err(Into::into)
+                }
+                Err(e) => {
+                    self.record_failure(&node, &e);
+                    Err(Error::Node(e))
+                }
+            };
+            (node, result)
+        });
+        Ok(futures::future::join_all(requests).await)
+    }
+
     pub(crate) async fn post_request_json<T: DeserializeOwned>(
         &self,
         path: &str,
@@ -395,14 +525,61 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_json(node, timeout, json.clone()).await {
+            let start_time = instant::Instant::now();
+            match self.http_client.post_json(node.clone(), timeout, json.clone()).await {
                 Ok(res) => {
+                    self.record_success(&node, start_time.elapsed());
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
                         Err(e) => error.replace(e.into()),
                     };
                 }
                 Err(e) => {
+                    self.record_failure(&node, &e);
+                    error.replace(Error::Node(e));
+                }
+            }
+        }
+        // Safe unwrap, there are nodes because we throw on empty nodepool.
+        // Each node will throw an error or return Ok()
+        Err(error.unwrap())
+    }
+
+    /// Sends a request with any method to the nodes until one of them answers, without quorum. An empty response is
+    /// deserialized as `null`, so it can be received as `()`.
+    pub(crate) async fn request_json<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        json: Option<&Value>,
+    ) -> Result<T> {
+        let nodes = self.get_nodes(path, query, false, false)?;
+        let mut error = None;
+        // Send requests
+        for node in nodes {
+            let start_time = instant::Instant::now();
+            match self
+                .http_client
+                .request_json(method.clone(), node.clone(), timeout, json)
+                .await
+            {
+                Ok(res) => {
+                    self.record_success(&node, start_time.elapsed());
+                    let body = res.into_bytes().await?;
+                    let res = if body.is_empty() {
+                        serde_json::from_value(Value::Null)
+                    } else {
+                        serde_json::from_slice(&body)
+                    };
+                    match res {
+                        Ok(res) => return Ok(res),
+                        Err(e) => error.replace(e.into()),
+                    };
+                }
+                Err(e) => {
+                    self.record_failure(&node, &e);
                     error.replace(Error::Node(e));
                 }
             }
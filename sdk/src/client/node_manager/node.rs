@@ -1,22 +1,99 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::hash::Hash;
+use std::{collections::BTreeMap, fmt, future::Future, hash::Hash, path::PathBuf, pin::Pin, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 
 use crate::Url;
 
 /// Node authentication object.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct NodeAuth {
     /// JWT.
     pub jwt: Option<String>,
     /// Username and password.
     pub basic_auth_name_pwd: Option<(String, String)>,
+    /// Additional headers sent with every request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<BTreeMap<String, String>>,
+    /// Path of a PEM file with the client certificate and private key for mutual TLS, only the path is stored with the
+    /// client options. Only used with the `tls` feature on non-wasm targets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate: Option<PathBuf>,
+}
+
+impl NodeAuth {
+    /// Creates a new [`NodeAuth`] without any authentication.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the JWT.
+    pub fn with_jwt(mut self, jwt: impl Into<String>) -> Self {
+        self.jwt.replace(jwt.into());
+        self
+    }
+
+    /// Sets the username and password for basic authentication.
+    pub fn with_basic_auth(mut self, name: impl Into<String>, password: impl Into<String>) -> Self {
+        self.basic_auth_name_pwd.replace((name.into(), password.into()));
+        self
+    }
+
+    /// Adds a header that is sent with every request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .get_or_insert_with(Default::default)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the path of the PEM file with the client certificate and private key for mutual TLS.
+    pub fn with_client_certificate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.client_certificate.replace(path.into());
+        self
+    }
 }
 
+type JwtRefreshFn = dyn Fn(Url) -> Pin<Box<dyn Future<Output = Option<String>> + Send>> + Send + Sync;
+
+/// Callback that provides a new JWT for a node after it rejected a request as unauthorized. It's called with the url of
+/// the node, the request is retried once if it returns a JWT.
+#[derive(Clone)]
+pub struct JwtRefresh(Arc<JwtRefreshFn>);
+
+impl JwtRefresh {
+    /// Creates a new [`JwtRefresh`] from an async callback.
+    pub fn new<F, Fut>(callback: F) -> Self
+    where
+        F: Fn(Url) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<String>> + Send + 'static,
+    {
+        Self(Arc::new(move |url| Box::pin(callback(url))))
+    }
+
+    pub(crate) async fn refresh(&self, url: Url) -> Option<String> {
+        (self.0)(url).await
+    }
+}
+
+impl fmt::Debug for JwtRefresh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtRefresh").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for JwtRefresh {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for JwtRefresh {}
+
 /// Node definition.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Node {
@@ -0,0 +1,245 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Health scoring of nodes, used to route requests to the best performing healthy node.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::atomic::Ordering,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::client::{
+    constants::{NODE_SCORE_FLAKY_ERROR_RATE, NODE_SCORE_SAMPLE_SIZE},
+    node_api::error::Error as NodeApiError,
+    node_manager::{node::Node, NodeManager},
+    ClientInner, Error, Result,
+};
+
+/// Request statistics of a single node, collected over the last [`NODE_SCORE_SAMPLE_SIZE`] requests.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct NodeStats {
+    latencies: VecDeque<Duration>,
+    outcomes: VecDeque<bool>,
+    milestone_lag: u32,
+}
+
+impl NodeStats {
+    pub(crate) fn record_success(&mut self, latency: Duration) {
+        if self.latencies.len() == NODE_SCORE_SAMPLE_SIZE {
+            self.latencies.pop_front();
+        }
+        self.latencies.push_back(latency);
+        self.record_outcome(true);
+    }
+
+    pub(crate) fn record_failure(&mut self) {
+        self.record_outcome(false);
+    }
+
+    pub(crate) fn set_milestone_lag(&mut self, milestone_lag: u32) {
+        self.milestone_lag = milestone_lag;
+    }
+
+    fn record_outcome(&mut self, success: bool) {
+        if self.outcomes.len() == NODE_SCORE_SAMPLE_SIZE {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(success);
+    }
+
+    /// Returns the latency below which `percentile`% of the recorded requests completed.
+    fn latency_percentile(&self, percentile: usize) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let mut latencies = self.latencies.iter().copied().collect::<Vec<_>>();
+        latencies.sort_unstable();
+        let index = (latencies.len() * percentile).div_ceil(100).saturating_sub(1);
+
+        Some(latencies[index.min(latencies.len() - 1)])
+    }
+
+    fn error_rate(&self) -> f64 {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        self.outcomes.iter().filter(|success| !**success).count() as f64 / self.outcomes.len() as f64
+    }
+
+    /// Computes a score between 0 and 100, higher is better. Nodes without samples get the maximum score so they
+    /// are tried.
+    pub(crate) fn score(&self) -> f64 {
+        let latency_factor = self
+            .latency_percentile(90)
+            .map_or(1.0, |latency| 1.0 / (1.0 + latency.as_secs_f64()));
+        let error_rate = self.error_rate();
+        let mut score = 100.0 * latency_factor * (1.0 - error_rate) / (1.0 + self.milestone_lag as f64);
+
+        // Demote flaky nodes so they are only used when nothing better is available.
+        if error_rate >= NODE_SCORE_FLAKY_ERROR_RATE {
+            score /= 10.0;
+        }
+
+        score
+    }
+
+    fn to_node_score(&self, url: String) -> NodeScore {
+        NodeScore {
+            url,
+            latency_p50: self.latency_percentile(50),
+            latency_p90: self.latency_percentile(90),
+            latency_p99: self.latency_percentile(99),
+            error_rate: self.error_rate(),
+            milestone_lag: self.milestone_lag,
+            samples: self.outcomes.len(),
+            score: self.score(),
+        }
+    }
+}
+
+/// The current health score of a node.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeScore {
+    /// The node url, without path.
+    pub url: String,
+    /// The median latency of the recorded requests.
+    pub latency_p50: Option<Duration>,
+    /// The 90th percentile latency of the recorded requests.
+    pub latency_p90: Option<Duration>,
+    /// The 99th percentile latency of the recorded requests.
+    pub latency_p99: Option<Duration>,
+    /// The share of failed requests, between 0 and 1.
+    pub error_rate: f64,
+    /// The number of milestones the node is behind the most synced node.
+    pub milestone_lag: u32,
+    /// The number of recorded requests.
+    pub samples: usize,
+    /// The score between 0 and 100, higher is better.
+    pub score: f64,
+}
+
+/// Returns the key under which the stats of a node are stored, which is independent of the request path.
+pub(crate) fn node_key(url: &Url) -> String {
+    url.origin().ascii_serialization()
+}
+
+/// Returns whether a request error should count against the health of a node.
+fn is_node_failure(error: &NodeApiError) -> bool {
+    match error {
+        NodeApiError::NotFound(_) | NodeApiError::UnavailablePow | NodeApiError::NotSupported(_) => false,
+        NodeApiError::ResponseError { code, .. } => *code >= 500 || *code == 429,
+        NodeApiError::Reqwest(_) | NodeApiError::Transport(_) | NodeApiError::Json(_) => true,
+    }
+}
+
+impl NodeManager {
+    pub(crate) fn record_success(&self, node: &Node, latency: Duration) {
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut node_stats) = self.node_stats.write() {
+            node_stats
+                .entry(node_key(&node.url))
+                .or_default()
+                .record_success(latency);
+        }
+    }
+
+    pub(crate) fn record_failure(&self, node: &Node, error: &NodeApiError) {
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        if is_node_failure(error) {
+            if let Ok(mut node_stats) = self.node_stats.write() {
+                node_stats.entry(node_key(&node.url)).or_default().record_failure();
+            }
+        }
+    }
+
+    pub(crate) fn node_score(&self, node: &Node) -> f64 {
+        self.node_stats
+            .read()
+            .ok()
+            .and_then(|node_stats| node_stats.get(&node_key(&node.url)).map(NodeStats::score))
+            .unwrap_or_else(|| NodeStats::default().score())
+    }
+}
+
+impl ClientInner {
+    /// Returns the current health scores of the nodes, best first.
+    pub async fn node_scores(&self) -> Result<Vec<NodeScore>> {
+        let node_manager = self.node_manager.read().await;
+        let node_stats = node_manager.node_stats.read().map_err(|_| Error::PoisonError)?;
+
+        let mut scores = node_manager
+            .primary_node
+            .iter()
+            .chain(node_manager.nodes.iter())
+            .map(|node| node_key(&node.url))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|key| node_stats.get(&key).cloned().unwrap_or_default().to_node_score(key))
+            .collect::<Vec<_>>();
+        scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        Ok(scores)
+    }
+
+    /// Returns the number of requests sent to nodes since the node options were set.
+    pub async fn request_count(&self) -> usize {
+        self.node_manager.read().await.request_count.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_percentiles() {
+        let mut stats = NodeStats::default();
+        for millis in 1..=100 {
+            stats.record_success(Duration::from_millis(millis));
+        }
+
+        assert_eq!(stats.latency_percentile(50), Some(Duration::from_millis(50)));
+        assert_eq!(stats.latency_percentile(90), Some(Duration::from_millis(90)));
+        assert_eq!(stats.latency_percentile(100), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn sample_window() {
+        let mut stats = NodeStats::default();
+        for _ in 0..NODE_SCORE_SAMPLE_SIZE {
+            stats.record_failure();
+        }
+        for _ in 0..NODE_SCORE_SAMPLE_SIZE {
+            stats.record_success(Duration::from_millis(10));
+        }
+
+        assert_eq!(stats.error_rate(), 0.0);
+        assert_eq!(stats.to_node_score(String::new()).samples, NODE_SCORE_SAMPLE_SIZE);
+    }
+
+    #[test]
+    fn score_ordering() {
+        let mut fast = NodeStats::default();
+        let mut slow = NodeStats::default();
+        let mut lagging = NodeStats::default();
+        let mut flaky = NodeStats::default();
+        for _ in 0..10 {
+            fast.record_success(Duration::from_millis(50));
+            slow.record_success(Duration::from_millis(2000));
+            lagging.record_success(Duration::from_millis(50));
+            flaky.record_success(Duration::from_millis(50));
+            flaky.record_failure();
+        }
+        lagging.set_milestone_lag(5);
+
+        assert!(fast.score() > slow.score());
+        assert!(fast.score() > lagging.score());
+        assert!(slow.score() > flaky.score());
+        assert_eq!(NodeStats::default().score(), 100.0);
+    }
+}
@@ -3,13 +3,17 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
+    super::score::node_key,
+    crate::client::runtime::sleep,
     crate::types::api::core::response::InfoResponse,
     std::{collections::HashSet, time::Duration},
-    tokio::time::sleep,
 };
 
 use super::{Node, NodeManager};
-use crate::client::{Client, ClientInner, Error, Result};
+use crate::{
+    client::{constants::PROTOCOL_PARAMETERS_MAX_AGE_IN_SYNC_INTERVALS, Client, ClientInner, Error, Result},
+    types::block::payload::transaction::{TransactionEssence, TransactionPayload},
+};
 
 impl ClientInner {
     /// Get a node candidate from the healthy node pool.
@@ -23,6 +27,60 @@ impl ClientInner {
         pool.into_iter().next().ok_or(Error::HealthyNodePoolEmpty)
     }
 
+    /// Returns an error if the protocol parameters weren't received from a node for several node sync intervals, so
+    /// they could be outdated after a protocol upgrade. Fixed protocol parameters and offline clients aren't checked.
+    pub async fn check_protocol_parameters(&self) -> Result<()> {
+        let (offline, max_age) = {
+            let node_manager = self.node_manager.read().await;
+            (
+                node_manager.offline,
+                node_manager.node_sync_interval * PROTOCOL_PARAMETERS_MAX_AGE_IN_SYNC_INTERVALS,
+            )
+        };
+        if offline {
+            return Ok(());
+        }
+
+        let network_info = self.get_network_info().await?;
+        if network_info.fixed_protocol_parameters {
+            return Ok(());
+        }
+
+        match network_info.protocol_parameters_updated_at {
+            Some(updated_at)
+                if crate::utils::unix_timestamp_now().as_secs().saturating_sub(updated_at) <= max_age.as_secs() =>
+            {
+                Ok(())
+            }
+            Some(updated_at) => Err(Error::OutdatedProtocolParameters(format!(
+                "last received from a node at {updated_at}, more than {}s ago",
+                max_age.as_secs()
+            ))),
+            None => Err(Error::OutdatedProtocolParameters(
+                "never received from a node".to_owned(),
+            )),
+        }
+    }
+
+    /// Returns an error if the transaction is for another network than the one of the nodes, e.g. because its essence
+    /// was built with a network id copied from another network. The node would only reject it after the PoW.
+    pub async fn check_transaction_network(&self, transaction: &TransactionPayload) -> Result<()> {
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        if essence.network_id() != protocol_parameters.network_id() {
+            return Err(Error::NetworkMismatch {
+                subject: "the network id of the transaction essence",
+                provided: essence.network_id().to_string(),
+                expected: format!(
+                    "{} ({})",
+                    protocol_parameters.network_id(),
+                    protocol_parameters.network_name()
+                ),
+            });
+        }
+        Ok(())
+    }
+
     /// returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     pub async fn unhealthy_nodes(&self) -> HashSet<Node> {
@@ -67,11 +125,23 @@ impl ClientInner {
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        let mut node_routes = HashMap::new();
+
+        let http_client = self.node_manager.read().await.http_client.clone();
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match crate::client::Client::get_node_info_with(&http_client, node.url.as_ref(), node.auth.clone()).await {
                 Ok(info) => {
+                    // Nodes without the routes endpoint are still used for all requests
+                    match crate::client::Client::get_routes_with(&http_client, node.url.as_ref(), node.auth.clone())
+                        .await
+                    {
+                        Ok(routes) => {
+                            node_routes.insert(node_key(&node.url), routes.routes.into_iter().collect::<HashSet<_>>());
+                        }
+                        Err(err) => log::debug!("Couldn't get routes of {}: {err}", node.url),
+                    }
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(info.protocol.network_name()) {
                             Some(network_node_entry) => {
@@ -88,14 +158,24 @@ impl ClientInner {
                 }
                 Err(err) => {
                     log::error!("Couldn't get node info: {err}");
+                    if let Ok(mut node_stats) = self.node_manager.read().await.node_stats.write() {
+                        node_stats.entry(node_key(&node.url)).or_default().record_failure();
+                    }
                 }
             }
         }
 
-        // Get network_id with the most nodes
+        let fixed_network_name = {
+            let network_info = self.network_info.read().await;
+            network_info
+                .fixed_protocol_parameters
+                .then(|| network_info.protocol_parameters.network_name().to_owned())
+        };
+
+        // Get network_id with the most nodes, if the protocol parameters are fixed only nodes of their network are used
         let mut most_nodes = ("network_id", 0);
         for (network_id, node) in &network_nodes {
-            if node.len() > most_nodes.1 {
+            if fixed_network_name.as_ref().map_or(true, |name| name == network_id) && node.len() > most_nodes.1 {
                 most_nodes.0 = network_id;
                 most_nodes.1 = node.len();
             }
@@ -106,22 +186,50 @@ impl ClientInner {
                 let mut network_info = self.network_info.write().await;
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = info.protocol.clone();
+                if let Some(update) =
+                    network_info.update_protocol_parameters(&info.protocol, info.status.latest_milestone.index)
+                {
+                    log::info!(
+                        "Protocol parameters updated at milestone {}, protocol version {} -> {}",
+                        update.milestone_index,
+                        update.previous.protocol_version(),
+                        update.current.protocol_version()
+                    );
+                    // Sending only fails if there are no subscribers
+                    self.protocol_parameters_updates.send(update).ok();
+                }
             }
 
             for (info, node_url) in nodes {
                 healthy_nodes.insert(node_url.clone(), info.clone());
             }
+
+            // Track how far each node is behind the most synced one, so lagging nodes get a lower score
+            let latest_milestone_index = nodes
+                .iter()
+                .map(|(info, _)| info.status.latest_milestone.index)
+                .max()
+                .unwrap_or_default();
+            if let Ok(mut node_stats) = self.node_manager.read().await.node_stats.write() {
+                for (info, node) in nodes {
+                    node_stats
+                        .entry(node_key(&node.url))
+                        .or_default()
+                        .set_milestone_lag(latest_milestone_index - info.status.latest_milestone.index);
+                }
+            }
         }
 
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
+        let node_manager = self.node_manager.read().await;
+        *node_manager
             .healthy_nodes
             .write()
             .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
+        *node_manager
+            .node_routes
+            .write()
+            .map_err(|_| crate::client::Error::PoisonError)? = node_routes;
 
         Ok(())
     }
@@ -132,6 +240,7 @@ impl Client {
     pub async fn update_node_manager(&self, node_manager: NodeManager) -> Result<()> {
         let node_sync_interval = node_manager.node_sync_interval;
         let ignore_node_health = node_manager.ignore_node_health;
+        let offline = node_manager.offline;
         let nodes = node_manager
             .primary_node
             .iter()
@@ -141,10 +250,15 @@ impl Client {
 
         *self.node_manager.write().await = node_manager;
 
+        if offline {
+            *self._sync_handle.write().await = crate::client::SyncHandle(None);
+            return Ok(());
+        }
+
         self.sync_nodes(&nodes, ignore_node_health).await?;
         let client = self.clone();
 
-        let sync_handle = tokio::spawn(async move {
+        let sync_handle = crate::client::runtime::spawn(async move {
             client
                 .start_sync_process(nodes, node_sync_interval, ignore_node_health)
                 .await
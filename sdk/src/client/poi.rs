@@ -0,0 +1,218 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proof of inclusion of a block in the past cone of a milestone.
+//!
+//! Nodes running the PoI plugin create proofs for blocks referenced by a milestone. A proof contains the block, the
+//! milestone and the merkle audit path from the block ID to the inclusion merkle root of the milestone, so it can be
+//! validated offline, without a node or permanode, given the public keys of the milestone issuers.
+//! An output can be proven the same way, through the block of the transaction that created it.
+//! <https://github.com/iotaledger/inx-poi>
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, ClientInner, Error, Result},
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            output::{Output, OutputId, OutputWithMetadata},
+            payload::milestone::{dto::MilestonePayloadDto, MilestoneKeyRange, MilestonePayload},
+            Block, BlockDto, BlockId,
+        },
+        TryFromDto,
+    },
+    utils::serde::prefix_hex_bytes,
+};
+
+/// Domain separation prefix of the hash of a leaf.
+const LEAF_HASH_PREFIX: u8 = 0;
+/// Domain separation prefix of the hash of a node.
+const NODE_HASH_PREFIX: u8 = 1;
+
+/// A merkle audit path, in the JSON format of the PoI plugin.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MerkleProof {
+    /// A node of the tree.
+    Node {
+        /// The left subtree.
+        #[serde(rename = "l")]
+        left: Box<MerkleProof>,
+        /// The right subtree.
+        #[serde(rename = "r")]
+        right: Box<MerkleProof>,
+    },
+    /// The hash of a subtree that isn't on the path.
+    Hash {
+        /// The hash of the subtree.
+        #[serde(rename = "h", with = "prefix_hex_bytes")]
+        hash: [u8; 32],
+    },
+    /// The leaf of the proven block.
+    Value {
+        /// The ID of the proven block.
+        value: BlockId,
+    },
+}
+
+impl MerkleProof {
+    /// Computes the merkle root of the proof.
+    pub fn hash(&self) -> [u8; 32] {
+        match self {
+            Self::Node { left, right } => {
+                let mut hasher = Blake2b256::new();
+                hasher.update([NODE_HASH_PREFIX]);
+                hasher.update(left.hash());
+                hasher.update(right.hash());
+                hasher.finalize().into()
+            }
+            Self::Hash { hash } => *hash,
+            Self::Value { value } => {
+                let mut hasher = Blake2b256::new();
+                hasher.update([LEAF_HASH_PREFIX]);
+                hasher.update(value.as_ref());
+                hasher.finalize().into()
+            }
+        }
+    }
+
+    /// Returns whether the proof contains the leaf of the block.
+    pub fn contains(&self, block_id: &BlockId) -> bool {
+        match self {
+            Self::Node { left, right } => left.contains(block_id) || right.contains(block_id),
+            Self::Hash { .. } => false,
+            Self::Value { value } => value == block_id,
+        }
+    }
+}
+
+/// A proof that a block is included in the past cone of a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProofOfInclusion {
+    /// The milestone that referenced the block.
+    pub milestone: MilestonePayloadDto,
+    /// The proven block.
+    pub block: BlockDto,
+    /// The merkle audit path from the block to the inclusion merkle root of the milestone.
+    pub proof: MerkleProof,
+}
+
+impl ProofOfInclusion {
+    /// Validates the proof offline: the audit path has to contain the block and lead to the inclusion merkle root of
+    /// the milestone, which has to be signed by at least `min_threshold` of the keys applicable at its index.
+    pub fn validate(&self, key_ranges: &[MilestoneKeyRange], min_threshold: usize) -> Result<()> {
+        let milestone = MilestonePayload::try_from_dto(self.milestone.clone())?;
+        let block = Block::try_from_dto(self.block.clone())?;
+
+        if !self.proof.contains(&block.id()) {
+            return Err(Error::InvalidProofOfInclusion(format!(
+                "the proof doesn't contain block {}",
+                block.id()
+            )));
+        }
+        if self.proof.hash() != **milestone.essence().inclusion_merkle_root() {
+            return Err(Error::InvalidProofOfInclusion(
+                "the proof doesn't match the inclusion merkle root of the milestone".to_string(),
+            ));
+        }
+
+        milestone
+            .verify(key_ranges, min_threshold)
+            .map_err(|e| Error::InvalidProofOfInclusion(format!("{e:?}")))
+    }
+
+    /// Returns the ID of the proven block.
+    pub fn block_id(&self) -> Result<BlockId> {
+        Ok(Block::try_from_dto(self.block.clone())?.id())
+    }
+}
+
+/// An output with its metadata and what's needed to verify its existence without trusting the node.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputWithMetadataProof {
+    /// The output and its metadata.
+    pub output: OutputWithMetadataResponse,
+    /// The block of the transaction that created the output.
+    pub block: BlockDto,
+    /// The milestone that booked the output.
+    pub milestone: MilestonePayloadDto,
+    /// The merkle audit path from the block to the inclusion merkle root of the milestone, if a node runs the PoI
+    /// plugin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<MerkleProof>,
+}
+
+impl OutputWithMetadataProof {
+    /// Validates the proof offline: the output has to be created by the block and booked by the milestone, the
+    /// audit path has to lead from the block to the milestone, which has to be signed by at least `min_threshold` of
+    /// the keys applicable at its index.
+    pub fn validate(&self, key_ranges: &[MilestoneKeyRange], min_threshold: usize) -> Result<()> {
+        let proof = self.proof.clone().ok_or_else(|| {
+            Error::InvalidProofOfInclusion("the proof doesn't contain a merkle audit path".to_string())
+        })?;
+
+        ProofOfInclusion {
+            milestone: self.milestone.clone(),
+            block: self.block.clone(),
+            proof,
+        }
+        .validate(key_ranges, min_threshold)?;
+
+        self.verify_output()
+    }
+
+    /// Verifies that the output and its metadata match the block and the milestone. Without the audit path, the
+    /// inclusion of the block in the milestone is trusted to the node.
+    pub fn verify_output(&self) -> Result<()> {
+        let output = OutputWithMetadata::new(Output::try_from_dto(self.output.output.clone())?, self.output.metadata);
+        let block = Block::try_from_dto(self.block.clone())?;
+        let milestone = MilestonePayload::try_from_dto(self.milestone.clone())?;
+
+        output
+            .verify_inclusion(&block, &milestone)
+            .map_err(|e| Error::InvalidProofOfInclusion(e.to_string()))
+    }
+}
+
+impl ClientInner {
+    /// Creates a proof of inclusion for a block referenced by a milestone. Requires the PoI plugin on the node.
+    /// GET /api/poi/v1/create/{blockId}
+    pub async fn create_proof_of_inclusion(&self, block_id: &BlockId) -> Result<ProofOfInclusion> {
+        let path = &format!("api/poi/v1/create/{block_id}");
+
+        self.get_request(path, None, false, false).await
+    }
+
+    /// Gets an output with its metadata, the block that created it, the milestone that booked it and, if a node runs
+    /// the PoI plugin, the merkle audit path from the block to the milestone, to verify the output offline.
+    pub async fn get_output_with_metadata_proof(&self, output_id: &OutputId) -> Result<OutputWithMetadataProof> {
+        let output = self.get_output(output_id).await?;
+        let block_id = output.metadata().block_id();
+
+        match self.create_proof_of_inclusion(block_id).await {
+            Ok(poi) => Ok(OutputWithMetadataProof {
+                output: OutputWithMetadataResponse::from(&output),
+                block: poi.block,
+                milestone: poi.milestone,
+                proof: Some(poi.proof),
+            }),
+            Err(Error::MissingCapability(_)) | Err(Error::Node(NodeApiError::NotFound(_))) => {
+                let block = self.get_block(block_id).await?;
+                let milestone = self
+                    .get_milestone_by_index(output.metadata().milestone_index_booked())
+                    .await?;
+
+                Ok(OutputWithMetadataProof {
+                    output: OutputWithMetadataResponse::from(&output),
+                    block: BlockDto::from(&block),
+                    milestone: MilestonePayloadDto::from(&milestone),
+                    proof: None,
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
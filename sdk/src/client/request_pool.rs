@@ -2,17 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloc::sync::Arc;
+use std::{collections::HashMap, sync::Mutex};
 
 use async_trait::async_trait;
 use futures::Future;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 use tokio::sync::{
+    broadcast,
     mpsc::{UnboundedReceiver, UnboundedSender},
     RwLock,
 };
 
+use crate::client::Result;
+
 #[derive(Debug, Clone)]
 pub(crate) struct RequestPool {
     inner: Arc<RwLock<RequestPoolInner>>,
+    // the senders of the results of the requests in flight, by request key
+    in_flight: Arc<Mutex<HashMap<String, broadcast::Sender<Value>>>>,
 }
 
 #[derive(Debug)]
@@ -31,6 +39,7 @@ impl RequestPool {
     pub(crate) fn new(size: usize) -> Self {
         Self {
             inner: Arc::new(RwLock::new(RequestPoolInner::new(size))),
+            in_flight: Default::default(),
         }
     }
 
@@ -50,6 +59,74 @@ impl RequestPool {
     pub(crate) async fn resize(&self, new_size: usize) {
         *self.write().await = RequestPoolInner::new(new_size);
     }
+
+    /// Sends the request only if no request with the same key is in flight, otherwise waits for the result of that one.
+    /// Failed requests aren't shared, if the request in flight fails or is cancelled the waiting callers send theirs
+    /// themselves, so every caller gets its own error.
+    pub(crate) async fn coalesce<T, F>(&self, key: String, request: impl FnOnce() -> F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Future<Output = Result<T>>,
+    {
+        let receiver = {
+            let mut in_flight = self.in_flight.lock().expect("in-flight requests lock poisoned");
+            match in_flight.get(&key) {
+                Some(sender) => Some(sender.subscribe()),
+                None => {
+                    in_flight.insert(key.clone(), broadcast::channel(1).0);
+                    None
+                }
+            }
+        };
+
+        if let Some(mut receiver) = receiver {
+            if let Ok(value) = receiver.recv().await {
+                return Ok(serde_json::from_value(value)?);
+            }
+            return request().await;
+        }
+
+        let mut in_flight = InFlightRequest {
+            pool: self,
+            key: Some(key),
+        };
+        let result = request().await;
+        if let (Ok(value), Some(sender)) = (&result, in_flight.remove()) {
+            // Serialize only if someone is waiting, if it fails they send their requests themselves
+            if sender.receiver_count() > 0 {
+                if let Ok(value) = serde_json::to_value(value) {
+                    sender.send(value).ok();
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Removes the request from the requests in flight when it's done or cancelled.
+struct InFlightRequest<'a> {
+    pool: &'a RequestPool,
+    // `None` once removed, so a later request with the same key isn't removed
+    key: Option<String>,
+}
+
+impl InFlightRequest<'_> {
+    /// Removes the request and returns the sender of its result.
+    fn remove(&mut self) -> Option<broadcast::Sender<Value>> {
+        let key = self.key.take()?;
+        self.pool
+            .in_flight
+            .lock()
+            .expect("in-flight requests lock poisoned")
+            .remove(&key)
+    }
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        // Dropping the sender lets the waiting callers send their requests themselves
+        self.remove();
+    }
 }
 
 impl core::ops::Deref for RequestPool {
@@ -91,3 +168,54 @@ pub(crate) trait RateLimitExt: Future {
     }
 }
 impl<F: Future> RateLimitExt for F {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::client::Error;
+
+    #[tokio::test]
+    async fn coalesce_concurrent_requests() {
+        let pool = RequestPool::new(10);
+        let sent = AtomicUsize::new(0);
+        let request = || async {
+            sent.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(42u32)
+        };
+
+        let results = futures::future::join_all((0..5).map(|_| pool.coalesce("key".to_string(), request))).await;
+        assert!(results.into_iter().all(|result| result.unwrap() == 42));
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+        // Finished requests aren't cached
+        pool.coalesce("key".to_string(), request).await.unwrap();
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+        assert!(pool.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_request_is_not_shared() {
+        let pool = RequestPool::new(10);
+        let sent = AtomicUsize::new(0);
+        let request = || async {
+            // Only the first request fails
+            let count = sent.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if count == 0 { Err(Error::Offline) } else { Ok(count) }
+        };
+
+        let (first, second) = futures::future::join(
+            pool.coalesce("key".to_string(), request),
+            pool.coalesce("key".to_string(), request),
+        )
+        .await;
+        assert!(matches!(first, Err(Error::Offline)));
+        assert_eq!(second.unwrap(), 1);
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! How failed node requests are retried.
+//!
+//! A [`RetryPolicy`] is set for all requests of a client with [`ClientBuilder::with_retry_policy()`] and can be
+//! overridden for the requests made by a single call with [`RetryPolicy::scope()`]. Every attempt tries all nodes that
+//! can answer the request, so a retry only happens after all of them failed.
+//!
+//! [`ClientBuilder::with_retry_policy()`]: crate::client::ClientBuilder::with_retry_policy
+
+use core::future::Future;
+use std::{collections::HashSet, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{node_api::error::Error as NodeApiError, Error, ErrorCategory, Result};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 1;
+const DEFAULT_INITIAL_DELAY: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(10);
+const UNTIL_INCLUDED_MAX_ATTEMPTS: u32 = 40;
+const UNTIL_INCLUDED_INTERVAL: Duration = Duration::from_secs(1);
+
+tokio::task_local! {
+    static SCOPED_RETRY_POLICY: RetryPolicy;
+}
+
+/// How the delay between two attempts grows.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Backoff {
+    /// Every retry waits the initial delay.
+    Constant,
+    /// Every retry waits the initial delay longer than the previous one.
+    Linear,
+    /// Every retry waits twice as long as the previous one.
+    Exponential,
+}
+
+/// Decides if and when a failed operation is attempted again.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one. 1 disables retrying.
+    pub max_attempts: u32,
+    /// The delay before the first retry.
+    pub initial_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// How the delay grows with every retry.
+    pub backoff: Backoff,
+    /// The categories of errors that are retried. Answers of a node that won't change when asking again, like a
+    /// missing block, are never retried.
+    pub retryable: HashSet<ErrorCategory>,
+    /// The time after the first attempt after which no further attempt is started.
+    pub deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_delay: DEFAULT_INITIAL_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            backoff: Backoff::Exponential,
+            retryable: HashSet::from([ErrorCategory::Network]),
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that doesn't retry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The policy used to check the inclusion of blocks by `retry_until_included()` if no interval or maximum number
+    /// of attempts is provided.
+    pub fn until_included() -> Self {
+        Self::default()
+            .with_max_attempts(UNTIL_INCLUDED_MAX_ATTEMPTS)
+            .with_initial_delay(UNTIL_INCLUDED_INTERVAL)
+            .with_backoff(Backoff::Constant)
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets how the delay grows with every retry.
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the categories of errors that are retried.
+    pub fn with_retryable(mut self, retryable: impl IntoIterator<Item = ErrorCategory>) -> Self {
+        self.retryable = retryable.into_iter().collect();
+        self
+    }
+
+    /// Sets the time after the first attempt after which no further attempt is started.
+    pub fn with_deadline(mut self, deadline: impl Into<Option<Duration>>) -> Self {
+        self.deadline = deadline.into();
+        self
+    }
+
+    /// Returns the delay before the retry with the given number, starting at 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = match self.backoff {
+            Backoff::Constant => self.initial_delay,
+            Backoff::Linear => self.initial_delay.saturating_mul(retry),
+            Backoff::Exponential => self
+                .initial_delay
+                .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1))),
+        };
+        delay.min(self.max_delay)
+    }
+
+    /// Returns whether the operation should be attempted again after the error.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        let may_change = match error {
+            Error::Node(
+                NodeApiError::NotFound(_)
+                | NodeApiError::NotSupported(_)
+                | NodeApiError::UnavailablePow
+                | NodeApiError::Json(_),
+            ) => false,
+            // Server errors, timeouts and rate limiting
+            Error::Node(NodeApiError::ResponseError { code, .. }) => *code >= 500 || *code == 408 || *code == 429,
+            _ => true,
+        };
+
+        may_change && self.retryable.contains(&error.category())
+    }
+
+    /// Runs the future with this policy instead of the one of the client for all node requests it makes itself.
+    /// Requests of tasks spawned by the future use the policy of the client.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        SCOPED_RETRY_POLICY.scope(self, future).await
+    }
+
+    /// Returns the policy set with [`RetryPolicy::scope()`] for the current call, if any.
+    pub(crate) fn scoped() -> Option<Self> {
+        SCOPED_RETRY_POLICY.try_with(Clone::clone).ok()
+    }
+
+    /// Runs the operation until it succeeds, fails with an error that isn't retried or the attempts or the deadline
+    /// are exhausted.
+    pub(crate) async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let start_time = instant::Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            match operation().await {
+                Err(err) if attempt < self.max_attempts && self.is_retryable(&err) => {
+                    let delay = self.delay(attempt);
+                    if let Some(deadline) = self.deadline {
+                        if start_time.elapsed() + delay > deadline {
+                            return Err(err);
+                        }
+                    }
+                    log::debug!("[RetryPolicy] attempt {attempt} failed, retrying in {delay:?}: {err}");
+
+                    #[cfg(target_family = "wasm")]
+                    gloo_timers::future::TimeoutFuture::new(delay.as_millis() as u32).await;
+                    #[cfg(not(target_family = "wasm"))]
+                    crate::client::runtime::sleep(delay).await;
+
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay() {
+        let policy = RetryPolicy::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_max_delay(Duration::from_secs(5));
+
+        let exponential = policy.clone().with_backoff(Backoff::Exponential);
+        assert_eq!(exponential.delay(1), Duration::from_secs(1));
+        assert_eq!(exponential.delay(3), Duration::from_secs(4));
+        assert_eq!(exponential.delay(4), Duration::from_secs(5));
+
+        let linear = policy.clone().with_backoff(Backoff::Linear);
+        assert_eq!(linear.delay(3), Duration::from_secs(3));
+
+        let constant = policy.with_backoff(Backoff::Constant);
+        assert_eq!(constant.delay(10), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn is_retryable() {
+        let policy = RetryPolicy::new();
+        let response_error = |code| {
+            Error::Node(NodeApiError::ResponseError {
+                code,
+                text: String::new(),
+                url: String::new(),
+            })
+        };
+
+        assert!(policy.is_retryable(&response_error(503)));
+        assert!(policy.is_retryable(&response_error(429)));
+        assert!(!policy.is_retryable(&response_error(400)));
+        assert!(!policy.is_retryable(&Error::Node(NodeApiError::NotFound(String::new()))));
+        assert!(policy.is_retryable(&Error::HealthyNodePoolEmpty));
+        assert!(
+            !policy
+                .with_retryable([ErrorCategory::Protocol])
+                .is_retryable(&Error::HealthyNodePoolEmpty)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The async runtime background tasks are spawned on and timers are run by.
+//!
+//! By default tasks are spawned on the Tokio runtime of the caller, or on a runtime owned by the SDK if there is none.
+//! Another [`Executor`], e.g. one of async-std or a single-threaded executor on an embedded device, can be installed
+//! with [`set_executor()`] before the first client is built. The Tokio synchronization primitives used internally
+//! don't depend on the Tokio runtime.
+//!
+//! Node requests are sent with `reqwest`, which needs a Tokio reactor, so executors without one need to poll these
+//! futures in a Tokio context, e.g. with `async-compat`. Background syncing, claiming and retrying of a wallet and the
+//! MQTT event loop run on their own threads with their own runtime.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use std::{panic::AssertUnwindSafe, sync::OnceLock};
+
+use futures::{
+    future::{AbortHandle, Abortable, BoxFuture},
+    FutureExt,
+};
+use tokio::sync::oneshot;
+
+static EXECUTOR: OnceLock<Box<dyn Executor>> = OnceLock::new();
+
+/// Spawns tasks and creates timers.
+pub trait Executor: Send + Sync + 'static {
+    /// Runs the future to completion in the background.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Returns a future that completes after the duration.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// Runs tasks and timers on the current Tokio runtime, or on a runtime of the SDK outside of one.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioExecutor;
+
+impl TokioExecutor {
+    fn fallback_runtime() -> &'static tokio::runtime::Runtime {
+        static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+        RUNTIME.get_or_init(|| tokio::runtime::Runtime::new().expect("failed to create Tokio runtime"))
+    }
+}
+
+impl Executor for TokioExecutor {
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle.spawn(future),
+            Err(_) => Self::fallback_runtime().spawn(future),
+        };
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        // The timer needs a Tokio context when it's created, so only when the future is polled it's known where to
+        // create it
+        Box::pin(async move {
+            if tokio::runtime::Handle::try_current().is_ok() {
+                tokio::time::sleep(duration).await;
+            } else {
+                Self::fallback_runtime()
+                    .spawn(async move { tokio::time::sleep(duration).await })
+                    .await
+                    .ok();
+            }
+        })
+    }
+}
+
+/// Installs the executor tasks are spawned on and timers are run by, instead of the [`TokioExecutor`]. Can only be
+/// called once and must be called before anything has been spawned, otherwise the executor is returned as error.
+pub fn set_executor(executor: impl Executor) -> Result<(), Box<dyn Executor>> {
+    EXECUTOR.set(Box::new(executor))
+}
+
+fn executor() -> &'static dyn Executor {
+    EXECUTOR.get_or_init(|| Box::new(TokioExecutor)).as_ref()
+}
+
+/// The error of a task that didn't complete.
+#[derive(Debug, thiserror::Error)]
+pub enum JoinError {
+    /// The task was aborted
+    #[error("task was aborted")]
+    Aborted,
+    /// The task panicked
+    #[error("task panicked")]
+    Panicked,
+}
+
+/// A handle to await the output of a spawned task or to abort it. Dropping it detaches the task.
+#[derive(Debug)]
+pub struct JoinHandle<T> {
+    receiver: oneshot::Receiver<std::thread::Result<T>>,
+    abort_handle: AbortHandle,
+}
+
+impl<T> JoinHandle<T> {
+    /// Aborts the task, it stops the next time it would be polled.
+    pub fn abort(&self) {
+        self.abort_handle.abort();
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx).map(|output| match output {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(_)) => Err(JoinError::Panicked),
+            // The sender is dropped without sending if the task was aborted
+            Err(_) => Err(JoinError::Aborted),
+        })
+    }
+}
+
+/// Spawns a task on the installed executor.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    let (abort_handle, abort_registration) = AbortHandle::new_pair();
+
+    executor().spawn(Box::pin(
+        Abortable::new(AssertUnwindSafe(future).catch_unwind(), abort_registration).map(move |output| {
+            if let Ok(output) = output {
+                // The handle may have been dropped
+                sender.send(output).ok();
+            }
+        }),
+    ));
+
+    JoinHandle { receiver, abort_handle }
+}
+
+/// Runs a blocking function, e.g. reading a file, on its own thread, so it doesn't block the executor. Aborting the
+/// handle doesn't stop the function.
+pub fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    let (abort_handle, _) = AbortHandle::new_pair();
+
+    std::thread::spawn(move || {
+        // The handle may have been dropped
+        sender.send(std::panic::catch_unwind(AssertUnwindSafe(f))).ok();
+    });
+
+    JoinHandle { receiver, abort_handle }
+}
+
+/// Waits for the duration with the timer of the installed executor.
+pub async fn sleep(duration: Duration) {
+    executor().sleep(duration).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn spawn_abort() {
+        assert_eq!(spawn(async { 1 }).await.unwrap(), 1);
+
+        let handle = spawn(async {
+            sleep(Duration::from_secs(60)).await;
+        });
+        handle.abort();
+        assert!(matches!(handle.await, Err(JoinError::Aborted)));
+
+        assert!(matches!(
+            spawn(async { panic!("task panic") }).await,
+            Err(JoinError::Panicked)
+        ));
+    }
+
+    #[tokio::test]
+    async fn spawn_blocking_result() {
+        assert_eq!(spawn_blocking(|| 1).await.unwrap(), 1);
+        assert!(matches!(
+            spawn_blocking(|| panic!("blocking panic")).await,
+            Err(JoinError::Panicked)
+        ));
+    }
+
+    #[test]
+    fn outside_of_runtime() {
+        let handle = spawn(async {
+            sleep(Duration::from_millis(1)).await;
+            2
+        });
+        assert_eq!(futures::executor::block_on(handle).unwrap(), 2);
+    }
+}
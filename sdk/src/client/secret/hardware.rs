@@ -0,0 +1,375 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Abstraction over hardware signing devices.
+//!
+//! Devices implement the [`HardwareSigner`] trait and get wrapped in a [`HardwareSecretManager`], which plugs them into
+//! the regular signing pipeline. The [`SimulatorSigner`] is a software implementation that can be used to test
+//! hardware signing flows without a device.
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    signatures::{
+        ed25519,
+        secp256k1_ecdsa::{self, EvmAddress},
+    },
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use super::{GenerateAddressOptions, SecretManage};
+use crate::{
+    client::{api::PreparedTransactionData, Client},
+    types::block::{
+        address::Ed25519Address,
+        payload::transaction::TransactionPayload,
+        signature::{Ed25519Signature, Signature},
+        unlock::{InputUnlock, SignatureUnlock, Unlock, Unlocks},
+    },
+};
+
+/// Hardware signer errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Device not connected
+    #[error("hardware device not connected")]
+    DeviceNotConnected,
+    /// Device locked
+    #[error("hardware device locked")]
+    DeviceLocked,
+    /// Denied by user
+    #[error("denied by user")]
+    DeniedByUser,
+    /// Blind signing is required, but not enabled on the device
+    #[error("blind signing required, but not enabled on the device")]
+    BlindSigningDisabled,
+    /// Unsupported operation
+    #[error("unsupported operation")]
+    UnsupportedOperation,
+    /// Device specific error
+    #[error("hardware device error: {0}")]
+    Device(String),
+}
+
+/// The status of a hardware signing device.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardwareDeviceStatus {
+    /// The device is available and ready to be used.
+    pub connected: bool,
+    /// The device is connected, but locked.
+    pub locked: bool,
+    /// Blind signing is enabled on the device.
+    pub blind_signing_enabled: bool,
+    /// The model of the device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The firmware or app version of the device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// The interface a hardware signing device has to provide to be used as secret manager.
+///
+/// A device either shows the transaction to the user (clear signing) or only its essence hash (blind signing), which
+/// requires blind signing to be enabled on the device. Confirming and signing is a single device operation, so a
+/// device only signs what the user approved, there is no way to sign without a confirmation.
+#[async_trait]
+pub trait HardwareSigner: Send + Sync {
+    /// Queries the status of the device.
+    async fn device_status(&self) -> Result<HardwareDeviceStatus, Error>;
+
+    /// Returns the Ed25519 public keys for the given chains, derived on the device.
+    async fn ed25519_public_keys(&self, chains: &[Bip44]) -> Result<Vec<ed25519::PublicKey>, Error>;
+
+    /// Returns whether the device is able to show the given transaction to the user.
+    fn supports_clear_signing(&self, _prepared_transaction_data: &PreparedTransactionData) -> bool {
+        false
+    }
+
+    /// Shows the transaction on the device, waits until the user approved it and signs its essence hash with the keys
+    /// of the given chains. Returns a signature per chain, in the same order.
+    async fn sign_transaction(
+        &self,
+        _prepared_transaction_data: &PreparedTransactionData,
+        _chains: &[Bip44],
+    ) -> Result<Vec<Ed25519Signature>, Error> {
+        Err(Error::UnsupportedOperation)
+    }
+
+    /// Shows the essence hash on the device, waits until the user approved it and signs it with the keys of the given
+    /// chains. Returns a signature per chain, in the same order.
+    async fn sign_blind(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Ed25519Signature>, Error>;
+}
+
+/// Secret manager that delegates address generation and signing to a [`HardwareSigner`].
+#[derive(Debug)]
+pub struct HardwareSecretManager<H: HardwareSigner> {
+    signer: H,
+}
+
+impl<H: HardwareSigner> HardwareSecretManager<H> {
+    /// Creates a new [`HardwareSecretManager`] for the given device.
+    pub fn new(signer: H) -> Self {
+        Self { signer }
+    }
+
+    /// Returns the underlying device.
+    pub fn signer(&self) -> &H {
+        &self.signer
+    }
+
+    /// Queries the status of the device.
+    pub async fn device_status(&self) -> Result<HardwareDeviceStatus, Error> {
+        self.signer.device_status().await
+    }
+
+    /// Returns an error if the device can't be used right now.
+    async fn ready_status(&self) -> Result<HardwareDeviceStatus, Error> {
+        let status = self.signer.device_status().await?;
+
+        if !status.connected {
+            return Err(Error::DeviceNotConnected);
+        }
+        if status.locked {
+            return Err(Error::DeviceLocked);
+        }
+
+        Ok(status)
+    }
+}
+
+#[async_trait]
+impl<H: HardwareSigner> SecretManage for HardwareSecretManager<H> {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        let internal = options.into().map(|o| o.internal).unwrap_or_default();
+
+        self.ready_status().await?;
+
+        let chains = address_indexes
+            .map(|address_index| {
+                Bip44::new(coin_type)
+                    .with_account(account_index)
+                    .with_change(internal as _)
+                    .with_address_index(address_index)
+            })
+            .collect::<Vec<_>>();
+
+        self.signer
+            .ed25519_public_keys(&chains)
+            .await?
+            .into_iter()
+            .map(|public_key| {
+                // Hash the public key to get the address
+                let result = Blake2b256::digest(public_key.to_bytes()).try_into().map_err(|_e| {
+                    crate::client::Error::Blake2b256("hashing the public key while generating the address failed.")
+                })?;
+
+                Ok(Ed25519Address::new(result))
+            })
+            .collect()
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    /// Always fails, devices only sign transactions the user confirmed on the device, not arbitrary messages.
+    async fn sign_ed25519(&self, _msg: &[u8], _chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        let status = self.ready_status().await?;
+
+        let input_unlocks = super::plan_transaction_unlocks(prepared_transaction_data, time)?;
+        let chains = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .zip(&input_unlocks)
+            .filter(|(_, input_unlock)| matches!(input_unlock, InputUnlock::Signature(_)))
+            .map(|(input, _)| input.chain.ok_or(crate::client::Error::MissingBip32Chain))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let signatures = if self.signer.supports_clear_signing(prepared_transaction_data) {
+            self.signer.sign_transaction(prepared_transaction_data, &chains).await?
+        } else {
+            if !status.blind_signing_enabled {
+                return Err(Error::BlindSigningDisabled.into());
+            }
+            self.signer
+                .sign_blind(&prepared_transaction_data.essence.hash(), &chains)
+                .await?
+        };
+        if signatures.len() != chains.len() {
+            return Err(Error::Device(format!(
+                "expected {} signatures, got {}",
+                chains.len(),
+                signatures.len()
+            ))
+            .into());
+        }
+
+        let mut signatures = signatures.into_iter();
+        let unlocks = input_unlocks
+            .into_iter()
+            .map(|input_unlock| match input_unlock {
+                InputUnlock::Signature(_) => Unlock::Signature(SignatureUnlock::new(Signature::from(
+                    // The number of signatures was checked above
+                    signatures.next().unwrap(),
+                ))),
+                InputUnlock::Unlock(unlock) => unlock,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Unlocks::new(unlocks)?)
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+/// How the [`SimulatorSigner`] answers confirmation requests.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SimulatedUserResponse {
+    /// Approve every request.
+    #[default]
+    Approve,
+    /// Deny every request.
+    Deny,
+}
+
+/// A software implementation of a [`HardwareSigner`] backed by a seed, meant for testing.
+pub struct SimulatorSigner {
+    seed: Seed,
+    status: RwLock<HardwareDeviceStatus>,
+    clear_signing: bool,
+    user_response: RwLock<SimulatedUserResponse>,
+}
+
+impl std::fmt::Debug for SimulatorSigner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SimulatorSigner")
+            .field("clear_signing", &self.clear_signing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SimulatorSigner {
+    /// Creates a connected and unlocked simulator from a mnemonic. With `clear_signing` the simulator shows
+    /// transactions to the user, otherwise it only supports blind signing.
+    pub fn try_from_mnemonic(mnemonic: impl Into<Mnemonic>, clear_signing: bool) -> crate::client::Result<Self> {
+        Ok(Self {
+            seed: Client::mnemonic_to_seed(mnemonic.into())?.into(),
+            status: RwLock::new(HardwareDeviceStatus {
+                connected: true,
+                locked: false,
+                blind_signing_enabled: true,
+                model: Some("Simulator".to_string()),
+                version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            }),
+            clear_signing,
+            user_response: RwLock::new(SimulatedUserResponse::Approve),
+        })
+    }
+
+    /// Replaces the status of the simulated device.
+    pub async fn set_status(&self, status: HardwareDeviceStatus) {
+        *self.status.write().await = status;
+    }
+
+    /// Sets how the simulated user answers confirmation requests.
+    pub async fn set_user_response(&self, user_response: SimulatedUserResponse) {
+        *self.user_response.write().await = user_response;
+    }
+
+    async fn confirm(&self) -> Result<(), Error> {
+        match *self.user_response.read().await {
+            SimulatedUserResponse::Approve => Ok(()),
+            SimulatedUserResponse::Deny => Err(Error::DeniedByUser),
+        }
+    }
+
+    fn sign(&self, msg: &[u8], chains: &[Bip44]) -> Vec<Ed25519Signature> {
+        chains
+            .iter()
+            .map(|chain| {
+                let private_key = self.secret_key(*chain);
+                Ed25519Signature::new(private_key.public_key(), private_key.sign(msg))
+            })
+            .collect()
+    }
+
+    fn secret_key(&self, chain: Bip44) -> ed25519::SecretKey {
+        chain
+            .derive(&self.seed.to_master_key::<ed25519::SecretKey>())
+            .secret_key()
+    }
+}
+
+#[async_trait]
+impl HardwareSigner for SimulatorSigner {
+    async fn device_status(&self) -> Result<HardwareDeviceStatus, Error> {
+        Ok(self.status.read().await.clone())
+    }
+
+    async fn ed25519_public_keys(&self, chains: &[Bip44]) -> Result<Vec<ed25519::PublicKey>, Error> {
+        Ok(chains
+            .iter()
+            .map(|chain| self.secret_key(*chain).public_key())
+            .collect())
+    }
+
+    fn supports_clear_signing(&self, _prepared_transaction_data: &PreparedTransactionData) -> bool {
+        self.clear_signing
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        chains: &[Bip44],
+    ) -> Result<Vec<Ed25519Signature>, Error> {
+        self.confirm().await?;
+        Ok(self.sign(&prepared_transaction_data.essence.hash(), chains))
+    }
+
+    async fn sign_blind(&self, essence_hash: &[u8; 32], chains: &[Bip44]) -> Result<Vec<Ed25519Signature>, Error> {
+        self.confirm().await?;
+        Ok(self.sign(essence_hash, chains))
+    }
+}
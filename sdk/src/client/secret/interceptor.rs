@@ -0,0 +1,351 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks that are called before anything gets signed.
+//!
+//! A [`SigningInterceptor`] receives the decoded transaction of every transaction signing request and every message
+//! that is signed directly, and can approve or reject them, for example to wait for a second factor or an approval
+//! workflow. Interceptors are added to a secret manager by wrapping it in an [`InterceptedSecretManager`].
+
+use std::{ops::Range, sync::Arc};
+
+use async_trait::async_trait;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+
+use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
+use crate::{
+    client::api::{input_selection::is_alias_transition, PreparedTransactionData},
+    types::block::{
+        address::{Address, Ed25519Address},
+        output::{NativeTokens, Output, OutputId},
+        payload::{transaction::TransactionEssence, TransactionPayload},
+        signature::Ed25519Signature,
+        unlock::Unlocks,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// An input of a [`TransactionSigningRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionSigningInput {
+    /// The id of the consumed output.
+    pub output_id: OutputId,
+    /// The address that needs to sign to unlock the input.
+    pub address: Address,
+    /// The amount of the consumed output.
+    pub amount: u64,
+    /// The native tokens of the consumed output.
+    pub native_tokens: Option<NativeTokens>,
+    /// The chain of the key that signs for the input, if it's unlocked by a signature.
+    pub chain: Option<Bip44>,
+}
+
+/// An output of a [`TransactionSigningRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionSigningOutput {
+    /// The address that controls the output, for alias outputs the state controller.
+    pub address: Option<Address>,
+    /// The amount of the output.
+    pub amount: u64,
+    /// The native tokens of the output.
+    pub native_tokens: Option<NativeTokens>,
+    /// Whether the output is the remainder of the transaction.
+    pub remainder: bool,
+}
+
+/// A decoded transaction that is about to be signed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionSigningRequest {
+    /// The hash of the transaction essence, which gets signed.
+    pub essence_hash: [u8; 32],
+    /// The network id of the transaction.
+    pub network_id: u64,
+    /// The time the addresses of the inputs were resolved at, the transaction is signed with the same time.
+    pub time: u32,
+    /// The inputs of the transaction.
+    pub inputs: Vec<TransactionSigningInput>,
+    /// The outputs of the transaction.
+    pub outputs: Vec<TransactionSigningOutput>,
+}
+
+impl TransactionSigningRequest {
+    /// Decodes the prepared transaction data, input addresses are resolved at the given time.
+    pub fn try_from_prepared_transaction_data(
+        prepared_transaction_data: &PreparedTransactionData,
+        time: u32,
+    ) -> crate::client::Result<Self> {
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let remainder = prepared_transaction_data
+            .remainder
+            .as_ref()
+            .map(|remainder| &remainder.output);
+
+        let inputs = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| {
+                let alias_transition = is_alias_transition(&input.output, *input.output_id(), essence.outputs(), None);
+                let (address, _) =
+                    input
+                        .output
+                        .required_and_unlocked_address(time, input.output_id(), alias_transition)?;
+
+                Ok(TransactionSigningInput {
+                    output_id: *input.output_id(),
+                    address,
+                    amount: input.output.amount(),
+                    native_tokens: input.output.native_tokens().cloned(),
+                    chain: input.chain,
+                })
+            })
+            .collect::<crate::client::Result<Vec<_>>>()?;
+
+        let outputs = essence
+            .outputs()
+            .iter()
+            .map(|output| TransactionSigningOutput {
+                address: controlling_address(output),
+                amount: output.amount(),
+                native_tokens: output.native_tokens().cloned(),
+                remainder: remainder == Some(output),
+            })
+            .collect();
+
+        Ok(Self {
+            essence_hash: prepared_transaction_data.essence.hash(),
+            network_id: essence.network_id(),
+            time,
+            inputs,
+            outputs,
+        })
+    }
+
+    /// Returns the sum of the amounts of the inputs.
+    pub fn input_amount(&self) -> u64 {
+        self.inputs.iter().map(|input| input.amount).sum()
+    }
+
+    /// Returns the sum of the amounts of the outputs that aren't a remainder.
+    pub fn sent_amount(&self) -> u64 {
+        self.outputs
+            .iter()
+            .filter(|output| !output.remainder)
+            .map(|output| output.amount)
+            .sum()
+    }
+}
+
+fn controlling_address(output: &Output) -> Option<Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    unlock_conditions
+        .address()
+        .map(|uc| *uc.address())
+        .or_else(|| unlock_conditions.state_controller_address().map(|uc| *uc.address()))
+        .or_else(|| {
+            unlock_conditions
+                .immutable_alias_address()
+                .map(|uc| Address::from(*uc.address()))
+        })
+}
+
+/// The signature scheme of a [`MessageSigningRequest`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageSignatureScheme {
+    /// Signed with [`SecretManage::sign_ed25519()`].
+    Ed25519,
+    /// Signed with [`SecretManage::sign_secp256k1_ecdsa()`].
+    Secp256k1Ecdsa,
+}
+
+/// A message that is about to be signed directly, e.g. for a sign-in or an ownership proof.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MessageSigningRequest {
+    /// The message that gets signed.
+    pub message: Vec<u8>,
+    /// The chain of the signing key.
+    pub chain: Bip44,
+    /// The signature scheme.
+    pub scheme: MessageSignatureScheme,
+}
+
+/// Hook that is called before anything gets signed.
+#[async_trait]
+pub trait SigningInterceptor: Send + Sync {
+    /// Approves the transaction by returning `Ok(())` or rejects it with [`Error::SigningRejected`].
+    ///
+    /// [`Error::SigningRejected`]: crate::client::Error::SigningRejected
+    async fn intercept_transaction(&self, request: &TransactionSigningRequest) -> crate::client::Result<()>;
+
+    /// Approves the message by returning `Ok(())` or rejects it with [`Error::SigningRejected`]. A message can be the
+    /// hash of a transaction essence too, so interceptors that restrict transactions should reject messages they don't
+    /// expect.
+    ///
+    /// [`Error::SigningRejected`]: crate::client::Error::SigningRejected
+    async fn intercept_message(&self, request: &MessageSigningRequest) -> crate::client::Result<()>;
+}
+
+/// Secret manager that calls interceptors before delegating transaction signing to the inner secret manager.
+pub struct InterceptedSecretManager<S: SecretManage> {
+    inner: S,
+    interceptors: Vec<Arc<dyn SigningInterceptor>>,
+}
+
+impl<S: SecretManage + std::fmt::Debug> std::fmt::Debug for InterceptedSecretManager<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InterceptedSecretManager")
+            .field("inner", &self.inner)
+            .field("interceptors", &self.interceptors.len())
+            .finish()
+    }
+}
+
+impl<S: SecretManage> InterceptedSecretManager<S> {
+    /// Wraps a secret manager without interceptors.
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// Adds an interceptor, interceptors are called in the order they were added.
+    pub fn with_interceptor(mut self, interceptor: impl SigningInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Returns the wrapped secret manager.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwraps the secret manager.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    async fn intercept_message(
+        &self,
+        message: &[u8],
+        chain: Bip44,
+        scheme: MessageSignatureScheme,
+    ) -> crate::client::Result<()> {
+        if self.interceptors.is_empty() {
+            return Ok(());
+        }
+
+        let request = MessageSigningRequest {
+            message: message.to_vec(),
+            chain,
+            scheme,
+        };
+        for interceptor in &self.interceptors {
+            interceptor.intercept_message(&request).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: SecretManage> SecretManage for InterceptedSecretManager<S>
+where
+    crate::client::Error: From<S::Error>,
+{
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        Ok(self
+            .inner
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+            .await?)
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Ok(self
+            .inner
+            .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+            .await?)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        self.intercept_message(msg, chain, MessageSignatureScheme::Ed25519)
+            .await?;
+
+        Ok(self.inner.sign_ed25519(msg, chain).await?)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        self.intercept_message(msg, chain, MessageSignatureScheme::Secp256k1Ecdsa)
+            .await?;
+
+        Ok(self.inner.sign_secp256k1_ecdsa(msg, chain).await?)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        if self.interceptors.is_empty() {
+            return Ok(self
+                .inner
+                .sign_transaction_essence(prepared_transaction_data, time)
+                .await?);
+        }
+
+        // The inputs are signed with the time they were resolved at for the interceptors
+        let time = time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
+        let request = TransactionSigningRequest::try_from_prepared_transaction_data(prepared_transaction_data, time)?;
+        for interceptor in &self.interceptors {
+            interceptor.intercept_transaction(&request).await?;
+        }
+
+        Ok(self
+            .inner
+            .sign_transaction_essence(prepared_transaction_data, Some(time))
+            .await?)
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+impl<S: SecretManagerConfig> SecretManagerConfig for InterceptedSecretManager<S>
+where
+    crate::client::Error: From<S::Error>,
+{
+    type Config = S::Config;
+
+    fn to_config(&self) -> Option<Self::Config> {
+        self.inner.to_config()
+    }
+
+    /// Interceptors aren't part of the config and need to be added again.
+    fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
+        Ok(Self::new(S::from_config(config)?))
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Secrets that are zeroized when they are dropped or locked, and kept in locked memory while they are unlocked.
+
+use std::{ops::Deref, sync::Arc};
+#[cfg(not(target_family = "wasm"))]
+use std::{sync::Weak, time::Duration};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+#[cfg(not(target_family = "wasm"))]
+use tokio::sync::Mutex;
+use tokio::sync::RwLock;
+use zeroize::Zeroize;
+
+use crate::client::Error;
+
+/// Secret bytes which are zeroized when they are dropped. On Unix they are also locked in memory, so they aren't
+/// written to swap.
+pub(crate) struct LockedBytes {
+    #[cfg(unix)]
+    bytes: PageBuffer,
+    #[cfg(not(unix))]
+    bytes: Box<[u8]>,
+}
+
+impl LockedBytes {
+    /// Copies the bytes into a new buffer, the caller is responsible for zeroizing the original.
+    pub(crate) fn new(bytes: &[u8]) -> Self {
+        #[cfg(unix)]
+        let bytes = PageBuffer::new(bytes);
+        #[cfg(not(unix))]
+        let bytes = Box::<[u8]>::from(bytes);
+
+        Self { bytes }
+    }
+}
+
+impl Deref for LockedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+#[cfg(not(unix))]
+impl Drop for LockedBytes {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// A buffer that occupies whole memory pages on its own. Memory is locked and unlocked per page, so if secrets shared
+/// a page, dropping one would unlock the memory of the others.
+#[cfg(unix)]
+struct PageBuffer {
+    ptr: core::ptr::NonNull<u8>,
+    len: usize,
+    layout: std::alloc::Layout,
+    mlocked: bool,
+}
+
+// SAFETY: the buffer is owned and only mutated through `&mut self`, like a `Box<[u8]>`
+#[cfg(unix)]
+unsafe impl Send for PageBuffer {}
+// SAFETY: see `Send`
+#[cfg(unix)]
+unsafe impl Sync for PageBuffer {}
+
+#[cfg(unix)]
+impl PageBuffer {
+    fn new(bytes: &[u8]) -> Self {
+        // SAFETY: `sysconf` has no preconditions
+        let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            page_size if page_size > 0 => page_size as usize,
+            _ => 4096,
+        };
+        // Allocate at least one page, a zero sized allocation isn't allowed
+        let size = bytes.len().max(1).next_multiple_of(page_size);
+        let layout = std::alloc::Layout::from_size_align(size, page_size).expect("invalid secret buffer layout");
+        // SAFETY: the layout has a non-zero size
+        let ptr = core::ptr::NonNull::new(unsafe { std::alloc::alloc_zeroed(layout) })
+            .unwrap_or_else(|| std::alloc::handle_alloc_error(layout));
+        // SAFETY: the allocation is at least `bytes.len()` long and doesn't overlap `bytes`
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.as_ptr(), bytes.len()) };
+
+        // Locking can fail if the limit of locked memory of the process is reached, the bytes are still zeroized then
+        // SAFETY: the pointer and size are the ones of the allocation
+        let mlocked = unsafe { libc::mlock(ptr.as_ptr().cast(), layout.size()) } == 0;
+        if !mlocked {
+            log::debug!("[SECRET] couldn't lock the memory of a secret");
+        }
+
+        Self {
+            ptr,
+            len: bytes.len(),
+            layout,
+            mlocked,
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Deref for PageBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: the first `len` bytes of the allocation are initialized
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for PageBuffer {
+    fn drop(&mut self) {
+        // SAFETY: the whole allocation is initialized and exclusively owned
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.layout.size()) }.zeroize();
+
+        if self.mlocked {
+            // SAFETY: the memory was locked in `new()`, no other buffer shares its pages
+            unsafe { libc::munlock(self.ptr.as_ptr().cast(), self.layout.size()) };
+        }
+        // SAFETY: the pointer was allocated with this layout
+        unsafe { std::alloc::dealloc(self.ptr.as_ptr(), self.layout) };
+    }
+}
+
+impl std::fmt::Debug for LockedBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LockedBytes").field(&"...").finish()
+    }
+}
+
+/// A secret which can be removed from memory by locking it, and be put back by unlocking it with the same secret.
+/// With an auto-lock timeout the secret is locked automatically after it has been unlocked for that long.
+pub(crate) struct LockableSecret {
+    secret: Arc<RwLock<Option<LockedBytes>>>,
+    /// The hash of the secret, to check that the same secret is used to unlock it.
+    fingerprint: [u8; 32],
+    #[cfg(not(target_family = "wasm"))]
+    auto_lock: Mutex<AutoLock>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[derive(Default)]
+struct AutoLock {
+    timeout: Option<Duration>,
+    task: Option<crate::client::runtime::JoinHandle<()>>,
+}
+
+impl LockableSecret {
+    /// Creates a new unlocked secret, the caller is responsible for zeroizing the original.
+    pub(crate) fn new(secret: &[u8]) -> Self {
+        Self {
+            secret: Arc::new(RwLock::new(Some(LockedBytes::new(secret)))),
+            fingerprint: Blake2b256::digest(secret).into(),
+            #[cfg(not(target_family = "wasm"))]
+            auto_lock: Mutex::default(),
+        }
+    }
+
+    /// Calls the function with the secret, or returns [`Error::SecretManagerLocked`] if it's locked.
+    pub(crate) async fn with<T>(&self, f: impl FnOnce(&[u8]) -> T) -> Result<T, Error> {
+        let secret = self.secret.read().await;
+        let secret = secret.as_ref().ok_or(Error::SecretManagerLocked)?;
+        Ok(f(secret))
+    }
+
+    /// Returns whether the secret is locked.
+    pub(crate) async fn is_locked(&self) -> bool {
+        self.secret.read().await.is_none()
+    }
+
+    /// Removes the secret from memory.
+    pub(crate) async fn lock(&self) {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(task) = self.auto_lock.lock().await.task.take() {
+            task.abort();
+        }
+
+        // Dropping the secret zeroizes it
+        self.secret.write().await.take();
+        log::debug!("[SECRET] locked");
+    }
+
+    /// Puts the secret back into memory, if it's the same as the one it was created with. Otherwise
+    /// [`Error::SecretMismatch`] is returned.
+    pub(crate) async fn unlock(&self, secret: &[u8]) -> Result<(), Error> {
+        let fingerprint: [u8; 32] = Blake2b256::digest(secret).into();
+        if fingerprint != self.fingerprint {
+            return Err(Error::SecretMismatch);
+        }
+
+        *self.secret.write().await = Some(LockedBytes::new(secret));
+        log::debug!("[SECRET] unlocked");
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let mut auto_lock = self.auto_lock.lock().await;
+            self.restart_auto_lock(&mut auto_lock);
+        }
+
+        Ok(())
+    }
+
+    /// Sets the time after which an unlocked secret is locked automatically, `None` disables it. The timer starts
+    /// when the secret is unlocked, or now if it's unlocked already.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn set_auto_lock(&self, timeout: impl Into<Option<Duration>> + Send) {
+        let mut auto_lock = self.auto_lock.lock().await;
+        auto_lock.timeout = timeout.into();
+
+        if self.is_locked().await {
+            if let Some(task) = auto_lock.task.take() {
+                task.abort();
+            }
+        } else {
+            self.restart_auto_lock(&mut auto_lock);
+        }
+    }
+
+    /// Returns the time after which an unlocked secret is locked automatically.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn auto_lock(&self) -> Option<Duration> {
+        self.auto_lock.lock().await.timeout
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn restart_auto_lock(&self, auto_lock: &mut AutoLock) {
+        if let Some(task) = auto_lock.task.take() {
+            task.abort();
+        }
+
+        if let Some(timeout) = auto_lock.timeout {
+            auto_lock.task = Some(crate::client::runtime::spawn(task_auto_lock(
+                Arc::downgrade(&self.secret),
+                timeout,
+            )));
+        }
+    }
+}
+
+impl std::fmt::Debug for LockableSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LockableSecret").finish_non_exhaustive()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+async fn task_auto_lock(secret: Weak<RwLock<Option<LockedBytes>>>, timeout: Duration) {
+    crate::client::runtime::sleep(timeout).await;
+
+    // If the weak pointer cannot upgrade, the secret manager has been dropped and the secret zeroized already
+    if let Some(secret) = secret.upgrade() {
+        secret.write().await.take();
+        log::debug!("[SECRET] locked after {timeout:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lock_unlock() {
+        let secret = LockableSecret::new(&[1; 64]);
+        assert_eq!(secret.with(|bytes| bytes[0]).await.unwrap(), 1);
+
+        secret.lock().await;
+        assert!(secret.is_locked().await);
+        assert!(matches!(secret.with(|_| ()).await, Err(Error::SecretManagerLocked)));
+
+        assert!(matches!(secret.unlock(&[2; 64]).await, Err(Error::SecretMismatch)));
+        assert!(secret.is_locked().await);
+
+        secret.unlock(&[1; 64]).await.unwrap();
+        assert!(!secret.is_locked().await);
+    }
+
+    #[tokio::test]
+    async fn auto_lock() {
+        let secret = LockableSecret::new(&[1; 64]);
+        secret.set_auto_lock(Duration::from_millis(10)).await;
+        assert_eq!(secret.auto_lock().await, Some(Duration::from_millis(10)));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(secret.is_locked().await);
+
+        // Disabling the auto-lock keeps the secret unlocked
+        secret.set_auto_lock(None).await;
+        secret.unlock(&[1; 64]).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!secret.is_locked().await);
+    }
+
+    #[test]
+    fn locked_bytes() {
+        let bytes = LockedBytes::new(&[42; 4]);
+        assert_eq!(&*bytes, &[42; 4]);
+        assert!(LockedBytes::new(&[]).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn page_buffers_dont_share_pages() {
+        // SAFETY: `sysconf` has no preconditions
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let buffers = [
+            PageBuffer::new(&[1; 32]),
+            PageBuffer::new(&[2; 32]),
+            PageBuffer::new(&vec![3; page_size + 1]),
+        ];
+        for buffer in &buffers {
+            assert_eq!(buffer.ptr.as_ptr() as usize % page_size, 0);
+            assert_eq!(buffer.layout.size() % page_size, 0);
+            assert!(buffer.layout.size() >= buffer.len);
+        }
+        assert_eq!(buffers[2].layout.size(), 2 * page_size);
+        assert_eq!(&*buffers[1], &[2; 32]);
+    }
+
+    #[test]
+    fn debug_omits_secret() {
+        assert_eq!(format!("{:?}", LockedBytes::new(&[42; 4])), "LockedBytes(\"...\")");
+    }
+}
@@ -4,11 +4,17 @@
 //! Implementation of [`MnemonicSecretManager`].
 
 use std::ops::Range;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Duration;
 
 use async_trait::async_trait;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+        slip10::Seed,
+    },
     signatures::{
         ed25519,
         secp256k1_ecdsa::{self, EvmAddress},
@@ -16,18 +22,21 @@ use crypto::{
 };
 use zeroize::Zeroizing;
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{locked::LockableSecret, GenerateAddressOptions, SecretManage};
 use crate::{
     client::{api::PreparedTransactionData, Client, Error},
     types::block::{
         address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
     },
+    utils::mnemonic::{mnemonic_to_seed, MnemonicLanguage},
 };
 
 /// Secret manager that uses only a mnemonic.
 ///
 /// Computation are done in-memory. A mnemonic needs to be supplied upon the creation of [`MnemonicSecretManager`].
-pub struct MnemonicSecretManager(Seed);
+/// The seed is kept in locked memory and zeroized when the secret manager is dropped or [locked](Self::lock). A locked
+/// secret manager can be unlocked again with the same mnemonic or seed.
+pub struct MnemonicSecretManager(LockableSecret);
 
 impl std::fmt::Debug for MnemonicSecretManager {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -47,6 +56,7 @@ impl SecretManage for MnemonicSecretManager {
         options: impl Into<Option<GenerateAddressOptions>> + Send,
     ) -> Result<Vec<Ed25519Address>, Self::Error> {
         let internal = options.into().map(|o| o.internal).unwrap_or_default();
+        let seed = self.seed().await?;
 
         Ok(address_indexes
             .map(|address_index| {
@@ -56,7 +66,7 @@ impl SecretManage for MnemonicSecretManager {
                     .with_address_index(address_index);
 
                 let public_key = chain
-                    .derive(&self.0.to_master_key::<ed25519::SecretKey>())
+                    .derive(&seed.to_master_key::<ed25519::SecretKey>())
                     .secret_key()
                     .public_key()
                     .to_bytes();
@@ -79,6 +89,7 @@ impl SecretManage for MnemonicSecretManager {
         options: impl Into<Option<GenerateAddressOptions>> + Send,
     ) -> Result<Vec<EvmAddress>, Self::Error> {
         let internal = options.into().map(|o| o.internal).unwrap_or_default();
+        let seed = self.seed().await?;
 
         Ok(address_indexes
             .map(|address_index| {
@@ -88,7 +99,7 @@ impl SecretManage for MnemonicSecretManager {
                     .with_address_index(address_index);
 
                 let public_key = chain
-                    .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
+                    .derive(&seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
                     .secret_key()
                     .public_key();
 
@@ -99,7 +110,8 @@ impl SecretManage for MnemonicSecretManager {
 
     async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
         // Get the private and public key for this Ed25519 address
-        let private_key = chain.derive(&self.0.to_master_key::<ed25519::SecretKey>()).secret_key();
+        let seed = self.seed().await?;
+        let private_key = chain.derive(&seed.to_master_key::<ed25519::SecretKey>()).secret_key();
         let public_key = private_key.public_key();
         let signature = private_key.sign(msg);
 
@@ -112,8 +124,9 @@ impl SecretManage for MnemonicSecretManager {
         chain: Bip44,
     ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
         // Get the private and public key for this secp256k1_ecdsa key
+        let seed = self.seed().await?;
         let private_key = chain
-            .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
+            .derive(&seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
             .secret_key();
         let public_key = private_key.public_key();
         let signature = private_key.try_sign_keccak256(msg)?;
@@ -142,15 +155,77 @@ impl MnemonicSecretManager {
     ///
     /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
     pub fn try_from_mnemonic(mnemonic: impl Into<Mnemonic>) -> Result<Self, Error> {
-        Ok(Self(Client::mnemonic_to_seed(mnemonic.into())?.into()))
+        let seed = Client::mnemonic_to_seed(mnemonic.into())?;
+        Ok(Self(LockableSecret::new(seed.as_ref())))
+    }
+
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in the language and a passphrase, also known as
+    /// the 25th word. Another passphrase derives another seed, so it has to be unlocked with the same passphrase.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+        language: MnemonicLanguage,
+    ) -> Result<Self, Error> {
+        let seed = mnemonic_to_seed(mnemonic.into(), language, Some(&passphrase.into()))?;
+        Ok(Self(LockableSecret::new(seed.as_ref())))
     }
 
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
     pub fn try_from_hex_seed(hex: impl Into<Zeroizing<String>>) -> Result<Self, Error> {
         let hex = hex.into();
         let bytes = Zeroizing::new(prefix_hex::decode::<Vec<u8>>(hex.as_str())?);
-        let seed = Seed::from_bytes(bytes.as_ref());
-        Ok(Self(seed))
+        Ok(Self(LockableSecret::new(&bytes)))
+    }
+
+    /// Zeroizes the seed, until the secret manager is unlocked again it returns [`Error::SecretManagerLocked`].
+    pub async fn lock(&self) {
+        self.0.lock().await
+    }
+
+    /// Unlocks the secret manager with the mnemonic it was created with.
+    pub async fn unlock_with_mnemonic(&self, mnemonic: impl Into<Mnemonic> + Send) -> Result<(), Error> {
+        let seed = Client::mnemonic_to_seed(mnemonic.into())?;
+        self.0.unlock(seed.as_ref()).await
+    }
+
+    /// Unlocks the secret manager with the mnemonic and passphrase it was created with.
+    pub async fn unlock_with_mnemonic_and_passphrase(
+        &self,
+        mnemonic: impl Into<Mnemonic> + Send,
+        passphrase: impl Into<Passphrase> + Send,
+        language: MnemonicLanguage,
+    ) -> Result<(), Error> {
+        let seed = mnemonic_to_seed(mnemonic.into(), language, Some(&passphrase.into()))?;
+        self.0.unlock(seed.as_ref()).await
+    }
+
+    /// Unlocks the secret manager with the hex-encoded seed it was created with.
+    pub async fn unlock_with_hex_seed(&self, hex: impl Into<Zeroizing<String>> + Send) -> Result<(), Error> {
+        let hex = hex.into();
+        let bytes = Zeroizing::new(prefix_hex::decode::<Vec<u8>>(hex.as_str())?);
+        self.0.unlock(&bytes).await
+    }
+
+    /// Returns whether the secret manager is locked.
+    pub async fn is_locked(&self) -> bool {
+        self.0.is_locked().await
+    }
+
+    /// Sets the time after which the secret manager locks itself once it was unlocked, `None` disables it. If it's
+    /// unlocked, the timer starts now.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn set_auto_lock(&self, timeout: impl Into<Option<Duration>> + Send) {
+        self.0.set_auto_lock(timeout).await
+    }
+
+    /// Returns the time after which the secret manager locks itself once it was unlocked.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn auto_lock(&self) -> Option<Duration> {
+        self.0.auto_lock().await
+    }
+
+    async fn seed(&self) -> Result<Seed, Error> {
+        self.0.with(Seed::from_bytes).await
     }
 }
 
@@ -194,4 +269,76 @@ mod tests {
             "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r"
         );
     }
+
+    #[tokio::test]
+    async fn lock_unlock() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic.to_owned()).unwrap();
+
+        secret_manager.lock().await;
+        assert!(secret_manager.is_locked().await);
+        assert!(matches!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await,
+            Err(Error::SecretManagerLocked)
+        ));
+
+        let other_mnemonic = Client::generate_mnemonic().unwrap();
+        assert!(matches!(
+            secret_manager.unlock_with_mnemonic(other_mnemonic).await,
+            Err(Error::SecretMismatch)
+        ));
+
+        secret_manager.unlock_with_mnemonic(mnemonic.to_owned()).await.unwrap();
+        let addresses = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            addresses[0].to_bech32_unchecked("atoi"),
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+    }
+
+    #[tokio::test]
+    async fn passphrase() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+            mnemonic.to_owned(),
+            "passphrase".to_owned(),
+            MnemonicLanguage::English,
+        )
+        .unwrap();
+
+        let addresses = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+            .await
+            .unwrap();
+        // The passphrase derives another seed than the mnemonic alone
+        assert_ne!(
+            addresses[0].to_bech32_unchecked("atoi"),
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+
+        secret_manager.lock().await;
+        assert!(matches!(
+            secret_manager.unlock_with_mnemonic(mnemonic.to_owned()).await,
+            Err(Error::SecretMismatch)
+        ));
+        secret_manager
+            .unlock_with_mnemonic_and_passphrase(
+                mnemonic.to_owned(),
+                "passphrase".to_owned(),
+                MnemonicLanguage::English,
+            )
+            .await
+            .unwrap();
+        assert!(!secret_manager.is_locked().await);
+    }
 }
@@ -3,12 +3,20 @@
 
 //! Secret manager module enabling address generation and transaction essence signing.
 
+/// Module for hardware signing devices.
+pub mod hardware;
+/// Module for hooks that are called before a transaction gets signed.
+pub mod interceptor;
 /// Module for ledger nano based secret management.
 #[cfg(feature = "ledger_nano")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
 pub mod ledger_nano;
+/// Module for secrets kept in locked memory.
+pub(crate) mod locked;
 /// Module for mnemonic based secret management.
 pub mod mnemonic;
+/// Module for coordinating signatures from multiple secret managers.
+pub mod multi;
 /// Module for single private key based secret management.
 #[cfg(feature = "private_key_secret_manager")]
 #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
@@ -20,13 +28,16 @@ pub mod stronghold;
 /// Signing related types
 pub mod types;
 
-#[cfg(feature = "stronghold")]
+#[cfg(any(feature = "stronghold", not(target_family = "wasm")))]
 use std::time::Duration;
-use std::{collections::HashMap, fmt::Debug, ops::Range, str::FromStr};
+use std::{fmt::Debug, ops::Range, str::FromStr};
 
 use async_trait::async_trait;
 use crypto::{
-    keys::{bip39::Mnemonic, bip44::Bip44},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+    },
     signatures::secp256k1_ecdsa::{self, EvmAddress},
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -45,21 +56,20 @@ use crate::client::secret::types::StrongholdDto;
 use crate::{
     client::{
         api::{
-            input_selection::{is_alias_transition, Error as InputSelectionError},
-            transaction::validate_transaction_payload_length,
+            input_selection::Error as InputSelectionError, transaction::validate_transaction_payload_length,
             verify_semantic, PreparedTransactionData,
         },
         Error,
     },
     types::block::{
-        address::{Address, Ed25519Address},
-        output::Output,
+        address::Ed25519Address,
         payload::{transaction::TransactionEssence, TransactionPayload},
         semantic::ConflictReason,
         signature::{Ed25519Signature, Signature},
-        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        unlock::{plan_unlocks, InputUnlock, SignatureUnlock, Unlock, Unlocks},
+        Error as BlockError,
     },
-    utils::unix_timestamp_now,
+    utils::{mnemonic::MnemonicLanguage, unix_timestamp_now},
 };
 
 /// The secret manager interface.
@@ -154,6 +164,16 @@ pub enum SecretManager {
     Placeholder,
 }
 
+impl SecretManager {
+    /// Wraps the secret manager, so the interceptor is called before a transaction or message gets signed.
+    pub fn with_interceptor(
+        self,
+        interceptor: impl interceptor::SigningInterceptor + 'static,
+    ) -> interceptor::InterceptedSecretManager<Self> {
+        interceptor::InterceptedSecretManager::new(self).with_interceptor(interceptor)
+    }
+}
+
 #[cfg(feature = "stronghold")]
 impl From<StrongholdSecretManager> for SecretManager {
     fn from(secret_manager: StrongholdSecretManager) -> Self {
@@ -205,7 +225,7 @@ impl FromStr for SecretManager {
 }
 
 /// DTO for secret manager types with required data.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum SecretManagerDto {
     /// Stronghold
@@ -221,6 +241,14 @@ pub enum SecretManagerDto {
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
+    /// Mnemonic with a passphrase, also known as the 25th word
+    #[serde(alias = "mnemonicWithPassphrase")]
+    MnemonicWithPassphrase {
+        mnemonic: Zeroizing<String>,
+        passphrase: Zeroizing<String>,
+        #[serde(default)]
+        language: MnemonicLanguage,
+    },
     /// Private Key
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
@@ -234,6 +262,28 @@ pub enum SecretManagerDto {
     Placeholder,
 }
 
+impl Debug for SecretManagerDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(stronghold_dto) => f.debug_tuple("Stronghold").field(stronghold_dto).finish(),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(is_simulator) => f.debug_tuple("LedgerNano").field(is_simulator).finish(),
+            Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
+            Self::MnemonicWithPassphrase { language, .. } => f
+                .debug_struct("MnemonicWithPassphrase")
+                .field("mnemonic", &"...")
+                .field("passphrase", &"...")
+                .field("language", language)
+                .finish(),
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"...").finish(),
+            Self::HexSeed(_) => f.debug_tuple("HexSeed").field(&"...").finish(),
+            Self::Placeholder => f.debug_struct("Placeholder").finish(),
+        }
+    }
+}
+
 impl TryFrom<SecretManagerDto> for SecretManager {
     type Error = Error;
 
@@ -261,6 +311,16 @@ impl TryFrom<SecretManagerDto> for SecretManager {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
 
+            SecretManagerDto::MnemonicWithPassphrase {
+                mnemonic,
+                passphrase,
+                language,
+            } => Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+                mnemonic.as_str().to_owned(),
+                passphrase.as_str().to_owned(),
+                language,
+            )?),
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key)?))
@@ -294,7 +354,7 @@ impl From<&SecretManager> for SecretManagerDto {
             #[cfg(feature = "ledger_nano")]
             SecretManager::LedgerNano(ledger_nano) => Self::LedgerNano(ledger_nano.is_simulator),
 
-            // `MnemonicSecretManager(Seed)` doesn't have Debug or Display implemented and in the current use cases of
+            // `MnemonicSecretManager` doesn't have Debug or Display implemented and in the current use cases of
             // the client/wallet we also don't need to convert it in this direction with the mnemonic/seed, we only need
             // to know the type
             SecretManager::Mnemonic(_mnemonic) => Self::Mnemonic("...".to_string().into()),
@@ -487,6 +547,15 @@ impl SecretManagerConfig for SecretManager {
             SecretManagerDto::Mnemonic(mnemonic) => {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
+            SecretManagerDto::MnemonicWithPassphrase {
+                mnemonic,
+                passphrase,
+                language,
+            } => Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+                mnemonic.as_str().to_owned(),
+                passphrase.as_str().to_owned(),
+                *language,
+            )?),
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key.to_owned())?))
@@ -502,10 +571,103 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?))
     }
 
+    /// Tries to create a [`SecretManager`] from a mnemonic string in the language and a passphrase.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+        language: MnemonicLanguage,
+    ) -> crate::client::Result<Self> {
+        Ok(Self::Mnemonic(
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase, language)?,
+        ))
+    }
+
     /// Tries to create a [`SecretManager`] from a seed hex string.
     pub fn try_from_hex_seed(seed: impl Into<Zeroizing<String>>) -> crate::client::Result<Self> {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
     }
+
+    /// Zeroizes the secrets of the secret manager, until it's unlocked again it can't be used for address generation or
+    /// signing. A Stronghold secret manager clears its key, Ledger Nano and placeholder secret managers aren't
+    /// affected.
+    pub async fn lock(&self) {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => secret_manager.clear_key().await,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => {}
+            Self::Mnemonic(secret_manager) => secret_manager.lock().await,
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.lock().await,
+            Self::Placeholder => {}
+        }
+    }
+
+    /// Unlocks the secret manager with its secret: the password of a Stronghold, the mnemonic or hex-encoded seed of a
+    /// mnemonic secret manager or the hex-encoded private key of a private key secret manager. Returns
+    /// [`Error::SecretMismatch`] if it's not the secret the secret manager was created with.
+    pub async fn unlock(&self, secret: impl Into<Zeroizing<String>> + Send) -> crate::client::Result<()> {
+        let secret = secret.into();
+
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => Ok(secret_manager
+                .set_password(crate::client::Password::from(secret.to_string()))
+                .await?),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => Ok(()),
+            Self::Mnemonic(secret_manager) => {
+                if secret.starts_with("0x") {
+                    secret_manager.unlock_with_hex_seed(secret).await
+                } else {
+                    secret_manager.unlock_with_mnemonic(secret.as_str().to_owned()).await
+                }
+            }
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.unlock_with_hex(secret).await,
+            Self::Placeholder => Ok(()),
+        }
+    }
+
+    /// Returns whether the secret manager is locked.
+    pub async fn is_locked(&self) -> bool {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => !secret_manager.is_key_available().await,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => false,
+            Self::Mnemonic(secret_manager) => secret_manager.is_locked().await,
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.is_locked().await,
+            Self::Placeholder => false,
+        }
+    }
+
+    /// Sets the time after which the secret manager locks itself once it was unlocked, `None` disables it. For a
+    /// Stronghold this is the timeout after which its key is cleared.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn set_auto_lock(&mut self, timeout: impl Into<Option<Duration>> + Send) {
+        let timeout = timeout.into();
+
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => secret_manager.set_timeout(timeout).await,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => {}
+            Self::Mnemonic(secret_manager) => secret_manager.set_auto_lock(timeout).await,
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(secret_manager) => secret_manager.set_auto_lock(timeout).await,
+            Self::Placeholder => {}
+        }
+    }
+
+    /// Signs arbitrary data with the key of the given chain, e.g. to prove the ownership of an address. The data is
+    /// prefixed and hashed before signing, so the signature can't be used to unlock outputs. Signatures can be
+    /// checked with [`verify_ed25519_signature()`](crate::client::utils::verify_ed25519_signature).
+    pub async fn sign_data(&self, chain: Bip44, data: &[u8]) -> crate::client::Result<Ed25519Signature> {
+        self.sign_ed25519(&crate::client::utils::signed_data_hash(data), chain)
+            .await
+    }
 }
 
 pub(crate) async fn default_sign_transaction_essence<M: SecretManage>(
@@ -518,68 +680,42 @@ where
 {
     // The hashed_essence gets signed
     let hashed_essence = prepared_transaction_data.essence.hash();
+    let input_unlocks = plan_transaction_unlocks(prepared_transaction_data, time)?;
     let mut blocks = Vec::new();
-    let mut block_indexes = HashMap::<Address, usize>::new();
-
-    // Assuming inputs_data is ordered by address type
-    for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
-        // Get the address that is required to unlock the input
-        let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
-        let alias_transition = is_alias_transition(&input.output, *input.output_id(), regular.outputs(), None);
-        let (input_address, _) = input.output.required_and_unlocked_address(
-            time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
-            input.output_metadata.output_id(),
-            alias_transition,
-        )?;
-
-        // Check if we already added an [Unlock] for this address
-        match block_indexes.get(&input_address) {
-            // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
-            Some(block_index) => match input_address {
-                Address::Alias(_alias) => blocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
-                Address::Ed25519(_ed25519) => {
-                    blocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
-                }
-                Address::Nft(_nft) => blocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
-            },
-            None => {
-                // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
-                // address already at this point, because the reference index needs to be lower
-                // than the current block index
-                if !input_address.is_ed25519() {
-                    Err(InputSelectionError::MissingInputWithEd25519Address)?;
-                }
 
+    for (input, input_unlock) in prepared_transaction_data.inputs_data.iter().zip(input_unlocks) {
+        match input_unlock {
+            InputUnlock::Signature(_) => {
                 let chain = input.chain.ok_or(Error::MissingBip32Chain)?;
-
-                let block = secret_manager.signature_unlock(&hashed_essence, chain).await?;
-                blocks.push(block);
-
-                // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
-                // the same address in their unlock condition
-                block_indexes.insert(input_address, current_block_index);
+                blocks.push(secret_manager.signature_unlock(&hashed_essence, chain).await?);
             }
+            InputUnlock::Unlock(unlock) => blocks.push(unlock),
         }
-
-        // When we have an alias or Nft output, we will add their alias or nft address to block_indexes,
-        // because they can be used to unlock outputs via [Unlock::Alias] or [Unlock::Nft],
-        // that have the corresponding alias or nft address in their unlock condition
-        match &input.output {
-            Output::Alias(alias_output) => block_indexes.insert(
-                Address::Alias(alias_output.alias_address(input.output_id())),
-                current_block_index,
-            ),
-            Output::Nft(nft_output) => block_indexes.insert(
-                Address::Nft(nft_output.nft_address(input.output_id())),
-                current_block_index,
-            ),
-            _ => None,
-        };
     }
 
     Ok(Unlocks::new(blocks)?)
 }
 
+// Determines how the inputs of a prepared transaction get unlocked, in the order of the inputs
+pub(crate) fn plan_transaction_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    time: Option<u32>,
+) -> crate::client::Result<Vec<InputUnlock>> {
+    let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
+    plan_unlocks(
+        prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| (input.output_id(), &input.output)),
+        regular.outputs(),
+        time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+    )
+    .map_err(|e| match e {
+        BlockError::MissingInputWithEd25519Address => InputSelectionError::MissingInputWithEd25519Address.into(),
+        e => Error::from(e),
+    })
+}
+
 pub(crate) async fn default_sign_transaction<M: SecretManage>(
     secret_manager: &M,
     prepared_transaction_data: PreparedTransactionData,
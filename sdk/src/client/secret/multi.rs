@@ -0,0 +1,374 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Coordination of transaction signing across multiple secret managers.
+//!
+//! A [`SigningCoordinator`] is created from a [`PreparedTransactionData`] whose inputs are controlled by several
+//! parties. It hands out a [`SigningRequest`] that can be sent to every participant, collects the
+//! [`PartialSignature`]s they return and assembles the final [`Unlocks`] once every required signer contributed.
+//!
+//! This is an N-of-N scheme: every distinct address backing the inputs has to sign. M-of-N thresholds aren't supported,
+//! as every address of the protocol is controlled by a single key, so a transaction can't be unlocked without the
+//! signatures of all its addresses. A threshold has to be agreed on by the participants before they sign. The request
+//! contains the transaction and the outputs its inputs spend, so every participant can check what they sign before
+//! signing it.
+
+use std::collections::HashMap;
+
+use crypto::keys::bip44::Bip44;
+use serde::{Deserialize, Serialize};
+
+use super::{plan_transaction_unlocks, SecretManage};
+use crate::{
+    client::{
+        api::{
+            transaction::validate_transaction_payload_length, verify_semantic, PreparedTransactionData,
+            PreparedTransactionDataDto,
+        },
+        Error, Result,
+    },
+    types::{
+        block::{
+            address::Ed25519Address,
+            input::Input,
+            output::InputsCommitment,
+            payload::{transaction::TransactionEssence, TransactionPayload},
+            semantic::ConflictReason,
+            signature::{dto::Ed25519SignatureDto, Ed25519Signature, Signature},
+            unlock::{InputUnlock, SignatureUnlock, Unlock, Unlocks},
+        },
+        TryFromDto, ValidationParams,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// An input that needs to be signed by a participant of a [`SigningRequest`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningRequestInput {
+    /// The index of the input in the transaction essence.
+    pub input_index: u16,
+    /// The address that needs to provide the signature.
+    pub address: Ed25519Address,
+    /// The chain to sign with.
+    #[serde(with = "crate::utils::serde::bip44::Bip44Def")]
+    pub chain: Bip44,
+}
+
+/// A request for signatures that can be sent to the participants of a multi-party signing flow.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningRequest {
+    /// The transaction that needs to be signed, with the outputs its inputs spend.
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The inputs that need a signature unlock.
+    pub inputs: Vec<SigningRequestInput>,
+}
+
+impl SigningRequest {
+    /// Returns the hash of the transaction essence that gets signed.
+    pub fn essence_hash(&self) -> [u8; 32] {
+        self.prepared_transaction_data.essence.hash()
+    }
+
+    /// Checks that the outputs of the request are the ones the inputs of the essence spend and commit to, so the
+    /// amounts a participant sees are the ones that get spent.
+    pub fn verify(&self) -> Result<()> {
+        let TransactionEssence::Regular(essence) = &self.prepared_transaction_data.essence;
+        let inputs_data = &self.prepared_transaction_data.inputs_data;
+
+        if essence.inputs().len() != inputs_data.len() {
+            return Err(Error::InvalidSigningRequest(format!(
+                "{} inputs, but {} resolved inputs",
+                essence.inputs().len(),
+                inputs_data.len()
+            )));
+        }
+        for (input, input_data) in essence.inputs().iter().zip(inputs_data) {
+            let Input::Utxo(input) = input else {
+                return Err(Error::InvalidSigningRequest("unsupported input".to_string()));
+            };
+            if input.output_id() != input_data.output_id() {
+                return Err(Error::InvalidSigningRequest(format!(
+                    "resolved input {} doesn't match input {}",
+                    input_data.output_id(),
+                    input.output_id()
+                )));
+            }
+        }
+        if essence.inputs_commitment() != &InputsCommitment::new(inputs_data.iter().map(|input| &input.output)) {
+            return Err(Error::InvalidSigningRequest(
+                "resolved inputs don't match the inputs commitment".to_string(),
+            ));
+        }
+        for input in &self.inputs {
+            if inputs_data.get(input.input_index as usize).is_none() {
+                return Err(Error::InvalidSigningRequest(format!(
+                    "input {} doesn't exist",
+                    input.input_index
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Dto for a [`SigningRequest`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningRequestDto {
+    /// The transaction that needs to be signed, with the outputs its inputs spend.
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The inputs that need a signature unlock.
+    pub inputs: Vec<SigningRequestInput>,
+}
+
+impl From<&SigningRequest> for SigningRequestDto {
+    fn from(value: &SigningRequest) -> Self {
+        Self {
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            inputs: value.inputs.clone(),
+        }
+    }
+}
+
+impl TryFromDto for SigningRequest {
+    type Dto = SigningRequestDto;
+    type Error = Error;
+
+    fn try_from_dto_with_params_inner(dto: Self::Dto, params: ValidationParams<'_>) -> Result<Self> {
+        Ok(Self {
+            prepared_transaction_data: PreparedTransactionData::try_from_dto_with_params(
+                dto.prepared_transaction_data,
+                &params,
+            )?,
+            inputs: dto.inputs,
+        })
+    }
+}
+
+/// A signature provided by a single participant for one of the inputs of a [`SigningRequest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialSignature {
+    /// The index of the signed input in the transaction essence.
+    pub input_index: u16,
+    /// The signature.
+    pub signature: Ed25519Signature,
+}
+
+/// Dto for a [`PartialSignature`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialSignatureDto {
+    /// The index of the signed input in the transaction essence.
+    pub input_index: u16,
+    /// The signature.
+    pub signature: Ed25519SignatureDto,
+}
+
+impl From<&PartialSignature> for PartialSignatureDto {
+    fn from(value: &PartialSignature) -> Self {
+        Self {
+            input_index: value.input_index,
+            signature: Ed25519SignatureDto::from(&value.signature),
+        }
+    }
+}
+
+impl TryFrom<PartialSignatureDto> for PartialSignature {
+    type Error = Error;
+
+    fn try_from(value: PartialSignatureDto) -> Result<Self> {
+        Ok(Self {
+            input_index: value.input_index,
+            signature: Ed25519Signature::try_from(value.signature)?,
+        })
+    }
+}
+
+/// Collects signatures from multiple secret managers and assembles the unlocks of a transaction.
+///
+/// Every distinct ed25519 address backing the inputs of the transaction is a required signer. The transaction can
+/// only be finished once all of them provided a valid signature.
+#[derive(Clone, Debug)]
+pub struct SigningCoordinator {
+    prepared_transaction_data: PreparedTransactionData,
+    time: u32,
+    essence_hash: [u8; 32],
+    input_unlocks: Vec<InputUnlock>,
+    signers: Vec<SigningRequestInput>,
+    signatures: HashMap<Ed25519Address, Ed25519Signature>,
+}
+
+impl SigningCoordinator {
+    /// Creates a new [`SigningCoordinator`] for the given prepared transaction. `time` is used to determine which
+    /// address needs to unlock an input, it defaults to the current time.
+    pub fn new(prepared_transaction_data: PreparedTransactionData, time: impl Into<Option<u32>>) -> Result<Self> {
+        let time = time.into().unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
+        let essence_hash = prepared_transaction_data.essence.hash();
+        let input_unlocks = plan_transaction_unlocks(&prepared_transaction_data, Some(time))?;
+        let mut signers = Vec::new();
+
+        for (input_index, input_unlock) in input_unlocks.iter().enumerate() {
+            if let InputUnlock::Signature(address) = input_unlock {
+                let chain = prepared_transaction_data.inputs_data[input_index]
+                    .chain
+                    .ok_or(Error::MissingBip32Chain)?;
+
+                signers.push(SigningRequestInput {
+                    input_index: input_index as u16,
+                    address: *address,
+                    chain,
+                });
+            }
+        }
+
+        Ok(Self {
+            prepared_transaction_data,
+            time,
+            essence_hash,
+            input_unlocks,
+            signers,
+            signatures: HashMap::new(),
+        })
+    }
+
+    /// Returns the [`SigningRequest`] to send to the participants.
+    pub fn signing_request(&self) -> SigningRequest {
+        SigningRequest {
+            prepared_transaction_data: self.prepared_transaction_data.clone(),
+            inputs: self.signers.clone(),
+        }
+    }
+
+    /// Returns the addresses of all required signers.
+    pub fn required_signers(&self) -> impl Iterator<Item = &Ed25519Address> + '_ {
+        self.signers.iter().map(|input| &input.address)
+    }
+
+    /// Returns the addresses of the signers that didn't provide a signature yet.
+    pub fn missing_signers(&self) -> Vec<Ed25519Address> {
+        self.required_signers()
+            .filter(|address| !self.signatures.contains_key(address))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the number of required signers that already provided a signature.
+    pub fn signature_count(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Returns the number of required signers.
+    pub fn required_count(&self) -> usize {
+        self.signers.len()
+    }
+
+    /// Returns whether all required signers provided a signature.
+    pub fn is_complete(&self) -> bool {
+        self.signature_count() == self.required_count()
+    }
+
+    /// Verifies and adds a [`PartialSignature`] to the coordinator.
+    pub fn add_partial_signature(&mut self, partial_signature: PartialSignature) -> Result<()> {
+        let signer = self
+            .signers
+            .iter()
+            .find(|input| input.input_index == partial_signature.input_index)
+            .ok_or(Error::UnexpectedPartialSignature(partial_signature.input_index))?;
+
+        partial_signature
+            .signature
+            .is_valid(&self.essence_hash, &signer.address)?;
+
+        self.signatures.insert(signer.address, partial_signature.signature);
+
+        Ok(())
+    }
+
+    /// Verifies and adds multiple [`PartialSignature`]s to the coordinator.
+    pub fn add_partial_signatures(
+        &mut self,
+        partial_signatures: impl IntoIterator<Item = PartialSignature>,
+    ) -> Result<()> {
+        for partial_signature in partial_signatures {
+            self.add_partial_signature(partial_signature)?;
+        }
+
+        Ok(())
+    }
+
+    /// Assembles the [`Unlocks`] of the transaction from the collected signatures.
+    pub fn unlocks(&self) -> Result<Unlocks> {
+        let blocks = self
+            .input_unlocks
+            .iter()
+            .map(|input_unlock| {
+                Ok(match input_unlock {
+                    InputUnlock::Signature(address) => {
+                        let signature = self
+                            .signatures
+                            .get(address)
+                            .ok_or_else(|| Error::MissingSignature(address.to_string()))?;
+                        Unlock::Signature(SignatureUnlock::new(Signature::from(signature.clone())))
+                    }
+                    InputUnlock::Unlock(unlock) => unlock.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Unlocks::new(blocks)?)
+    }
+
+    /// Assembles and validates the final [`TransactionPayload`].
+    pub fn finish(self) -> Result<TransactionPayload> {
+        let unlocks = self.unlocks()?;
+        let PreparedTransactionData {
+            essence, inputs_data, ..
+        } = self.prepared_transaction_data;
+        let tx_payload = TransactionPayload::new(essence, unlocks)?;
+
+        validate_transaction_payload_length(&tx_payload)?;
+
+        let conflict = verify_semantic(&inputs_data, &tx_payload, self.time)?;
+
+        if conflict != ConflictReason::None {
+            log::debug!(
+                "[SigningCoordinator::finish] conflict: {conflict:?} for {:#?}",
+                tx_payload
+            );
+            return Err(Error::TransactionSemantic(conflict));
+        }
+
+        Ok(tx_payload)
+    }
+}
+
+/// Signs the inputs of a [`SigningRequest`] that are controlled by the given secret manager, after checking the
+/// request with [`SigningRequest::verify()`]. The essence hash is computed from the transaction of the request, it's
+/// up to the participant to check the transaction before. Inputs whose address doesn't match the signing key are
+/// skipped, so every participant can be sent the complete request.
+pub async fn sign_request<M: SecretManage>(
+    secret_manager: &M,
+    request: &SigningRequest,
+) -> Result<Vec<PartialSignature>>
+where
+    Error: From<M::Error>,
+{
+    request.verify()?;
+    let essence_hash = request.essence_hash();
+    let mut partial_signatures = Vec::new();
+
+    for input in &request.inputs {
+        let signature = secret_manager.sign_ed25519(&essence_hash, input.chain).await?;
+
+        if signature.is_valid(&essence_hash, &input.address).is_ok() {
+            partial_signatures.push(PartialSignature {
+                input_index: input.input_index,
+                signature,
+            });
+        }
+    }
+
+    Ok(partial_signatures)
+}
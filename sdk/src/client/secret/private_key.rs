@@ -4,6 +4,8 @@
 //! Implementation of [`PrivateKeySecretManager`].
 
 use std::ops::Range;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Duration;
 
 use async_trait::async_trait;
 use crypto::{
@@ -16,7 +18,7 @@ use crypto::{
 };
 use zeroize::{Zeroize, Zeroizing};
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{locked::LockableSecret, GenerateAddressOptions, SecretManage};
 use crate::{
     client::{api::PreparedTransactionData, Error},
     types::block::{
@@ -25,7 +27,9 @@ use crate::{
 };
 
 /// Secret manager based on a single private key.
-pub struct PrivateKeySecretManager(ed25519::SecretKey);
+///
+/// The private key is kept in locked memory and zeroized when the secret manager is dropped or [locked](Self::lock).
+pub struct PrivateKeySecretManager(LockableSecret);
 
 impl std::fmt::Debug for PrivateKeySecretManager {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -44,7 +48,7 @@ impl SecretManage for PrivateKeySecretManager {
         _address_indexes: Range<u32>,
         _options: impl Into<Option<GenerateAddressOptions>> + Send,
     ) -> Result<Vec<Ed25519Address>, Self::Error> {
-        let public_key = self.0.public_key().to_bytes();
+        let public_key = self.private_key().await?.public_key().to_bytes();
 
         // Hash the public key to get the address
         let result = Blake2b256::digest(public_key).try_into().map_err(|_e| {
@@ -66,8 +70,9 @@ impl SecretManage for PrivateKeySecretManager {
     }
 
     async fn sign_ed25519(&self, msg: &[u8], _chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
-        let public_key = self.0.public_key();
-        let signature = self.0.sign(msg);
+        let private_key = self.private_key().await?;
+        let public_key = private_key.public_key();
+        let signature = private_key.sign(msg);
 
         Ok(Ed25519Signature::new(public_key, signature))
     }
@@ -112,7 +117,7 @@ impl PrivateKeySecretManager {
             return Err(crypto::Error::PrivateKeyError.into());
         }
 
-        let private_key = Self(ed25519::SecretKey::from_bytes(&bytes));
+        let private_key = Self(LockableSecret::new(&bytes));
 
         bytes.zeroize();
 
@@ -121,12 +126,56 @@ impl PrivateKeySecretManager {
 
     /// Create a new [`PrivateKeySecretManager`] from an hex encoded private key.
     pub fn try_from_hex(hex: impl Into<Zeroizing<String>>) -> Result<Self, Error> {
-        let mut bytes = prefix_hex::decode(hex.into())?;
+        let mut bytes: [u8; ed25519::SecretKey::LENGTH] = prefix_hex::decode(hex.into())?;
 
-        let private_key = Self(ed25519::SecretKey::from_bytes(&bytes));
+        let private_key = Self(LockableSecret::new(&bytes));
 
         bytes.zeroize();
 
         Ok(private_key)
     }
+
+    /// Zeroizes the private key, until the secret manager is unlocked again it returns
+    /// [`Error::SecretManagerLocked`].
+    pub async fn lock(&self) {
+        self.0.lock().await
+    }
+
+    /// Unlocks the secret manager with the hex encoded private key it was created with.
+    pub async fn unlock_with_hex(&self, hex: impl Into<Zeroizing<String>> + Send) -> Result<(), Error> {
+        let mut bytes: [u8; ed25519::SecretKey::LENGTH] = prefix_hex::decode(hex.into())?;
+
+        let result = self.0.unlock(&bytes).await;
+
+        bytes.zeroize();
+
+        result
+    }
+
+    /// Returns whether the secret manager is locked.
+    pub async fn is_locked(&self) -> bool {
+        self.0.is_locked().await
+    }
+
+    /// Sets the time after which the secret manager locks itself once it was unlocked, `None` disables it. If it's
+    /// unlocked, the timer starts now.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn set_auto_lock(&self, timeout: impl Into<Option<Duration>> + Send) {
+        self.0.set_auto_lock(timeout).await
+    }
+
+    /// Returns the time after which the secret manager locks itself once it was unlocked.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn auto_lock(&self) -> Option<Duration> {
+        self.0.auto_lock().await
+    }
+
+    async fn private_key(&self) -> Result<ed25519::SecretKey, Error> {
+        self.0
+            .with(|bytes| {
+                // The length is checked when the secret manager is created
+                ed25519::SecretKey::from_bytes(bytes.try_into().expect("invalid private key length"))
+            })
+            .await
+    }
 }
@@ -0,0 +1,177 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing in to web services with an address instead of a password.
+//!
+//! The service creates a [`SignInChallenge`] for the address of the user, bound to its domain, with a nonce it
+//! remembers and an expiry. The wallet signs the [message](SignInChallenge::message()) of the challenge with the key of
+//! the address, prefixed like [`SecretManager::sign_data()`](crate::client::secret::SecretManager::sign_data), so the
+//! signature can't be used to unlock outputs. The service checks the returned [`SignInResponse`] with
+//! [`SignInResponse::verify()`].
+
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{utils::signed_data_hash, Result},
+    types::block::{
+        address::{Address, Bech32Address},
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature},
+        ConvertTo,
+    },
+};
+
+/// Length of the random nonce of a challenge, in bytes.
+const SIGN_IN_NONCE_LENGTH: usize = 16;
+
+/// A challenge of a web service to sign in with an address.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInChallenge {
+    /// The domain of the service the user signs in to, e.g. `example.com`.
+    pub domain: String,
+    /// The address the user signs in with.
+    pub address: Bech32Address,
+    /// A statement shown to the user, e.g. the terms of service being accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement: Option<String>,
+    /// A random nonce, the service only accepts a response with it once.
+    pub nonce: String,
+    /// The UNIX timestamp in seconds the challenge was created at.
+    pub issued_at: u32,
+    /// The UNIX timestamp in seconds after which the challenge can't be used anymore.
+    pub expires_at: u32,
+}
+
+impl SignInChallenge {
+    /// Creates a challenge for the address with a random nonce, valid for the given duration from now.
+    pub fn new(domain: impl Into<String>, address: impl ConvertTo<Bech32Address>, validity: Duration) -> Result<Self> {
+        let mut nonce = [0u8; SIGN_IN_NONCE_LENGTH];
+        crypto::utils::rand::fill(&mut nonce)?;
+        let issued_at = crate::utils::unix_timestamp_now().as_secs() as u32;
+
+        Ok(Self {
+            domain: domain.into(),
+            address: address.convert()?,
+            statement: None,
+            nonce: prefix_hex::encode(nonce),
+            issued_at,
+            expires_at: issued_at.saturating_add(validity.as_secs() as u32),
+        })
+    }
+
+    /// Sets the statement shown to the user.
+    pub fn with_statement(mut self, statement: impl Into<String>) -> Self {
+        self.statement.replace(statement.into());
+        self
+    }
+
+    /// Returns the message that gets signed, readable by the user before signing.
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} wants you to sign in with your IOTA address:\n{}\n\n",
+            self.domain, self.address
+        );
+        if let Some(statement) = &self.statement {
+            message.push_str(statement);
+            message.push_str("\n\n");
+        }
+        message.push_str(&format!(
+            "Nonce: {}\nIssued At: {}\nExpiration Time: {}",
+            self.nonce, self.issued_at, self.expires_at
+        ));
+        message
+    }
+}
+
+/// Reasons a [`SignInResponse`] isn't accepted.
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SignInError {
+    /// The challenge was created for another domain.
+    #[error("the challenge is bound to domain {0}")]
+    DomainMismatch(String),
+    /// The challenge has another nonce than the one of the service.
+    #[error("the nonce of the challenge doesn't match")]
+    NonceMismatch,
+    /// The challenge isn't valid yet, its timestamp is in the future.
+    #[error("the challenge is issued at {0}, in the future")]
+    NotYetValid(u32),
+    /// The challenge expired.
+    #[error("the challenge expired at {0}")]
+    Expired(u32),
+    /// The address can't sign, only Ed25519 addresses are supported.
+    #[error("address kind {0} can't sign in")]
+    UnsupportedAddress(u8),
+    /// The signature wasn't made by the key of the address or not for the challenge.
+    #[error("invalid signature: {0}")]
+    InvalidSignature(String),
+}
+
+/// A challenge signed with the key of its address.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignInResponse {
+    /// The signed challenge.
+    pub challenge: SignInChallenge,
+    /// The signature of the message of the challenge.
+    pub signature: Ed25519Signature,
+}
+
+impl SignInResponse {
+    /// Verifies that the challenge is for the domain and nonce of the service, that it's valid at `current_time`, a
+    /// UNIX timestamp in seconds, and that it was signed by the key of its address. The service needs to make sure
+    /// the nonce isn't accepted again afterwards.
+    pub fn verify(&self, domain: &str, nonce: &str, current_time: u32) -> core::result::Result<(), SignInError> {
+        let challenge = &self.challenge;
+        if challenge.domain != domain {
+            return Err(SignInError::DomainMismatch(challenge.domain.clone()));
+        }
+        if challenge.nonce != nonce {
+            return Err(SignInError::NonceMismatch);
+        }
+        if current_time < challenge.issued_at {
+            return Err(SignInError::NotYetValid(challenge.issued_at));
+        }
+        if current_time >= challenge.expires_at {
+            return Err(SignInError::Expired(challenge.expires_at));
+        }
+
+        match challenge.address.inner() {
+            Address::Ed25519(address) => self
+                .signature
+                .is_valid(&signed_data_hash(challenge.message().as_bytes()), address)
+                .map_err(|e| SignInError::InvalidSignature(e.to_string())),
+            address => Err(SignInError::UnsupportedAddress(address.kind())),
+        }
+    }
+}
+
+/// Dto for a [`SignInResponse`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignInResponseDto {
+    /// The signed challenge.
+    pub challenge: SignInChallenge,
+    /// The signature of the message of the challenge.
+    pub signature: Ed25519SignatureDto,
+}
+
+impl From<&SignInResponse> for SignInResponseDto {
+    fn from(value: &SignInResponse) -> Self {
+        Self {
+            challenge: value.challenge.clone(),
+            signature: Ed25519SignatureDto::from(&value.signature),
+        }
+    }
+}
+
+impl TryFrom<SignInResponseDto> for SignInResponse {
+    type Error = crate::types::block::Error;
+
+    fn try_from(value: SignInResponseDto) -> core::result::Result<Self, Self::Error> {
+        Ok(Self {
+            challenge: value.challenge,
+            signature: Ed25519Signature::try_from(value.signature)?,
+        })
+    }
+}
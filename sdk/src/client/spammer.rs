@@ -0,0 +1,243 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sending blocks at a fixed rate to put load on a network, for load tests of development and test networks.
+//!
+//! All workers share one rate limiter, so the number of workers only needs to be increased if sending a single block,
+//! including its PoW, takes longer than the interval between two blocks. Value transactions are sent one at a time
+//! and awaited until they're included, so they never conflict, the other workers keep sending tagged data blocks
+//! meanwhile.
+
+use core::{
+    num::{NonZeroU32, NonZeroUsize},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use tokio::sync::Mutex;
+
+use crate::{
+    client::{api::GetAddressesOptions, secret::SecretManager, Client, Result},
+    types::block::{
+        address::Bech32Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    },
+};
+
+/// Options for spamming a network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpammerOptions {
+    /// The number of blocks sent per second by all workers together.
+    pub blocks_per_second: NonZeroU32,
+    /// The number of workers sending blocks concurrently.
+    pub workers: NonZeroUsize,
+    /// The number of blocks, including failed ones, after which the spammer stops. It only stops when dropped if
+    /// neither this nor the duration is set.
+    pub block_count: Option<u64>,
+    /// The time after which the spammer stops.
+    pub duration: Option<Duration>,
+    /// The tag of the tagged data blocks.
+    pub tag: Vec<u8>,
+    /// The interval in which the statistics are logged.
+    pub report_interval: Duration,
+}
+
+impl Default for SpammerOptions {
+    fn default() -> Self {
+        Self {
+            blocks_per_second: NonZeroU32::new(1).unwrap(),
+            workers: NonZeroUsize::new(1).unwrap(),
+            block_count: None,
+            duration: None,
+            tag: b"iota-sdk-spammer".to_vec(),
+            report_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// A funded account that sends value transactions to itself while spamming.
+#[derive(Clone, Copy, Debug)]
+pub struct ValueSpam<'a> {
+    /// The secret manager controlling the funds.
+    pub secret_manager: &'a SecretManager,
+    /// The coin type the addresses of the account are derived with.
+    pub coin_type: u32,
+    /// The account index the addresses of the account are derived with.
+    pub account_index: u32,
+}
+
+/// The statistics of a spammer run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpamStatistics {
+    /// The number of sent tagged data blocks.
+    pub data_blocks: u64,
+    /// The number of sent and included value transactions.
+    pub value_blocks: u64,
+    /// The number of blocks that failed to be sent.
+    pub failed_blocks: u64,
+    /// The time the spammer ran.
+    pub elapsed: Duration,
+}
+
+impl SpamStatistics {
+    /// Returns the average number of sent blocks per second.
+    pub fn blocks_per_second(&self) -> f64 {
+        let elapsed = self.elapsed.as_secs_f64();
+        if elapsed == 0.0 {
+            return 0.0;
+        }
+        (self.data_blocks + self.value_blocks) as f64 / elapsed
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    started: AtomicU64,
+    data_blocks: AtomicU64,
+    value_blocks: AtomicU64,
+    failed_blocks: AtomicU64,
+}
+
+impl Counters {
+    fn statistics(&self, elapsed: Duration) -> SpamStatistics {
+        SpamStatistics {
+            data_blocks: self.data_blocks.load(Ordering::Relaxed),
+            value_blocks: self.value_blocks.load(Ordering::Relaxed),
+            failed_blocks: self.failed_blocks.load(Ordering::Relaxed),
+            elapsed,
+        }
+    }
+}
+
+impl Client {
+    /// Sends tagged data blocks at the rate of the options, and value transactions of the account if one is provided,
+    /// until the block count or duration of the options is reached. Blocks that fail to be sent are counted, but
+    /// don't stop the spammer.
+    pub async fn spam(&self, options: SpammerOptions, value_spam: Option<ValueSpam<'_>>) -> Result<SpamStatistics> {
+        log::debug!("[spam] {options:?}");
+        let value_spam = match value_spam {
+            Some(value_spam) => {
+                let address = value_spam
+                    .secret_manager
+                    .generate_ed25519_addresses(
+                        GetAddressesOptions::from_client(self)
+                            .await?
+                            .with_coin_type(value_spam.coin_type)
+                            .with_account_index(value_spam.account_index)
+                            .with_range(0..1),
+                    )
+                    .await?[0];
+                Some((value_spam, address))
+            }
+            None => None,
+        };
+
+        let start_time = instant::Instant::now();
+        let counters = Counters::default();
+        let rate_limiter = Mutex::new(tokio::time::interval(
+            Duration::from_secs(1) / options.blocks_per_second.get(),
+        ));
+        let value_lock = Mutex::new(());
+
+        let workers = futures::future::join_all(
+            (0..options.workers.get())
+                .map(|_| self.spam_worker(&options, value_spam.as_ref(), &rate_limiter, &value_lock, &counters)),
+        );
+        let run = async {
+            match options.duration {
+                Some(duration) => {
+                    // Workers that are still sending a block are dropped with it
+                    let _ = tokio::time::timeout(duration, workers).await;
+                }
+                None => {
+                    workers.await;
+                }
+            }
+        };
+        let report = async {
+            let mut interval = tokio::time::interval(options.report_interval);
+            // The first tick completes immediately
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let statistics = counters.statistics(start_time.elapsed());
+                log::info!(
+                    "[spam] {} data blocks, {} value blocks, {} failed, {:.2} blocks per second",
+                    statistics.data_blocks,
+                    statistics.value_blocks,
+                    statistics.failed_blocks,
+                    statistics.blocks_per_second()
+                );
+            }
+        };
+
+        tokio::select! {
+            _ = run => {}
+            _ = report => {}
+        }
+
+        Ok(counters.statistics(start_time.elapsed()))
+    }
+
+    async fn spam_worker(
+        &self,
+        options: &SpammerOptions,
+        value_spam: Option<&(ValueSpam<'_>, Bech32Address)>,
+        rate_limiter: &Mutex<tokio::time::Interval>,
+        value_lock: &Mutex<()>,
+        counters: &Counters,
+    ) {
+        loop {
+            if let Some(block_count) = options.block_count {
+                if counters.started.fetch_add(1, Ordering::Relaxed) >= block_count {
+                    return;
+                }
+            }
+            rate_limiter.lock().await.tick().await;
+
+            // Only one value transaction is in flight at a time, its inputs are spent once it's included
+            let value_guard =
+                value_spam.and_then(|value_spam| value_lock.try_lock().ok().map(|guard| (guard, value_spam)));
+            let result = match value_guard {
+                Some((_guard, (value_spam, address))) => self
+                    .send_value_spam(value_spam, address)
+                    .await
+                    .map(|_| &counters.value_blocks),
+                None => self
+                    .build_block()
+                    .with_tag(options.tag.clone())
+                    .with_data(counters.data_blocks.load(Ordering::Relaxed).to_le_bytes().to_vec())
+                    .finish()
+                    .await
+                    .map(|_| &counters.data_blocks),
+            };
+
+            match result {
+                Ok(counter) => {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    log::debug!("[spam] failed to send block: {e}");
+                    counters.failed_blocks.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    async fn send_value_spam(&self, value_spam: &ValueSpam<'_>, address: &Bech32Address) -> Result<()> {
+        let output = BasicOutputBuilder::new_with_minimum_storage_deposit(self.get_rent_structure().await?)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(self.get_token_supply().await?)?;
+        let block = self
+            .build_block()
+            .with_secret_manager(value_spam.secret_manager)
+            .with_coin_type(value_spam.coin_type)
+            .with_account_index(value_spam.account_index)
+            .with_outputs([output])?
+            .finish()
+            .await?;
+        self.retry_until_included(&block.id(), None, None).await?;
+
+        Ok(())
+    }
+}
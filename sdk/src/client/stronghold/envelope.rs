@@ -0,0 +1,174 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Envelope encryption of data keys with keys that never leave the vault.
+//!
+//! A data key, for example the key that encrypts the records of a wallet storage, is only stored wrapped by a key
+//! encryption key in the vault, so it can't be recovered without the snapshot and its password. Rotating the key
+//! encryption key only wraps the data key again, the data encrypted with it doesn't change.
+//!
+//! A wrapped key is `version || nonce || tag || ciphertext`, the version being the little endian `u32` of the key
+//! encryption key that wrapped it.
+
+use crypto::ciphers::{chacha::XChaCha20Poly1305, traits::Aead};
+use iota_stronghold::{
+    procedures::{self, AeadCipher},
+    Location,
+};
+use zeroize::Zeroizing;
+
+use super::{
+    common::{PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH},
+    Error, StrongholdAdapter,
+};
+
+/// Stronghold record path prefix of the key encryption keys, followed by their version.
+const KEY_ENCRYPTION_KEY_RECORD_PATH: &[u8] = b"key-encryption-key-";
+
+const VERSION_LENGTH: usize = core::mem::size_of::<u32>();
+
+fn key_encryption_key_location(version: u32) -> Location {
+    Location::generic(
+        SECRET_VAULT_PATH,
+        [KEY_ENCRYPTION_KEY_RECORD_PATH, &version.to_le_bytes()].concat(),
+    )
+}
+
+fn wrapped_key_version(wrapped_key: &[u8]) -> Result<u32, Error> {
+    if wrapped_key.len() != VERSION_LENGTH + XChaCha20Poly1305::NONCE_LENGTH + XChaCha20Poly1305::TAG_LENGTH + 32 {
+        return Err(Error::InvalidWrappedKey);
+    }
+    // PANIC: the length was checked.
+    Ok(u32::from_le_bytes(wrapped_key[..VERSION_LENGTH].try_into().unwrap()))
+}
+
+impl StrongholdAdapter {
+    /// Generates a random data key and returns it together with its wrapped form, which can be stored anywhere.
+    pub async fn generate_data_key(&self) -> Result<(Zeroizing<[u8; 32]>, Vec<u8>), Error> {
+        let mut data_key = Zeroizing::new([0u8; 32]);
+        crypto::utils::rand::fill(data_key.as_mut())?;
+        let wrapped_key = self.wrap_data_key(&data_key, 0).await?;
+
+        Ok((data_key, wrapped_key))
+    }
+
+    /// Unwraps a data key that was wrapped by a key encryption key of this Stronghold.
+    pub async fn unwrap_data_key(&self, wrapped_key: &[u8]) -> Result<Zeroizing<[u8; 32]>, Error> {
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+        let version = wrapped_key_version(wrapped_key)?;
+        let mut data = wrapped_key[VERSION_LENGTH..].to_vec();
+
+        let data_key = Zeroizing::new(
+            self.stronghold
+                .lock()
+                .await
+                .get_client(PRIVATE_DATA_CLIENT_PATH)?
+                .execute_procedure(procedures::AeadDecrypt {
+                    cipher: AeadCipher::XChaCha20Poly1305,
+                    associated_data: Vec::new(),
+                    nonce: data.drain(..XChaCha20Poly1305::NONCE_LENGTH).collect(),
+                    tag: data.drain(..XChaCha20Poly1305::TAG_LENGTH).collect(),
+                    ciphertext: data,
+                    key: key_encryption_key_location(version),
+                })?,
+        );
+
+        Ok(Zeroizing::new(
+            data_key.as_slice().try_into().map_err(|_| Error::InvalidWrappedKey)?,
+        ))
+    }
+
+    /// Wraps a data key with a new key encryption key and returns the new wrapped key. The previous key encryption
+    /// key is kept, so the previous wrapped key stays valid until it's removed with
+    /// [`remove_key_encryption_key()`](Self::remove_key_encryption_key()) once the new one is stored.
+    pub async fn rotate_key_encryption_key(&self, wrapped_key: &[u8]) -> Result<Vec<u8>, Error> {
+        let version = wrapped_key_version(wrapped_key)?;
+        let data_key = self.unwrap_data_key(wrapped_key).await?;
+
+        self.wrap_data_key(&data_key, version.checked_add(1).ok_or(Error::InvalidWrappedKey)?)
+            .await
+    }
+
+    /// Removes the key encryption key a data key was wrapped with, so the wrapped key can't be unwrapped anymore.
+    pub async fn remove_key_encryption_key(&self, wrapped_key: &[u8]) -> Result<(), Error> {
+        let location = key_encryption_key_location(wrapped_key_version(wrapped_key)?);
+
+        self.stronghold
+            .lock()
+            .await
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .vault(SECRET_VAULT_PATH)
+            .delete_secret(location.record_path())?;
+
+        self.write_stronghold_snapshot(None).await
+    }
+
+    async fn wrap_data_key(&self, data_key: &[u8; 32], version: u32) -> Result<Vec<u8>, Error> {
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+        let location = key_encryption_key_location(version);
+
+        let encrypted_key = {
+            let stronghold_client = self.stronghold.lock().await.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+
+            if !stronghold_client.record_exists(&location)? {
+                let mut key_encryption_key = Zeroizing::new(vec![0_u8; 32]);
+                crypto::utils::rand::fill(key_encryption_key.as_mut())?;
+                stronghold_client
+                    .vault(location.vault_path())
+                    .write_secret(location.clone(), key_encryption_key)?;
+            }
+
+            let mut nonce = [0; XChaCha20Poly1305::NONCE_LENGTH];
+            crypto::utils::rand::fill(&mut nonce)?;
+
+            // The output is `tag || ciphertext`
+            let encrypted_key = stronghold_client.execute_procedure(procedures::AeadEncrypt {
+                cipher: AeadCipher::XChaCha20Poly1305,
+                associated_data: Vec::new(),
+                nonce: nonce.to_vec(),
+                plaintext: data_key.to_vec(),
+                key: location,
+            })?;
+
+            [&version.to_le_bytes(), nonce.as_slice(), &encrypted_key].concat()
+        };
+
+        // The key encryption key must be persisted before anything gets encrypted with the data key
+        self.write_stronghold_snapshot(None).await?;
+
+        Ok(encrypted_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn wrap_rotate_unwrap() {
+        let snapshot_path = "test_stronghold_envelope.stronghold";
+        std::fs::remove_file(snapshot_path).unwrap_or(());
+
+        let stronghold = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(snapshot_path)
+            .unwrap();
+
+        let (data_key, wrapped_key) = stronghold.generate_data_key().await.unwrap();
+        assert_eq!(stronghold.unwrap_data_key(&wrapped_key).await.unwrap(), data_key);
+
+        let rotated_key = stronghold.rotate_key_encryption_key(&wrapped_key).await.unwrap();
+        assert_ne!(rotated_key, wrapped_key);
+        assert_eq!(stronghold.unwrap_data_key(&rotated_key).await.unwrap(), data_key);
+
+        stronghold.remove_key_encryption_key(&wrapped_key).await.unwrap();
+        assert!(stronghold.unwrap_data_key(&wrapped_key).await.is_err());
+        assert_eq!(stronghold.unwrap_data_key(&rotated_key).await.unwrap(), data_key);
+
+        std::fs::remove_file(snapshot_path).unwrap();
+    }
+}
@@ -13,6 +13,12 @@ pub enum Error {
     /// Invalid stronghold password.
     #[error("invalid stronghold password")]
     InvalidPassword,
+    /// An application record has an invalid namespace, key or value
+    #[error("invalid record: {0}")]
+    InvalidRecord(String),
+    /// A wrapped data key is malformed
+    #[error("invalid wrapped data key")]
+    InvalidWrappedKey,
     #[error("invalid json {0}")]
     Json(#[from] serde_json::Error),
     /// No password has been supplied to a Stronghold vault, or it has been cleared
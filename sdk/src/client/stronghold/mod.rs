@@ -7,6 +7,7 @@
 //!
 //! - Smart-card-like secret vault
 //! - Generic key-value, encrypted database
+//! - Record store for small secrets of applications, see [`insert_record()`]
 //!
 //! [`StrongholdAdapter`] respectively implements [`StorageAdapter`] and [`SecretManage`] for the above purposes
 //! using Stronghold. Type aliases `StrongholdStorageAdapter` and `StrongholdSecretManager` are also provided if one
@@ -41,14 +42,17 @@
 //! [`StorageAdapter`]: crate::client::storage::StorageAdapter
 //! [`SecretManage`]: crate::client::secret::SecretManage
 //! [`builder()`]: self::StrongholdAdapter::builder()
+//! [`insert_record()`]: self::StrongholdAdapter::insert_record()
 //! [`set_password()`]: self::StrongholdAdapter::set_password()
 //! [`set_timeout()`]: self::StrongholdAdapter::set_timeout()
 //! [`read_stronghold_snapshot()`]: self::StrongholdAdapter::read_stronghold_snapshot()
 //! [`write_stronghold_snapshot()`]: self::StrongholdAdapter::write_stronghold_snapshot()
 
 mod common;
+mod envelope;
 mod error;
 mod migration;
+mod record;
 mod secret;
 mod storage;
 
@@ -61,14 +65,11 @@ use std::{
 
 use iota_stronghold::{KeyProvider, SnapshotPath, Stronghold};
 use log::{debug, error, warn};
-use tokio::{
-    sync::{Mutex, MutexGuard},
-    task::JoinHandle,
-};
+use tokio::sync::{Mutex, MutexGuard};
 use zeroize::Zeroizing;
 
 pub(crate) use self::common::PRIVATE_DATA_CLIENT_PATH;
-pub use self::error::Error;
+pub use self::{error::Error, record::MAX_RECORD_LENGTH};
 use super::{storage::StorageAdapter, utils::Password};
 
 /// A wrapper on [Stronghold].
@@ -170,14 +171,9 @@ impl StrongholdAdapterBuilder {
     /// Builds a [`StrongholdAdapter`] from the configuration.
     ///
     /// If both `key` (via [`password()`]) and `timeout` (via [`timeout()`]) are set, then an asynchronous task would be
-    /// spawned in Tokio to purge ([zeroize]) `key` after `timeout`. There is a small delay (usually a few milliseconds)
-    /// from the return of this function to this task actually being spawned and set in the returned
-    /// [`StrongholdAdapter`].
-    ///
-    /// **This function must be called inside a Tokio runtime context (usually in an `async fn` invoked by a Tokio
-    /// runtime, either directly or indirectly)**, as it uses [tokio::spawn()], which requires a Tokio context.
-    /// Otherwise, the function would panic. If this is not desired, one needs to avoid calling [`password()`] and
-    /// [`timeout()`] during the building process.
+    /// spawned on the [executor](crate::client::runtime) to purge ([zeroize]) `key` after `timeout`. There is a small
+    /// delay (usually a few milliseconds) from the return of this function to this task actually being spawned and set
+    /// in the returned [`StrongholdAdapter`].
     ///
     /// [`password()`]: Self::password()
     /// [`timeout()`]: Self::timeout()
@@ -210,7 +206,7 @@ impl StrongholdAdapterBuilder {
         // If both `key` and `timeout` are set, then we spawn the task and keep its join handle.
         if let (true, Some(timeout)) = (has_key_provider, self.timeout) {
             let weak = Arc::downgrade(&timeout_task);
-            *timeout_task.try_lock().unwrap() = Some(tokio::spawn(task_key_clear(
+            *timeout_task.try_lock().unwrap() = Some(crate::client::runtime::spawn(task_key_clear(
                 weak,
                 stronghold.clone(),
                 key_provider.clone(),
@@ -242,7 +238,7 @@ impl StrongholdAdapter {
 
     /// Use an user-input password string to derive a key to use Stronghold.
     ///
-    /// This function will also spawn an asynchronous task in Tokio to automatically purge the derived key from
+    /// This function will also spawn an asynchronous task to automatically purge the derived key from
     /// `password` after `timeout` (if set).
     /// It will also try to load a snapshot to check if the provided password is correct, if not it's cleared and an
     /// error will be returned.
@@ -276,7 +272,7 @@ impl StrongholdAdapter {
 
             let key_provider = self.key_provider.clone();
 
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
+            *self.timeout_task.lock().await = Some(crate::client::runtime::spawn(task_key_clear(
                 Arc::downgrade(&self.timeout_task),
                 self.stronghold.clone(),
                 key_provider,
@@ -334,7 +330,7 @@ impl StrongholdAdapter {
                     if let Some(timeout) = self.timeout {
                         let key_provider = self.key_provider.clone();
 
-                        *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
+                        *self.timeout_task.lock().await = Some(crate::client::runtime::spawn(task_key_clear(
                             Arc::downgrade(&self.timeout_task),
                             self.stronghold.clone(),
                             key_provider,
@@ -375,7 +371,7 @@ impl StrongholdAdapter {
                 if let Some(timeout) = self.timeout {
                     let key_provider = self.key_provider.clone();
 
-                    *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
+                    *self.timeout_task.lock().await = Some(crate::client::runtime::spawn(task_key_clear(
                         Arc::downgrade(&self.timeout_task),
                         self.stronghold.clone(),
                         key_provider,
@@ -394,7 +390,7 @@ impl StrongholdAdapter {
         if let Some(timeout) = self.timeout {
             let key_provider = self.key_provider.clone();
 
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
+            *self.timeout_task.lock().await = Some(crate::client::runtime::spawn(task_key_clear(
                 Arc::downgrade(&self.timeout_task),
                 self.stronghold.clone(),
                 key_provider,
@@ -452,7 +448,7 @@ impl StrongholdAdapter {
         if let (Some(_), Some(timeout)) = (self.key_provider.lock().await.as_ref(), self.timeout) {
             let key_provider = self.key_provider.clone();
 
-            *self.timeout_task.lock().await = Some(tokio::spawn(task_key_clear(
+            *self.timeout_task.lock().await = Some(crate::client::runtime::spawn(task_key_clear(
                 Arc::downgrade(&self.timeout_task),
                 self.stronghold.clone(),
                 key_provider,
@@ -545,16 +541,16 @@ impl StrongholdAdapter {
     }
 }
 
-type TaskHandle = JoinHandle<()>;
+type TaskHandle = crate::client::runtime::JoinHandle<()>;
 
-/// The asynchronous key clearing task purging `key` after `timeout` spent in Tokio.
+/// The asynchronous key clearing task purging `key` after `timeout`.
 async fn task_key_clear(
     task: Weak<Mutex<Option<TaskHandle>>>,
     stronghold: Arc<Mutex<Stronghold>>,
     key_provider: Arc<Mutex<Option<KeyProvider>>>,
     timeout: Duration,
 ) {
-    tokio::time::sleep(timeout).await;
+    crate::client::runtime::sleep(timeout).await;
 
     // If the weak pointer cannot upgrade, that means the secret manager has been dropped,
     // so we can just exit.
@@ -0,0 +1,120 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A generic record store for small secrets of applications, like API keys or the HMAC secrets of webhooks, kept in the
+//! same encrypted snapshot as the keys of the wallet.
+//!
+//! Records are stored under the namespace of an application, so they can't overwrite the records of the wallet or of
+//! other applications, and are encrypted like the records of the storage. Every change is written to the snapshot.
+
+use zeroize::Zeroizing;
+
+use super::{Error, StrongholdAdapter};
+use crate::client::storage::StorageAdapter;
+
+/// Store key prefix of the application records, followed by the namespace and the key of the record.
+const RECORD_KEY_PREFIX: &str = "app-record/";
+
+/// The maximum length of the value of a record, the store is only meant for small secrets.
+pub const MAX_RECORD_LENGTH: usize = 4096;
+
+fn record_key(namespace: &str, key: &str) -> Result<String, Error> {
+    if namespace.is_empty() || namespace.contains('/') {
+        return Err(Error::InvalidRecord(format!("invalid namespace `{namespace}`")));
+    }
+    if key.is_empty() {
+        return Err(Error::InvalidRecord("empty key".to_owned()));
+    }
+    Ok(format!("{RECORD_KEY_PREFIX}{namespace}/{key}"))
+}
+
+impl StrongholdAdapter {
+    /// Stores a record of an application, replacing the one with the same key. The namespace, e.g. the name of the
+    /// application, must not be empty or contain a `/`, and the value can have at most [`MAX_RECORD_LENGTH`] bytes.
+    pub async fn insert_record(&self, namespace: &str, key: &str, value: &[u8]) -> Result<(), Error> {
+        let record_key = record_key(namespace, key)?;
+        if value.len() > MAX_RECORD_LENGTH {
+            return Err(Error::InvalidRecord(format!(
+                "value of {} bytes exceeds {MAX_RECORD_LENGTH} bytes",
+                value.len()
+            )));
+        }
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        self.set_bytes(&record_key, value).await?;
+        self.write_stronghold_snapshot(None).await
+    }
+
+    /// Returns the value of a record of an application.
+    pub async fn get_record(&self, namespace: &str, key: &str) -> Result<Option<Zeroizing<Vec<u8>>>, Error> {
+        let record_key = record_key(namespace, key)?;
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        Ok(self.get_bytes(&record_key).await?.map(Zeroizing::new))
+    }
+
+    /// Deletes a record of an application.
+    pub async fn delete_record(&self, namespace: &str, key: &str) -> Result<(), Error> {
+        let record_key = record_key(namespace, key)?;
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        self.delete(&record_key).await?;
+        self.write_stronghold_snapshot(None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn insert_get_delete_record() {
+        let snapshot_path = "test_stronghold_record.stronghold";
+        std::fs::remove_file(snapshot_path).unwrap_or(());
+
+        let stronghold = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(snapshot_path)
+            .unwrap();
+
+        assert!(stronghold.get_record("payroll", "api-key").await.unwrap().is_none());
+        stronghold.insert_record("payroll", "api-key", b"secret").await.unwrap();
+        assert_eq!(
+            stronghold
+                .get_record("payroll", "api-key")
+                .await
+                .unwrap()
+                .unwrap()
+                .as_slice(),
+            b"secret"
+        );
+        // Namespaces are separated
+        assert!(stronghold.get_record("shop", "api-key").await.unwrap().is_none());
+
+        assert!(matches!(
+            stronghold.insert_record("pay/roll", "api-key", b"secret").await,
+            Err(Error::InvalidRecord(_))
+        ));
+        assert!(matches!(
+            stronghold.insert_record("payroll", "", b"secret").await,
+            Err(Error::InvalidRecord(_))
+        ));
+        assert!(matches!(
+            stronghold
+                .insert_record("payroll", "api-key", &[0; MAX_RECORD_LENGTH + 1])
+                .await,
+            Err(Error::InvalidRecord(_))
+        ));
+
+        stronghold.delete_record("payroll", "api-key").await.unwrap();
+        assert!(stronghold.get_record("payroll", "api-key").await.unwrap().is_none());
+
+        std::fs::remove_file(snapshot_path).unwrap();
+    }
+}
@@ -468,7 +468,7 @@ impl StrongholdAdapter {
 
         // Check if the mnemonic is valid.
         crypto::keys::bip39::wordlist::verify(&trimmed_mnemonic, &crypto::keys::bip39::wordlist::ENGLISH)
-            .map_err(|e| Error::InvalidMnemonic(format!("{e:?}")))?;
+            .map_err(|e| Error::InvalidMnemonic(crate::client::utils::mnemonic_error_message(&e)))?;
 
         // We need to check if there has been a mnemonic stored in Stronghold or not to prevent overwriting it.
         if self
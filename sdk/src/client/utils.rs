@@ -4,25 +4,32 @@
 //! Utility functions for IOTA
 
 use core::borrow::Borrow;
-use std::collections::HashMap;
 
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, Seed},
-    utils,
+    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Seed},
+    signatures::ed25519,
 };
 use serde::{Deserialize, Serialize};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::{Client, ClientInner};
 use crate::{
-    client::{Error, Result},
+    client::{
+        constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
+        node_manager::http_client::HttpClient,
+        Error, Result,
+    },
     types::block::{
         address::{Address, Bech32Address, Ed25519Address, Hrp, ToBech32Ext},
         output::{AliasId, NftId},
         payload::TaggedDataPayload,
         ConvertTo,
     },
+    utils::{
+        mnemonic::{self, MnemonicLanguage, DEFAULT_MNEMONIC_WORD_COUNT},
+        parse::{InputParser, ParseMode},
+    },
 };
 
 /// Transforms bech32 to hex
@@ -34,15 +41,15 @@ pub fn bech32_to_hex(bech32: impl ConvertTo<Bech32Address>) -> Result<String> {
     })
 }
 
-/// Transforms a hex encoded address to a bech32 encoded address
+/// Transforms a hex encoded address to a bech32 encoded address, the `0x` prefix is optional
 pub fn hex_to_bech32(hex: &str, bech32_hrp: impl ConvertTo<Hrp>) -> Result<Bech32Address> {
-    let address: Ed25519Address = hex.parse::<Ed25519Address>()?;
+    let address = InputParser::new(ParseMode::Lenient).parse_id::<Ed25519Address>(hex)?;
     Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
-/// Transforms a prefix hex encoded public key to a bech32 encoded address
+/// Transforms a hex encoded public key to a bech32 encoded address, the `0x` prefix is optional
 pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: impl ConvertTo<Hrp>) -> Result<Bech32Address> {
-    let public_key: [u8; Ed25519Address::LENGTH] = prefix_hex::decode(hex)?;
+    let public_key = InputParser::new(ParseMode::Lenient).parse_bytes::<{ Ed25519Address::LENGTH }>(hex)?;
 
     let address = Blake2b256::digest(public_key)
         .try_into()
@@ -51,14 +58,10 @@ pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: impl ConvertTo<Hr
     Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
-/// Generates a new mnemonic.
+/// Generates a new mnemonic with 24 English words, see [`crate::utils::mnemonic`] for other word counts and
+/// languages.
 pub fn generate_mnemonic() -> Result<Mnemonic> {
-    let mut entropy = [0u8; 32];
-    utils::rand::fill(&mut entropy)?;
-    let mnemonic = wordlist::encode(&entropy, &crypto::keys::bip39::wordlist::ENGLISH)
-        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
-    entropy.zeroize();
-    Ok(mnemonic)
+    mnemonic::generate_mnemonic(DEFAULT_MNEMONIC_WORD_COUNT, MnemonicLanguage::English)
 }
 
 /// Returns a hex encoded seed for a mnemonic.
@@ -66,42 +69,63 @@ pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String
     Ok(prefix_hex::encode(mnemonic_to_seed(mnemonic)?.as_ref()))
 }
 
-/// Returns a seed for a mnemonic.
+/// Returns a seed for an English mnemonic without passphrase.
 pub fn mnemonic_to_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<Seed> {
-    // first we check if the mnemonic is valid to give meaningful errors
-    verify_mnemonic(mnemonic.borrow())?;
-    Ok(crypto::keys::bip39::mnemonic_to_seed(
-        mnemonic.borrow(),
-        &Passphrase::default(),
-    ))
+    mnemonic::mnemonic_to_seed(mnemonic, MnemonicLanguage::English, None)
 }
 
-/// Verifies that a &str is a valid mnemonic.
+/// Verifies that a &str is a valid English mnemonic.
 pub fn verify_mnemonic(mnemonic: impl Borrow<MnemonicRef>) -> Result<()> {
-    crypto::keys::bip39::wordlist::verify(mnemonic.borrow(), &crypto::keys::bip39::wordlist::ENGLISH)
-        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
-    Ok(())
+    mnemonic::verify_mnemonic(mnemonic, MnemonicLanguage::English)
+}
+
+/// Describes a mnemonic error without the words of the mnemonic, so they don't end up in logs.
+pub(crate) fn mnemonic_error_message(error: &wordlist::Error) -> String {
+    match error {
+        wordlist::Error::NoSuchWord(_) => "NoSuchWord".to_string(),
+        e => format!("{e:?}"),
+    }
+}
+
+/// Prefix of the data signed with [`SecretManager::sign_data()`](crate::client::secret::SecretManager::sign_data), so
+/// that signed data can never be mistaken for a transaction essence.
+pub const SIGNED_DATA_PREFIX: &[u8] = b"IOTA Signed Data:\n";
+
+/// Returns the hash that gets signed by [`SecretManager::sign_data()`](crate::client::secret::SecretManager::sign_data)
+/// for the given data.
+pub fn signed_data_hash(data: &[u8]) -> [u8; 32] {
+    Blake2b256::new()
+        .chain_update(SIGNED_DATA_PREFIX)
+        .chain_update(data)
+        .finalize()
+        .into()
 }
 
-/// Requests funds from a faucet
+/// Verifies an Ed25519 signature created with
+/// [`SecretManager::sign_data()`](crate::client::secret::SecretManager::sign_data) over the given data.
+pub fn verify_ed25519_signature(public_key: &ed25519::PublicKey, signature: &ed25519::Signature, data: &[u8]) -> bool {
+    public_key.verify(signature, &signed_data_hash(data))
+}
+
+/// Requests funds from a faucet, rate limited requests are retried.
 pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address) -> Result<String> {
-    let mut map = HashMap::new();
-    map.insert("address", bech32_address.to_string());
-
-    let client = reqwest::Client::new();
-    let faucet_response = client
-        .post(url)
-        .json(&map)
-        .send()
-        .await
-        .map_err(|err| Error::Node(err.into()))?
-        .text()
-        .await
-        .map_err(|err| Error::Node(err.into()))?;
-    Ok(faucet_response)
+    super::api::request_funds(
+        &HttpClient::new(DEFAULT_USER_AGENT.to_string()),
+        DEFAULT_API_TIMEOUT,
+        url,
+        bech32_address,
+        &Default::default(),
+    )
+    .await
 }
 
 impl ClientInner {
+    /// Returns a parser for user-provided addresses and IDs, which checks bech32 addresses against the HRP of the
+    /// network.
+    pub async fn input_parser(&self, mode: ParseMode) -> Result<InputParser> {
+        Ok(InputParser::new(mode).with_bech32_hrp(self.get_bech32_hrp().await?))
+    }
+
     /// Transforms a hex encoded address to a bech32 encoded address
     pub async fn hex_to_bech32(
         &self,
@@ -172,6 +196,16 @@ impl Client {
         mnemonic_to_hex_seed(mnemonic)
     }
 
+    /// Verifies an Ed25519 signature created with
+    /// [`SecretManager::sign_data()`](crate::client::secret::SecretManager::sign_data) over the given data.
+    pub fn verify_ed25519_signature(
+        public_key: &ed25519::PublicKey,
+        signature: &ed25519::Signature,
+        data: &[u8],
+    ) -> bool {
+        verify_ed25519_signature(public_key, signature, data)
+    }
+
     /// UTF-8 encodes the `tag` of a given TaggedDataPayload.
     pub fn tag_to_utf8(payload: &TaggedDataPayload) -> Result<String> {
         String::from_utf8(payload.tag().to_vec()).map_err(|_| Error::TaggedData("found invalid UTF-8".to_string()))
@@ -57,6 +57,9 @@ impl MinerCancel {
 pub struct MinerBuilder {
     num_workers: Option<usize>,
     cancel: Option<MinerCancel>,
+    niceness: Option<i32>,
+    #[cfg(feature = "rayon")]
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
 }
 
 impl MinerBuilder {
@@ -77,11 +80,39 @@ impl MinerBuilder {
         self
     }
 
+    /// Sets the niceness of the worker threads, from -20 (highest priority) to 19 (lowest priority). It's only applied
+    /// on Linux, where it can be set per thread, and a higher priority than the one of the process requires permission.
+    pub fn with_niceness(mut self, niceness: impl Into<Option<i32>>) -> Self {
+        self.niceness = niceness.into();
+        self
+    }
+
+    /// Sets a thread pool the workers are run on, instead of spawning a thread for each of them. The niceness isn't
+    /// applied to the threads of the pool, and the number of workers defaults to the number of threads of the pool.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn with_thread_pool(mut self, thread_pool: impl Into<Option<Arc<rayon::ThreadPool>>>) -> Self {
+        self.thread_pool = thread_pool.into();
+        self
+    }
+
     /// Builds the [`Miner`].
     pub fn finish(self) -> Miner {
+        #[cfg(feature = "rayon")]
+        let num_workers = self.num_workers.unwrap_or_else(|| {
+            self.thread_pool
+                .as_ref()
+                .map_or_else(num_cpus::get, |thread_pool| thread_pool.current_num_threads())
+        });
+        #[cfg(not(feature = "rayon"))]
+        let num_workers = self.num_workers.unwrap_or_else(num_cpus::get);
+
         Miner {
-            num_workers: self.num_workers.unwrap_or_else(num_cpus::get),
+            num_workers,
             cancel: self.cancel.unwrap_or_default(),
+            niceness: self.niceness,
+            #[cfg(feature = "rayon")]
+            thread_pool: self.thread_pool,
         }
     }
 }
@@ -90,6 +121,9 @@ impl MinerBuilder {
 pub struct Miner {
     num_workers: usize,
     cancel: MinerCancel,
+    niceness: Option<i32>,
+    #[cfg(feature = "rayon")]
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
 }
 
 impl Miner {
@@ -142,12 +176,38 @@ impl Miner {
 
         b1t6::encode::<T1B1Buf>(&hash).iter().for_each(|t| pow_digest.push(t));
 
+        #[cfg(feature = "rayon")]
+        if let Some(thread_pool) = &self.thread_pool {
+            let nonce = std::sync::Mutex::new(None);
+
+            thread_pool.scope(|scope| {
+                for i in 0..self.num_workers {
+                    let start_nonce = i as u64 * worker_width;
+                    let _cancel = self.cancel.clone();
+                    let _pow_digest = pow_digest.clone();
+                    let nonce = &nonce;
+
+                    scope.spawn(move |_| {
+                        if let Some(mined_nonce) = Self::worker(_cancel, _pow_digest, start_nonce, target_zeros) {
+                            nonce.lock().unwrap().replace(mined_nonce);
+                        }
+                    });
+                }
+            });
+
+            return nonce.into_inner().unwrap();
+        }
+
         for i in 0..self.num_workers {
             let start_nonce = i as u64 * worker_width;
             let _cancel = self.cancel.clone();
             let _pow_digest = pow_digest.clone();
+            let niceness = self.niceness;
 
             workers.push(thread::spawn(move || {
+                if let Some(niceness) = niceness {
+                    set_thread_niceness(niceness);
+                }
                 Self::worker(_cancel, _pow_digest, start_nonce, target_zeros)
             }));
         }
@@ -162,6 +222,18 @@ impl Miner {
     }
 }
 
+/// Sets the niceness of the current thread. `setpriority()` only applies to the calling thread on Linux, on other
+/// platforms it would change the priority of the whole process, so it's not set there.
+#[allow(unused_variables)]
+fn set_thread_niceness(niceness: i32) {
+    // Failing to set it, e.g. because of missing permissions, isn't a reason to not do the PoW
+    #[cfg(target_os = "linux")]
+    // SAFETY: setpriority() only reads its arguments
+    unsafe {
+        libc::setpriority(libc::PRIO_PROCESS, 0, niceness);
+    }
+}
+
 fn _get_miner(bytes: &[u8], min_pow_score: u32, num_workers: usize) -> Option<u64> {
     MinerBuilder::new()
         .with_num_workers(num_workers)
@@ -5,11 +5,17 @@ use alloc::{string::String, vec::Vec};
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::{
-    output::{dto::OutputDto, OutputId, OutputMetadata, OutputWithMetadata},
-    payload::milestone::{option::dto::ReceiptMilestoneOptionDto, MilestoneId},
-    protocol::ProtocolParameters,
-    BlockId,
+use crate::types::{
+    block::{
+        output::{dto::OutputDto, OutputId, OutputMetadata, OutputWithMetadata},
+        payload::milestone::{
+            option::dto::ReceiptMilestoneOptionDto, MilestoneId, MilestoneIndex, ReceiptMilestoneOption,
+        },
+        protocol::ProtocolParameters,
+        semantic::{ConflictError, ConflictReason},
+        BlockId, Error,
+    },
+    TryFromDto, ValidationParams,
 };
 
 /// Response of GET /api/core/v2/info.
@@ -154,6 +160,14 @@ pub struct BlockMetadataResponse {
     pub should_reattach: Option<bool>,
 }
 
+impl BlockMetadataResponse {
+    /// Returns the decoded conflict reason of the block, if any. Fails for reasons this version doesn't know, the raw
+    /// number stays available in the `conflict_reason` field.
+    pub fn conflict_reason(&self) -> Result<Option<ConflictReason>, ConflictError> {
+        self.conflict_reason.map(ConflictReason::try_from).transpose()
+    }
+}
+
 /// Response of GET /api/core/v2/outputs/{output_id}.
 /// Returns an output and its metadata.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -186,6 +200,53 @@ pub struct ReceiptResponse {
     pub milestone_index: u32,
 }
 
+/// A receipt of funds migrated from the legacy network, with the index of the milestone that contained it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    /// The index of the milestone that contained the receipt.
+    pub milestone_index: u32,
+    /// The receipt milestone option.
+    pub receipt: ReceiptMilestoneOption,
+}
+
+impl Receipt {
+    /// Returns the index of the legacy milestone at which the funds were migrated.
+    pub fn migrated_at(&self) -> MilestoneIndex {
+        self.receipt.migrated_at()
+    }
+
+    /// Returns the ID of the milestone whose treasury output the treasury transaction of the receipt consumed.
+    pub fn consumed_treasury_milestone_id(&self) -> &MilestoneId {
+        self.receipt.transaction().input().milestone_id()
+    }
+
+    /// Returns the amount left in the treasury after the funds of the receipt were migrated.
+    pub fn remaining_treasury(&self) -> u64 {
+        self.receipt.transaction().output().amount()
+    }
+}
+
+impl From<&Receipt> for ReceiptResponse {
+    fn from(value: &Receipt) -> Self {
+        Self {
+            receipt: ReceiptMilestoneOptionDto::from(&value.receipt),
+            milestone_index: value.milestone_index,
+        }
+    }
+}
+
+impl TryFromDto for Receipt {
+    type Dto = ReceiptResponse;
+    type Error = Error;
+
+    fn try_from_dto_with_params_inner(dto: Self::Dto, params: ValidationParams<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            milestone_index: dto.milestone_index,
+            receipt: ReceiptMilestoneOption::try_from_dto_with_params(dto.receipt, params)?,
+        })
+    }
+}
+
 /// Response of:
 /// * GET /api/core/v2/receipts/{milestone_index}, returns all stored receipts for the given milestone index.
 /// * GET /api/core/v2/receipts, returns all stored receipts, independent of a milestone index.
@@ -289,3 +350,20 @@ pub struct WhiteFlagResponse {
 pub struct RoutesResponse {
     pub routes: Vec<String>,
 }
+
+/// Response of POST /api/core/v2/control/database/prune.
+/// Returns the index of the milestone up to which the database was pruned.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneDatabaseResponse {
+    pub index: u32,
+}
+
+/// Response of POST /api/core/v2/control/snapshots/create.
+/// Returns the milestone index and the file path of the created snapshot.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSnapshotsResponse {
+    pub index: u32,
+    pub file_path: String,
+}
@@ -12,20 +12,24 @@ use crate::types::block::{
     input::UtxoInput,
     output::{
         feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, MetadataFeatureLength,
-        NativeTokenCount, NftId, OutputIndex, StateMetadataLength, TagFeatureLength,
+        NativeTokenCount, NftId, OutputId, OutputIndex, StateMetadataLength, TagFeatureLength,
     },
     parent::ParentCount,
     payload::{
-        milestone::BinaryParametersLength, InputCount, MilestoneMetadataLength, MilestoneOptionCount, OutputCount,
-        ReceiptFundsCount, SignatureCount, TagLength, TaggedDataLength,
+        milestone::{BinaryParametersLength, MilestoneId},
+        transaction::TransactionId,
+        InputCount, MilestoneMetadataLength, MilestoneOptionCount, OutputCount, ReceiptFundsCount, SignatureCount,
+        TagLength, TaggedDataLength,
     },
     unlock::{UnlockCount, UnlockIndex},
+    BlockId,
 };
 
 /// Error occurring when creating/parsing/validating blocks.
 #[derive(Debug, PartialEq, Eq)]
 #[allow(missing_docs)]
 pub enum Error {
+    BlockIdMismatch { expected: BlockId, actual: BlockId },
     ConsumedAmountOverflow,
     ConsumedNativeTokensAmountOverflow,
     CreatedAmountOverflow,
@@ -92,18 +96,23 @@ pub enum Error {
     InvalidUnlockConditionCount(<UnlockConditionCount as TryFrom<usize>>::Error),
     InvalidUnlockConditionKind(u8),
     InvalidFoundryZeroSerialNumber,
+    MilestoneIndexMismatch { expected: u32, actual: u32 },
     MilestoneInvalidSignatureCount(<SignatureCount as TryFrom<usize>>::Error),
     MilestonePublicKeysSignaturesCountMismatch { key_count: usize, sig_count: usize },
     MilestoneOptionsNotUniqueSorted,
     MilestoneSignaturesNotUniqueSorted,
+    MilestoneTimestampMismatch { expected: u32, actual: u32 },
     MissingAddressUnlockCondition,
+    MissingInputWithEd25519Address,
     MissingGovernorUnlockCondition,
     MissingStateControllerUnlockCondition,
+    MissingTransactionPayload,
     NativeTokensNotUniqueSorted,
     NativeTokensNullAmount,
     NativeTokensOverflow,
     NetworkIdMismatch { expected: u64, actual: u64 },
     NonZeroStateIndexOrFoundryCounter,
+    OutputMismatch(OutputId),
     ParentsNotUniqueSorted,
     ProtocolVersionMismatch { expected: u8, actual: u8 },
     NonceNotFound,
@@ -115,6 +124,9 @@ pub enum Error {
     StorageDepositReturnOverflow,
     TailTransactionHashNotUnique { previous: usize, current: usize },
     TimelockUnlockConditionZero,
+    TransactionIdMismatch { expected: TransactionId, actual: TransactionId },
+    TreasuryAmountMismatch { expected: u64, actual: u64 },
+    TreasuryInputMismatch { expected: MilestoneId, actual: MilestoneId },
     UnallowedFeature { index: usize, kind: u8 },
     UnallowedUnlockCondition { index: usize, kind: u8 },
     UnlockConditionsNotUniqueSorted,
@@ -129,6 +141,9 @@ impl std::error::Error for Error {}
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::BlockIdMismatch { expected, actual } => {
+                write!(f, "block ID mismatch: expected {expected} but got {actual}")
+            }
             Self::ConsumedAmountOverflow => write!(f, "consumed amount overflow"),
             Self::ConsumedNativeTokensAmountOverflow => write!(f, "consumed native tokens amount overflow"),
             Self::CreatedAmountOverflow => write!(f, "created amount overflow"),
@@ -262,15 +277,23 @@ impl fmt::Display for Error {
                     "milestone public keys and signatures count mismatch: {key_count} != {sig_count}",
                 )
             }
+            Self::MilestoneIndexMismatch { expected, actual } => {
+                write!(f, "milestone index mismatch: expected {expected} but got {actual}")
+            }
             Self::MilestoneOptionsNotUniqueSorted => {
                 write!(f, "milestone options are not unique and/or sorted")
             }
             Self::MilestoneSignaturesNotUniqueSorted => {
                 write!(f, "milestone signatures are not unique and/or sorted")
             }
+            Self::MilestoneTimestampMismatch { expected, actual } => {
+                write!(f, "milestone timestamp mismatch: expected {expected} but got {actual}")
+            }
             Self::MissingAddressUnlockCondition => write!(f, "missing address unlock condition"),
+            Self::MissingInputWithEd25519Address => write!(f, "missing input with ed25519 address"),
             Self::MissingGovernorUnlockCondition => write!(f, "missing governor unlock condition"),
             Self::MissingStateControllerUnlockCondition => write!(f, "missing state controller unlock condition"),
+            Self::MissingTransactionPayload => write!(f, "missing transaction payload"),
             Self::NativeTokensNotUniqueSorted => write!(f, "native tokens are not unique and/or sorted"),
             Self::NativeTokensNullAmount => write!(f, "native tokens null amount"),
             Self::NativeTokensOverflow => write!(f, "native tokens overflow"),
@@ -280,6 +303,9 @@ impl fmt::Display for Error {
             Self::NonZeroStateIndexOrFoundryCounter => {
                 write!(f, "non zero state index or foundry counter while alias ID is all zero")
             }
+            Self::OutputMismatch(output_id) => {
+                write!(f, "output {output_id} doesn't match the output created by its transaction")
+            }
             Self::ParentsNotUniqueSorted => {
                 write!(f, "parents are not unique and/or sorted")
             }
@@ -319,6 +345,18 @@ impl fmt::Display for Error {
                     "timelock unlock condition with milestone index and timestamp set to 0",
                 )
             }
+            Self::TransactionIdMismatch { expected, actual } => {
+                write!(f, "transaction ID mismatch: expected {expected} but got {actual}")
+            }
+            Self::TreasuryAmountMismatch { expected, actual } => {
+                write!(f, "treasury amount mismatch: expected {expected} but got {actual}")
+            }
+            Self::TreasuryInputMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "treasury input mismatch: expected milestone ID {expected} but got {actual}"
+                )
+            }
             Self::UnallowedFeature { index, kind } => {
                 write!(f, "unallowed feature at index {index} with kind {kind}")
             }
@@ -14,6 +14,7 @@ mod state_transition;
 mod token_id;
 mod token_scheme;
 mod treasury;
+mod unlockability;
 
 ///
 pub mod alias;
@@ -65,9 +66,15 @@ pub use self::{
     token_scheme::{SimpleTokenScheme, TokenScheme},
     treasury::TreasuryOutput,
     unlock_condition::{UnlockCondition, UnlockConditions},
+    unlockability::Unlockability,
 };
 use super::protocol::ProtocolParameters;
-use crate::types::block::{address::Address, semantic::ValidationContext, Error};
+use crate::types::block::{
+    address::Address,
+    payload::{milestone::MilestonePayload, transaction::TransactionEssence, Payload},
+    semantic::ValidationContext,
+    Block, Error,
+};
 
 /// The maximum number of outputs of a transaction.
 pub const OUTPUT_COUNT_MAX: u16 = 128;
@@ -116,6 +123,52 @@ impl OutputWithMetadata {
     pub fn into_metadata(self) -> OutputMetadata {
         self.metadata
     }
+
+    /// Verifies that the output and its metadata match the block that created the output and the milestone that
+    /// booked it. Combined with a proof that the milestone references the block, this proves the existence of the
+    /// output independently of the node that returned it.
+    pub fn verify_inclusion(&self, block: &Block, milestone: &MilestonePayload) -> Result<(), Error> {
+        if block.id() != *self.metadata.block_id() {
+            return Err(Error::BlockIdMismatch {
+                expected: *self.metadata.block_id(),
+                actual: block.id(),
+            });
+        }
+
+        let transaction = match block.payload() {
+            Some(Payload::Transaction(transaction)) => transaction,
+            _ => return Err(Error::MissingTransactionPayload),
+        };
+
+        if transaction.id() != *self.metadata.transaction_id() {
+            return Err(Error::TransactionIdMismatch {
+                expected: *self.metadata.transaction_id(),
+                actual: transaction.id(),
+            });
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+
+        if essence.outputs().get(self.metadata.output_index() as usize) != Some(&self.output) {
+            return Err(Error::OutputMismatch(*self.metadata.output_id()));
+        }
+
+        if *milestone.essence().index() != self.metadata.milestone_index_booked() {
+            return Err(Error::MilestoneIndexMismatch {
+                expected: self.metadata.milestone_index_booked(),
+                actual: *milestone.essence().index(),
+            });
+        }
+
+        if milestone.essence().timestamp() != self.metadata.milestone_timestamp_booked() {
+            return Err(Error::MilestoneTimestampMismatch {
+                expected: self.metadata.milestone_timestamp_booked(),
+                actual: milestone.essence().timestamp(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// A generic output that can represent different types defining the deposit of funds.
@@ -350,6 +403,61 @@ impl Output {
         }
     }
 
+    /// Returns when the address can unlock this [`Output`], given the current milestone timestamp, by evaluating its
+    /// timelock and expiration unlock conditions. A storage deposit return unlock condition doesn't restrict who can
+    /// unlock the output, its amount only has to be returned by the transaction that unlocks it.
+    pub fn unlockable_by(&self, address: &Address, current_time: u32) -> Unlockability {
+        let (owner, unlock_conditions) = match self {
+            Self::Basic(output) => (output.address(), output.unlock_conditions()),
+            Self::Nft(output) => (output.address(), output.unlock_conditions()),
+            // Alias and foundry outputs can't have time based unlock conditions
+            Self::Alias(output) => {
+                return if output.state_controller_address() == address || output.governor_address() == address {
+                    Unlockability::Now { expires_at: None }
+                } else {
+                    Unlockability::Never
+                };
+            }
+            Self::Foundry(output) => {
+                return if Address::Alias(*output.alias_address()) == *address {
+                    Unlockability::Now { expires_at: None }
+                } else {
+                    Unlockability::Never
+                };
+            }
+            Self::Treasury(_) => return Unlockability::Never,
+        };
+        let timelock = unlock_conditions.timelock().map_or(0, |timelock| timelock.timestamp());
+
+        // The owner can unlock the output from the timelock until the expiration, the return address from the
+        // expiration on.
+        let window = match unlock_conditions.expiration() {
+            None => (owner == address).then_some((timelock, None)),
+            Some(expiration) => match (owner == address, expiration.return_address() == address) {
+                (true, true) => Some((timelock, None)),
+                (true, false) => {
+                    (timelock < expiration.timestamp()).then_some((timelock, Some(expiration.timestamp())))
+                }
+                (false, true) => Some((timelock.max(expiration.timestamp()), None)),
+                (false, false) => None,
+            },
+        };
+
+        match window {
+            Some((unlockable_at, expires_at)) if expires_at.map_or(true, |expires_at| current_time < expires_at) => {
+                if current_time >= unlockable_at {
+                    Unlockability::Now { expires_at }
+                } else {
+                    Unlockability::Later {
+                        unlockable_at,
+                        expires_at,
+                    }
+                }
+            }
+            _ => Unlockability::Never,
+        }
+    }
+
     ///
     pub fn verify_state_transition(
         current_state: Option<&Self>,
@@ -14,7 +14,7 @@ use crate::types::block::{
     address::{Address, Ed25519Address},
     output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
-        BasicOutputBuilder, NativeTokens, Output, OutputId,
+        BasicOutputBuilder, Feature, NativeTokens, Output, OutputId,
     },
     payload::milestone::MilestoneIndex,
     BlockId, Error,
@@ -173,6 +173,11 @@ impl MinimumStorageDepositBasicOutput {
         self
     }
 
+    pub fn with_features(mut self, features: impl IntoIterator<Item = impl Into<Feature>>) -> Self {
+        self.builder = self.builder.with_features(features);
+        self
+    }
+
     pub fn with_storage_deposit_return(mut self) -> Result<Self, Error> {
         self.builder = self
             .builder
@@ -0,0 +1,45 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Describes when an address can unlock an [`Output`](crate::types::block::output::Output).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", rename_all = "camelCase")
+)]
+pub enum Unlockability {
+    /// The output can be unlocked now.
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+    Now {
+        /// The milestone timestamp from which the output can't be unlocked by the address anymore, if any.
+        expires_at: Option<u32>,
+    },
+    /// The output can be unlocked once a timelock expired.
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+    Later {
+        /// The milestone timestamp from which the output can be unlocked.
+        unlockable_at: u32,
+        /// The milestone timestamp from which the output can't be unlocked by the address anymore, if any.
+        expires_at: Option<u32>,
+    },
+    /// The output can't be unlocked by the address, now or in the future.
+    Never,
+}
+
+impl Unlockability {
+    /// Returns whether the output can be unlocked now.
+    pub fn is_now(&self) -> bool {
+        matches!(self, Self::Now { .. })
+    }
+
+    /// Returns whether the output can only be unlocked later.
+    pub fn is_later(&self) -> bool {
+        matches!(self, Self::Later { .. })
+    }
+
+    /// Returns whether the output can't be unlocked anymore.
+    pub fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+}
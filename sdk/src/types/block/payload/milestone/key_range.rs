@@ -0,0 +1,53 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::string::String;
+
+use crate::types::block::payload::milestone::MilestoneIndex;
+
+/// A public key that is allowed to sign the milestones within a range of indexes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct MilestoneKeyRange {
+    /// The hex encoded public key, without prefix.
+    public_key: String,
+    /// The index of the first milestone the key can sign.
+    start_index: MilestoneIndex,
+    /// The index of the last milestone the key can sign, 0 if the key doesn't expire.
+    end_index: MilestoneIndex,
+}
+
+impl MilestoneKeyRange {
+    /// Creates a new [`MilestoneKeyRange`].
+    pub fn new(public_key: String, start_index: MilestoneIndex, end_index: MilestoneIndex) -> Self {
+        Self {
+            public_key,
+            start_index,
+            end_index,
+        }
+    }
+
+    /// Returns the public key of the [`MilestoneKeyRange`].
+    pub fn public_key(&self) -> &String {
+        &self.public_key
+    }
+
+    /// Returns the start index of the [`MilestoneKeyRange`].
+    pub fn start_index(&self) -> MilestoneIndex {
+        self.start_index
+    }
+
+    /// Returns the end index of the [`MilestoneKeyRange`].
+    pub fn end_index(&self) -> MilestoneIndex {
+        self.end_index
+    }
+
+    /// Returns whether the key can sign the milestone with the given index.
+    pub fn is_applicable(&self, index: MilestoneIndex) -> bool {
+        index >= self.start_index && (*self.end_index == 0 || index <= self.end_index)
+    }
+}
@@ -5,6 +5,7 @@
 
 mod essence;
 mod index;
+mod key_range;
 mod merkle;
 mod milestone_id;
 
@@ -22,6 +23,7 @@ use packable::{bounded::BoundedU8, prefix::VecPrefix, Packable};
 pub use self::{
     essence::MilestoneEssence,
     index::MilestoneIndex,
+    key_range::MilestoneKeyRange,
     merkle::MerkleRoot,
     milestone_id::MilestoneId,
     option::{MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption},
@@ -136,6 +138,25 @@ impl MilestonePayload {
 
         Ok(())
     }
+
+    /// Verifies that the [`MilestonePayload`] is signed by at least `min_threshold` of the keys that are applicable at
+    /// its index. All signatures have to be from applicable keys.
+    pub fn verify(
+        &self,
+        key_ranges: &[MilestoneKeyRange],
+        min_threshold: usize,
+    ) -> Result<(), MilestoneValidationError> {
+        let index = self.essence().index();
+        let mut applicable_public_keys = key_ranges
+            .iter()
+            .filter(|key_range| key_range.is_applicable(index))
+            .map(|key_range| key_range.public_key().clone())
+            .collect::<Vec<_>>();
+        applicable_public_keys.sort_unstable();
+        applicable_public_keys.dedup();
+
+        self.validate(&applicable_public_keys, min_threshold)
+    }
 }
 
 fn verify_signatures<const VERIFY: bool>(signatures: &[Signature]) -> Result<(), Error> {
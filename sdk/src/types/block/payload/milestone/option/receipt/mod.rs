@@ -16,7 +16,10 @@ use packable::{bounded::BoundedU16, prefix::VecPrefix, Packable, PackableExt};
 pub use self::{migrated_funds_entry::MigratedFundsEntry, tail_transaction_hash::TailTransactionHash};
 use crate::types::block::{
     output::OUTPUT_COUNT_RANGE,
-    payload::{milestone::MilestoneIndex, Payload, TreasuryTransactionPayload},
+    payload::{
+        milestone::{MilestoneId, MilestoneIndex},
+        Payload, TreasuryTransactionPayload,
+    },
     protocol::ProtocolParameters,
     Error,
 };
@@ -96,6 +99,17 @@ impl ReceiptMilestoneOption {
     pub fn amount(&self) -> u64 {
         self.funds.iter().map(|f| f.amount()).sum()
     }
+
+    /// Verifies that the treasury transaction of a [`ReceiptMilestoneOption`] consumes the treasury created by the
+    /// milestone with the given ID, which holds `treasury_amount`, and removes exactly the migrated funds from it.
+    pub fn verify_treasury_transition(
+        &self,
+        treasury_milestone_id: &MilestoneId,
+        treasury_amount: u64,
+    ) -> Result<(), Error> {
+        self.transaction()
+            .verify_migration(treasury_milestone_id, treasury_amount, self.amount())
+    }
 }
 
 fn verify_funds<const VERIFY: bool>(funds: &[MigratedFundsEntry], token_supply: &u64) -> Result<(), Error> {
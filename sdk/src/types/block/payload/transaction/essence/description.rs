@@ -0,0 +1,220 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{string::String, vec::Vec};
+
+use crate::types::block::{
+    address::{Address, Bech32Address, Hrp, ToBech32Ext},
+    input::Input,
+    output::{ChainId, NativeToken, Output, OutputId},
+    payload::Payload,
+    protocol::ProtocolParameters,
+};
+
+/// A breakdown of a transaction essence that can be displayed to confirm a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct TransactionDescription {
+    /// Whether the essence is for the network of the protocol parameters.
+    pub is_network_matching: bool,
+    /// The IDs of the consumed outputs.
+    pub inputs: Vec<OutputId>,
+    /// The consumed outputs, only known if they were provided when describing the transaction.
+    pub consumed_outputs: Vec<OutputDescription>,
+    /// The created outputs.
+    pub outputs: Vec<OutputDescription>,
+    /// The tag of the tagged data payload.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub tag: Option<DescribedData>,
+    /// The data of the tagged data payload.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub data: Option<DescribedData>,
+}
+
+/// A breakdown of an output: who can unlock it, what it holds and under which conditions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct OutputDescription {
+    /// The kind of the output, e.g. "Basic".
+    pub kind: String,
+    /// Whether the output returns the remaining funds to the sender.
+    pub is_change: bool,
+    /// The address that can unlock the output: the address of a basic or NFT output, the state controller of an
+    /// alias output or the alias controlling a foundry output.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub recipient: Option<Bech32Address>,
+    /// The governor of an alias output.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub governor: Option<Bech32Address>,
+    /// The amount of base tokens.
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde::string"))]
+    pub amount: u64,
+    /// The native tokens.
+    pub native_tokens: Vec<NativeToken>,
+    /// The alias, foundry or NFT ID, null if the output creates the alias or NFT.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub chain_id: Option<ChainId>,
+    /// The amount that must be returned to the return address when the output is claimed.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub storage_deposit_return: Option<StorageDepositReturnDescription>,
+    /// The unix timestamp until which the output can't be unlocked.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub timelock: Option<u32>,
+    /// The unix timestamp from which on the output can only be unlocked by the return address.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub expiration: Option<ExpirationDescription>,
+    /// The sender feature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub sender: Option<Bech32Address>,
+    /// The issuer feature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub issuer: Option<Bech32Address>,
+    /// The metadata feature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub metadata: Option<DescribedData>,
+    /// The tag feature.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub tag: Option<DescribedData>,
+}
+
+/// A storage deposit return unlock condition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct StorageDepositReturnDescription {
+    /// The address the amount must be returned to.
+    pub return_address: Bech32Address,
+    /// The amount to return.
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde::string"))]
+    pub amount: u64,
+}
+
+/// An expiration unlock condition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct ExpirationDescription {
+    /// The address that can unlock the output once it expired.
+    pub return_address: Bech32Address,
+    /// The unix timestamp at which the output expires.
+    pub timestamp: u32,
+}
+
+/// Arbitrary bytes, as hex and as text if they're valid UTF-8.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct DescribedData {
+    /// The prefix hex encoded bytes.
+    pub hex: String,
+    /// The bytes as text, if they're valid UTF-8.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub utf8: Option<String>,
+}
+
+impl DescribedData {
+    fn new(bytes: &[u8]) -> Self {
+        Self {
+            hex: prefix_hex::encode(bytes),
+            utf8: core::str::from_utf8(bytes).ok().map(String::from),
+        }
+    }
+}
+
+impl TransactionDescription {
+    /// Describes the inputs, outputs and payload of a transaction essence. Addresses are encoded with the HRP of the
+    /// protocol parameters.
+    pub fn new(
+        network_id: u64,
+        inputs: &[Input],
+        outputs: &[Output],
+        payload: Option<&Payload>,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Self {
+        let hrp = *protocol_parameters.bech32_hrp();
+        let (tag, data) = match payload {
+            Some(Payload::TaggedData(tagged_data)) => (
+                Some(DescribedData::new(tagged_data.tag())),
+                Some(DescribedData::new(tagged_data.data())),
+            ),
+            _ => (None, None),
+        };
+
+        Self {
+            is_network_matching: network_id == protocol_parameters.network_id(),
+            inputs: inputs
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Utxo(input) => Some(*input.output_id()),
+                    Input::Treasury(_) => None,
+                })
+                .collect(),
+            consumed_outputs: Vec::new(),
+            outputs: outputs
+                .iter()
+                .map(|output| OutputDescription::new(output, hrp))
+                .collect(),
+            tag,
+            data,
+        }
+    }
+}
+
+impl OutputDescription {
+    /// Describes an output, addresses are encoded with the given HRP.
+    pub fn new(output: &Output, hrp: Hrp) -> Self {
+        let bech32 = |address: &Address| (*address).to_bech32(hrp);
+        let unlock_conditions = output.unlock_conditions();
+        let features = output.features();
+        let immutable_features = output.immutable_features();
+
+        let recipient = unlock_conditions.and_then(|unlock_conditions| {
+            unlock_conditions
+                .address()
+                .map(|uc| uc.address())
+                .or_else(|| unlock_conditions.state_controller_address().map(|uc| uc.address()))
+                .or_else(|| unlock_conditions.immutable_alias_address().map(|uc| uc.address()))
+                .map(bech32)
+        });
+
+        Self {
+            kind: String::from(output.kind_str()),
+            is_change: false,
+            recipient,
+            governor: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.governor_address())
+                .map(|uc| bech32(uc.address())),
+            amount: output.amount(),
+            native_tokens: output
+                .native_tokens()
+                .map(|native_tokens| native_tokens.iter().cloned().collect())
+                .unwrap_or_default(),
+            chain_id: output.chain_id().filter(|chain_id| !chain_id.is_null()),
+            storage_deposit_return: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                .map(|uc| StorageDepositReturnDescription {
+                    return_address: bech32(uc.return_address()),
+                    amount: uc.amount(),
+                }),
+            timelock: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.timelock())
+                .map(|uc| uc.timestamp()),
+            expiration: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.expiration())
+                .map(|uc| ExpirationDescription {
+                    return_address: bech32(uc.return_address()),
+                    timestamp: uc.timestamp(),
+                }),
+            sender: features
+                .and_then(|features| features.sender())
+                .map(|feature| bech32(feature.address())),
+            issuer: immutable_features
+                .and_then(|features| features.issuer())
+                .map(|feature| bech32(feature.address())),
+            metadata: features
+                .and_then(|features| features.metadata())
+                .or_else(|| immutable_features.and_then(|features| features.metadata()))
+                .map(|feature| DescribedData::new(feature.data())),
+            tag: features
+                .and_then(|features| features.tag())
+                .map(|feature| DescribedData::new(feature.tag())),
+        }
+    }
+}
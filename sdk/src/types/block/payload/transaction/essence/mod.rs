@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod description;
 mod regular;
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
@@ -8,8 +9,14 @@ use derive_more::From;
 use packable::PackableExt;
 
 pub(crate) use self::regular::{InputCount, OutputCount};
-pub use self::regular::{RegularTransactionEssence, RegularTransactionEssenceBuilder};
-use crate::types::block::Error;
+pub use self::{
+    description::{
+        DescribedData, ExpirationDescription, OutputDescription, StorageDepositReturnDescription,
+        TransactionDescription,
+    },
+    regular::{RegularTransactionEssence, RegularTransactionEssenceBuilder},
+};
+use crate::types::block::{protocol::ProtocolParameters, Error};
 
 /// A generic essence that can represent different types defining transaction essences.
 #[derive(Clone, Debug, Eq, PartialEq, From, packable::Packable)]
@@ -45,6 +52,18 @@ impl TransactionEssence {
         let Self::Regular(essence) = self;
         essence
     }
+
+    /// Returns a human-readable breakdown of the essence, to be displayed before signing it.
+    pub fn describe(&self, protocol_parameters: &ProtocolParameters) -> TransactionDescription {
+        let Self::Regular(essence) = self;
+        TransactionDescription::new(
+            essence.network_id(),
+            essence.inputs(),
+            essence.outputs(),
+            essence.payload(),
+            protocol_parameters,
+        )
+    }
 }
 
 #[cfg(feature = "serde")]
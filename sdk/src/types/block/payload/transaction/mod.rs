@@ -11,7 +11,10 @@ use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable,
 
 pub(crate) use self::essence::{InputCount, OutputCount};
 pub use self::{
-    essence::{RegularTransactionEssence, RegularTransactionEssenceBuilder, TransactionEssence},
+    essence::{
+        DescribedData, ExpirationDescription, OutputDescription, RegularTransactionEssence,
+        RegularTransactionEssenceBuilder, StorageDepositReturnDescription, TransactionDescription, TransactionEssence,
+    },
     transaction_id::TransactionId,
 };
 use crate::types::block::{protocol::ProtocolParameters, unlock::Unlocks, Error};
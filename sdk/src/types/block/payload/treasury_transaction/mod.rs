@@ -6,6 +6,7 @@
 use crate::types::block::{
     input::{Input, TreasuryInput},
     output::{Output, TreasuryOutput},
+    payload::milestone::MilestoneId,
     protocol::ProtocolParameters,
     Error,
 };
@@ -32,6 +33,52 @@ impl TreasuryTransactionPayload {
         })
     }
 
+    /// Creates the [`TreasuryTransactionPayload`] that migrates an amount from the treasury created by the milestone
+    /// with the given ID, which holds `treasury_amount`.
+    pub fn new_migration(
+        treasury_milestone_id: MilestoneId,
+        treasury_amount: u64,
+        migrated_amount: u64,
+        token_supply: u64,
+    ) -> Result<Self, Error> {
+        let remaining_amount = treasury_amount
+            .checked_sub(migrated_amount)
+            .ok_or(Error::InvalidReceiptFundsSum(migrated_amount as u128))?;
+
+        Self::new(
+            TreasuryInput::new(treasury_milestone_id),
+            TreasuryOutput::new(remaining_amount, token_supply)?,
+        )
+    }
+
+    /// Verifies that the [`TreasuryTransactionPayload`] consumes the treasury created by the milestone with the given
+    /// ID, which holds `treasury_amount`, and leaves exactly the amount that wasn't migrated in it.
+    pub fn verify_migration(
+        &self,
+        treasury_milestone_id: &MilestoneId,
+        treasury_amount: u64,
+        migrated_amount: u64,
+    ) -> Result<(), Error> {
+        if self.input().milestone_id() != treasury_milestone_id {
+            return Err(Error::TreasuryInputMismatch {
+                expected: *treasury_milestone_id,
+                actual: *self.input().milestone_id(),
+            });
+        }
+
+        let expected = treasury_amount
+            .checked_sub(migrated_amount)
+            .ok_or(Error::InvalidReceiptFundsSum(migrated_amount as u128))?;
+        if self.output().amount() != expected {
+            return Err(Error::TreasuryAmountMismatch {
+                expected,
+                actual: self.output().amount(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Returns the input of a [`TreasuryTransactionPayload`].
     pub fn input(&self) -> &TreasuryInput {
         if let Input::Treasury(ref input) = self.input {
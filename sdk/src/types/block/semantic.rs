@@ -100,7 +100,7 @@ impl fmt::Display for ConflictReason {
             Self::InputsCommitmentsMismatch => write!(f, "The inputs commitment is invalid"),
             Self::UnverifiedSender => write!(
                 f,
-                " The output contains a Sender with an ident (address) which is not unlocked"
+                "The output contains a Sender with an ident (address) which is not unlocked"
             ),
             Self::InvalidChainStateTransition => write!(f, "The chain state transition is invalid"),
             Self::SemanticValidationFailed => write!(f, "The semantic validation failed"),
@@ -168,6 +168,8 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    /// Whether signature unlocks are verified, only disabled to simulate transactions that aren't signed yet.
+    pub verify_signatures: bool,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -212,6 +214,7 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            verify_signatures: true,
         }
     }
 }
@@ -3,6 +3,7 @@
 
 mod alias;
 mod nft;
+mod plan;
 mod reference;
 mod signature;
 
@@ -13,7 +14,13 @@ use derive_more::{Deref, From};
 use hashbrown::HashSet;
 use packable::{bounded::BoundedU16, prefix::BoxedSlicePrefix, Packable};
 
-pub use self::{alias::AliasUnlock, nft::NftUnlock, reference::ReferenceUnlock, signature::SignatureUnlock};
+pub use self::{
+    alias::AliasUnlock,
+    nft::NftUnlock,
+    plan::{plan_unlocks, InputUnlock},
+    reference::ReferenceUnlock,
+    signature::SignatureUnlock,
+};
 use crate::types::block::{
     input::{INPUT_COUNT_MAX, INPUT_COUNT_RANGE, INPUT_INDEX_MAX, INPUT_INDEX_RANGE},
     Error,
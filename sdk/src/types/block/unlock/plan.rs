@@ -0,0 +1,90 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+
+use crate::types::block::{
+    address::{Address, Ed25519Address},
+    output::{AliasTransition, Output, OutputId},
+    unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, Unlock},
+    Error,
+};
+
+/// How an input of a transaction gets unlocked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputUnlock {
+    /// The input needs a [`SignatureUnlock`](crate::types::block::unlock::SignatureUnlock) by the key of the address.
+    Signature(Ed25519Address),
+    /// The input is unlocked by the unlock of a previous input, with a reference, alias or NFT unlock.
+    Unlock(Unlock),
+}
+
+/// Determines how the inputs of a transaction get unlocked, in the order of the inputs, without signing anything.
+/// Inputs that require the same address share one signature, inputs owned by an alias or NFT are unlocked by the
+/// input of that alias or NFT, which must come first.
+///
+/// Replacing every [`InputUnlock::Signature`] with a signature of the essence hash gives the
+/// [`Unlocks`](crate::types::block::unlock::Unlocks) of the transaction, so signing can happen on a separate device.
+pub fn plan_unlocks<'a>(
+    inputs: impl IntoIterator<Item = (&'a OutputId, &'a Output)>,
+    outputs: &[Output],
+    current_time: u32,
+) -> Result<Vec<InputUnlock>, Error> {
+    let mut unlocks = Vec::new();
+    let mut unlock_indexes = HashMap::<Address, u16>::new();
+
+    for (index, (output_id, output)) in inputs.into_iter().enumerate() {
+        let index = u16::try_from(index).map_err(|_| Error::InvalidField("inputs"))?;
+        let (required_address, unlocked_address) = output.required_and_unlocked_address(
+            current_time,
+            output_id,
+            alias_transition(output, output_id, outputs),
+        )?;
+
+        let unlock = match (unlock_indexes.get(&required_address), required_address) {
+            (Some(&unlock_index), Address::Ed25519(_)) => {
+                InputUnlock::Unlock(Unlock::Reference(ReferenceUnlock::new(unlock_index)?))
+            }
+            (Some(&unlock_index), Address::Alias(_)) => {
+                InputUnlock::Unlock(Unlock::Alias(AliasUnlock::new(unlock_index)?))
+            }
+            (Some(&unlock_index), Address::Nft(_)) => InputUnlock::Unlock(Unlock::Nft(NftUnlock::new(unlock_index)?)),
+            (None, Address::Ed25519(address)) => {
+                unlock_indexes.insert(required_address, index);
+                InputUnlock::Signature(address)
+            }
+            // An alias or NFT address can only be unlocked by a previous input
+            (None, _) => return Err(Error::MissingInputWithEd25519Address),
+        };
+        unlocks.push(unlock);
+
+        // Later inputs owned by this alias or NFT are unlocked by this input
+        if let Some(unlocked_address) = unlocked_address {
+            unlock_indexes.insert(unlocked_address, index);
+        }
+    }
+
+    Ok(unlocks)
+}
+
+// An alias input is state transitioned if its alias is created with a different state index, governance transitioned
+// otherwise.
+fn alias_transition(input: &Output, output_id: &OutputId, outputs: &[Output]) -> Option<AliasTransition> {
+    let Output::Alias(alias_input) = input else {
+        return None;
+    };
+    let alias_id = alias_input.alias_id_non_null(output_id);
+
+    outputs.iter().find_map(|output| match output {
+        Output::Alias(alias_output) if *alias_output.alias_id() == alias_id => {
+            if alias_output.state_index() == alias_input.state_index() {
+                Some(AliasTransition::Governance)
+            } else {
+                Some(AliasTransition::State)
+            }
+        }
+        _ => None,
+    })
+}
@@ -0,0 +1,42 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The JSON forms of blocks, their parts and node responses, in one place.
+//!
+//! This is the only definition of the JSON schema of these types, the bindings serialize to and deserialize from these
+//! types instead of defining their own, so their JSON can't drift apart. DTOs are converted to the validated types
+//! with [`TryFromDto`], or from them with `From`.
+
+pub use super::{
+    api::core::response,
+    block::{
+        address::dto::{AddressDto, AliasAddressDto, Ed25519AddressDto, NftAddressDto},
+        input::dto::{InputDto, TreasuryInputDto, UtxoInputDto},
+        output::{
+            dto::{
+                AliasOutputDto, BasicOutputDto, FoundryOutputDto, NftOutputDto, OutputBuilderAmountDto, OutputDto,
+                SimpleTokenSchemeDto, TokenSchemeDto, TreasuryOutputDto,
+            },
+            feature::dto::{FeatureDto, IssuerFeatureDto, MetadataFeatureDto, SenderFeatureDto, TagFeatureDto},
+            unlock_condition::dto::{
+                AddressUnlockConditionDto, ExpirationUnlockConditionDto, GovernorAddressUnlockConditionDto,
+                ImmutableAliasAddressUnlockConditionDto, StateControllerAddressUnlockConditionDto,
+                StorageDepositReturnUnlockConditionDto, TimelockUnlockConditionDto, UnlockConditionDto,
+            },
+        },
+        payload::{
+            dto::{
+                MilestonePayloadDto, PayloadDto, TaggedDataPayloadDto, TransactionPayloadDto,
+                TreasuryTransactionPayloadDto,
+            },
+            milestone::option::dto::{
+                MigratedFundsEntryDto, MilestoneOptionDto, ParametersMilestoneOptionDto, ReceiptMilestoneOptionDto,
+            },
+            transaction::dto::{RegularTransactionEssenceDto, TransactionEssenceDto},
+        },
+        signature::dto::{Ed25519SignatureDto, SignatureDto},
+        unlock::dto::{AliasUnlockDto, NftUnlockDto, ReferenceUnlockDto, SignatureUnlockDto, UnlockDto},
+        BlockDto,
+    },
+    TryFromDto,
+};
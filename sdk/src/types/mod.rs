@@ -2,6 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Common types required by nodes and clients APIs like blocks, responses and DTOs.
+//!
+//! Without default features, the [`block`] module only needs `alloc`, so blocks and transactions can be built,
+//! validated and packed on devices without `std`, e.g. hardware wallets or secure enclaves. The unlocks of a
+//! transaction are planned with [`plan_unlocks()`](block::unlock::plan_unlocks) and only their signatures need to
+//! be created by the device.
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -9,6 +14,8 @@ extern crate std;
 #[cfg(feature = "serde")]
 pub mod api;
 pub mod block;
+#[cfg(feature = "serde")]
+pub mod dto;
 
 use core::ops::Deref;
 
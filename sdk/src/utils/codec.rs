@@ -0,0 +1,86 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between the binary representation of blocks and payloads and the JSON of the REST API.
+//!
+//! All conversions fully validate their input and check that it converts back to the exact same representation, so
+//! captured node traffic can't be translated into something the node didn't send.
+
+use packable::PackableExt;
+
+use crate::types::{
+    block::{
+        payload::{dto::PayloadDto, Payload},
+        protocol::ProtocolParameters,
+        Block, BlockDto,
+    },
+    TryFromDto,
+};
+
+/// Codec errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Block error
+    #[error("{0}")]
+    Block(#[from] crate::types::block::Error),
+    /// Json error
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    /// Hex error
+    #[error("{0}")]
+    PrefixHex(#[from] prefix_hex::Error),
+    /// The bytes couldn't be unpacked
+    #[error("invalid bytes: {0}")]
+    Unpack(String),
+    /// The input converts back to a different representation, because it isn't in canonical form
+    #[error("{0} isn't in canonical form")]
+    NotCanonical(&'static str),
+}
+
+/// Converts the bytes of a block to its JSON representation.
+pub fn block_to_json(bytes: &[u8], protocol_parameters: &ProtocolParameters) -> Result<String, Error> {
+    let block = Block::unpack_strict(bytes, protocol_parameters).map_err(|e| Error::Unpack(format!("{e:?}")))?;
+
+    if block.pack_to_vec() != bytes {
+        return Err(Error::NotCanonical("block bytes"));
+    }
+
+    Ok(serde_json::to_string(&BlockDto::from(&block))?)
+}
+
+/// Converts the JSON representation of a block to its bytes.
+pub fn json_to_block(json: &str, protocol_parameters: &ProtocolParameters) -> Result<Vec<u8>, Error> {
+    let dto = serde_json::from_str::<BlockDto>(json)?;
+    let block = Block::try_from_dto_with_params(dto.clone(), protocol_parameters)?;
+
+    if BlockDto::from(&block) != dto {
+        return Err(Error::NotCanonical("block JSON"));
+    }
+
+    let bytes = block.pack_to_vec();
+    // The binary checks also verify what the DTO conversion doesn't, like the protocol version
+    Block::unpack_strict(&bytes, protocol_parameters).map_err(|e| Error::Unpack(format!("{e:?}")))?;
+
+    Ok(bytes)
+}
+
+/// Converts the prefix hex encoded bytes of a payload to its JSON representation, checking that the JSON converts back
+/// to the same bytes.
+pub fn payload_hex_roundtrip(hex: &str, protocol_parameters: &ProtocolParameters) -> Result<String, Error> {
+    let bytes = prefix_hex::decode::<Vec<u8>>(hex)?;
+    let payload = Payload::unpack_verified(&bytes, protocol_parameters).map_err(|e| Error::Unpack(format!("{e:?}")))?;
+
+    if payload.pack_to_vec() != bytes {
+        return Err(Error::NotCanonical("payload bytes"));
+    }
+
+    let dto = PayloadDto::from(&payload);
+    let json = serde_json::to_string(&dto)?;
+
+    let converted = Payload::try_from_dto_with_params(serde_json::from_str::<PayloadDto>(&json)?, protocol_parameters)?;
+    if converted.pack_to_vec() != bytes {
+        return Err(Error::NotCanonical("payload JSON"));
+    }
+
+    Ok(json)
+}
@@ -0,0 +1,257 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between IOTA units, address encodings and DIDs.
+
+use core::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::NetworkInfo,
+    types::block::{
+        address::{Address, AliasAddress, Bech32Address, Ed25519Address, Hrp, NftAddress},
+        output::{AliasId, NftId},
+    },
+    utils::parse::{InputParser, ParseError, ParseMode},
+};
+
+/// The DID method of IOTA identities.
+pub const DID_METHOD: &str = "iota";
+/// The network name that is omitted in DIDs.
+const DID_DEFAULT_NETWORK: &str = "iota";
+
+/// Conversion errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The converted amount doesn't fit into an u64
+    #[error("amount overflow")]
+    AmountOverflow,
+    /// The amount can't be represented in the target unit without losing precision
+    #[error("amount {amount} can't be converted to {unit} without losing precision")]
+    PrecisionLoss { amount: u64, unit: IotaUnit },
+    /// Invalid amount
+    #[error("invalid amount: {0}")]
+    InvalidAmount(String),
+    /// Invalid unit
+    #[error("invalid unit: {0}")]
+    InvalidUnit(String),
+    /// The address belongs to another network
+    #[error("hrp mismatch: expected {expected} but got {found}")]
+    HrpMismatch { expected: Hrp, found: Hrp },
+    /// Invalid address kind
+    #[error("invalid address kind: {0}")]
+    InvalidAddressKind(u8),
+    /// Invalid DID
+    #[error("invalid DID: {0}")]
+    InvalidDid(String),
+    /// Block error
+    #[error("{0}")]
+    Block(#[from] crate::types::block::Error),
+    /// Parse error
+    #[error("{0}")]
+    Parse(#[from] ParseError),
+}
+
+/// The units of IOTA amounts, all of them are powers of 1000 of the base unit.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum IotaUnit {
+    /// Base unit, 1 i
+    #[serde(rename = "i")]
+    I,
+    /// Kilo, 1_000 i
+    #[serde(rename = "Ki")]
+    Ki,
+    /// Mega, 1_000_000 i
+    #[serde(rename = "Mi")]
+    Mi,
+    /// Giga, 1_000_000_000 i
+    #[serde(rename = "Gi")]
+    Gi,
+    /// Tera, 1_000_000_000_000 i
+    #[serde(rename = "Ti")]
+    Ti,
+    /// Peta, 1_000_000_000_000_000 i
+    #[serde(rename = "Pi")]
+    Pi,
+}
+
+impl IotaUnit {
+    /// Returns the number of decimals of the unit relative to the base unit.
+    pub const fn decimals(&self) -> u32 {
+        match self {
+            Self::I => 0,
+            Self::Ki => 3,
+            Self::Mi => 6,
+            Self::Gi => 9,
+            Self::Ti => 12,
+            Self::Pi => 15,
+        }
+    }
+
+    /// Returns the amount of base units in one of this unit.
+    pub const fn base_units(&self) -> u64 {
+        10u64.pow(self.decimals())
+    }
+}
+
+impl fmt::Display for IotaUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::I => "i",
+            Self::Ki => "Ki",
+            Self::Mi => "Mi",
+            Self::Gi => "Gi",
+            Self::Ti => "Ti",
+            Self::Pi => "Pi",
+        })
+    }
+}
+
+impl FromStr for IotaUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "i" => Self::I,
+            "Ki" => Self::Ki,
+            "Mi" => Self::Mi,
+            "Gi" => Self::Gi,
+            "Ti" => Self::Ti,
+            "Pi" => Self::Pi,
+            _ => return Err(Error::InvalidUnit(s.to_string())),
+        })
+    }
+}
+
+/// Converts an amount from one unit to another. Fails if the result overflows or isn't a whole number.
+pub fn convert_units(amount: u64, from: IotaUnit, to: IotaUnit) -> Result<u64, Error> {
+    if from >= to {
+        amount
+            .checked_mul(10u64.pow(from.decimals() - to.decimals()))
+            .ok_or(Error::AmountOverflow)
+    } else {
+        let divisor = 10u64.pow(to.decimals() - from.decimals());
+        if amount % divisor != 0 {
+            return Err(Error::PrecisionLoss { amount, unit: to });
+        }
+        Ok(amount / divisor)
+    }
+}
+
+/// Parses an amount with an optional decimal fraction and unit, like `"1.5 Mi"` or `"42"`, to base units.
+pub fn parse_amount(amount: &str) -> Result<u64, Error> {
+    let amount = amount.trim();
+    let (value, unit) = match amount.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(index) => (amount[..index].trim(), amount[index..].parse::<IotaUnit>()?),
+        None => (amount, IotaUnit::I),
+    };
+    let invalid_amount = || Error::InvalidAmount(amount.to_string());
+
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid_amount());
+    }
+    if fraction.len() > unit.decimals() as usize {
+        return Err(invalid_amount());
+    }
+
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<u64>().map_err(|_| Error::AmountOverflow)?
+    };
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        // Safe to unwrap, the fraction only contains digits and is shorter than 16 characters
+        fraction.parse::<u64>().unwrap() * 10u64.pow(unit.decimals() - fraction.len() as u32)
+    };
+
+    integer
+        .checked_mul(unit.base_units())
+        .and_then(|integer| integer.checked_add(fraction))
+        .ok_or(Error::AmountOverflow)
+}
+
+/// Formats an amount of base units in the given unit, without trailing zeros, like `"1.5 Mi"`.
+pub fn format_amount(amount: u64, unit: IotaUnit) -> String {
+    let integer = amount / unit.base_units();
+    let fraction = amount % unit.base_units();
+
+    if fraction == 0 {
+        format!("{integer} {unit}")
+    } else {
+        let fraction = format!("{fraction:0width$}", width = unit.decimals() as usize);
+        format!("{integer}.{} {unit}", fraction.trim_end_matches('0'))
+    }
+}
+
+/// Transforms a bech32 encoded address to the hex encoded address kind and id, after checking that it belongs to the
+/// network.
+pub fn bech32_to_hex(bech32: &str, network_info: &NetworkInfo) -> Result<(u8, String), Error> {
+    let address = Bech32Address::try_from_str(bech32)?;
+    check_hrp(address.hrp(), network_info)?;
+
+    Ok(match address.inner() {
+        Address::Ed25519(ed25519) => (Ed25519Address::KIND, ed25519.to_string()),
+        Address::Alias(alias) => (AliasAddress::KIND, alias.to_string()),
+        Address::Nft(nft) => (NftAddress::KIND, nft.to_string()),
+    })
+}
+
+/// Transforms a hex encoded address id of the given address kind to a bech32 encoded address of the network. The `0x`
+/// prefix is optional.
+pub fn hex_to_bech32(kind: u8, hex: &str, network_info: &NetworkInfo) -> Result<Bech32Address, Error> {
+    let parser = InputParser::new(ParseMode::Lenient);
+    let address = match kind {
+        Ed25519Address::KIND => Address::from(parser.parse_id::<Ed25519Address>(hex)?),
+        AliasAddress::KIND => Address::from(AliasAddress::new(parser.parse_id::<AliasId>(hex)?)),
+        NftAddress::KIND => Address::from(NftAddress::new(parser.parse_id::<NftId>(hex)?)),
+        _ => return Err(Error::InvalidAddressKind(kind)),
+    };
+
+    Ok(Bech32Address::new(
+        *network_info.protocol_parameters.bech32_hrp(),
+        address,
+    ))
+}
+
+fn check_hrp(hrp: &Hrp, network_info: &NetworkInfo) -> Result<(), Error> {
+    let expected = network_info.protocol_parameters.bech32_hrp();
+    if hrp != expected {
+        return Err(Error::HrpMismatch {
+            expected: *expected,
+            found: *hrp,
+        });
+    }
+    Ok(())
+}
+
+/// Derives the DID of the identity stored in an alias output, like `did:iota:smr:0x...`. The network name is omitted
+/// for the IOTA mainnet.
+pub fn alias_id_to_did(alias_id: &AliasId, hrp: &Hrp) -> String {
+    if hrp == DID_DEFAULT_NETWORK {
+        format!("did:{DID_METHOD}:{alias_id}")
+    } else {
+        format!("did:{DID_METHOD}:{hrp}:{alias_id}")
+    }
+}
+
+/// Extracts the network name and alias id from a DID.
+pub fn did_to_alias_id(did: &str) -> Result<(Hrp, AliasId), Error> {
+    let invalid_did = || Error::InvalidDid(did.to_string());
+
+    let (network, alias_id) = match did.split(':').collect::<Vec<_>>()[..] {
+        ["did", DID_METHOD, alias_id] => (DID_DEFAULT_NETWORK, alias_id),
+        ["did", DID_METHOD, network, alias_id] => (network, alias_id),
+        _ => return Err(invalid_did()),
+    };
+
+    Ok((
+        network.parse().map_err(|_| invalid_did())?,
+        alias_id.parse().map_err(|_| invalid_did())?,
+    ))
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generating and verifying BIP39 mnemonics in the supported languages, and deriving seeds from them.
+//!
+//! A mnemonic can be combined with a passphrase, also known as the 25th word, which derives a different seed. There is
+//! no way to tell whether a passphrase is the right one, a wrong one just leads to a seed with other addresses.
+
+use core::borrow::Borrow;
+
+use crypto::keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, Seed};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::client::{utils::mnemonic_error_message, Error, Result};
+
+/// The numbers of words a mnemonic can have.
+pub const MNEMONIC_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+/// The number of words of generated mnemonics if no other is requested.
+pub const DEFAULT_MNEMONIC_WORD_COUNT: usize = 24;
+
+/// The languages of the BIP39 wordlists.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum MnemonicLanguage {
+    /// English.
+    #[default]
+    English,
+    /// Japanese, the words are separated by ideographic spaces.
+    Japanese,
+}
+
+impl MnemonicLanguage {
+    fn wordlist(self) -> &'static wordlist::Wordlist<'static> {
+        match self {
+            Self::English => &wordlist::ENGLISH,
+            Self::Japanese => &wordlist::JAPANESE,
+        }
+    }
+}
+
+/// Generates a new random mnemonic with the number of words, one of [`MNEMONIC_WORD_COUNTS`], in the language.
+pub fn generate_mnemonic(word_count: usize, language: MnemonicLanguage) -> Result<Mnemonic> {
+    if !MNEMONIC_WORD_COUNTS.contains(&word_count) {
+        return Err(Error::InvalidMnemonic(format!(
+            "unsupported word count {word_count}, expected one of {MNEMONIC_WORD_COUNTS:?}"
+        )));
+    }
+
+    // Every word encodes 11 bits, one of 33 bits is a checksum bit
+    let mut entropy = Zeroizing::new(vec![0u8; word_count * 4 / 3]);
+    crypto::utils::rand::fill(&mut entropy[..])?;
+
+    wordlist::encode(&entropy, language.wordlist()).map_err(|e| Error::InvalidMnemonic(format!("{e:?}")))
+}
+
+/// Verifies that all words of a mnemonic are in the wordlist of the language and that its checksum is valid.
+pub fn verify_mnemonic(mnemonic: impl Borrow<MnemonicRef>, language: MnemonicLanguage) -> Result<()> {
+    wordlist::verify(mnemonic.borrow(), language.wordlist())
+        .map_err(|e| Error::InvalidMnemonic(mnemonic_error_message(&e)))
+}
+
+/// Verifies a mnemonic in the language and returns its seed, derived with the passphrase if there is one.
+pub fn mnemonic_to_seed(
+    mnemonic: impl Borrow<MnemonicRef>,
+    language: MnemonicLanguage,
+    passphrase: Option<&Passphrase>,
+) -> Result<Seed> {
+    // The mnemonic is verified first to give meaningful errors, deriving a seed doesn't check it
+    verify_mnemonic(mnemonic.borrow(), language)?;
+
+    Ok(crypto::keys::bip39::mnemonic_to_seed(
+        mnemonic.borrow(),
+        passphrase.unwrap_or(&Passphrase::default()),
+    ))
+}
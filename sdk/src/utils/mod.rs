@@ -1,9 +1,27 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub mod codec;
+#[cfg(feature = "client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub mod convert;
+#[cfg(feature = "client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub mod mnemonic;
+#[cfg(feature = "client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client")))]
+pub mod parse;
 #[cfg(feature = "serde")]
 pub mod serde;
 
+/// Compares two byte slices in constant time for slices of the same length, so secrets can't be guessed byte by byte.
+#[cfg(feature = "wallet")]
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
 #[cfg(feature = "instant")]
 pub fn unix_timestamp_now() -> core::time::Duration {
     instant::SystemTime::now()
@@ -0,0 +1,212 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of addresses and IDs provided by users, e.g. pasted into a form or passed to the bindings.
+//!
+//! In [`ParseMode::Lenient`], hex without the `0x` prefix, uppercase hex, surrounding whitespace and hex Ed25519
+//! addresses where a bech32 address is expected are accepted. [`ParseMode::Strict`] only accepts the canonical forms
+//! and returns a [`ParseError`] describing what's wrong with the input. In both modes the HRP of bech32 addresses is
+//! checked against the one of the network, if it's known.
+
+use core::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::address::{Bech32Address, Ed25519Address, Hrp};
+
+/// The number of hex characters of an Ed25519 address.
+const ED25519_ADDRESS_HEX_LENGTH: usize = Ed25519Address::LENGTH * 2;
+
+/// How strictly user-provided addresses and IDs are parsed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ParseMode {
+    /// Accepts hex without the `0x` prefix, uppercase hex and bech32, surrounding whitespace and hex Ed25519 addresses.
+    #[default]
+    Lenient,
+    /// Only accepts `0x`-prefixed lowercase hex and lowercase bech32 addresses, without surrounding whitespace.
+    Strict,
+}
+
+/// Describes why a user-provided address or ID couldn't be parsed.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ParseError {
+    /// The input has surrounding whitespace.
+    #[error("`{input}` has leading or trailing whitespace")]
+    Whitespace { input: String },
+    /// Hex without the `0x` prefix.
+    #[error("`{input}` is missing the `0x` prefix")]
+    MissingHexPrefix { input: String },
+    /// Uppercase hex or bech32.
+    #[error("`{input}` contains uppercase characters, only lowercase is accepted")]
+    Uppercase { input: String },
+    /// A character that isn't a hex digit.
+    #[error("`{input}` contains the invalid hex character `{character}` at position {position}")]
+    InvalidHexCharacter {
+        input: String,
+        character: char,
+        position: usize,
+    },
+    /// A hex address where a bech32 address is expected.
+    #[error("`{input}` is a hex address, which doesn't identify the address kind and network, use a bech32 address")]
+    HexAddress { input: String },
+    /// A hex address that can't be converted because the bech32 HRP of the network isn't known.
+    #[error("the hex address `{input}` can't be converted to a bech32 address without the bech32 HRP of the network")]
+    MissingBech32Hrp { input: String },
+    /// An invalid bech32 address.
+    #[error("`{input}` isn't a valid bech32 address: {reason}")]
+    InvalidBech32 { input: String, reason: String },
+    /// A bech32 address of another network.
+    #[error("`{input}` has the bech32 HRP `{provided}`, but the network expects `{expected}`")]
+    Bech32HrpMismatch {
+        input: String,
+        provided: String,
+        expected: String,
+    },
+    /// Valid hex that isn't a valid value, e.g. because of its length.
+    #[error("`{input}` is invalid: {reason}")]
+    Invalid { input: String, reason: String },
+}
+
+/// Parses user-provided addresses and IDs.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct InputParser {
+    mode: ParseMode,
+    bech32_hrp: Option<Hrp>,
+}
+
+impl InputParser {
+    /// Creates a parser that doesn't know the bech32 HRP of the network.
+    pub fn new(mode: ParseMode) -> Self {
+        Self { mode, bech32_hrp: None }
+    }
+
+    /// Sets the bech32 HRP of the network. Bech32 addresses with another HRP are rejected and hex addresses are
+    /// converted with it.
+    pub fn with_bech32_hrp(mut self, bech32_hrp: impl Into<Option<Hrp>>) -> Self {
+        self.bech32_hrp = bech32_hrp.into();
+        self
+    }
+
+    /// Returns the mode of the parser.
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Returns the bech32 HRP of the network, if it's known.
+    pub fn bech32_hrp(&self) -> Option<&Hrp> {
+        self.bech32_hrp.as_ref()
+    }
+
+    /// Parses an ID from hex, e.g. a [`BlockId`](crate::types::block::BlockId) or an
+    /// [`OutputId`](crate::types::block::output::OutputId).
+    pub fn parse_id<T>(&self, input: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        T::from_str(&self.normalize_hex(input)?).map_err(|e| ParseError::Invalid {
+            input: input.to_owned(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Parses bytes of a fixed length from hex, e.g. a public key.
+    pub fn parse_bytes<const N: usize>(&self, input: &str) -> Result<[u8; N], ParseError> {
+        prefix_hex::decode(self.normalize_hex(input)?).map_err(|e| ParseError::Invalid {
+            input: input.to_owned(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Parses a bech32 address, or in lenient mode also a hex Ed25519 address, which is converted with the bech32 HRP
+    /// of the network.
+    pub fn parse_address(&self, input: &str) -> Result<Bech32Address, ParseError> {
+        let trimmed = self.trim(input)?;
+
+        if is_hex_address(trimmed) {
+            if self.mode == ParseMode::Strict {
+                return Err(ParseError::HexAddress {
+                    input: input.to_owned(),
+                });
+            }
+            let bech32_hrp = self.bech32_hrp.ok_or_else(|| ParseError::MissingBech32Hrp {
+                input: input.to_owned(),
+            })?;
+
+            return Ok(Bech32Address::new(
+                bech32_hrp,
+                self.parse_id::<Ed25519Address>(trimmed)?,
+            ));
+        }
+
+        // Bech32 is case-insensitive as long as the case isn't mixed, which the decoding rejects
+        if self.mode == ParseMode::Strict && trimmed.chars().any(|c| c.is_ascii_uppercase()) {
+            return Err(ParseError::Uppercase {
+                input: input.to_owned(),
+            });
+        }
+        let address = Bech32Address::try_from_str(trimmed).map_err(|e| ParseError::InvalidBech32 {
+            input: input.to_owned(),
+            reason: e.to_string(),
+        })?;
+
+        match self.bech32_hrp {
+            Some(expected) if address.hrp() != &expected => Err(ParseError::Bech32HrpMismatch {
+                input: input.to_owned(),
+                provided: address.hrp().to_string(),
+                expected: expected.to_string(),
+            }),
+            _ => Ok(address),
+        }
+    }
+
+    fn trim<'a>(&self, input: &'a str) -> Result<&'a str, ParseError> {
+        let trimmed = input.trim();
+
+        if self.mode == ParseMode::Strict && trimmed.len() != input.len() {
+            return Err(ParseError::Whitespace {
+                input: input.to_owned(),
+            });
+        }
+
+        Ok(trimmed)
+    }
+
+    /// Returns the hex as `0x`-prefixed lowercase hex.
+    fn normalize_hex(&self, input: &str) -> Result<String, ParseError> {
+        let trimmed = self.trim(input)?;
+        let digits = match (trimmed.strip_prefix("0x"), self.mode) {
+            (Some(digits), _) => digits,
+            (None, ParseMode::Strict) => {
+                return Err(ParseError::MissingHexPrefix {
+                    input: input.to_owned(),
+                });
+            }
+            (None, ParseMode::Lenient) => trimmed.strip_prefix("0X").unwrap_or(trimmed),
+        };
+
+        if let Some((index, character)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(ParseError::InvalidHexCharacter {
+                input: input.to_owned(),
+                character,
+                position: trimmed.len() - digits.len() + index,
+            });
+        }
+        if self.mode == ParseMode::Strict && digits.chars().any(|c| c.is_ascii_uppercase()) {
+            return Err(ParseError::Uppercase {
+                input: input.to_owned(),
+            });
+        }
+
+        Ok(format!("0x{}", digits.to_ascii_lowercase()))
+    }
+}
+
+/// Returns whether the input is meant as a hex address: it's `0x`-prefixed, or it has the length of a hex Ed25519
+/// address and only hex characters, which no bech32 address of a network has.
+fn is_hex_address(input: &str) -> bool {
+    input.starts_with("0x")
+        || input.starts_with("0X")
+        || (input.len() == ED25519_ADDRESS_HEX_LENGTH && input.chars().all(|c| c.is_ascii_hexdigit()))
+}
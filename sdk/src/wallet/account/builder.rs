@@ -1,12 +1,15 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use tokio::sync::RwLock;
 
 use crate::{
-    client::secret::{SecretManage, SecretManager},
+    client::{
+        secret::{SecretManage, SecretManager},
+        ClientBuilder,
+    },
     types::block::address::{Address, Bech32Address, Ed25519Address, Hrp},
     wallet::{
         account::{types::AccountAddress, Account, AccountDetails},
@@ -14,11 +17,17 @@ use crate::{
     },
 };
 
+/// Set on the path segments that are hardened when deriving keys.
+const HARDENED_BIT: u32 = 1 << 31;
+
 /// The AccountBuilder
 pub struct AccountBuilder<S: SecretManage = SecretManager> {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    coin_type: Option<u32>,
+    bip44_account_index: Option<u32>,
+    client_options: Option<ClientBuilder>,
     wallet: Wallet<S>,
 }
 
@@ -32,6 +41,9 @@ where
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            coin_type: None,
+            bip44_account_index: None,
+            client_options: None,
             wallet,
         }
     }
@@ -55,12 +67,34 @@ where
         self
     }
 
+    /// Set the coin type the addresses are derived with, for example to claim funds sent to addresses of another
+    /// network. Defaults to the coin type of the wallet.
+    pub fn with_coin_type(mut self, coin_type: impl Into<Option<u32>>) -> Self {
+        self.coin_type = coin_type.into();
+        self
+    }
+
+    /// Set the BIP44 account index the addresses are derived with, `m/44'/coin_type'/bip44_account_index'`. Defaults
+    /// to the index of the new account.
+    pub fn with_bip44_account_index(mut self, bip44_account_index: impl Into<Option<u32>>) -> Self {
+        self.bip44_account_index = bip44_account_index.into();
+        self
+    }
+
+    /// Bind the account to another network than the wallet, like a private tangle next to the mainnet. The account
+    /// uses its own client with these options and its addresses get the bech32 HRP of that network, the coin type
+    /// should usually be set too.
+    pub fn with_client_options(mut self, client_options: impl Into<Option<ClientBuilder>>) -> Self {
+        self.client_options = client_options.into();
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
     pub async fn finish(&mut self) -> crate::wallet::Result<Account<S>> {
         let mut accounts = self.wallet.accounts.write().await;
-        let account_index = accounts.len() as u32;
+        let account_index = next_account_index(&accounts).await;
         // If no alias is provided, the account index will be set as alias
         let account_alias = self.alias.clone().unwrap_or_else(|| account_index.to_string());
         log::debug!(
@@ -77,7 +111,36 @@ where
             }
         }
 
-        let coin_type = self.wallet.coin_type.load(core::sync::atomic::Ordering::Relaxed);
+        let coin_type = self
+            .coin_type
+            .unwrap_or_else(|| self.wallet.coin_type.load(core::sync::atomic::Ordering::Relaxed));
+        let bip44_account_index = self.bip44_account_index.unwrap_or(account_index);
+
+        // The path segments are hardened when deriving, so they can't have the hardened bit set themselves
+        if coin_type & HARDENED_BIT != 0 || bip44_account_index & HARDENED_BIT != 0 {
+            return Err(Error::InvalidDerivationPath(format!(
+                "m/44'/{coin_type}'/{bip44_account_index}' has an index out of the hardened range"
+            )));
+        }
+        // Two accounts with the same path would share their addresses and outputs, watch-only accounts can belong to
+        // another seed
+        for account in accounts.iter() {
+            let account = account.details().await;
+            if !account.watch_only
+                && account.coin_type == coin_type
+                && account.bip44_account_index == bip44_account_index
+            {
+                return Err(Error::InvalidDerivationPath(format!(
+                    "m/44'/{coin_type}'/{bip44_account_index}' is already used by account {}",
+                    account.alias()
+                )));
+            }
+        }
+
+        let client = match &self.client_options {
+            Some(client_options) => Some(client_options.clone().finish().await?),
+            None => None,
+        };
 
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
@@ -85,13 +148,31 @@ where
             Some(addresses) => addresses.clone(),
             None => {
                 let mut bech32_hrp = self.bech32_hrp;
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.details().await.coin_type();
+                // Watch-only accounts don't have to be derived from the seed of the secret manager
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !*account.details().await.watch_only() {
+                        first_account = Some(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_coin_type, first_account_bip44_account_index) = {
+                        let first_account_details = first_account.details().await;
+                        (
+                            first_account_details.coin_type,
+                            first_account_details.bip44_account_index,
+                        )
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
                     // seeds
-                    let first_account_public_address =
-                        get_first_public_address(&self.wallet.secret_manager, first_account_coin_type, 0).await?;
+                    let first_account_public_address = get_first_public_address(
+                        &self.wallet.secret_manager,
+                        first_account_coin_type,
+                        first_account_bip44_account_index,
+                    )
+                    .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if Address::Ed25519(first_account_public_address)
@@ -106,9 +187,9 @@ where
                         ));
                     }
 
-                    // Get bech32_hrp from address
+                    // Get bech32_hrp from address, if the first account is on the same network
                     if let Some(address) = first_account_addresses.first() {
-                        if bech32_hrp.is_none() {
+                        if bech32_hrp.is_none() && client.is_none() && first_account.uses_wallet_client() {
                             bech32_hrp = Some(address.address.hrp);
                         }
                     }
@@ -118,18 +199,20 @@ where
                 let bech32_hrp = {
                     match bech32_hrp {
                         Some(bech32_hrp) => bech32_hrp,
-                        None => self.wallet.client().get_bech32_hrp().await?,
+                        None => client.as_ref().unwrap_or(self.wallet.client()).get_bech32_hrp().await?,
                     }
                 };
 
                 let first_public_address =
-                    get_first_public_address(&self.wallet.secret_manager, coin_type, account_index).await?;
+                    get_first_public_address(&self.wallet.secret_manager, coin_type, bip44_account_index).await?;
 
                 let first_public_account_address = AccountAddress {
                     address: Bech32Address::new(bech32_hrp, first_public_address),
                     key_index: 0,
                     internal: false,
                     used: false,
+                    label: None,
+                    frozen: false,
                 };
 
                 vec![first_public_account_address]
@@ -139,6 +222,7 @@ where
         let account = AccountDetails {
             index: account_index,
             coin_type,
+            bip44_account_index,
             alias: account_alias,
             public_addresses: addresses,
             internal_addresses: Vec::new(),
@@ -151,9 +235,13 @@ where
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            metadata: BTreeMap::new(),
+            archived: false,
+            watch_only: false,
+            client_options: self.client_options.clone(),
         };
 
-        let account = Account::new(account, self.wallet.inner.clone()).await?;
+        let account = Account::new(account, self.wallet.inner.clone(), client).await?;
         #[cfg(feature = "storage")]
         account.save(None).await?;
         accounts.push(account.clone());
@@ -162,6 +250,16 @@ where
     }
 }
 
+/// Returns the index for a new account, one more than the highest index, so the indexes of removed accounts aren't
+/// reused
+pub(crate) async fn next_account_index<S: SecretManage>(accounts: &[Account<S>]) -> u32 {
+    let mut next_index = 0;
+    for account in accounts {
+        next_index = next_index.max(*account.details().await.index() + 1);
+    }
+    next_index
+}
+
 /// Generate the first public address of an account
 pub(crate) async fn get_first_public_address<S: SecretManage>(
     secret_manager: &RwLock<S>,
@@ -11,9 +11,19 @@ pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 /// Amount of API request that can be sent in parallel during syncing
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
+/// Number of milestones in which outputs are checked for reorgs during syncing, older outputs could be pruned by the
+/// node
+pub(crate) const REORG_DETECTION_MILESTONES: u32 = 360;
+
 /// ms before an account actually syncs with the network, before it just returns the previous syncing result
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+/// Number of consecutive unused addresses after which the search for outputs to sweep stops
+pub(crate) const SWEEP_ADDRESS_GAP_LIMIT: u32 = 20;
+
+/// Time in seconds before their expiration in which claimable outputs are considered as expiring soon in the balance
+pub(crate) const EXPIRING_SOON_TIME: u32 = 86400;
@@ -13,7 +13,7 @@ pub mod types;
 pub(crate) mod update;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     ops::Deref,
     sync::Arc,
 };
@@ -24,17 +24,27 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction, TransactionDto,
+use self::{
+    operations::syncing::report::SyncCounters,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        Balance, OutputData, Transaction, TransactionDto,
+    },
 };
 pub use self::{
     operations::{
-        output_claiming::OutputsToClaim,
+        output_claiming::{AutoClaimPolicy, ClaimableOutputs, OutputsToClaim},
         output_consolidation::ConsolidationParams,
+        ownership_proof::{OwnershipProof, OwnershipProofDto},
+        payment_request::{
+            CreatePaymentRequestParams, PaymentRequest, PaymentUnit, PAYMENT_REQUEST_SCHEME, PAYMENT_REQUEST_VERSION,
+        },
+        public_account::{AddressDerivationProof, AddressDerivationProofDto, PublicAccount, PublicAccountDto},
+        retry::RetryOptions,
+        scheduled_send::{ScheduledSend, ScheduledTransfer},
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            SyncCheckpoint, SyncMetrics, SyncOptions, SyncPhaseDurations, SyncReport,
         },
         transaction::{
             high_level::{
@@ -46,6 +56,8 @@ pub use self::{
                     },
                     mint_nfts::MintNftParams,
                 },
+                send_all::{CarryoverPolicy, SendAllOptions},
+                send_batch::{PayoutReport, PayoutTransaction},
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
             RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
@@ -57,15 +69,16 @@ use super::core::WalletInner;
 use crate::{
     client::{
         secret::{SecretManage, SecretManager},
-        Client,
+        Client, ClientBuilder,
     },
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::{Address, Bech32Address},
             output::{dto::FoundryOutputDto, AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
-                TransactionPayload,
+                Payload, TransactionPayload,
             },
         },
         TryFromDto,
@@ -91,6 +104,97 @@ pub struct FilterOptions {
     pub nft_ids: Option<HashSet<NftId>>,
 }
 
+/// Options to filter transactions
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFilterOptions {
+    /// Return only incoming (true) or only outgoing (false) transactions.
+    pub incoming: Option<bool>,
+    /// Return only transactions with one of these inclusion states.
+    pub inclusion_states: Option<HashSet<InclusionState>>,
+    /// Filter all transactions where the timestamp is below the specified timestamp in milliseconds
+    pub lower_bound_timestamp: Option<u128>,
+    /// Filter all transactions where the timestamp is above the specified timestamp in milliseconds
+    pub upper_bound_timestamp: Option<u128>,
+    /// Filter all transactions where the summed amount of the outputs is below the specified amount
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub min_amount: Option<u64>,
+    /// Filter all transactions where the summed amount of the outputs is above the specified amount
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub max_amount: Option<u64>,
+    /// Return only transactions with (true) or without (false) a tagged data payload.
+    pub has_tagged_data: Option<bool>,
+    /// Return only transactions with a tagged data payload whose tag starts with these bytes.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag_prefix: Option<Vec<u8>>,
+    /// Return only transactions with a note containing this text, ignoring case.
+    pub note: Option<String>,
+}
+
+impl TransactionFilterOptions {
+    fn matches(&self, transaction: &Transaction) -> bool {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let tag = match essence.payload() {
+            Some(Payload::TaggedData(tagged_data)) => Some(tagged_data.tag()),
+            _ => None,
+        };
+        let amount = essence.outputs().iter().map(Output::amount).sum::<u64>();
+
+        self.incoming.map_or(true, |incoming| transaction.incoming == incoming)
+            && self.inclusion_states.as_ref().map_or(true, |inclusion_states| {
+                inclusion_states.contains(&transaction.inclusion_state)
+            })
+            && self
+                .lower_bound_timestamp
+                .map_or(true, |timestamp| transaction.timestamp >= timestamp)
+            && self
+                .upper_bound_timestamp
+                .map_or(true, |timestamp| transaction.timestamp <= timestamp)
+            && self.min_amount.map_or(true, |min_amount| amount >= min_amount)
+            && self.max_amount.map_or(true, |max_amount| amount <= max_amount)
+            && self
+                .has_tagged_data
+                .map_or(true, |has_tagged_data| tag.is_some() == has_tagged_data)
+            && self
+                .tag_prefix
+                .as_ref()
+                .map_or(true, |tag_prefix| tag.is_some_and(|tag| tag.starts_with(tag_prefix)))
+            && self.note.as_ref().map_or(true, |text| {
+                transaction
+                    .note
+                    .as_ref()
+                    .is_some_and(|note| note.to_lowercase().contains(&text.to_lowercase()))
+            })
+    }
+}
+
+/// Options to filter accounts
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountFilterOptions {
+    /// Also return archived accounts.
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Return only accounts with an alias containing this text, ignoring case.
+    pub alias: Option<String>,
+    /// Return only accounts that have all of these metadata entries.
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl AccountFilterOptions {
+    pub(crate) fn matches(&self, account_details: &AccountDetails) -> bool {
+        (self.include_archived || !account_details.archived)
+            && self.alias.as_ref().map_or(true, |text| {
+                account_details.alias.to_lowercase().contains(&text.to_lowercase())
+            })
+            && self
+                .metadata
+                .iter()
+                .all(|(key, value)| account_details.metadata.get(key) == Some(value))
+    }
+}
+
 /// Details of an account.
 #[derive(Clone, Debug, Eq, PartialEq, Getters, Setters)]
 #[getset(get = "pub")]
@@ -99,6 +203,9 @@ pub struct AccountDetails {
     index: u32,
     /// The coin type
     coin_type: u32,
+    /// The BIP44 account index the addresses are derived with, `m/44'/coin_type'/bip44_account_index'`. It's the
+    /// account index, unless another derivation path was chosen when creating the account.
+    bip44_account_index: u32,
     /// The account alias.
     alias: String,
     /// Public addresses
@@ -135,6 +242,127 @@ pub struct AccountDetails {
     inaccessible_incoming_transactions: HashSet<TransactionId>,
     /// Foundries for native tokens in outputs
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Arbitrary key-value metadata set by the user
+    metadata: BTreeMap<String, String>,
+    /// Archived accounts are hidden when filtering accounts and aren't synced in the background
+    archived: bool,
+    /// Watch-only accounts only have the addresses of a public account export and can't sign, so they can be synced
+    /// but not used to send
+    watch_only: bool,
+    /// The client options of an account bound to another network than the wallet, it uses its own client then
+    client_options: Option<ClientBuilder>,
+}
+
+impl AccountDetails {
+    /// Creates the details of a watch-only account with the addresses of a public account export.
+    pub(crate) fn new_watch_only(index: u32, alias: String, public_account: PublicAccount) -> Self {
+        let (mut internal_addresses, mut public_addresses): (Vec<_>, Vec<_>) = public_account
+            .addresses
+            .into_iter()
+            .map(|proof| AccountAddress {
+                address: proof.address,
+                key_index: proof.key_index,
+                internal: proof.internal,
+                used: false,
+                label: None,
+                frozen: false,
+            })
+            .partition(|address| address.internal);
+        public_addresses.sort_by_key(|address| address.key_index);
+        internal_addresses.sort_by_key(|address| address.key_index);
+
+        Self {
+            index,
+            coin_type: public_account.coin_type,
+            bip44_account_index: public_account.account_index,
+            alias,
+            public_addresses,
+            internal_addresses,
+            addresses_with_unspent_outputs: Vec::new(),
+            outputs: HashMap::new(),
+            locked_outputs: HashSet::new(),
+            unspent_outputs: HashMap::new(),
+            transactions: HashMap::new(),
+            pending_transactions: HashSet::new(),
+            incoming_transactions: HashMap::new(),
+            inaccessible_incoming_transactions: HashSet::new(),
+            native_token_foundries: HashMap::new(),
+            metadata: BTreeMap::new(),
+            archived: false,
+            watch_only: true,
+            client_options: None,
+        }
+    }
+
+    /// Returns an error if the account is watch-only, so there are no keys to sign with.
+    pub(crate) fn check_can_sign(&self) -> Result<()> {
+        if self.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(self.alias.clone()));
+        }
+        Ok(())
+    }
+
+    /// Returns the frozen addresses of the account, their outputs must not be used as inputs.
+    pub(crate) fn frozen_addresses(&self) -> HashSet<Address> {
+        self.public_addresses
+            .iter()
+            .chain(&self.internal_addresses)
+            .filter(|address| address.frozen)
+            .map(|address| *address.address.inner())
+            .collect()
+    }
+
+    /// Returns a mutable reference to an address of the account.
+    pub(crate) fn account_address_mut(&mut self, address: &Bech32Address) -> Result<&mut AccountAddress> {
+        self.public_addresses
+            .iter_mut()
+            .chain(&mut self.internal_addresses)
+            .find(|account_address| &account_address.address == address)
+            .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(*address))
+    }
+}
+
+#[cfg(any(feature = "storage", feature = "stronghold"))]
+impl From<crate::wallet::migration::interchange::AccountInterchange> for AccountDetails {
+    fn from(account: crate::wallet::migration::interchange::AccountInterchange) -> Self {
+        let (internal_addresses, public_addresses) =
+            account.addresses.into_iter().partition(|address| address.internal);
+        let mut transactions = HashMap::new();
+        let mut pending_transactions = HashSet::new();
+        let mut incoming_transactions = HashMap::new();
+        for transaction in account.transactions {
+            if transaction.incoming {
+                incoming_transactions.insert(transaction.transaction_id, transaction);
+            } else {
+                if transaction.inclusion_state == InclusionState::Pending {
+                    pending_transactions.insert(transaction.transaction_id);
+                }
+                transactions.insert(transaction.transaction_id, transaction);
+            }
+        }
+
+        Self {
+            index: account.index,
+            coin_type: account.coin_type,
+            bip44_account_index: account.bip44_account_index,
+            alias: account.alias,
+            public_addresses,
+            internal_addresses,
+            addresses_with_unspent_outputs: Vec::new(),
+            outputs: HashMap::new(),
+            locked_outputs: HashSet::new(),
+            unspent_outputs: HashMap::new(),
+            transactions,
+            pending_transactions,
+            incoming_transactions,
+            inaccessible_incoming_transactions: HashSet::new(),
+            native_token_foundries: HashMap::new(),
+            metadata: account.metadata.into_iter().collect(),
+            archived: false,
+            watch_only: false,
+            client_options: None,
+        }
+    }
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -167,6 +395,21 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    pub(crate) sync_checkpoint: Mutex<Option<SyncCheckpoint>>,
+    pub(crate) last_sync_report: Mutex<Option<SyncReport>>,
+    // outputs counted by the sync operations for the report
+    pub(crate) sync_counters: SyncCounters,
+    // transactions created with a request ID, by request ID
+    pub(crate) submission_journal: Mutex<HashMap<String, Transaction>>,
+    // payment requests that weren't paid yet
+    pub(crate) payment_requests: Mutex<Vec<PaymentRequest>>,
+    // transfers that are sent once they are due, ordered by time
+    pub(crate) scheduled_sends: Mutex<Vec<ScheduledSend>>,
+    // queue of the operations that send transactions, held from the input selection until the transaction is
+    // submitted, so concurrent tasks are served in order and see the inputs and balance left by the previous ones
+    pub(crate) operation_queue: Mutex<()>,
+    // the client of an account bound to another network than the wallet
+    client: Option<Client>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -182,8 +425,13 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Create a new Account with an AccountDetails
-    pub(crate) async fn new(details: AccountDetails, wallet: Arc<WalletInner<S>>) -> Result<Self> {
+    /// Create a new Account with an AccountDetails. The client of an account bound to another network is built from
+    /// its client options, unless it's provided.
+    pub(crate) async fn new(
+        details: AccountDetails,
+        wallet: Arc<WalletInner<S>>,
+        client: Option<Client>,
+    ) -> Result<Self> {
         #[cfg(feature = "storage")]
         let default_sync_options = wallet
             .storage_manager
@@ -194,6 +442,53 @@ where
             .unwrap_or_default();
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
+        #[cfg(feature = "storage")]
+        let sync_checkpoint = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_sync_checkpoint(*details.index())
+            .await?;
+        #[cfg(not(feature = "storage"))]
+        let sync_checkpoint = None;
+        #[cfg(feature = "storage")]
+        let submission_journal = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_submission_journal(*details.index())
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(request_id, transaction)| Ok((request_id, Transaction::try_from_dto(transaction)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+        #[cfg(not(feature = "storage"))]
+        let submission_journal = HashMap::new();
+        #[cfg(feature = "storage")]
+        let payment_requests = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_payment_requests(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let payment_requests = Vec::new();
+        #[cfg(feature = "storage")]
+        let scheduled_sends = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_scheduled_sends(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let scheduled_sends = Vec::new();
+        let client = match (client, &details.client_options) {
+            (Some(client), _) => Some(client),
+            (None, Some(client_options)) => Some(client_options.clone().finish().await?),
+            (None, None) => None,
+        };
 
         Ok(Self {
             wallet,
@@ -201,13 +496,26 @@ where
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                sync_checkpoint: Mutex::new(sync_checkpoint),
+                last_sync_report: Default::default(),
+                sync_counters: Default::default(),
+                submission_journal: Mutex::new(submission_journal),
+                payment_requests: Mutex::new(payment_requests),
+                scheduled_sends: Mutex::new(scheduled_sends),
+                operation_queue: Mutex::new(()),
+                client,
             }),
         })
     }
 
-    // Get the Client
+    // Get the Client, the one of the account if it's bound to another network than the wallet
     pub fn client(&self) -> &Client {
-        &self.wallet.client
+        self.inner.client.as_ref().unwrap_or(&self.wallet.client)
+    }
+
+    /// Returns whether the account uses the client of the wallet, instead of being bound to another network
+    pub fn uses_wallet_client(&self) -> bool {
+        self.inner.client.is_none()
     }
 
     /// Get the [`Output`] that minted a native token by the token ID. First try to get it
@@ -430,6 +738,22 @@ impl AccountInner {
         self.details().await.transactions.values().cloned().collect()
     }
 
+    /// Returns the sent and incoming transactions of the account which match the filter, newest first
+    pub async fn filter_transactions(&self, filter: TransactionFilterOptions) -> Vec<Transaction> {
+        let account_details = self.details().await;
+
+        let mut transactions = account_details
+            .transactions
+            .values()
+            .chain(account_details.incoming_transactions.values())
+            .filter(|transaction| filter.matches(transaction))
+            .cloned()
+            .collect::<Vec<_>>();
+        transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+        transactions
+    }
+
     /// Returns all pending transactions of the account
     pub async fn pending_transactions(&self) -> Vec<Transaction> {
         let mut transactions = Vec::new();
@@ -464,6 +788,8 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         incoming: true,
         note: None,
         inputs,
+        ownership: None,
+        attachments: Vec::new(),
     })
 }
 
@@ -475,6 +801,9 @@ pub struct AccountDetailsDto {
     pub index: u32,
     /// The coin type
     pub coin_type: u32,
+    /// The BIP44 account index, the account index if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bip44_account_index: Option<u32>,
     /// The account alias.
     pub alias: String,
     /// Public addresses
@@ -498,6 +827,18 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Arbitrary key-value metadata set by the user
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+    /// Whether the account is archived
+    #[serde(default)]
+    pub archived: bool,
+    /// Whether the account is watch-only
+    #[serde(default)]
+    pub watch_only: bool,
+    /// The client options of an account bound to another network than the wallet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_options: Option<ClientBuilder>,
 }
 
 impl TryFromDto for AccountDetails {
@@ -511,6 +852,7 @@ impl TryFromDto for AccountDetails {
         Ok(Self {
             index: dto.index,
             coin_type: dto.coin_type,
+            bip44_account_index: dto.bip44_account_index.unwrap_or(dto.index),
             alias: dto.alias,
             public_addresses: dto.public_addresses,
             internal_addresses: dto.internal_addresses,
@@ -543,6 +885,10 @@ impl TryFromDto for AccountDetails {
                 .into_iter()
                 .map(|(id, o)| Ok((id, FoundryOutput::try_from_dto_with_params(o, &params)?)))
                 .collect::<crate::wallet::Result<_>>()?,
+            metadata: dto.metadata,
+            archived: dto.archived,
+            watch_only: dto.watch_only,
+            client_options: dto.client_options,
         })
     }
 }
@@ -552,6 +898,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
         Self {
             index: *value.index(),
             coin_type: *value.coin_type(),
+            bip44_account_index: Some(*value.bip44_account_index()),
             alias: value.alias().clone(),
             public_addresses: value.public_addresses().clone(),
             internal_addresses: value.internal_addresses().clone(),
@@ -583,6 +930,10 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            metadata: value.metadata().clone(),
+            archived: *value.archived(),
+            watch_only: *value.watch_only(),
+            client_options: value.client_options().clone(),
         }
     }
 }
@@ -591,6 +942,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
 fn serialize() {
     use core::str::FromStr;
 
+    use self::types::{AttachmentKind, TransactionAttachment};
     use crate::types::block::{
         address::{Address, Ed25519Address},
         input::{Input, UtxoInput},
@@ -602,6 +954,7 @@ fn serialize() {
         protocol::ProtocolParameters,
         signature::{Ed25519Signature, Signature},
         unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        BlockId,
     };
 
     const TRANSACTION_ID: &str = "0x24a1f46bdb6b2bf38f1c59f73cdd4ae5b418804bb231d76d06fbf246498d5883";
@@ -660,6 +1013,21 @@ fn serialize() {
         incoming: false,
         note: None,
         inputs: Vec::new(),
+        ownership: None,
+        attachments: vec![
+            TransactionAttachment {
+                kind: AttachmentKind::Submission,
+                block_id: None,
+                timestamp: 1_700_000_000_000,
+                error: Some("no healthy node available".to_string()),
+            },
+            TransactionAttachment {
+                kind: AttachmentKind::Reattachment,
+                block_id: Some(BlockId::new([1; 32])),
+                timestamp: 1_700_000_060_000,
+                error: None,
+            },
+        ],
     };
 
     let mut incoming_transactions = HashMap::new();
@@ -671,6 +1039,7 @@ fn serialize() {
     let account = AccountDetails {
         index: 0,
         coin_type: 4218,
+        bip44_account_index: 0,
         alias: "0".to_string(),
         public_addresses: Vec::new(),
         internal_addresses: Vec::new(),
@@ -683,6 +1052,10 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        metadata: BTreeMap::new(),
+        archived: false,
+        watch_only: false,
+        client_options: None,
     };
 
     let deser_account = AccountDetails::try_from_dto(
@@ -694,6 +1067,155 @@ fn serialize() {
     assert_eq!(account, deser_account);
 }
 
+#[cfg(test)]
+fn mock_transaction_with_tag(transaction_id: TransactionId, tag: Option<&[u8]>) -> Transaction {
+    use crate::types::block::{
+        payload::{transaction::RegularTransactionEssence, TaggedDataPayload},
+        protocol::ProtocolParameters,
+    };
+
+    let mut transaction = Transaction::mock(transaction_id, &[OutputId::new(transaction_id, 0).unwrap()]);
+    if let Some(tag) = tag {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let essence = RegularTransactionEssence::builder(essence.network_id(), *essence.inputs_commitment())
+            .with_inputs(essence.inputs().to_vec())
+            .with_outputs(essence.outputs().to_vec())
+            .with_payload(TaggedDataPayload::new(tag.to_vec(), b"data".to_vec()).unwrap())
+            .finish_with_params(ProtocolParameters::default())
+            .unwrap();
+        transaction.payload = TransactionPayload::new(
+            TransactionEssence::Regular(essence),
+            transaction.payload.unlocks().clone(),
+        )
+        .unwrap();
+    }
+    transaction
+}
+
+#[test]
+fn transaction_filter_options() {
+    let mut transaction = mock_transaction_with_tag(TransactionId::new([1; 32]), Some(b"invoice-42"));
+    transaction.timestamp = 1_000;
+    transaction.inclusion_state = InclusionState::Confirmed;
+    transaction.note = Some("Coffee at the Corner".to_string());
+    let untagged = mock_transaction_with_tag(TransactionId::new([2; 32]), None);
+
+    // Without filters every transaction matches
+    assert!(TransactionFilterOptions::default().matches(&transaction));
+    assert!(TransactionFilterOptions::default().matches(&untagged));
+
+    let matches = |filter: TransactionFilterOptions| filter.matches(&transaction);
+    assert!(matches(TransactionFilterOptions {
+        incoming: Some(false),
+        inclusion_states: Some(HashSet::from([InclusionState::Pending, InclusionState::Confirmed])),
+        lower_bound_timestamp: Some(1_000),
+        upper_bound_timestamp: Some(1_000),
+        // The mock transaction has a single output with 1_000_000
+        min_amount: Some(1_000_000),
+        max_amount: Some(1_000_000),
+        has_tagged_data: Some(true),
+        tag_prefix: Some(b"invoice".to_vec()),
+        note: Some("coffee at".to_string()),
+    }));
+
+    // Every option excludes the transaction on its own
+    let excluding = [
+        TransactionFilterOptions {
+            incoming: Some(true),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            inclusion_states: Some(HashSet::from([InclusionState::Pending])),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            lower_bound_timestamp: Some(1_001),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            upper_bound_timestamp: Some(999),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            min_amount: Some(1_000_001),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            max_amount: Some(999_999),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            has_tagged_data: Some(false),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            tag_prefix: Some(b"receipt".to_vec()),
+            ..Default::default()
+        },
+        TransactionFilterOptions {
+            note: Some("tea".to_string()),
+            ..Default::default()
+        },
+    ];
+    for filter in excluding {
+        assert!(!matches(filter.clone()), "{filter:?}");
+    }
+
+    // Transactions without tagged data or note don't match filters on them
+    let untagged_matches = |filter: TransactionFilterOptions| filter.matches(&untagged);
+    assert!(untagged_matches(TransactionFilterOptions {
+        has_tagged_data: Some(false),
+        ..Default::default()
+    }));
+    assert!(!untagged_matches(TransactionFilterOptions {
+        tag_prefix: Some(Vec::new()),
+        ..Default::default()
+    }));
+    assert!(!untagged_matches(TransactionFilterOptions {
+        note: Some(String::new()),
+        ..Default::default()
+    }));
+}
+
+#[tokio::test]
+async fn filter_transactions() {
+    let storage_path = "test-storage/filter_transactions";
+    let account = Account::mock(storage_path).await;
+
+    let mut sent = mock_transaction_with_tag(TransactionId::new([1; 32]), None);
+    sent.timestamp = 1_000;
+    let mut incoming = mock_transaction_with_tag(TransactionId::new([2; 32]), Some(b"tag"));
+    incoming.timestamp = 2_000;
+    incoming.incoming = true;
+    let mut older_incoming = mock_transaction_with_tag(TransactionId::new([3; 32]), None);
+    older_incoming.incoming = true;
+    let mut account_details = account.details_mut().await;
+    account_details.transactions.insert(sent.transaction_id, sent.clone());
+    for transaction in [&incoming, &older_incoming] {
+        account_details
+            .incoming_transactions
+            .insert(transaction.transaction_id, transaction.clone());
+    }
+    drop(account_details);
+
+    // Sent and incoming transactions, newest first
+    assert_eq!(
+        account.filter_transactions(TransactionFilterOptions::default()).await,
+        [incoming.clone(), sent, older_incoming.clone()]
+    );
+    assert_eq!(
+        account
+            .filter_transactions(TransactionFilterOptions {
+                incoming: Some(true),
+                ..Default::default()
+            })
+            .await,
+        [incoming, older_incoming]
+    );
+
+    std::fs::remove_dir_all(storage_path).ok();
+}
+
 #[cfg(test)]
 impl AccountDetails {
     /// Returns a mock of this type with the following values:
@@ -706,6 +1228,7 @@ impl AccountDetails {
         Self {
             index: 0,
             coin_type: 4218,
+            bip44_account_index: 0,
             alias: "Alice".to_string(),
             public_addresses: vec![AccountAddress {
                 address: crate::types::block::address::Bech32Address::from_str(
@@ -715,6 +1238,8 @@ impl AccountDetails {
                 key_index: 0,
                 internal: false,
                 used: false,
+                label: None,
+                frozen: false,
             }],
             internal_addresses: Vec::new(),
             addresses_with_unspent_outputs: Vec::new(),
@@ -726,6 +1251,129 @@ impl AccountDetails {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            metadata: BTreeMap::new(),
+            archived: false,
+            watch_only: false,
+            client_options: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Account {
+    /// Returns the first account of a new wallet with a random mnemonic and the default protocol parameters. Its node
+    /// answers requests for the metadata of an output with the output being unspent, doesn't know any block and
+    /// rejects all other requests, e.g. to submit blocks. With the storage feature the wallet is stored at the path,
+    /// which the caller has to remove.
+    pub(crate) async fn mock(storage_path: &str) -> Self {
+        Self::mock_with_spent_outputs(storage_path, HashMap::new()).await
+    }
+
+    /// Like [`Account::mock()`], but the node answers that the given outputs were spent by the transactions.
+    #[allow(unused_variables)]
+    pub(crate) async fn mock_with_spent_outputs(
+        storage_path: &str,
+        spent_outputs: HashMap<OutputId, TransactionId>,
+    ) -> Self {
+        use crate::{
+            client::{
+                constants::SHIMMER_COIN_TYPE,
+                node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+                secret::mnemonic::MnemonicSecretManager,
+                Client,
+            },
+            types::block::{output::OutputMetadata, protocol::ProtocolParameters, BlockId},
+            wallet::{ClientOptions, Wallet},
+        };
+
+        struct MockNode {
+            spent_outputs: HashMap<OutputId, TransactionId>,
+        }
+
+        #[async_trait::async_trait]
+        impl HttpTransport for MockNode {
+            async fn send(&self, request: HttpRequest) -> crate::client::node_api::error::Result<HttpResponse> {
+                let segments = request.url.path_segments().into_iter().flatten().collect::<Vec<_>>();
+                match segments.as_slice() {
+                    ["api", "core", "v2", "outputs", output_id, "metadata"] => {
+                        if let Ok(output_id) = output_id.parse::<OutputId>() {
+                            let transaction_id_spent = self.spent_outputs.get(&output_id).copied();
+                            let metadata = OutputMetadata::new(
+                                BlockId::new([0; 32]),
+                                output_id,
+                                transaction_id_spent.is_some(),
+                                transaction_id_spent.map(|_| 2),
+                                transaction_id_spent.map(|_| 1),
+                                transaction_id_spent,
+                                1,
+                                0,
+                                2,
+                            );
+                            return Ok(HttpResponse {
+                                status: 200,
+                                headers: Vec::new(),
+                                body: serde_json::to_vec(&metadata).unwrap(),
+                            });
+                        }
+                    }
+                    ["api", "core", "v2", "blocks", _, "metadata"]
+                    | ["api", "core", "v2", "transactions", _, "included-block"]
+                    | ["api", "core", "v2", "transactions", _, "included-block", "metadata"] => {
+                        return Ok(HttpResponse {
+                            status: 404,
+                            headers: Vec::new(),
+                            body: br#"{"error":{"code":"404","message":"unknown to the mock node"}}"#.to_vec(),
+                        });
+                    }
+                    _ => {}
+                }
+                Ok(HttpResponse {
+                    status: 400,
+                    headers: Vec::new(),
+                    body: br#"{"error":{"code":"400","message":"rejected by the mock node"}}"#.to_vec(),
+                })
+            }
         }
+
+        let client_options = ClientOptions::new()
+            .with_node("http://localhost:14265")
+            .unwrap()
+            .with_ignore_node_health()
+            .with_protocol_parameters(ProtocolParameters::default())
+            .with_http_client(MockNode { spent_outputs });
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic().unwrap()).unwrap();
+
+        #[allow(unused_mut)]
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(client_options)
+            .with_coin_type(SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            wallet_builder = wallet_builder.with_storage_path(storage_path);
+        }
+
+        let wallet = wallet_builder.finish().await.unwrap();
+        wallet.create_account().finish().await.unwrap()
+    }
+
+    /// Adds a pending transaction of the network of the client that wasn't submitted yet and spends an output the
+    /// account doesn't know, see [`Transaction::mock()`]. Returns the id of the transaction.
+    pub(crate) async fn add_mock_pending_transaction(&self) -> TransactionId {
+        self.add_mock_pending_transaction_with_inputs(&[OutputId::new(TransactionId::new([0xff; 32]), 0).unwrap()])
+            .await
+    }
+
+    /// Like [`Account::add_mock_pending_transaction()`], but the transaction spends the given outputs.
+    pub(crate) async fn add_mock_pending_transaction_with_inputs(&self, inputs: &[OutputId]) -> TransactionId {
+        let mut transaction = Transaction::mock(TransactionId::new([0; 32]), inputs);
+        // The account stores updated transactions by the id of their payload
+        let transaction_id = transaction.payload.id();
+        transaction.transaction_id = transaction_id;
+        transaction.network_id = self.client().get_network_id().await.unwrap();
+        let mut account_details = self.details_mut().await;
+        account_details.transactions.insert(transaction_id, transaction);
+        account_details.pending_transactions.insert(transaction_id);
+        transaction_id
     }
 }
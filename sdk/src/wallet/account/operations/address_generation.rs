@@ -47,6 +47,7 @@ where
         }
 
         let account_details = self.details().await;
+        account_details.check_can_sign()?;
 
         // get the highest index for the public or internal addresses
         let highest_current_index_plus_one = if options.internal {
@@ -105,7 +106,7 @@ where
                             .await
                             .generate_ed25519_addresses(
                                 account_details.coin_type,
-                                account_details.index,
+                                account_details.bip44_account_index,
                                 address_index..address_index + 1,
                                 Some(changed_options),
                             )
@@ -126,7 +127,7 @@ where
                         .await
                         .generate_ed25519_addresses(
                             account_details.coin_type,
-                            account_details.index,
+                            account_details.bip44_account_index,
                             address_index..address_index + 1,
                             Some(options),
                         )
@@ -141,7 +142,7 @@ where
                     .await
                     .generate_ed25519_addresses(
                         account_details.coin_type,
-                        account_details.index,
+                        account_details.bip44_account_index,
                         address_range,
                         Some(options),
                     )
@@ -157,7 +158,7 @@ where
             .await
             .generate_ed25519_addresses(
                 account_details.coin_type,
-                account_details.index,
+                account_details.bip44_account_index,
                 address_range,
                 Some(options),
             )
@@ -173,6 +174,8 @@ where
                 key_index: highest_current_index_plus_one + index as u32,
                 internal: options.internal,
                 used: false,
+                label: None,
+                frozen: false,
             })
             .collect();
 
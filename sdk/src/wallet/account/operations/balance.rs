@@ -6,14 +6,15 @@ use primitive_types::U256;
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::Bech32Address,
-        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        address::{Address, Bech32Address},
+        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, OutputId, Rent},
         ConvertTo,
     },
     wallet::{
         account::{
+            constants::EXPIRING_SOON_TIME,
             operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-            types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance},
+            types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance, TimeBoundAmount},
             Account, AccountDetails, OutputsToClaim,
         },
         Error, Result,
@@ -69,8 +70,10 @@ where
 
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
+        let frozen_addresses = account_details.frozen_addresses();
 
         for address_with_unspent_outputs in addresses_with_unspent_outputs {
+            let frozen = frozen_addresses.contains(address_with_unspent_outputs.address.inner());
             #[cfg(feature = "participation")]
             {
                 if let Some(voting_output) = &voting_output {
@@ -86,6 +89,11 @@ where
                     if data.network_id != network_id {
                         continue;
                     }
+                    // Outputs of frozen addresses are part of the total, but not available like locked outputs
+                    let unavailable = frozen || account_details.locked_outputs.contains(output_id);
+                    if frozen {
+                        balance.frozen_outputs.push(*output_id);
+                    }
 
                     let output = &data.output;
                     let rent = output.rent_cost(&rent_structure);
@@ -98,7 +106,7 @@ where
                             balance.base_coin.total += output.amount();
                             // Add storage deposit
                             balance.required_storage_deposit.alias += rent;
-                            if !account_details.locked_outputs.contains(output_id) {
+                            if !unavailable {
                                 total_rent_amount += rent;
                             }
                             // Add native tokens
@@ -112,7 +120,7 @@ where
                             balance.base_coin.total += output.amount();
                             // Add storage deposit
                             balance.required_storage_deposit.foundry += rent;
-                            if !account_details.locked_outputs.contains(output_id) {
+                            if !unavailable {
                                 total_rent_amount += rent;
                             }
                             // Add native tokens
@@ -144,13 +152,13 @@ where
                                         .native_tokens()
                                         .map(|native_tokens| !native_tokens.is_empty())
                                         .unwrap_or(false)
-                                        && !account_details.locked_outputs.contains(output_id)
+                                        && !unavailable
                                     {
                                         total_rent_amount += rent;
                                     }
                                 } else if output.is_nft() {
                                     balance.required_storage_deposit.nft += rent;
-                                    if !account_details.locked_outputs.contains(output_id) {
+                                    if !unavailable {
                                         total_rent_amount += rent;
                                     }
                                 }
@@ -167,12 +175,23 @@ where
                                 let local_time = self.client().get_time_checked().await?;
                                 let is_claimable =
                                     self.claimable_outputs(OutputsToClaim::All).await?.contains(output_id);
+                                let is_account_address =
+                                    |address: &Address| account_addresses.iter().any(|a| a.address.inner == *address);
+                                // If output has a StorageDepositReturnUnlockCondition, the amount of it needs to be
+                                // sent back, unless we're sending to ourself
+                                let storage_deposit_return = output
+                                    .unlock_conditions()
+                                    .and_then(|u| u.storage_deposit_return())
+                                    .filter(|sdr| !is_account_address(sdr.return_address()))
+                                    .map_or(0, |sdr| sdr.amount());
 
                                 // For outputs that are expired or have a timelock unlock condition, but no expiration
                                 // unlock condition and we then can unlock them, then
                                 // they can never be not available for us anymore
                                 // and should be added to the balance
                                 if is_claimable {
+                                    balance.base_coin.storage_deposit_return += storage_deposit_return;
+
                                     // check if output can be unlocked always from now on, in that case it should be
                                     // added to the total amount
                                     let output_can_be_unlocked_now_and_in_future =
@@ -186,27 +205,7 @@ where
                                         );
 
                                     if output_can_be_unlocked_now_and_in_future {
-                                        // If output has a StorageDepositReturnUnlockCondition, the amount of it should
-                                        // be subtracted, because this part
-                                        // needs to be sent back
-                                        let amount = output
-                                            .unlock_conditions()
-                                            .and_then(|u| u.storage_deposit_return())
-                                            .map_or_else(
-                                                || output.amount(),
-                                                |sdr| {
-                                                    if account_addresses
-                                                        .iter()
-                                                        .any(|a| a.address.inner == *sdr.return_address())
-                                                    {
-                                                        // sending to ourself, we get the full amount
-                                                        output.amount()
-                                                    } else {
-                                                        // Sending to someone else
-                                                        output.amount() - sdr.amount()
-                                                    }
-                                                },
-                                            );
+                                        let amount = output.amount() - storage_deposit_return;
 
                                         // add nft_id for nft outputs
                                         if let Output::Nft(output) = &output {
@@ -227,13 +226,13 @@ where
                                                 .native_tokens()
                                                 .map(|native_tokens| !native_tokens.is_empty())
                                                 .unwrap_or(false)
-                                                && !account_details.locked_outputs.contains(output_id)
+                                                && !unavailable
                                             {
                                                 total_rent_amount += rent;
                                             }
                                         } else if output.is_nft() {
                                             balance.required_storage_deposit.nft += rent;
-                                            if !account_details.locked_outputs.contains(output_id) {
+                                            if !unavailable {
                                                 total_rent_amount += rent;
                                             }
                                         }
@@ -245,6 +244,14 @@ where
                                     } else {
                                         // only add outputs that can't be locked now and at any point in the future
                                         balance.potentially_locked_outputs.insert(*output_id, true);
+                                        add_time_bound_amount(
+                                            &mut balance,
+                                            output_id,
+                                            output,
+                                            storage_deposit_return,
+                                            is_account_address,
+                                            local_time,
+                                        );
                                     }
                                 } else {
                                     // Don't add expired outputs that can't ever be unlocked by us
@@ -260,6 +267,14 @@ where
                                     } else {
                                         balance.potentially_locked_outputs.insert(*output_id, false);
                                     }
+                                    add_time_bound_amount(
+                                        &mut balance,
+                                        output_id,
+                                        output,
+                                        storage_deposit_return,
+                                        is_account_address,
+                                        local_time,
+                                    );
                                 }
                             }
                         }
@@ -307,6 +322,23 @@ where
             }
         }
 
+        for frozen_output in &balance.frozen_outputs {
+            // Skip potentially_locked_outputs, as their amounts aren't added to the balance, and locked outputs, as
+            // their amounts were subtracted already
+            if balance.potentially_locked_outputs.contains_key(frozen_output)
+                || account_details.locked_outputs.contains(frozen_output)
+            {
+                continue;
+            }
+            if let Some(output_data) = account_details.unspent_outputs.get(frozen_output) {
+                locked_amount += output_data.output.amount();
+                balance.base_coin.frozen += output_data.output.amount();
+                if let Some(native_tokens) = output_data.output.native_tokens() {
+                    locked_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+            }
+        }
+
         log::debug!(
             "[BALANCE] total_amount: {}, locked_amount: {}, total_rent_amount: {}",
             balance.base_coin.total,
@@ -356,3 +388,64 @@ where
         Ok(balance)
     }
 }
+
+/// Adds the amount of an output with time related unlock conditions to the balance, if the account can unlock it now
+/// or at a later time.
+fn add_time_bound_amount(
+    balance: &mut Balance,
+    output_id: &OutputId,
+    output: &Output,
+    storage_deposit_return: u64,
+    is_account_address: impl Fn(&Address) -> bool,
+    local_time: u32,
+) {
+    let Some(unlock_conditions) = output.unlock_conditions() else {
+        return;
+    };
+    let timelock = unlock_conditions.timelock().map(|timelock| timelock.timestamp());
+    let expiration = unlock_conditions.expiration();
+
+    let time_bound_amount = if unlock_conditions
+        .address()
+        .is_some_and(|address| is_account_address(address.address()))
+    {
+        // Can be unlocked once the timelock expired, until the output expires
+        TimeBoundAmount {
+            output_id: *output_id,
+            amount: output.amount() - storage_deposit_return,
+            unlockable_from: timelock,
+            unlockable_until: expiration.map(|expiration| expiration.timestamp()),
+        }
+    } else if let Some(expiration) = expiration.filter(|expiration| is_account_address(expiration.return_address())) {
+        // Can be unlocked once the output expired and the timelock expired
+        TimeBoundAmount {
+            output_id: *output_id,
+            amount: output.amount(),
+            unlockable_from: Some(timelock.unwrap_or_default().max(expiration.timestamp())),
+            unlockable_until: None,
+        }
+    } else {
+        return;
+    };
+
+    // Expired, can't be unlocked by us anymore
+    if time_bound_amount
+        .unlockable_until
+        .is_some_and(|until| until <= local_time)
+    {
+        return;
+    }
+
+    if time_bound_amount.is_unlockable_at(local_time) {
+        if time_bound_amount
+            .unlockable_until
+            .is_some_and(|until| until - local_time <= EXPIRING_SOON_TIME)
+        {
+            balance.base_coin.expiring_soon += time_bound_amount.amount;
+        }
+    } else {
+        balance.base_coin.timelocked += time_bound_amount.amount;
+    }
+
+    balance.time_bound_amounts.push(time_bound_amount);
+}
@@ -0,0 +1,210 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, AliasAddress, NftAddress},
+        input::Input,
+        output::{NativeTokensBuilder, Output, OutputId, OutputMetadata},
+        payload::transaction::TransactionEssence,
+    },
+    wallet::account::{types::HistoricalBalance, Account, AccountDetails, OutputData},
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Reconstructs the balance and the unspent outputs of the account at a past milestone, e.g. for auditing or tax
+    /// reporting. Only outputs the account synced are considered, so it should be synced before.
+    ///
+    /// Outputs count if they were booked at or before the milestone and not spent at it. The milestone an output was
+    /// spent in is taken from its metadata, from the outputs of the spending transaction, or requested from the nodes,
+    /// preferring a permanode, as other nodes prune old milestones. Requested metadata is stored with the outputs, so
+    /// it's only requested once.
+    pub async fn balance_at(&self, milestone_index: u32) -> crate::wallet::Result<HistoricalBalance> {
+        log::debug!("[BALANCE] balance_at {milestone_index}");
+        let milestone_timestamp = self
+            .client()
+            .get_milestone_by_index(milestone_index)
+            .await?
+            .essence()
+            .timestamp();
+        let network_id = self.client().get_network_id().await?;
+
+        let (booked_outputs, unknown_spent_output_ids) = {
+            let account_details = self.details().await;
+            let spent_milestones = spent_milestones_from_transactions(&account_details);
+            let mut booked_outputs = Vec::new();
+            let mut unknown_spent_output_ids = Vec::new();
+
+            for output_data in account_details.outputs.values() {
+                if output_data.network_id != network_id
+                    || output_data.metadata.milestone_index_booked() > milestone_index
+                {
+                    continue;
+                }
+                let spent_milestone = output_data
+                    .metadata
+                    .milestone_index_spent()
+                    .or_else(|| spent_milestones.get(&output_data.output_id).copied());
+                match spent_milestone {
+                    Some(spent_milestone) if spent_milestone <= milestone_index => {}
+                    None if output_data.is_spent => unknown_spent_output_ids.push(output_data.output_id),
+                    _ => booked_outputs.push(output_data.clone()),
+                }
+            }
+
+            (booked_outputs, unknown_spent_output_ids)
+        };
+
+        let mut outputs = booked_outputs;
+        if !unknown_spent_output_ids.is_empty() {
+            let metadata = futures::future::try_join_all(
+                unknown_spent_output_ids
+                    .iter()
+                    .map(|output_id| self.client().get_output_metadata(output_id)),
+            )
+            .await?;
+            let spent_outputs = self.store_spent_metadata(metadata).await?;
+            outputs.extend(spent_outputs.into_iter().filter(|output_data| {
+                output_data
+                    .metadata
+                    .milestone_index_spent()
+                    .map_or(true, |spent_milestone| spent_milestone > milestone_index)
+            }));
+        }
+        outputs.sort_by_key(|output_data| output_data.output_id);
+
+        let account_details = self.details().await;
+        historical_balance(&account_details, milestone_index, milestone_timestamp, &outputs)
+    }
+
+    /// Updates the metadata of spent outputs, returns the updated outputs.
+    async fn store_spent_metadata(&self, metadata: Vec<OutputMetadata>) -> crate::wallet::Result<Vec<OutputData>> {
+        let mut account_details = self.details_mut().await;
+        let mut updated_outputs = Vec::new();
+        for metadata in metadata {
+            if let Some(output_data) = account_details.outputs.get_mut(metadata.output_id()) {
+                output_data.metadata = metadata;
+                updated_outputs.push(output_data.clone());
+            }
+        }
+
+        #[cfg(feature = "storage")]
+        {
+            log::debug!(
+                "[BALANCE] storing account {} with metadata of spent outputs",
+                account_details.alias()
+            );
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(updated_outputs)
+    }
+}
+
+/// Returns the milestones the inputs of the transactions of the account were spent in, known from the outputs of the
+/// transactions, which were booked in the same milestone.
+fn spent_milestones_from_transactions(account_details: &AccountDetails) -> HashMap<OutputId, u32> {
+    let booked_milestones = account_details
+        .outputs
+        .values()
+        .map(|output_data| {
+            (
+                *output_data.output_id.transaction_id(),
+                output_data.metadata.milestone_index_booked(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    account_details
+        .transactions
+        .values()
+        .filter_map(|transaction| {
+            let booked_milestone = *booked_milestones.get(&transaction.payload.id())?;
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            Some(essence.inputs().iter().filter_map(move |input| match input {
+                Input::Utxo(input) => Some((*input.output_id(), booked_milestone)),
+                _ => None,
+            }))
+        })
+        .flatten()
+        .collect()
+}
+
+/// Sums up the outputs the account could unlock at the milestone.
+fn historical_balance(
+    account_details: &AccountDetails,
+    milestone_index: u32,
+    milestone_timestamp: u32,
+    outputs: &[OutputData],
+) -> crate::wallet::Result<HistoricalBalance> {
+    let mut account_addresses = account_details
+        .public_addresses
+        .iter()
+        .chain(&account_details.internal_addresses)
+        .map(|address| *address.address.inner())
+        .collect::<HashSet<_>>();
+    // Foundries and other outputs can be owned by the aliases and NFTs of the account
+    for output_data in outputs {
+        match &output_data.output {
+            Output::Alias(alias_output) => {
+                account_addresses.insert(Address::Alias(AliasAddress::from(
+                    alias_output.alias_id_non_null(&output_data.output_id),
+                )));
+            }
+            Output::Nft(nft_output) => {
+                account_addresses.insert(Address::Nft(NftAddress::from(
+                    nft_output.nft_id_non_null(&output_data.output_id),
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    let mut balance = HistoricalBalance {
+        milestone_index,
+        milestone_timestamp,
+        ..Default::default()
+    };
+    let mut native_tokens = NativeTokensBuilder::new();
+
+    for output_data in outputs {
+        let output = &output_data.output;
+        // Outputs that expired to the sender didn't belong to the account anymore
+        let Ok((unlock_address, _)) =
+            output.required_and_unlocked_address(milestone_timestamp, &output_data.output_id, None)
+        else {
+            continue;
+        };
+        if !account_addresses.contains(&unlock_address) {
+            continue;
+        }
+
+        let storage_deposit_return = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            .filter(|storage_deposit_return| !account_addresses.contains(storage_deposit_return.return_address()))
+            .map_or(0, |storage_deposit_return| storage_deposit_return.amount());
+        balance.base_coin += output.amount() - storage_deposit_return;
+        if let Some(output_native_tokens) = output.native_tokens() {
+            native_tokens.add_native_tokens(output_native_tokens.clone())?;
+        }
+        match output {
+            Output::Alias(alias_output) => balance
+                .aliases
+                .push(alias_output.alias_id_non_null(&output_data.output_id)),
+            Output::Foundry(foundry_output) => balance.foundries.push(foundry_output.id()),
+            Output::Nft(nft_output) => balance.nfts.push(nft_output.nft_id_non_null(&output_data.output_id)),
+            _ => {}
+        }
+        balance.outputs.push(output_data.output_id);
+    }
+    balance.native_tokens = native_tokens.finish_vec()?;
+
+    Ok(balance)
+}
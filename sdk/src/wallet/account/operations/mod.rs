@@ -7,6 +7,10 @@ pub(crate) mod address_generation;
 pub(crate) mod balance;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module to reconstruct the balance at a past milestone
+pub(crate) mod historical_balance;
+/// The module for submitting transactions that couldn't be sent before
+pub(crate) mod outbox;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
@@ -15,11 +19,27 @@ pub(crate) mod output_claiming;
 pub(crate) mod output_consolidation;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module for proving the ownership of outputs
+pub(crate) mod ownership_proof;
+/// The module for requesting payments
+pub(crate) mod payment_request;
+/// The module for exporting the public derivation material of an account
+pub(crate) mod public_account;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
+/// The module for replacing pending transactions
+pub(crate) mod replacement;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
+/// The module for sends that are executed at a later time
+pub(crate) mod scheduled_send;
+/// The module for signing in to web services with an address
+pub(crate) mod sign_in;
+/// The module for recovering transactions that were signed, but not stored
+pub(crate) mod submission_journal;
+/// The module for sweeping funds from other secret managers
+pub(crate) mod sweep;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
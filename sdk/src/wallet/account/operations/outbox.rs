@@ -0,0 +1,239 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{input::Input, payload::transaction::TransactionEssence},
+    wallet::account::{
+        operations::syncing::transactions::updated_transaction_and_outputs,
+        types::{AttachmentKind, InclusionState, Transaction, TransactionAttachment},
+        Account,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the outbox, the signed transactions that are stored in the account, but couldn't be submitted to a node
+    /// yet, e.g. because the device was offline.
+    pub async fn outbox(&self) -> Vec<Transaction> {
+        let account_details = self.details().await;
+
+        account_details
+            .pending_transactions
+            .iter()
+            .filter_map(|transaction_id| account_details.transactions.get(transaction_id))
+            .filter(|transaction| transaction.block_id.is_none())
+            .cloned()
+            .collect()
+    }
+
+    /// Submits the transactions of the outbox, which is also done when syncing pending transactions. A transaction of
+    /// which an input got spent by another transaction in the meantime is marked as conflicting and its unspent inputs
+    /// are unlocked again, one of which the inputs got spent by itself was already submitted and is marked as
+    /// confirmed. Stops at the first transaction that can't be submitted, it stays in the outbox for the next attempt.
+    /// Returns the transactions that left the outbox.
+    pub async fn submit_outbox(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        let outbox = self.outbox().await;
+        if outbox.is_empty() {
+            return Ok(Vec::new());
+        }
+        log::debug!("[OUTBOX] submitting {} transactions", outbox.len());
+
+        let network_id = self.client().get_network_id().await?;
+        let mut updated_transactions = Vec::new();
+        let mut spent_output_ids = Vec::new();
+        let mut output_ids_to_unlock = Vec::new();
+        let mut failed_transaction = None;
+
+        for mut transaction in outbox {
+            // only submit transactions for the network we're connected to
+            if transaction.network_id != network_id {
+                continue;
+            }
+
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            let input_ids = essence
+                .inputs()
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Utxo(input) => Some(*input.output_id()),
+                    Input::Treasury(_) => None,
+                })
+                .collect::<Vec<_>>();
+
+            let inputs_metadata = match futures::future::try_join_all(
+                input_ids
+                    .iter()
+                    .map(|output_id| self.client().get_output_metadata(output_id)),
+            )
+            .await
+            {
+                Ok(inputs_metadata) => inputs_metadata,
+                Err(err) => {
+                    log::debug!("[OUTBOX] couldn't get inputs of {}: {err}", transaction.transaction_id);
+                    break;
+                }
+            };
+
+            // The transaction was already submitted, e.g. the response of the node got lost
+            let spent_by_transaction = !inputs_metadata.is_empty()
+                && inputs_metadata
+                    .iter()
+                    .all(|metadata| metadata.transaction_id_spent() == Some(&transaction.transaction_id));
+            if spent_by_transaction {
+                log::debug!("[OUTBOX] confirmed transaction {}", transaction.transaction_id);
+                let block_id = self
+                    .client()
+                    .get_included_block(&transaction.transaction_id)
+                    .await
+                    .ok()
+                    .map(|block| block.id());
+                updated_transaction_and_outputs(
+                    transaction,
+                    block_id,
+                    InclusionState::Confirmed,
+                    &mut updated_transactions,
+                    &mut spent_output_ids,
+                );
+                continue;
+            }
+
+            let spent_by_other_transaction = inputs_metadata.iter().any(|metadata| {
+                metadata
+                    .transaction_id_spent()
+                    .is_some_and(|transaction_id| *transaction_id != transaction.transaction_id)
+            });
+            if spent_by_other_transaction {
+                log::debug!("[OUTBOX] conflicting transaction {}", transaction.transaction_id);
+                output_ids_to_unlock.extend(
+                    inputs_metadata
+                        .iter()
+                        .filter(|metadata| !metadata.is_spent())
+                        .map(|metadata| *metadata.output_id()),
+                );
+                transaction.inclusion_state = InclusionState::Conflicting;
+                updated_transactions.push(transaction);
+                continue;
+            }
+
+            let result = self.submit_transaction_payload(transaction.payload.clone()).await;
+            transaction.add_attachment(TransactionAttachment::new(AttachmentKind::Submission, &result));
+            match result {
+                Ok(block_id) => {
+                    log::debug!("[OUTBOX] submitted {} in block {block_id}", transaction.transaction_id);
+                    transaction.block_id.replace(block_id);
+                    updated_transactions.push(transaction);
+                }
+                Err(err) => {
+                    log::debug!("[OUTBOX] couldn't submit {}: {err}", transaction.transaction_id);
+                    // The transaction stays in the outbox, but the failed attempt is stored in its history
+                    failed_transaction.replace(transaction);
+                    break;
+                }
+            }
+        }
+
+        let mut transactions_to_store = updated_transactions.clone();
+        transactions_to_store.extend(failed_transaction);
+        self.update_account_with_transactions(transactions_to_store, spent_output_ids, output_ids_to_unlock)
+            .await?;
+
+        Ok(updated_transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+    use crate::types::block::{output::OutputId, payload::transaction::TransactionId};
+
+    #[tokio::test]
+    async fn failed_submission_stays_in_outbox() {
+        let storage_path = "test-storage/outbox_failed_submission";
+        let account = Account::mock(storage_path).await;
+        account.add_mock_pending_transaction().await;
+        assert_eq!(account.outbox().await.len(), 1);
+
+        // The inputs are unspent, but the node rejects the block
+        assert!(account.submit_outbox().await.unwrap().is_empty());
+
+        let outbox = account.outbox().await;
+        assert_eq!(outbox.len(), 1);
+        let history = outbox[0].attachment_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, AttachmentKind::Submission);
+        assert!(history[0].error.is_some());
+
+        // Every attempt is stored
+        account.submit_outbox().await.unwrap();
+        assert_eq!(account.outbox().await[0].attachment_history().len(), 2);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[tokio::test]
+    async fn conflicting_transaction_leaves_outbox() {
+        let storage_path = "test-storage/outbox_conflicting_transaction";
+        let spent_input = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let unspent_input = OutputId::new(TransactionId::new([1; 32]), 1).unwrap();
+        let spent_outputs = HashMap::from([(spent_input, TransactionId::new([2; 32]))]);
+        let account = Account::mock_with_spent_outputs(storage_path, spent_outputs).await;
+        let transaction_id = account
+            .add_mock_pending_transaction_with_inputs(&[spent_input, unspent_input])
+            .await;
+        account
+            .details_mut()
+            .await
+            .locked_outputs
+            .extend([spent_input, unspent_input]);
+
+        let submitted = account.submit_outbox().await.unwrap();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].transaction_id, transaction_id);
+        assert_eq!(submitted[0].inclusion_state, InclusionState::Conflicting);
+        assert!(submitted[0].attachment_history().is_empty());
+
+        // Only the unspent input is available again
+        assert!(account.outbox().await.is_empty());
+        let account_details = account.details().await;
+        assert!(!account_details.pending_transactions.contains(&transaction_id));
+        assert_eq!(account_details.locked_outputs, HashSet::from([spent_input]));
+        drop(account_details);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[tokio::test]
+    async fn submitted_transaction_leaves_outbox() {
+        let storage_path = "test-storage/outbox_submitted_transaction";
+        let inputs = [
+            OutputId::new(TransactionId::new([1; 32]), 0).unwrap(),
+            OutputId::new(TransactionId::new([1; 32]), 1).unwrap(),
+        ];
+        // The node knows the inputs as spent by the transaction itself
+        let transaction_id = Transaction::mock(TransactionId::new([0; 32]), &inputs).payload.id();
+        let spent_outputs = inputs.iter().map(|input| (*input, transaction_id)).collect();
+        let account = Account::mock_with_spent_outputs(storage_path, spent_outputs).await;
+        assert_eq!(
+            account.add_mock_pending_transaction_with_inputs(&inputs).await,
+            transaction_id
+        );
+        account.details_mut().await.locked_outputs.extend(inputs);
+
+        let submitted = account.submit_outbox().await.unwrap();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].inclusion_state, InclusionState::Confirmed);
+        // It's not submitted again and the node doesn't know the block that included it
+        assert!(submitted[0].attachment_history().is_empty());
+        assert!(submitted[0].block_id.is_none());
+
+        assert!(account.outbox().await.is_empty());
+        assert!(account.details().await.locked_outputs.is_empty());
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -9,10 +9,11 @@ use crate::{
     client::secret::SecretManage,
     types::block::{
         address::Address,
+        input::INPUT_COUNT_MAX,
         output::{
             unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
             BasicOutputBuilder, MinimumStorageDepositBasicOutput, NativeTokens, NativeTokensBuilder, NftOutputBuilder,
-            Output, OutputId,
+            Output, OutputId, Unlockability,
         },
     },
     wallet::account::{
@@ -32,6 +33,39 @@ pub enum OutputsToClaim {
     All,
 }
 
+/// Outputs with unlock conditions besides the address unlock condition, categorized by when the account can claim
+/// them.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimableOutputs {
+    /// Outputs that can be claimed now, expiring ones have to be claimed before they expire or they are lost.
+    pub now: HashMap<OutputId, Unlockability>,
+    /// Outputs that can be claimed once their timelock expired.
+    pub later: HashMap<OutputId, Unlockability>,
+    /// Outputs that can't be claimed anymore, because they expired and were returned to the sender.
+    pub never: HashSet<OutputId>,
+}
+
+/// Policy for claiming outputs automatically.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AutoClaimPolicy {
+    /// Outputs are only claimed if at least this amount remains after returning their storage deposit.
+    #[serde(with = "crate::utils::serde::string")]
+    pub min_amount: u64,
+    /// The maximum number of outputs claimed in one transaction, the other inputs can be used for storage deposits.
+    pub batch_size: usize,
+}
+
+impl Default for AutoClaimPolicy {
+    fn default() -> Self {
+        Self {
+            min_amount: 0,
+            batch_size: INPUT_COUNT_MAX as usize / 2,
+        }
+    }
+}
+
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -124,6 +158,95 @@ where
         Ok(output_ids_to_claim.into_iter().collect())
     }
 
+    /// Categorizes the basic and nft outputs that have
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition),
+    /// [`StorageDepositReturnUnlockCondition`] or
+    /// [`TimelockUnlockCondition`](crate::types::block::output::unlock_condition::TimelockUnlockCondition) by whether
+    /// the account can claim them now, later or never.
+    pub async fn categorize_claimable_outputs(&self) -> crate::wallet::Result<ClaimableOutputs> {
+        log::debug!("[OUTPUT_CLAIMING] categorize_claimable_outputs");
+        let account_details = self.details().await;
+
+        let local_time = self.client().get_time_checked().await?;
+
+        let mut claimable_outputs = ClaimableOutputs::default();
+        for (output_id, output_data) in account_details
+            .unspent_outputs
+            .iter()
+            .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
+        {
+            // Don't use outputs that are locked for other transactions
+            if account_details.locked_outputs.contains(output_id)
+                || output_data
+                    .output
+                    .unlock_conditions()
+                    .map_or(true, |unlock_conditions| unlock_conditions.len() == 1)
+            {
+                continue;
+            }
+
+            // Use the best unlockability of all addresses, outputs controlled by an alias or nft are currently not
+            // considered
+            let unlockability = account_details
+                .addresses_with_unspent_outputs
+                .iter()
+                .map(|address| output_data.output.unlockable_by(address.address.inner(), local_time))
+                .fold(Unlockability::Never, best_unlockability);
+
+            match unlockability {
+                Unlockability::Now { .. } => {
+                    claimable_outputs.now.insert(*output_id, unlockability);
+                }
+                Unlockability::Later { .. } => {
+                    claimable_outputs.later.insert(*output_id, unlockability);
+                }
+                Unlockability::Never => {
+                    claimable_outputs.never.insert(*output_id);
+                }
+            }
+        }
+
+        Ok(claimable_outputs)
+    }
+
+    /// Claims the outputs that can be claimed now and match the policy, in batches of
+    /// [`AutoClaimPolicy::batch_size`] outputs. Outputs that expire first are claimed first. Uses the outputs of the
+    /// last sync. A failed batch doesn't stop the following ones, an error is only returned if no batch could be
+    /// claimed.
+    pub async fn auto_claim_outputs(&self, policy: &AutoClaimPolicy) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[OUTPUT_CLAIMING] auto_claim_outputs");
+        let current_time = self.client().get_time_checked().await?;
+        let claimable_outputs = self.categorize_claimable_outputs().await?;
+
+        let batches = {
+            let account_details = self.details().await;
+            auto_claim_batches(
+                claimable_outputs.now,
+                |output_id| account_details.unspent_outputs.get(output_id).map(|o| &o.output),
+                current_time,
+                policy,
+            )
+        };
+
+        let mut transactions = Vec::new();
+        let mut last_error = None;
+        for batch in batches {
+            match self.claim_outputs(batch).await {
+                Ok(transaction) => transactions.push(transaction),
+                Err(err) => {
+                    log::debug!("[OUTPUT_CLAIMING] failed to claim a batch: {err}");
+                    last_error.replace(err);
+                }
+            }
+        }
+        log::debug!("[OUTPUT_CLAIMING] sent {} claiming transactions", transactions.len());
+
+        match last_error {
+            Some(err) if transactions.is_empty() => Err(err),
+            _ => Ok(transactions),
+        }
+    }
+
     /// Get basic outputs that have only one unlock condition which is [AddressUnlockCondition], so they can be used as
     /// additional inputs
     pub(crate) async fn get_basic_outputs_for_additional_inputs(&self) -> crate::wallet::Result<Vec<OutputData>> {
@@ -207,10 +330,16 @@ where
 
         let account_details = self.details().await;
 
+        // Outputs of frozen addresses can't be used until the addresses are unfrozen
+        let frozen_addresses = account_details.frozen_addresses();
+        possible_additional_inputs.retain(|output_data| !frozen_addresses.contains(&output_data.address));
+
         let mut outputs_to_claim = Vec::new();
         for output_id in output_ids_to_claim {
             if let Some(output_data) = account_details.unspent_outputs.get(&output_id) {
-                if !account_details.locked_outputs.contains(&output_id) {
+                if !account_details.locked_outputs.contains(&output_id)
+                    && !frozen_addresses.contains(&output_data.address)
+                {
                     outputs_to_claim.push(output_data.clone());
                 }
             }
@@ -407,6 +536,39 @@ where
 }
 
 /// Get the `StorageDepositReturnUnlockCondition`, if not expired
+// Selects the outputs that can be claimed now and have at least the minimum amount of the policy left after returning
+// their storage deposit, and splits them into batches. Outputs that expire first are in the first batches.
+fn auto_claim_batches<'a>(
+    claimable_now: HashMap<OutputId, Unlockability>,
+    get_output: impl Fn(&OutputId) -> Option<&'a Output>,
+    current_time: u32,
+    policy: &AutoClaimPolicy,
+) -> Vec<Vec<OutputId>> {
+    let mut outputs_to_claim = claimable_now
+        .into_iter()
+        .filter_map(|(output_id, unlockability)| {
+            let output = get_output(&output_id)?;
+            // An output with a storage deposit return higher than its amount is invalid, skip it instead of
+            // underflowing
+            let claimable_amount = output
+                .amount()
+                .checked_sub(sdr_not_expired(output, current_time).map_or(0, |sdr| sdr.amount()))?;
+            let expires_at = match unlockability {
+                Unlockability::Now { expires_at } => expires_at,
+                _ => None,
+            };
+            (claimable_amount >= policy.min_amount).then_some((output_id, expires_at.unwrap_or(u32::MAX)))
+        })
+        .collect::<Vec<_>>();
+    // Sort by the output id too, so the batches don't depend on the order of the map
+    outputs_to_claim.sort_by_key(|(output_id, expires_at)| (*expires_at, *output_id));
+
+    outputs_to_claim
+        .chunks(policy.batch_size.clamp(1, INPUT_COUNT_MAX as usize))
+        .map(|batch| batch.iter().map(|(output_id, _)| *output_id).collect())
+        .collect()
+}
+
 pub(crate) fn sdr_not_expired(output: &Output, current_time: u32) -> Option<&StorageDepositReturnUnlockCondition> {
     output.unlock_conditions().and_then(|unlock_conditions| {
         unlock_conditions.storage_deposit_return().and_then(|sdr| {
@@ -431,3 +593,145 @@ pub(crate) fn get_new_native_token_count(
     native_tokens_count.add_native_tokens(native_tokens.clone())?;
     Ok(native_tokens_count.len())
 }
+
+/// Prefers outputs that can be unlocked now and don't expire or expire later, then the ones that can be unlocked
+/// earlier.
+fn best_unlockability(a: Unlockability, b: Unlockability) -> Unlockability {
+    match (a, b) {
+        (Unlockability::Now { expires_at: a_expires }, Unlockability::Now { expires_at: b_expires }) => {
+            Unlockability::Now {
+                expires_at: a_expires.zip(b_expires).map(|(a, b)| a.max(b)),
+            }
+        }
+        (now @ Unlockability::Now { .. }, _) | (_, now @ Unlockability::Now { .. }) => now,
+        (
+            Unlockability::Later {
+                unlockable_at: a_unlockable_at,
+                ..
+            },
+            Unlockability::Later {
+                unlockable_at: b_unlockable_at,
+                ..
+            },
+        ) => {
+            if a_unlockable_at <= b_unlockable_at {
+                a
+            } else {
+                b
+            }
+        }
+        (later @ Unlockability::Later { .. }, _) | (_, later @ Unlockability::Later { .. }) => later,
+        (Unlockability::Never, Unlockability::Never) => Unlockability::Never,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address, output::unlock_condition::ExpirationUnlockCondition,
+        payload::transaction::TransactionId, protocol::ProtocolParameters,
+    };
+
+    const CURRENT_TIME: u32 = 1_000;
+
+    fn output(amount: u64, sdr_amount: Option<u64>, expires_at: Option<u32>) -> Output {
+        let token_supply = ProtocolParameters::default().token_supply();
+        let address = Address::from(Ed25519Address::new([0; 32]));
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(amount).add_unlock_condition(AddressUnlockCondition::new(address));
+        if let Some(sdr_amount) = sdr_amount {
+            builder = builder.add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(address, sdr_amount, token_supply).unwrap(),
+            );
+        }
+        if let Some(expires_at) = expires_at {
+            builder = builder.add_unlock_condition(ExpirationUnlockCondition::new(address, expires_at).unwrap());
+        }
+        builder.finish_output(token_supply).unwrap()
+    }
+
+    fn output_id(index: u16) -> OutputId {
+        OutputId::new(TransactionId::new([1; 32]), index).unwrap()
+    }
+
+    fn batches(outputs: &[(Output, Unlockability)], policy: &AutoClaimPolicy) -> Vec<Vec<OutputId>> {
+        let outputs = outputs
+            .iter()
+            .enumerate()
+            .map(|(index, output)| (output_id(index as u16), output.clone()))
+            .collect::<HashMap<_, _>>();
+        auto_claim_batches(
+            outputs
+                .iter()
+                .map(|(output_id, (_, unlockability))| (*output_id, *unlockability))
+                .collect(),
+            |output_id| outputs.get(output_id).map(|(output, _)| output),
+            CURRENT_TIME,
+            policy,
+        )
+    }
+
+    #[test]
+    fn auto_claim_min_amount() {
+        let now = Unlockability::Now { expires_at: None };
+        let outputs = [
+            // 600_000 remain after returning the storage deposit
+            (output(1_000_000, Some(400_000), None), now),
+            // The storage deposit doesn't have to be returned anymore after the expiration
+            (output(1_000_000, Some(900_000), Some(CURRENT_TIME)), now),
+            // Only 100_000 remain
+            (output(1_000_000, Some(900_000), None), now),
+        ];
+        let policy = AutoClaimPolicy {
+            min_amount: 500_000,
+            ..Default::default()
+        };
+
+        assert_eq!(batches(&outputs, &policy), vec![vec![output_id(0), output_id(1)]]);
+    }
+
+    #[test]
+    fn auto_claim_skips_invalid_storage_deposit_return() {
+        let now = Unlockability::Now { expires_at: None };
+        // The return amount is higher than the amount of the output, this mustn't underflow
+        let outputs = [(output(1_000_000, Some(2_000_000), None), now)];
+
+        assert!(batches(&outputs, &AutoClaimPolicy::default()).is_empty());
+    }
+
+    #[test]
+    fn auto_claim_batches_expiring_first() {
+        let outputs = [
+            (output(1_000_000, None, None), Unlockability::Now { expires_at: None }),
+            (
+                output(1_000_000, None, Some(CURRENT_TIME + 20)),
+                Unlockability::Now {
+                    expires_at: Some(CURRENT_TIME + 20),
+                },
+            ),
+            (
+                output(1_000_000, None, Some(CURRENT_TIME + 10)),
+                Unlockability::Now {
+                    expires_at: Some(CURRENT_TIME + 10),
+                },
+            ),
+        ];
+        let policy = AutoClaimPolicy {
+            batch_size: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            batches(&outputs, &policy),
+            vec![vec![output_id(2), output_id(1)], vec![output_id(0)]]
+        );
+
+        // A batch size of 0 is treated as 1
+        let policy = AutoClaimPolicy {
+            batch_size: 0,
+            ..Default::default()
+        };
+        assert_eq!(batches(&outputs, &policy).len(), 3);
+    }
+}
@@ -107,6 +107,7 @@ where
     /// is set to `true`, the threshold is ignored. Only consolidates the amount of outputs that fit into a single
     /// transaction.
     pub async fn consolidate_outputs(&self, params: ConsolidationParams) -> Result<Transaction> {
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_consolidate_outputs(params).await?;
         let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction, None).await?;
 
@@ -130,6 +131,7 @@ where
         let mut outputs_to_consolidate = Vec::new();
         let account_details = self.details().await;
         let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+        let frozen_addresses = account_details.frozen_addresses();
 
         for (output_id, output_data) in account_details.unspent_outputs() {
             #[cfg(feature = "participation")]
@@ -140,9 +142,10 @@ where
                 }
             }
             let is_locked_output = account_details.locked_outputs.contains(output_id);
+            let is_frozen_output = frozen_addresses.contains(&output_data.address);
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
-            if !is_locked_output && should_consolidate_output {
+            if !is_locked_output && !is_frozen_output && should_consolidate_output {
                 outputs_to_consolidate.push(output_data.clone());
             }
         }
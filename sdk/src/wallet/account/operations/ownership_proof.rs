@@ -0,0 +1,138 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proving the ownership of outputs without moving them, for example for a proof of reserves.
+//!
+//! The key controlling an output signs the BLAKE2b-256 hash of a domain separator, the output ID and a challenge of the
+//! verifier. The domain separator makes sure such a signature can't be used as the signature of a transaction.
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Address,
+        output::{Output, OutputId},
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature},
+        Error,
+    },
+    wallet::account::Account,
+};
+
+/// Domain separator of the signed ownership statements.
+const OWNERSHIP_PROOF_DOMAIN: &[u8] = b"IOTA-SDK-OWNERSHIP-PROOF";
+
+/// A signed statement that the key of an Ed25519 address controls an output, for the challenge of a verifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipProof {
+    /// The ID of the output.
+    pub output_id: OutputId,
+    /// The signature of the statement, made with the key controlling the output.
+    pub signature: Ed25519Signature,
+}
+
+impl OwnershipProof {
+    /// Returns the statement that gets signed for an output and a challenge.
+    pub fn statement(output_id: &OutputId, challenge: &[u8]) -> [u8; 32] {
+        Blake2b256::new()
+            .chain_update(OWNERSHIP_PROOF_DOMAIN)
+            .chain_update(output_id.pack_to_vec())
+            .chain_update(challenge)
+            .finalize()
+            .into()
+    }
+
+    /// Verifies that the proof was signed for the challenge by the key of the address that controls the output at
+    /// `current_time`. The verifier needs to get the output from a node it trusts and check that it's unspent.
+    pub fn verify(&self, challenge: &[u8], output: &Output, current_time: u32) -> Result<(), Error> {
+        let (address, _) = output.required_and_unlocked_address(current_time, &self.output_id, None)?;
+
+        match address {
+            Address::Ed25519(address) => self
+                .signature
+                .is_valid(&Self::statement(&self.output_id, challenge), &address),
+            address => Err(Error::InvalidAddressKind(address.kind())),
+        }
+    }
+}
+
+/// Dto for an [`OwnershipProof`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipProofDto {
+    /// The ID of the output.
+    pub output_id: OutputId,
+    /// The signature of the statement.
+    pub signature: Ed25519SignatureDto,
+}
+
+impl From<&OwnershipProof> for OwnershipProofDto {
+    fn from(value: &OwnershipProof) -> Self {
+        Self {
+            output_id: value.output_id,
+            signature: Ed25519SignatureDto::from(&value.signature),
+        }
+    }
+}
+
+impl TryFrom<OwnershipProofDto> for OwnershipProof {
+    type Error = Error;
+
+    fn try_from(value: OwnershipProofDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            output_id: value.output_id,
+            signature: Ed25519Signature::try_from(value.signature)?,
+        })
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Signs an ownership statement for the challenge with the key controlling each of the unspent outputs. Only
+    /// outputs that are currently controlled by an Ed25519 address of the account can be proven.
+    pub async fn generate_ownership_proof(
+        &self,
+        output_ids: &[OutputId],
+        challenge: &[u8],
+    ) -> crate::wallet::Result<Vec<OwnershipProof>> {
+        log::debug!("[generate_ownership_proof]");
+        let current_time = self.client().get_time_checked().await?;
+
+        let chains = {
+            let account_details = self.details().await;
+            account_details.check_can_sign()?;
+            output_ids
+                .iter()
+                .map(|output_id| {
+                    let output_data = account_details
+                        .unspent_outputs
+                        .get(output_id)
+                        .ok_or(crate::wallet::Error::OutputNotFound(*output_id))?;
+                    output_data
+                        .input_signing_data(&account_details, current_time, None)?
+                        .and_then(|input_signing_data| input_signing_data.chain)
+                        .map(|chain| (*output_id, chain))
+                        .ok_or_else(|| crate::client::Error::MissingBip32Chain.into())
+                })
+                .collect::<crate::wallet::Result<Vec<_>>>()?
+        };
+
+        let mut proofs = Vec::with_capacity(chains.len());
+        for (output_id, chain) in chains {
+            let signature = self
+                .wallet
+                .secret_manager
+                .read()
+                .await
+                .sign_ed25519(&OwnershipProof::statement(&output_id, challenge), chain)
+                .await?;
+            proofs.push(OwnershipProof { output_id, signature });
+        }
+
+        Ok(proofs)
+    }
+}
@@ -41,6 +41,7 @@ where
         event_id: impl Into<Option<ParticipationEventId>> + Send,
         answers: impl Into<Option<Vec<u8>>> + Send,
     ) -> Result<Transaction> {
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_vote(event_id, answers).await?;
 
         self.sign_and_submit_transaction(prepared, None).await
@@ -132,6 +133,7 @@ where
     /// milestones in there against latest network milestone).
     /// If NOT already voting for this event, throws an error.
     pub async fn stop_participating(&self, event_id: ParticipationEventId) -> Result<Transaction> {
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_stop_participating(event_id).await?;
 
         self.sign_and_submit_transaction(prepared, None).await
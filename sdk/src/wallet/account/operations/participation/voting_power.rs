@@ -44,6 +44,7 @@ where
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then increases again immediately after).
     pub async fn increase_voting_power(&self, amount: u64) -> Result<Transaction> {
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_increase_voting_power(amount).await?;
 
         self.sign_and_submit_transaction(prepared, None).await
@@ -103,6 +104,7 @@ where
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then decreases immediately after).
     pub async fn decrease_voting_power(&self, amount: u64) -> Result<Transaction> {
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_decrease_voting_power(amount).await?;
 
         self.sign_and_submit_transaction(prepared, None).await
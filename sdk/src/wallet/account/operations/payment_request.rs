@@ -0,0 +1,417 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Requesting payments with URIs that can be shared as links or QR codes.
+//!
+//! A payment request URI has the form `iota:<address>?v=1&amount=<amount>&unit=<token ID>&tag=<tag>&exp=<timestamp>`,
+//! all parameters but the version being optional. The amount is in the smallest unit of the base token, or of the
+//! native token given as unit, the tag is prefix hex encoded and the expiry is a unix timestamp in seconds. Parsing is
+//! strict: unknown or repeated parameters and requests of other versions are rejected, so a payment is never sent
+//! while ignoring a condition of the request.
+
+use core::{fmt, str::FromStr};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Bech32Address,
+        output::{feature::TagFeature, TokenId},
+    },
+    wallet::{
+        account::{types::OutputData, Account},
+        Error,
+    },
+};
+
+/// The URI scheme of payment requests.
+pub const PAYMENT_REQUEST_SCHEME: &str = "iota";
+/// The version of payment request URIs that is created and understood.
+pub const PAYMENT_REQUEST_VERSION: u8 = 1;
+
+/// The token a payment is requested in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PaymentUnit {
+    /// The base token of the network.
+    #[default]
+    BaseToken,
+    /// A native token.
+    NativeToken(TokenId),
+}
+
+/// A request for a payment to an address of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequest {
+    /// The address to pay to.
+    pub address: Bech32Address,
+    /// The requested amount, any amount if not set.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub amount: Option<u64>,
+    /// The token of the amount.
+    #[serde(default)]
+    pub unit: PaymentUnit,
+    /// The tag the output of the payment must have.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag: Option<Vec<u8>>,
+    /// The unix timestamp in seconds after which the request isn't valid anymore.
+    #[serde(default)]
+    pub expires_at: Option<u32>,
+}
+
+impl PaymentRequest {
+    /// Checks that the request can be encoded and paid.
+    pub fn validate(&self) -> crate::wallet::Result<()> {
+        if self.amount == Some(0) {
+            return Err(Error::InvalidPaymentRequest("zero amount".to_owned()));
+        }
+        if matches!(self.unit, PaymentUnit::NativeToken(_)) && self.amount.is_none() {
+            return Err(Error::InvalidPaymentRequest("unit without amount".to_owned()));
+        }
+        if let Some(tag) = &self.tag {
+            if !TagFeature::LENGTH_RANGE.contains(&u8::try_from(tag.len()).unwrap_or(u8::MAX)) {
+                return Err(Error::InvalidPaymentRequest(format!(
+                    "invalid tag length {}",
+                    tag.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the URI of the request, which is also the payload of its QR code.
+    pub fn to_uri(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns whether the request expired at the unix timestamp in seconds.
+    pub fn is_expired(&self, time: u32) -> bool {
+        self.expires_at.is_some_and(|expires_at| time > expires_at)
+    }
+
+    /// Returns whether the output pays the request: it must be unlockable by the address, hold the amount besides a
+    /// storage deposit it has to return, have the tag and be booked before the request expired.
+    pub(crate) fn is_paid_by(&self, output_data: &OutputData) -> bool {
+        let output = &output_data.output;
+        let booked_at = output_data.metadata.milestone_timestamp_booked();
+
+        if self.is_expired(booked_at) || !output.unlockable_by(self.address.inner(), booked_at).is_now() {
+            return false;
+        }
+        if let Some(tag) = &self.tag {
+            let output_tag = output.features().and_then(|features| features.tag());
+            if output_tag.map(|feature| feature.tag()) != Some(tag.as_slice()) {
+                return false;
+            }
+        }
+
+        match self.unit {
+            PaymentUnit::BaseToken => {
+                let returned_amount = output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                    .map_or(0, |storage_deposit_return| storage_deposit_return.amount());
+                output.amount().saturating_sub(returned_amount) >= self.amount.unwrap_or(0)
+            }
+            PaymentUnit::NativeToken(token_id) => output
+                .native_tokens()
+                .and_then(|native_tokens| {
+                    native_tokens
+                        .iter()
+                        .find(|native_token| *native_token.token_id() == token_id)
+                })
+                .is_some_and(|native_token| native_token.amount() >= U256::from(self.amount.unwrap_or(0))),
+        }
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{PAYMENT_REQUEST_SCHEME}:{}?v={PAYMENT_REQUEST_VERSION}",
+            self.address
+        )?;
+        if let Some(amount) = self.amount {
+            write!(f, "&amount={amount}")?;
+        }
+        if let PaymentUnit::NativeToken(token_id) = self.unit {
+            write!(f, "&unit={token_id}")?;
+        }
+        if let Some(tag) = &self.tag {
+            write!(f, "&tag={}", prefix_hex::encode(tag))?;
+        }
+        if let Some(expires_at) = self.expires_at {
+            write!(f, "&exp={expires_at}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = Error;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid = |message: String| Error::InvalidPaymentRequest(message);
+
+        let (scheme, rest) = uri
+            .split_once(':')
+            .ok_or_else(|| invalid("missing scheme".to_owned()))?;
+        if scheme != PAYMENT_REQUEST_SCHEME {
+            return Err(invalid(format!("unsupported scheme {scheme}")));
+        }
+        let (address, query) = rest
+            .split_once('?')
+            .ok_or_else(|| invalid("missing version".to_owned()))?;
+        let address = Bech32Address::try_from_str(address).map_err(|e| invalid(format!("invalid address: {e}")))?;
+
+        let mut version = None;
+        let mut request = Self {
+            address,
+            amount: None,
+            unit: PaymentUnit::BaseToken,
+            tag: None,
+            expires_at: None,
+        };
+        let mut unit = None;
+
+        for parameter in query.split('&') {
+            let (key, value) = parameter
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("invalid parameter {parameter}")))?;
+            let is_duplicate = match key {
+                "v" => version
+                    .replace(
+                        value
+                            .parse::<u8>()
+                            .map_err(|_| invalid(format!("invalid version {value}")))?,
+                    )
+                    .is_some(),
+                "amount" => request
+                    .amount
+                    .replace(value.parse().map_err(|_| invalid(format!("invalid amount {value}")))?)
+                    .is_some(),
+                "unit" => unit
+                    .replace(TokenId::from_str(value).map_err(|_| invalid(format!("invalid unit {value}")))?)
+                    .is_some(),
+                "tag" => request
+                    .tag
+                    .replace(prefix_hex::decode(value).map_err(|_| invalid(format!("invalid tag {value}")))?)
+                    .is_some(),
+                "exp" => request
+                    .expires_at
+                    .replace(value.parse().map_err(|_| invalid(format!("invalid expiry {value}")))?)
+                    .is_some(),
+                _ => return Err(invalid(format!("unknown parameter {key}"))),
+            };
+            if is_duplicate {
+                return Err(invalid(format!("duplicate parameter {key}")));
+            }
+        }
+
+        match version {
+            Some(PAYMENT_REQUEST_VERSION) => {}
+            Some(version) => return Err(invalid(format!("unsupported version {version}"))),
+            None => return Err(invalid("missing version".to_owned())),
+        }
+        if let Some(token_id) = unit {
+            request.unit = PaymentUnit::NativeToken(token_id);
+        }
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+/// Parameters for [`Account::create_payment_request()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePaymentRequestParams {
+    /// The address to pay to, the first address of the account if not set.
+    #[serde(default)]
+    pub address: Option<Bech32Address>,
+    /// The requested amount, any amount if not set.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub amount: Option<u64>,
+    /// The token of the amount.
+    #[serde(default)]
+    pub unit: PaymentUnit,
+    /// The tag the output of the payment must have. Setting a distinct tag for every request makes sure a payment
+    /// only fulfills the request it's meant for.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag: Option<Vec<u8>>,
+    /// The unix timestamp in seconds after which the request isn't valid anymore.
+    #[serde(default)]
+    pub expires_at: Option<u32>,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Creates a payment request to an address of the account and registers it, so the output paying it is reported
+    /// with a `PaymentReceived` event when it's synced. A request is fulfilled by the first output that pays it.
+    pub async fn create_payment_request(
+        &self,
+        params: CreatePaymentRequestParams,
+    ) -> crate::wallet::Result<PaymentRequest> {
+        log::debug!("[create_payment_request]");
+        let address = {
+            let account_details = self.details().await;
+            match params.address {
+                Some(address) => {
+                    if !account_details
+                        .public_addresses
+                        .iter()
+                        .chain(&account_details.internal_addresses)
+                        .any(|account_address| account_address.address.inner == address.inner)
+                    {
+                        return Err(Error::AddressNotFoundInAccount(address));
+                    }
+                    address
+                }
+                None => {
+                    account_details
+                        .public_addresses
+                        .first()
+                        .ok_or(Error::MissingParameter("address"))?
+                        .address
+                }
+            }
+        };
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        let request = PaymentRequest {
+            address,
+            amount: params.amount,
+            unit: params.unit,
+            tag: params.tag,
+            expires_at: params.expires_at,
+        };
+        request.validate()?;
+
+        let mut payment_requests = self.payment_requests.lock().await;
+        payment_requests.push(request.clone());
+        self.save_payment_requests(&payment_requests).await?;
+
+        Ok(request)
+    }
+
+    /// Returns the registered payment requests that weren't paid yet.
+    pub async fn payment_requests(&self) -> Vec<PaymentRequest> {
+        self.payment_requests.lock().await.clone()
+    }
+
+    /// Stops waiting for the payment of a request. Returns whether the request was registered.
+    pub async fn cancel_payment_request(&self, request: &PaymentRequest) -> crate::wallet::Result<bool> {
+        let mut payment_requests = self.payment_requests.lock().await;
+        let Some(position) = payment_requests.iter().position(|registered| registered == request) else {
+            return Ok(false);
+        };
+        payment_requests.remove(position);
+        self.save_payment_requests(&payment_requests).await?;
+
+        Ok(true)
+    }
+
+    /// Matches new outputs against the registered payment requests. Paid and expired requests are removed, the paid
+    /// ones are returned together with the output that paid them.
+    pub(crate) async fn fulfill_payment_requests(
+        &self,
+        new_outputs: &[OutputData],
+    ) -> crate::wallet::Result<Vec<(PaymentRequest, OutputData)>> {
+        let mut payment_requests = self.payment_requests.lock().await;
+        if payment_requests.is_empty() {
+            return Ok(Vec::new());
+        }
+        let now = crate::utils::unix_timestamp_now().as_secs() as u32;
+        let len = payment_requests.len();
+        let mut fulfilled = Vec::<(PaymentRequest, OutputData)>::new();
+
+        payment_requests.retain(|request| {
+            // An output only pays one request
+            let paying_output = new_outputs.iter().find(|output_data| {
+                request.is_paid_by(output_data)
+                    && !fulfilled
+                        .iter()
+                        .any(|(_, paid_output)| paid_output.output_id == output_data.output_id)
+            });
+            match paying_output {
+                Some(output_data) => {
+                    log::debug!("[PAYMENT_REQUEST] {request} paid by {}", output_data.output_id);
+                    fulfilled.push((request.clone(), output_data.clone()));
+                    false
+                }
+                None => !request.is_expired(now),
+            }
+        });
+
+        if payment_requests.len() != len {
+            self.save_payment_requests(&payment_requests).await?;
+        }
+        Ok(fulfilled)
+    }
+
+    #[cfg_attr(not(feature = "storage"), allow(unused_variables))]
+    async fn save_payment_requests(&self, payment_requests: &[PaymentRequest]) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_payment_requests(index, payment_requests).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{address::Hrp, rand::address::rand_ed25519_address};
+
+    fn request() -> PaymentRequest {
+        PaymentRequest {
+            address: Bech32Address::new(Hrp::from_str_unchecked("rms"), rand_ed25519_address()),
+            amount: Some(1_000_000),
+            unit: PaymentUnit::BaseToken,
+            tag: Some(b"invoice-42".to_vec()),
+            expires_at: Some(1_700_000_000),
+        }
+    }
+
+    #[test]
+    fn uri_round_trip() {
+        let request = request();
+        assert_eq!(request.to_uri().parse::<PaymentRequest>().unwrap(), request);
+
+        let request = PaymentRequest {
+            unit: PaymentUnit::NativeToken(TokenId::null()),
+            tag: None,
+            expires_at: None,
+            ..request
+        };
+        assert_eq!(request.to_uri().parse::<PaymentRequest>().unwrap(), request);
+    }
+
+    #[test]
+    fn strict_parsing() {
+        let uri = request().to_uri();
+
+        for invalid in [
+            uri.replacen("iota:", "http:", 1),
+            uri.replacen("v=1", "v=2", 1),
+            uri.replacen("v=1&", "", 1),
+            format!("{uri}&amount=1"),
+            format!("{uri}&memo=lunch"),
+            uri.replacen("amount=1000000", "amount=0", 1),
+            uri.replacen("amount=1000000", "amount=-1", 1),
+            uri.replacen("amount=1000000", &format!("unit={}", TokenId::null()), 1),
+            uri.replacen("0x696e766f6963652d3432", &format!("0x{}", "00".repeat(65)), 1),
+        ] {
+            assert!(invalid.parse::<PaymentRequest>().is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exporting the public derivation material of an account, so an auditor can check which addresses belong to it
+//! without access to its private keys.
+//!
+//! Ed25519 keys are derived with SLIP-10, which only supports hardened derivation, so there is no extended public key
+//! from which further addresses could be derived. Instead, the key of every generated address signs the BLAKE2b-256
+//! hash of a domain separator, its BIP44 path and the address. The signature contains the public key, so the auditor
+//! can check that the address is the one of the key and that the key was used for that path. The domain separator
+//! makes sure such a signature can't be used as the signature of a transaction.
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+};
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature},
+        Error,
+    },
+    wallet::account::Account,
+};
+
+/// Domain separator of the signed derivation statements.
+const ADDRESS_DERIVATION_PROOF_DOMAIN: &[u8] = b"IOTA-SDK-ADDRESS-DERIVATION-PROOF";
+
+/// The public derivation material of an account: a derivation proof for each of its generated addresses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicAccount {
+    /// The coin type of the BIP44 paths.
+    pub coin_type: u32,
+    /// The account index of the BIP44 paths.
+    pub account_index: u32,
+    /// The derivation proofs of the public and internal addresses.
+    pub addresses: Vec<AddressDerivationProof>,
+}
+
+impl PublicAccount {
+    /// Verifies the derivation proofs of all addresses.
+    pub fn verify(&self) -> Result<(), Error> {
+        self.addresses
+            .iter()
+            .try_for_each(|proof| proof.verify(self.coin_type, self.account_index))
+    }
+
+    /// Returns the derivation proof of an address, e.g. of a deposit, if it belongs to the account.
+    pub fn derivation_proof(&self, address: &Address) -> Option<&AddressDerivationProof> {
+        self.addresses.iter().find(|proof| proof.address.inner() == address)
+    }
+}
+
+/// A signed statement that the key of the BIP44 path `m/44'/coin_type'/account_index'/internal'/key_index'` controls
+/// an Ed25519 address.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressDerivationProof {
+    /// The address.
+    pub address: Bech32Address,
+    /// The address key index.
+    pub key_index: u32,
+    /// Determines if an address is a public or an internal (change) address.
+    pub internal: bool,
+    /// The signature of the statement, made with the key of the address.
+    pub signature: Ed25519Signature,
+}
+
+impl AddressDerivationProof {
+    /// Returns the statement that gets signed for an address and its path.
+    pub fn statement(
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        key_index: u32,
+        address: &Address,
+    ) -> [u8; 32] {
+        Blake2b256::new()
+            .chain_update(ADDRESS_DERIVATION_PROOF_DOMAIN)
+            .chain_update(coin_type.to_le_bytes())
+            .chain_update(account_index.to_le_bytes())
+            .chain_update([internal as u8])
+            .chain_update(key_index.to_le_bytes())
+            .chain_update(address.pack_to_vec())
+            .finalize()
+            .into()
+    }
+
+    /// Verifies that the proof was signed for the path by the key of the address.
+    pub fn verify(&self, coin_type: u32, account_index: u32) -> Result<(), Error> {
+        match self.address.inner() {
+            Address::Ed25519(address) => self.signature.is_valid(
+                &Self::statement(
+                    coin_type,
+                    account_index,
+                    self.internal,
+                    self.key_index,
+                    self.address.inner(),
+                ),
+                address,
+            ),
+            address => Err(Error::InvalidAddressKind(address.kind())),
+        }
+    }
+}
+
+/// Dto for a [`PublicAccount`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicAccountDto {
+    /// The coin type of the BIP44 paths.
+    pub coin_type: u32,
+    /// The account index of the BIP44 paths.
+    pub account_index: u32,
+    /// The derivation proofs of the public and internal addresses.
+    pub addresses: Vec<AddressDerivationProofDto>,
+}
+
+impl From<&PublicAccount> for PublicAccountDto {
+    fn from(value: &PublicAccount) -> Self {
+        Self {
+            coin_type: value.coin_type,
+            account_index: value.account_index,
+            addresses: value.addresses.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<PublicAccountDto> for PublicAccount {
+    type Error = Error;
+
+    fn try_from(value: PublicAccountDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            coin_type: value.coin_type,
+            account_index: value.account_index,
+            addresses: value
+                .addresses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Dto for an [`AddressDerivationProof`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressDerivationProofDto {
+    /// The address.
+    pub address: Bech32Address,
+    /// The address key index.
+    pub key_index: u32,
+    /// Determines if an address is a public or an internal (change) address.
+    pub internal: bool,
+    /// The signature of the statement.
+    pub signature: Ed25519SignatureDto,
+}
+
+impl From<&AddressDerivationProof> for AddressDerivationProofDto {
+    fn from(value: &AddressDerivationProof) -> Self {
+        Self {
+            address: value.address.clone(),
+            key_index: value.key_index,
+            internal: value.internal,
+            signature: Ed25519SignatureDto::from(&value.signature),
+        }
+    }
+}
+
+impl TryFrom<AddressDerivationProofDto> for AddressDerivationProof {
+    type Error = Error;
+
+    fn try_from(value: AddressDerivationProofDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: value.address,
+            key_index: value.key_index,
+            internal: value.internal,
+            signature: Ed25519Signature::try_from(value.signature)?,
+        })
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Exports the public derivation material of the account, a signed derivation proof for each of its generated
+    /// addresses. With it an auditor can verify that addresses, e.g. of seen deposits, belong to the account, without
+    /// access to its private keys. Addresses generated later aren't included, so the export has to be repeated.
+    pub async fn export_public_account(&self) -> crate::wallet::Result<PublicAccount> {
+        log::debug!("[export_public_account]");
+        let (coin_type, account_index, addresses) = {
+            let account_details = self.details().await;
+            account_details.check_can_sign()?;
+            (
+                *account_details.coin_type(),
+                *account_details.bip44_account_index(),
+                account_details
+                    .public_addresses
+                    .iter()
+                    .chain(&account_details.internal_addresses)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        let mut proofs = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let chain = Bip44::new(coin_type)
+                .with_account(account_index)
+                .with_change(address.internal as _)
+                .with_address_index(address.key_index);
+            let statement = AddressDerivationProof::statement(
+                coin_type,
+                account_index,
+                address.internal,
+                address.key_index,
+                address.address.inner(),
+            );
+            let signature = self
+                .wallet
+                .secret_manager
+                .read()
+                .await
+                .sign_ed25519(&statement, chain)
+                .await?;
+            proofs.push(AddressDerivationProof {
+                address: address.address,
+                key_index: address.key_index,
+                internal: address.internal,
+                signature,
+            });
+        }
+
+        Ok(PublicAccount {
+            coin_type,
+            account_index,
+            addresses: proofs,
+        })
+    }
+}
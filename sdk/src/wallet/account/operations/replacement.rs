@@ -0,0 +1,252 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, secret::SecretManage, Error as ClientError},
+    types::block::{
+        address::{Address, Bech32Address},
+        input::Input,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftOutputBuilder, Output},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account, TransactionOptions,
+        },
+        Error, Result,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Replaces a pending transaction that doesn't get confirmed with a new transaction that spends the same inputs.
+    /// The outputs of the transaction to addresses of other accounts are sent to `destination` instead, or back to the
+    /// account if none is provided, all other outputs stay the same.
+    ///
+    /// Since both transactions spend the same inputs at most one of them can get confirmed, so the funds can't be sent
+    /// twice. The replacement is refused if the node already knows the transaction as confirmed or one of its inputs as
+    /// spent, the original transaction stays pending until syncing finds out which of both got confirmed.
+    pub async fn replace_transaction(
+        &self,
+        transaction_id: &TransactionId,
+        destination: impl Into<Option<Bech32Address>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<Transaction> {
+        log::debug!("[TRANSACTION] replace_transaction {transaction_id}");
+        let transaction = self
+            .get_transaction(transaction_id)
+            .await
+            .ok_or(Error::TransactionNotFound(*transaction_id))?;
+
+        if transaction.incoming || transaction.inclusion_state != InclusionState::Pending {
+            return Err(Error::TransactionNotReplaceable {
+                transaction_id: *transaction_id,
+                reason: "it's not a pending transaction of the account",
+            });
+        }
+
+        // The account may not be synced, so the node decides if it's too late to replace the transaction
+        match self.client().get_included_block_metadata(transaction_id).await {
+            Ok(_) => {
+                return Err(Error::TransactionNotReplaceable {
+                    transaction_id: *transaction_id,
+                    reason: "it's already confirmed",
+                });
+            }
+            Err(ClientError::Node(NodeApiError::NotFound(_))) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        for output_id in &input_ids {
+            if self.client().get_output_metadata(output_id).await?.is_spent() {
+                return Err(Error::TransactionNotReplaceable {
+                    transaction_id: *transaction_id,
+                    reason: "one of its inputs is already spent",
+                });
+            }
+        }
+
+        let outputs = self.replacement_outputs(essence.outputs(), destination.into()).await?;
+
+        // Same inputs and outputs would give the same transaction
+        if outputs.as_slice() == essence.outputs() {
+            return Err(Error::TransactionNotReplaceable {
+                transaction_id: *transaction_id,
+                reason: "it doesn't send anything to other addresses",
+            });
+        }
+
+        let mut options = options.into().unwrap_or_default();
+        options.custom_inputs = Some(input_ids.clone());
+        options.mandatory_inputs = None;
+
+        let _operation = self.operation_queue.lock().await;
+        // The inputs are locked by the transaction, they have to be unlocked so they can be selected again
+        {
+            let mut account_details = self.details_mut().await;
+            for output_id in &input_ids {
+                account_details.locked_outputs.remove(output_id);
+            }
+        }
+
+        let result = match self.prepare_transaction(outputs, options.clone()).await {
+            Ok(prepared_transaction_data) => {
+                self.sign_and_submit_transaction(prepared_transaction_data, options)
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+
+        if result.is_err() {
+            // The transaction is still pending, so its inputs must not be used by other transactions
+            self.details_mut().await.locked_outputs.extend(input_ids);
+        }
+
+        result
+    }
+
+    /// Returns the outputs of the transaction with the basic and NFT outputs to other addresses sent to the
+    /// destination instead.
+    async fn replacement_outputs(&self, outputs: &[Output], destination: Option<Bech32Address>) -> Result<Vec<Output>> {
+        let destination = match destination {
+            Some(bech32_address) => {
+                self.client().bech32_hrp_matches(bech32_address.hrp()).await?;
+                bech32_address.into_inner()
+            }
+            None => self
+                .public_addresses()
+                .await
+                .first()
+                .ok_or(Error::MissingParameter("address"))?
+                .address()
+                .into_inner(),
+        };
+        let account_addresses = self
+            .addresses()
+            .await?
+            .into_iter()
+            .map(|address| address.address().into_inner())
+            .collect::<HashSet<Address>>();
+        let token_supply = self.client().get_token_supply().await?;
+
+        outputs
+            .iter()
+            .map(|output| {
+                Ok(match output {
+                    Output::Basic(basic_output) if !account_addresses.contains(basic_output.address()) => {
+                        BasicOutputBuilder::from(basic_output)
+                            .replace_unlock_condition(AddressUnlockCondition::new(destination))
+                            .finish_output(token_supply)?
+                    }
+                    Output::Nft(nft_output) if !account_addresses.contains(nft_output.address()) => {
+                        NftOutputBuilder::from(nft_output)
+                            .replace_unlock_condition(AddressUnlockCondition::new(destination))
+                            .finish_output(token_supply)?
+                    }
+                    output => output.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use super::*;
+    use crate::types::block::output::OutputId;
+
+    fn assert_not_replaceable(result: Result<Transaction>, expected_reason: &str) {
+        match result {
+            Err(Error::TransactionNotReplaceable { reason, .. }) => assert_eq!(reason, expected_reason),
+            _ => panic!("expected the transaction to not be replaceable"),
+        }
+    }
+
+    #[tokio::test]
+    async fn only_pending_transactions_are_replaced() {
+        let storage_path = "test-storage/replace_transaction_not_pending";
+        let account = Account::mock(storage_path).await;
+
+        assert!(matches!(
+            account
+                .replace_transaction(&TransactionId::new([1; 32]), None, None)
+                .await,
+            Err(Error::TransactionNotFound(_))
+        ));
+
+        let transaction_id = account.add_mock_pending_transaction().await;
+        account
+            .details_mut()
+            .await
+            .transactions
+            .get_mut(&transaction_id)
+            .unwrap()
+            .inclusion_state = InclusionState::Confirmed;
+        assert_not_replaceable(
+            account.replace_transaction(&transaction_id, None, None).await,
+            "it's not a pending transaction of the account",
+        );
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[tokio::test]
+    async fn spent_inputs_are_not_replaced() {
+        let storage_path = "test-storage/replace_transaction_spent_inputs";
+        let spent_input = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let unspent_input = OutputId::new(TransactionId::new([1; 32]), 1).unwrap();
+        let spent_outputs = HashMap::from([(spent_input, TransactionId::new([2; 32]))]);
+        let account = Account::mock_with_spent_outputs(storage_path, spent_outputs).await;
+        let transaction_id = account
+            .add_mock_pending_transaction_with_inputs(&[unspent_input, spent_input])
+            .await;
+
+        assert_not_replaceable(
+            account.replace_transaction(&transaction_id, None, None).await,
+            "one of its inputs is already spent",
+        );
+        // The original transaction is kept
+        assert!(account.details().await.pending_transactions.contains(&transaction_id));
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[tokio::test]
+    async fn failed_replacement_keeps_inputs_locked() {
+        let storage_path = "test-storage/replace_transaction_failed";
+        let inputs = [
+            OutputId::new(TransactionId::new([1; 32]), 0).unwrap(),
+            OutputId::new(TransactionId::new([1; 32]), 1).unwrap(),
+        ];
+        let account = Account::mock(storage_path).await;
+        let transaction_id = account.add_mock_pending_transaction_with_inputs(&inputs).await;
+        account.details_mut().await.locked_outputs.extend(inputs);
+
+        // The inputs are unspent, but the account doesn't know them, so no replacement can be built
+        assert!(account.replace_transaction(&transaction_id, None, None).await.is_err());
+
+        let account_details = account.details().await;
+        assert_eq!(account_details.locked_outputs, HashSet::from(inputs));
+        assert!(account_details.pending_transactions.contains(&transaction_id));
+        drop(account_details);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
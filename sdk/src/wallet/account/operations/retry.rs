@@ -1,8 +1,14 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionBlockEvent, TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::{secret::SecretManage, Error as ClientError},
+    client::{secret::SecretManage, Error as ClientError, RetryPolicy},
     types::{
         api::core::response::LedgerInclusionState,
         block::{
@@ -10,14 +16,56 @@ use crate::{
             Block, BlockId,
         },
     },
+    utils::unix_timestamp_now,
     wallet::{
-        account::{types::InclusionState, Account},
+        account::{
+            types::{AttachmentKind, InclusionState, Transaction, TransactionAttachment},
+            Account,
+        },
         Error,
     },
 };
 
-const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
-const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+const DEFAULT_PROMOTE_AFTER: u64 = 10;
+const DEFAULT_REATTACH_AFTER: u64 = 60;
+const DEFAULT_MAX_MILESTONE_LAG: u32 = 15;
+
+/// Thresholds used by the background retrying to decide when a pending transaction gets promoted or reattached.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryOptions {
+    /// Seconds after the creation of a transaction from which its block gets promoted, if the node suggests it.
+    #[serde(default = "default_promote_after")]
+    pub promote_after: u64,
+    /// Seconds after the creation of a transaction from which its block gets reattached, if it's still not included.
+    #[serde(default = "default_reattach_after")]
+    pub reattach_after: u64,
+    /// Amount of milestones a block can stay unreferenced before it gets reattached.
+    #[serde(default = "default_max_milestone_lag")]
+    pub max_milestone_lag: u32,
+}
+
+fn default_promote_after() -> u64 {
+    DEFAULT_PROMOTE_AFTER
+}
+
+fn default_reattach_after() -> u64 {
+    DEFAULT_REATTACH_AFTER
+}
+
+fn default_max_milestone_lag() -> u32 {
+    DEFAULT_MAX_MILESTONE_LAG
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            promote_after: default_promote_after(),
+            reattach_after: default_reattach_after(),
+            max_milestone_lag: default_max_milestone_lag(),
+        }
+    }
+}
 
 impl<S: 'static + SecretManage> Account<S>
 where
@@ -48,6 +96,26 @@ where
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
 
+        let mut attached = false;
+        let result = self
+            .retry_transaction_until_included_internal(transaction_id, interval, max_attempts, &mut attached)
+            .await;
+        // The attachments are only added in memory while retrying, so the account is saved once instead of after
+        // every promotion or reattachment
+        #[cfg(feature = "storage")]
+        if attached {
+            self.save(None).await?;
+        }
+        result
+    }
+
+    async fn retry_transaction_until_included_internal(
+        &self,
+        transaction_id: &TransactionId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+        attached: &mut bool,
+    ) -> crate::wallet::Result<BlockId> {
         let transaction = self.details().await.transactions.get(transaction_id).cloned();
 
         if let Some(transaction) = transaction {
@@ -67,25 +135,33 @@ where
 
             let block_id = match transaction.block_id {
                 Some(block_id) => block_id,
-                None => self
-                    .client()
-                    .build_block()
-                    .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                    .await?
-                    .id(),
+                None => {
+                    let result = self
+                        .client()
+                        .build_block()
+                        .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
+                        .await
+                        .map(|block| block.id());
+                    *attached |= self
+                        .add_transaction_attachment(
+                            transaction_id,
+                            TransactionAttachment::new(AttachmentKind::Submission, &result),
+                        )
+                        .await;
+                    result?
+                }
             };
 
             // Attachments of the Block to check inclusion state
             let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-                let duration =
-                    std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
-
+            let policy = RetryPolicy::until_included();
+            let duration = interval.map_or(policy.initial_delay, std::time::Duration::from_secs);
+            for _ in 0..max_attempts.unwrap_or(policy.max_attempts as u64) {
                 #[cfg(target_family = "wasm")]
                 gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
 
                 #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
+                crate::client::runtime::sleep(duration).await;
 
                 // Check inclusion state for each attachment
                 let block_ids_len = block_ids.len();
@@ -106,14 +182,32 @@ where
                     if index == block_ids_len - 1 {
                         if block_metadata.should_promote.unwrap_or(false) {
                             // Safe to unwrap since we iterate over it
-                            self.client().promote_unchecked(block_ids.last().unwrap()).await?;
+                            let result = self
+                                .client()
+                                .promote_unchecked(block_ids.last().unwrap())
+                                .await
+                                .map(|(promotion_block_id, _)| promotion_block_id);
+                            *attached |= self
+                                .add_transaction_attachment(
+                                    transaction_id,
+                                    TransactionAttachment::new(AttachmentKind::Promotion, &result),
+                                )
+                                .await;
+                            result?;
                         } else if block_metadata.should_reattach.unwrap_or(false) {
-                            let reattached_block = self
+                            let result = self
                                 .client()
                                 .build_block()
                                 .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                                .await?;
-                            block_ids.push(reattached_block.id());
+                                .await
+                                .map(|block| block.id());
+                            *attached |= self
+                                .add_transaction_attachment(
+                                    transaction_id,
+                                    TransactionAttachment::new(AttachmentKind::Reattachment, &result),
+                                )
+                                .await;
+                            block_ids.push(result?);
                         }
                     }
                 }
@@ -136,4 +230,245 @@ where
             Err(Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    /// Promotes or reattaches the blocks of all pending transactions based on the provided thresholds.
+    /// `attachments` keeps the latest milestone index and the time at which a block was attached or first checked, so
+    /// the age and milestone lag of a block can be computed across calls.
+    pub(crate) async fn retry_pending_transactions(
+        &self,
+        options: &RetryOptions,
+        attachments: &mut HashMap<BlockId, (u32, u128)>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[retry_pending_transactions]");
+
+        // Syncing reattaches pending transactions too, holding its lock prevents both from reattaching the same
+        // transaction and from overwriting the transactions updated by the other one
+        let _last_synced = self.last_synced.lock().await;
+
+        let account_details = self.details().await;
+        #[cfg(feature = "events")]
+        let account_index = account_details.index;
+        let pending_transactions = account_details
+            .pending_transactions
+            .iter()
+            .filter_map(|transaction_id| account_details.transactions.get(transaction_id).cloned())
+            .collect::<Vec<_>>();
+        drop(account_details);
+
+        if pending_transactions.is_empty() {
+            attachments.clear();
+            return Ok(());
+        }
+
+        let network_id = self.client().get_network_id().await?;
+        let latest_milestone_index = self.client().get_info().await?.node_info.status.latest_milestone.index;
+        let time_now = unix_timestamp_now().as_millis();
+
+        let mut pending_block_ids = Vec::new();
+        let mut updated_transactions = Vec::new();
+        let mut retry_error = None;
+
+        for mut transaction in pending_transactions {
+            // Transactions without a block are submitted by the outbox
+            let block_id = match transaction.block_id {
+                Some(block_id) if transaction.network_id == network_id => block_id,
+                _ => continue,
+            };
+
+            let block_metadata = match self.client().get_block_metadata(&block_id).await {
+                Ok(block_metadata) => block_metadata,
+                Err(err) => {
+                    // Don't let a single block, e.g. one the node doesn't know anymore, stop retrying the others
+                    log::warn!("[retry_pending_transactions] couldn't get the metadata of block {block_id}: {err}");
+                    pending_block_ids.push(block_id);
+                    continue;
+                }
+            };
+            // The inclusion state gets updated during syncing
+            if block_metadata.ledger_inclusion_state.is_some() {
+                continue;
+            }
+
+            let (attached_milestone_index, attached_at) = *attachments
+                .entry(block_id)
+                .or_insert_with(|| (latest_milestone_index, first_seen_at(&transaction, &block_id, time_now)));
+            let milestone_lag = latest_milestone_index.saturating_sub(attached_milestone_index);
+            let age = time_now.saturating_sub(attached_at);
+
+            if block_metadata.should_reattach.unwrap_or(false)
+                || age > options.reattach_after as u128 * 1000
+                || milestone_lag > options.max_milestone_lag
+            {
+                log::debug!(
+                    "[retry_pending_transactions] reattaching transaction {}",
+                    transaction.transaction_id
+                );
+                let result = self.submit_transaction_payload(transaction.payload.clone()).await;
+                transaction.add_attachment(TransactionAttachment::new(AttachmentKind::Reattachment, &result));
+                let reattached_block_id = match result {
+                    Ok(block_id) => block_id,
+                    Err(err) => {
+                        // Store the failed attempt before returning the error
+                        updated_transactions.push(transaction);
+                        retry_error.replace(err);
+                        break;
+                    }
+                };
+                attachments.remove(&block_id);
+                attachments.insert(reattached_block_id, (latest_milestone_index, time_now));
+                pending_block_ids.push(reattached_block_id);
+                transaction.block_id.replace(reattached_block_id);
+                #[cfg(feature = "events")]
+                self.emit(
+                    account_index,
+                    WalletEvent::TransactionProgress(TransactionProgressEvent::Reattached(TransactionBlockEvent {
+                        transaction_id: transaction.transaction_id,
+                        block_id: reattached_block_id,
+                    })),
+                )
+                .await;
+                updated_transactions.push(transaction);
+            } else {
+                pending_block_ids.push(block_id);
+                if block_metadata.should_promote.unwrap_or(false) && age > options.promote_after as u128 * 1000 {
+                    log::debug!("[retry_pending_transactions] promoting block {block_id}");
+                    let result = self
+                        .client()
+                        .promote_unchecked(&block_id)
+                        .await
+                        .map(|(promotion_block_id, _)| promotion_block_id);
+                    transaction.add_attachment(TransactionAttachment::new(AttachmentKind::Promotion, &result));
+                    let _promotion_block_id = match result {
+                        Ok(promotion_block_id) => promotion_block_id,
+                        Err(err) => {
+                            updated_transactions.push(transaction);
+                            retry_error.replace(err.into());
+                            break;
+                        }
+                    };
+                    #[cfg(feature = "events")]
+                    self.emit(
+                        account_index,
+                        WalletEvent::TransactionProgress(TransactionProgressEvent::Promoted(TransactionBlockEvent {
+                            transaction_id: transaction.transaction_id,
+                            block_id: _promotion_block_id,
+                        })),
+                    )
+                    .await;
+                    updated_transactions.push(transaction);
+                }
+            }
+        }
+
+        // Forget blocks that aren't pending anymore, not all of them were checked if retrying failed
+        if retry_error.is_none() {
+            attachments.retain(|block_id, _| pending_block_ids.contains(block_id));
+        }
+
+        if !updated_transactions.is_empty() {
+            self.update_account_with_transactions(updated_transactions, Vec::new(), Vec::new())
+                .await?;
+        }
+
+        if let Some(err) = retry_error {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    // Adds an attachment to the history of a stored transaction without saving the account, returns whether the
+    // transaction was found
+    async fn add_transaction_attachment(
+        &self,
+        transaction_id: &TransactionId,
+        attachment: TransactionAttachment,
+    ) -> bool {
+        match self.details_mut().await.transactions.get_mut(transaction_id) {
+            Some(transaction) => {
+                transaction.add_attachment(attachment);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// Returns when a block of a transaction was attached according to its attachment history, or `time_now` for blocks
+// that weren't attached by the wallet, e.g. the ones of a restored account, so their age starts when they're first seen
+fn first_seen_at(transaction: &Transaction, block_id: &BlockId, time_now: u128) -> u128 {
+    transaction
+        .attachment_history()
+        .iter()
+        .rev()
+        .find(|attachment| attachment.block_id.as_ref() == Some(block_id))
+        .map_or(time_now, |attachment| attachment.timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::output::OutputId;
+
+    #[test]
+    fn first_seen() {
+        let transaction_id = TransactionId::new([1; 32]);
+        let mut transaction = Transaction::mock(transaction_id, &[OutputId::new(transaction_id, 0).unwrap()]);
+        let block_id = BlockId::new([2; 32]);
+        let time_now = 1_700_000_000_000;
+
+        // Not attached by the wallet, the transaction timestamp isn't used
+        assert_eq!(first_seen_at(&transaction, &block_id, time_now), time_now);
+
+        let mut attachment = TransactionAttachment::new(AttachmentKind::Submission, &Ok::<_, Error>(block_id));
+        attachment.timestamp = 1_699_999_990_000;
+        transaction.add_attachment(attachment);
+        transaction.add_attachment(TransactionAttachment::new(
+            AttachmentKind::Reattachment,
+            &Err::<BlockId, _>(Error::MissingParameter("block id")),
+        ));
+        assert_eq!(first_seen_at(&transaction, &block_id, time_now), 1_699_999_990_000);
+    }
+
+    #[tokio::test]
+    async fn failed_submission_is_stored() {
+        let storage_path = "test-storage/retry_failed_submission";
+        let account = Account::mock(storage_path).await;
+        let transaction_id = account.add_mock_pending_transaction().await;
+
+        // The node rejects the block
+        assert!(
+            account
+                .retry_transaction_until_included(&transaction_id, Some(0), Some(1))
+                .await
+                .is_err()
+        );
+
+        let history = account.details().await.transactions[&transaction_id]
+            .attachment_history()
+            .to_vec();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, AttachmentKind::Submission);
+        assert!(history[0].block_id.is_none());
+        assert!(history[0].error.is_some());
+
+        // The account is saved once retrying stopped
+        #[cfg(feature = "storage")]
+        {
+            let stored_accounts = account
+                .wallet
+                .storage_manager
+                .write()
+                .await
+                .get_accounts()
+                .await
+                .unwrap();
+            assert_eq!(
+                stored_accounts[0].transactions[&transaction_id].attachment_history(),
+                history
+            );
+        }
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
 }
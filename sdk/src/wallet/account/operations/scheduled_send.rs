@@ -0,0 +1,241 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sends that are stored with the account and executed by the wallet once they are due, e.g. for payouts on a fixed
+//! date without an external scheduler.
+//!
+//! Scheduled sends are executed by [`Account::send_due_scheduled_sends()`], which
+//! [`Wallet::start_background_scheduling()`](crate::wallet::Wallet::start_background_scheduling) calls periodically.
+//! A send is due when its time is reached by the local clock, which is checked against the timestamp of the latest
+//! milestone, so a wrong clock neither sends early nor late, but the sends wait until the clock is fixed. The id of a
+//! scheduled send is the request id of its transaction, so it isn't sent twice if the wallet stops after the
+//! transaction was submitted, but before the scheduled send was removed. Failed sends are tried again on the next run.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions, TransactionOptionsDto},
+        transfer_template::TransferTemplateOverrides,
+        Error, SendParams,
+    },
+};
+
+/// The length of the random ids of scheduled sends, in bytes.
+const SCHEDULED_SEND_ID_LENGTH: usize = 16;
+
+/// The transfer of a [`ScheduledSend`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScheduledTransfer {
+    /// Sends base coins with the params, like [`Account::send_with_params()`].
+    #[serde(rename_all = "camelCase")]
+    Send {
+        /// The params of the outputs.
+        params: Vec<SendParams>,
+        /// The options of the transaction.
+        #[serde(default)]
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Sends a transfer template of the wallet, like [`Account::send_from_template()`]. Changes of the template until
+    /// the send is due are used.
+    #[serde(rename_all = "camelCase")]
+    Template {
+        /// The id of the template.
+        id: String,
+        /// The fields replacing the ones of the template.
+        #[serde(default)]
+        overrides: Option<TransferTemplateOverrides>,
+    },
+}
+
+/// A transfer that is sent by the wallet once it's due.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledSend {
+    /// The id of the scheduled send, also used as request id of its transaction.
+    pub id: String,
+    /// The unix timestamp in seconds from which on the transfer is sent.
+    pub execute_at: u32,
+    /// The transfer to send.
+    pub transfer: ScheduledTransfer,
+    /// The number of failed attempts to send the transfer.
+    #[serde(default)]
+    pub attempts: u32,
+    /// The error of the last failed attempt.
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl ScheduledSend {
+    /// Returns whether the transfer should be sent at the given unix timestamp in seconds.
+    pub fn is_due(&self, now: u32) -> bool {
+        self.execute_at <= now
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Schedules a transfer to be sent from the given unix timestamp in seconds on. A time in the past sends it with
+    /// the next execution of the due sends.
+    pub async fn schedule_send(&self, at: u32, transfer: ScheduledTransfer) -> crate::wallet::Result<ScheduledSend> {
+        log::debug!("[schedule_send] at {at}");
+        self.details().await.check_can_sign()?;
+        match &transfer {
+            ScheduledTransfer::Send { params, .. } => {
+                if params.is_empty() {
+                    return Err(Error::MissingParameter("params"));
+                }
+            }
+            ScheduledTransfer::Template { id, .. } => {
+                if self.wallet.transfer_template(id).await.is_none() {
+                    return Err(Error::TransferTemplateNotFound(id.clone()));
+                }
+            }
+        }
+
+        let mut id = [0u8; SCHEDULED_SEND_ID_LENGTH];
+        crypto::utils::rand::fill(&mut id)?;
+        let scheduled_send = ScheduledSend {
+            id: format!("scheduled-{}", prefix_hex::encode(id)),
+            execute_at: at,
+            transfer,
+            attempts: 0,
+            last_error: None,
+        };
+
+        let mut scheduled_sends = self.scheduled_sends.lock().await;
+        // Keep the sends ordered by time, so they are executed in this order
+        let position = scheduled_sends.partition_point(|scheduled| scheduled.execute_at <= at);
+        scheduled_sends.insert(position, scheduled_send.clone());
+        self.save_scheduled_sends(&scheduled_sends).await?;
+
+        Ok(scheduled_send)
+    }
+
+    /// Returns the scheduled sends that weren't sent yet, ordered by time.
+    pub async fn scheduled_sends(&self) -> Vec<ScheduledSend> {
+        self.scheduled_sends.lock().await.clone()
+    }
+
+    /// Cancels a scheduled send. Returns whether it was scheduled, a send that is currently executed can't be
+    /// cancelled anymore.
+    pub async fn cancel_scheduled_send(&self, id: &str) -> crate::wallet::Result<bool> {
+        let mut scheduled_sends = self.scheduled_sends.lock().await;
+        let Some(position) = scheduled_sends.iter().position(|scheduled| scheduled.id == id) else {
+            return Ok(false);
+        };
+        scheduled_sends.remove(position);
+        self.save_scheduled_sends(&scheduled_sends).await?;
+
+        Ok(true)
+    }
+
+    /// Sends the scheduled transfers that are due and returns them with their transactions. Sent transfers are
+    /// removed, failed ones stay scheduled with the error and are tried again on the next call. Fails without sending
+    /// anything if the local time differs too much from the time of the latest milestone.
+    pub async fn send_due_scheduled_sends(&self) -> crate::wallet::Result<Vec<(ScheduledSend, Transaction)>> {
+        let mut scheduled_sends = self.scheduled_sends.lock().await;
+        if scheduled_sends.is_empty() {
+            return Ok(Vec::new());
+        }
+        let now = self.client().get_time_checked().await?;
+        if !scheduled_sends[0].is_due(now) {
+            return Ok(Vec::new());
+        }
+
+        let mut sent = Vec::new();
+        let mut remaining = Vec::with_capacity(scheduled_sends.len());
+        for mut scheduled_send in scheduled_sends.drain(..) {
+            if !scheduled_send.is_due(now) {
+                remaining.push(scheduled_send);
+                continue;
+            }
+            log::debug!("[schedule_send] sending {}", scheduled_send.id);
+            match self.send_scheduled(&scheduled_send).await {
+                Ok(transaction) => sent.push((scheduled_send, transaction)),
+                Err(err) => {
+                    log::debug!("[schedule_send] sending {} failed: {err}", scheduled_send.id);
+                    scheduled_send.attempts += 1;
+                    scheduled_send.last_error = Some(err.to_string());
+                    remaining.push(scheduled_send);
+                }
+            }
+        }
+        *scheduled_sends = remaining;
+        self.save_scheduled_sends(&scheduled_sends).await?;
+
+        Ok(sent)
+    }
+
+    async fn send_scheduled(&self, scheduled_send: &ScheduledSend) -> crate::wallet::Result<Transaction> {
+        let (params, options) = match &scheduled_send.transfer {
+            ScheduledTransfer::Send { params, options } => (
+                params.clone(),
+                options.clone().map(TransactionOptions::try_from_dto).transpose()?,
+            ),
+            ScheduledTransfer::Template { id, overrides } => {
+                self.transfer_template_params(id, overrides.clone()).await?
+            }
+        };
+        let mut options = options.unwrap_or_default();
+        options.request_id.get_or_insert_with(|| scheduled_send.id.clone());
+
+        self.send_with_params(params, options).await
+    }
+
+    #[cfg_attr(not(feature = "storage"), allow(unused_variables))]
+    async fn save_scheduled_sends(&self, scheduled_sends: &[ScheduledSend]) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_scheduled_sends(index, scheduled_sends).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scheduled_send_serde() {
+        let scheduled_send = ScheduledSend {
+            id: "scheduled-0x01".to_string(),
+            execute_at: 1_700_000_000,
+            transfer: ScheduledTransfer::Template {
+                id: "payroll".to_string(),
+                overrides: None,
+            },
+            attempts: 0,
+            last_error: None,
+        };
+        assert!(scheduled_send.is_due(1_700_000_000));
+        assert!(!scheduled_send.is_due(1_699_999_999));
+
+        let json = serde_json::to_value(&scheduled_send).unwrap();
+        assert_eq!(json["transfer"]["type"], "template");
+        assert_eq!(json["executeAt"], 1_700_000_000);
+
+        let params = SendParams::new(
+            1_000_000,
+            "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+        )
+        .unwrap();
+        let transfer = ScheduledTransfer::Send {
+            params: vec![params],
+            options: None,
+        };
+        let json = serde_json::to_value(&transfer).unwrap();
+        assert_eq!(json["type"], "send");
+        assert!(matches!(
+            serde_json::from_value(json).unwrap(),
+            ScheduledTransfer::Send { params, options: None } if params.len() == 1
+        ));
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+
+use crate::{
+    client::{
+        secret::SecretManage,
+        sign_in::{SignInChallenge, SignInResponse},
+        utils::signed_data_hash,
+    },
+    wallet::account::Account,
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Signs a challenge of a web service to sign in with an address of the account. The message of the challenge
+    /// should be shown to the user before, so they know which service they sign in to.
+    pub async fn sign_in(&self, challenge: SignInChallenge) -> crate::wallet::Result<SignInResponse> {
+        log::debug!("[sign_in] {}", challenge.domain);
+        let chain = {
+            let account_details = self.details().await;
+            account_details.check_can_sign()?;
+            let address = account_details
+                .public_addresses
+                .iter()
+                .chain(&account_details.internal_addresses)
+                .find(|address| address.address == challenge.address)
+                .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(challenge.address.clone()))?;
+            Bip44::new(account_details.coin_type)
+                .with_account(account_details.bip44_account_index)
+                .with_change(address.internal as _)
+                .with_address_index(address.key_index)
+        };
+
+        let signature = self
+            .wallet
+            .secret_manager
+            .read()
+            .await
+            .sign_ed25519(&signed_data_hash(challenge.message().as_bytes()), chain)
+            .await?;
+
+        Ok(SignInResponse { challenge, signature })
+    }
+}
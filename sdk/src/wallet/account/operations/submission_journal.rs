@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        input::Input,
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::account::{types::Transaction, Account},
+};
+
+/// How long journal entries are kept after their transaction isn't pending anymore, in milliseconds. A request ID
+/// can't be reused within this time.
+const SUBMISSION_JOURNAL_RETENTION: u128 = 24 * 60 * 60 * 1000;
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the transaction that was created for the request ID, if any.
+    pub async fn transaction_by_request_id(&self, request_id: &str) -> Option<Transaction> {
+        let journal = self.submission_journal.lock().await;
+        self.journaled_transaction(&journal, request_id).await
+    }
+
+    // Returns the transaction of a journal entry, the stored transaction is more recent than the journal entry
+    async fn journaled_transaction(
+        &self,
+        journal: &HashMap<String, Transaction>,
+        request_id: &str,
+    ) -> Option<Transaction> {
+        let transaction = journal.get(request_id)?;
+        Some(
+            self.details()
+                .await
+                .transactions()
+                .get(&transaction.transaction_id)
+                .cloned()
+                .unwrap_or_else(|| transaction.clone()),
+        )
+    }
+
+    /// Records a signed transaction before it gets submitted, unless a transaction was already created for the request
+    /// ID, which is returned instead. The check and the record happen under the same lock, so only one of concurrent
+    /// submissions with the same request ID is recorded. If storage is enabled, it will persist during restarts.
+    pub(crate) async fn journal_submission(
+        &self,
+        request_id: String,
+        transaction: &Transaction,
+    ) -> crate::wallet::Result<Option<Transaction>> {
+        let mut journal = self.submission_journal.lock().await;
+        if let Some(journaled_transaction) = self.journaled_transaction(&journal, &request_id).await {
+            return Ok(Some(journaled_transaction));
+        }
+
+        // Pruned here too, as the journal would otherwise only shrink when pending submissions are recovered
+        let now = crate::utils::unix_timestamp_now().as_millis();
+        prune_journal(&mut journal, &self.details().await.pending_transactions, now);
+        journal.insert(request_id, transaction.clone());
+        self.save_submission_journal(&journal).await?;
+        Ok(None)
+    }
+
+    /// Recovers the journaled transactions that aren't stored in the account, because the wallet stopped between
+    /// signing and storing them. Transactions that were already submitted get their block ID from the node, the others
+    /// are submitted again. As the same signed transactions are used, this can't spend the inputs twice. Returns the
+    /// recovered transactions.
+    pub async fn recover_pending_submissions(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[SUBMISSION_JOURNAL] recover_pending_submissions");
+        let unstored_transactions = {
+            let journal = self.submission_journal.lock().await;
+            let account_details = self.details().await;
+            journal
+                .values()
+                .filter(|transaction| !account_details.transactions.contains_key(&transaction.transaction_id))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let mut recovered_transactions = Vec::with_capacity(unstored_transactions.len());
+        for mut transaction in unstored_transactions {
+            if let Ok(block) = self.client().get_included_block(&transaction.transaction_id).await {
+                transaction.block_id.replace(block.id());
+            }
+            log::debug!("[SUBMISSION_JOURNAL] recovered {}", transaction.transaction_id);
+            recovered_transactions.push(transaction);
+        }
+
+        if !recovered_transactions.is_empty() {
+            let mut account_details = self.details_mut().await;
+            for transaction in &recovered_transactions {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                for input in essence.inputs() {
+                    if let Input::Utxo(input) = input {
+                        account_details.locked_outputs.insert(*input.output_id());
+                    }
+                }
+                account_details
+                    .transactions
+                    .insert(transaction.transaction_id, transaction.clone());
+                account_details.pending_transactions.insert(transaction.transaction_id);
+            }
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+
+        self.prune_submission_journal().await?;
+        // Transactions without block ID are in the outbox now
+        self.submit_outbox().await?;
+
+        Ok(recovered_transactions)
+    }
+
+    /// Removes the entries of transactions that aren't pending anymore and are older than the retention time.
+    async fn prune_submission_journal(&self) -> crate::wallet::Result<()> {
+        let now = crate::utils::unix_timestamp_now().as_millis();
+        let mut journal = self.submission_journal.lock().await;
+        let pruned = prune_journal(&mut journal, &self.details().await.pending_transactions, now);
+
+        if pruned {
+            self.save_submission_journal(&journal).await?;
+        }
+        Ok(())
+    }
+
+    #[cfg_attr(not(feature = "storage"), allow(unused_variables))]
+    async fn save_submission_journal(&self, journal: &HashMap<String, Transaction>) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let journal = journal
+                .iter()
+                .map(|(request_id, transaction)| {
+                    (
+                        request_id.clone(),
+                        crate::wallet::account::types::TransactionDto::from(transaction),
+                    )
+                })
+                .collect::<HashMap<_, _>>();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_submission_journal(index, &journal).await?;
+        }
+        Ok(())
+    }
+}
+
+// Removes the entries of transactions that aren't pending anymore and are older than the retention time, returns
+// whether entries were removed
+fn prune_journal(
+    journal: &mut HashMap<String, Transaction>,
+    pending_transactions: &HashSet<TransactionId>,
+    now: u128,
+) -> bool {
+    let len = journal.len();
+    journal.retain(|_, transaction| {
+        pending_transactions.contains(&transaction.transaction_id)
+            || now.saturating_sub(transaction.timestamp) < SUBMISSION_JOURNAL_RETENTION
+    });
+    journal.len() != len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::output::OutputId;
+
+    #[test]
+    fn prune_journal_keeps_pending_and_recent() {
+        let now = 10 * SUBMISSION_JOURNAL_RETENTION;
+        let mut journal = HashMap::new();
+        let mut pending_transactions = HashSet::new();
+        for (request_id, id, timestamp, pending) in [
+            ("old", 1, 0, false),
+            ("old-pending", 2, 0, true),
+            ("recent", 3, now - SUBMISSION_JOURNAL_RETENTION + 1, false),
+            ("expired", 4, now - SUBMISSION_JOURNAL_RETENTION, false),
+        ] {
+            let transaction_id = TransactionId::new([id; 32]);
+            let mut transaction = Transaction::mock(transaction_id, &[OutputId::new(transaction_id, 0).unwrap()]);
+            transaction.timestamp = timestamp;
+            if pending {
+                pending_transactions.insert(transaction_id);
+            }
+            journal.insert(request_id.to_string(), transaction);
+        }
+
+        assert!(prune_journal(&mut journal, &pending_transactions, now));
+        let mut request_ids = journal.keys().map(String::as_str).collect::<Vec<_>>();
+        request_ids.sort_unstable();
+        assert_eq!(request_ids, ["old-pending", "recent"]);
+
+        // Nothing left to prune
+        assert!(!prune_journal(&mut journal, &pending_transactions, now));
+    }
+}
@@ -0,0 +1,307 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use crate::{
+    client::{
+        api::GetAddressesOptions,
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{SecretManage, SecretManager},
+    },
+    types::block::{
+        address::{Address, Bech32Address},
+        input::{UtxoInput, INPUT_COUNT_MAX},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder,
+            NftOutputBuilder, Output, OutputWithMetadata, OUTPUT_COUNT_MAX,
+        },
+        Block,
+    },
+    wallet::{
+        account::{
+            constants::SWEEP_ADDRESS_GAP_LIMIT, operations::output_claiming::get_new_native_token_count, Account,
+        },
+        Error, Result,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Moves all basic and NFT outputs controlled by another secret manager, e.g. a paper wallet mnemonic or a single
+    /// private key, into the account. Outputs are discovered on the public and internal addresses of each of the
+    /// `account_indexes` of the secret manager, until [`SWEEP_ADDRESS_GAP_LIMIT`] consecutive addresses without outputs
+    /// were found. Only outputs without further unlock constraints are swept. They're sent to `target_address`, or to
+    /// the first address of the account if none is provided, in as many transactions as needed.
+    /// Returns the blocks of the sent transactions, the account needs to be synced to see the swept funds.
+    pub async fn sweep(
+        &self,
+        from_secret_manager: &SecretManager,
+        account_indexes: Range<u32>,
+        target_address: impl Into<Option<Bech32Address>> + Send,
+    ) -> Result<Vec<Block>> {
+        log::debug!("[SWEEP] sweep");
+        let target_address = match target_address.into() {
+            Some(bech32_address) => {
+                self.client().bech32_hrp_matches(bech32_address.hrp()).await?;
+                bech32_address.into_inner()
+            }
+            None => self
+                .public_addresses()
+                .await
+                .first()
+                .ok_or(Error::FailedToGetRemainder)?
+                .address()
+                .into_inner(),
+        };
+        let coin_type = *self.details().await.coin_type();
+        let mut blocks = Vec::new();
+
+        // The inputs of a transaction are signed with the addresses of a single account index
+        for account_index in account_indexes {
+            let (outputs, address_range_end) = self
+                .sweep_outputs(from_secret_manager, coin_type, account_index)
+                .await?;
+
+            log::debug!(
+                "[SWEEP] found {} outputs on the first {} addresses of account index {}",
+                outputs.len(),
+                address_range_end,
+                account_index
+            );
+
+            for batch in sweep_batches(outputs)? {
+                let transaction_outputs = self.sweep_transaction_outputs(&batch, target_address).await?;
+
+                let mut block_builder = self
+                    .client()
+                    .build_block()
+                    .with_secret_manager(from_secret_manager)
+                    .with_coin_type(coin_type)
+                    .with_account_index(account_index)
+                    .with_input_range(0..address_range_end);
+                for output_with_metadata in &batch {
+                    block_builder =
+                        block_builder.with_input(UtxoInput::from(*output_with_metadata.metadata().output_id()))?;
+                }
+                let block = block_builder.with_outputs(transaction_outputs)?.finish().await?;
+
+                log::debug!("[SWEEP] sent block {}", block.id());
+                blocks.push(block);
+            }
+        }
+
+        Ok(blocks)
+    }
+
+    /// Discovers the outputs that can be swept from an account index of a secret manager. Returns them together with
+    /// the end of the address index range that was searched.
+    async fn sweep_outputs(
+        &self,
+        secret_manager: &SecretManager,
+        coin_type: u32,
+        account_index: u32,
+    ) -> Result<(Vec<OutputWithMetadata>, u32)> {
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let mut outputs = Vec::new();
+        let mut address_index = 0;
+        let mut unused_addresses = 0;
+
+        while unused_addresses < SWEEP_ADDRESS_GAP_LIMIT {
+            let options = GetAddressesOptions::default()
+                .with_coin_type(coin_type)
+                .with_account_index(account_index)
+                .with_bech32_hrp(bech32_hrp)
+                .with_range(address_index..address_index + SWEEP_ADDRESS_GAP_LIMIT);
+            let public_addresses = secret_manager.generate_ed25519_addresses(options.clone()).await?;
+            let internal_addresses = secret_manager.generate_ed25519_addresses(options.internal()).await?;
+
+            for (public_address, internal_address) in public_addresses.into_iter().zip(internal_addresses) {
+                let mut used = false;
+                for address in [public_address, internal_address] {
+                    let address_outputs = self.sweepable_outputs(address).await?;
+                    used |= !address_outputs.is_empty();
+                    outputs.extend(address_outputs);
+                }
+
+                if used {
+                    unused_addresses = 0;
+                } else {
+                    unused_addresses += 1;
+                }
+            }
+
+            address_index += SWEEP_ADDRESS_GAP_LIMIT;
+        }
+
+        Ok((outputs, address_index))
+    }
+
+    /// Returns the basic and NFT outputs on an address that don't have further unlock constraints.
+    async fn sweepable_outputs(&self, address: Bech32Address) -> Result<Vec<OutputWithMetadata>> {
+        let query_parameters = [
+            QueryParameter::Address(address),
+            QueryParameter::HasExpiration(false),
+            QueryParameter::HasTimelock(false),
+            QueryParameter::HasStorageDepositReturn(false),
+        ];
+
+        let mut output_ids = self.client().basic_output_ids(query_parameters.clone()).await?.items;
+        output_ids.extend(self.client().nft_output_ids(query_parameters).await?.items);
+
+        Ok(self.client().get_outputs(&output_ids).await?)
+    }
+
+    /// Builds the outputs of a sweep transaction, which transfer the funds and NFTs of the inputs to the target
+    /// address.
+    async fn sweep_transaction_outputs(
+        &self,
+        inputs: &[OutputWithMetadata],
+        target_address: Address,
+    ) -> Result<Vec<Output>> {
+        let token_supply = self.client().get_token_supply().await?;
+        let mut outputs = Vec::new();
+        let mut total_amount = 0;
+        let mut total_native_tokens = NativeTokensBuilder::new();
+
+        for input in inputs {
+            match input.output() {
+                Output::Nft(nft_output) => outputs.push(
+                    NftOutputBuilder::from(nft_output)
+                        .with_nft_id(nft_output.nft_id_non_null(input.metadata().output_id()))
+                        .with_unlock_conditions([AddressUnlockCondition::new(target_address)])
+                        .finish_output(token_supply)?,
+                ),
+                output => {
+                    if let Some(native_tokens) = output.native_tokens() {
+                        total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                    }
+                    total_amount += output.amount();
+                }
+            }
+        }
+
+        if total_amount > 0 {
+            outputs.push(
+                BasicOutputBuilder::new_with_amount(total_amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(target_address))
+                    .with_native_tokens(total_native_tokens.finish()?)
+                    .finish_output(token_supply)?,
+            );
+        }
+
+        Ok(outputs)
+    }
+}
+
+/// Splits the outputs into batches that fit into a single transaction.
+fn sweep_batches(outputs: Vec<OutputWithMetadata>) -> Result<Vec<Vec<OutputWithMetadata>>> {
+    let mut batches = Vec::new();
+    let mut batch: Vec<OutputWithMetadata> = Vec::new();
+    let mut batch_native_tokens = NativeTokensBuilder::new();
+    // One output is reserved for the basic output collecting the funds
+    let mut batch_output_count = 1;
+
+    for output in outputs {
+        let is_nft = output.output().is_nft();
+        let native_tokens = output.output().native_tokens().filter(|_| !is_nft);
+        let exceeds_native_tokens = match native_tokens {
+            Some(native_tokens) => {
+                get_new_native_token_count(&batch_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into()
+            }
+            None => false,
+        };
+
+        if batch.len() == INPUT_COUNT_MAX as usize
+            || (is_nft && batch_output_count == OUTPUT_COUNT_MAX)
+            || exceeds_native_tokens
+        {
+            batches.push(std::mem::take(&mut batch));
+            batch_native_tokens = NativeTokensBuilder::new();
+            batch_output_count = 1;
+        }
+
+        if let Some(native_tokens) = native_tokens {
+            batch_native_tokens.add_native_tokens(native_tokens.clone())?;
+        }
+        if is_nft {
+            batch_output_count += 1;
+        }
+        batch.push(output);
+    }
+
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+
+    Ok(batches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        output::{NativeToken, TokenId},
+        protocol::ProtocolParameters,
+        rand::{
+            bytes::rand_bytes_array,
+            output::{rand_nft_output, rand_output_metadata, unlock_condition::rand_address_unlock_condition},
+        },
+    };
+
+    fn basic_output(token_id: Option<TokenId>) -> OutputWithMetadata {
+        let token_supply = ProtocolParameters::default().token_supply();
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(rand_address_unlock_condition());
+        if let Some(token_id) = token_id {
+            builder = builder.add_native_token(NativeToken::new(token_id, 10).unwrap());
+        }
+        OutputWithMetadata::new(builder.finish_output(token_supply).unwrap(), rand_output_metadata())
+    }
+
+    fn nft_output() -> OutputWithMetadata {
+        let token_supply = ProtocolParameters::default().token_supply();
+        OutputWithMetadata::new(Output::Nft(rand_nft_output(token_supply)), rand_output_metadata())
+    }
+
+    fn batch_lengths(batches: &[Vec<OutputWithMetadata>]) -> Vec<usize> {
+        batches.iter().map(Vec::len).collect()
+    }
+
+    #[test]
+    fn sweep_batches_input_count() {
+        assert!(sweep_batches(Vec::new()).unwrap().is_empty());
+
+        let outputs = std::iter::repeat_with(|| basic_output(None))
+            .take(300)
+            .collect::<Vec<_>>();
+        let batches = sweep_batches(outputs.clone()).unwrap();
+        assert_eq!(batch_lengths(&batches), [128, 128, 44]);
+        // The order of the outputs is kept
+        let output_ids = |outputs: &[OutputWithMetadata]| {
+            outputs
+                .iter()
+                .map(|output| *output.metadata().output_id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(output_ids(&batches.concat()), output_ids(&outputs));
+    }
+
+    #[test]
+    fn sweep_batches_nft_count() {
+        // Every NFT needs its own output, besides the basic output collecting the funds
+        let outputs = std::iter::repeat_with(nft_output).take(200).collect::<Vec<_>>();
+        assert_eq!(batch_lengths(&sweep_batches(outputs).unwrap()), [127, 73]);
+    }
+
+    #[test]
+    fn sweep_batches_native_token_count() {
+        // Outputs with native tokens that are already in the batch don't need another slot
+        let token_id = TokenId::from(rand_bytes_array());
+        let mut outputs = vec![basic_output(Some(token_id)); 10];
+        outputs.extend(std::iter::repeat_with(|| basic_output(Some(TokenId::from(rand_bytes_array())))).take(100));
+        assert_eq!(batch_lengths(&sweep_batches(outputs).unwrap()), [73, 37]);
+    }
+}
@@ -28,15 +28,14 @@ where
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
         sync_options: &SyncOptions,
+        created_after: Option<u32>,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         log::debug!("[SYNC] get_alias_and_foundry_output_ids");
         let bech32_address = bech32_address.convert()?;
+        let mut query_parameters = vec![QueryParameter::UnlockableByAddress(bech32_address)];
+        query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
 
-        let mut output_ids = self
-            .client()
-            .alias_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items;
+        let mut output_ids = self.client().alias_output_ids(query_parameters).await?.items;
 
         // Get all results
         if sync_options.alias.foundry_outputs {
@@ -18,8 +18,10 @@ use crate::{
         output::OutputId,
     },
     wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+        constants::PARALLEL_REQUESTS_AMOUNT,
+        operations::syncing::{SyncCheckpoint, SyncOptions},
+        types::address::AddressWithUnspentOutputs,
+        Account,
     },
 };
 
@@ -29,17 +31,18 @@ where
 {
     /// Returns output ids for outputs that are directly (Ed25519 address in AddressUnlockCondition) or indirectly
     /// (alias/nft address in AddressUnlockCondition and the alias/nft output is controlled with the Ed25519 address)
-    /// connected to
+    /// connected to. With `created_after` only outputs created after this timestamp are returned.
     pub(crate) async fn get_output_ids_for_address(
         &self,
         address: Address,
         sync_options: &SyncOptions,
+        created_after: Option<u32>,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         let bech32_address = Bech32Address::new(self.client().get_bech32_hrp().await?, address);
 
         if sync_options.sync_only_most_basic_outputs {
             let output_ids = self
-                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address)
+                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address, created_after)
                 .await?;
             return Ok(output_ids);
         }
@@ -49,11 +52,10 @@ where
             || (address.is_nft() && sync_options.nft.all_outputs())
             || (address.is_alias() && sync_options.alias.all_outputs())
         {
-            return Ok(self
-                .client()
-                .output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-                .await?
-                .items);
+            let mut query_parameters = vec![QueryParameter::UnlockableByAddress(bech32_address)];
+            query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
+
+            return Ok(self.client().output_ids(query_parameters).await?.items);
         }
 
         #[cfg(target_family = "wasm")]
@@ -70,7 +72,7 @@ where
             #[cfg(target_family = "wasm")]
             {
                 results.push(
-                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address, created_after)
                         .await,
                 )
             }
@@ -80,9 +82,9 @@ where
                 tasks.push(
                     async move {
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        crate::wallet::task::spawn(async move {
                             account
-                                .get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_basic_output_ids_with_any_unlock_condition(bech32_address, created_after)
                                 .await
                         })
                         .await
@@ -99,7 +101,10 @@ where
             // nfts
             #[cfg(target_family = "wasm")]
             {
-                results.push(self.get_nft_output_ids_with_any_unlock_condition(bech32_address).await)
+                results.push(
+                    self.get_nft_output_ids_with_any_unlock_condition(bech32_address, created_after)
+                        .await,
+                )
             }
 
             #[cfg(not(target_family = "wasm"))]
@@ -107,9 +112,9 @@ where
                 tasks.push(
                     async move {
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        crate::wallet::task::spawn(async move {
                             account
-                                .get_nft_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_nft_output_ids_with_any_unlock_condition(bech32_address, created_after)
                                 .await
                         })
                         .await
@@ -127,7 +132,7 @@ where
             #[cfg(target_family = "wasm")]
             {
                 results.push(
-                    self.get_alias_and_foundry_output_ids(bech32_address, sync_options)
+                    self.get_alias_and_foundry_output_ids(bech32_address, sync_options, created_after)
                         .await,
                 )
             }
@@ -138,9 +143,9 @@ where
                     async move {
                         let sync_options = sync_options.clone();
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        crate::wallet::task::spawn(async move {
                             account
-                                .get_alias_and_foundry_output_ids(bech32_address, &sync_options)
+                                .get_alias_and_foundry_output_ids(bech32_address, &sync_options, created_after)
                                 .await
                         })
                         .await
@@ -164,7 +169,7 @@ where
                 tasks.push(
                     async move {
                         let client = self.client().clone();
-                        tokio::spawn(async move {
+                        crate::wallet::task::spawn(async move {
                             Ok(client
                                 .foundry_output_ids([QueryParameter::AliasAddress(bech32_address)])
                                 .await?
@@ -190,12 +195,44 @@ where
         Ok(output_ids.into_iter().collect())
     }
 
+    /// Returns the output ids of an address. If the address is part of the sync checkpoint, only outputs created since
+    /// then are queried and the previously known outputs are kept as long as they're unspent.
+    async fn get_output_ids_since_checkpoint(
+        &self,
+        address: &AddressWithUnspentOutputs,
+        sync_options: &SyncOptions,
+        created_after: Option<u32>,
+    ) -> crate::wallet::Result<Vec<OutputId>> {
+        let mut output_ids = self
+            .get_output_ids_for_address(address.address.inner, sync_options, created_after)
+            .await?;
+
+        if created_after.is_some() && !address.output_ids.is_empty() {
+            let known_unspent_output_ids = self
+                .client()
+                .get_outputs_metadata_ignore_errors(&address.output_ids)
+                .await?
+                .into_iter()
+                .filter(|metadata| !metadata.is_spent())
+                .map(|metadata| *metadata.output_id());
+
+            for output_id in known_unspent_output_ids {
+                if !output_ids.contains(&output_id) {
+                    output_ids.push(output_id);
+                }
+            }
+        }
+
+        Ok(output_ids)
+    }
+
     /// Get the current output ids for provided addresses and only returns addresses that have unspent outputs and
     /// return spent outputs separated
     pub(crate) async fn get_output_ids_for_addresses(
         &self,
         options: &SyncOptions,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        sync_checkpoint: Option<&SyncCheckpoint>,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>)> {
         log::debug!("[SYNC] start get_output_ids_for_addresses");
         let address_output_ids_start_time = Instant::now();
@@ -213,7 +250,11 @@ where
             {
                 let mut tasks = Vec::new();
                 for address in addresses_chunk {
-                    let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
+                    let created_after =
+                        sync_checkpoint.and_then(|checkpoint| checkpoint.created_after(&address.address));
+                    let output_ids = self
+                        .get_output_ids_since_checkpoint(&address, options, created_after)
+                        .await?;
                     tasks.push(crate::wallet::Result::Ok((address, output_ids)));
                 }
                 results = tasks;
@@ -225,10 +266,12 @@ where
                 for address in addresses_chunk {
                     let account = self.clone();
                     let sync_options = options.clone();
+                    let created_after =
+                        sync_checkpoint.and_then(|checkpoint| checkpoint.created_after(&address.address));
                     tasks.push(async move {
-                        tokio::spawn(async move {
+                        crate::wallet::task::spawn(async move {
                             let output_ids = account
-                                .get_output_ids_for_address(address.address.inner, &sync_options)
+                                .get_output_ids_since_checkpoint(&address, &sync_options, created_after)
                                 .await?;
                             crate::wallet::Result::Ok((address, output_ids))
                         })
@@ -15,13 +15,12 @@ where
     pub(crate) async fn get_nft_output_ids_with_any_unlock_condition(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
+        created_after: Option<u32>,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         let bech32_address = bech32_address.convert()?;
+        let mut query_parameters = vec![QueryParameter::UnlockableByAddress(bech32_address)];
+        query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
 
-        Ok(self
-            .client()
-            .nft_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items)
+        Ok(self.client().nft_output_ids(query_parameters).await?.items)
     }
 }
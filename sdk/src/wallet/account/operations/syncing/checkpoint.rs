@@ -0,0 +1,116 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions, SyncOptions};
+use crate::types::block::address::Bech32Address;
+
+/// Seconds the indexer of a node may lag behind its confirmed milestone. Outputs created within this time before the
+/// checkpoint of an address are queried again, so none are missed.
+const SYNC_CHECKPOINT_OVERLAP: u32 = 60;
+
+/// The state of the last sync, so following syncs only query outputs created since then.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCheckpoint {
+    /// The index of the confirmed milestone when the last sync started.
+    pub milestone_index: u32,
+    /// The timestamp of the confirmed milestone when the last sync started.
+    pub milestone_timestamp: u32,
+    /// The milestone timestamp up to which the outputs of an address are synced.
+    pub addresses: HashMap<Bech32Address, u32>,
+    // The outputs that were synced, the checkpoint is only used if the same outputs are synced again
+    account: AccountSyncOptions,
+    alias: AliasSyncOptions,
+    nft: NftSyncOptions,
+    sync_only_most_basic_outputs: bool,
+}
+
+impl SyncCheckpoint {
+    pub(crate) fn new(options: &SyncOptions) -> Self {
+        Self {
+            milestone_index: 0,
+            milestone_timestamp: 0,
+            addresses: HashMap::new(),
+            account: options.account.clone(),
+            alias: options.alias.clone(),
+            nft: options.nft.clone(),
+            sync_only_most_basic_outputs: options.sync_only_most_basic_outputs,
+        }
+    }
+
+    /// Returns whether the checkpoint was created while syncing the same outputs.
+    pub(crate) fn matches(&self, options: &SyncOptions) -> bool {
+        self.account == options.account
+            && self.alias == options.alias
+            && self.nft == options.nft
+            && self.sync_only_most_basic_outputs == options.sync_only_most_basic_outputs
+    }
+
+    /// Returns the timestamp after which outputs of the address need to be queried, `None` if the address wasn't
+    /// synced yet.
+    pub(crate) fn created_after(&self, address: &Bech32Address) -> Option<u32> {
+        self.addresses
+            .get(address)
+            .map(|timestamp| timestamp.saturating_sub(SYNC_CHECKPOINT_OVERLAP))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{address::Hrp, rand::address::rand_ed25519_address};
+
+    fn rand_bech32_address() -> Bech32Address {
+        Bech32Address::new(Hrp::from_str_unchecked("rms"), rand_ed25519_address())
+    }
+
+    #[test]
+    fn created_after_overlaps_checkpoint() {
+        let (synced, recently_synced, not_synced) =
+            (rand_bech32_address(), rand_bech32_address(), rand_bech32_address());
+        let mut checkpoint = SyncCheckpoint::new(&SyncOptions::default());
+        checkpoint.addresses.insert(synced.clone(), 1_000);
+        checkpoint
+            .addresses
+            .insert(recently_synced.clone(), SYNC_CHECKPOINT_OVERLAP - 1);
+
+        assert_eq!(checkpoint.created_after(&synced), Some(1_000 - SYNC_CHECKPOINT_OVERLAP));
+        assert_eq!(checkpoint.created_after(&recently_synced), Some(0));
+        assert_eq!(checkpoint.created_after(&not_synced), None);
+    }
+
+    #[test]
+    fn matches_synced_outputs() {
+        let options = SyncOptions::default();
+        let checkpoint = SyncCheckpoint::new(&options);
+        assert!(checkpoint.matches(&options));
+
+        // Options that don't change the synced outputs don't invalidate the checkpoint
+        assert!(checkpoint.matches(&SyncOptions {
+            address_start_index: 10,
+            force_syncing: true,
+            sync_pending_transactions: false,
+            ..Default::default()
+        }));
+
+        let mut other_outputs = options.clone();
+        other_outputs.sync_only_most_basic_outputs = true;
+        assert!(!checkpoint.matches(&other_outputs));
+
+        let mut other_outputs = options.clone();
+        other_outputs.account.nft_outputs = !options.account.nft_outputs;
+        assert!(!checkpoint.matches(&other_outputs));
+
+        let mut other_outputs = options.clone();
+        other_outputs.alias.basic_outputs = !options.alias.basic_outputs;
+        assert!(!checkpoint.matches(&other_outputs));
+
+        let mut other_outputs = options;
+        other_outputs.nft.alias_outputs = !other_outputs.nft.alias_outputs;
+        assert!(!checkpoint.matches(&other_outputs));
+    }
+}
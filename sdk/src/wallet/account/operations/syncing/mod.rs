@@ -2,19 +2,33 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod checkpoint;
 pub(crate) mod foundries;
 pub(crate) mod options;
 pub(crate) mod outputs;
+pub(crate) mod reorgs;
+pub(crate) mod report;
 pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
-pub use self::options::SyncOptions;
+use instant::Instant;
+
+pub use self::{
+    checkpoint::SyncCheckpoint,
+    options::SyncOptions,
+    report::{SyncMetrics, SyncPhaseDurations, SyncReport},
+};
+#[cfg(feature = "events")]
+use crate::wallet::events::types::WalletEvent;
 use crate::{
     client::secret::SecretManage,
-    types::block::{
-        address::{Address, AliasAddress, NftAddress, ToBech32Ext},
-        output::{FoundryId, Output, OutputId, OutputMetadata},
+    types::{
+        api::core::response::ConfirmedMilestoneResponse,
+        block::{
+            address::{Address, AliasAddress, NftAddress, ToBech32Ext},
+            output::{FoundryId, Output, OutputId, OutputMetadata},
+        },
     },
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
@@ -46,8 +60,33 @@ where
         self.default_sync_options.lock().await.clone()
     }
 
+    /// Returns the checkpoint of the last sync, following syncs only query outputs created since then.
+    pub async fn sync_checkpoint(&self) -> Option<SyncCheckpoint> {
+        self.sync_checkpoint.lock().await.clone()
+    }
+
+    /// Replaces the sync checkpoint. If storage is enabled, it will persist during restarts.
+    async fn set_sync_checkpoint(&self, sync_checkpoint: SyncCheckpoint) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_sync_checkpoint(index, &sync_checkpoint).await?;
+        }
+
+        *self.sync_checkpoint.lock().await = Some(sync_checkpoint);
+        Ok(())
+    }
+
+    /// Returns the report of the last sync, which isn't updated if the account was synced within the minimum sync
+    /// interval.
+    pub async fn last_sync_report(&self) -> Option<SyncReport> {
+        self.last_sync_report.lock().await.clone()
+    }
+
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
+    /// A [`SyncReport`] of the sync is kept and, with the `events` feature, emitted as event.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
         let options = match options {
             Some(opt) => opt,
@@ -55,7 +94,7 @@ where
         };
 
         log::debug!("[SYNC] start syncing with {:?}", options);
-        let syc_start_time = instant::Instant::now();
+        let syc_start_time = Instant::now();
 
         // Prevent syncing the account multiple times simultaneously
         let time_now = crate::utils::unix_timestamp_now().as_millis();
@@ -71,45 +110,120 @@ where
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        let account_index = *self.details().await.index();
+        let requests_before = self.client().request_count().await;
+        let mut report = SyncReport::default();
+        self.sync_counters.reset();
+
+        if let Err(error) = self.sync_with_report(&options, &mut report).await {
+            self.wallet
+                .sync_metrics
+                .write()
+                .await
+                .entry(account_index)
+                .or_default()
+                .record_failure();
+            return Err(error);
+        }
+
+        let balance = self.balance().await?;
+        // Update last_synced mutex
+        let time_now = crate::utils::unix_timestamp_now().as_millis();
+        *last_synced = time_now;
+        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
+
+        report.requests = self.client().request_count().await.saturating_sub(requests_before);
+        self.sync_counters.take_into(&mut report);
+        report.duration = syc_start_time.elapsed();
+        log::debug!("[SYNC] {report:?}");
+        self.wallet
+            .sync_metrics
+            .write()
+            .await
+            .entry(account_index)
+            .or_default()
+            .record(&report);
+        *self.last_sync_report.lock().await = Some(report.clone());
+        #[cfg(feature = "events")]
+        self.emit(account_index, WalletEvent::SyncReport(Box::new(report)))
+            .await;
+
+        Ok(balance)
+    }
+
+    async fn sync_with_report(&self, options: &SyncOptions, report: &mut SyncReport) -> crate::wallet::Result<()> {
+        // Requested once per sync, a milestone that is a few seconds old only makes the checkpoint more conservative
+        let confirmed_milestone = self.client().get_info().await?.node_info.status.confirmed_milestone;
+
+        // Detect reorgs before syncing the outputs, which would mark the outputs of rolled back transactions as spent
+        if options.sync_reorgs {
+            let phase_start_time = Instant::now();
+            report.reorgs += self.detect_reorgs(confirmed_milestone.index).await?.len();
+            report.phases.reorgs += phase_start_time.elapsed();
+        }
+
+        self.sync_internal(options, &confirmed_milestone, report).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
+            let phase_start_time = Instant::now();
             let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
+            report.phases.pending_transactions += phase_start_time.elapsed();
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(options, &confirmed_milestone, report).await?;
             }
+            // Submit the transactions that couldn't be sent before, e.g. while being offline
+            self.submit_outbox().await?;
         };
 
-        let balance = self.balance().await?;
-        // Update last_synced mutex
-        let time_now = crate::utils::unix_timestamp_now().as_millis();
-        *last_synced = time_now;
-        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
-        Ok(balance)
+        Ok(())
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    async fn sync_internal(
+        &self,
+        options: &SyncOptions,
+        confirmed_milestone: &ConfirmedMilestoneResponse,
+        report: &mut SyncReport,
+    ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
 
+        // Only use the checkpoint if the same outputs were synced and the node isn't behind it, e.g. after switching
+        // to another node
+        let sync_checkpoint = if options.force_full_sync {
+            None
+        } else {
+            self.sync_checkpoint().await.filter(|checkpoint| {
+                checkpoint.matches(options) && checkpoint.milestone_index <= confirmed_milestone.index
+            })
+        };
+        log::debug!("[SYNC] sync checkpoint {sync_checkpoint:?}");
+
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
         log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
+        report.addresses_scanned += addresses_to_sync.len();
+        let synced_addresses = addresses_to_sync.iter().map(|a| a.address).collect::<Vec<_>>();
 
+        let phase_start_time = Instant::now();
         let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
             Vec<OutputId>,
             Vec<AddressWithUnspentOutputs>,
             Vec<OutputData>,
-        ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
+        ) = self
+            .request_outputs_recursively(addresses_to_sync, options, sync_checkpoint.as_ref())
+            .await?;
+        report.phases.outputs += phase_start_time.elapsed();
 
         // Request possible spent outputs
         log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
+        let phase_start_time = Instant::now();
         let spent_or_unsynced_output_metadata_responses = self
             .client()
             .get_outputs_metadata_ignore_errors(&spent_or_not_synced_output_ids)
             .await?;
+        report.phases.spent_outputs += phase_start_time.elapsed();
 
         // Add the output response to the output ids, the output response is optional, because an output could be
         // pruned and then we can't get the metadata
@@ -126,7 +240,9 @@ where
                 .map(|output| *output.output_id.transaction_id())
                 .collect();
             // Request and store transaction payload for newly received unspent outputs
+            let phase_start_time = Instant::now();
             self.request_incoming_transaction_data(transaction_ids).await?;
+            report.phases.incoming_transactions += phase_start_time.elapsed();
         }
 
         if options.sync_native_token_foundries {
@@ -141,17 +257,34 @@ where
                 .collect::<HashSet<_>>();
 
             // Request and store foundry outputs
+            let phase_start_time = Instant::now();
             self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
+            report.phases.foundries += phase_start_time.elapsed();
         }
 
         // Updates account with balances, output ids, outputs
+        let phase_start_time = Instant::now();
         self.update_account(
             addresses_with_unspent_outputs,
             outputs_data,
             spent_or_unsynced_output_metadata_map,
             options,
         )
-        .await
+        .await?;
+        report.phases.account_update += phase_start_time.elapsed();
+
+        // Without a timestamp the indexer can't be queried for outputs created after the checkpoint
+        if let Some(milestone_timestamp) = confirmed_milestone.timestamp {
+            let mut sync_checkpoint = sync_checkpoint.unwrap_or_else(|| SyncCheckpoint::new(options));
+            sync_checkpoint.milestone_index = confirmed_milestone.index;
+            sync_checkpoint.milestone_timestamp = milestone_timestamp;
+            for address in synced_addresses {
+                sync_checkpoint.addresses.insert(address, milestone_timestamp);
+            }
+            self.set_sync_checkpoint(sync_checkpoint).await?;
+        }
+
+        Ok(())
     }
 
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
@@ -161,6 +294,7 @@ where
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
         options: &SyncOptions,
+        sync_checkpoint: Option<&SyncCheckpoint>,
     ) -> crate::wallet::Result<(Vec<OutputId>, Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         // Cache the alias and nft address with the related ed2559 address, so we can update the account address with
         // the new output ids
@@ -172,7 +306,7 @@ where
             let new_outputs_data = if new_alias_and_nft_addresses.is_empty() {
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_output_ids, spent_or_not_synced_output_ids_inner) = self
-                    .get_output_ids_for_addresses(options, addresses_to_sync.clone())
+                    .get_output_ids_for_addresses(options, addresses_to_sync.clone(), sync_checkpoint)
                     .await?;
                 spent_or_not_synced_output_ids = spent_or_not_synced_output_ids_inner;
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
@@ -186,7 +320,9 @@ where
                 let bech32_hrp = self.client().get_bech32_hrp().await?;
                 let mut new_outputs_data = Vec::new();
                 for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses {
-                    let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
+                    let output_ids = self
+                        .get_output_ids_for_address(alias_or_nft_address, options, None)
+                        .await?;
 
                     // Update address with unspent outputs
                     let address_with_unspent_outputs = addresses_with_unspent_outputs
@@ -195,7 +331,12 @@ where
                         .ok_or_else(|| {
                             crate::wallet::Error::AddressNotFoundInAccount(ed25519_address.to_bech32(bech32_hrp))
                         })?;
-                    address_with_unspent_outputs.output_ids.extend(output_ids.clone());
+                    // Known outputs can already be there when syncing since a checkpoint
+                    for output_id in &output_ids {
+                        if !address_with_unspent_outputs.output_ids.contains(output_id) {
+                            address_with_unspent_outputs.output_ids.push(*output_id);
+                        }
+                    }
 
                     let new_outputs_data_inner = self.get_outputs(output_ids).await?;
 
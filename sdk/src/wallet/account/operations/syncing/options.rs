@@ -11,6 +11,7 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_SYNC_REORGS: bool = true;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -33,6 +34,10 @@ pub struct SyncOptions {
     /// When this to true, we will sync anyways, even if it's called 0ms after the las sync finished.
     #[serde(default)]
     pub force_syncing: bool,
+    /// Ignore the sync checkpoint and query all outputs of the addresses again, instead of only the ones created since
+    /// the last sync. The checkpoint is replaced afterwards.
+    #[serde(default)]
+    pub force_full_sync: bool,
     /// Try to sync transactions from incoming outputs with their inputs. Some data may not be obtained if it has been
     /// pruned.
     #[serde(default = "default_sync_incoming_transactions")]
@@ -40,6 +45,10 @@ pub struct SyncOptions {
     /// Checks pending transactions and promotes/reattaches them if necessary.
     #[serde(default = "default_sync_pending_transactions")]
     pub sync_pending_transactions: bool,
+    /// Checks if transactions of recently received outputs are still included, and rolls back the ones that aren't,
+    /// e.g. after switching to a node with another history.
+    #[serde(default = "default_sync_reorgs")]
+    pub sync_reorgs: bool,
     /// Specifies what outputs should be synced for the ed25519 addresses from the account.
     #[serde(default)]
     pub account: AccountSyncOptions,
@@ -82,6 +91,10 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_sync_reorgs() -> bool {
+    DEFAULT_SYNC_REORGS
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -90,12 +103,14 @@ impl Default for SyncOptions {
             address_start_index_internal: default_address_start_index(),
             sync_incoming_transactions: default_sync_incoming_transactions(),
             sync_pending_transactions: default_sync_pending_transactions(),
+            sync_reorgs: default_sync_reorgs(),
             account: AccountSyncOptions::default(),
             alias: AliasSyncOptions::default(),
             nft: NftSyncOptions::default(),
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            force_full_sync: false,
         }
     }
 }
@@ -18,7 +18,11 @@ use crate::{
         },
     },
     wallet::{
-        account::{build_transaction_from_payload_and_inputs, types::OutputData, Account, AddressWithUnspentOutputs},
+        account::{
+            build_transaction_from_payload_and_inputs,
+            types::{OutputData, TransactionOwnership},
+            Account, AddressWithUnspentOutputs,
+        },
         task,
     },
 };
@@ -50,7 +54,7 @@ where
 
                 // BIP 44 (HD wallets) and 4218 is the registered index for IOTA https://github.com/satoshilabs/slips/blob/master/slip-0044.md
                 let chain = Bip44::new(account_details.coin_type)
-                    .with_account(account_details.index)
+                    .with_account(account_details.bip44_account_index)
                     .with_change(associated_address.internal as _)
                     .with_address_index(associated_address.key_index);
 
@@ -102,6 +106,7 @@ where
         }
 
         drop(account_details);
+        self.sync_counters.add_outputs(outputs.len(), unknown_outputs.len());
 
         if !unknown_outputs.is_empty() {
             outputs.extend(self.client().get_outputs(&unknown_outputs).await?);
@@ -179,7 +184,8 @@ where
         // Update account with new transactions
         let mut account_details = self.details_mut().await;
         for (transaction_id, txn) in results.into_iter().flatten() {
-            if let Some(transaction) = txn {
+            if let Some(mut transaction) = txn {
+                transaction.ownership = Some(TransactionOwnership::new(&account_details, &transaction.payload));
                 account_details
                     .incoming_transactions
                     .insert(transaction_id, transaction);
@@ -0,0 +1,407 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+#[cfg(feature = "events")]
+use crate::wallet::{
+    account::types::OutputDataDto,
+    events::types::{ReorgEvent, WalletEvent},
+};
+use crate::{
+    client::secret::SecretManage,
+    types::{
+        api::core::response::LedgerInclusionState,
+        block::{
+            input::Input,
+            output::{OutputId, OutputMetadata},
+            payload::transaction::{TransactionEssence, TransactionId},
+        },
+    },
+    wallet::account::{
+        constants::REORG_DETECTION_MILESTONES,
+        types::{AddressWithUnspentOutputs, InclusionState, OutputData},
+        Account, AccountDetails,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Detects transactions whose outputs the account holds, but that the node doesn't know as included anymore, e.g.
+    /// after switching to a node with another history. Their outputs are removed and their inputs are unspent again,
+    /// so the balance doesn't keep the stale state. Returns the IDs of the transactions that were rolled back.
+    ///
+    /// Only outputs booked in the latest [`REORG_DETECTION_MILESTONES`] milestones are checked, older ones could have
+    /// been pruned by the node. Needs to run before the outputs are synced, which would mark the outputs as spent.
+    pub(crate) async fn detect_reorgs(
+        &self,
+        confirmed_milestone_index: u32,
+    ) -> crate::wallet::Result<Vec<TransactionId>> {
+        log::debug!("[SYNC] detect reorgs");
+        let network_id = self.client().get_network_id().await?;
+
+        let recent_output_ids = self
+            .details()
+            .await
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                output_data.network_id == network_id
+                    && !output_data.output_id.transaction_id().is_null()
+                    && output_data
+                        .metadata
+                        .milestone_index_booked()
+                        .saturating_add(REORG_DETECTION_MILESTONES)
+                        >= confirmed_milestone_index
+            })
+            .map(|output_data| output_data.output_id)
+            .collect::<Vec<_>>();
+        if recent_output_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Outputs the node doesn't know can come from a transaction that isn't part of its ledger
+        let known_output_ids = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&recent_output_ids)
+            .await?
+            .into_iter()
+            .map(|metadata| *metadata.output_id())
+            .collect::<HashSet<_>>();
+        let suspicious_transaction_ids = recent_output_ids
+            .iter()
+            .filter(|output_id| !known_output_ids.contains(output_id))
+            .map(|output_id| *output_id.transaction_id())
+            .collect::<HashSet<_>>();
+
+        let mut reorged_transaction_ids = Vec::new();
+        for transaction_id in suspicious_transaction_ids {
+            match self.client().get_included_block_metadata(&transaction_id).await {
+                // The indexer of the node can lag behind
+                Ok(metadata) if metadata.ledger_inclusion_state == Some(LedgerInclusionState::Included) => {}
+                Ok(_) | Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                    log::debug!("[SYNC] transaction {transaction_id} isn't included anymore");
+                    reorged_transaction_ids.push(transaction_id);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        if reorged_transaction_ids.is_empty() {
+            return Ok(reorged_transaction_ids);
+        }
+
+        // The inputs that are unspent according to the node can be used again
+        let input_ids = {
+            let account_details = self.details().await;
+            reorged_transaction_ids
+                .iter()
+                .flat_map(|transaction_id| transaction_input_ids(&account_details, transaction_id))
+                .filter(|output_id| account_details.outputs.contains_key(output_id))
+                .collect::<Vec<_>>()
+        };
+        let unspent_inputs = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&input_ids)
+            .await?
+            .into_iter()
+            .filter(|metadata| !metadata.is_spent())
+            .map(|metadata| (*metadata.output_id(), metadata))
+            .collect::<HashMap<_, _>>();
+
+        let mut account_details = self.details_mut().await;
+        for transaction_id in &reorged_transaction_ids {
+            #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+            let (removed_outputs, restored_outputs) =
+                roll_back_transaction(&mut account_details, transaction_id, &unspent_inputs);
+            #[cfg(feature = "events")]
+            self.emit(
+                account_details.index,
+                WalletEvent::Reorg(Box::new(ReorgEvent {
+                    transaction_id: *transaction_id,
+                    removed_outputs: removed_outputs.iter().map(OutputDataDto::from).collect(),
+                    restored_outputs: restored_outputs.iter().map(OutputDataDto::from).collect(),
+                })),
+            )
+            .await;
+        }
+
+        #[cfg(feature = "storage")]
+        {
+            log::debug!(
+                "[SYNC] storing account {} with rolled back transactions",
+                account_details.alias()
+            );
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(reorged_transaction_ids)
+    }
+}
+
+fn transaction_input_ids(account_details: &AccountDetails, transaction_id: &TransactionId) -> Vec<OutputId> {
+    let Some(transaction) = account_details
+        .transactions
+        .get(transaction_id)
+        .or_else(|| account_details.incoming_transactions.get(transaction_id))
+    else {
+        return Vec::new();
+    };
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence
+        .inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::Utxo(input) => Some(*input.output_id()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Removes the outputs created by the transaction and makes its inputs unspent again, if the node reports them as
+/// unspent. Returns the removed and the restored outputs.
+fn roll_back_transaction(
+    account_details: &mut AccountDetails,
+    transaction_id: &TransactionId,
+    unspent_inputs: &HashMap<OutputId, OutputMetadata>,
+) -> (Vec<OutputData>, Vec<OutputData>) {
+    let removed_output_ids = account_details
+        .outputs
+        .keys()
+        .filter(|output_id| output_id.transaction_id() == transaction_id)
+        .copied()
+        .collect::<Vec<_>>();
+    let mut removed_outputs = Vec::new();
+    for output_id in &removed_output_ids {
+        if let Some(output_data) = account_details.outputs.remove(output_id) {
+            removed_outputs.push(output_data);
+        }
+        account_details.unspent_outputs.remove(output_id);
+        account_details.locked_outputs.remove(output_id);
+    }
+    for address in &mut account_details.addresses_with_unspent_outputs {
+        address
+            .output_ids
+            .retain(|output_id| !removed_output_ids.contains(output_id));
+    }
+    account_details
+        .addresses_with_unspent_outputs
+        .retain(|address| !address.output_ids.is_empty());
+
+    let mut restored_outputs = Vec::new();
+    for input_id in transaction_input_ids(account_details, transaction_id) {
+        let (Some(metadata), Some(output_data)) = (
+            unspent_inputs.get(&input_id),
+            account_details.outputs.get_mut(&input_id),
+        ) else {
+            continue;
+        };
+        output_data.metadata = metadata.clone();
+        output_data.is_spent = false;
+        let output_data = output_data.clone();
+
+        match account_details
+            .addresses_with_unspent_outputs
+            .iter_mut()
+            .find(|address| address.address.inner == output_data.address)
+        {
+            Some(address) => {
+                if !address.output_ids.contains(&input_id) {
+                    address.output_ids.push(input_id);
+                }
+            }
+            None => {
+                if let Some(address) = account_details
+                    .public_addresses
+                    .iter()
+                    .chain(&account_details.internal_addresses)
+                    .find(|address| address.address.inner == output_data.address)
+                {
+                    let address_with_unspent_outputs = AddressWithUnspentOutputs {
+                        address: address.address,
+                        key_index: address.key_index,
+                        internal: address.internal,
+                        output_ids: vec![input_id],
+                    };
+                    account_details
+                        .addresses_with_unspent_outputs
+                        .push(address_with_unspent_outputs);
+                }
+            }
+        }
+        account_details.locked_outputs.remove(&input_id);
+        account_details.unspent_outputs.insert(input_id, output_data.clone());
+        restored_outputs.push(output_data);
+    }
+
+    // The transaction can be an outgoing or an incoming one
+    for transactions in [
+        &mut account_details.transactions,
+        &mut account_details.incoming_transactions,
+    ] {
+        if let Some(transaction) = transactions.get_mut(transaction_id) {
+            transaction.inclusion_state = InclusionState::Conflicting;
+        }
+    }
+    account_details.pending_transactions.remove(transaction_id);
+
+    (removed_outputs, restored_outputs)
+}
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::Address,
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+            protocol::ProtocolParameters,
+            BlockId,
+        },
+        wallet::account::types::Transaction,
+    };
+
+    fn metadata(output_id: OutputId, is_spent: bool) -> OutputMetadata {
+        OutputMetadata::new(BlockId::new([0; 32]), output_id, is_spent, None, None, None, 1, 0, 0)
+    }
+
+    fn output_data(output_id: OutputId, address: Address, is_spent: bool) -> OutputData {
+        OutputData {
+            output_id,
+            metadata: metadata(output_id, is_spent),
+            output: BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(ProtocolParameters::default().token_supply())
+                .unwrap(),
+            is_spent,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    // An account with a transaction that spent `input_id` and created `created_id`, both owned by the account
+    fn account_with_transaction(
+        transaction_id: TransactionId,
+        input_id: OutputId,
+        created_id: OutputId,
+        incoming: bool,
+    ) -> AccountDetails {
+        let mut account_details = AccountDetails::mock();
+        let address = account_details.public_addresses[0].address;
+
+        account_details
+            .outputs
+            .insert(input_id, output_data(input_id, *address.inner(), true));
+        let created = output_data(created_id, *address.inner(), false);
+        account_details.outputs.insert(created_id, created.clone());
+        account_details.unspent_outputs.insert(created_id, created);
+        account_details.locked_outputs.insert(created_id);
+        account_details.addresses_with_unspent_outputs = vec![AddressWithUnspentOutputs {
+            address,
+            key_index: 0,
+            internal: false,
+            output_ids: vec![created_id],
+        }];
+
+        let mut transaction = Transaction::mock(transaction_id, &[input_id]);
+        if incoming {
+            transaction.incoming = true;
+            account_details
+                .incoming_transactions
+                .insert(transaction_id, transaction);
+        } else {
+            account_details.transactions.insert(transaction_id, transaction);
+            account_details.pending_transactions.insert(transaction_id);
+        }
+        account_details
+    }
+
+    #[test]
+    fn roll_back_restores_unspent_inputs() {
+        let transaction_id = TransactionId::new([2; 32]);
+        let input_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let created_id = OutputId::new(transaction_id, 0).unwrap();
+        let mut account_details = account_with_transaction(transaction_id, input_id, created_id, false);
+        let unspent_inputs = HashMap::from([(input_id, metadata(input_id, false))]);
+
+        let (removed_outputs, restored_outputs) =
+            roll_back_transaction(&mut account_details, &transaction_id, &unspent_inputs);
+
+        assert_eq!(
+            removed_outputs.iter().map(|o| o.output_id).collect::<Vec<_>>(),
+            [created_id]
+        );
+        assert_eq!(
+            restored_outputs.iter().map(|o| o.output_id).collect::<Vec<_>>(),
+            [input_id]
+        );
+        assert!(!restored_outputs[0].is_spent);
+        assert!(!restored_outputs[0].metadata.is_spent());
+
+        assert!(!account_details.outputs.contains_key(&created_id));
+        assert_eq!(
+            account_details.unspent_outputs.keys().copied().collect::<Vec<_>>(),
+            [input_id]
+        );
+        assert!(!account_details.outputs[&input_id].is_spent);
+        assert!(account_details.locked_outputs.is_empty());
+        // The created output is replaced by the restored input
+        assert_eq!(account_details.addresses_with_unspent_outputs.len(), 1);
+        assert_eq!(account_details.addresses_with_unspent_outputs[0].output_ids, [input_id]);
+
+        assert_eq!(
+            account_details.transactions[&transaction_id].inclusion_state,
+            InclusionState::Conflicting
+        );
+        assert!(account_details.pending_transactions.is_empty());
+    }
+
+    #[test]
+    fn roll_back_keeps_spent_inputs() {
+        let transaction_id = TransactionId::new([2; 32]);
+        let input_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let created_id = OutputId::new(transaction_id, 0).unwrap();
+        let mut account_details = account_with_transaction(transaction_id, input_id, created_id, false);
+
+        // The node reports the input as spent, e.g. by another transaction
+        let (removed_outputs, restored_outputs) =
+            roll_back_transaction(&mut account_details, &transaction_id, &HashMap::new());
+
+        assert_eq!(removed_outputs.len(), 1);
+        assert!(restored_outputs.is_empty());
+        assert!(account_details.outputs[&input_id].is_spent);
+        assert!(account_details.unspent_outputs.is_empty());
+        // Addresses without unspent outputs are removed
+        assert!(account_details.addresses_with_unspent_outputs.is_empty());
+    }
+
+    #[test]
+    fn roll_back_incoming_transaction() {
+        let transaction_id = TransactionId::new([2; 32]);
+        let input_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let created_id = OutputId::new(transaction_id, 0).unwrap();
+        let mut account_details = account_with_transaction(transaction_id, input_id, created_id, true);
+        // The inputs of incoming transactions usually aren't owned by the account
+        account_details.outputs.remove(&input_id);
+        let unspent_inputs = HashMap::from([(input_id, metadata(input_id, false))]);
+
+        let (removed_outputs, restored_outputs) =
+            roll_back_transaction(&mut account_details, &transaction_id, &unspent_inputs);
+
+        assert_eq!(
+            removed_outputs.iter().map(|o| o.output_id).collect::<Vec<_>>(),
+            [created_id]
+        );
+        assert!(restored_outputs.is_empty());
+        assert!(account_details.unspent_outputs.is_empty());
+        assert!(account_details.addresses_with_unspent_outputs.is_empty());
+        assert_eq!(
+            account_details.incoming_transactions[&transaction_id].inclusion_state,
+            InclusionState::Conflicting
+        );
+    }
+}
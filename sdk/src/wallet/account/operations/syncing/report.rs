@@ -0,0 +1,144 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// What a sync of an account did and how long it took, to find out why syncing is slow.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncReport {
+    /// The number of addresses of which the outputs were queried.
+    pub addresses_scanned: usize,
+    /// The number of requests sent to nodes.
+    pub requests: usize,
+    /// The number of outputs that were already known by the account and not requested again.
+    pub cache_hits: usize,
+    /// The number of outputs that were requested from nodes.
+    pub outputs_discovered: usize,
+    /// The number of transactions that were rolled back, because they weren't included anymore.
+    #[serde(default)]
+    pub reorgs: usize,
+    /// The time spent in each phase.
+    pub phases: SyncPhaseDurations,
+    /// The total time of the sync.
+    pub duration: Duration,
+}
+
+/// The time spent in each phase of a sync.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPhaseDurations {
+    /// Querying the output IDs of the addresses and requesting the outputs, recursively for alias and NFT addresses.
+    pub outputs: Duration,
+    /// Requesting the metadata of outputs that are spent or weren't synced.
+    pub spent_outputs: Duration,
+    /// Requesting the transactions that created new outputs.
+    pub incoming_transactions: Duration,
+    /// Requesting the foundries of native tokens.
+    pub foundries: Duration,
+    /// Updating and storing the account.
+    pub account_update: Duration,
+    /// Checking the inclusion state of pending transactions.
+    pub pending_transactions: Duration,
+    /// Checking if the transactions of recently received outputs are still included.
+    #[serde(default)]
+    pub reorgs: Duration,
+}
+
+impl SyncPhaseDurations {
+    /// Returns the name and duration of each phase.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Duration)> {
+        [
+            ("outputs", self.outputs),
+            ("spent_outputs", self.spent_outputs),
+            ("incoming_transactions", self.incoming_transactions),
+            ("foundries", self.foundries),
+            ("account_update", self.account_update),
+            ("pending_transactions", self.pending_transactions),
+            ("reorgs", self.reorgs),
+        ]
+        .into_iter()
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.outputs += other.outputs;
+        self.spent_outputs += other.spent_outputs;
+        self.incoming_transactions += other.incoming_transactions;
+        self.foundries += other.foundries;
+        self.account_update += other.account_update;
+        self.pending_transactions += other.pending_transactions;
+        self.reorgs += other.reorgs;
+    }
+}
+
+/// The sync reports of an account, aggregated since the wallet was built.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncMetrics {
+    /// The number of syncs.
+    pub syncs: u64,
+    /// The number of syncs that failed.
+    pub failed_syncs: u64,
+    /// The number of scanned addresses.
+    pub addresses_scanned: u64,
+    /// The number of requests sent to nodes.
+    pub requests: u64,
+    /// The number of outputs that were already known by the account and not requested again.
+    pub cache_hits: u64,
+    /// The number of outputs that were requested from nodes.
+    pub outputs_discovered: u64,
+    /// The number of transactions that were rolled back, because they weren't included anymore.
+    #[serde(default)]
+    pub reorgs: u64,
+    /// The time spent in each phase.
+    pub phases: SyncPhaseDurations,
+    /// The total time of all syncs.
+    pub duration: Duration,
+}
+
+impl SyncMetrics {
+    pub(crate) fn record(&mut self, report: &SyncReport) {
+        self.syncs += 1;
+        self.addresses_scanned += report.addresses_scanned as u64;
+        self.requests += report.requests as u64;
+        self.cache_hits += report.cache_hits as u64;
+        self.outputs_discovered += report.outputs_discovered as u64;
+        self.reorgs += report.reorgs as u64;
+        self.phases.add(&report.phases);
+        self.duration += report.duration;
+    }
+
+    pub(crate) fn record_failure(&mut self) {
+        self.failed_syncs += 1;
+    }
+}
+
+/// Counters updated by the sync operations which run in parallel tasks.
+#[derive(Debug, Default)]
+pub(crate) struct SyncCounters {
+    cache_hits: AtomicUsize,
+    outputs_discovered: AtomicUsize,
+}
+
+impl SyncCounters {
+    pub(crate) fn add_outputs(&self, cache_hits: usize, outputs_discovered: usize) {
+        self.cache_hits.fetch_add(cache_hits, Ordering::Relaxed);
+        self.outputs_discovered.fetch_add(outputs_discovered, Ordering::Relaxed);
+    }
+
+    pub(crate) fn reset(&self) {
+        self.cache_hits.store(0, Ordering::Relaxed);
+        self.outputs_discovered.store(0, Ordering::Relaxed);
+    }
+
+    /// Moves the counted values into the report and resets the counters.
+    pub(crate) fn take_into(&self, report: &mut SyncReport) {
+        report.cache_hits += self.cache_hits.swap(0, Ordering::Relaxed);
+        report.outputs_discovered += self.outputs_discovered.swap(0, Ordering::Relaxed);
+    }
+}
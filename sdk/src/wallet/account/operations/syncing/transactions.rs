@@ -9,7 +9,7 @@ use crate::{
     },
     utils::unix_timestamp_now,
     wallet::account::{
-        types::{InclusionState, Transaction},
+        types::{AttachmentKind, InclusionState, Transaction, TransactionAttachment},
         Account, AccountDetails,
     },
 };
@@ -99,6 +99,8 @@ where
                 }
             }
 
+            // Transactions that weren't submitted yet are in the outbox, which is submitted after the pending
+            // transactions are synced
             if let Some(block_id) = transaction.block_id {
                 match self.client().get_block_metadata(&block_id).await {
                     Ok(metadata) => {
@@ -189,35 +191,43 @@ where
                     }
                     Err(e) => return Err(e.into()),
                 }
-            } else {
-                // transaction wasn't submitted yet, so we have to send it again
-                // no need to reattach if one input got spent
-                if input_got_spent {
-                } else {
-                    // only reattach if inputs are still unspent
-                    transactions_to_reattach.push(transaction);
-                }
             }
         }
         drop(account_details);
 
+        let mut reattach_error = None;
         for mut transaction in transactions_to_reattach {
             log::debug!("[SYNC] reattach transaction");
-            let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
-            transaction.block_id.replace(reattached_block);
-            updated_transactions.push(transaction);
+            let result = self.submit_transaction_payload(transaction.payload.clone()).await;
+            transaction.add_attachment(TransactionAttachment::new(AttachmentKind::Reattachment, &result));
+            match result {
+                Ok(reattached_block) => {
+                    transaction.block_id.replace(reattached_block);
+                    updated_transactions.push(transaction);
+                }
+                Err(err) => {
+                    // Store the failed attempt before returning the error
+                    updated_transactions.push(transaction);
+                    reattach_error.replace(err);
+                    break;
+                }
+            }
         }
 
         // updates account with balances, output ids, outputs
         self.update_account_with_transactions(updated_transactions, spent_output_ids, output_ids_to_unlock)
             .await?;
 
+        if let Some(err) = reattach_error {
+            return Err(err);
+        }
+
         Ok(confirmed_unknown_output)
     }
 }
 
 // Set the outputs as spent so they will not be used as input again
-fn updated_transaction_and_outputs(
+pub(crate) fn updated_transaction_and_outputs(
     mut transaction: Transaction,
     block_id: Option<BlockId>,
     inclusion_state: InclusionState,
@@ -269,3 +279,163 @@ fn process_transaction_with_unknown_state(
     updated_transactions.push(transaction);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn failed_reattachment_is_stored() {
+        let storage_path = "test-storage/sync_failed_reattachment";
+        let account = Account::mock(storage_path).await;
+        let transaction_id = account.add_mock_pending_transaction().await;
+        let block_id = BlockId::new([1; 32]);
+        account
+            .details_mut()
+            .await
+            .transactions
+            .get_mut(&transaction_id)
+            .unwrap()
+            .block_id
+            .replace(block_id);
+
+        // The node doesn't know the block and the inputs aren't known as spent, but the node rejects the reattachment
+        assert!(account.sync_pending_transactions().await.is_err());
+
+        let account_details = account.details().await;
+        assert!(account_details.pending_transactions.contains(&transaction_id));
+        let transaction = &account_details.transactions[&transaction_id];
+        assert_eq!(transaction.block_id, Some(block_id));
+        let history = transaction.attachment_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].kind, AttachmentKind::Reattachment);
+        assert!(history[0].error.is_some());
+        drop(account_details);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[tokio::test]
+    async fn unsubmitted_transactions_are_left_to_the_outbox() {
+        let storage_path = "test-storage/sync_unsubmitted_transaction";
+        let account = Account::mock(storage_path).await;
+        let transaction_id = account.add_mock_pending_transaction().await;
+
+        assert!(!account.sync_pending_transactions().await.unwrap());
+
+        let account_details = account.details().await;
+        assert!(account_details.pending_transactions.contains(&transaction_id));
+        assert!(
+            account_details.transactions[&transaction_id]
+                .attachment_history()
+                .is_empty()
+        );
+        drop(account_details);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+
+    #[cfg(feature = "storage")]
+    mod unknown_state {
+        use super::*;
+        use crate::{
+            types::block::{
+                output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata},
+                payload::transaction::TransactionId,
+                protocol::ProtocolParameters,
+            },
+            wallet::account::types::OutputData,
+        };
+
+        // An account that knows the inputs with the given spent states
+        fn account_with_inputs(inputs: &[(OutputId, bool)]) -> AccountDetails {
+            let mut account_details = AccountDetails::mock();
+            let address = *account_details.public_addresses[0].address.inner();
+            for (output_id, is_spent) in inputs {
+                let output_data = OutputData {
+                    output_id: *output_id,
+                    metadata: OutputMetadata::new(
+                        BlockId::new([0; 32]),
+                        *output_id,
+                        *is_spent,
+                        None,
+                        None,
+                        None,
+                        1,
+                        0,
+                        0,
+                    ),
+                    output: BasicOutputBuilder::new_with_amount(1_000_000)
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .finish_output(ProtocolParameters::default().token_supply())
+                        .unwrap(),
+                    is_spent: *is_spent,
+                    address,
+                    network_id: 0,
+                    remainder: false,
+                    chain: None,
+                };
+                account_details.outputs.insert(*output_id, output_data);
+                account_details.locked_outputs.insert(*output_id);
+            }
+            account_details
+        }
+
+        fn process(account_details: &AccountDetails, inputs: &[OutputId]) -> (InclusionState, Vec<OutputId>) {
+            let transaction = Transaction::mock(TransactionId::new([2; 32]), inputs);
+            let mut updated_transactions = Vec::new();
+            let mut output_ids_to_unlock = Vec::new();
+            process_transaction_with_unknown_state(
+                account_details,
+                transaction,
+                &mut updated_transactions,
+                &mut output_ids_to_unlock,
+            )
+            .unwrap();
+            assert_eq!(updated_transactions.len(), 1);
+            (updated_transactions[0].inclusion_state, output_ids_to_unlock)
+        }
+
+        #[test]
+        fn all_inputs_spent() {
+            let inputs = [
+                OutputId::new(TransactionId::new([1; 32]), 0).unwrap(),
+                OutputId::new(TransactionId::new([1; 32]), 1).unwrap(),
+            ];
+            let account_details = account_with_inputs(&[(inputs[0], true), (inputs[1], true)]);
+
+            // The transaction or a conflicting one could have spent them, which can't be known after pruning
+            assert_eq!(
+                process(&account_details, &inputs),
+                (InclusionState::UnknownPruned, Vec::new())
+            );
+        }
+
+        #[test]
+        fn some_inputs_unspent() {
+            let inputs = [
+                OutputId::new(TransactionId::new([1; 32]), 0).unwrap(),
+                OutputId::new(TransactionId::new([1; 32]), 1).unwrap(),
+            ];
+            let account_details = account_with_inputs(&[(inputs[0], true), (inputs[1], false)]);
+
+            // The transaction can't have spent only some of its inputs, the unspent ones are available again
+            assert_eq!(
+                process(&account_details, &inputs),
+                (InclusionState::Conflicting, vec![inputs[1]])
+            );
+        }
+
+        #[test]
+        fn unknown_inputs() {
+            let known_input = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+            let unknown_input = OutputId::new(TransactionId::new([1; 32]), 1).unwrap();
+            let account_details = account_with_inputs(&[(known_input, true)]);
+
+            assert_eq!(
+                process(&account_details, &[known_input, unknown_input]),
+                (InclusionState::Conflicting, Vec::new())
+            );
+        }
+    }
+}
@@ -23,7 +23,8 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Builds the transaction essence from the selected in and outputs.
+    /// Builds the transaction essence from the selected in and outputs, calling the block builder middleware of the
+    /// client before and after.
     pub(crate) async fn build_transaction_essence(
         &self,
         selected_transaction_data: Selected,
@@ -32,6 +33,9 @@ where
         log::debug!("[TRANSACTION] build_transaction");
 
         let build_transaction_essence_start_time = Instant::now();
+        self.client()
+            .run_after_input_selection(&selected_transaction_data)
+            .await?;
         let protocol_parameters = self.client().get_protocol_parameters().await?;
 
         let mut inputs_for_essence: Vec<Input> = Vec::new();
@@ -62,15 +66,16 @@ where
 
         let essence = essence_builder.finish_with_params(protocol_parameters)?;
 
-        validate_regular_transaction_essence_length(&essence)?;
-
-        let essence = TransactionEssence::Regular(essence);
-
-        let prepared_transaction_data = PreparedTransactionData {
-            essence,
+        let mut prepared_transaction_data = PreparedTransactionData {
+            essence: TransactionEssence::Regular(essence),
             inputs_data: inputs_for_signing,
             remainder: selected_transaction_data.remainder,
         };
+        self.client()
+            .run_after_essence_build(&mut prepared_transaction_data)
+            .await?;
+
+        validate_regular_transaction_essence_length(prepared_transaction_data.essence.as_regular())?;
 
         log::debug!(
             "[TRANSACTION] finished build_transaction in {:.2?}",
@@ -30,6 +30,7 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self
             .prepare_melt_native_token(token_id, melt_amount, options.clone())
             .await?;
@@ -23,6 +23,7 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_burn(burn, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared, options).await
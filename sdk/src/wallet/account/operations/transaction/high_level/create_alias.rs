@@ -60,6 +60,7 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_create_alias_output(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
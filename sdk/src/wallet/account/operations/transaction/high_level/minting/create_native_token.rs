@@ -113,6 +113,7 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<CreateNativeTokenTransaction> {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared = self.prepare_create_native_token(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared.transaction, options)
@@ -38,6 +38,7 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared = self
             .prepare_mint_native_token(token_id, mint_amount, options.clone())
             .await?;
@@ -144,6 +144,7 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_mint_nfts(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
@@ -9,10 +9,11 @@ use crate::{
     types::block::{
         address::Bech32Address,
         output::{
+            feature::{Feature, MetadataFeature, TagFeature},
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, Output,
         },
         ConvertTo,
     },
@@ -20,7 +21,7 @@ use crate::{
         account::{
             constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, TransactionOptions,
         },
-        Error,
+        Error, Recipient,
     },
 };
 
@@ -44,6 +45,14 @@ pub struct SendParams {
     /// expiration is needed but not provided, it will default to one day.
     #[getset(get = "pub")]
     expiration: Option<u32>,
+    /// Tag feature of the output, e.g. to identify a withdrawal at the receiver.
+    #[getset(get = "pub")]
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    tag: Option<Vec<u8>>,
+    /// Metadata feature of the output, e.g. a reference to an invoice.
+    #[getset(get = "pub")]
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    metadata: Option<Vec<u8>>,
 }
 
 impl SendParams {
@@ -53,6 +62,8 @@ impl SendParams {
             address: address.convert()?,
             return_address: None,
             expiration: None,
+            tag: None,
+            metadata: None,
         })
     }
 
@@ -73,6 +84,16 @@ impl SendParams {
         self.expiration = expiration.into();
         self
     }
+
+    pub fn with_tag(mut self, tag: impl Into<Option<Vec<u8>>>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: impl Into<Option<Vec<u8>>>) -> Self {
+        self.metadata = metadata.into();
+        self
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -83,13 +104,15 @@ where
     ///
     /// Calls [Account::send_with_params()](crate::wallet::Account::send_with_params) internally.
     /// The options may define the remainder value strategy or custom inputs.
-    /// The provided Addresses provided with [`SendParams`] need to be bech32-encoded.
+    /// The recipient can be a bech32 address or the label of a contact in the address book of the wallet, see
+    /// [`Recipient`].
     pub async fn send(
         &self,
         amount: u64,
-        address: impl ConvertTo<Bech32Address>,
+        recipient: impl Into<Recipient> + Send,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
+        let address = self.wallet.resolve_recipient(recipient).await?;
         let params = [SendParams::new(amount, address)?];
         self.send_with_params(params, options).await
     }
@@ -120,6 +143,7 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_send(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
@@ -137,6 +161,21 @@ where
     {
         log::debug!("[TRANSACTION] prepare_send");
         let options = options.into();
+        let outputs = self.build_send_outputs(params, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Builds the outputs that send the amounts of the params, with a storage deposit return and expiration unlock
+    /// condition for amounts below the minimum storage deposit.
+    pub(crate) async fn build_send_outputs<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -151,6 +190,8 @@ where
             amount,
             return_address,
             expiration,
+            tag,
+            metadata,
         } in params
         {
             self.client().bech32_hrp_matches(address.hrp()).await?;
@@ -166,10 +207,18 @@ where
                 })
                 .transpose()?
                 .unwrap_or(default_return_address.address);
+            let mut features = Vec::<Feature>::new();
+            if let Some(tag) = tag {
+                features.push(TagFeature::new(tag)?.into());
+            }
+            if let Some(metadata) = metadata {
+                features.push(MetadataFeature::new(metadata)?.into());
+            }
 
             // Get the minimum required amount for an output assuming it does not need a storage deposit.
             let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
                 .add_unlock_condition(AddressUnlockCondition::new(address))
+                .with_features(features.clone())
                 .finish_output(token_supply)?;
 
             if amount >= output.amount() {
@@ -185,11 +234,12 @@ where
 
                 // Since it does need a storage deposit, calculate how much that should be
                 let storage_deposit_amount = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
+                    .with_features(features)
                     .with_storage_deposit_return()?
                     .with_expiration()?
                     .finish()?;
 
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
+                if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
                         available: amount,
                         required: amount + storage_deposit_amount,
@@ -216,6 +266,6 @@ where
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
     }
 }
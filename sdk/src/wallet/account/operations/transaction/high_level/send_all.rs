@@ -0,0 +1,199 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokensBuilder, NftOutputBuilder, Output,
+        },
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, OutputData, TransactionOptions},
+        Error, Recipient,
+    },
+};
+
+/// What happens with the native tokens or NFTs of the account when sending all funds.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CarryoverPolicy {
+    /// They're sent to the target address too.
+    #[default]
+    Transfer,
+    /// They stay in the account, the outputs holding them aren't spent.
+    Keep,
+}
+
+/// Options for [`Account::send_all()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAllOptions {
+    /// What happens with the native tokens of basic outputs, native tokens of NFT outputs always stay with the NFT.
+    #[serde(default)]
+    pub native_tokens: CarryoverPolicy,
+    /// What happens with the NFTs.
+    #[serde(default)]
+    pub nfts: CarryoverPolicy,
+    /// A note stored with the transaction.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends all funds of the account to a single address, without a remainder output. Every basic and NFT output
+    /// that can be unlocked with only the address of the account is spent, outputs with other unlock conditions, of
+    /// frozen addresses or used in pending transactions are left untouched. The base coins are sent in one basic
+    /// output, the NFTs in their own outputs.
+    ///
+    /// The recipient can be a bech32 address or the label of a contact in the address book of the wallet, see
+    /// [`Recipient`].
+    pub async fn send_all(
+        &self,
+        recipient: impl Into<Recipient> + Send,
+        options: impl Into<Option<SendAllOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let options = options.into().unwrap_or_default();
+        let note = options.note.clone();
+        let _operation = self.operation_queue.lock().await;
+        let prepared_transaction = self.prepare_send_all(recipient, options).await?;
+
+        self.sign_and_submit_transaction(
+            prepared_transaction,
+            TransactionOptions {
+                note,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Prepares the transaction for [Account::send_all()](crate::wallet::Account::send_all).
+    pub async fn prepare_send_all(
+        &self,
+        recipient: impl Into<Recipient> + Send,
+        options: impl Into<Option<SendAllOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_all");
+        let options = options.into().unwrap_or_default();
+        let address = self.wallet.resolve_recipient(recipient).await?;
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        let inputs = self.send_all_inputs(&options).await?;
+        if inputs.len() > INPUT_COUNT_MAX as usize {
+            return Err(Error::ConsolidationRequired {
+                output_count: inputs.len(),
+                output_count_max: INPUT_COUNT_MAX,
+            });
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let mut outputs = Vec::new();
+        let mut total_amount = 0;
+        let mut total_native_tokens = NativeTokensBuilder::new();
+
+        for input in &inputs {
+            match &input.output {
+                Output::Nft(nft_output) => outputs.push(
+                    NftOutputBuilder::from(nft_output)
+                        .with_nft_id(nft_output.nft_id_non_null(&input.output_id))
+                        .with_unlock_conditions([AddressUnlockCondition::new(address)])
+                        .finish_output(token_supply)?,
+                ),
+                output => {
+                    if let Some(native_tokens) = output.native_tokens() {
+                        total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                    }
+                    total_amount += output.amount();
+                }
+            }
+        }
+
+        // Without basic outputs only the NFTs are sent
+        if total_amount > 0 {
+            let basic_output = BasicOutputBuilder::new_with_amount(total_amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .with_native_tokens(total_native_tokens.finish()?)
+                .finish_output(token_supply)?;
+            // The native tokens of many outputs can need a higher storage deposit than the outputs had together
+            let required_amount = basic_output.rent_cost(&rent_structure);
+            if total_amount < required_amount {
+                return Err(Error::InsufficientFunds {
+                    available: total_amount,
+                    required: required_amount,
+                });
+            }
+            outputs.push(basic_output);
+        }
+        if outputs.is_empty() {
+            let minimum_output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?;
+            return Err(Error::InsufficientFunds {
+                available: 0,
+                required: minimum_output.amount(),
+            });
+        }
+
+        self.prepare_transaction(
+            outputs,
+            TransactionOptions {
+                custom_inputs: Some(inputs.iter().map(|input| input.output_id).collect()),
+                note: options.note,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Returns the outputs that are spent when sending all funds.
+    async fn send_all_inputs(&self, options: &SendAllOptions) -> crate::wallet::Result<Vec<OutputData>> {
+        // Spending the voting output would stop the participation. It needs to be requested before locking the
+        // account details to prevent a deadlock.
+        #[cfg(feature = "participation")]
+        let voting_output_id = self.get_voting_output().await?.map(|output_data| output_data.output_id);
+        #[cfg(not(feature = "participation"))]
+        let voting_output_id = None;
+        let network_id = self.client().get_network_id().await?;
+        let account_details = self.details().await;
+        let frozen_addresses = account_details.frozen_addresses();
+
+        Ok(account_details
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                output_data.network_id == network_id
+                    && Some(output_data.output_id) != voting_output_id
+                    && !account_details.locked_outputs.contains(&output_data.output_id)
+                    && !frozen_addresses.contains(&output_data.address)
+                    && is_unlockable_by_address_only(&output_data.output)
+            })
+            .filter(|output_data| match &output_data.output {
+                Output::Basic(basic_output) => {
+                    options.native_tokens == CarryoverPolicy::Transfer || basic_output.native_tokens().is_empty()
+                }
+                Output::Nft(_) => options.nfts == CarryoverPolicy::Transfer,
+                _ => false,
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+/// Returns whether the output only has an address unlock condition with an Ed25519 address, so it can be spent
+/// without further inputs or outputs.
+fn is_unlockable_by_address_only(output: &Output) -> bool {
+    output.unlock_conditions().map_or(false, |unlock_conditions| {
+        unlock_conditions.len() == 1
+            && unlock_conditions
+                .address()
+                .map_or(false, |unlock_condition| unlock_condition.address().is_ed25519())
+    })
+}
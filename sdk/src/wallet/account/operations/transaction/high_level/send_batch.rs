@@ -0,0 +1,181 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        output::{Output, OUTPUT_COUNT_MAX},
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+    wallet::{
+        account::{Account, TransactionOptions},
+        Error, Result, SendParams,
+    },
+};
+
+/// A transaction sent by [`Account::send_batch()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutTransaction {
+    /// The id of the transaction.
+    pub transaction_id: TransactionId,
+    /// The id of the block that contains the transaction.
+    pub block_id: Option<BlockId>,
+    /// The indexes of the params that are paid out by the transaction.
+    pub payouts: Vec<usize>,
+    /// The base coin amount sent to the receivers, including storage deposits that will be returned.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+}
+
+/// The result of [`Account::send_batch()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutReport {
+    /// The sent transactions, in the order of the params they pay out.
+    pub transactions: Vec<PayoutTransaction>,
+    /// The indexes of the params that weren't paid out, because a transaction failed.
+    pub unsent: Vec<usize>,
+    /// The error of the failed transaction, if not all params were paid out.
+    pub error: Option<String>,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends base coins to many addresses, e.g. for the withdrawals of an exchange. The outputs are packed into as few
+    /// transactions as the protocol allows, each one keeps an output free for the remainder.
+    ///
+    /// All params are validated and the total amount is checked against the available balance before anything is sent.
+    /// The options apply to every transaction, except for custom and mandatory inputs, as the inputs are selected
+    /// for each transaction from the outputs that weren't spent by the previous ones. If a transaction fails after
+    /// other ones were sent, the remaining params aren't sent and the report contains the error, so they can be sent
+    /// again later without paying anything twice. With a request ID, each transaction gets its own one, the request ID
+    /// followed by the index of the transaction, e.g. `withdrawals-1`.
+    pub async fn send_batch<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PayoutReport>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] send_batch");
+        let mut options = options.into();
+        if let Some(options) = options.as_mut() {
+            options.custom_inputs = None;
+            options.mandatory_inputs = None;
+        }
+
+        let outputs = self.build_send_outputs(params, options.as_ref()).await?;
+
+        let _operation = self.operation_queue.lock().await;
+        let required = outputs.iter().map(Output::amount).sum::<u64>();
+        let available = self.balance().await?.base_coin().available();
+        if required > available {
+            return Err(Error::InsufficientFunds { available, required });
+        }
+
+        let mut report = PayoutReport::default();
+        let batches = payout_batches(outputs.len());
+
+        for (batch_index, payouts) in batches.iter().enumerate() {
+            let batch = &outputs[payouts.clone()];
+            let batch_options = options.clone().map(|mut options| {
+                // The transactions of the other batches would otherwise be returned for the same request ID
+                options.request_id = options
+                    .request_id
+                    .map(|request_id| batch_request_id(&request_id, batch_index));
+                options
+            });
+            let result = match self.prepare_transaction(batch.to_vec(), batch_options.clone()).await {
+                Ok(prepared_transaction_data) => {
+                    self.sign_and_submit_transaction(prepared_transaction_data, batch_options)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(transaction) => {
+                    log::debug!("[TRANSACTION] send_batch sent {}", transaction.transaction_id);
+                    report.transactions.push(PayoutTransaction {
+                        transaction_id: transaction.transaction_id,
+                        block_id: transaction.block_id,
+                        payouts: payouts.clone().collect(),
+                        amount: batch.iter().map(Output::amount).sum(),
+                    });
+                }
+                // Nothing was sent yet, so there is nothing to report
+                Err(err) if report.transactions.is_empty() => return Err(err),
+                Err(err) => {
+                    report.unsent = unsent_payouts(&batches, batch_index);
+                    report.error = Some(err.to_string());
+                    break;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+// Returns the ranges of the param indexes that are paid out together, each transaction keeps an output free for the
+// remainder
+fn payout_batches(output_count: usize) -> Vec<Range<usize>> {
+    let batch_size = OUTPUT_COUNT_MAX as usize - 1;
+    (0..output_count)
+        .step_by(batch_size)
+        .map(|start| start..output_count.min(start + batch_size))
+        .collect()
+}
+
+// Returns the request ID of a batch
+fn batch_request_id(request_id: &str, batch_index: usize) -> String {
+    format!("{request_id}-{batch_index}")
+}
+
+// Returns the param indexes of the failed batch and all batches after it
+fn unsent_payouts(batches: &[Range<usize>], failed_batch: usize) -> Vec<usize> {
+    batches[failed_batch..].iter().cloned().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_and_unsent_payouts() {
+        assert!(payout_batches(0).is_empty());
+        assert_eq!(payout_batches(1), [0..1]);
+        assert_eq!(payout_batches(127), [0..127]);
+        assert_eq!(payout_batches(300), [0..127, 127..254, 254..300]);
+
+        let batches = payout_batches(300);
+        assert_eq!(unsent_payouts(&batches, 2), (254..300).collect::<Vec<_>>());
+        assert_eq!(unsent_payouts(&batches, 1), (127..300).collect::<Vec<_>>());
+
+        let report = PayoutReport {
+            transactions: Vec::new(),
+            unsent: unsent_payouts(&batches, 2),
+            error: Some("insufficient funds".to_string()),
+        };
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["unsent"].as_array().unwrap().len(), 46);
+        assert_eq!(json["unsent"][0], 254);
+    }
+
+    #[test]
+    fn request_ids_of_batches() {
+        let request_ids = (0..payout_batches(300).len())
+            .map(|batch_index| batch_request_id("withdrawals", batch_index))
+            .collect::<Vec<_>>();
+        assert_eq!(request_ids, ["withdrawals-0", "withdrawals-1", "withdrawals-2"]);
+    }
+}
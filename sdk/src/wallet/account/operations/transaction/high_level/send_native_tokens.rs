@@ -112,6 +112,7 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_send_native_tokens(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
@@ -74,6 +74,7 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        let _operation = self.operation_queue.lock().await;
         let prepared_transaction = self.prepare_send_nft(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
@@ -0,0 +1,68 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        transfer_template::TransferTemplateOverrides,
+        Error, SendParams,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends the transfer of a [`TransferTemplate`](crate::wallet::TransferTemplate) stored in the wallet. The set
+    /// fields of the overrides replace the ones of the template for this transfer only, the stored template doesn't
+    /// change. Contact labels of the destinations are resolved with the address book of the wallet.
+    pub async fn send_from_template(
+        &self,
+        id: &str,
+        overrides: impl Into<Option<TransferTemplateOverrides>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let (params, options) = self.transfer_template_params(id, overrides.into()).await?;
+        self.send_with_params(params, options).await
+    }
+
+    /// Prepares the transaction for
+    /// [Account::send_from_template()](crate::wallet::Account::send_from_template).
+    pub async fn prepare_send_from_template(
+        &self,
+        id: &str,
+        overrides: impl Into<Option<TransferTemplateOverrides>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_from_template");
+        let (params, options) = self.transfer_template_params(id, overrides.into()).await?;
+        self.prepare_send(params, options).await
+    }
+
+    pub(crate) async fn transfer_template_params(
+        &self,
+        id: &str,
+        overrides: Option<TransferTemplateOverrides>,
+    ) -> crate::wallet::Result<(Vec<SendParams>, Option<TransactionOptions>)> {
+        let mut template = self
+            .wallet
+            .transfer_template(id)
+            .await
+            .ok_or_else(|| Error::TransferTemplateNotFound(id.to_string()))?;
+        if let Some(overrides) = overrides {
+            template = template.apply(overrides);
+        }
+
+        let mut params = Vec::with_capacity(template.destinations.len());
+        for destination in template.destinations {
+            let address = self.wallet.resolve_recipient(destination.recipient).await?;
+            params.push(
+                SendParams::new(destination.amount, address)?
+                    .with_tag(template.tag.clone())
+                    .with_metadata(template.metadata.clone()),
+            );
+        }
+        let options = template.options.map(TransactionOptions::try_from_dto).transpose()?;
+
+        Ok((params, options))
+    }
+}
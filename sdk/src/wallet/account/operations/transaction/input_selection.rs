@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{hash_map::Values, HashSet};
+use std::collections::HashSet;
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
@@ -29,6 +29,7 @@ where
         outputs: Vec<Output>,
         custom_inputs: Option<HashSet<OutputId>>,
         mandatory_inputs: Option<HashSet<OutputId>>,
+        excluded_outputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
     ) -> crate::wallet::Result<Selected> {
@@ -69,11 +70,51 @@ where
             }
         }
 
+        if let Some(excluded_outputs) = excluded_outputs {
+            if let Some(input) = custom_inputs
+                .iter()
+                .chain(mandatory_inputs.iter())
+                .flatten()
+                .find(|input| excluded_outputs.contains(input))
+            {
+                return Err(crate::wallet::Error::CustomInput(format!(
+                    "provided custom input {input} is excluded from the transaction",
+                )));
+            }
+            forbidden_inputs.extend(excluded_outputs);
+        }
+
+        // Outputs of frozen addresses can't be used until the addresses are unfrozen
+        let frozen_addresses = account_details.frozen_addresses();
+        if !frozen_addresses.is_empty() {
+            let frozen_outputs = account_details
+                .unspent_outputs
+                .values()
+                .filter(|output_data| frozen_addresses.contains(&output_data.address))
+                .map(|output_data| output_data.output_id)
+                .collect::<HashSet<_>>();
+            if let Some(input) = custom_inputs
+                .iter()
+                .chain(mandatory_inputs.iter())
+                .flatten()
+                .find(|input| frozen_outputs.contains(input))
+            {
+                return Err(crate::wallet::Error::CustomInput(format!(
+                    "provided custom input {input} belongs to a frozen address",
+                )));
+            }
+            forbidden_inputs.extend(frozen_outputs);
+        }
+
         // Filter inputs to not include inputs that require additional outputs for storage deposit return or could be
         // still locked.
         let available_outputs_signing_data = filter_inputs(
             &account_details,
-            account_details.unspent_outputs.values(),
+            account_details
+                .unspent_outputs
+                .values()
+                // Outputs of another network can't be spent on this one, e.g. after the client options were changed
+                .filter(|output_data| output_data.network_id == protocol_parameters.network_id()),
             current_time,
             &outputs,
             burn,
@@ -224,7 +265,7 @@ where
 #[allow(clippy::too_many_arguments)]
 fn filter_inputs(
     account: &AccountDetails,
-    available_outputs: Values<'_, OutputId, OutputData>,
+    available_outputs: impl Iterator<Item = &OutputData>,
     current_time: u32,
     outputs: &[Output],
     burn: Option<&Burn>,
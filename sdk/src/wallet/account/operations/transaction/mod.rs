@@ -26,7 +26,7 @@ use crate::{
         },
     },
     wallet::account::{
-        types::{InclusionState, Transaction},
+        types::{AttachmentKind, InclusionState, Transaction, TransactionAttachment},
         Account,
     },
 };
@@ -92,6 +92,15 @@ where
         log::debug!("[TRANSACTION] finish_transaction");
         let options = options.into();
 
+        // Queued, so a transaction with the same request ID can't be created concurrently
+        let _operation = self.operation_queue.lock().await;
+        if let Some(request_id) = options.as_ref().and_then(|options| options.request_id.as_deref()) {
+            if let Some(transaction) = self.transaction_by_request_id(request_id).await {
+                log::debug!("[TRANSACTION] transaction for request {request_id} already exists");
+                return Ok(transaction);
+            }
+        }
+
         let prepared_transaction_data = self.prepare_transaction(outputs, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction_data, options)
@@ -150,18 +159,15 @@ where
             return Err(Error::TransactionSemantic(conflict).into());
         }
 
-        // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
-        let block_id = match self
-            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
-            .await
-        {
-            Ok(block_id) => Some(block_id),
-            Err(err) => {
-                log::error!("Failed to submit_transaction_payload {}", err);
-                None
-            }
-        };
+        // A transaction for another network would be rejected by the node or, if it still has the network id of the
+        // nodes, create outputs for addresses that are meant for another network
+        if let Err(err) = self.check_network(&signed_transaction_data.transaction_payload).await {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
 
+        let request_id = options.as_ref().and_then(|options| options.request_id.clone());
         let transaction_id = signed_transaction_data.transaction_payload.id();
 
         // store transaction payload to account (with db feature also store the account to the db)
@@ -169,25 +175,44 @@ where
 
         let inputs = signed_transaction_data
             .inputs_data
-            .into_iter()
+            .iter()
             .map(|input| OutputWithMetadataResponse {
-                metadata: input.output_metadata,
+                metadata: input.output_metadata.clone(),
                 output: OutputDto::from(&input.output),
             })
             .collect();
 
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             transaction_id,
             payload: signed_transaction_data.transaction_payload,
-            block_id,
+            block_id: None,
             network_id,
             timestamp: crate::utils::unix_timestamp_now().as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: options.and_then(|o| o.note),
             inputs,
+            ownership: None,
+            attachments: Vec::new(),
         };
 
+        // Journal the transaction before submitting it, so it can be recovered if the wallet stops before it's stored
+        if let Some(request_id) = request_id {
+            if let Some(journaled_transaction) = self.journal_submission(request_id.clone(), &transaction).await? {
+                log::debug!("[TRANSACTION] transaction for request {request_id} already exists");
+                self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+                return Ok(journaled_transaction);
+            }
+        }
+
+        // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
+        let result = self.submit_transaction_payload(transaction.payload.clone()).await;
+        transaction.add_attachment(TransactionAttachment::new(AttachmentKind::Submission, &result));
+        match result {
+            Ok(block_id) => transaction.block_id = Some(block_id),
+            Err(err) => log::error!("Failed to submit_transaction_payload {}", err),
+        }
+
         let mut account_details = self.details_mut().await;
 
         account_details.transactions.insert(transaction_id, transaction.clone());
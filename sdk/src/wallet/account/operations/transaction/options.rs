@@ -22,9 +22,14 @@ pub struct TransactionOptions {
     // `mandatory_inputs` should be used instead.
     pub custom_inputs: Option<Vec<OutputId>>,
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    // Outputs that must not be used for the transaction, e.g. frozen ones.
+    pub excluded_outputs: Option<Vec<OutputId>>,
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    // Client generated ID of the request. Only one transaction is created per request ID, also if the wallet stops
+    // before the transaction is stored, see `Account::recover_pending_submissions()`.
+    pub request_id: Option<String>,
 }
 
 impl TransactionOptions {
@@ -35,9 +40,11 @@ impl TransactionOptions {
             tagged_data_payload: value.tagged_data_payload.map(TaggedDataPayload::try_from).transpose()?,
             custom_inputs: value.custom_inputs,
             mandatory_inputs: value.mandatory_inputs,
+            excluded_outputs: value.excluded_outputs,
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            request_id: value.request_id,
         })
     }
 }
@@ -56,10 +63,15 @@ pub struct TransactionOptionsDto {
     pub custom_inputs: Option<Vec<OutputId>>,
     #[serde(default)]
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    // Outputs that must not be used for the transaction, e.g. frozen ones.
+    #[serde(default)]
+    pub excluded_outputs: Option<Vec<OutputId>>,
     pub burn: Option<BurnDto>,
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -9,7 +9,10 @@ use packable::bounded::TryIntoBoundedU16Error;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::{api::PreparedTransactionData, secret::SecretManage},
+    client::{
+        api::{transaction::verify_prepared_transaction, PreparedTransactionData},
+        secret::SecretManage,
+    },
     types::block::{
         input::INPUT_COUNT_RANGE,
         output::{Output, OUTPUT_COUNT_RANGE},
@@ -111,6 +114,10 @@ where
                     .as_ref()
                     .and_then(|options| options.mandatory_inputs.as_ref())
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
+                options
+                    .as_ref()
+                    .and_then(|options| options.excluded_outputs.as_ref())
+                    .map(|outputs| HashSet::from_iter(outputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
             )
@@ -134,4 +141,23 @@ where
         );
         Ok(prepared_transaction_data)
     }
+
+    /// Selects the inputs and builds the transaction essence like [`Account::send_outputs()`], but returns it without
+    /// signing or submitting it. Everything that can be checked without signatures is verified and the selected
+    /// inputs are unlocked again, so they're still available for other transactions.
+    pub async fn dry_run_transaction(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] dry_run_transaction");
+        let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
+        self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let current_time = self.client().get_time_checked().await?;
+        verify_prepared_transaction(&prepared_transaction_data, &protocol_parameters, current_time)?;
+
+        Ok(prepared_transaction_data)
+    }
 }
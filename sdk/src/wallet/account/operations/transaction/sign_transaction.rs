@@ -31,6 +31,18 @@ where
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        let can_sign = self.details().await.check_can_sign();
+        if let Err(err) = can_sign {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
+        // Don't sign against protocol parameters that could have changed with a protocol upgrade
+        if let Err(err) = self.client().check_protocol_parameters().await {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err.into());
+        }
         #[cfg(feature = "events")]
         self.emit(
             self.details().await.index,
@@ -13,6 +13,29 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
+    /// Returns a [`NetworkMismatch`](crate::client::Error::NetworkMismatch) error if the transaction essence or the
+    /// addresses of the account are for another network than the one of the nodes of the account.
+    pub(crate) async fn check_network(&self, transaction_payload: &TransactionPayload) -> crate::wallet::Result<()> {
+        self.client().check_transaction_network(transaction_payload).await?;
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let account_details = self.details().await;
+        if let Some(address) = account_details
+            .public_addresses
+            .iter()
+            .chain(&account_details.internal_addresses)
+            .find(|address| address.address.hrp != bech32_hrp)
+        {
+            return Err(crate::client::Error::NetworkMismatch {
+                subject: "the bech32 HRP of the account addresses",
+                provided: address.address.hrp.to_string(),
+                expected: bech32_hrp.to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     /// Submits a payload in a block
     pub(crate) async fn submit_transaction_payload(
         &self,
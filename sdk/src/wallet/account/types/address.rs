@@ -27,6 +27,12 @@ pub struct AccountAddress {
     // do we want this field? Could be useful if we don't store spent output ids and because of that wouldn't know if
     // an address was used or not just by looking at it
     pub(crate) used: bool,
+    /// A label of the address, e.g. the customer it was given to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+    /// Whether the address is frozen. Outputs of frozen addresses aren't selected as inputs of transactions.
+    #[serde(default)]
+    pub(crate) frozen: bool,
 }
 
 impl AccountAddress {
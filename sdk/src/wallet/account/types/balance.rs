@@ -7,7 +7,7 @@ use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NativeToken, NftId, OutputId, TokenId};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -32,6 +32,29 @@ pub struct Balance {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub(crate) potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Base coin amounts of outputs that can only be unlocked by the account in a certain time frame, they're not
+    /// part of the total amount
+    #[serde(default)]
+    pub(crate) time_bound_amounts: Vec<TimeBoundAmount>,
+    /// Outputs of frozen addresses, they're part of the total amount, but not available
+    #[serde(default)]
+    pub(crate) frozen_outputs: Vec<OutputId>,
+}
+
+impl Balance {
+    /// Returns the base coin amount that is expected to be available at the given milestone timestamp, if nothing
+    /// else changes in the meantime. In addition to [`BaseCoinBalance::available()`], it contains the amounts of
+    /// outputs that can be unlocked at that time because of their timelock or expiration unlock conditions. The
+    /// required storage deposit for them isn't considered.
+    pub fn available_at(&self, timestamp: u32) -> u64 {
+        self.base_coin.available
+            + self
+                .time_bound_amounts
+                .iter()
+                .filter(|time_bound_amount| time_bound_amount.is_unlockable_at(timestamp))
+                .map(|time_bound_amount| time_bound_amount.amount)
+                .sum::<u64>()
+    }
 }
 
 impl std::ops::AddAssign for Balance {
@@ -54,9 +77,44 @@ impl std::ops::AddAssign for Balance {
         self.aliases.extend(rhs.aliases);
         self.foundries.extend(rhs.foundries);
         self.nfts.extend(rhs.nfts);
+        self.time_bound_amounts.extend(rhs.time_bound_amounts);
+        self.frozen_outputs.extend(rhs.frozen_outputs);
     }
 }
 
+/// The balance of an account at a past milestone, returned from
+/// [`crate::wallet::account::Account::balance_at()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalBalance {
+    /// The index of the milestone
+    #[getset(get_copy = "pub")]
+    pub(crate) milestone_index: u32,
+    /// The timestamp of the milestone
+    #[getset(get_copy = "pub")]
+    pub(crate) milestone_timestamp: u32,
+    /// Amount of base coins the account could unlock at the milestone, without storage deposits that needed to be
+    /// returned
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) base_coin: u64,
+    /// Native tokens
+    #[getset(get = "pub")]
+    pub(crate) native_tokens: Vec<NativeToken>,
+    /// Aliases
+    #[getset(get = "pub")]
+    pub(crate) aliases: Vec<AliasId>,
+    /// Foundries
+    #[getset(get = "pub")]
+    pub(crate) foundries: Vec<FoundryId>,
+    /// Nfts
+    #[getset(get = "pub")]
+    pub(crate) nfts: Vec<NftId>,
+    /// The unspent outputs of the account at the milestone
+    #[getset(get = "pub")]
+    pub(crate) outputs: Vec<OutputId>,
+}
+
 /// Base coin fields for [`Balance`]
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
 #[serde(rename_all = "camelCase")]
@@ -68,6 +126,19 @@ pub struct BaseCoinBalance {
     /// Balance that can currently be spent
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) available: u64,
+    /// Amount of outputs that the account can only unlock at a later time, because of a timelock or because they
+    /// will expire to the account
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub(crate) timelocked: u64,
+    /// Amount of outputs that can currently be claimed, but will expire back to the sender within a day
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub(crate) expiring_soon: u64,
+    /// Amount of claimable outputs that needs to be returned because of a storage deposit return unlock condition
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub(crate) storage_deposit_return: u64,
+    /// Amount of outputs of frozen addresses, which can't be spent until the addresses are unfrozen
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub(crate) frozen: u64,
     /// Voting power
     #[cfg(feature = "participation")]
     #[serde(with = "crate::utils::serde::string")]
@@ -78,6 +149,10 @@ impl std::ops::AddAssign for BaseCoinBalance {
     fn add_assign(&mut self, rhs: Self) {
         self.total += rhs.total;
         self.available += rhs.available;
+        self.timelocked += rhs.timelocked;
+        self.expiring_soon += rhs.expiring_soon;
+        self.storage_deposit_return += rhs.storage_deposit_return;
+        self.frozen += rhs.frozen;
         #[cfg(feature = "participation")]
         {
             self.voting_power += rhs.voting_power;
@@ -85,6 +160,34 @@ impl std::ops::AddAssign for BaseCoinBalance {
     }
 }
 
+/// Base coin amount of an output that can only be unlocked by the account in a certain time frame, because of its
+/// timelock or expiration unlock condition
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBoundAmount {
+    /// The output id
+    #[getset(get = "pub")]
+    pub(crate) output_id: OutputId,
+    /// Amount the account receives, without a storage deposit that needs to be returned
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) amount: u64,
+    /// Milestone timestamp from which on the output can be unlocked by the account
+    #[getset(get_copy = "pub")]
+    pub(crate) unlockable_from: Option<u32>,
+    /// Milestone timestamp from which on the output can't be unlocked by the account anymore
+    #[getset(get_copy = "pub")]
+    pub(crate) unlockable_until: Option<u32>,
+}
+
+impl TimeBoundAmount {
+    /// Returns whether the output can be unlocked by the account at the given milestone timestamp.
+    pub fn is_unlockable_at(&self, timestamp: u32) -> bool {
+        self.unlockable_from.map_or(true, |from| timestamp >= from)
+            && self.unlockable_until.map_or(true, |until| timestamp < until)
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
 #[getset(get_copy = "pub")]
 pub struct RequiredStorageDeposit {
@@ -203,6 +306,7 @@ impl Balance {
                 available: total / 2,
                 #[cfg(feature = "participation")]
                 voting_power: total / 4,
+                ..Default::default()
             },
             required_storage_deposit: RequiredStorageDeposit {
                 basic: total / 8,
@@ -218,3 +322,65 @@ impl Balance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_at() {
+        let balance = Balance {
+            base_coin: BaseCoinBalance {
+                total: 100,
+                available: 100,
+                ..Default::default()
+            },
+            time_bound_amounts: vec![
+                // timelocked until 10
+                TimeBoundAmount {
+                    output_id: OutputId::null(),
+                    amount: 10,
+                    unlockable_from: Some(10),
+                    unlockable_until: None,
+                },
+                // claimable until it expires at 20
+                TimeBoundAmount {
+                    output_id: OutputId::null(),
+                    amount: 20,
+                    unlockable_from: None,
+                    unlockable_until: Some(20),
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(balance.available_at(5), 120);
+        assert_eq!(balance.available_at(10), 130);
+        assert_eq!(balance.available_at(20), 110);
+    }
+
+    #[test]
+    fn deserialize_without_time_bound_fields() {
+        // A balance serialized before the time-bound amounts were added, e.g. by an older version of the bindings
+        let balance = serde_json::from_str::<Balance>(
+            r#"{
+                "baseCoin": { "total": "100", "available": "90", "votingPower": "0" },
+                "requiredStorageDeposit": { "basic": "10", "alias": "0", "foundry": "0", "nft": "0" },
+                "nativeTokens": [],
+                "aliases": [],
+                "foundries": [],
+                "nfts": [],
+                "potentiallyLockedOutputs": {}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(balance.base_coin.total, 100);
+        assert_eq!(balance.base_coin.available, 90);
+        assert_eq!(balance.base_coin.timelocked, 0);
+        assert_eq!(balance.base_coin.expiring_soon, 0);
+        assert_eq!(balance.base_coin.storage_deposit_return, 0);
+        assert!(balance.time_bound_amounts.is_empty());
+        assert_eq!(balance.available_at(u32::MAX), 90);
+    }
+}
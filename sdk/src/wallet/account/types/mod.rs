@@ -7,14 +7,16 @@ pub(crate) mod balance;
 #[cfg(feature = "participation")]
 pub mod participation;
 
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    balance::{Balance, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
+    balance::{
+        Balance, BaseCoinBalance, HistoricalBalance, NativeTokensBalance, RequiredStorageDeposit, TimeBoundAmount,
+    },
 };
 use crate::{
     client::secret::types::InputSigningData,
@@ -22,8 +24,9 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::{dto::AddressDto, Address},
+            input::Input,
             output::{dto::OutputDto, AliasTransition, Output, OutputId, OutputMetadata},
-            payload::transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
+            payload::transaction::{dto::TransactionPayloadDto, TransactionEssence, TransactionId, TransactionPayload},
             BlockId, Error as BlockError,
         },
         TryFromDto,
@@ -72,7 +75,7 @@ impl OutputData {
             {
                 Some(
                     Bip44::new(account.coin_type)
-                        .with_account(account.index)
+                        .with_account(account.bip44_account_index)
                         .with_change(address.internal as _)
                         .with_address_index(address.key_index),
                 )
@@ -172,6 +175,261 @@ pub struct Transaction {
     /// from the node.
     // serde(default) is needed so it doesn't break with old dbs
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// Who owns the inputs and outputs, set once the transaction is confirmed
+    pub ownership: Option<TransactionOwnership>,
+    /// The blocks the wallet posted for the transaction, see [`Transaction::attachment_history()`]
+    pub(crate) attachments: Vec<TransactionAttachment>,
+}
+
+impl Transaction {
+    /// Returns the submission, reattachments and promotions of the transaction by the wallet in the order they
+    /// happened, including the ones that failed, e.g. to explain why a payment took long to be confirmed. Only the
+    /// first entry and the latest ones are kept for transactions that are retried very often.
+    pub fn attachment_history(&self) -> &[TransactionAttachment] {
+        &self.attachments
+    }
+
+    pub(crate) fn add_attachment(&mut self, attachment: TransactionAttachment) {
+        if self.attachments.len() >= MAX_ATTACHMENT_HISTORY {
+            // Keep the first submission
+            self.attachments.remove(1);
+        }
+        self.attachments.push(attachment);
+    }
+
+    /// Returns the base coin amounts the transaction moved from the view of the account, if its ownership is known.
+    pub fn amounts(&self) -> Option<TransactionAmounts> {
+        let ownership = self.ownership.as_ref()?;
+        let TransactionEssence::Regular(essence) = self.payload.essence();
+        let outputs = essence.outputs().iter().zip(&ownership.outputs);
+        let mut amounts = TransactionAmounts::default();
+
+        if ownership.inputs.iter().any(AddressOwnership::is_mine) {
+            if ownership.outputs.iter().all(AddressOwnership::is_mine) {
+                amounts.self_transfer = essence.outputs().iter().map(Output::amount).sum();
+            } else {
+                // Outputs to the account's own addresses are the remainder
+                amounts.sent = outputs
+                    .filter(|(_, ownership)| !ownership.is_mine())
+                    .map(|(output, _)| output.amount())
+                    .sum();
+            }
+        } else {
+            amounts.received = outputs
+                .filter(|(_, ownership)| ownership.is_mine())
+                .map(|(output, _)| output.amount())
+                .sum();
+        }
+
+        Some(amounts)
+    }
+}
+
+#[cfg(test)]
+impl Transaction {
+    /// Returns a mock of this type with the given transaction id, a pending outgoing transaction with a payload that
+    /// spends the given outputs, at least one, and creates a single output of 1 Mi, all other fields are set to their
+    /// Rust defaults.
+    pub(crate) fn mock(transaction_id: TransactionId, inputs: &[OutputId]) -> Self {
+        use crate::types::block::{
+            address::Ed25519Address,
+            input::UtxoInput,
+            output::{unlock_condition::AddressUnlockCondition, BasicOutput, InputsCommitment},
+            payload::transaction::RegularTransactionEssence,
+            protocol::ProtocolParameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        };
+
+        const ED25519_PUBLIC_KEY: &str = "0x1da5ddd11ba3f961acab68fafee3177d039875eaa94ac5fdbff8b53f0c50bfb9";
+        const ED25519_SIGNATURE: &str = "0xc6a40edf9a089f42c18f4ebccb35fe4b578d93b879e99b87f63573324a710d3456b03fb6d1fcc027e6401cbd9581f790ee3ed7a3f68e9c225fcb9f1cd7b7110d";
+
+        let protocol_parameters = ProtocolParameters::default();
+        let output = Output::Basic(
+            BasicOutput::build_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::new([0; 32]))))
+                .finish_with_params(protocol_parameters.clone())
+                .unwrap(),
+        );
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs(
+                    inputs
+                        .iter()
+                        .map(|output_id| Input::Utxo(UtxoInput::from(*output_id)))
+                        .collect::<Vec<_>>(),
+                )
+                .add_output(output)
+                .finish_with_params(protocol_parameters)
+                .unwrap(),
+        );
+        let signature = Ed25519Signature::try_from_bytes(
+            prefix_hex::decode(ED25519_PUBLIC_KEY).unwrap(),
+            prefix_hex::decode(ED25519_SIGNATURE).unwrap(),
+        )
+        .unwrap();
+        let unlocks = (0..inputs.len())
+            .map(|index| {
+                if index == 0 {
+                    Unlock::Signature(SignatureUnlock::from(Signature::from(signature)))
+                } else {
+                    Unlock::Reference(ReferenceUnlock::new(0).unwrap())
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            payload: TransactionPayload::new(essence, Unlocks::new(unlocks).unwrap()).unwrap(),
+            block_id: None,
+            inclusion_state: InclusionState::Pending,
+            timestamp: 0,
+            transaction_id,
+            network_id: 0,
+            incoming: false,
+            note: None,
+            inputs: Vec::new(),
+            ownership: None,
+            attachments: Vec::new(),
+        }
+    }
+}
+
+/// The maximum number of attachments kept in the history of a transaction.
+const MAX_ATTACHMENT_HISTORY: usize = 100;
+
+/// Why a block was posted for a transaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AttachmentKind {
+    /// The transaction was posted for the first time.
+    Submission,
+    /// The transaction was posted in a new block, because the previous one wasn't included.
+    Reattachment,
+    /// An empty block referencing the block of the transaction was posted, so it gets referenced sooner.
+    Promotion,
+}
+
+/// A block the wallet posted for a transaction, or tried to.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionAttachment {
+    /// Why the block was posted.
+    pub kind: AttachmentKind,
+    /// The id of the posted block, `None` if posting failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<BlockId>,
+    /// The unix timestamp in milliseconds at which the block was posted.
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// Why posting the block failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl TransactionAttachment {
+    /// Creates a [`TransactionAttachment`] from the result of posting a block, at the current time.
+    pub(crate) fn new<E: std::fmt::Display>(kind: AttachmentKind, result: &Result<BlockId, E>) -> Self {
+        Self {
+            kind,
+            block_id: result.as_ref().ok().copied(),
+            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+            error: result.as_ref().err().map(ToString::to_string),
+        }
+    }
+}
+
+/// Who owns an input or output of a transaction, from the view of an account.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AddressOwnership {
+    /// An internal address of the account, used for remainders.
+    Internal,
+    /// A public address of the account.
+    External,
+    /// An address that isn't generated by the account.
+    Foreign,
+}
+
+impl AddressOwnership {
+    /// Returns whether the address belongs to the account.
+    pub fn is_mine(&self) -> bool {
+        matches!(self, Self::Internal | Self::External)
+    }
+}
+
+/// The ownership of the inputs and outputs of a transaction, in the order of its essence.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionOwnership {
+    pub inputs: Vec<AddressOwnership>,
+    pub outputs: Vec<AddressOwnership>,
+}
+
+impl TransactionOwnership {
+    /// Classifies the inputs and outputs of a transaction. Inputs are only known to be the account's if their outputs
+    /// were synced by it, outputs are classified by the address that can unlock them, so outputs to aliases and NFTs
+    /// are foreign.
+    pub(crate) fn new(account: &AccountDetails, payload: &TransactionPayload) -> Self {
+        let TransactionEssence::Regular(essence) = payload.essence();
+        let addresses = account
+            .public_addresses
+            .iter()
+            .map(|a| (*a.address.inner(), AddressOwnership::External))
+            .chain(
+                account
+                    .internal_addresses
+                    .iter()
+                    .map(|a| (*a.address.inner(), AddressOwnership::Internal)),
+            )
+            .collect::<HashMap<_, _>>();
+        let ownership = |address: Option<&Address>| {
+            address
+                .and_then(|address| addresses.get(address).copied())
+                .unwrap_or(AddressOwnership::Foreign)
+        };
+
+        Self {
+            inputs: essence
+                .inputs()
+                .iter()
+                .map(|input| {
+                    let Input::Utxo(input) = input else {
+                        return AddressOwnership::Foreign;
+                    };
+                    ownership(account.outputs.get(input.output_id()).map(|output| &output.address))
+                })
+                .collect(),
+            outputs: essence
+                .outputs()
+                .iter()
+                .map(|output| {
+                    let unlock_conditions = output.unlock_conditions();
+                    ownership(
+                        unlock_conditions
+                            .and_then(|u| u.address().map(|u| u.address()))
+                            .or_else(|| {
+                                unlock_conditions.and_then(|u| u.state_controller_address().map(|u| u.address()))
+                            }),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The base coin amounts a transaction moved, from the view of an account.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionAmounts {
+    /// The amount sent to foreign addresses, without the remainder.
+    #[serde(with = "crate::utils::serde::string")]
+    pub sent: u64,
+    /// The amount received from foreign addresses.
+    #[serde(with = "crate::utils::serde::string")]
+    pub received: u64,
+    /// The amount moved between the account's own addresses, if it didn't send to anyone else.
+    #[serde(with = "crate::utils::serde::string")]
+    pub self_transfer: u64,
 }
 
 /// Dto for a transaction with metadata
@@ -195,6 +453,15 @@ pub struct TransactionDto {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// Who owns the inputs and outputs, set once the transaction is confirmed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ownership: Option<TransactionOwnership>,
+    /// The base coin amounts the transaction moved, derived from the ownership
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amounts: Option<TransactionAmounts>,
+    /// The blocks the wallet posted for the transaction
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachment_history: Vec<TransactionAttachment>,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -209,6 +476,9 @@ impl From<&Transaction> for TransactionDto {
             incoming: value.incoming,
             note: value.note.clone(),
             inputs: value.inputs.clone(),
+            ownership: value.ownership.clone(),
+            amounts: value.amounts(),
+            attachment_history: value.attachments.clone(),
         }
     }
 }
@@ -237,6 +507,8 @@ impl TryFromDto for Transaction {
             incoming: dto.incoming,
             note: dto.note,
             inputs: dto.inputs,
+            ownership: dto.ownership,
+            attachments: dto.attachment_history,
         })
     }
 }
@@ -352,3 +624,107 @@ impl core::fmt::Display for AccountIdentifier {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attachment_history_keeps_first_and_latest() {
+        let transaction_id = TransactionId::new([1; 32]);
+        let mut transaction = Transaction::mock(transaction_id, &[OutputId::new(transaction_id, 0).unwrap()]);
+        let attachment = |kind, index: u128| {
+            let mut attachment = TransactionAttachment::new(kind, &Ok::<_, BlockError>(BlockId::new([0; 32])));
+            attachment.timestamp = index;
+            attachment
+        };
+
+        transaction.add_attachment(attachment(AttachmentKind::Submission, 0));
+        for index in 1..MAX_ATTACHMENT_HISTORY as u128 + 50 {
+            transaction.add_attachment(attachment(AttachmentKind::Promotion, index));
+        }
+
+        let history = transaction.attachment_history();
+        assert_eq!(history.len(), MAX_ATTACHMENT_HISTORY);
+        // The first submission is kept, followed by the latest attachments in order
+        assert_eq!(history[0].kind, AttachmentKind::Submission);
+        assert_eq!(history[0].timestamp, 0);
+        assert_eq!(history[1].timestamp, 51);
+        assert_eq!(history.last().unwrap().timestamp, MAX_ATTACHMENT_HISTORY as u128 + 49);
+        assert!(history[1..].windows(2).all(|w| w[0].timestamp + 1 == w[1].timestamp));
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn transaction_ownership_and_amounts() {
+        use crate::types::block::address::{Ed25519Address, ToBech32Ext};
+
+        let input_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let mut transaction = Transaction::mock(TransactionId::new([2; 32]), &[input_id]);
+        // Without the ownership the amounts aren't known
+        assert_eq!(transaction.amounts(), None);
+        assert_eq!(TransactionDto::from(&transaction).amounts, None);
+
+        let mut account = AccountDetails::mock();
+        let public_address = account.public_addresses[0].address;
+        // The output of the mock transaction goes to this address
+        let output_address = Address::from(Ed25519Address::new([0; 32]));
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        // The spent output of the account, its amount doesn't matter
+        let input = OutputData {
+            output_id: input_id,
+            metadata: OutputMetadata::new(BlockId::new([0; 32]), input_id, true, None, None, None, 1, 0, 1),
+            output: essence.outputs()[0].clone(),
+            is_spent: true,
+            address: *public_address.inner(),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        };
+        let amounts = |account: &AccountDetails, transaction: &mut Transaction| {
+            transaction.ownership = Some(TransactionOwnership::new(account, &transaction.payload));
+            transaction.amounts().unwrap()
+        };
+
+        // Sent from the account to a foreign address
+        account.outputs.insert(input_id, input);
+        assert_eq!(
+            amounts(&account, &mut transaction),
+            TransactionAmounts {
+                sent: 1_000_000,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            transaction.ownership,
+            Some(TransactionOwnership {
+                inputs: vec![AddressOwnership::External],
+                outputs: vec![AddressOwnership::Foreign],
+            })
+        );
+
+        // Sent from the account to its own internal address
+        let mut internal_address = account.public_addresses[0].clone();
+        internal_address.address = output_address.to_bech32(public_address.hrp);
+        internal_address.internal = true;
+        account.internal_addresses.push(internal_address);
+        assert_eq!(
+            amounts(&account, &mut transaction),
+            TransactionAmounts {
+                self_transfer: 1_000_000,
+                ..Default::default()
+            }
+        );
+
+        // Received from a foreign address
+        account.outputs.clear();
+        assert_eq!(
+            amounts(&account, &mut transaction),
+            TransactionAmounts {
+                received: 1_000_000,
+                ..Default::default()
+            }
+        );
+        assert_eq!(TransactionDto::from(&transaction).amounts.unwrap().received, 1_000_000);
+    }
+}
@@ -4,12 +4,21 @@
 use std::collections::HashMap;
 
 use crate::{
-    client::secret::SecretManage,
-    types::block::output::{OutputId, OutputMetadata},
-    wallet::account::{
-        operations::syncing::options::SyncOptions,
-        types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        Account, AccountAddress,
+    client::{secret::SecretManage, ClientBuilder},
+    types::block::{
+        address::Bech32Address,
+        output::{OutputId, OutputMetadata, OutputWithMetadata},
+        ConvertTo,
+    },
+    wallet::{
+        account::{
+            operations::syncing::options::SyncOptions,
+            types::{
+                address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction, TransactionOwnership,
+            },
+            Account, AccountAddress,
+        },
+        core::operations::{client::update_client, snapshot::output_addresses},
     },
 };
 #[cfg(feature = "events")]
@@ -17,7 +26,9 @@ use crate::{
     types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::dto::TransactionPayloadDto},
     wallet::{
         account::types::OutputDataDto,
-        events::types::{NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent},
+        events::types::{
+            NewOutputEvent, PaymentReceivedEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent,
+        },
     },
 };
 
@@ -34,6 +45,87 @@ where
         Ok(())
     }
 
+    /// Sets a metadata entry of the account, replacing the previous value of the key
+    pub async fn set_metadata(&self, key: &str, value: &str) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.metadata.insert(key.to_string(), value.to_string());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Removes a metadata entry of the account, returns its value if it existed
+    pub async fn remove_metadata(&self, key: &str) -> crate::wallet::Result<Option<String>> {
+        let mut account_details = self.details_mut().await;
+        let value = account_details.metadata.remove(key);
+        #[cfg(feature = "storage")]
+        if value.is_some() {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(value)
+    }
+
+    /// Archives or restores the account. Archived accounts keep their data, but are hidden when filtering accounts
+    /// and aren't synced in the background
+    pub async fn set_archived(&self, archived: bool) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.archived = archived;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Sets or removes the label of an address of the account
+    pub async fn set_address_label(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        label: impl Into<Option<String>> + Send,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+        let mut account_details = self.details_mut().await;
+        account_details.account_address_mut(&address)?.label = label.into();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Freezes or unfreezes an address of the account. The outputs of frozen addresses aren't selected as inputs of
+    /// transactions and are reported as frozen in the balance, e.g. to quarantine deposits pending a review.
+    pub async fn set_address_frozen(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        frozen: bool,
+    ) -> crate::wallet::Result<()> {
+        let address = address.convert()?;
+        let mut account_details = self.details_mut().await;
+        account_details.account_address_mut(&address)?.frozen = frozen;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Sets the client options of an account bound to another network than the wallet, the ones of other accounts are
+    /// set with [`Wallet::set_client_options()`](crate::wallet::Wallet::set_client_options).
+    pub async fn set_client_options(&self, client_options: ClientBuilder) -> crate::wallet::Result<()> {
+        let Some(client) = &self.inner.client else {
+            return Err(crate::wallet::Error::AccountUsesWalletClient(
+                *self.details().await.index(),
+            ));
+        };
+        let change_in_node_manager = update_client(client, client_options.clone()).await?;
+
+        let mut account_details = self.details_mut().await;
+        account_details.client_options = Some(client_options);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        drop(account_details);
+
+        if change_in_node_manager {
+            self.update_account_bech32_hrp().await?;
+        }
+        Ok(())
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -138,6 +230,7 @@ where
         }
 
         // Add new synced outputs
+        let mut new_outputs = Vec::new();
         for output_data in unspent_outputs {
             // Insert output, if it's unknown emit the NewOutputEvent
             if account_details
@@ -145,6 +238,7 @@ where
                 .insert(output_data.output_id, output_data.clone())
                 .is_none()
             {
+                new_outputs.push(output_data.clone());
                 #[cfg(feature = "events")]
                 {
                     let transaction = account_details
@@ -182,9 +276,171 @@ where
             );
             self.save(Some(&account_details)).await?;
         }
+        drop(account_details);
+
+        #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+        for (request, output_data) in self.fulfill_payment_requests(&new_outputs).await? {
+            #[cfg(feature = "events")]
+            self.emit(
+                account_index,
+                WalletEvent::PaymentReceived(Box::new(PaymentReceivedEvent {
+                    request,
+                    output: OutputDataDto::from(&output_data),
+                })),
+            )
+            .await;
+        }
+
         Ok(())
     }
 
+    /// Update account with the unspent outputs of a ledger snapshot
+    pub(crate) async fn import_snapshot_outputs(&self, outputs: &[OutputWithMetadata]) -> crate::wallet::Result<()> {
+        log::debug!("[import_snapshot_outputs]");
+        let mut addresses_with_unspent_outputs = Vec::new();
+        let mut unspent_outputs = Vec::new();
+
+        for address in self.addresses().await? {
+            let address_outputs = outputs
+                .iter()
+                .filter(|output| output_addresses(output.output()).any(|a| a == address.address.inner()))
+                .cloned()
+                .collect::<Vec<_>>();
+            if address_outputs.is_empty() {
+                continue;
+            }
+
+            let address_with_unspent_outputs = AddressWithUnspentOutputs {
+                address: address.address,
+                key_index: address.key_index,
+                internal: address.internal,
+                output_ids: address_outputs
+                    .iter()
+                    .map(|output| *output.metadata().output_id())
+                    .collect(),
+            };
+            unspent_outputs.extend(
+                self.output_response_to_output_data(address_outputs, &address_with_unspent_outputs)
+                    .await?,
+            );
+            addresses_with_unspent_outputs.push(address_with_unspent_outputs);
+        }
+
+        if unspent_outputs.is_empty() {
+            return Ok(());
+        }
+
+        self.update_account(
+            addresses_with_unspent_outputs,
+            unspent_outputs,
+            HashMap::new(),
+            &SyncOptions::default(),
+        )
+        .await
+    }
+
+    /// Update account with the UTXO changes of a milestone, the created outputs can belong to any address
+    #[cfg(feature = "mqtt")]
+    pub(crate) async fn apply_ledger_changes(
+        &self,
+        created_outputs: &[OutputWithMetadata],
+        consumed_output_ids: &[OutputId],
+    ) -> crate::wallet::Result<()> {
+        let mut addresses_with_new_outputs = Vec::new();
+        let mut new_outputs = Vec::new();
+
+        for address in self.addresses().await? {
+            let address_outputs = created_outputs
+                .iter()
+                .filter(|output| output_addresses(output.output()).any(|a| a == address.address.inner()))
+                .cloned()
+                .collect::<Vec<_>>();
+            if address_outputs.is_empty() {
+                continue;
+            }
+
+            let address_with_new_outputs = AddressWithUnspentOutputs {
+                address: address.address,
+                key_index: address.key_index,
+                internal: address.internal,
+                output_ids: address_outputs
+                    .iter()
+                    .map(|output| *output.metadata().output_id())
+                    .collect(),
+            };
+            new_outputs.extend(
+                self.output_response_to_output_data(address_outputs, &address_with_new_outputs)
+                    .await?,
+            );
+            addresses_with_new_outputs.push(address_with_new_outputs);
+        }
+
+        let mut account_details = self.details_mut().await;
+        let spent_output_ids = consumed_output_ids
+            .iter()
+            .filter(|output_id| account_details.unspent_outputs.contains_key(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if new_outputs.is_empty() && spent_output_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Only the addresses with changed outputs are updated, unlike syncing which replaces the synced addresses
+        for address in account_details.addresses_with_unspent_outputs.iter_mut() {
+            address
+                .output_ids
+                .retain(|output_id| !spent_output_ids.contains(output_id));
+        }
+        account_details
+            .addresses_with_unspent_outputs
+            .retain(|address| !address.output_ids.is_empty());
+        for address_with_new_outputs in addresses_with_new_outputs {
+            let addresses = if address_with_new_outputs.internal {
+                &mut account_details.internal_addresses
+            } else {
+                &mut account_details.public_addresses
+            };
+            if let Some(address) = addresses
+                .iter_mut()
+                .find(|a| a.key_index == address_with_new_outputs.key_index)
+            {
+                address.used = true;
+            }
+
+            match account_details
+                .addresses_with_unspent_outputs
+                .iter()
+                .position(|a| a.address == address_with_new_outputs.address)
+            {
+                Some(position) => account_details.addresses_with_unspent_outputs[position]
+                    .output_ids
+                    .extend(address_with_new_outputs.output_ids),
+                None => account_details
+                    .addresses_with_unspent_outputs
+                    .push(address_with_new_outputs),
+            }
+        }
+        drop(account_details);
+
+        // Keeps the addresses with unspent outputs that were updated above
+        let options = SyncOptions {
+            address_start_index: u32::MAX,
+            address_start_index_internal: u32::MAX,
+            ..Default::default()
+        };
+        self.update_account(
+            Vec::new(),
+            new_outputs,
+            spent_output_ids
+                .into_iter()
+                .map(|output_id| (output_id, None))
+                .collect(),
+            &options,
+        )
+        .await
+    }
+
     /// Update account with newly synced transactions
     pub(crate) async fn update_account_with_transactions(
         &self,
@@ -196,7 +452,10 @@ where
 
         let mut account_details = self.details_mut().await;
 
-        for transaction in updated_transactions {
+        for mut transaction in updated_transactions {
+            if transaction.inclusion_state == InclusionState::Confirmed && transaction.ownership.is_none() {
+                transaction.ownership = Some(TransactionOwnership::new(&account_details, &transaction.payload));
+            }
             match transaction.inclusion_state {
                 InclusionState::Confirmed | InclusionState::Conflicting | InclusionState::UnknownPruned => {
                     let transaction_id = transaction.payload.id();
@@ -279,7 +538,7 @@ where
 
     // Should only be called from the Wallet so all accounts are on the same state
     // Will update the addresses with a possible new Bech32 HRP and clear the inaccessible_incoming_transactions.
-    pub(crate) async fn update_account_bech32_hrp(&mut self) -> crate::wallet::Result<()> {
+    pub(crate) async fn update_account_bech32_hrp(&self) -> crate::wallet::Result<()> {
         let bech32_hrp = self.client().get_bech32_hrp().await?;
         log::debug!("[UPDATE ACCOUNT WITH BECH32 HRP] new bech32_hrp: {}", bech32_hrp);
         let mut account_details = self.details_mut().await;
@@ -307,3 +566,73 @@ where
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "mqtt"))]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output},
+        payload::transaction::TransactionId,
+        protocol::ProtocolParameters,
+        BlockId,
+    };
+
+    fn output_with_metadata(address: Address, output_id: OutputId) -> OutputWithMetadata {
+        let output: Output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(ProtocolParameters::default().token_supply())
+            .unwrap();
+        OutputWithMetadata::new(
+            output,
+            OutputMetadata::new(BlockId::new([0; 32]), output_id, false, None, None, None, 1, 0, 1),
+        )
+    }
+
+    #[tokio::test]
+    async fn apply_ledger_changes() {
+        let storage_path = "test-storage/apply_ledger_changes";
+        let account = Account::mock(storage_path).await;
+        let account_address = account.public_addresses().await[0].address;
+        let own_output_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+        let foreign_output_id = OutputId::new(TransactionId::new([1; 32]), 1).unwrap();
+
+        // Only the outputs of the account's addresses are added
+        account
+            .apply_ledger_changes(
+                &[
+                    output_with_metadata(account_address.into_inner(), own_output_id),
+                    output_with_metadata(Address::from(Ed25519Address::new([0; 32])), foreign_output_id),
+                ],
+                &[],
+            )
+            .await
+            .unwrap();
+        {
+            let account_details = account.details().await;
+            assert_eq!(
+                account_details.unspent_outputs.keys().collect::<Vec<_>>(),
+                [&own_output_id]
+            );
+            assert_eq!(account_details.addresses_with_unspent_outputs.len(), 1);
+            assert_eq!(
+                account_details.addresses_with_unspent_outputs[0].output_ids,
+                [own_output_id]
+            );
+            assert!(account_details.public_addresses[0].used);
+        }
+
+        // Consumed outputs of other accounts are ignored, the account's own are spent
+        account
+            .apply_ledger_changes(&[], &[foreign_output_id, own_output_id])
+            .await
+            .unwrap();
+        let account_details = account.details().await;
+        assert!(account_details.unspent_outputs.is_empty());
+        assert!(account_details.addresses_with_unspent_outputs.is_empty());
+        assert!(account_details.outputs[&own_output_id].is_spent);
+        drop(account_details);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::{account::types::AccountAddress, Error, Result},
+};
+
+/// A labeled address stored in the [`AddressBook`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    /// The human readable label of the contact.
+    pub label: String,
+    /// The address of the contact.
+    pub address: Bech32Address,
+}
+
+/// Maps human readable labels to addresses, so they can be used as recipients of transactions. Labels and addresses
+/// are unique within an address book.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AddressBook(BTreeMap<String, Bech32Address>);
+
+impl AddressBook {
+    /// Returns the address of a contact.
+    pub fn get(&self, label: &str) -> Option<&Bech32Address> {
+        self.0.get(label)
+    }
+
+    /// Returns the label under which an address is stored.
+    pub fn label_of(&self, address: &Bech32Address) -> Option<&str> {
+        self.0
+            .iter()
+            .find_map(|(label, contact_address)| (contact_address == address).then_some(label.as_str()))
+    }
+
+    /// Returns all contacts, sorted by label.
+    pub fn contacts(&self) -> Vec<Contact> {
+        self.0
+            .iter()
+            .map(|(label, address)| Contact {
+                label: label.clone(),
+                address: *address,
+            })
+            .collect()
+    }
+
+    /// Returns the number of contacts.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the address book has no contacts.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds a contact. Fails if the label or the address is already used by another contact, adding an identical
+    /// contact again is a no-op.
+    pub fn insert(&mut self, contact: Contact) -> Result<()> {
+        if let Some(address) = self.0.get(&contact.label) {
+            if *address == contact.address {
+                return Ok(());
+            }
+            return Err(Error::ContactAlreadyExists(contact.label));
+        }
+        if contact.label.is_empty() || Bech32Address::try_from_str(&contact.label).is_ok() {
+            return Err(Error::InvalidContactLabel(contact.label));
+        }
+        if let Some(label) = self.label_of(&contact.address) {
+            return Err(Error::ContactAddressAlreadyExists {
+                address: contact.address,
+                label: label.to_owned(),
+            });
+        }
+
+        self.0.insert(contact.label, contact.address);
+
+        Ok(())
+    }
+
+    /// Removes a contact, returns its address.
+    pub fn remove(&mut self, label: &str) -> Option<Bech32Address> {
+        self.0.remove(label)
+    }
+
+    /// Adds all given contacts. Nothing is added if one of them collides with an existing contact or with another
+    /// contact of the import.
+    pub fn import(&mut self, contacts: impl IntoIterator<Item = Contact>) -> Result<()> {
+        let mut address_book = self.clone();
+
+        for contact in contacts {
+            address_book.insert(contact)?;
+        }
+        *self = address_book;
+
+        Ok(())
+    }
+
+    /// Resolves a [`Recipient`] to an address.
+    pub fn resolve(&self, recipient: Recipient) -> Result<Bech32Address> {
+        match recipient {
+            Recipient::Address(address) => Ok(address),
+            Recipient::Text(text) => match Bech32Address::try_from_str(&text) {
+                Ok(address) => Ok(address),
+                Err(error) => self.get(&text).copied().ok_or_else(|| error.into()),
+            },
+            Recipient::Contact(label) => self.get(&label).copied().ok_or(Error::ContactNotFound(label)),
+        }
+    }
+}
+
+/// The recipient of a transaction, either an address or the label of a contact in the [`AddressBook`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Recipient {
+    /// A bech32 address.
+    Address(Bech32Address),
+    /// Text that is parsed as bech32 address first and only used as label if it's no valid address but an existing
+    /// contact has it, so a mistyped address fails with the parsing error instead of being taken for an unknown label.
+    Text(String),
+    /// The label of a contact.
+    Contact(String),
+}
+
+impl Recipient {
+    /// Creates a recipient from the label of a contact.
+    pub fn contact(label: impl Into<String>) -> Self {
+        Self::Contact(label.into())
+    }
+}
+
+impl From<Bech32Address> for Recipient {
+    fn from(value: Bech32Address) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<&Bech32Address> for Recipient {
+    fn from(value: &Bech32Address) -> Self {
+        Self::Address(*value)
+    }
+}
+
+impl From<AccountAddress> for Recipient {
+    fn from(value: AccountAddress) -> Self {
+        Self::Address(value.into_bech32())
+    }
+}
+
+impl From<&str> for Recipient {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<String> for Recipient {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&String> for Recipient {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+    const OTHER_ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+    fn contact(label: &str, address: &str) -> Contact {
+        Contact {
+            label: label.to_owned(),
+            address: Bech32Address::try_from_str(address).unwrap(),
+        }
+    }
+
+    #[test]
+    fn collisions() {
+        let mut address_book = AddressBook::default();
+        address_book.insert(contact("alice", ADDRESS)).unwrap();
+        address_book.insert(contact("alice", ADDRESS)).unwrap();
+
+        assert!(matches!(
+            address_book.insert(contact("alice", OTHER_ADDRESS)),
+            Err(Error::ContactAlreadyExists(_))
+        ));
+        assert!(matches!(
+            address_book.insert(contact("bob", ADDRESS)),
+            Err(Error::ContactAddressAlreadyExists { .. })
+        ));
+        assert!(matches!(
+            address_book.insert(contact(OTHER_ADDRESS, OTHER_ADDRESS)),
+            Err(Error::InvalidContactLabel(_))
+        ));
+        assert!(
+            address_book
+                .import([contact("bob", OTHER_ADDRESS), contact("carol", OTHER_ADDRESS)])
+                .is_err()
+        );
+        assert_eq!(address_book.len(), 1);
+    }
+
+    #[test]
+    fn resolve() {
+        let mut address_book = AddressBook::default();
+        address_book.insert(contact("alice", ADDRESS)).unwrap();
+
+        let address = Bech32Address::try_from_str(ADDRESS).unwrap();
+        assert_eq!(address_book.resolve(Recipient::contact("alice")).unwrap(), address);
+        assert_eq!(address_book.resolve(ADDRESS.into()).unwrap(), address);
+        assert_eq!(address_book.resolve(address.into()).unwrap(), address);
+        assert!(matches!(
+            address_book.resolve(Recipient::contact("bob")),
+            Err(Error::ContactNotFound(_))
+        ));
+        // Text is only used as label of an existing contact
+        assert_eq!(address_book.resolve("alice".into()).unwrap(), address);
+        assert!(matches!(address_book.resolve("bob".into()), Err(Error::Block(_))));
+        // A mistyped address isn't taken for an unknown label
+        let mistyped = format!("{}x", &ADDRESS[..ADDRESS.len() - 1]);
+        assert!(matches!(address_book.resolve(mistyped.into()), Err(Error::Block(_))));
+    }
+}
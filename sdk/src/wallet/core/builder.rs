@@ -15,12 +15,11 @@ use tokio::sync::RwLock;
 use super::operations::storage::SaveLoadWallet;
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
-#[cfg(all(feature = "storage", not(feature = "rocksdb")))]
-use crate::wallet::storage::adapter::memory::Memory;
 #[cfg(feature = "storage")]
 use crate::wallet::{
     account::AccountDetails,
-    storage::{StorageManager, StorageOptions},
+    migration::{check_migrations, MigrationStatus},
+    storage::{Storage, StorageManager, StorageOptions},
 };
 use crate::{
     client::secret::{SecretManage, SecretManager},
@@ -96,16 +95,30 @@ where
         self
     }
 
-    /// Set the storage path to be used.
+    /// Set the storage path to be used, other storage options like the encryption key are kept.
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     pub fn with_storage_path(mut self, path: &str) -> Self {
-        self.storage_options = Some(StorageOptions {
-            path: path.into(),
-            ..Default::default()
-        });
+        self.storage_options.get_or_insert_with(Default::default).path = path.into();
         self
     }
+
+    /// Checks which migrations would be applied to the storage when the wallet is built, without applying them.
+    /// Returns an error if the storage was migrated by a newer version of the SDK. Nothing is created if there's no
+    /// storage at the path yet.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn check_storage_migrations(&self) -> crate::wallet::Result<MigrationStatus> {
+        let storage_options = self.storage_options.clone().unwrap_or_default();
+        if !storage_options.path.is_dir() {
+            return Ok(MigrationStatus {
+                current: None,
+                pending: Vec::new(),
+            });
+        }
+
+        check_migrations(&Storage::from_options(&storage_options)?).await
+    }
 }
 
 impl<S: 'static + SecretManage> WalletBuilder<S>
@@ -134,14 +147,8 @@ where
             }
         }
 
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
-        #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
-        let storage = Memory::default();
-
         #[cfg(feature = "storage")]
-        let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key.clone()).await?;
+        let mut storage_manager = StorageManager::open(Storage::from_options(&storage_options)?).await?;
 
         #[cfg(feature = "storage")]
         let read_manager_builder = Self::load(&storage_manager).await?;
@@ -182,10 +189,11 @@ where
         #[cfg(feature = "storage")]
         let mut accounts = storage_manager.get_accounts().await?;
 
-        // Check against potential account coin type before saving the wallet data
+        // Check against potential account coin types before saving the wallet data, accounts can be created with
+        // another coin type, but at least one of them needs to use the coin type of the wallet
         #[cfg(feature = "storage")]
         if let Some(account) = accounts.first() {
-            if *account.coin_type() != coin_type {
+            if accounts.iter().all(|account| *account.coin_type() != coin_type) {
                 return Err(crate::wallet::Error::InvalidCoinType {
                     new_coin_type: coin_type,
                     existing_coin_type: *account.coin_type(),
@@ -206,8 +214,21 @@ where
         unlock_unused_inputs(&mut accounts)?;
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
+        #[cfg(feature = "storage")]
+        let address_book = storage_manager.get_address_book().await?;
+        #[cfg(not(feature = "storage"))]
+        let address_book = crate::wallet::AddressBook::default();
+        #[cfg(feature = "storage")]
+        let transfer_templates = storage_manager.get_transfer_templates().await?;
+        #[cfg(not(feature = "storage"))]
+        let transfer_templates = crate::wallet::TransferTemplates::default();
         let wallet_inner = Arc::new(WalletInner {
             background_syncing_status: AtomicUsize::new(0),
+            background_retrying_status: AtomicUsize::new(0),
+            background_claiming_status: AtomicUsize::new(0),
+            background_scheduling_status: AtomicUsize::new(0),
+            #[cfg(feature = "mqtt")]
+            ledger_follower_status: AtomicUsize::new(0),
             client: self
                 .client_options
                 .clone()
@@ -215,9 +236,12 @@ where
                 .finish()
                 .await?,
             coin_type: AtomicU32::new(coin_type),
+            address_book: RwLock::new(address_book),
+            transfer_templates: RwLock::new(transfer_templates),
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            sync_metrics: Default::default(),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
@@ -229,7 +253,7 @@ where
         let mut accounts: Vec<Account<S>> = try_join_all(
             accounts
                 .into_iter()
-                .map(|a| Account::new(a, wallet_inner.clone()).boxed()),
+                .map(|a| Account::new(a, wallet_inner.clone(), None).boxed()),
         )
         .await?;
 
@@ -325,3 +349,35 @@ pub(crate) mod dto {
         }
     }
 }
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn storage_path_keeps_options() {
+        let key = [1; 32];
+        let storage_options = StorageOptions::new("options-path".into(), Default::default()).with_encryption_key(key);
+
+        let builder = WalletBuilder::<SecretManager>::new()
+            .with_storage_options(storage_options.clone())
+            .with_storage_path("path");
+        let options = builder.storage_options.unwrap();
+        assert_eq!(options.path(), Path::new("path"));
+        assert_eq!(options.encryption_key(), Some(&key));
+
+        let builder = WalletBuilder::<SecretManager>::new()
+            .with_storage_path("path")
+            .with_storage_options(storage_options);
+        let options = builder.storage_options.unwrap();
+        assert_eq!(options.path(), Path::new("options-path"));
+        assert_eq!(options.encryption_key(), Some(&key));
+
+        let builder = WalletBuilder::<SecretManager>::new().with_storage_path("path");
+        let options = builder.storage_options.unwrap();
+        assert_eq!(options.path(), Path::new("path"));
+        assert_eq!(options.encryption_key(), None);
+    }
+}
@@ -4,18 +4,22 @@
 pub(crate) mod builder;
 pub(crate) mod operations;
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU32, AtomicUsize},
+        Arc,
+    },
 };
 
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
+use futures::StreamExt;
 use tokio::sync::RwLock;
 
 pub use self::builder::WalletBuilder;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
-    types::{Event, WalletEventType},
+    types::{Event, SyncProgressEvent, WalletEvent, WalletEventType},
     EventEmitter,
 };
 #[cfg(feature = "storage")]
@@ -25,9 +29,21 @@ use crate::{
         secret::{SecretManage, SecretManager},
         verify_mnemonic, Client,
     },
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account},
+    wallet::{
+        account::{
+            builder::AccountBuilder,
+            operations::syncing::{SyncMetrics, SyncOptions},
+            types::{Balance, Transaction},
+            Account, AccountDetails, AccountFilterOptions,
+        },
+        address_book::AddressBook,
+        transfer_template::TransferTemplates,
+    },
 };
 
+/// The maximum number of accounts that are synced at the same time by [`Wallet::sync_all()`].
+const MAX_CONCURRENT_ACCOUNT_SYNCS: usize = 10;
+
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
 /// all share the same secret_manager type with the same seed/mnemonic.
 #[derive(Debug)]
@@ -74,9 +90,22 @@ where
 pub struct WalletInner<S: SecretManage = SecretManager> {
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) background_retrying_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) background_claiming_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) background_scheduling_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    #[cfg(feature = "mqtt")]
+    pub(crate) ledger_follower_status: AtomicUsize,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
+    pub(crate) address_book: RwLock<AddressBook>,
+    pub(crate) transfer_templates: RwLock<TransferTemplates>,
     pub(crate) secret_manager: Arc<RwLock<S>>,
+    // the aggregated sync reports, by account index
+    pub(crate) sync_metrics: RwLock<BTreeMap<u32, SyncMetrics>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
     #[cfg(feature = "storage")]
@@ -94,6 +123,27 @@ where
         Ok(self.accounts.read().await.clone())
     }
 
+    /// Get the accounts which match the filter, archived accounts are only returned if requested
+    pub async fn filter_accounts(&self, filter: AccountFilterOptions) -> crate::wallet::Result<Vec<Account<S>>> {
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            if filter.matches(&*account.details().await) {
+                accounts.push(account.clone());
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Recovers the transactions of all accounts that were signed with a request ID, but not stored, because the
+    /// wallet stopped in between. Should be called after restarting the wallet. Returns the recovered transactions.
+    pub async fn recover_pending_submissions(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        let mut recovered_transactions = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            recovered_transactions.extend(account.recover_pending_submissions().await?);
+        }
+        Ok(recovered_transactions)
+    }
+
     /// Get all account aliases
     pub async fn get_account_aliases(&self) -> crate::wallet::Result<Vec<String>> {
         let accounts = self.accounts.read().await;
@@ -164,6 +214,79 @@ where
 
         Ok(balance)
     }
+
+    /// Syncs all accounts that aren't archived concurrently, the most recently active accounts first. The requests of
+    /// all accounts together are limited by the `max_parallel_api_requests` of the client. A `SyncProgress` event is
+    /// emitted after each account. If accounts fail to sync, the others are still synced and the first error is
+    /// returned.
+    pub async fn sync_all(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            if !*account_details.archived() {
+                accounts.push((last_activity(&account_details), account.clone()));
+            }
+        }
+        accounts.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let total_accounts = accounts.len();
+        let mut synced_accounts = 0;
+        let mut failed_accounts = 0;
+        let mut balance = Balance::default();
+        let mut first_error = None;
+
+        let mut results = futures::stream::iter(accounts.into_iter().map(|(_, account)| {
+            let options = options.clone();
+            async move { (*account.details().await.index(), account.sync(options).await) }
+        }))
+        .buffer_unordered(MAX_CONCURRENT_ACCOUNT_SYNCS);
+
+        while let Some((account_index, result)) = results.next().await {
+            match result {
+                Ok(account_balance) => {
+                    balance += account_balance;
+                    synced_accounts += 1;
+                }
+                Err(err) => {
+                    log::debug!("[sync_all] account {account_index} failed to sync: {err}");
+                    failed_accounts += 1;
+                    first_error.get_or_insert(err);
+                }
+            }
+
+            #[cfg(feature = "events")]
+            self.emit(
+                account_index,
+                WalletEvent::SyncProgress(SyncProgressEvent {
+                    synced_accounts,
+                    failed_accounts,
+                    total_accounts,
+                }),
+            )
+            .await;
+        }
+
+        log::debug!("[sync_all] synced {synced_accounts}/{total_accounts} accounts, {failed_accounts} failed");
+
+        first_error.map_or(Ok(balance), Err)
+    }
+}
+
+/// Returns the time of the last transaction or received output of an account, in milliseconds.
+fn last_activity(account_details: &AccountDetails) -> u128 {
+    let last_transaction = account_details
+        .transactions()
+        .values()
+        .chain(account_details.incoming_transactions().values())
+        .map(|transaction| transaction.timestamp)
+        .max();
+    let last_output = account_details
+        .outputs()
+        .values()
+        .map(|output_data| output_data.metadata.milestone_timestamp_booked() as u128 * 1000)
+        .max();
+
+    last_transaction.max(last_output).unwrap_or_default()
 }
 
 impl<S: SecretManage> WalletInner<S> {
@@ -184,6 +307,25 @@ impl<S: SecretManage> WalletInner<S> {
         emitter.on(events, handler);
     }
 
+    /// Registers a listener that can be removed on its own with [`WalletInner::remove_listener()`].
+    #[cfg(feature = "events")]
+    pub(crate) async fn add_listener<F>(
+        &self,
+        events: impl IntoIterator<Item = WalletEventType> + Send,
+        handler: F,
+    ) -> u64
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        self.event_emitter.write().await.add_listener(events, handler)
+    }
+
+    /// Removes a listener registered with [`WalletInner::add_listener()`].
+    #[cfg(feature = "events")]
+    pub(crate) async fn remove_listener(&self, id: u64) {
+        self.event_emitter.write().await.remove_listener(id);
+    }
+
     /// Remove wallet event listeners, empty vec will remove all listeners
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
@@ -195,6 +337,11 @@ impl<S: SecretManage> WalletInner<S> {
         emitter.clear(events);
     }
 
+    /// Returns the sync reports of the accounts aggregated since the wallet was built, by account index.
+    pub async fn sync_metrics(&self) -> BTreeMap<u32, SyncMetrics> {
+        self.sync_metrics.read().await.clone()
+    }
+
     /// Generates a new random mnemonic.
     pub fn generate_mnemonic(&self) -> crate::wallet::Result<Mnemonic> {
         Ok(Client::generate_mnemonic()?)
@@ -206,9 +353,42 @@ impl<S: SecretManage> WalletInner<S> {
         Ok(())
     }
 
+    /// Appends the event to the event log in the storage, if there is one, and invokes the listeners of its type.
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
-        self.event_emitter.read().await.emit(account_index, event);
+        #[allow(unused_mut)]
+        let mut event = Event {
+            account_index,
+            seq: None,
+            event,
+        };
+
+        #[cfg(feature = "storage")]
+        match self.storage_manager.write().await.append_event(&event).await {
+            Ok(seq) => event.seq = Some(seq),
+            // Listeners still get the event, it just can't be replayed
+            Err(e) => log::warn!("[emit] couldn't append the event to the event log: {e}"),
+        }
+
+        self.event_emitter.read().await.emit_event(&event);
+    }
+
+    /// Returns the events of the event log with a sequence number of at least `from_seq`, in the order they were
+    /// emitted. A consumer that stores the sequence number of the last event it processed can resume from the next
+    /// one after it crashed or was restarted. Events before the first one that was kept, see
+    /// [`WalletInner::prune_events()`], can't be replayed anymore.
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    pub async fn replay_events(&self, from_seq: u64) -> crate::wallet::Result<Vec<Event>> {
+        self.storage_manager.read().await.get_events(from_seq).await
+    }
+
+    /// Removes the events with a sequence number lower than `before_seq` from the event log, e.g. after all consumers
+    /// processed them. Sequence numbers aren't reused.
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    pub async fn prune_events(&self, before_seq: u64) -> crate::wallet::Result<()> {
+        self.storage_manager.write().await.prune_events(before_seq).await
     }
 
     /// Helper function to test events. Emits a provided event with account index 0.
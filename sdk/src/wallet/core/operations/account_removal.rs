@@ -0,0 +1,126 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        account::types::{AccountIdentifier, Balance},
+        Error, Result, Wallet,
+    },
+};
+
+/// Options for [`Wallet::remove_account()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RemoveAccountOptions {
+    /// Removes the account even if it still holds funds or has pending transactions.
+    pub force: bool,
+    /// Clears the Stronghold key from memory after the account was removed, it has to be set again with the password
+    /// before the wallet can sign.
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    pub clear_stronghold_key: bool,
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Removes an account and all records stored for it. Accounts with funds according to their last sync or with
+    /// pending transactions are only removed with [`RemoveAccountOptions::force`], as the funds can only be accessed
+    /// again by recreating the account.
+    pub async fn remove_account(
+        &self,
+        identifier: impl Into<AccountIdentifier> + Send,
+        options: impl Into<Option<RemoveAccountOptions>> + Send,
+    ) -> Result<()> {
+        let options = options.into().unwrap_or_default();
+        let account = self.get_account(identifier).await?;
+        let account_index = *account.details().await.index();
+
+        if !options.force {
+            let balance = account.balance().await?;
+            let has_pending_transactions = !account.pending_transactions().await.is_empty();
+            if let Some(reason) = removal_refusal(&balance, has_pending_transactions) {
+                return Err(Error::AccountNotRemovable { account_index, reason });
+            }
+        }
+
+        let mut accounts = self.accounts.write().await;
+        let mut position = None;
+        for (i, account) in accounts.iter().enumerate() {
+            if *account.details().await.index() == account_index {
+                position = Some(i);
+                break;
+            }
+        }
+        if let Some(position) = position {
+            accounts.remove(position);
+        }
+        drop(accounts);
+
+        #[cfg(feature = "storage")]
+        self.storage_manager.write().await.remove_account(account_index).await?;
+
+        #[cfg(feature = "stronghold")]
+        if options.clear_stronghold_key {
+            use crate::client::secret::{stronghold::StrongholdSecretManager, DowncastSecretManager, SecretManager};
+
+            let secret_manager = self.secret_manager.read().await;
+            let stronghold = secret_manager.downcast::<StrongholdSecretManager>().or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::Stronghold(s) = s {
+                        Some(s)
+                    } else {
+                        None
+                    }
+                })
+            });
+            if let Some(stronghold) = stronghold {
+                stronghold.clear_key().await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Returns why an account can't be removed without force, funds are only accessible again by recreating the account
+fn removal_refusal(balance: &Balance, has_pending_transactions: bool) -> Option<&'static str> {
+    if balance.base_coin().total() > 0
+        || !balance.native_tokens().is_empty()
+        || !balance.nfts().is_empty()
+        || !balance.aliases().is_empty()
+        || !balance.foundries().is_empty()
+    {
+        Some("it holds funds")
+    } else if has_pending_transactions {
+        Some("it has pending transactions")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::output::NftId;
+
+    #[test]
+    fn removal_refusals() {
+        let empty = Balance::default();
+        assert_eq!(removal_refusal(&empty, false), None);
+        assert_eq!(removal_refusal(&empty, true), Some("it has pending transactions"));
+
+        let mut with_base_coin = Balance::default();
+        with_base_coin.base_coin.total = 1;
+        assert_eq!(removal_refusal(&with_base_coin, false), Some("it holds funds"));
+        assert_eq!(removal_refusal(&with_base_coin, true), Some("it holds funds"));
+
+        let mut with_nft = Balance::default();
+        with_nft.nfts.push(NftId::null());
+        assert_eq!(removal_refusal(&with_nft, false), Some("it holds funds"));
+    }
+}
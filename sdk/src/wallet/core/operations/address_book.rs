@@ -0,0 +1,77 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{
+        address_book::{AddressBook, Contact, Recipient},
+        core::WalletInner,
+    },
+};
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Returns the address book of the wallet.
+    pub async fn address_book(&self) -> AddressBook {
+        self.address_book.read().await.clone()
+    }
+
+    /// Adds a contact to the address book. Fails if the label or the address is already used by another contact.
+    pub async fn add_contact(
+        &self,
+        label: impl Into<String> + Send,
+        address: Bech32Address,
+    ) -> crate::wallet::Result<()> {
+        let mut address_book = self.address_book.write().await;
+        address_book.insert(Contact {
+            label: label.into(),
+            address,
+        })?;
+        self.save_address_book(&address_book).await
+    }
+
+    /// Removes a contact from the address book, returns its address.
+    pub async fn remove_contact(&self, label: &str) -> crate::wallet::Result<Option<Bech32Address>> {
+        let mut address_book = self.address_book.write().await;
+        let address = address_book.remove(label);
+        if address.is_some() {
+            self.save_address_book(&address_book).await?;
+        }
+        Ok(address)
+    }
+
+    /// Adds the given contacts to the address book. Nothing is imported if one of them collides with an existing
+    /// contact.
+    pub async fn import_contacts(
+        &self,
+        contacts: impl IntoIterator<Item = Contact> + Send,
+    ) -> crate::wallet::Result<()> {
+        let mut address_book = self.address_book.write().await;
+        address_book.import(contacts)?;
+        self.save_address_book(&address_book).await
+    }
+
+    /// Returns all contacts of the address book, to be imported in another wallet.
+    pub async fn export_contacts(&self) -> Vec<Contact> {
+        self.address_book.read().await.contacts()
+    }
+
+    /// Resolves a [`Recipient`] to an address, using the address book for contact labels.
+    pub async fn resolve_recipient(
+        &self,
+        recipient: impl Into<Recipient> + Send,
+    ) -> crate::wallet::Result<Bech32Address> {
+        self.address_book.read().await.resolve(recipient.into())
+    }
+
+    #[allow(unused_variables)]
+    async fn save_address_book(&self, address_book: &AddressBook) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .save_address_book(address_book)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use super::background_process::{restart_process, should_stop, spawn_process, stop_process, wait_interval, STOPPED};
+use crate::{
+    client::secret::SecretManage,
+    wallet::{account::AutoClaimPolicy, Wallet},
+};
+
+/// The default interval for background claiming
+pub(crate) const DEFAULT_BACKGROUNDCLAIMING_INTERVAL: Duration = Duration::from_secs(60);
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start the background process that claims the outputs of all accounts that match the policy, default interval
+    /// is 60 seconds. Uses the outputs of the last sync, so it should run together with background syncing.
+    pub async fn start_background_claiming(
+        &self,
+        policy: Option<AutoClaimPolicy>,
+        interval: Option<Duration>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[start_background_claiming]");
+        restart_process("background_claiming", &self.background_claiming_status).await;
+
+        let wallet = self.clone();
+        let policy = policy.unwrap_or_default();
+        let interval = interval.unwrap_or(DEFAULT_BACKGROUNDCLAIMING_INTERVAL);
+        spawn_process(move || async move {
+            'outer: loop {
+                log::debug!("[background_claiming]: claiming outputs");
+                for account in wallet.accounts.read().await.iter() {
+                    // Check if the process should stop before claiming for each account so it stops faster
+                    if should_stop(&wallet.background_claiming_status) {
+                        break 'outer;
+                    }
+                    // Watch-only accounts can't sign the claiming transactions
+                    {
+                        let account_details = account.details().await;
+                        if *account_details.archived() || *account_details.watch_only() {
+                            continue;
+                        }
+                    }
+                    if let Err(err) = account.auto_claim_outputs(&policy).await {
+                        log::debug!("[background_claiming] error: {}", err);
+                    }
+                }
+                if !wait_interval(&wallet.background_claiming_status, interval).await {
+                    break;
+                }
+            }
+            wallet.background_claiming_status.store(STOPPED, Ordering::Relaxed);
+            log::debug!("[background_claiming]: stopped");
+        });
+        Ok(())
+    }
+
+    /// Stop the background claiming of outputs
+    pub async fn stop_background_claiming(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_background_claiming]");
+        stop_process(&self.background_claiming_status).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers shared by the background processes of the wallet (syncing, retrying, claiming and scheduling), which run in
+//! a loop on their own thread and runtime until their status is set to [`STOPPING`].
+
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// The status of a background process that isn't running.
+pub(crate) const STOPPED: usize = 0;
+/// The status of a running background process.
+pub(crate) const RUNNING: usize = 1;
+/// The status of a background process that was requested to stop.
+pub(crate) const STOPPING: usize = 2;
+
+/// Stops the process with the given status if it's running and marks it as running again, must be called before
+/// [`spawn_process()`].
+pub(crate) async fn restart_process(name: &str, status: &AtomicUsize) {
+    log::debug!("[{name}]: stopping the old process if running");
+    stop_process(status).await;
+    status.store(RUNNING, Ordering::Relaxed);
+}
+
+/// Requests the process with the given status to stop and waits until it stopped.
+pub(crate) async fn stop_process(status: &AtomicUsize) {
+    // immediately return if not running
+    if status.load(Ordering::Relaxed) == STOPPED {
+        return;
+    }
+    // send stop request
+    status.store(STOPPING, Ordering::Relaxed);
+    // wait until it stopped
+    while status.load(Ordering::Relaxed) != STOPPED {
+        #[cfg(target_family = "wasm")]
+        gloo_timers::future::TimeoutFuture::new(10).await;
+        #[cfg(not(target_family = "wasm"))]
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+/// Returns whether the process with the given status was requested to stop.
+pub(crate) fn should_stop(status: &AtomicUsize) -> bool {
+    status.load(Ordering::Relaxed) == STOPPING
+}
+
+/// Waits for the interval in steps of one second, so stopping the process doesn't have to wait long. Returns `false`
+/// if the process was requested to stop.
+pub(crate) async fn wait_interval(status: &AtomicUsize, interval: Duration) -> bool {
+    for _ in 0..interval.as_secs() {
+        if should_stop(status) {
+            return false;
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    !should_stop(status)
+}
+
+/// Runs the future returned by `process` on a new thread with its own runtime, so it neither blocks nor depends on the
+/// runtime of the caller. The process has to mark its status as [`STOPPED`] once it returns.
+pub(crate) fn spawn_process<F, Fut>(process: F)
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()>,
+{
+    std::thread::spawn(move || {
+        #[cfg(not(target_family = "wasm"))]
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        #[cfg(target_family = "wasm")]
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(process());
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_interval_stops_early() {
+        let status = AtomicUsize::new(RUNNING);
+        assert!(wait_interval(&status, Duration::ZERO).await);
+
+        status.store(STOPPING, Ordering::Relaxed);
+        // Returns before the first second elapsed
+        let start = std::time::Instant::now();
+        assert!(!wait_interval(&status, Duration::from_secs(60)).await);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn stop_and_restart_process() {
+        let status = Arc::new(AtomicUsize::new(STOPPED));
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        // Stopping a process that isn't running returns immediately
+        stop_process(&status).await;
+        assert_eq!(status.load(Ordering::Relaxed), STOPPED);
+
+        for expected_runs in 1..=2 {
+            restart_process("test", &status).await;
+            let (process_status, process_runs) = (status.clone(), runs.clone());
+            spawn_process(move || async move {
+                process_runs.fetch_add(1, Ordering::Relaxed);
+                while wait_interval(&process_status, Duration::ZERO).await {
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                }
+                process_status.store(STOPPED, Ordering::Relaxed);
+            });
+            assert_eq!(status.load(Ordering::Relaxed), RUNNING);
+            // Wait until the process started so the count is deterministic
+            while runs.load(Ordering::Relaxed) != expected_runs {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        }
+
+        stop_process(&status).await;
+        assert_eq!(status.load(Ordering::Relaxed), STOPPED);
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, sync::atomic::Ordering, time::Duration};
+
+use super::background_process::{restart_process, should_stop, spawn_process, stop_process, wait_interval, STOPPED};
+use crate::{
+    client::secret::SecretManage,
+    wallet::{account::RetryOptions, Wallet},
+};
+
+/// The default interval for background retrying
+pub(crate) const DEFAULT_BACKGROUNDRETRYING_INTERVAL: Duration = Duration::from_secs(5);
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start the background process that promotes and reattaches pending transactions of all accounts, default
+    /// interval is 5 seconds
+    pub async fn start_background_retrying(
+        &self,
+        options: Option<RetryOptions>,
+        interval: Option<Duration>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[start_background_retrying]");
+        restart_process("background_retrying", &self.background_retrying_status).await;
+
+        let wallet = self.clone();
+        let options = options.unwrap_or_default();
+        let interval = interval.unwrap_or(DEFAULT_BACKGROUNDRETRYING_INTERVAL);
+        spawn_process(move || async move {
+            // attachments of pending blocks per account index
+            let mut attachments = HashMap::new();
+            'outer: loop {
+                log::debug!("[background_retrying]: checking pending transactions");
+                for account in wallet.accounts.read().await.iter() {
+                    // Check if the process should stop before checking each account so it stops faster
+                    if should_stop(&wallet.background_retrying_status) {
+                        break 'outer;
+                    }
+                    let account_index = *account.details().await.index();
+                    if let Err(err) = account
+                        .retry_pending_transactions(&options, attachments.entry(account_index).or_default())
+                        .await
+                    {
+                        log::debug!("[background_retrying] error: {}", err);
+                    }
+                }
+                if !wait_interval(&wallet.background_retrying_status, interval).await {
+                    break;
+                }
+            }
+            wallet.background_retrying_status.store(STOPPED, Ordering::Relaxed);
+            log::debug!("[background_retrying]: stopped");
+        });
+        Ok(())
+    }
+
+    /// Stop the background retrying of pending transactions
+    pub async fn stop_background_retrying(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_background_retrying]");
+        stop_process(&self.background_retrying_status).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use super::background_process::{restart_process, should_stop, spawn_process, stop_process, wait_interval, STOPPED};
+use crate::{client::secret::SecretManage, wallet::Wallet};
+
+/// The default interval for background scheduling
+pub(crate) const DEFAULT_BACKGROUNDSCHEDULING_INTERVAL: Duration = Duration::from_secs(10);
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start the background process that sends the due scheduled sends of all accounts, default interval is 10
+    /// seconds. The balance is the one of the last sync, so it should run together with background syncing.
+    pub async fn start_background_scheduling(&self, interval: Option<Duration>) -> crate::wallet::Result<()> {
+        log::debug!("[start_background_scheduling]");
+        restart_process("background_scheduling", &self.background_scheduling_status).await;
+
+        let wallet = self.clone();
+        let interval = interval.unwrap_or(DEFAULT_BACKGROUNDSCHEDULING_INTERVAL);
+        spawn_process(move || async move {
+            'outer: loop {
+                log::debug!("[background_scheduling]: sending due scheduled sends");
+                for account in wallet.accounts.read().await.iter() {
+                    // Check if the process should stop before sending for each account so it stops faster
+                    if should_stop(&wallet.background_scheduling_status) {
+                        break 'outer;
+                    }
+                    if *account.details().await.archived() {
+                        continue;
+                    }
+                    if let Err(err) = account.send_due_scheduled_sends().await {
+                        log::debug!("[background_scheduling] error: {}", err);
+                    }
+                }
+                if !wait_interval(&wallet.background_scheduling_status, interval).await {
+                    break;
+                }
+            }
+            wallet.background_scheduling_status.store(STOPPED, Ordering::Relaxed);
+            log::debug!("[background_scheduling]: stopped");
+        });
+        Ok(())
+    }
+
+    /// Stop the background sending of scheduled sends
+    pub async fn stop_background_scheduling(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_background_scheduling]");
+        stop_process(&self.background_scheduling_status).await;
+        Ok(())
+    }
+}
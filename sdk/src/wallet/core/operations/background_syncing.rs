@@ -3,8 +3,7 @@
 
 use std::{sync::atomic::Ordering, time::Duration};
 
-use tokio::time::sleep;
-
+use super::background_process::{restart_process, should_stop, spawn_process, stop_process, wait_interval, STOPPED};
 use crate::{
     client::secret::SecretManage,
     wallet::{account::operations::syncing::SyncOptions, Wallet},
@@ -24,55 +23,32 @@ where
         interval: Option<Duration>,
     ) -> crate::wallet::Result<()> {
         log::debug!("[start_background_syncing]");
-        // stop existing process if running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 1 {
-            self.background_syncing_status.store(2, Ordering::Relaxed);
-        };
-        while self.background_syncing_status.load(Ordering::Relaxed) == 2 {
-            log::debug!("[background_syncing]: waiting for the old process to stop");
-            sleep(Duration::from_secs(1)).await;
-        }
+        restart_process("background_syncing", &self.background_syncing_status).await;
 
-        self.background_syncing_status.store(1, Ordering::Relaxed);
         let wallet = self.clone();
-        let _background_syncing = std::thread::spawn(move || {
-            #[cfg(not(target_family = "wasm"))]
-            let runtime = tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .build()
-                .unwrap();
-            #[cfg(target_family = "wasm")]
-            let runtime = tokio::runtime::Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .unwrap();
-            runtime.block_on(async {
-                'outer: loop {
-                    log::debug!("[background_syncing]: syncing accounts");
-                    for account in wallet.accounts.read().await.iter() {
-                        // Check if the process should stop before syncing each account so it stops faster
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
-                        }
-                        match account.sync(options.clone()).await {
-                            Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
-                        };
+        let interval = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL);
+        spawn_process(move || async move {
+            'outer: loop {
+                log::debug!("[background_syncing]: syncing accounts");
+                for account in wallet.accounts.read().await.iter() {
+                    // Check if the process should stop before syncing each account so it stops faster
+                    if should_stop(&wallet.background_syncing_status) {
+                        break 'outer;
                     }
-                    // split interval syncing to seconds so stopping the process doesn't have to wait long
-                    let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
-                    for _ in 0..seconds {
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
-                        }
-                        sleep(Duration::from_secs(1)).await;
+                    if *account.details().await.archived() {
+                        continue;
                     }
+                    match account.sync(options.clone()).await {
+                        Ok(_) => {}
+                        Err(err) => log::debug!("[background_syncing] error: {}", err),
+                    };
+                }
+                if !wait_interval(&wallet.background_syncing_status, interval).await {
+                    break;
                 }
-                wallet.background_syncing_status.store(0, Ordering::Relaxed);
-                log::debug!("[background_syncing]: stopped");
-            });
+            }
+            wallet.background_syncing_status.store(STOPPED, Ordering::Relaxed);
+            log::debug!("[background_syncing]: stopped");
         });
         Ok(())
     }
@@ -80,19 +56,7 @@ where
     /// Stop the background syncing of the accounts
     pub async fn stop_background_syncing(&self) -> crate::wallet::Result<()> {
         log::debug!("[stop_background_syncing]");
-        // immediately return if not running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 0 {
-            return Ok(());
-        }
-        // send stop request
-        self.background_syncing_status.store(2, Ordering::Relaxed);
-        // wait until it stopped
-        while self.background_syncing_status.load(Ordering::Relaxed) != 0 {
-            #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(10).await;
-            #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
-        }
+        stop_process(&self.background_syncing_status).await;
         Ok(())
     }
 }
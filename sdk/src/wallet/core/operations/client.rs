@@ -33,48 +33,14 @@ where
     WalletBuilder<S>: SaveLoadWallet,
 {
     pub async fn set_client_options(&self, client_options: ClientBuilder) -> crate::wallet::Result<()> {
-        let ClientBuilder {
-            node_manager_builder,
-            #[cfg(feature = "mqtt")]
-            broker_options,
-            mut network_info,
-            api_timeout,
-            remote_pow_timeout,
-            #[cfg(not(target_family = "wasm"))]
-            pow_worker_count,
-            #[cfg(not(target_family = "wasm"))]
-            max_parallel_api_requests,
-        } = client_options;
-
-        // Only check bech32 if something in the node_manager_builder changed
-        let change_in_node_manager = self.client_options().await.node_manager_builder != node_manager_builder;
-
-        self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
-            .await?;
-        *self.client.api_timeout.write().await = api_timeout;
-        *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
-        #[cfg(not(target_family = "wasm"))]
-        self.client.request_pool.resize(max_parallel_api_requests).await;
-        #[cfg(not(target_family = "wasm"))]
-        {
-            *self.client.pow_worker_count.write().await = pow_worker_count;
-        }
-        #[cfg(feature = "mqtt")]
-        {
-            *self.client.mqtt.broker_options.write().await = broker_options;
-        }
+        let change_in_node_manager = update_client(&self.client, client_options).await?;
 
         if change_in_node_manager {
-            // Update the protocol of the network_info to not have the default data, which can be wrong
-            // Ignore errors, because there might be no node at all and then it should still not error
-            if let Ok(info) = self.client.get_info().await {
-                network_info.protocol_parameters = info.node_info.protocol;
-            }
-            *self.client.network_info.write().await = network_info;
-
             for account in self.accounts.write().await.iter_mut() {
-                account.update_account_bech32_hrp().await?;
+                // Accounts bound to another network keep their addresses
+                if account.uses_wallet_client() {
+                    account.update_account_bech32_hrp().await?;
+                }
             }
         }
 
@@ -176,9 +142,71 @@ where
             .await?;
 
         for account in self.accounts.write().await.iter_mut() {
-            account.update_account_bech32_hrp().await?;
+            if account.uses_wallet_client() {
+                account.update_account_bech32_hrp().await?;
+            }
         }
 
         Ok(())
     }
 }
+
+/// Applies client options to an existing client, so everything holding it uses the new options. Returns whether the
+/// nodes changed, in which case the network info was updated from them.
+pub(crate) async fn update_client(client: &Client, client_options: ClientBuilder) -> crate::wallet::Result<bool> {
+    let ClientBuilder {
+        node_manager_builder,
+        #[cfg(feature = "mqtt")]
+        broker_options,
+        mut network_info,
+        api_timeout,
+        remote_pow_timeout,
+        retry_policy,
+        pow_server,
+        #[cfg(not(target_family = "wasm"))]
+        pow_worker_count,
+        #[cfg(not(target_family = "wasm"))]
+        pow_worker_niceness,
+        #[cfg(all(feature = "rayon", not(target_family = "wasm")))]
+        pow_thread_pool,
+        #[cfg(not(target_family = "wasm"))]
+        max_parallel_api_requests,
+    } = client_options;
+
+    // Only check bech32 if something in the node_manager_builder changed
+    let change_in_node_manager = ClientBuilder::from_client(client).await.node_manager_builder != node_manager_builder;
+
+    client
+        .update_node_manager(node_manager_builder.build(HashMap::new()))
+        .await?;
+    *client.api_timeout.write().await = api_timeout;
+    *client.remote_pow_timeout.write().await = remote_pow_timeout;
+    client.set_retry_policy(retry_policy).await;
+    *client.pow_server.write().await = pow_server.map(Into::into);
+    #[cfg(not(target_family = "wasm"))]
+    client.request_pool.resize(max_parallel_api_requests).await;
+    #[cfg(not(target_family = "wasm"))]
+    {
+        *client.pow_worker_count.write().await = pow_worker_count;
+        *client.pow_worker_niceness.write().await = pow_worker_niceness;
+        #[cfg(feature = "rayon")]
+        {
+            *client.pow_thread_pool.write().await = pow_thread_pool;
+        }
+    }
+    #[cfg(feature = "mqtt")]
+    {
+        *client.mqtt.broker_options.write().await = broker_options;
+    }
+
+    if change_in_node_manager {
+        // Update the protocol of the network_info to not have the default data, which can be wrong
+        // Ignore errors, because there might be no node at all and then it should still not error
+        if let Ok(info) = client.get_info().await {
+            network_info.protocol_parameters = info.node_info.protocol;
+        }
+        *client.network_info.write().await = network_info;
+    }
+
+    Ok(change_in_node_manager)
+}
@@ -0,0 +1,116 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Address,
+    wallet::{
+        account::{builder::get_first_public_address, Account, AccountDetails},
+        migration::interchange::{AccountInterchange, WalletInterchange},
+        Error, Wallet,
+    },
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Exports the accounts with their addresses and transaction history to a file in the
+    /// [interchange format](crate::wallet::migration::interchange), which other wallet implementations can read.
+    pub async fn export_interchange(&self, path: impl AsRef<Path> + Send) -> crate::wallet::Result<()> {
+        log::debug!("[export_interchange]");
+        let mut interchange = WalletInterchange::default();
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            interchange.accounts.push(AccountInterchange {
+                index: *account_details.index(),
+                coin_type: *account_details.coin_type(),
+                bip44_account_index: *account_details.bip44_account_index(),
+                alias: account_details.alias().clone(),
+                metadata: account_details
+                    .metadata()
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+                addresses: account_details
+                    .public_addresses()
+                    .iter()
+                    .chain(account_details.internal_addresses())
+                    .cloned()
+                    .collect(),
+                transactions: account_details
+                    .transactions()
+                    .values()
+                    .chain(account_details.incoming_transactions().values())
+                    .cloned()
+                    .collect(),
+            });
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        interchange.write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Imports the accounts of a file in the [interchange format](crate::wallet::migration::interchange) into a wallet
+    /// without accounts. The addresses of the accounts have to be derived from the seed of the secret manager. The
+    /// accounts use the client of the wallet, their outputs are available after syncing.
+    pub async fn import_interchange(&self, path: impl AsRef<Path> + Send) -> crate::wallet::Result<()> {
+        log::debug!("[import_interchange]");
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let interchange = WalletInterchange::read(&mut BufReader::new(File::open(path)?), &protocol_parameters)?;
+
+        let mut accounts = self.accounts.write().await;
+        if !accounts.is_empty() {
+            return Err(Error::InvalidInterchange(
+                "accounts can only be imported into a wallet without accounts".to_string(),
+            ));
+        }
+
+        let mut imported_accounts = Vec::with_capacity(interchange.accounts.len());
+        let mut previous_index = None;
+        for account in interchange.accounts {
+            // Accounts are ordered by their index, removed accounts leave gaps
+            if previous_index.map_or(false, |previous_index| account.index <= previous_index) {
+                return Err(Error::InvalidInterchange(format!(
+                    "account index {} isn't unique or out of order",
+                    account.index
+                )));
+            }
+            previous_index = Some(account.index);
+            if let Some(first_address) = account
+                .addresses
+                .iter()
+                .find(|address| !address.internal && address.key_index == 0)
+            {
+                let first_public_address =
+                    get_first_public_address(&self.secret_manager, account.coin_type, account.bip44_account_index)
+                        .await?;
+                if Address::Ed25519(first_public_address) != *first_address.address.inner() {
+                    return Err(Error::InvalidMnemonic(format!(
+                        "account {} was created with another seed",
+                        account.alias
+                    )));
+                }
+            }
+            imported_accounts.push(AccountDetails::from(account));
+        }
+
+        for account_details in imported_accounts {
+            let account = Account::new(account_details, self.inner.clone(), None).await?;
+            #[cfg(feature = "storage")]
+            account.save(None).await?;
+            accounts.push(account);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use tokio::{sync::mpsc, time::sleep};
+
+use crate::{
+    client::{
+        node_api::mqtt::{MqttPayload, Topic},
+        secret::SecretManage,
+        Error as ClientError,
+    },
+    wallet::Wallet,
+};
+
+/// The maximum number of missed milestones that are applied one by one, if more were missed the accounts are synced
+/// instead
+const LEDGER_FOLLOWER_MAX_MILESTONE_GAP: u32 = 30;
+
+/// Applies the UTXO changes of confirmed milestones to the accounts of a wallet.
+struct LedgerFollower<S: SecretManage> {
+    wallet: Wallet<S>,
+    /// The index of the last milestone that was applied
+    milestone_index: Option<u32>,
+}
+
+impl<S: 'static + SecretManage> LedgerFollower<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Applies the milestone and the ones that were missed since the last applied milestone.
+    async fn apply_milestone(&mut self, milestone_index: u32) -> crate::wallet::Result<()> {
+        let first_milestone_index = match self.milestone_index {
+            // Events can arrive more than once or out of order
+            Some(last_milestone_index) if milestone_index <= last_milestone_index => return Ok(()),
+            Some(last_milestone_index)
+                if milestone_index - last_milestone_index > LEDGER_FOLLOWER_MAX_MILESTONE_GAP =>
+            {
+                log::debug!("[ledger_follower]: missed too many milestones, syncing accounts");
+                for account in self.wallet.accounts.read().await.iter() {
+                    if account.uses_wallet_client() && !*account.details().await.archived() {
+                        account.sync(None).await?;
+                    }
+                }
+                self.milestone_index = Some(milestone_index);
+                return Ok(());
+            }
+            Some(last_milestone_index) => last_milestone_index + 1,
+            None => milestone_index,
+        };
+
+        for index in first_milestone_index..=milestone_index {
+            log::debug!("[ledger_follower]: applying milestone {index}");
+            let utxo_changes = self.wallet.client().get_utxo_changes_by_index(index).await?;
+            let created_outputs = self.wallet.client().get_outputs(&utxo_changes.created_outputs).await?;
+
+            for account in self.wallet.accounts.read().await.iter() {
+                // Accounts bound to another network don't follow the ledger of the wallet's network
+                if !account.uses_wallet_client() || *account.details().await.archived() {
+                    continue;
+                }
+                account
+                    .apply_ledger_changes(&created_outputs, &utxo_changes.consumed_outputs)
+                    .await?;
+            }
+            self.milestone_index = Some(index);
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start following the ledger: the UTXO changes of every confirmed milestone reported by MQTT are applied to the
+    /// accounts, so their balances update right after a milestone without syncing. Outputs of addresses that weren't
+    /// generated yet and the inclusion of pending transactions are only found by syncing, so the accounts should be
+    /// synced before and background syncing can run with a longer interval at the same time.
+    ///
+    /// The MQTT subscription isn't removed when the ledger follower is stopped, see [`Client::unsubscribe()`].
+    ///
+    /// [`Client::unsubscribe()`]: crate::client::Client::unsubscribe
+    pub async fn start_ledger_follower(&self) -> crate::wallet::Result<()> {
+        log::debug!("[start_ledger_follower]");
+        // stop existing process if running
+        if self.ledger_follower_status.load(Ordering::Relaxed) == 1 {
+            self.ledger_follower_status.store(2, Ordering::Relaxed);
+        };
+        while self.ledger_follower_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[ledger_follower]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        self.client()
+            .subscribe([Topic::ConfirmedMilestoneInfo], move |event| {
+                if let MqttPayload::Json(json) = &event.payload {
+                    if let Some(milestone_index) = json["index"].as_u64() {
+                        // Fails once the ledger follower stopped
+                        sender.send(milestone_index as u32).ok();
+                    }
+                }
+            })
+            .await
+            .map_err(ClientError::from)?;
+
+        self.ledger_follower_status.store(1, Ordering::Relaxed);
+        let mut ledger_follower = LedgerFollower {
+            wallet: self.clone(),
+            milestone_index: None,
+        };
+        let _ledger_follower = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let wallet = ledger_follower.wallet.clone();
+                while wallet.ledger_follower_status.load(Ordering::Relaxed) != 2 {
+                    // Wait at most a second so stopping the process doesn't have to wait long
+                    match tokio::time::timeout(Duration::from_secs(1), receiver.recv()).await {
+                        Ok(Some(milestone_index)) => {
+                            if let Err(err) = ledger_follower.apply_milestone(milestone_index).await {
+                                log::debug!("[ledger_follower] error: {}", err);
+                            }
+                        }
+                        Ok(None) => break,
+                        Err(_) => {}
+                    }
+                }
+                wallet.ledger_follower_status.store(0, Ordering::Relaxed);
+                log::debug!("[ledger_follower]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop following the ledger
+    pub async fn stop_ledger_follower(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_ledger_follower]");
+        // immediately return if not running
+        if self.ledger_follower_status.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        // send stop request
+        self.ledger_follower_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.ledger_follower_status.load(Ordering::Relaxed) != 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
@@ -2,16 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+pub(crate) mod account_removal;
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
+pub(crate) mod background_claiming;
+pub(crate) mod background_process;
+pub(crate) mod background_retrying;
+pub(crate) mod background_scheduling;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
 pub(crate) mod get_account;
+#[cfg(any(feature = "storage", feature = "stronghold"))]
+pub(crate) mod interchange;
+#[cfg(feature = "mqtt")]
+pub(crate) mod ledger_follower;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+pub(crate) mod snapshot;
 pub(crate) mod storage;
+pub(crate) mod transfer_template;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold_backup;
 #[cfg(debug_assertions)]
 pub(crate) mod verify_integrity;
+pub(crate) mod watch_only;
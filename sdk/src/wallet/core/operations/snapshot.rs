@@ -0,0 +1,90 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, fs::File, io::BufReader, path::Path};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{address::Address, output::Output},
+    wallet::{snapshot::LedgerSnapshot, Wallet},
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Imports the outputs of all accounts from a node ledger snapshot, so balances are available without syncing.
+    /// Milestone diffs of the optional delta snapshot are applied on top of the full snapshot. Only addresses that are
+    /// already generated are considered, a following sync reconciles the outputs with the current ledger state.
+    pub async fn import_ledger_snapshot(
+        &self,
+        full_snapshot_path: impl AsRef<Path> + Send,
+        delta_snapshot_path: Option<impl AsRef<Path> + Send>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[import_ledger_snapshot]");
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let accounts = self.accounts.read().await;
+
+        let mut addresses = HashSet::new();
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+            addresses.extend(
+                account_details
+                    .public_addresses()
+                    .iter()
+                    .chain(account_details.internal_addresses())
+                    .map(|address| *address.address().inner()),
+            );
+        }
+        let full_snapshot_path = full_snapshot_path.as_ref().to_path_buf();
+        let delta_snapshot_path = delta_snapshot_path.map(|path| path.as_ref().to_path_buf());
+
+        // Snapshots can be gigabytes large, they're read without blocking the executor
+        let (full_snapshot, delta_snapshot) = crate::client::runtime::spawn_blocking(move || {
+            let filter = |output: &Output| output_addresses(output).any(|address| addresses.contains(address));
+            let full_snapshot = LedgerSnapshot::read(
+                &mut BufReader::new(File::open(full_snapshot_path)?),
+                &protocol_parameters,
+                filter,
+            )?;
+            let delta_snapshot = delta_snapshot_path
+                .map(|path| LedgerSnapshot::read(&mut BufReader::new(File::open(path)?), &protocol_parameters, filter))
+                .transpose()?;
+            crate::wallet::Result::Ok((full_snapshot, delta_snapshot))
+        })
+        .await??;
+
+        if let Some(delta_snapshot) = &delta_snapshot {
+            if delta_snapshot.header.full_snapshot_target_milestone_id != Some(full_snapshot.header.target_milestone_id)
+            {
+                return Err(crate::wallet::Error::InvalidSnapshot(
+                    "delta snapshot doesn't belong to the full snapshot".to_string(),
+                ));
+            }
+        }
+
+        let unspent_outputs = full_snapshot.unspent_outputs(delta_snapshot.as_ref());
+        log::debug!(
+            "[import_ledger_snapshot] found {} outputs at milestone {}",
+            unspent_outputs.len(),
+            full_snapshot.ledger_index(delta_snapshot.as_ref())
+        );
+
+        for account in accounts.iter() {
+            account.import_snapshot_outputs(&unspent_outputs).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the addresses that can unlock an output.
+pub(crate) fn output_addresses(output: &Output) -> impl Iterator<Item = &Address> {
+    let unlock_conditions = output.unlock_conditions();
+    unlock_conditions
+        .and_then(|u| u.address().map(|u| u.address()))
+        .into_iter()
+        .chain(unlock_conditions.and_then(|u| u.state_controller_address().map(|u| u.address())))
+        .chain(unlock_conditions.and_then(|u| u.governor_address().map(|u| u.address())))
+        .chain(unlock_conditions.and_then(|u| u.expiration().map(|u| u.return_address())))
+}
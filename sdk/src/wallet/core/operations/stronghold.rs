@@ -1,10 +1,28 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "storage")]
+use std::path::Path;
 use std::time::Duration;
 
 use crypto::keys::bip39::Mnemonic;
+#[cfg(feature = "storage")]
+use zeroize::Zeroizing;
 
+#[cfg(feature = "storage")]
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        storage::{
+            constants::{
+                ACCOUNTS_INDEXATION_KEY, DATABASE_SCHEMA_VERSION_KEY, WALLET_INDEXATION_KEY,
+                WRAPPED_STORAGE_KEY_FILENAME,
+            },
+            Storage, StorageOptions,
+        },
+        WalletBuilder,
+    },
+};
 use crate::{
     client::{secret::SecretManager, stronghold::StrongholdAdapter, utils::Password},
     wallet::Wallet,
@@ -61,6 +79,19 @@ impl Wallet {
         }
     }
 
+    /// Wraps the storage encryption key with a new key in Stronghold and removes the previous one. The records don't
+    /// need to be encrypted again, so this is cheap enough to be done regularly.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn rotate_storage_key_encryption_key(&self) -> crate::wallet::Result<()> {
+        log::debug!("[rotate_storage_key_encryption_key]");
+        if let SecretManager::Stronghold(stronghold) = &*self.secret_manager.read().await {
+            rotate_storage_key_encryption_key(stronghold, self.storage_options.path()).await
+        } else {
+            Err(crate::client::Error::SecretManagerMismatch.into())
+        }
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
@@ -112,6 +143,15 @@ impl Wallet<StrongholdAdapter> {
         Ok(self.secret_manager.write().await.store_mnemonic(mnemonic).await?)
     }
 
+    /// Wraps the storage encryption key with a new key in Stronghold and removes the previous one. The records don't
+    /// need to be encrypted again, so this is cheap enough to be done regularly.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn rotate_storage_key_encryption_key(&self) -> crate::wallet::Result<()> {
+        log::debug!("[rotate_storage_key_encryption_key]");
+        rotate_storage_key_encryption_key(&*self.secret_manager.read().await, self.storage_options.path()).await
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
@@ -125,3 +165,121 @@ impl Wallet<StrongholdAdapter> {
         Ok(self.secret_manager.write().await.is_key_available().await)
     }
 }
+
+#[cfg(feature = "storage")]
+impl WalletBuilder {
+    /// Encrypts the storage with a key that is stored wrapped by a key in the Stronghold of the secret manager, so the
+    /// records don't leak anything without the Stronghold snapshot and its password. A new storage gets a new key, an
+    /// existing storage can only be opened if it was created with this option. Must be called after the storage path
+    /// and the Stronghold password have been set.
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn with_stronghold_storage_encryption(mut self) -> crate::wallet::Result<Self> {
+        let encryption_key = match &*self
+            .secret_manager
+            .as_ref()
+            .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?
+            .read()
+            .await
+        {
+            SecretManager::Stronghold(stronghold) => {
+                load_or_generate_storage_key(stronghold, self.storage_options.get_or_insert_with(Default::default))
+                    .await?
+            }
+            _ => return Err(crate::client::Error::SecretManagerMismatch.into()),
+        };
+        self.storage_options.get_or_insert_with(Default::default).encryption_key = Some(encryption_key);
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "storage")]
+impl WalletBuilder<StrongholdAdapter> {
+    /// Encrypts the storage with a key that is stored wrapped by a key in the Stronghold of the secret manager, so the
+    /// records don't leak anything without the Stronghold snapshot and its password. A new storage gets a new key, an
+    /// existing storage can only be opened if it was created with this option. Must be called after the storage path
+    /// and the Stronghold password have been set.
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn with_stronghold_storage_encryption(mut self) -> crate::wallet::Result<Self> {
+        let encryption_key = load_or_generate_storage_key(
+            &*self
+                .secret_manager
+                .as_ref()
+                .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?
+                .read()
+                .await,
+            self.storage_options.get_or_insert_with(Default::default),
+        )
+        .await?;
+        self.storage_options.get_or_insert_with(Default::default).encryption_key = Some(encryption_key);
+        Ok(self)
+    }
+}
+
+/// Unwraps the storage encryption key of the storage of the options, or generates one for a new storage.
+#[cfg(feature = "storage")]
+async fn load_or_generate_storage_key(
+    stronghold: &StrongholdAdapter,
+    storage_options: &StorageOptions,
+) -> crate::wallet::Result<Zeroizing<[u8; 32]>> {
+    let storage_path = storage_options.path();
+    let wrapped_key_path = storage_path.join(WRAPPED_STORAGE_KEY_FILENAME);
+
+    if wrapped_key_path.is_file() {
+        return Ok(stronghold.unwrap_data_key(&std::fs::read(wrapped_key_path)?).await?);
+    }
+    // Records that were stored without the key couldn't be read anymore
+    if has_storage_records(storage_options).await? {
+        return Err(crate::wallet::Error::Storage(format!(
+            "{} wasn't created with a storage key in Stronghold",
+            storage_path.display()
+        )));
+    }
+
+    let (storage_key, wrapped_key) = stronghold.generate_data_key().await?;
+    std::fs::create_dir_all(storage_path)?;
+    std::fs::write(wrapped_key_path, wrapped_key)?;
+
+    Ok(storage_key)
+}
+
+/// Returns whether the storage contains wallet records, other files in the storage folder don't matter.
+#[cfg(feature = "storage")]
+async fn has_storage_records(storage_options: &StorageOptions) -> crate::wallet::Result<bool> {
+    if !storage_options.path().is_dir() {
+        return Ok(false);
+    }
+    // The storage is dropped before returning, so the wallet can open it again
+    let storage = Storage::from_options(&StorageOptions {
+        encryption_key: None,
+        ..storage_options.clone()
+    })?;
+    for key in [
+        WALLET_INDEXATION_KEY,
+        ACCOUNTS_INDEXATION_KEY,
+        DATABASE_SCHEMA_VERSION_KEY,
+    ] {
+        if storage.get_bytes(key).await?.is_some() {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(feature = "storage")]
+async fn rotate_storage_key_encryption_key(
+    stronghold: &StrongholdAdapter,
+    storage_path: &Path,
+) -> crate::wallet::Result<()> {
+    let wrapped_key_path = storage_path.join(WRAPPED_STORAGE_KEY_FILENAME);
+    let wrapped_key = std::fs::read(&wrapped_key_path)?;
+    let rotated_key = stronghold.rotate_key_encryption_key(&wrapped_key).await?;
+
+    // Replace the file atomically, so the storage key can't get lost if writing fails
+    let tmp_path = wrapped_key_path.with_extension("tmp");
+    std::fs::write(&tmp_path, rotated_key)?;
+    std::fs::rename(tmp_path, wrapped_key_path)?;
+
+    // Only remove the previous key once the new wrapped key is stored
+    Ok(stronghold.remove_key_encryption_key(&wrapped_key).await?)
+}
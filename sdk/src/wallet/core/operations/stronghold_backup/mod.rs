@@ -181,7 +181,7 @@ impl Wallet {
                     let restored_account = try_join_all(
                         read_accounts
                             .into_iter()
-                            .map(|a| Account::new(a, self.inner.clone()).boxed()),
+                            .map(|a| Account::new(a, self.inner.clone(), None).boxed()),
                     )
                     .await?;
                     *accounts = restored_account;
@@ -354,7 +354,7 @@ impl Wallet<StrongholdSecretManager> {
                     let restored_account = try_join_all(
                         read_accounts
                             .into_iter()
-                            .map(|a| Account::new(a, self.inner.clone()).boxed()),
+                            .map(|a| Account::new(a, self.inner.clone(), None).boxed()),
                     )
                     .await?;
                     *accounts = restored_account;
@@ -0,0 +1,55 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        core::WalletInner,
+        transfer_template::{TransferTemplate, TransferTemplates},
+    },
+};
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Returns all transfer templates of the wallet, sorted by id.
+    pub async fn transfer_templates(&self) -> Vec<TransferTemplate> {
+        self.transfer_templates.read().await.templates()
+    }
+
+    /// Returns a transfer template.
+    pub async fn transfer_template(&self, id: &str) -> Option<TransferTemplate> {
+        self.transfer_templates.read().await.get(id).cloned()
+    }
+
+    /// Stores a transfer template, replacing and returning the one with the same id. Fails if the id is empty or the
+    /// template has no destinations.
+    pub async fn set_transfer_template(
+        &self,
+        template: TransferTemplate,
+    ) -> crate::wallet::Result<Option<TransferTemplate>> {
+        let mut transfer_templates = self.transfer_templates.write().await;
+        let previous = transfer_templates.insert(template)?;
+        self.save_transfer_templates(&transfer_templates).await?;
+        Ok(previous)
+    }
+
+    /// Removes a transfer template.
+    pub async fn remove_transfer_template(&self, id: &str) -> crate::wallet::Result<Option<TransferTemplate>> {
+        let mut transfer_templates = self.transfer_templates.write().await;
+        let template = transfer_templates.remove(id);
+        if template.is_some() {
+            self.save_transfer_templates(&transfer_templates).await?;
+        }
+        Ok(template)
+    }
+
+    #[allow(unused_variables)]
+    async fn save_transfer_templates(&self, transfer_templates: &TransferTemplates) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .save_transfer_templates(transfer_templates)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        account::{builder::next_account_index, Account, AccountDetails, PublicAccount},
+        Error, Wallet,
+    },
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Creates a watch-only account from the [public export](Account::export_public_account) of an account, e.g. to
+    /// monitor the account of another wallet without access to its keys. It can be synced and its balance,
+    /// transactions and events are available like for other accounts, but everything that needs a signature, like
+    /// sending or generating addresses, fails with [`Error::WatchOnlyAccount`]. The derivation proofs of the export are
+    /// verified and its addresses have to be on the network of the wallet. If no alias is provided, the account index
+    /// will be set as alias.
+    pub async fn create_watch_only_account(
+        &self,
+        public_account: PublicAccount,
+        alias: impl Into<Option<String>> + Send,
+    ) -> crate::wallet::Result<Account<S>> {
+        log::debug!("[create_watch_only_account]");
+        public_account.verify()?;
+        if public_account.addresses.iter().all(|proof| proof.internal) {
+            return Err(Error::MissingParameter("public addresses"));
+        }
+        for proof in &public_account.addresses {
+            self.client().bech32_hrp_matches(proof.address.hrp()).await?;
+        }
+
+        let mut accounts = self.accounts.write().await;
+        let account_index = next_account_index(&accounts).await;
+        let alias = alias.into().unwrap_or_else(|| account_index.to_string());
+
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+            if account_details.alias().to_lowercase() == alias.to_lowercase() {
+                return Err(Error::AccountAliasAlreadyExists(alias));
+            }
+            // An account with the same addresses has the same path, its outputs would be counted twice
+            if account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses())
+                .any(|address| public_account.derivation_proof(address.address.inner()).is_some())
+            {
+                return Err(Error::InvalidDerivationPath(format!(
+                    "m/44'/{}'/{}' is already used by account {}",
+                    public_account.coin_type,
+                    public_account.account_index,
+                    account_details.alias()
+                )));
+            }
+        }
+
+        let account_details = AccountDetails::new_watch_only(account_index, alias, public_account);
+        let account = Account::new(account_details, self.inner.clone(), None).await?;
+        #[cfg(feature = "storage")]
+        account.save(None).await?;
+        accounts.push(account.clone());
+
+        Ok(account)
+    }
+}
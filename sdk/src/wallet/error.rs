@@ -8,7 +8,7 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{address::Bech32Address, output::OutputId, payload::transaction::TransactionId};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -19,6 +19,17 @@ pub enum Error {
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
+    /// Account can't be removed
+    #[error("account {account_index} can't be removed: {reason}")]
+    AccountNotRemovable {
+        /// The index of the account.
+        account_index: u32,
+        /// Why the account can't be removed.
+        reason: &'static str,
+    },
+    /// Account uses the client of the wallet
+    #[error("account {0} uses the client options of the wallet")]
+    AccountUsesWalletClient(u32),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
@@ -37,6 +48,15 @@ pub enum Error {
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
+    /// Address is already stored under another contact label
+    #[error("address {address} is already stored as contact {label}")]
+    ContactAddressAlreadyExists { address: Bech32Address, label: String },
+    /// Contact label is already used for another address
+    #[error("contact {0} already exists")]
+    ContactAlreadyExists(String),
+    /// Contact not found in the address book
+    #[error("contact {0} not found")]
+    ContactNotFound(String),
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
@@ -46,21 +66,44 @@ pub enum Error {
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
+    /// gRPC server error
+    #[cfg(feature = "grpc-server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+    #[error("grpc server error: {0}")]
+    GrpcServer(#[from] tonic::transport::Error),
     /// Insufficient funds to send transaction.
     #[error("insufficient funds {available}/{required} available")]
     InsufficientFunds { available: u64, required: u64 },
-    /// Invalid coin type, all accounts need to have the same coin type
+    /// Invalid coin type, the coin type of the wallet isn't used by any of its accounts
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
         new_coin_type: u32,
         existing_coin_type: u32,
     },
+    /// Invalid contact label, it must not be empty or a bech32 address
+    #[error("invalid contact label: {0}")]
+    InvalidContactLabel(String),
+    /// Invalid BIP44 derivation path for a new account
+    #[error("invalid derivation path for new account: {0}")]
+    InvalidDerivationPath(String),
+    /// Invalid ledger snapshot
+    #[error("invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+    /// Invalid wallet interchange file
+    #[error("invalid interchange file: {0}")]
+    InvalidInterchange(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid payment request.
+    #[error("invalid payment request: {0}")]
+    InvalidPaymentRequest(String),
+    /// Invalid transfer template.
+    #[error("invalid transfer template: {0}")]
+    InvalidTransferTemplate(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -89,6 +132,9 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// Output not found in the unspent outputs of the account
+    #[error("output {0} not found in unspent outputs")]
+    OutputNotFound(OutputId),
     /// Errors not covered by other variants.
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -105,10 +151,21 @@ pub enum Error {
     StorageIsEncrypted,
     /// Tokio task join error
     #[error("{0}")]
-    TaskJoin(#[from] tokio::task::JoinError),
+    TaskJoin(#[from] crate::client::runtime::JoinError),
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Transaction can't be replaced
+    #[error("transaction {transaction_id} can't be replaced: {reason}")]
+    TransactionNotReplaceable {
+        /// The id of the transaction.
+        transaction_id: TransactionId,
+        /// Why the transaction can't be replaced.
+        reason: &'static str,
+    },
+    /// Transfer template not found
+    #[error("transfer template {0} not found")]
+    TransferTemplateNotFound(String),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -119,6 +176,14 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("invalid voting power")]
     InvalidVotingPower,
+    /// Watch-only accounts have no keys to sign with
+    #[error("account {0} is watch-only and can't sign")]
+    WatchOnlyAccount(String),
+    /// Webhook error
+    #[cfg(feature = "webhooks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+    #[error("webhook error: {0}")]
+    Webhook(String),
 }
 
 // Serialize type with Display error
@@ -189,3 +254,10 @@ impl From<rocksdb::Error> for Error {
         Self::Storage(error.to_string())
     }
 }
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Storage(error.to_string())
+    }
+}
@@ -14,7 +14,8 @@ pub use self::types::{Event, WalletEvent, WalletEventType};
 type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
 
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    handlers: HashMap<WalletEventType, Vec<(u64, Handler<Event>)>>,
+    next_listener_id: u64,
 }
 
 impl EventEmitter {
@@ -22,6 +23,7 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            next_listener_id: 0,
         }
     }
 
@@ -31,8 +33,19 @@ impl EventEmitter {
     where
         F: Fn(&Event) + 'static + Send + Sync,
     {
+        self.add_listener(events, handler);
+    }
+
+    /// Registers a listener like [`EventEmitter::on()`], returns an ID to remove only this listener with
+    /// [`EventEmitter::remove_listener()`].
+    pub(crate) fn add_listener<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F) -> u64
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let id = self.next_listener_id;
+        self.next_listener_id += 1;
         let mut events = events.into_iter().peekable();
-        let handler = Arc::new(handler);
+        let handler: Handler<Event> = Arc::new(handler);
         // if no event is provided the handler is registered for all event types
         if events.peek().is_none() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
@@ -42,15 +55,29 @@ impl EventEmitter {
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
+                WalletEventType::SyncProgress,
+                WalletEventType::PaymentReceived,
+                WalletEventType::SyncReport,
+                WalletEventType::Reorg,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
-                self.handlers.entry(event_type).or_default().push(handler.clone());
+                self.handlers.entry(event_type).or_default().push((id, handler.clone()));
             }
         }
         for event in events {
-            self.handlers.entry(event).or_default().push(handler.clone());
+            self.handlers.entry(event).or_default().push((id, handler.clone()));
         }
+
+        id
+    }
+
+    /// Removes the listener with the ID returned by [`EventEmitter::add_listener()`] for all event types.
+    pub(crate) fn remove_listener(&mut self, id: u64) {
+        self.handlers.retain(|_, handlers| {
+            handlers.retain(|(listener_id, _)| *listener_id != id);
+            !handlers.is_empty()
+        });
     }
 
     /// Removes handlers for each given `WalletEventType`.
@@ -69,19 +96,18 @@ impl EventEmitter {
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
     /// argument to each of them.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
-        let event = Event { account_index, event };
-        if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
-                handler(&event);
+        self.emit_event(&Event {
+            account_index,
+            seq: None,
+            event,
+        });
+    }
+
+    /// Invokes all listeners of the type of the event.
+    pub(crate) fn emit_event(&self, event: &Event) {
+        if let Some(handlers) = self.handlers.get(&event.event.kind()) {
+            for (_, handler) in handlers {
+                handler(event);
             }
         }
     }
@@ -204,4 +230,33 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn remove_listener() {
+        let mut emitter = EventEmitter::new();
+        let removed_counter = Arc::new(AtomicUsize::new(0));
+        let kept_counter = Arc::new(AtomicUsize::new(0));
+
+        let counter = removed_counter.clone();
+        let id = emitter.add_listener([], move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let counter = kept_counter.clone();
+        emitter.on([WalletEventType::ConsolidationRequired], move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.remove_listener(id);
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            0,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+
+        assert_eq!(removed_counter.load(Ordering::SeqCst), 1);
+        assert_eq!(kept_counter.load(Ordering::SeqCst), 2);
+        // Only the event type of the remaining listener is left
+        assert_eq!(emitter.handlers.len(), 1);
+    }
 }
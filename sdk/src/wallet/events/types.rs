@@ -11,9 +11,13 @@ use crate::{
         block::{
             address::Bech32Address,
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
+            BlockId,
         },
     },
-    wallet::account::types::{InclusionState, OutputDataDto},
+    wallet::account::{
+        types::{InclusionState, OutputDataDto},
+        PaymentRequest, SyncReport,
+    },
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -21,6 +25,9 @@ use crate::{
 pub struct Event {
     /// Associated account index.
     pub account_index: u32,
+    /// The sequence number of the event in the event log of the storage, `None` if it wasn't persisted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u64>,
     /// The event
     pub event: WalletEvent,
 }
@@ -33,11 +40,34 @@ pub enum WalletEvent {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration(AddressData),
     NewOutput(Box<NewOutputEvent>),
+    PaymentReceived(Box<PaymentReceivedEvent>),
+    Reorg(Box<ReorgEvent>),
     SpentOutput(Box<SpentOutputEvent>),
+    SyncProgress(SyncProgressEvent),
+    SyncReport(Box<SyncReport>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
 }
 
+impl WalletEvent {
+    /// Returns the type of the event.
+    pub fn kind(&self) -> WalletEventType {
+        match self {
+            Self::NewOutput(_) => WalletEventType::NewOutput,
+            Self::PaymentReceived(_) => WalletEventType::PaymentReceived,
+            Self::Reorg(_) => WalletEventType::Reorg,
+            Self::SpentOutput(_) => WalletEventType::SpentOutput,
+            Self::SyncProgress(_) => WalletEventType::SyncProgress,
+            Self::SyncReport(_) => WalletEventType::SyncReport,
+            Self::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
+            Self::TransactionProgress(_) => WalletEventType::TransactionProgress,
+            Self::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
+        }
+    }
+}
+
 impl Serialize for WalletEvent {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -58,6 +88,10 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a SyncProgressEvent),
+            T7(&'a PaymentReceivedEvent),
+            T8(&'a SyncReport),
+            T9(&'a ReorgEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +126,22 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::SyncProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncProgress as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::PaymentReceived(e) => TypedWalletEvent_ {
+                kind: WalletEventType::PaymentReceived as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::SyncReport(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncReport as u8,
+                event: WalletEvent_::T8(e),
+            },
+            Self::Reorg(e) => TypedWalletEvent_ {
+                kind: WalletEventType::Reorg as u8,
+                event: WalletEvent_::T9(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,6 +193,25 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::SyncProgress => Self::SyncProgress(
+                    SyncProgressEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize SyncProgress: {e}")))?,
+                ),
+                WalletEventType::PaymentReceived => {
+                    Self::PaymentReceived(Box::new(PaymentReceivedEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize PaymentReceived: {e}"))
+                    })?))
+                }
+                WalletEventType::SyncReport => {
+                    Self::SyncReport(Box::new(SyncReport::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize SyncReport: {e}"))
+                    })?))
+                }
+                WalletEventType::Reorg => {
+                    Self::Reorg(Box::new(ReorgEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize Reorg: {e}"))
+                    })?))
+                }
             },
         )
     }
@@ -160,6 +229,10 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    SyncProgress = 6,
+    PaymentReceived = 7,
+    SyncReport = 8,
+    Reorg = 9,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +247,10 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::SyncProgress,
+            7 => Self::PaymentReceived,
+            8 => Self::SyncReport,
+            9 => Self::Reorg,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -206,6 +283,49 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+/// A registered payment request was paid.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentReceivedEvent {
+    /// The request that was paid.
+    pub request: PaymentRequest,
+    /// The output that paid the request.
+    pub output: OutputDataDto,
+}
+
+/// A transaction of received outputs isn't included anymore, e.g. after switching to a node with another history, and
+/// was rolled back.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgEvent {
+    /// The transaction that was rolled back.
+    pub transaction_id: TransactionId,
+    /// The outputs created by the transaction, which were removed.
+    pub removed_outputs: Vec<OutputDataDto>,
+    /// The inputs of the transaction, which are unspent again.
+    pub restored_outputs: Vec<OutputDataDto>,
+}
+
+/// The progress of syncing all accounts of the wallet, emitted with the index of the account that was just synced.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncProgressEvent {
+    /// The number of accounts that were synced.
+    pub synced_accounts: usize,
+    /// The number of accounts that failed to sync.
+    pub failed_accounts: usize,
+    /// The number of accounts to sync.
+    pub total_accounts: usize,
+}
+
+/// A block that was issued for a pending transaction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBlockEvent {
+    pub transaction_id: TransactionId,
+    pub block_id: BlockId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TransactionProgressEvent {
@@ -223,6 +343,10 @@ pub enum TransactionProgressEvent {
     PerformingPow,
     /// Broadcasting.
     Broadcasting,
+    /// The transaction got reattached in a new block.
+    Reattached(TransactionBlockEvent),
+    /// The block of the transaction got promoted by a new block.
+    Promoted(TransactionBlockEvent),
 }
 
 impl Serialize for TransactionProgressEvent {
@@ -245,6 +369,8 @@ impl Serialize for TransactionProgressEvent {
             T4,
             T5,
             T6,
+            T7(&'a TransactionBlockEvent),
+            T8(&'a TransactionBlockEvent),
         }
         #[derive(Serialize)]
         struct TypedTransactionProgressEvent_<'a> {
@@ -282,6 +408,14 @@ impl Serialize for TransactionProgressEvent {
                 kind: 6,
                 event: TransactionProgressEvent_::T6,
             },
+            Self::Reattached(e) => TypedTransactionProgressEvent_ {
+                kind: 7,
+                event: TransactionProgressEvent_::T7(e),
+            },
+            Self::Promoted(e) => TypedTransactionProgressEvent_ {
+                kind: 8,
+                event: TransactionProgressEvent_::T8(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -319,6 +453,14 @@ impl<'de> Deserialize<'de> for TransactionProgressEvent {
                 4 => Self::SigningTransaction,
                 5 => Self::PerformingPow,
                 6 => Self::Broadcasting,
+                7 => Self::Reattached(
+                    TransactionBlockEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize Reattached: {e}")))?,
+                ),
+                8 => Self::Promoted(
+                    TransactionBlockEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize Promoted: {e}")))?,
+                ),
                 _ => return Err(serde::de::Error::custom("invalid transaction progress event type")),
             },
         )
@@ -0,0 +1,367 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A gRPC server exposing wallet operations, so backends written in other languages can integrate the wallet without
+//! FFI bindings.
+//!
+//! The service is defined in `proto/wallet.proto`. Every request goes through an [`Interceptor`] first, which has to
+//! authenticate it, e.g. the one returned by [`bearer_token_interceptor()`], as the service can send funds.
+//!
+//! The service definitions are compiled when the SDK is built with the `grpc-server` feature, which requires the
+//! Protocol Buffers compiler `protoc`. It's found on the `PATH`, another binary can be set with the `PROTOC`
+//! environment variable.
+
+use std::{collections::HashSet, net::SocketAddr, pin::Pin, sync::Arc};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use futures::Stream;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, StreamExt};
+use tonic::{
+    service::{interceptor::InterceptedService, Interceptor},
+    transport::Server,
+    Request, Response, Status,
+};
+
+use self::proto::{
+    account_identifier::Identifier,
+    wallet_service_server::{WalletService, WalletServiceServer},
+    AccountResponse, AddressResponse, BalanceResponse, CreateAccountRequest, NativeTokenBalance, SendRequest,
+    SubscribeEventsRequest, TransactionResponse,
+};
+use crate::{
+    client::secret::{SecretManage, SecretManager},
+    wallet::{
+        account::{
+            types::{AccountIdentifier, Balance},
+            Account,
+        },
+        events::types::{Event, WalletEventType},
+        Error, Wallet,
+    },
+};
+
+/// Types generated from `proto/wallet.proto`.
+#[allow(missing_docs, unreachable_pub, clippy::all, clippy::nursery, clippy::pedantic)]
+pub mod proto {
+    tonic::include_proto!("iota.wallet.v1");
+}
+
+/// Number of events buffered for subscribers, slow subscribers miss older events.
+const EVENT_CHANNEL_CAPACITY: usize = 1000;
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<proto::Event, Status>> + Send>>;
+
+/// Serves a [`Wallet`] over gRPC.
+pub struct GrpcServer<S: SecretManage = SecretManager> {
+    wallet: Wallet<S>,
+    events: broadcast::Sender<Event>,
+    // Removes the event listener of the server from the wallet
+    remove_listener: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl<S: 'static + SecretManage> GrpcServer<S>
+where
+    Error: From<S::Error>,
+{
+    /// Creates a new server for the wallet, which starts collecting wallet events for subscribers.
+    pub async fn new(wallet: Wallet<S>) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let sender = events.clone();
+        // Sending only fails if there is no subscriber
+        let listener_id = wallet
+            .add_listener([], move |event| {
+                sender.send(event.clone()).ok();
+            })
+            .await;
+        let inner = Arc::downgrade(&wallet.inner);
+        let remove_listener = Box::new(move || {
+            if let Some(inner) = inner.upgrade() {
+                crate::client::runtime::spawn(async move { inner.remove_listener(listener_id).await });
+            }
+        });
+
+        Self {
+            wallet,
+            events,
+            remove_listener: Some(remove_listener),
+        }
+    }
+
+    /// Serves the wallet on the given address. Every request is passed through the interceptor first, which can
+    /// reject it, e.g. to authenticate clients.
+    pub async fn serve_with_interceptor(
+        self,
+        address: SocketAddr,
+        interceptor: impl Interceptor + Clone + Send + Sync + 'static,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[GRPC] serving on {address}");
+        Server::builder()
+            .add_service(self.into_service(interceptor))
+            .serve(address)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the service to be added to a custom [`Server`], every request is passed through the interceptor first.
+    pub fn into_service<I: Interceptor>(self, interceptor: I) -> InterceptedService<WalletServiceServer<Self>, I> {
+        WalletServiceServer::with_interceptor(self, interceptor)
+    }
+
+    async fn account(&self, identifier: Option<proto::AccountIdentifier>) -> Result<Account<S>, Status> {
+        let identifier = match identifier.and_then(|identifier| identifier.identifier) {
+            Some(Identifier::Index(index)) => AccountIdentifier::Index(index),
+            Some(Identifier::Alias(alias)) => AccountIdentifier::Alias(alias),
+            None => return Err(Status::invalid_argument("missing account identifier")),
+        };
+
+        Ok(self.wallet.get_account(identifier).await?)
+    }
+}
+
+impl<S: SecretManage> Drop for GrpcServer<S> {
+    fn drop(&mut self) {
+        if let Some(remove_listener) = self.remove_listener.take() {
+            remove_listener();
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl<S: 'static + SecretManage> WalletService for GrpcServer<S>
+where
+    Error: From<S::Error>,
+{
+    type SubscribeEventsStream = EventStream;
+
+    async fn create_account(
+        &self,
+        request: Request<CreateAccountRequest>,
+    ) -> Result<Response<AccountResponse>, Status> {
+        let mut account_builder = self.wallet.create_account();
+        if let Some(alias) = request.into_inner().alias {
+            account_builder = account_builder.with_alias(alias);
+        }
+        let account = account_builder.finish().await?;
+
+        Ok(Response::new(account_response(&account).await))
+    }
+
+    async fn get_account(
+        &self,
+        request: Request<proto::AccountIdentifier>,
+    ) -> Result<Response<AccountResponse>, Status> {
+        let account = self.account(Some(request.into_inner())).await?;
+
+        Ok(Response::new(account_response(&account).await))
+    }
+
+    async fn generate_address(
+        &self,
+        request: Request<proto::AccountIdentifier>,
+    ) -> Result<Response<AddressResponse>, Status> {
+        let account = self.account(Some(request.into_inner())).await?;
+        let addresses = account.generate_ed25519_addresses(1, None).await?;
+        let address = addresses
+            .first()
+            .ok_or_else(|| Status::internal("no address generated"))?;
+
+        Ok(Response::new(AddressResponse {
+            address: address.address().to_string(),
+        }))
+    }
+
+    async fn sync(&self, request: Request<proto::AccountIdentifier>) -> Result<Response<BalanceResponse>, Status> {
+        let account = self.account(Some(request.into_inner())).await?;
+        let balance = account.sync(None).await?;
+
+        Ok(Response::new(balance_response(&balance)))
+    }
+
+    async fn get_balance(
+        &self,
+        request: Request<proto::AccountIdentifier>,
+    ) -> Result<Response<BalanceResponse>, Status> {
+        let account = self.account(Some(request.into_inner())).await?;
+        let balance = account.balance().await?;
+
+        Ok(Response::new(balance_response(&balance)))
+    }
+
+    async fn send(&self, request: Request<SendRequest>) -> Result<Response<TransactionResponse>, Status> {
+        let SendRequest {
+            account,
+            amount,
+            recipient,
+        } = request.into_inner();
+        let account = self.account(account).await?;
+        let transaction = account.send(amount, recipient, None).await?;
+
+        Ok(Response::new(TransactionResponse {
+            transaction_id: transaction.transaction_id.to_string(),
+            block_id: transaction.block_id.map(|block_id| block_id.to_string()),
+        }))
+    }
+
+    async fn subscribe_events(
+        &self,
+        request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let SubscribeEventsRequest {
+            event_types,
+            account_index,
+        } = request.into_inner();
+        let event_types = event_types
+            .into_iter()
+            .map(|event_type| {
+                u8::try_from(event_type)
+                    .map_err(|_| format!("invalid event type {event_type}"))
+                    .and_then(WalletEventType::try_from)
+                    .map_err(Status::invalid_argument)
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let stream = BroadcastStream::new(self.events.subscribe()).filter_map(move |event| {
+            // Events missed by a lagging subscriber are skipped
+            let event = event.ok()?;
+            let is_subscribed = (event_types.is_empty() || event_types.contains(&event.event.kind()))
+                && account_index.map_or(true, |account_index| account_index == event.account_index);
+
+            is_subscribed.then(|| event_response(&event))
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// Returns an [`Interceptor`] which only accepts requests with an `authorization: Bearer <token>` header.
+pub fn bearer_token_interceptor(token: impl Into<String>) -> impl Interceptor + Clone + Send + Sync + 'static {
+    // Hashes are compared, so neither the token nor its length can be guessed from the response time
+    let expected = Blake2b256::digest(format!("Bearer {}", token.into()));
+
+    move |request: Request<()>| match request.metadata().get("authorization") {
+        Some(authorization)
+            if crate::utils::constant_time_eq(&Blake2b256::digest(authorization.as_bytes()), &expected) =>
+        {
+            Ok(request)
+        }
+        _ => Err(Status::unauthenticated("invalid or missing bearer token")),
+    }
+}
+
+impl From<Error> for Status {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::AccountNotFound(_) | Error::ContactNotFound(_) | Error::TransactionNotFound(_) => {
+                Self::not_found(error.to_string())
+            }
+            Error::AccountAliasAlreadyExists(_) => Self::already_exists(error.to_string()),
+            Error::InsufficientFunds { .. } | Error::StorageIsEncrypted => Self::failed_precondition(error.to_string()),
+            _ => Self::internal(error.to_string()),
+        }
+    }
+}
+
+async fn account_response<S: SecretManage>(account: &Account<S>) -> AccountResponse {
+    let details = account.details().await;
+
+    AccountResponse {
+        index: *details.index(),
+        alias: details.alias().clone(),
+        coin_type: *details.coin_type(),
+    }
+}
+
+fn balance_response(balance: &Balance) -> BalanceResponse {
+    let base_coin = balance.base_coin();
+
+    BalanceResponse {
+        total: base_coin.total(),
+        available: base_coin.available(),
+        timelocked: base_coin.timelocked(),
+        expiring_soon: base_coin.expiring_soon(),
+        storage_deposit_return: base_coin.storage_deposit_return(),
+        native_tokens: balance
+            .native_tokens()
+            .iter()
+            .map(|native_token| NativeTokenBalance {
+                token_id: native_token.token_id().to_string(),
+                total: native_token.total().to_string(),
+                available: native_token.available().to_string(),
+            })
+            .collect(),
+        nft_ids: balance.nfts().iter().map(ToString::to_string).collect(),
+        alias_ids: balance.aliases().iter().map(ToString::to_string).collect(),
+        foundry_ids: balance.foundries().iter().map(ToString::to_string).collect(),
+        frozen: base_coin.frozen(),
+    }
+}
+
+fn event_response(event: &Event) -> Result<proto::Event, Status> {
+    Ok(proto::Event {
+        account_index: event.account_index,
+        event_type: event.event.kind() as u32,
+        event: serde_json::to_string(&event.event).map_err(|e| Status::internal(e.to_string()))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_token() {
+        let mut interceptor = bearer_token_interceptor("secret");
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer secret".parse().unwrap());
+        assert!(interceptor.call(request).is_ok());
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer other".parse().unwrap());
+        assert_eq!(
+            interceptor.call(request).unwrap_err().code(),
+            tonic::Code::Unauthenticated
+        );
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer secret2".parse().unwrap());
+        assert!(interceptor.call(request).is_err());
+        assert!(interceptor.call(Request::new(())).is_err());
+    }
+
+    #[tokio::test]
+    async fn event_listener_is_removed_on_drop() {
+        use crate::wallet::events::types::WalletEvent;
+
+        let storage_path = "test-storage/grpc_event_listener_is_removed_on_drop";
+        let account = Account::mock(storage_path).await;
+        let wallet = Wallet {
+            inner: account.wallet.clone(),
+            accounts: Arc::new(tokio::sync::RwLock::new(vec![account])),
+        };
+
+        let server = GrpcServer::new(wallet.clone()).await;
+        let mut receiver = server.events.subscribe();
+        wallet.emit(0, WalletEvent::ConsolidationRequired).await;
+        assert_eq!(receiver.try_recv().unwrap().account_index, 0);
+
+        // The listener holds the last sender, so the channel is closed once it's removed
+        drop(server);
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !matches!(receiver.try_recv(), Err(broadcast::error::TryRecvError::Closed)) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("the event listener wasn't removed");
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
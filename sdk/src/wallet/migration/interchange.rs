@@ -0,0 +1,326 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The wallet interchange format, to move accounts between wallet implementations.
+//!
+//! Unlike the storage and backup records, which are serde structs that change with the SDK, the format is versioned and
+//! fully described here. A file has the following layout, all integers are little endian:
+//!
+//! - magic `b"IWIF"`, version `u8`, account count `u32`, the accounts
+//! - account: index `u32`, coin type `u32`, BIP44 account index `u32`, alias string, bech32 hrp string, metadata count
+//!   `u32` and key and value strings, address count `u32` and addresses, transaction count `u32` and transactions
+//! - address: Ed25519 address `[u8; 32]`, key index `u32`, flags `u8` (`0x01` internal, `0x02` used, `0x04` frozen),
+//!   label string, empty if there is none
+//! - transaction: network id `u64`, creation timestamp in milliseconds `u64`, incoming `u8` (0 or 1), inclusion state
+//!   `u8` (0 = pending, 1 = confirmed, 2 = conflicting, 3 = unknown/pruned), block id flag `u8` (0 or 1) followed by
+//!   the block id `[u8; 32]` if it's 1, note string, empty if there is none, transaction payload length `u32` and the
+//!   packed transaction payload
+//!
+//! Strings are stored as length `u32` and UTF-8 bytes. The transaction id isn't stored, it's the hash of the payload.
+//! Outputs aren't part of the format, they are fetched from the node by syncing after an import, neither are the
+//! inputs of transactions, the network of an account, which is the one of the importing wallet, and the addresses
+//! with unspent outputs.
+
+use std::io::{Read, Write};
+
+use packable::PackableExt;
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address, Ed25519Address, Hrp},
+        payload::transaction::TransactionPayload,
+        protocol::ProtocolParameters,
+        BlockId,
+    },
+    wallet::{
+        account::types::{AccountAddress, InclusionState, Transaction},
+        snapshot::{read_array, read_bytes, read_u32, read_u64, read_u8},
+        Error, Result,
+    },
+};
+
+/// The magic bytes at the start of an interchange file.
+pub const INTERCHANGE_MAGIC: [u8; 4] = *b"IWIF";
+/// The interchange format version written by this SDK.
+pub const INTERCHANGE_VERSION: u8 = 1;
+
+const ADDRESS_INTERNAL: u8 = 0x01;
+const ADDRESS_USED: u8 = 0x02;
+const ADDRESS_FROZEN: u8 = 0x04;
+
+/// The accounts of a wallet in the interchange format.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WalletInterchange {
+    /// The exported accounts.
+    pub accounts: Vec<AccountInterchange>,
+}
+
+/// An account in the interchange format.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountInterchange {
+    /// The account index.
+    pub index: u32,
+    /// The coin type.
+    pub coin_type: u32,
+    /// The BIP44 account index the addresses are derived with.
+    pub bip44_account_index: u32,
+    /// The account alias.
+    pub alias: String,
+    /// Arbitrary key-value metadata set by the user.
+    pub metadata: Vec<(String, String)>,
+    /// The public and internal addresses.
+    pub addresses: Vec<AccountAddress>,
+    /// The sent and incoming transactions.
+    pub transactions: Vec<Transaction>,
+}
+
+impl WalletInterchange {
+    /// Writes the accounts in the interchange format.
+    pub fn write(&self, writer: &mut impl Write) -> Result<()> {
+        writer.write_all(&INTERCHANGE_MAGIC)?;
+        writer.write_all(&[INTERCHANGE_VERSION])?;
+        write_u32(writer, self.accounts.len())?;
+
+        for account in &self.accounts {
+            writer.write_all(&account.index.to_le_bytes())?;
+            writer.write_all(&account.coin_type.to_le_bytes())?;
+            writer.write_all(&account.bip44_account_index.to_le_bytes())?;
+            write_string(writer, &account.alias)?;
+            // All addresses of an account share the hrp
+            let hrp = account
+                .addresses
+                .first()
+                .map(|address| address.address.hrp.to_string())
+                .unwrap_or_default();
+            write_string(writer, &hrp)?;
+
+            write_u32(writer, account.metadata.len())?;
+            for (key, value) in &account.metadata {
+                write_string(writer, key)?;
+                write_string(writer, value)?;
+            }
+
+            write_u32(writer, account.addresses.len())?;
+            for address in &account.addresses {
+                let Address::Ed25519(ed25519_address) = address.address.inner() else {
+                    return Err(Error::InvalidInterchange(format!(
+                        "account address {} isn't an Ed25519 address",
+                        address.address
+                    )));
+                };
+                writer.write_all(&**ed25519_address)?;
+                writer.write_all(&address.key_index.to_le_bytes())?;
+                let mut flags = 0;
+                if address.internal {
+                    flags |= ADDRESS_INTERNAL;
+                }
+                if address.used {
+                    flags |= ADDRESS_USED;
+                }
+                if address.frozen {
+                    flags |= ADDRESS_FROZEN;
+                }
+                writer.write_all(&[flags])?;
+                write_string(writer, address.label.as_deref().unwrap_or_default())?;
+            }
+
+            write_u32(writer, account.transactions.len())?;
+            for transaction in &account.transactions {
+                writer.write_all(&transaction.network_id.to_le_bytes())?;
+                writer.write_all(&(transaction.timestamp as u64).to_le_bytes())?;
+                writer.write_all(&[transaction.incoming as u8])?;
+                let inclusion_state = match transaction.inclusion_state {
+                    InclusionState::Pending => 0,
+                    InclusionState::Confirmed => 1,
+                    InclusionState::Conflicting => 2,
+                    InclusionState::UnknownPruned => 3,
+                };
+                writer.write_all(&[inclusion_state])?;
+                match &transaction.block_id {
+                    Some(block_id) => {
+                        writer.write_all(&[1])?;
+                        writer.write_all(block_id.as_ref())?;
+                    }
+                    None => writer.write_all(&[0])?,
+                }
+                write_string(writer, transaction.note.as_deref().unwrap_or_default())?;
+                let payload = transaction.payload.pack_to_vec();
+                write_u32(writer, payload.len())?;
+                writer.write_all(&payload)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads accounts in the interchange format. The transaction payloads are verified with the protocol parameters.
+    pub fn read(reader: &mut impl Read, protocol_parameters: &ProtocolParameters) -> Result<Self> {
+        let magic = read_array::<4>(reader)?;
+        if magic != INTERCHANGE_MAGIC {
+            return Err(Error::InvalidInterchange("not an interchange file".to_string()));
+        }
+        let version = read_u8(reader)?;
+        if version != INTERCHANGE_VERSION {
+            return Err(Error::InvalidInterchange(format!("unsupported version {version}")));
+        }
+
+        let account_count = read_u32(reader)?;
+        let mut accounts = Vec::new();
+        for _ in 0..account_count {
+            let index = read_u32(reader)?;
+            let coin_type = read_u32(reader)?;
+            let bip44_account_index = read_u32(reader)?;
+            let alias = read_string(reader)?;
+            let hrp = read_string(reader)?
+                .parse::<Hrp>()
+                .map_err(|e| Error::InvalidInterchange(format!("invalid hrp of account {index}: {e}")))?;
+
+            let mut metadata = Vec::new();
+            for _ in 0..read_u32(reader)? {
+                metadata.push((read_string(reader)?, read_string(reader)?));
+            }
+
+            let mut addresses = Vec::new();
+            for _ in 0..read_u32(reader)? {
+                let address = Ed25519Address::new(read_array(reader)?);
+                let key_index = read_u32(reader)?;
+                let flags = read_u8(reader)?;
+                let label = read_string(reader)?;
+                addresses.push(AccountAddress {
+                    address: Bech32Address::new(hrp, address),
+                    key_index,
+                    internal: flags & ADDRESS_INTERNAL != 0,
+                    used: flags & ADDRESS_USED != 0,
+                    label: (!label.is_empty()).then_some(label),
+                    frozen: flags & ADDRESS_FROZEN != 0,
+                });
+            }
+
+            let mut transactions = Vec::new();
+            for _ in 0..read_u32(reader)? {
+                let network_id = read_u64(reader)?;
+                let timestamp = read_u64(reader)? as u128;
+                let incoming = read_u8(reader)? != 0;
+                let inclusion_state = match read_u8(reader)? {
+                    0 => InclusionState::Pending,
+                    1 => InclusionState::Confirmed,
+                    2 => InclusionState::Conflicting,
+                    3 => InclusionState::UnknownPruned,
+                    state => {
+                        return Err(Error::InvalidInterchange(format!("invalid inclusion state {state}")));
+                    }
+                };
+                let block_id = match read_u8(reader)? {
+                    0 => None,
+                    _ => Some(BlockId::new(read_array(reader)?)),
+                };
+                let note = read_string(reader)?;
+                let length = read_u32(reader)?;
+                let payload =
+                    TransactionPayload::unpack_verified(read_bytes(reader, length as usize)?, protocol_parameters)
+                        .map_err(|e| Error::InvalidInterchange(format!("invalid transaction payload: {e:?}")))?;
+
+                transactions.push(Transaction {
+                    transaction_id: payload.id(),
+                    payload,
+                    block_id,
+                    inclusion_state,
+                    timestamp,
+                    network_id,
+                    incoming,
+                    note: (!note.is_empty()).then_some(note),
+                    inputs: Vec::new(),
+                    ownership: None,
+                    attachments: Vec::new(),
+                });
+            }
+
+            accounts.push(AccountInterchange {
+                index,
+                coin_type,
+                bip44_account_index,
+                alias,
+                metadata,
+                addresses,
+                transactions,
+            });
+        }
+
+        Ok(Self { accounts })
+    }
+}
+
+fn write_u32(writer: &mut impl Write, value: usize) -> Result<()> {
+    let value = u32::try_from(value).map_err(|_| Error::InvalidInterchange(format!("{value} exceeds u32")))?;
+    writer.write_all(&value.to_le_bytes())?;
+    Ok(())
+}
+
+fn write_string(writer: &mut impl Write, value: &str) -> Result<()> {
+    write_u32(writer, value.len())?;
+    writer.write_all(value.as_bytes())?;
+    Ok(())
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String> {
+    let length = read_u32(reader)?;
+    String::from_utf8(read_bytes(reader, length as usize)?)
+        .map_err(|e| Error::InvalidInterchange(format!("invalid string: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interchange_round_trip() {
+        let address = |key_index, internal| AccountAddress {
+            address: Bech32Address::new(
+                Hrp::from_str_unchecked("rms"),
+                Ed25519Address::new([key_index as u8 + internal as u8 * 16; 32]),
+            ),
+            key_index,
+            internal,
+            used: key_index == 0,
+            label: (key_index == 1).then(|| "Alice".to_string()),
+            frozen: internal,
+        };
+        let interchange = WalletInterchange {
+            accounts: vec![
+                AccountInterchange {
+                    index: 0,
+                    coin_type: 4219,
+                    bip44_account_index: 0,
+                    alias: "main".to_string(),
+                    metadata: vec![("purpose".to_string(), "savings".to_string())],
+                    addresses: vec![address(0, false), address(1, false), address(0, true)],
+                    transactions: Vec::new(),
+                },
+                AccountInterchange {
+                    index: 1,
+                    coin_type: 4219,
+                    bip44_account_index: 5,
+                    alias: "empty".to_string(),
+                    metadata: Vec::new(),
+                    addresses: Vec::new(),
+                    transactions: Vec::new(),
+                },
+            ],
+        };
+
+        let mut bytes = Vec::new();
+        interchange.write(&mut bytes).unwrap();
+        assert_eq!(&bytes[..5], b"IWIF\x01");
+
+        let protocol_parameters = ProtocolParameters::default();
+        let read = WalletInterchange::read(&mut bytes.as_slice(), &protocol_parameters).unwrap();
+        // The hrp of an account without addresses isn't needed
+        assert_eq!(read, interchange);
+
+        bytes[4] = 2;
+        assert!(matches!(
+            WalletInterchange::read(&mut bytes.as_slice(), &protocol_parameters),
+            Err(Error::InvalidInterchange(_))
+        ));
+    }
+}
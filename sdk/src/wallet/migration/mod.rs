@@ -3,6 +3,7 @@
 
 #[cfg(any(feature = "storage", feature = "stronghold"))]
 pub(crate) mod chrysalis;
+pub mod interchange;
 mod migrate_0;
 mod migrate_1;
 mod migrate_2;
@@ -80,6 +81,23 @@ impl std::fmt::Display for MigrationVersion {
     }
 }
 
+/// The migration state of a storage, returned by [`check_migrations()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationStatus {
+    /// The version the storage was migrated to last, `None` if it was never migrated.
+    pub current: Option<MigrationVersion>,
+    /// The migrations that are applied in this order when the storage is opened.
+    pub pending: Vec<MigrationVersion>,
+}
+
+impl MigrationStatus {
+    /// Returns whether the storage is migrated to the latest version.
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 pub(crate) trait MigrationData {
     const ID: usize;
     const SDK_VERSION: &'static str;
@@ -125,6 +143,8 @@ where
     }
 }
 
+/// Applies the pending migrations to the storage. Returns an error without changing anything if the storage was
+/// migrated by a newer version of the SDK, as its records could have a format this version doesn't know.
 pub async fn migrate<S: 'static + StorageAdapter>(storage: &S) -> Result<()>
 where
     crate::wallet::Error: From<S::Error>,
@@ -137,6 +157,21 @@ where
     Ok(())
 }
 
+/// Returns the migrations that [`migrate()`] would apply to the storage, without applying them. Returns the same error
+/// as [`migrate()`] if the storage was migrated by a newer version of the SDK.
+pub async fn check_migrations<S: 'static + StorageAdapter>(storage: &S) -> Result<MigrationStatus>
+where
+    crate::wallet::Error: From<S::Error>,
+    S::Error: From<serde_json::Error>,
+{
+    let current = storage.get::<MigrationVersion>(MIGRATION_VERSION_KEY).await?;
+    let pending = migrations::<S>(current.clone())?
+        .into_iter()
+        .map(|migration| migration.version())
+        .collect();
+    Ok(MigrationStatus { current, pending })
+}
+
 fn migrations<S: 'static + StorageAdapter>(
     mut last_migration: Option<MigrationVersion>,
 ) -> Result<Vec<&'static dyn DynMigration<S>>> {
@@ -148,6 +183,17 @@ fn migrations<S: 'static + StorageAdapter>(
                 std::any::type_name::<S>()
             ))
         })?;
+    // A version that isn't in the registry was written by a newer SDK, so the following migrations are unknown
+    if let Some(last_migration) = &last_migration {
+        if !migrations
+            .values()
+            .any(|migration| migration.version().id == last_migration.id)
+        {
+            return Err(Error::Migration(format!(
+                "storage was migrated to unknown version {last_migration}, it needs a newer SDK version"
+            )));
+        }
+    }
     let mut res = Vec::new();
     while let Some(next) = migrations.get(&last_migration.as_ref().map(|m| m.id)) {
         last_migration = Some(next.version());
@@ -6,6 +6,8 @@
 /// [`Account`]: crate::wallet::Account
 /// The account module. Interaction with an Account happens via an [`Account`].
 pub mod account;
+/// The address book module.
+pub mod address_book;
 /// The core module.
 pub mod core;
 #[cfg(any(feature = "stronghold", feature = "storage"))]
@@ -20,12 +22,28 @@ pub mod error;
 #[cfg(feature = "events")]
 #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
 pub mod events;
+/// The gRPC server module.
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+pub mod grpc;
+/// The Prometheus exporter module.
+#[cfg(feature = "prometheus")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prometheus")))]
+pub mod prometheus;
+/// The ledger snapshot module.
+pub mod snapshot;
 /// The storage module.
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
 pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
+/// The transfer template module.
+pub mod transfer_template;
+/// The webhooks module.
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhooks;
 
 pub use self::{
     account::{
@@ -37,8 +55,10 @@ pub use self::{
         },
         Account,
     },
-    core::{Wallet, WalletBuilder},
+    address_book::{AddressBook, Contact, Recipient},
+    core::{operations::account_removal::RemoveAccountOptions, Wallet, WalletBuilder},
     error::Error,
+    transfer_template::{TemplateDestination, TransferTemplate, TransferTemplateOverrides, TransferTemplates},
 };
 
 /// The wallet Result type.
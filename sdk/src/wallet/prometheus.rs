@@ -0,0 +1,169 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A Prometheus exporter of the sync metrics of the wallet, for long-running services.
+//!
+//! The metrics are served in the text exposition format, on every path of the address, and are labeled with the
+//! account index.
+
+use std::{collections::BTreeMap, fmt::Write, net::SocketAddr};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+use crate::{
+    client::secret::{SecretManage, SecretManager},
+    wallet::{account::SyncMetrics, Error, Wallet},
+};
+
+/// Serves the sync metrics of a [`Wallet`] to Prometheus.
+pub struct PrometheusExporter<S: SecretManage = SecretManager> {
+    wallet: Wallet<S>,
+}
+
+impl<S: 'static + SecretManage> PrometheusExporter<S>
+where
+    Error: From<S::Error>,
+{
+    /// Creates a new exporter for the wallet.
+    pub fn new(wallet: Wallet<S>) -> Self {
+        Self { wallet }
+    }
+
+    /// Returns the current metrics in the Prometheus text exposition format.
+    pub async fn render(&self) -> String {
+        render_metrics(&self.wallet.sync_metrics().await)
+    }
+
+    /// Serves the metrics on the given address.
+    pub async fn serve(self, address: SocketAddr) -> crate::wallet::Result<()> {
+        let listener = TcpListener::bind(address).await?;
+        log::debug!("[PROMETHEUS] serving metrics on {address}");
+
+        loop {
+            let (mut stream, _) = listener.accept().await?;
+            let body = self.render().await;
+
+            crate::client::runtime::spawn(async move {
+                // The request is only read so the client doesn't get a reset connection, the metrics are served on
+                // every path
+                let mut request = [0; 1024];
+                match stream.read(&mut request).await {
+                    Ok(length) => log::trace!("[PROMETHEUS] received request of {length} bytes"),
+                    Err(e) => {
+                        log::debug!("[PROMETHEUS] couldn't read request: {e}");
+                        return;
+                    }
+                }
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                if let Err(e) = stream.write_all(response.as_bytes()).await {
+                    log::debug!("[PROMETHEUS] couldn't send metrics: {e}");
+                }
+            });
+        }
+    }
+}
+
+fn render_metrics(metrics: &BTreeMap<u32, SyncMetrics>) -> String {
+    let counters: [(&str, &str, fn(&SyncMetrics) -> f64); 8] = [
+        ("iota_wallet_syncs_total", "Number of account syncs.", |m| {
+            m.syncs as f64
+        }),
+        (
+            "iota_wallet_failed_syncs_total",
+            "Number of account syncs that failed.",
+            |m| m.failed_syncs as f64,
+        ),
+        (
+            "iota_wallet_sync_addresses_scanned_total",
+            "Number of addresses scanned by syncs.",
+            |m| m.addresses_scanned as f64,
+        ),
+        (
+            "iota_wallet_sync_requests_total",
+            "Number of requests sent to nodes by syncs.",
+            |m| m.requests as f64,
+        ),
+        (
+            "iota_wallet_sync_cache_hits_total",
+            "Number of outputs known by the account, which syncs didn't request again.",
+            |m| m.cache_hits as f64,
+        ),
+        (
+            "iota_wallet_sync_outputs_discovered_total",
+            "Number of outputs requested from nodes by syncs.",
+            |m| m.outputs_discovered as f64,
+        ),
+        (
+            "iota_wallet_sync_reorgs_total",
+            "Number of transactions rolled back by syncs, because they weren't included anymore.",
+            |m| m.reorgs as f64,
+        ),
+        ("iota_wallet_sync_duration_seconds_total", "Time spent syncing.", |m| {
+            m.duration.as_secs_f64()
+        }),
+    ];
+
+    let mut output = String::new();
+    for (name, help, value) in counters {
+        writeln!(output, "# HELP {name} {help}").ok();
+        writeln!(output, "# TYPE {name} counter").ok();
+        for (account_index, account_metrics) in metrics {
+            writeln!(
+                output,
+                "{name}{{account=\"{account_index}\"}} {}",
+                value(account_metrics)
+            )
+            .ok();
+        }
+    }
+
+    let name = "iota_wallet_sync_phase_duration_seconds_total";
+    writeln!(output, "# HELP {name} Time spent in each phase of syncing.").ok();
+    writeln!(output, "# TYPE {name} counter").ok();
+    for (account_index, account_metrics) in metrics {
+        for (phase, duration) in account_metrics.phases.iter() {
+            writeln!(
+                output,
+                "{name}{{account=\"{account_index}\",phase=\"{phase}\"}} {}",
+                duration.as_secs_f64()
+            )
+            .ok();
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let mut metrics = SyncMetrics {
+            syncs: 3,
+            requests: 42,
+            duration: Duration::from_millis(1500),
+            ..Default::default()
+        };
+        metrics.phases.outputs = Duration::from_secs(1);
+
+        let output = render_metrics(&BTreeMap::from([(0, metrics.clone()), (2, metrics)]));
+
+        assert!(output.contains("# TYPE iota_wallet_syncs_total counter\n"));
+        assert!(output.contains("iota_wallet_syncs_total{account=\"0\"} 3\n"));
+        assert!(output.contains("iota_wallet_sync_requests_total{account=\"2\"} 42\n"));
+        assert!(output.contains("iota_wallet_sync_duration_seconds_total{account=\"0\"} 1.5\n"));
+        assert!(output.contains("iota_wallet_sync_phase_duration_seconds_total{account=\"2\",phase=\"outputs\"} 1\n"));
+    }
+}
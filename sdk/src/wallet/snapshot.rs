@@ -0,0 +1,355 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reading of node ledger snapshot files.
+//!
+//! Full snapshots contain the unspent outputs of the ledger at a milestone, delta snapshots contain the milestone diffs
+//! that happened after a full snapshot. Both share the following layout, all integers are little endian:
+//!
+//! - version `u8`, type `u8` (0 = full, 1 = delta), genesis milestone index `u32`, target milestone index `u32`, target
+//!   milestone timestamp `u32`, target milestone id `[u8; 32]`
+//! - full snapshots: ledger milestone index `u32`, treasury output milestone id `[u8; 32]`, treasury output amount
+//!   `u64`, protocol parameters milestone option length `u16` and bytes, output count `u64`
+//! - delta snapshots: full snapshot target milestone id `[u8; 32]`, SEP file offset `u64`
+//! - milestone diff count `u32`, SEP count `u16`
+//! - full snapshots: the outputs
+//! - the milestone diffs: milestone payload length `u32` and bytes, the consumed treasury output if the milestone has a
+//!   receipt, created output count `u32` and outputs, consumed output count `u32` and spents
+//!
+//! Outputs are stored as output id `[u8; 34]`, block id `[u8; 32]`, milestone index booked `u32`, milestone timestamp
+//! booked `u32`, output length `u32` and the output bytes. Spents additionally contain the id of the spending
+//! transaction `[u8; 32]`, the milestone index spent `u32` and milestone timestamp spent `u32`. The solid entry points
+//! at the end of the file are not needed by the wallet and aren't read.
+
+use std::{collections::HashMap, io::Read};
+
+use packable::PackableExt;
+
+use crate::{
+    types::block::{
+        output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
+        payload::{milestone::MilestoneId, transaction::TransactionId, MilestonePayload},
+        protocol::ProtocolParameters,
+        Block, BlockId,
+    },
+    wallet::Error,
+};
+
+/// The supported snapshot file version.
+pub const SNAPSHOT_VERSION: u8 = 2;
+
+/// The kind of a ledger snapshot.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SnapshotKind {
+    /// Contains all unspent outputs at the target milestone.
+    Full,
+    /// Contains the milestone diffs since a full snapshot.
+    Delta,
+}
+
+/// The header of a ledger snapshot.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotHeader {
+    /// The kind of the snapshot.
+    pub kind: SnapshotKind,
+    /// The index of the genesis milestone of the network.
+    pub genesis_milestone_index: u32,
+    /// The index of the milestone the snapshot was taken at.
+    pub target_milestone_index: u32,
+    /// The timestamp of the milestone the snapshot was taken at.
+    pub target_milestone_timestamp: u32,
+    /// The id of the milestone the snapshot was taken at.
+    pub target_milestone_id: MilestoneId,
+    /// The ledger index of the outputs of a full snapshot.
+    pub ledger_milestone_index: Option<u32>,
+    /// The target milestone id of the full snapshot a delta snapshot belongs to.
+    pub full_snapshot_target_milestone_id: Option<MilestoneId>,
+}
+
+/// The outputs created and consumed by a milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneDiff {
+    /// The index of the milestone.
+    pub milestone_index: u32,
+    /// The timestamp of the milestone.
+    pub milestone_timestamp: u32,
+    /// The outputs created by the milestone.
+    pub created: Vec<OutputWithMetadata>,
+    /// The outputs consumed by the milestone, with their spent metadata.
+    pub consumed: Vec<OutputWithMetadata>,
+}
+
+/// The content of a ledger snapshot, restricted to the outputs a filter selected.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerSnapshot {
+    /// The header of the snapshot.
+    pub header: SnapshotHeader,
+    /// The unspent outputs of a full snapshot.
+    pub outputs: Vec<OutputWithMetadata>,
+    /// The milestone diffs, ordered like in the file.
+    pub milestone_diffs: Vec<MilestoneDiff>,
+}
+
+impl LedgerSnapshot {
+    /// Reads a full or delta snapshot, only keeping the outputs for which `filter` returns true.
+    pub fn read(
+        reader: &mut impl Read,
+        protocol_parameters: &ProtocolParameters,
+        filter: impl Fn(&Output) -> bool,
+    ) -> crate::wallet::Result<Self> {
+        let version = read_u8(reader)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(Error::InvalidSnapshot(format!("unsupported version {version}")));
+        }
+        let kind = match read_u8(reader)? {
+            0 => SnapshotKind::Full,
+            1 => SnapshotKind::Delta,
+            kind => return Err(Error::InvalidSnapshot(format!("invalid kind {kind}"))),
+        };
+        let genesis_milestone_index = read_u32(reader)?;
+        let target_milestone_index = read_u32(reader)?;
+        let target_milestone_timestamp = read_u32(reader)?;
+        let target_milestone_id = MilestoneId::new(read_array(reader)?);
+
+        let mut header = SnapshotHeader {
+            kind,
+            genesis_milestone_index,
+            target_milestone_index,
+            target_milestone_timestamp,
+            target_milestone_id,
+            ledger_milestone_index: None,
+            full_snapshot_target_milestone_id: None,
+        };
+
+        let output_count = match kind {
+            SnapshotKind::Full => {
+                let ledger_milestone_index = read_u32(reader)?;
+                header.ledger_milestone_index = Some(ledger_milestone_index);
+                // treasury output milestone id and amount
+                read_array::<{ MilestoneId::LENGTH + core::mem::size_of::<u64>() }>(reader)?;
+                // protocol parameters milestone option
+                let length = read_u16(reader)?;
+                read_bytes(reader, length as usize)?;
+                read_u64(reader)?
+            }
+            SnapshotKind::Delta => {
+                header.full_snapshot_target_milestone_id = Some(MilestoneId::new(read_array(reader)?));
+                // SEP file offset
+                read_u64(reader)?;
+                0
+            }
+        };
+        let milestone_diff_count = read_u32(reader)?;
+        // SEP count
+        read_u16(reader)?;
+
+        let ledger_index = header.ledger_milestone_index.unwrap_or(target_milestone_index);
+        let mut outputs = Vec::new();
+        for _ in 0..output_count {
+            let output = read_output(reader, protocol_parameters, ledger_index)?;
+            if filter(output.output()) {
+                outputs.push(output);
+            }
+        }
+
+        // The counts aren't trusted, the capacity grows with the diffs that are actually read
+        let mut milestone_diffs = Vec::new();
+        for _ in 0..milestone_diff_count {
+            let milestone = MilestonePayload::unpack_verified(read_packed_bytes(reader)?, protocol_parameters)
+                .map_err(|e| Error::InvalidSnapshot(format!("invalid milestone: {e:?}")))?;
+            let milestone_index = *milestone.essence().index();
+            let milestone_timestamp = milestone.essence().timestamp();
+
+            if milestone.essence().options().receipt().is_some() {
+                // consumed treasury output milestone id and amount
+                read_array::<{ MilestoneId::LENGTH + core::mem::size_of::<u64>() }>(reader)?;
+            }
+
+            let mut created = Vec::new();
+            for _ in 0..read_u32(reader)? {
+                let output = read_output(reader, protocol_parameters, milestone_index)?;
+                if filter(output.output()) {
+                    created.push(output);
+                }
+            }
+
+            let mut consumed = Vec::new();
+            for _ in 0..read_u32(reader)? {
+                let output = read_output(reader, protocol_parameters, milestone_index)?;
+                let transaction_id_spent = TransactionId::new(read_array(reader)?);
+                let milestone_index_spent = read_u32(reader)?;
+                let milestone_timestamp_spent = read_u32(reader)?;
+                if filter(output.output()) {
+                    let metadata = output.metadata();
+                    consumed.push(OutputWithMetadata::new(
+                        output.output().clone(),
+                        OutputMetadata::new(
+                            *metadata.block_id(),
+                            *metadata.output_id(),
+                            true,
+                            Some(milestone_index_spent),
+                            Some(milestone_timestamp_spent),
+                            Some(transaction_id_spent),
+                            metadata.milestone_index_booked(),
+                            metadata.milestone_timestamp_booked(),
+                            milestone_index,
+                        ),
+                    ));
+                }
+            }
+
+            milestone_diffs.push(MilestoneDiff {
+                milestone_index,
+                milestone_timestamp,
+                created,
+                consumed,
+            });
+        }
+
+        Ok(Self {
+            header,
+            outputs,
+            milestone_diffs,
+        })
+    }
+
+    /// Returns the outputs that are unspent at the target milestone of the optional delta snapshot, or of the snapshot
+    /// without one. The milestone diffs of a full snapshot lead from its ledger milestone back to its target milestone
+    /// and are rolled back, the ones of a delta snapshot lead from the target milestone of the full snapshot to its own
+    /// and are applied.
+    pub fn unspent_outputs(&self, delta: Option<&Self>) -> Vec<OutputWithMetadata> {
+        let mut outputs = self
+            .outputs
+            .iter()
+            .map(|output| (*output.metadata().output_id(), output.clone()))
+            .collect::<HashMap<_, _>>();
+
+        for snapshot in core::iter::once(self).chain(delta) {
+            let mut milestone_diffs = snapshot.milestone_diffs.iter().collect::<Vec<_>>();
+            match snapshot.header.kind {
+                SnapshotKind::Full => {
+                    // Rolled back from the latest milestone on
+                    milestone_diffs.sort_by_key(|milestone_diff| core::cmp::Reverse(milestone_diff.milestone_index));
+                    for milestone_diff in milestone_diffs {
+                        for output in &milestone_diff.created {
+                            outputs.remove(output.metadata().output_id());
+                        }
+                        for output in &milestone_diff.consumed {
+                            outputs.insert(*output.metadata().output_id(), unspent(output));
+                        }
+                    }
+                }
+                SnapshotKind::Delta => {
+                    milestone_diffs.sort_by_key(|milestone_diff| milestone_diff.milestone_index);
+                    for milestone_diff in milestone_diffs {
+                        for output in &milestone_diff.created {
+                            outputs.insert(*output.metadata().output_id(), output.clone());
+                        }
+                        for output in &milestone_diff.consumed {
+                            outputs.remove(output.metadata().output_id());
+                        }
+                    }
+                }
+            }
+        }
+
+        outputs.into_values().collect()
+    }
+
+    /// Returns the index of the latest milestone contained in the snapshot and the optional delta snapshot.
+    pub fn ledger_index(&self, delta: Option<&Self>) -> u32 {
+        delta
+            .map(|delta| delta.header.target_milestone_index)
+            .unwrap_or(self.header.target_milestone_index)
+    }
+}
+
+// Returns an output that was consumed by a rolled back milestone with the metadata it had before
+fn unspent(output: &OutputWithMetadata) -> OutputWithMetadata {
+    let metadata = output.metadata();
+    OutputWithMetadata::new(
+        output.output().clone(),
+        OutputMetadata::new(
+            *metadata.block_id(),
+            *metadata.output_id(),
+            false,
+            None,
+            None,
+            None,
+            metadata.milestone_index_booked(),
+            metadata.milestone_timestamp_booked(),
+            metadata.ledger_index(),
+        ),
+    )
+}
+
+fn read_output(
+    reader: &mut impl Read,
+    protocol_parameters: &ProtocolParameters,
+    ledger_index: u32,
+) -> crate::wallet::Result<OutputWithMetadata> {
+    let output_id = OutputId::try_from(read_array::<{ OutputId::LENGTH }>(reader)?)?;
+    let block_id = BlockId::new(read_array(reader)?);
+    let milestone_index_booked = read_u32(reader)?;
+    let milestone_timestamp_booked = read_u32(reader)?;
+    let output = Output::unpack_verified(read_packed_bytes(reader)?, protocol_parameters)
+        .map_err(|e| Error::InvalidSnapshot(format!("invalid output {output_id}: {e:?}")))?;
+
+    Ok(OutputWithMetadata::new(
+        output,
+        OutputMetadata::new(
+            block_id,
+            output_id,
+            false,
+            None,
+            None,
+            None,
+            milestone_index_booked,
+            milestone_timestamp_booked,
+            ledger_index,
+        ),
+    ))
+}
+
+pub(crate) fn read_array<const N: usize>(reader: &mut impl Read) -> crate::wallet::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads `length` bytes. The length isn't trusted, only the bytes that are actually read are allocated.
+pub(crate) fn read_bytes(reader: &mut impl Read, length: usize) -> crate::wallet::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != length {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(bytes)
+}
+
+// Reads the length prefixed bytes of an output or milestone, which can't be larger than a block
+fn read_packed_bytes(reader: &mut impl Read) -> crate::wallet::Result<Vec<u8>> {
+    let length = read_u32(reader)? as usize;
+    if length > Block::LENGTH_MAX {
+        return Err(Error::InvalidSnapshot(format!(
+            "length {length} exceeds the maximum block length"
+        )));
+    }
+    read_bytes(reader, length)
+}
+
+pub(crate) fn read_u8(reader: &mut impl Read) -> crate::wallet::Result<u8> {
+    Ok(u8::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u16(reader: &mut impl Read) -> crate::wallet::Result<u16> {
+    Ok(u16::from_le_bytes(read_array(reader)?))
+}
+
+pub(crate) fn read_u32(reader: &mut impl Read) -> crate::wallet::Result<u32> {
+    Ok(u32::from_le_bytes(read_array(reader)?))
+}
+
+pub(crate) fn read_u64(reader: &mut impl Read) -> crate::wallet::Result<u64> {
+    Ok(u64::from_le_bytes(read_array(reader)?))
+}
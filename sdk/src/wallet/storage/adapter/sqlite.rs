@@ -0,0 +1,249 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::Path, sync::Arc};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::sync::Mutex;
+
+use crate::{
+    client::storage::StorageAdapter,
+    types::{block::address::ToBech32Ext, TryFromDto},
+    wallet::{
+        account::{AccountDetails, AccountDetailsDto},
+        storage::constants::ACCOUNT_INDEXATION_KEY,
+    },
+};
+
+/// The schema of the database. The `records` table is used by the storage manager, the other tables contain the
+/// accounts, addresses and outputs in a normalized form, so they can be queried by other applications.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS records (
+        key TEXT PRIMARY KEY NOT NULL,
+        value BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS accounts (
+        account_index INTEGER PRIMARY KEY NOT NULL,
+        alias TEXT NOT NULL,
+        coin_type INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS addresses (
+        account_index INTEGER NOT NULL,
+        address TEXT NOT NULL,
+        key_index INTEGER NOT NULL,
+        internal INTEGER NOT NULL,
+        used INTEGER NOT NULL,
+        PRIMARY KEY (account_index, address)
+    );
+    CREATE TABLE IF NOT EXISTS outputs (
+        account_index INTEGER NOT NULL,
+        output_id TEXT NOT NULL,
+        address TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        is_spent INTEGER NOT NULL,
+        PRIMARY KEY (account_index, output_id)
+    );
+    CREATE TABLE IF NOT EXISTS events (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        account_index INTEGER NOT NULL,
+        kind INTEGER NOT NULL,
+        event TEXT NOT NULL
+    );
+";
+
+/// SQLite storage adapter, storing the whole wallet in a single file which can be encrypted with SQLCipher.
+#[derive(Clone, Debug)]
+pub struct SqliteStorageAdapter {
+    pub(crate) connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorageAdapter {
+    /// Opens or creates the database at `path`. If an encryption key is provided, the database is encrypted with
+    /// SQLCipher using it as raw key, records are then not encrypted again by the storage manager.
+    pub fn new(path: impl AsRef<Path>, encryption_key: Option<&[u8; 32]>) -> crate::wallet::Result<Self> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let connection = Connection::open(path)?;
+        if let Some(encryption_key) = encryption_key {
+            // A 64 characters blob literal is used as raw key, without key derivation
+            connection.pragma_update(None, "key", format!("x'{}'", hex::encode(encryption_key)))?;
+        }
+        connection.execute_batch(SCHEMA)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Copies all records of a RocksDB storage into this storage. If the RocksDB storage was encrypted, its encryption
+    /// key is needed to decrypt the records.
+    #[cfg(feature = "rocksdb")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rocksdb")))]
+    pub async fn import_from_rocksdb(
+        &self,
+        rocksdb_storage: &super::rocksdb::RocksdbStorageAdapter,
+        encryption_key: Option<&[u8; 32]>,
+    ) -> crate::wallet::Result<()> {
+        let records = {
+            let db = rocksdb_storage.db.lock().await;
+            db.iterator(rocksdb::IteratorMode::Start)
+                .map(|record| {
+                    let (key, value) = record?;
+                    let key = String::from_utf8(key.to_vec())
+                        .map_err(|_| crate::wallet::Error::Storage("invalid record key".to_string()))?;
+                    let value = match encryption_key {
+                        Some(encryption_key) => crypto::ciphers::chacha::aead_decrypt(encryption_key, &value)?,
+                        None => value.to_vec(),
+                    };
+                    Ok((key, value))
+                })
+                .collect::<crate::wallet::Result<Vec<_>>>()?
+        };
+
+        for (key, value) in records {
+            self.set_bytes(&key, &value).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores a wallet event, can be used as event listener callback to keep a history of the events.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn save_event(&self, event: &crate::wallet::events::types::Event) -> crate::wallet::Result<()> {
+        self.connection.lock().await.execute(
+            "INSERT INTO events (account_index, kind, event) VALUES (?1, ?2, ?3)",
+            params![
+                event.account_index,
+                event.event.kind() as u8,
+                serde_json::to_string(&event.event)?
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Returns the stored events of an account as JSON, in the order they were saved.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn events(&self, account_index: u32) -> crate::wallet::Result<Vec<serde_json::Value>> {
+        let connection = self.connection.lock().await;
+        let mut statement = connection.prepare("SELECT event FROM events WHERE account_index = ?1 ORDER BY id")?;
+        let events = statement
+            .query_map(params![account_index], |row| row.get::<_, String>(0))?
+            .map(|event| Ok(serde_json::from_str(&event?)?))
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+        Ok(events)
+    }
+}
+
+/// Replaces the normalized rows of an account with the ones from its stored details.
+fn update_account_rows(connection: &Connection, account: &AccountDetails) -> rusqlite::Result<()> {
+    let account_index = *account.index();
+    delete_account_rows(connection, account_index)?;
+
+    connection.execute(
+        "INSERT INTO accounts (account_index, alias, coin_type) VALUES (?1, ?2, ?3)",
+        params![account_index, account.alias(), account.coin_type()],
+    )?;
+
+    for address in account.public_addresses().iter().chain(account.internal_addresses()) {
+        connection.execute(
+            "INSERT OR REPLACE INTO addresses (account_index, address, key_index, internal, used) VALUES (?1, ?2, ?3, \
+             ?4, ?5)",
+            params![
+                account_index,
+                address.address().to_string(),
+                address.key_index(),
+                address.internal(),
+                address.used()
+            ],
+        )?;
+    }
+
+    // Accounts always have at least one public address, its hrp is used for the output addresses
+    if let Some(hrp) = account
+        .public_addresses()
+        .first()
+        .map(|address| *address.address().hrp())
+    {
+        for output_data in account.outputs().values() {
+            connection.execute(
+                "INSERT INTO outputs (account_index, output_id, address, amount, is_spent) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    account_index,
+                    output_data.output_id.to_string(),
+                    output_data.address.to_bech32(hrp).to_string(),
+                    // Stored as text, because SQLite integers are signed
+                    output_data.output.amount().to_string(),
+                    output_data.is_spent
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn delete_account_rows(connection: &Connection, account_index: u32) -> rusqlite::Result<()> {
+    for table in ["accounts", "addresses", "outputs"] {
+        connection.execute(
+            &format!("DELETE FROM {table} WHERE account_index = ?1"),
+            params![account_index],
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the account index if the key is the key of the account details.
+fn account_index_from_key(key: &str) -> Option<u32> {
+    key.strip_prefix(ACCOUNT_INDEXATION_KEY)?.parse().ok()
+}
+
+#[async_trait::async_trait]
+impl StorageAdapter for SqliteStorageAdapter {
+    type Error = crate::wallet::Error;
+
+    async fn get_bytes(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
+        Ok(self
+            .connection
+            .lock()
+            .await
+            .query_row("SELECT value FROM records WHERE key = ?1", params![key], |row| {
+                row.get(0)
+            })
+            .optional()?)
+    }
+
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> crate::wallet::Result<()> {
+        let mut connection = self.connection.lock().await;
+        let transaction = connection.transaction()?;
+        transaction.execute(
+            "INSERT OR REPLACE INTO records (key, value) VALUES (?1, ?2)",
+            params![key, record],
+        )?;
+        if account_index_from_key(key).is_some() {
+            // Records that can't be read, because they were encrypted by the storage manager, are only stored as is
+            match serde_json::from_slice::<AccountDetailsDto>(record)
+                .map_err(crate::wallet::Error::from)
+                .and_then(AccountDetails::try_from_dto)
+            {
+                Ok(account) => update_account_rows(&transaction, &account)?,
+                Err(err) => log::debug!("[SqliteStorageAdapter] couldn't normalize account record {key}: {err}"),
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> crate::wallet::Result<()> {
+        let mut connection = self.connection.lock().await;
+        let transaction = connection.transaction()?;
+        transaction.execute("DELETE FROM records WHERE key = ?1", params![key])?;
+        if let Some(account_index) = account_index_from_key(key) {
+            delete_account_rows(&transaction, account_index)?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+}
@@ -8,6 +8,17 @@ pub const DEFAULT_STORAGE_PATH: &str = "./storage";
 #[cfg(feature = "rocksdb")]
 pub(crate) const ROCKSDB_FOLDERNAME: &str = "walletdb";
 
+/// The name of the SQLite database file in the storage folder.
+#[cfg(feature = "sqlite")]
+pub(crate) const SQLITE_FILENAME: &str = "wallet.sqlite";
+
+/// The name of the file in the storage folder that is locked while a wallet uses the storage.
+pub(crate) const STORAGE_LOCK_FILENAME: &str = "wallet.lock";
+
+/// The name of the file in the storage folder holding the storage encryption key wrapped by a key in Stronghold.
+#[cfg(feature = "stronghold")]
+pub(crate) const WRAPPED_STORAGE_KEY_FILENAME: &str = "storage-key";
+
 pub const fn default_storage_path() -> &'static str {
     #[cfg(feature = "rocksdb")]
     return ROCKSDB_FOLDERNAME;
@@ -23,6 +34,16 @@ pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
+pub(crate) const ACCOUNT_SYNC_CHECKPOINT: &str = "sync-checkpoint";
+pub(crate) const ACCOUNT_SUBMISSION_JOURNAL: &str = "submission-journal";
+pub(crate) const ACCOUNT_PAYMENT_REQUESTS: &str = "payment-requests";
+pub(crate) const ACCOUNT_SCHEDULED_SENDS: &str = "scheduled-sends";
+
+pub(crate) const ADDRESS_BOOK_KEY: &str = "address-book";
+pub(crate) const TRANSFER_TEMPLATES_KEY: &str = "transfer-templates";
+
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_KEY: &str = "event-log";
 
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
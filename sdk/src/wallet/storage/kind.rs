@@ -10,6 +10,9 @@ pub enum StorageKind {
     /// RocksDB storage.
     #[cfg(feature = "rocksdb")]
     Rocksdb,
+    /// SQLite storage, in a single file that can be encrypted.
+    #[cfg(feature = "sqlite")]
+    Sqlite,
     /// Storage backed by a Map in memory.
     Memory,
     /// Wasm storage.
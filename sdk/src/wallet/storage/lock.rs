@@ -0,0 +1,96 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs::File, path::Path};
+
+use crate::wallet::{storage::constants::STORAGE_LOCK_FILENAME, Error};
+
+/// An advisory lock of a storage folder, held as long as the storage is open, so the same storage can't be used by two
+/// wallets at the same time. The lock is released by the operating system when the file is closed, also if the process
+/// crashes, so no stale lock is left behind.
+#[derive(Debug)]
+pub(crate) struct StorageLock {
+    _file: File,
+}
+
+impl StorageLock {
+    /// Locks the storage folder, creating it if it doesn't exist yet. Returns an error if the storage is used by
+    /// another wallet.
+    #[cfg_attr(not(any(feature = "rocksdb", feature = "sqlite")), allow(dead_code))]
+    pub(crate) fn acquire(storage_path: &Path) -> crate::wallet::Result<Self> {
+        std::fs::create_dir_all(storage_path)?;
+        let lock_path = storage_path.join(STORAGE_LOCK_FILENAME);
+        let used_by_other_wallet = || {
+            Error::Storage(format!(
+                "{} is used by another wallet, possibly in another process",
+                storage_path.display()
+            ))
+        };
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::io::AsRawFd;
+
+            let file = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .open(lock_path)?;
+            // SAFETY: the file descriptor is valid as long as the file is open
+            if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+                let error = std::io::Error::last_os_error();
+                return Err(if error.kind() == std::io::ErrorKind::WouldBlock {
+                    used_by_other_wallet()
+                } else {
+                    error.into()
+                });
+            }
+
+            Ok(Self { _file: file })
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::OpenOptionsExt;
+
+            // Without sharing, the file can't be opened again until it's closed
+            const ERROR_SHARING_VIOLATION: i32 = 32;
+            match std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .share_mode(0)
+                .open(lock_path)
+            {
+                Ok(file) => Ok(Self { _file: file }),
+                Err(error) if error.raw_os_error() == Some(ERROR_SHARING_VIOLATION) => Err(used_by_other_wallet()),
+                Err(error) => Err(error.into()),
+            }
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = used_by_other_wallet;
+            Ok(Self {
+                _file: std::fs::OpenOptions::new().write(true).create(true).open(lock_path)?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn lock_storage() {
+        let storage_path = Path::new("test-storage/lock_storage");
+        std::fs::remove_dir_all(storage_path).ok();
+
+        let lock = StorageLock::acquire(storage_path).unwrap();
+        assert!(matches!(StorageLock::acquire(storage_path), Err(Error::Storage(_))));
+        drop(lock);
+        assert!(StorageLock::acquire(storage_path).is_ok());
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -1,16 +1,27 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use futures::{StreamExt, TryStreamExt};
+#[cfg(feature = "events")]
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::Event;
 use crate::{
     client::storage::StorageAdapter,
     types::TryFromDto,
     wallet::{
-        account::{AccountDetails, AccountDetailsDto, SyncOptions},
+        account::{
+            types::TransactionDto, AccountDetails, AccountDetailsDto, PaymentRequest, ScheduledSend, SyncCheckpoint,
+            SyncOptions,
+        },
+        address_book::AddressBook,
         migration::migrate,
         storage::{constants::*, DynStorageAdapter, Storage},
+        transfer_template::TransferTemplates,
     },
 };
 
@@ -22,15 +33,32 @@ pub(crate) struct StorageManager {
     account_indexes: Vec<u32>,
 }
 
+/// The range of sequence numbers of the events in the event log.
+#[cfg(feature = "events")]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventLogHead {
+    /// The sequence number of the oldest event that wasn't pruned.
+    first_seq: u64,
+    /// The sequence number of the next appended event.
+    next_seq: u64,
+}
+
 impl StorageManager {
     pub(crate) async fn new(
         storage: impl DynStorageAdapter + 'static,
         encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
     ) -> crate::wallet::Result<Self> {
-        let storage = Storage {
+        Self::open(Storage {
             inner: Box::new(storage) as _,
             encryption_key: encryption_key.into(),
-        };
+            _lock: None,
+        })
+        .await
+    }
+
+    /// Migrates the storage and loads the indexes of the stored accounts.
+    pub(crate) async fn open(storage: Storage) -> crate::wallet::Result<Self> {
         migrate(&storage).await?;
 
         // Get the db version or set it
@@ -90,8 +118,23 @@ impl StorageManager {
         .await
     }
 
+    /// Removes the account and all records stored for it.
     pub(crate) async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
         self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await?;
+        for record in [
+            ACCOUNT_SYNC_OPTIONS,
+            ACCOUNT_SYNC_CHECKPOINT,
+            ACCOUNT_SUBMISSION_JOURNAL,
+            ACCOUNT_PAYMENT_REQUESTS,
+            ACCOUNT_SCHEDULED_SENDS,
+        ] {
+            self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{record}"))
+                .await?;
+        }
+        #[cfg(feature = "participation")]
+        for record in [PARTICIPATION_EVENTS, PARTICIPATION_CACHED_OUTPUTS] {
+            self.delete(&format!("{record}{account_index}")).await?;
+        }
         self.account_indexes.retain(|a| a != &account_index);
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
@@ -112,6 +155,144 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn set_sync_checkpoint(
+        &self,
+        account_index: u32,
+        sync_checkpoint: &SyncCheckpoint,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CHECKPOINT}");
+        self.set(&key, sync_checkpoint).await
+    }
+
+    pub(crate) async fn get_sync_checkpoint(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<SyncCheckpoint>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CHECKPOINT}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_submission_journal(
+        &self,
+        account_index: u32,
+        submission_journal: &HashMap<String, TransactionDto>,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SUBMISSION_JOURNAL}");
+        self.set(&key, submission_journal).await
+    }
+
+    pub(crate) async fn get_submission_journal(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<HashMap<String, TransactionDto>>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SUBMISSION_JOURNAL}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_payment_requests(
+        &self,
+        account_index: u32,
+        payment_requests: &[PaymentRequest],
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_PAYMENT_REQUESTS}");
+        self.set(&key, payment_requests).await
+    }
+
+    pub(crate) async fn get_payment_requests(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<Vec<PaymentRequest>>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_PAYMENT_REQUESTS}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_scheduled_sends(
+        &self,
+        account_index: u32,
+        scheduled_sends: &[ScheduledSend],
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SCHEDULED_SENDS}");
+        self.set(&key, scheduled_sends).await
+    }
+
+    pub(crate) async fn get_scheduled_sends(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<Vec<ScheduledSend>>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SCHEDULED_SENDS}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn save_address_book(&self, address_book: &AddressBook) -> crate::wallet::Result<()> {
+        self.set(ADDRESS_BOOK_KEY, address_book).await
+    }
+
+    pub(crate) async fn get_address_book(&self) -> crate::wallet::Result<AddressBook> {
+        Ok(self.get(ADDRESS_BOOK_KEY).await?.unwrap_or_default())
+    }
+
+    pub(crate) async fn save_transfer_templates(
+        &self,
+        transfer_templates: &TransferTemplates,
+    ) -> crate::wallet::Result<()> {
+        self.set(TRANSFER_TEMPLATES_KEY, transfer_templates).await
+    }
+
+    pub(crate) async fn get_transfer_templates(&self) -> crate::wallet::Result<TransferTemplates> {
+        Ok(self.get(TRANSFER_TEMPLATES_KEY).await?.unwrap_or_default())
+    }
+
+    #[cfg(feature = "events")]
+    async fn get_event_log_head(&self) -> crate::wallet::Result<EventLogHead> {
+        Ok(self.get(EVENT_LOG_KEY).await?.unwrap_or_default())
+    }
+
+    /// Appends an event to the event log and returns its sequence number.
+    #[cfg(feature = "events")]
+    pub(crate) async fn append_event(&mut self, event: &Event) -> crate::wallet::Result<u64> {
+        let mut head = self.get_event_log_head().await?;
+        let seq = head.next_seq;
+        let event = Event {
+            seq: Some(seq),
+            ..event.clone()
+        };
+
+        // The record is written before the head, so a crash in between only leaves an unreferenced record which is
+        // overwritten by the next append
+        self.set(&format!("{EVENT_LOG_KEY}-{seq}"), &event).await?;
+        head.next_seq += 1;
+        self.set(EVENT_LOG_KEY, &head).await?;
+
+        Ok(seq)
+    }
+
+    /// Returns the events with a sequence number of at least `from_seq`.
+    #[cfg(feature = "events")]
+    pub(crate) async fn get_events(&self, from_seq: u64) -> crate::wallet::Result<Vec<Event>> {
+        let head = self.get_event_log_head().await?;
+
+        futures::stream::iter(from_seq.max(head.first_seq)..head.next_seq)
+            .filter_map(|seq| async move { self.get::<Event>(&format!("{EVENT_LOG_KEY}-{seq}")).await.transpose() })
+            .try_collect::<Vec<_>>()
+            .await
+    }
+
+    /// Removes the events with a sequence number lower than `before_seq`.
+    #[cfg(feature = "events")]
+    pub(crate) async fn prune_events(&mut self, before_seq: u64) -> crate::wallet::Result<()> {
+        let mut head = self.get_event_log_head().await?;
+        let before_seq = before_seq.min(head.next_seq);
+        if before_seq <= head.first_seq {
+            return Ok(());
+        }
+
+        for seq in head.first_seq..before_seq {
+            self.delete(&format!("{EVENT_LOG_KEY}-{seq}")).await?;
+        }
+        head.first_seq = before_seq;
+        self.set(EVENT_LOG_KEY, &head).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -136,9 +317,17 @@ mod tests {
     use serde::{Deserialize, Serialize};
 
     use super::*;
+    #[cfg(feature = "events")]
+    use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
     use crate::{
         client::secret::SecretManager,
-        wallet::{core::operations::storage::SaveLoadWallet, storage::adapter::memory::Memory, WalletBuilder},
+        wallet::{
+            account::ScheduledTransfer,
+            core::operations::storage::SaveLoadWallet,
+            migration::{check_migrations, MigrationVersion, MIGRATION_VERSION_KEY},
+            storage::adapter::memory::Memory,
+            Error, WalletBuilder,
+        },
     };
 
     #[tokio::test]
@@ -162,6 +351,43 @@ mod tests {
         assert_eq!(Some(rec), storage_manager.get::<Record>("key").await.unwrap());
     }
 
+    #[tokio::test]
+    async fn migrations() {
+        let status = check_migrations(&Memory::default()).await.unwrap();
+        assert!(status.current.is_none());
+        assert!(!status.is_up_to_date());
+
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let status = check_migrations(&storage_manager).await.unwrap();
+        assert!(status.is_up_to_date());
+
+        // A version written by a newer SDK is neither migrated nor opened
+        let mut newer_version = status.current.unwrap();
+        newer_version.id += 1;
+        storage_manager
+            .set(MIGRATION_VERSION_KEY, &newer_version)
+            .await
+            .unwrap();
+        assert!(matches!(
+            check_migrations(&storage_manager).await,
+            Err(Error::Migration(_))
+        ));
+        let storage = Storage {
+            inner: Box::new(Memory::default()) as _,
+            encryption_key: None,
+            _lock: None,
+        };
+        storage.set(MIGRATION_VERSION_KEY, &newer_version).await.unwrap();
+        assert!(matches!(StorageManager::open(storage).await, Err(Error::Migration(_))));
+        assert_eq!(
+            storage_manager
+                .get::<MigrationVersion>(MIGRATION_VERSION_KEY)
+                .await
+                .unwrap(),
+            Some(newer_version)
+        );
+    }
+
     #[tokio::test]
     async fn save_remove_account() {
         let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
@@ -174,8 +400,35 @@ mod tests {
         assert_eq!(accounts.len(), 1);
         assert_eq!(accounts[0].alias(), "Alice");
 
+        storage_manager
+            .set_default_sync_options(0, &SyncOptions::default())
+            .await
+            .unwrap();
+        storage_manager
+            .set_submission_journal(0, &HashMap::new())
+            .await
+            .unwrap();
+        storage_manager.set_payment_requests(0, &[]).await.unwrap();
+        let scheduled_send = ScheduledSend {
+            id: "scheduled-0x01".to_string(),
+            execute_at: 1_700_000_000,
+            transfer: ScheduledTransfer::Template {
+                id: "payroll".to_string(),
+                overrides: None,
+            },
+            attempts: 0,
+            last_error: None,
+        };
+        storage_manager.set_scheduled_sends(0, &[scheduled_send]).await.unwrap();
+        assert_eq!(storage_manager.get_scheduled_sends(0).await.unwrap().unwrap().len(), 1);
+
         storage_manager.remove_account(0).await.unwrap();
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
+        // The records of the account are removed with it, so they aren't loaded for a new account with the same index
+        assert!(storage_manager.get_default_sync_options(0).await.unwrap().is_none());
+        assert!(storage_manager.get_submission_journal(0).await.unwrap().is_none());
+        assert!(storage_manager.get_payment_requests(0).await.unwrap().is_none());
+        assert!(storage_manager.get_scheduled_sends(0).await.unwrap().is_none());
     }
 
     #[tokio::test]
@@ -198,4 +451,51 @@ mod tests {
                 .is_some()
         );
     }
+
+    #[cfg(feature = "events")]
+    #[tokio::test]
+    async fn append_replay_prune_events() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        assert!(storage_manager.get_events(0).await.unwrap().is_empty());
+
+        for account_index in 0..3 {
+            let event = Event {
+                account_index,
+                seq: None,
+                event: WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+            };
+            assert_eq!(
+                storage_manager.append_event(&event).await.unwrap(),
+                account_index as u64
+            );
+        }
+
+        let events = storage_manager.get_events(1).await.unwrap();
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.account_index, event.seq))
+                .collect::<Vec<_>>(),
+            [(1, Some(1)), (2, Some(2))]
+        );
+
+        storage_manager.prune_events(2).await.unwrap();
+        assert!(
+            storage_manager
+                .get::<Event>(&format!("{EVENT_LOG_KEY}-1"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let events = storage_manager.get_events(0).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, Some(2));
+
+        // Sequence numbers aren't reused after pruning
+        let event = Event {
+            seq: None,
+            ..events[0].clone()
+        };
+        assert_eq!(storage_manager.append_event(&event).await.unwrap(), 3);
+    }
 }
@@ -7,6 +7,8 @@ pub mod adapter;
 pub mod constants;
 /// Storage kind.
 mod kind;
+/// Storage lock.
+mod lock;
 /// Storage manager.
 mod manager;
 /// Storage options.
@@ -29,6 +31,48 @@ use crate::client::storage::StorageAdapter;
 pub struct Storage {
     pub(crate) inner: Box<dyn DynStorageAdapter>,
     encryption_key: Option<Zeroizing<[u8; 32]>>,
+    _lock: Option<self::lock::StorageLock>,
+}
+
+impl Storage {
+    /// Opens the storage of the kind and at the path of the options, without migrating it. The storage folder is
+    /// locked until the storage is dropped, an error is returned if it's already used by another wallet.
+    pub(crate) fn from_options(storage_options: &StorageOptions) -> crate::wallet::Result<Self> {
+        #[cfg_attr(not(feature = "sqlite"), allow(clippy::match_single_binding))]
+        match storage_options.kind {
+            #[cfg(feature = "sqlite")]
+            StorageKind::Sqlite => {
+                let lock = self::lock::StorageLock::acquire(&storage_options.path)?;
+                let storage = self::adapter::sqlite::SqliteStorageAdapter::new(
+                    storage_options.path.join(constants::SQLITE_FILENAME),
+                    storage_options.encryption_key(),
+                )?;
+                // The whole database is encrypted already, so the records aren't encrypted again
+                Ok(Self {
+                    inner: Box::new(storage) as _,
+                    encryption_key: None,
+                    _lock: Some(lock),
+                })
+            }
+            _ => {
+                #[cfg(feature = "rocksdb")]
+                let lock = Some(self::lock::StorageLock::acquire(&storage_options.path)?);
+                // The memory storage can't be shared anyway
+                #[cfg(not(feature = "rocksdb"))]
+                let lock = None;
+                #[cfg(feature = "rocksdb")]
+                let storage = self::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
+                #[cfg(not(feature = "rocksdb"))]
+                let storage = self::adapter::memory::Memory::default();
+
+                Ok(Self {
+                    inner: Box::new(storage) as _,
+                    encryption_key: storage_options.encryption_key.clone(),
+                    _lock: lock,
+                })
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -82,6 +126,7 @@ mod tests {
         let storage = Storage {
             inner: Box::<Memory>::default(),
             encryption_key: None,
+            _lock: None,
         };
 
         let rec = Record {
@@ -110,6 +155,7 @@ mod tests {
         let storage = Storage {
             inner: Box::<Memory>::default(),
             encryption_key: Some(Zeroizing::new(encryption_key)),
+            _lock: None,
         };
 
         let rec = Record {
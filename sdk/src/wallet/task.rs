@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #[cfg(not(target_family = "wasm"))]
-pub(crate) fn spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
+pub(crate) fn spawn<F>(future: F) -> crate::client::runtime::JoinHandle<F::Output>
 where
     F: futures::Future + Send + 'static,
     F::Output: Send + 'static,
 {
-    tokio::task::spawn(future)
+    crate::client::runtime::spawn(future)
 }
 
 #[cfg(target_family = "wasm")]
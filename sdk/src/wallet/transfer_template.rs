@@ -0,0 +1,222 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{account::TransactionOptionsDto, Error, Result};
+
+/// A recipient and amount of a [`TransferTemplate`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateDestination {
+    /// A bech32 address or the label of a contact in the address book, resolved when the template is sent.
+    pub recipient: String,
+    /// The amount of base coins.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+}
+
+impl TemplateDestination {
+    /// Creates a new [`TemplateDestination`].
+    pub fn new(recipient: impl Into<String>, amount: u64) -> Self {
+        Self {
+            recipient: recipient.into(),
+            amount,
+        }
+    }
+}
+
+/// A transfer that is stored in the wallet and can be sent repeatedly with
+/// [`Account::send_from_template()`](crate::wallet::Account::send_from_template), e.g. for recurring payouts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferTemplate {
+    /// The unique id of the template.
+    pub id: String,
+    /// The recipients and amounts, one output is created per destination.
+    pub destinations: Vec<TemplateDestination>,
+    /// Tag feature of the outputs.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag: Option<Vec<u8>>,
+    /// Metadata feature of the outputs.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub metadata: Option<Vec<u8>>,
+    /// The options of the transactions.
+    #[serde(default)]
+    pub options: Option<TransactionOptionsDto>,
+}
+
+impl TransferTemplate {
+    /// Creates a new [`TransferTemplate`].
+    pub fn new(id: impl Into<String>, destinations: impl IntoIterator<Item = TemplateDestination>) -> Self {
+        Self {
+            id: id.into(),
+            destinations: destinations.into_iter().collect(),
+            tag: None,
+            metadata: None,
+            options: None,
+        }
+    }
+
+    /// Sets the tag feature of the outputs.
+    pub fn with_tag(mut self, tag: impl Into<Option<Vec<u8>>>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Sets the metadata feature of the outputs.
+    pub fn with_metadata(mut self, metadata: impl Into<Option<Vec<u8>>>) -> Self {
+        self.metadata = metadata.into();
+        self
+    }
+
+    /// Sets the options of the transactions.
+    pub fn with_options(mut self, options: impl Into<Option<TransactionOptionsDto>>) -> Self {
+        self.options = options.into();
+        self
+    }
+
+    /// Returns the template with the set fields of the overrides replacing its own.
+    pub fn apply(mut self, overrides: TransferTemplateOverrides) -> Self {
+        if let Some(destinations) = overrides.destinations {
+            self.destinations = destinations;
+        }
+        if let Some(tag) = overrides.tag {
+            self.tag = Some(tag);
+        }
+        if let Some(metadata) = overrides.metadata {
+            self.metadata = Some(metadata);
+        }
+        if let Some(options) = overrides.options {
+            self.options = Some(options);
+        }
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.id.is_empty() {
+            return Err(Error::InvalidTransferTemplate("empty id".to_string()));
+        }
+        if self.destinations.is_empty() {
+            return Err(Error::InvalidTransferTemplate(format!(
+                "{} has no destinations",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Fields that replace the ones of a [`TransferTemplate`] for a single transfer, e.g. the amounts of this payout or a
+/// request id in the options.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferTemplateOverrides {
+    /// Replaces all destinations of the template.
+    #[serde(default)]
+    pub destinations: Option<Vec<TemplateDestination>>,
+    /// Replaces the tag feature of the outputs.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub tag: Option<Vec<u8>>,
+    /// Replaces the metadata feature of the outputs.
+    #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
+    pub metadata: Option<Vec<u8>>,
+    /// Replaces the options of the transaction.
+    #[serde(default)]
+    pub options: Option<TransactionOptionsDto>,
+}
+
+/// The transfer templates of a wallet, by id.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TransferTemplates(BTreeMap<String, TransferTemplate>);
+
+impl TransferTemplates {
+    /// Returns a template.
+    pub fn get(&self, id: &str) -> Option<&TransferTemplate> {
+        self.0.get(id)
+    }
+
+    /// Returns all templates, sorted by id.
+    pub fn templates(&self) -> Vec<TransferTemplate> {
+        self.0.values().cloned().collect()
+    }
+
+    /// Returns the number of templates.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether there are no templates.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds a template, replacing and returning the one with the same id. Fails if the id is empty or the template
+    /// has no destinations.
+    pub fn insert(&mut self, template: TransferTemplate) -> Result<Option<TransferTemplate>> {
+        template.validate()?;
+        Ok(self.0.insert(template.id.clone(), template))
+    }
+
+    /// Removes a template.
+    pub fn remove(&mut self, id: &str) -> Option<TransferTemplate> {
+        self.0.remove(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_apply() {
+        let mut templates = TransferTemplates::default();
+        assert!(matches!(
+            templates.insert(TransferTemplate::new("payroll", [])),
+            Err(Error::InvalidTransferTemplate(_))
+        ));
+        assert!(matches!(
+            templates.insert(TransferTemplate::new(
+                "",
+                [TemplateDestination::new("alice", 1_000_000)]
+            )),
+            Err(Error::InvalidTransferTemplate(_))
+        ));
+
+        let template = TransferTemplate::new(
+            "payroll",
+            [
+                TemplateDestination::new("alice", 1_000_000),
+                TemplateDestination::new("bob", 2_000_000),
+            ],
+        )
+        .with_tag(b"payroll".to_vec());
+        assert!(templates.insert(template.clone()).unwrap().is_none());
+        assert!(templates.insert(template).unwrap().is_some());
+        assert_eq!(templates.len(), 1);
+
+        let template = templates
+            .get("payroll")
+            .unwrap()
+            .clone()
+            .apply(TransferTemplateOverrides {
+                destinations: Some(vec![TemplateDestination::new("alice", 1_500_000)]),
+                metadata: Some(b"march".to_vec()),
+                ..Default::default()
+            });
+        assert_eq!(template.destinations, [TemplateDestination::new("alice", 1_500_000)]);
+        assert_eq!(template.tag.as_deref(), Some(&b"payroll"[..]));
+        assert_eq!(template.metadata.as_deref(), Some(&b"march"[..]));
+
+        let json = serde_json::to_value(&template).unwrap();
+        assert_eq!(json["destinations"][0]["amount"], "1500000");
+        let deserialized: TransferTemplate = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.metadata, template.metadata);
+
+        assert!(templates.remove("payroll").is_some());
+        assert!(templates.is_empty());
+    }
+}
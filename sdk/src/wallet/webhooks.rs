@@ -0,0 +1,389 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pushing wallet events to HTTPS endpoints, so server-side integrations don't need to poll the wallet.
+//!
+//! Every event is POSTed as JSON [`WebhookPayload`] to the endpoints subscribed to its type. The body is signed with
+//! the secret of the endpoint: the `X-Iota-Webhook-Signature` header holds the prefix hex encoded HMAC-SHA256 of
+//! `{timestamp}.{body}`, the timestamp being the `X-Iota-Webhook-Timestamp` header, see [`verify_signature()`].
+//! Receivers should reject old timestamps against replays and deduplicate by delivery ID, since a delivery may
+//! arrive more than once.
+//!
+//! Failed deliveries are retried with exponential backoff, deliveries that still fail end up in a dead-letter queue
+//! from which they can be redelivered. Deliveries of different events are independent, so they can arrive out of
+//! order.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use crypto::macs::hmac::HMAC_SHA256;
+use serde::Serialize;
+use tokio::{
+    sync::{Mutex, Semaphore},
+    task::JoinHandle,
+};
+use url::Url;
+use zeroize::Zeroizing;
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        events::types::{Event, WalletEventType},
+        Error, Wallet,
+    },
+};
+
+/// Header holding the unix timestamp in seconds of a delivery.
+pub const TIMESTAMP_HEADER: &str = "X-Iota-Webhook-Timestamp";
+/// Header holding the signature of a delivery.
+pub const SIGNATURE_HEADER: &str = "X-Iota-Webhook-Signature";
+
+/// An HTTPS endpoint events are delivered to.
+#[derive(Clone)]
+pub struct WebhookEndpoint {
+    url: Url,
+    secret: Arc<Zeroizing<Vec<u8>>>,
+    events: Vec<WalletEventType>,
+}
+
+impl core::fmt::Debug for WebhookEndpoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WebhookEndpoint")
+            .field("url", &self.url.as_str())
+            .field("events", &self.events)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookEndpoint {
+    /// Creates an endpoint that receives the given event types, or all of them if none are given. Deliveries are
+    /// signed with the secret.
+    pub fn new(
+        url: &str,
+        secret: impl Into<Vec<u8>>,
+        events: impl IntoIterator<Item = WalletEventType>,
+    ) -> crate::wallet::Result<Self> {
+        let url = Url::parse(url).map_err(|e| Error::Webhook(format!("invalid url {url}: {e}")))?;
+        if url.scheme() != "https" {
+            return Err(Error::Webhook(format!("{url} isn't an HTTPS url")));
+        }
+        let secret = secret.into();
+        if secret.is_empty() {
+            return Err(Error::Webhook("empty secret".to_owned()));
+        }
+
+        Ok(Self {
+            url,
+            secret: Arc::new(Zeroizing::new(secret)),
+            events: events.into_iter().collect(),
+        })
+    }
+
+    /// Returns the URL of the endpoint.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the event types the endpoint is subscribed to, all of them if empty.
+    pub fn events(&self) -> &[WalletEventType] {
+        &self.events
+    }
+
+    fn is_subscribed(&self, event_type: WalletEventType) -> bool {
+        self.events.is_empty() || self.events.contains(&event_type)
+    }
+}
+
+/// Options for delivering events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebhookOptions {
+    /// The number of attempts to deliver an event before it's moved to the dead-letter queue.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for every further retry.
+    pub initial_backoff: Duration,
+    /// The timeout of a single request.
+    pub request_timeout: Duration,
+    /// The number of failed deliveries kept in the dead-letter queue, the oldest ones are dropped first.
+    pub dead_letter_capacity: usize,
+    /// The number of events waiting for delivery, further events are dropped while the queue is full.
+    pub queue_capacity: usize,
+    /// The maximum number of deliveries in progress at the same time, including their retries.
+    pub max_concurrent_deliveries: usize,
+}
+
+impl Default for WebhookOptions {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            request_timeout: Duration::from_secs(10),
+            dead_letter_capacity: 1000,
+            queue_capacity: 1000,
+            max_concurrent_deliveries: 16,
+        }
+    }
+}
+
+/// The body of a delivery.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// The ID of the delivery, redeliveries of the same event keep it.
+    pub delivery_id: u64,
+    /// The event.
+    pub event: Event,
+}
+
+/// A delivery that failed all its attempts.
+#[derive(Clone, Debug)]
+pub struct FailedDelivery {
+    /// The endpoint the delivery was meant for.
+    pub endpoint: WebhookEndpoint,
+    /// The body of the delivery.
+    pub payload: WebhookPayload,
+    /// The error of the last attempt.
+    pub error: String,
+}
+
+/// Returns the prefix hex encoded signature of a delivery body sent at the given unix timestamp.
+pub fn sign(secret: &[u8], timestamp: u64, body: &[u8]) -> String {
+    let mut mac = [0; 32];
+    HMAC_SHA256(
+        &[timestamp.to_string().as_bytes(), b".", body].concat(),
+        secret,
+        &mut mac,
+    );
+    prefix_hex::encode(mac)
+}
+
+/// Checks the signature of a delivery body, for receivers of deliveries.
+pub fn verify_signature(secret: &[u8], timestamp: u64, body: &[u8], signature: &str) -> bool {
+    crate::utils::constant_time_eq(sign(secret, timestamp, body).as_bytes(), signature.as_bytes())
+}
+
+struct Dispatcher {
+    http_client: reqwest::Client,
+    options: WebhookOptions,
+    dead_letters: Mutex<VecDeque<FailedDelivery>>,
+}
+
+impl Dispatcher {
+    async fn deliver(&self, endpoint: WebhookEndpoint, payload: WebhookPayload) -> bool {
+        let mut backoff = self.options.initial_backoff;
+        let mut error = String::new();
+
+        for attempt in 1..=self.options.max_attempts {
+            match self.send(&endpoint, &payload).await {
+                Ok(()) => return true,
+                Err(e) => {
+                    log::debug!(
+                        "[webhooks] delivery {} to {} failed, attempt {attempt}: {e}",
+                        payload.delivery_id,
+                        endpoint.url
+                    );
+                    error = e;
+                }
+            }
+            if attempt < self.options.max_attempts {
+                tokio::time::sleep(backoff).await;
+                backoff = backoff.saturating_mul(2);
+            }
+        }
+
+        let mut dead_letters = self.dead_letters.lock().await;
+        if dead_letters.len() >= self.options.dead_letter_capacity {
+            dead_letters.pop_front();
+        }
+        if self.options.dead_letter_capacity > 0 {
+            dead_letters.push_back(FailedDelivery {
+                endpoint,
+                payload,
+                error,
+            });
+        }
+
+        false
+    }
+
+    async fn send(&self, endpoint: &WebhookEndpoint, payload: &WebhookPayload) -> Result<(), String> {
+        let body = serde_json::to_vec(payload).map_err(|e| e.to_string())?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_secs();
+
+        let response = self
+            .http_client
+            .post(endpoint.url.clone())
+            .timeout(self.options.request_timeout)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(SIGNATURE_HEADER, sign(&endpoint.secret, timestamp, &body))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("response status {}", response.status()))
+        }
+    }
+}
+
+/// Delivers the events of a [`Wallet`] to webhook endpoints. Deliveries stop and the event listener is removed when
+/// it's dropped.
+pub struct WebhookDispatcher {
+    dispatcher: Arc<Dispatcher>,
+    task: JoinHandle<()>,
+    remove_listener: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl WebhookDispatcher {
+    /// Starts delivering the events of the wallet to the endpoints.
+    pub async fn new<S: 'static + SecretManage>(
+        wallet: &Wallet<S>,
+        endpoints: Vec<WebhookEndpoint>,
+        options: WebhookOptions,
+    ) -> crate::wallet::Result<Self>
+    where
+        Error: From<S::Error>,
+    {
+        let http_client = reqwest::Client::builder()
+            .https_only(true)
+            .build()
+            .map_err(|e| Error::Webhook(e.to_string()))?;
+        let dispatcher = Arc::new(Dispatcher {
+            http_client,
+            options,
+            dead_letters: Mutex::new(VecDeque::new()),
+        });
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel::<Event>(dispatcher.options.queue_capacity.max(1));
+        let event_types = if endpoints.iter().any(|endpoint| endpoint.events.is_empty()) {
+            Vec::new()
+        } else {
+            endpoints.iter().flat_map(|endpoint| endpoint.events.clone()).collect()
+        };
+        let listener_id = wallet
+            .add_listener(event_types, move |event| {
+                if let Err(tokio::sync::mpsc::error::TrySendError::Full(event)) = sender.try_send(event.clone()) {
+                    log::warn!(
+                        "[webhooks] delivery queue is full, dropping {:?} event",
+                        event.event.kind()
+                    );
+                }
+            })
+            .await;
+        let wallet = Arc::downgrade(&wallet.inner);
+        let remove_listener = Box::new(move || {
+            if let Some(wallet) = wallet.upgrade() {
+                crate::client::runtime::spawn(async move { wallet.remove_listener(listener_id).await });
+            }
+        });
+
+        let task = tokio::spawn({
+            let dispatcher = dispatcher.clone();
+            let deliveries = Arc::new(Semaphore::new(dispatcher.options.max_concurrent_deliveries.max(1)));
+            let delivery_ids = AtomicU64::new(0);
+            async move {
+                while let Some(event) = receiver.recv().await {
+                    let event_type = event.event.kind();
+                    for endpoint in endpoints.iter().filter(|endpoint| endpoint.is_subscribed(event_type)) {
+                        // Waiting for a free delivery slot lets events queue up in the bounded channel
+                        let Ok(permit) = deliveries.clone().acquire_owned().await else {
+                            return;
+                        };
+                        let payload = WebhookPayload {
+                            delivery_id: delivery_ids.fetch_add(1, Ordering::Relaxed),
+                            event: event.clone(),
+                        };
+                        let dispatcher = dispatcher.clone();
+                        let endpoint = endpoint.clone();
+                        tokio::spawn(async move {
+                            dispatcher.deliver(endpoint, payload).await;
+                            drop(permit);
+                        });
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            dispatcher,
+            task,
+            remove_listener: Some(remove_listener),
+        })
+    }
+
+    /// Returns the deliveries that failed all their attempts.
+    pub async fn dead_letters(&self) -> Vec<FailedDelivery> {
+        self.dispatcher.dead_letters.lock().await.iter().cloned().collect()
+    }
+
+    /// Delivers the dead-letter queue again, with the same retries. Deliveries that fail again are put back into the
+    /// queue. Returns the number of successful deliveries.
+    pub async fn redeliver_dead_letters(&self) -> usize {
+        let dead_letters = core::mem::take(&mut *self.dispatcher.dead_letters.lock().await);
+        let delivered = futures::future::join_all(
+            dead_letters
+                .into_iter()
+                .map(|failed| self.dispatcher.deliver(failed.endpoint, failed.payload)),
+        )
+        .await;
+
+        delivered.into_iter().filter(|delivered| *delivered).count()
+    }
+}
+
+impl Drop for WebhookDispatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+        if let Some(remove_listener) = self.remove_listener.take() {
+            remove_listener();
+        }
+    }
+}
+
+impl core::fmt::Debug for WebhookDispatcher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WebhookDispatcher")
+            .field("options", &self.dispatcher.options)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature() {
+        let body = br#"{"deliveryId":0}"#;
+        let signature = sign(b"secret", 1_700_000_000, body);
+
+        assert!(verify_signature(b"secret", 1_700_000_000, body, &signature));
+        assert!(!verify_signature(b"other", 1_700_000_000, body, &signature));
+        assert!(!verify_signature(b"secret", 1_700_000_001, body, &signature));
+        assert!(!verify_signature(b"secret", 1_700_000_000, b"{}", &signature));
+    }
+
+    #[test]
+    fn endpoint() {
+        assert!(WebhookEndpoint::new("https://example.com/hook", "secret", []).is_ok());
+        assert!(WebhookEndpoint::new("http://example.com/hook", "secret", []).is_err());
+        assert!(WebhookEndpoint::new("https://example.com/hook", "", []).is_err());
+
+        let endpoint =
+            WebhookEndpoint::new("https://example.com/hook", "secret", [WalletEventType::NewOutput]).unwrap();
+        assert!(endpoint.is_subscribed(WalletEventType::NewOutput));
+        assert!(!endpoint.is_subscribed(WalletEventType::TransactionInclusion));
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{api::BlockBuilderMiddleware, Client, Error, Result},
+    types::block::{
+        parent::Parents,
+        payload::{Payload, TaggedDataPayload},
+        protocol::protocol_parameters,
+        rand::block::rand_block_ids,
+    },
+};
+
+struct InjectTaggedData;
+
+#[async_trait::async_trait]
+impl BlockBuilderMiddleware for InjectTaggedData {
+    async fn before_pow(&self, _parents: Option<&Parents>, payload: &mut Option<Payload>) -> Result<()> {
+        if payload.is_none() {
+            *payload = Some(Payload::from(TaggedDataPayload::new(b"app".to_vec(), b"v1".to_vec())?));
+        }
+        Ok(())
+    }
+}
+
+struct RejectAll;
+
+#[async_trait::async_trait]
+impl BlockBuilderMiddleware for RejectAll {
+    async fn before_pow(&self, _parents: Option<&Parents>, _payload: &mut Option<Payload>) -> Result<()> {
+        Err(Error::BlockBuilderMiddleware("rejected".to_string()))
+    }
+}
+
+async fn offline_client() -> Client {
+    Client::builder()
+        .with_protocol_parameters(protocol_parameters())
+        .with_offline_mode()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn before_pow_injects_payload() {
+    let client = offline_client().await;
+    client.add_block_builder_middleware(InjectTaggedData).await;
+    let parents = Parents::from_vec(rand_block_ids(2)).unwrap();
+
+    let block = client.finish_block_builder(Some(parents), None).await.unwrap();
+    let Some(Payload::TaggedData(tagged_data)) = block.payload() else {
+        panic!("expected a tagged data payload");
+    };
+    assert_eq!(tagged_data.tag(), b"app");
+}
+
+#[tokio::test]
+async fn before_pow_aborts_block() {
+    let client = offline_client().await;
+    client.add_block_builder_middleware(InjectTaggedData).await;
+    client.add_block_builder_middleware(RejectAll).await;
+    let parents = Parents::from_vec(rand_block_ids(2)).unwrap();
+
+    assert!(matches!(
+        client.finish_block_builder(Some(parents.clone()), None).await,
+        Err(Error::BlockBuilderMiddleware(_))
+    ));
+
+    client.clear_block_builder_middleware().await;
+    let block = client.finish_block_builder(Some(parents), None).await.unwrap();
+    assert!(block.payload().is_none());
+}
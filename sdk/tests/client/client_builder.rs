@@ -1,7 +1,36 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, ClientBuilder};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use iota_sdk::{
+    client::{
+        api::pow::PowServerOptions,
+        node_api::error::Result as NodeApiResult,
+        node_manager::{
+            http_client::{HttpRequest, HttpResponse, HttpTransport},
+            node::{JwtRefresh, NodeAuth},
+        },
+        Client, ClientBuilder, Error,
+    },
+    types::block::{
+        input::Input,
+        output::{Output, RentStructure},
+        payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
+        protocol::ProtocolParameters,
+        rand::{
+            input::rand_utxo_input,
+            output::{rand_basic_output, rand_inputs_commitment},
+            parents::rand_parents,
+            signature::rand_signature,
+        },
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+        BlockBuilder,
+    },
+};
 
 #[tokio::test]
 async fn invalid_url() {
@@ -61,3 +90,422 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[derive(Default)]
+struct RecordingTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for RecordingTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        self.requests.lock().unwrap().push(request);
+        Ok(HttpResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: Vec::new(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn custom_http_client() {
+    let transport = RecordingTransport::default();
+    let requests = transport.requests.clone();
+
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(client.get_health("http://localhost:14265").await.unwrap());
+
+    let requests = requests.lock().unwrap();
+    // The node info requested when the client was built and the health request
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].url.path(), "/api/core/v2/info");
+    assert_eq!(requests[1].url.path(), "/health");
+    assert!(
+        requests[1]
+            .headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("user-agent"))
+    );
+}
+
+#[tokio::test]
+async fn offline_mode() {
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("private-tangle"),
+        "tst",
+        0,
+        15,
+        RentStructure::new(250, 10, 1),
+        2_779_530_283_277_761,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        Client::builder().with_offline_mode().finish().await,
+        Err(Error::MissingParameter(_))
+    ));
+
+    let transport = RecordingTransport::default();
+    let requests = transport.requests.clone();
+
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_protocol_parameters(protocol_parameters.clone())
+        .with_offline_mode()
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    assert_eq!(client.get_protocol_parameters().await.unwrap(), protocol_parameters);
+    assert_eq!(client.get_bech32_hrp().await.unwrap(), "tst");
+    assert_eq!(client.get_min_pow_score().await.unwrap(), 0);
+    assert!(matches!(client.get_info().await, Err(Error::Offline)));
+    assert!(matches!(client.get_peer("12D3KooW").await, Err(Error::Offline)));
+    assert!(matches!(
+        client.get_health("http://localhost:14265").await,
+        Err(Error::Offline)
+    ));
+    assert!(matches!(
+        client.get_tips_from(&"http://localhost:14265".parse().unwrap()).await,
+        Err(Error::Offline)
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn outdated_protocol_parameters() {
+    // The node info can't be parsed, so the protocol parameters are never received from a node
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_http_client(RecordingTransport::default())
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client.check_protocol_parameters().await,
+        Err(Error::OutdatedProtocolParameters(_))
+    ));
+
+    // Fixed protocol parameters are never outdated
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_http_client(RecordingTransport::default())
+        .finish()
+        .await
+        .unwrap();
+
+    client.check_protocol_parameters().await.unwrap();
+}
+
+#[test]
+fn pow_server_options() {
+    let client_builder = serde_json::from_str::<ClientBuilder>(
+        r#"{"powServer":{"url":"https://pow.example.com/pow","auth":{"jwt":"token"}}}"#,
+    )
+    .unwrap();
+
+    let pow_server = client_builder.pow_server.unwrap();
+    assert_eq!(pow_server.url.as_str(), "https://pow.example.com/pow");
+    assert_eq!(pow_server.auth.unwrap().jwt.as_deref(), Some("token"));
+    assert_eq!(pow_server.max_concurrent_jobs, 4);
+}
+
+#[derive(Default)]
+struct PowServerTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for PowServerTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        self.requests.lock().unwrap().push(request);
+        Ok(HttpResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: br#"{"nonce":"42"}"#.to_vec(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn pow_server_request() {
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("private-tangle"),
+        "tst",
+        0,
+        15,
+        RentStructure::default(),
+        1_000,
+    )
+    .unwrap();
+    let transport = PowServerTransport::default();
+    let requests = transport.requests.clone();
+    let pow_server_url = "https://pow.example.com/pow";
+
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_protocol_parameters(protocol_parameters.clone())
+        .with_pow_server(
+            PowServerOptions::new(pow_server_url.parse().unwrap())
+                .with_auth(NodeAuth::new().with_jwt("token").with_header("x-api-key", "key")),
+        )
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    let parents = rand_parents();
+    let block = client.finish_block_builder(Some(parents.clone()), None).await.unwrap();
+    assert_eq!(block.nonce(), 42);
+    assert_eq!(block.parents(), &parents);
+
+    // The server is requested with the transport and the auth of the client
+    let requests = requests.lock().unwrap();
+    let pow_request = requests
+        .iter()
+        .find(|request| request.url.as_str() == pow_server_url)
+        .unwrap();
+    let header = |name: &str| {
+        pow_request
+            .headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    };
+    assert_eq!(header("authorization"), Some("Bearer token"));
+    assert_eq!(header("x-api-key"), Some("key"));
+    assert!(header("user-agent").is_some());
+    let body = serde_json::from_slice::<serde_json::Value>(pow_request.body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["targetScore"], 0);
+    drop(requests);
+
+    // Nothing is sent to the server in offline mode
+    let transport = PowServerTransport::default();
+    let requests = transport.requests.clone();
+    let client = Client::builder()
+        .with_protocol_parameters(protocol_parameters)
+        .with_offline_mode()
+        .with_pow_server(PowServerOptions::new(pow_server_url.parse().unwrap()))
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client.finish_block_builder(Some(rand_parents()), None).await,
+        Err(Error::Offline)
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn network_mismatch() {
+    let protocol_parameters = ProtocolParameters::default();
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_protocol_parameters(protocol_parameters.clone())
+        .with_offline_mode()
+        .finish()
+        .await
+        .unwrap();
+
+    let transaction = |network_id| {
+        // Only the token supply is validated, so the essence can have another network id
+        let essence = RegularTransactionEssence::builder(network_id, rand_inputs_commitment())
+            .with_inputs([Input::Utxo(rand_utxo_input())])
+            .add_output(Output::Basic(rand_basic_output(protocol_parameters.token_supply())))
+            .finish_with_params(protocol_parameters.token_supply())
+            .unwrap();
+        TransactionPayload::new(
+            TransactionEssence::Regular(essence),
+            Unlocks::new([Unlock::Signature(SignatureUnlock::new(rand_signature()))]).unwrap(),
+        )
+        .unwrap()
+    };
+
+    client
+        .check_transaction_network(&transaction(protocol_parameters.network_id()))
+        .await
+        .unwrap();
+    assert!(matches!(
+        client
+            .check_transaction_network(&transaction(protocol_parameters.network_id() + 1))
+            .await,
+        Err(Error::NetworkMismatch { subject, .. }) if subject == "the network id of the transaction essence"
+    ));
+}
+
+#[tokio::test]
+async fn broadcast_block() {
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("private-tangle"),
+        "tst",
+        0,
+        15,
+        RentStructure::new(250, 10, 1),
+        2_779_530_283_277_761,
+    )
+    .unwrap();
+    let transport = RecordingTransport::default();
+    let requests = transport.requests.clone();
+
+    let client = Client::builder()
+        .with_nodes(&["http://localhost:14265", "http://localhost:14266"])
+        .unwrap()
+        .with_ignore_node_health()
+        .with_protocol_parameters(protocol_parameters)
+        .with_broadcast(true)
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+    requests.lock().unwrap().clear();
+
+    let block = BlockBuilder::new(rand_parents()).with_nonce(0).finish().unwrap();
+    // The transport doesn't return a block id, so no node accepts the block
+    assert!(matches!(
+        client.post_block(&block).await,
+        Err(Error::BroadcastFailed(_))
+    ));
+
+    let requests = requests.lock().unwrap();
+    let mut ports = requests
+        .iter()
+        .filter(|request| request.url.path() == "/api/core/v2/blocks")
+        .map(|request| request.url.port().unwrap())
+        .collect::<Vec<_>>();
+    ports.sort_unstable();
+    assert_eq!(ports, [14265, 14266]);
+}
+
+/// Rejects route requests without the refreshed JWT as unauthorized.
+#[derive(Default)]
+struct AuthTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for AuthTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        if request.url.path() != "/api/routes" {
+            return Ok(HttpResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: Vec::new(),
+            });
+        }
+        let authorized = header(&request, "authorization") == Some("Bearer refreshed");
+        self.requests.lock().unwrap().push(request);
+
+        Ok(if authorized {
+            HttpResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: br#"{"routes":["core/v2"]}"#.to_vec(),
+            }
+        } else {
+            HttpResponse {
+                status: 401,
+                headers: Vec::new(),
+                body: Vec::new(),
+            }
+        })
+    }
+}
+
+fn header<'a>(request: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    request
+        .headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+#[tokio::test]
+async fn node_auth_headers_and_jwt_refresh() {
+    let transport = AuthTransport::default();
+    let requests = transport.requests.clone();
+    let refreshes = Arc::new(AtomicUsize::new(0));
+    let refreshes_ = refreshes.clone();
+
+    let client = Client::builder()
+        .with_node_auth(
+            "http://localhost:14265",
+            NodeAuth::new().with_jwt("expired").with_header("x-api-key", "key"),
+        )
+        .unwrap()
+        .with_ignore_node_health()
+        .with_jwt_refresh(JwtRefresh::new(move |_url| {
+            refreshes_.fetch_add(1, Ordering::SeqCst);
+            async { Some("refreshed".to_string()) }
+        }))
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    // The rejected request is sent once more with the refreshed JWT
+    assert_eq!(client.get_routes().await.unwrap().routes, ["core/v2"]);
+    {
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(header(&requests[0], "authorization"), Some("Bearer expired"));
+        assert_eq!(header(&requests[1], "authorization"), Some("Bearer refreshed"));
+        assert!(
+            requests
+                .iter()
+                .all(|request| header(request, "x-api-key") == Some("key"))
+        );
+    }
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+
+    // Later requests use the refreshed JWT right away
+    client.get_routes().await.unwrap();
+    assert_eq!(requests.lock().unwrap().len(), 3);
+    assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "tls")]
+#[tokio::test]
+async fn node_auth_missing_client_certificate() {
+    let client = Client::builder()
+        .with_node_auth(
+            "http://localhost:14265",
+            NodeAuth::new().with_client_certificate("test-storage/missing-client-certificate.pem"),
+        )
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+
+    let error = client.get_routes().await.unwrap_err();
+    assert!(error.to_string().contains("client certificate"), "{error}");
+
+    // Only the path of the certificate is part of the options
+    let options = serde_json::to_value(NodeAuth::new().with_client_certificate("certs/client.pem")).unwrap();
+    assert_eq!(options["clientCertificate"], "certs/client.pem");
+}
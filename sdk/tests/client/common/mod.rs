@@ -5,8 +5,10 @@ mod constants;
 
 use crypto::keys::bip39::Mnemonic;
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter,
-    request_funds_from_faucet, secret::SecretManager, Client, Result,
+    api::{FaucetOptions, GetAddressesOptions},
+    constants::SHIMMER_COIN_TYPE,
+    secret::SecretManager,
+    Client, Result,
 };
 
 pub use self::constants::{FAUCET_URL, NODE_LOCAL};
@@ -40,23 +42,9 @@ pub async fn create_client_and_secret_manager_with_funds(
         )
         .await?[0];
 
-    request_funds_from_faucet(FAUCET_URL, &address).await?;
-
-    // Continue only after funds are received
-    for _ in 0..30 {
-        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-        let output_ids_response = client
-            .basic_output_ids([
-                QueryParameter::Address(address),
-                QueryParameter::HasExpiration(false),
-                QueryParameter::HasTimelock(false),
-                QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?;
-
-        if !output_ids_response.is_empty() {
-            return Ok((client, secret_manager));
-        }
-    }
-    panic!("Faucet no longer wants to hand over coins");
+    client
+        .request_funds_from_faucet_and_wait(FAUCET_URL, &address, &FaucetOptions::default())
+        .await?;
+
+    Ok((client, secret_manager))
 }
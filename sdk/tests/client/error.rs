@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::{api::input_selection::Error as IsaError, Error},
-    types::block::Error as BlockError,
+    client::{api::input_selection::Error as IsaError, Error, ErrorCategory},
+    types::block::{semantic::ConflictReason, Error as BlockError},
 };
 
 #[test]
@@ -11,7 +11,7 @@ fn stringified_error() {
     let error = Error::InvalidAmount("0".into());
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"invalidAmount\",\"error\":\"invalid amount in API response: 0\"}"
+        "{\"type\":\"invalidAmount\",\"error\":\"invalid amount in API response: 0\",\"code\":5002,\"category\":\"validation\"}"
     );
 
     let error = Error::TimeNotSynced {
@@ -20,13 +20,13 @@ fn stringified_error() {
     };
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"timeNotSynced\",\"error\":\"local time 0 doesn't match the time of the latest milestone timestamp: 10000\"}"
+        "{\"type\":\"timeNotSynced\",\"error\":\"local time 0 doesn't match the time of the latest milestone timestamp: 10000\",\"code\":1005,\"category\":\"network\"}"
     );
 
     let error = Error::PlaceholderSecretManager;
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"placeholderSecretManager\",\"error\":\"placeholderSecretManager can't be used for address generation or signing\"}"
+        "{\"type\":\"placeholderSecretManager\",\"error\":\"placeholderSecretManager can't be used for address generation or signing\",\"code\":3008,\"category\":\"signing\"}"
     );
 
     let error = Error::InputSelection(IsaError::InsufficientAmount {
@@ -35,12 +35,29 @@ fn stringified_error() {
     });
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"inputSelection\",\"error\":\"insufficient amount: found 0, required 100\"}"
+        "{\"type\":\"inputSelection\",\"error\":\"insufficient amount: found 0, required 100\",\"code\":5009,\"category\":\"validation\"}"
     );
 
     let error = Error::InputSelection(IsaError::Block(BlockError::InvalidAddress));
     assert_eq!(
         &serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"inputSelection\",\"error\":\"invalid address provided\"}"
+        "{\"type\":\"inputSelection\",\"error\":\"invalid address provided\",\"code\":5009,\"category\":\"validation\"}"
+    );
+}
+
+#[test]
+fn error_code_and_category() {
+    let error = Error::HealthyNodePoolEmpty;
+    assert_eq!(error.code(), 1002);
+    assert_eq!(error.category(), ErrorCategory::Network);
+    assert_eq!(error.conflict_reason(), None);
+
+    let error = Error::TransactionSemantic(ConflictReason::InputUtxoAlreadySpent);
+    assert_eq!(error.code(), 2004);
+    assert_eq!(error.category(), ErrorCategory::Protocol);
+    assert_eq!(error.conflict_reason(), Some(ConflictReason::InputUtxoAlreadySpent));
+    assert_eq!(
+        error.to_string(),
+        "the semantic validation of a transaction failed with conflict reason 1: The referenced UTXO was already spent"
     );
 }
@@ -0,0 +1,153 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use iota_sdk::{
+    client::{
+        node_api::error::Result as NodeApiResult,
+        node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+        request_funds_from_faucet, Client, Error, Result,
+    },
+    types::block::{address::Bech32Address, protocol::ProtocolParameters},
+};
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+/// Serves the given responses to consecutive requests and returns the faucet URL.
+fn serve(responses: &'static [&'static str]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/api/enqueue", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            assert!(String::from_utf8(body).unwrap().contains(ADDRESS));
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    url
+}
+
+#[tokio::test]
+async fn faucet_retries_rate_limited_requests() -> Result<()> {
+    let url = serve(&[
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        "HTTP/1.1 202 Accepted\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+    ]);
+
+    let response = request_funds_from_faucet(&url, &Bech32Address::try_from_str(ADDRESS)?).await?;
+    assert_eq!(response, "{}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn faucet_error_response() -> Result<()> {
+    let url = serve(&["HTTP/1.1 400 Bad Request\r\nContent-Length: 5\r\nConnection: close\r\n\r\nerror"]);
+
+    let error = request_funds_from_faucet(&url, &Bech32Address::try_from_str(ADDRESS)?)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Node(iota_sdk::client::node_api::error::Error::ResponseError { code: 400, .. })
+    ));
+
+    Ok(())
+}
+
+/// Rate limits the first request and accepts the next ones.
+#[derive(Default)]
+struct FaucetTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for FaucetTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        let mut requests = self.requests.lock().unwrap();
+        requests.push(request);
+        Ok(if requests.len() == 1 {
+            HttpResponse {
+                status: 429,
+                headers: vec![("Retry-After".to_string(), "0".to_string())],
+                body: Vec::new(),
+            }
+        } else {
+            HttpResponse {
+                status: 202,
+                headers: Vec::new(),
+                body: b"{}".to_vec(),
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn faucet_request_with_client() -> Result<()> {
+    let faucet_url = "http://localhost:8091/api/enqueue";
+    let address = Bech32Address::try_from_str(ADDRESS)?;
+    let transport = FaucetTransport::default();
+    let requests = transport.requests.clone();
+
+    // Without nodes the client doesn't send other requests with the transport
+    let client = Client::builder()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_user_agent("faucet-test".to_string())
+        .with_http_client(transport)
+        .finish()
+        .await?;
+
+    assert_eq!(client.request_funds_from_faucet(faucet_url, &address).await?, "{}");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    for request in requests.iter() {
+        assert_eq!(request.url.as_str(), faucet_url);
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|(name, value)| name.eq_ignore_ascii_case("user-agent") && value == "faucet-test")
+        );
+        assert!(String::from_utf8_lossy(request.body.as_deref().unwrap()).contains(ADDRESS));
+    }
+    drop(requests);
+
+    let transport = FaucetTransport::default();
+    let requests = transport.requests.clone();
+    let client = Client::builder()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_offline_mode()
+        .with_http_client(transport)
+        .finish()
+        .await?;
+
+    assert!(matches!(
+        client.request_funds_from_faucet(faucet_url, &address).await,
+        Err(Error::Offline)
+    ));
+    assert!(requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
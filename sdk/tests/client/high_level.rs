@@ -58,3 +58,20 @@ async fn test_find_inputs() {
         unreachable!();
     }
 }
+
+#[ignore]
+#[tokio::test]
+async fn test_send_data_chunked() {
+    let client = setup_client_with_node_health_ignored().await;
+    let tag = b"chunked".to_vec();
+    let data = (0..100_000u32).map(|i| i as u8).collect::<Vec<_>>();
+
+    let blocks = client.send_data_chunked(tag.clone(), &data[..]).await.unwrap();
+    assert_eq!(blocks.len(), 4);
+
+    let retrieved = client
+        .retrieve_data_chunked(&tag, &blocks.last().unwrap().id())
+        .await
+        .unwrap();
+    assert_eq!(retrieved, data);
+}
@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod addresses;
+mod block_builder_middleware;
 mod client_builder;
 mod common;
 mod consolidation;
 mod error;
+mod faucet;
 mod high_level;
 mod input_selection;
 mod input_signing_data;
@@ -13,8 +15,16 @@ mod mnemonic;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod payload_length;
+mod poi;
 mod secret_manager;
+mod sign_in;
 mod signing;
+mod simulation;
+#[cfg(feature = "spammer")]
+mod spammer;
+mod tagged_data;
+mod tip_selection;
 mod transactions;
 
 use std::{
@@ -1,7 +1,15 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::mqtt::{Error, Topic};
+use iota_sdk::{
+    client::mqtt::{Error, Topic, TopicUnlockCondition},
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::{feature::TagFeature, OutputId},
+    },
+};
+
+const ADDRESS: &str = "iota1qrwfnskm4f7utdrxqnkfntfqxehtpj8s0kf68zkcwm0yrhuemzjp5sjfw5v";
 
 #[test]
 fn valid_topics() {
@@ -80,3 +88,48 @@ fn invalid_topics() {
         Err(Error::InvalidTopic(_))
     ));
 }
+
+#[test]
+fn typed_topics() {
+    let address = Bech32Address::try_from_str(ADDRESS).unwrap();
+    let output_id = "0x36845227a59864ac12d3d2389fcb4ea0bdd1a5d1d4ed464bde3154216c3246c40100"
+        .parse::<OutputId>()
+        .unwrap();
+
+    for (topic, string) in [
+        (Topic::ConfirmedMilestoneInfo, "milestone-info/confirmed".to_string()),
+        (
+            Topic::TaggedDataBlocks(Some(TagFeature::new([0x01, 0x23]).unwrap())),
+            "blocks/tagged-data/0x0123".to_string(),
+        ),
+        (Topic::Outputs(output_id), format!("outputs/{output_id}")),
+        (
+            Topic::SpentAddressOutputs(address),
+            format!("outputs/unlock/address/{ADDRESS}/spent"),
+        ),
+        (
+            Topic::UnlockConditionOutputs {
+                unlock_condition: TopicUnlockCondition::Any,
+                address,
+                spent: false,
+            },
+            format!("outputs/unlock/+/{ADDRESS}"),
+        ),
+    ] {
+        assert_eq!(topic.to_string(), string);
+        assert_eq!(Topic::new(string).unwrap(), topic);
+    }
+
+    // Only the canonical form is accepted
+    assert!(Topic::new("blocks/tagged-data/0xABCD").is_err());
+    assert!(Topic::new(format!("outputs/unlock/address/{ADDRESS}/unspent")).is_err());
+    assert!(Topic::new(format!("outputs/unlock/state-controller/{ADDRESS}/spent")).is_ok());
+
+    let topic = Topic::AddressOutputs(address).with_hrp(Hrp::from_str_unchecked("smr"));
+    assert_eq!(
+        topic,
+        Topic::AddressOutputs(Bech32Address::new(Hrp::from_str_unchecked("smr"), address.into_inner()))
+    );
+    assert!(topic.to_string().starts_with("outputs/unlock/address/smr1"));
+    assert_eq!(Topic::Blocks.with_hrp(Hrp::from_str_unchecked("smr")), Topic::Blocks);
+}
@@ -0,0 +1,180 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use iota_sdk::{
+    client::{
+        node_api::{admin::PruneDatabaseOptions, error::Result as NodeApiResult},
+        node_manager::{
+            http_client::{HttpRequest, HttpResponse, HttpTransport},
+            node::NodeAuth,
+        },
+        Client, Error,
+    },
+    types::{
+        api::core::response::{PeerResponse, Relation},
+        block::protocol::ProtocolParameters,
+    },
+};
+
+const PEER_ID: &str = "12D3KooWRVt4Engu27jHnF2RjfX48EqiAqJbgLfFdHNt3Vn6BtJK";
+const MULTI_ADDRESS: &str = "/ip4/127.0.0.1/tcp/15600/p2p/12D3KooWRVt4Engu27jHnF2RjfX48EqiAqJbgLfFdHNt3Vn6BtJK";
+
+/// Answers every request with the given JSON.
+struct AdminTransport {
+    response: serde_json::Value,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl AdminTransport {
+    fn new(response: serde_json::Value) -> Self {
+        Self {
+            response,
+            requests: Default::default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for AdminTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        self.requests.lock().unwrap().push(request);
+        Ok(HttpResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: serde_json::to_vec(&self.response).unwrap(),
+        })
+    }
+}
+
+async fn admin_client(transport: AdminTransport) -> Client {
+    Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_primary_node(
+            "http://localhost:14266",
+            Some(NodeAuth::new().with_jwt("admin").with_basic_auth("user", "password")),
+        )
+        .unwrap()
+        .with_ignore_node_health()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap()
+}
+
+/// Returns the sent admin requests, without the node info requested when the client was built.
+fn admin_requests(requests: &Mutex<Vec<HttpRequest>>) -> Vec<HttpRequest> {
+    requests
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|request| request.url.path() != "/api/core/v2/info")
+        .cloned()
+        .collect()
+}
+
+fn body(request: &HttpRequest) -> Option<serde_json::Value> {
+    request.body.as_ref().map(|body| serde_json::from_slice(body).unwrap())
+}
+
+#[tokio::test]
+async fn peer_routes() {
+    let peer = PeerResponse {
+        id: PEER_ID.to_string(),
+        multi_addresses: vec![MULTI_ADDRESS.to_string()],
+        alias: Some("peer".to_string()),
+        relation: Relation::Known,
+        connected: true,
+        gossip: None,
+    };
+    let transport = AdminTransport::new(serde_json::to_value(&peer).unwrap());
+    let requests = transport.requests.clone();
+    let client = admin_client(transport).await;
+
+    assert_eq!(client.get_peer(PEER_ID).await.unwrap(), peer);
+    assert_eq!(client.add_peer(MULTI_ADDRESS, Some("peer")).await.unwrap(), peer);
+    client.remove_peer(PEER_ID).await.unwrap();
+
+    let requests = admin_requests(&requests);
+    let sent = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.url.path().to_string(), body(request)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sent,
+        [
+            ("GET", format!("/api/core/v2/peers/{PEER_ID}"), None),
+            (
+                "POST",
+                "/api/core/v2/peers".to_string(),
+                Some(serde_json::json!({ "multiAddress": MULTI_ADDRESS, "alias": "peer" }))
+            ),
+            ("DELETE", format!("/api/core/v2/peers/{PEER_ID}"), None),
+        ]
+    );
+
+    // Only the primary node is requested, with its credentials
+    for request in requests.iter() {
+        assert_eq!(request.url.port(), Some(14266));
+        assert_eq!(request.url.username(), "user");
+        assert_eq!(request.url.password(), Some("password"));
+        assert!(
+            request
+                .headers
+                .iter()
+                .any(|(name, value)| name.eq_ignore_ascii_case("authorization") && value == "Bearer admin")
+        );
+    }
+}
+
+#[tokio::test]
+async fn control_routes() {
+    let transport = AdminTransport::new(serde_json::json!({ "index": 10, "filePath": "snapshots/full_snapshot.bin" }));
+    let requests = transport.requests.clone();
+    let client = admin_client(transport).await;
+
+    assert_eq!(
+        client
+            .prune_database(PruneDatabaseOptions::Depth(100))
+            .await
+            .unwrap()
+            .index,
+        10
+    );
+    let snapshot = client.create_snapshot(10).await.unwrap();
+    assert_eq!(snapshot.index, 10);
+    assert_eq!(snapshot.file_path, "snapshots/full_snapshot.bin");
+
+    let requests = admin_requests(&requests);
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method.as_str(), "POST");
+    assert_eq!(requests[0].url.path(), "/api/core/v2/control/database/prune");
+    assert_eq!(body(&requests[0]), Some(serde_json::json!({ "depth": 100 })));
+    assert_eq!(requests[1].method.as_str(), "POST");
+    assert_eq!(requests[1].url.path(), "/api/core/v2/control/snapshots/create");
+    assert_eq!(body(&requests[1]), Some(serde_json::json!({ "index": 10 })));
+    assert!(requests.iter().all(|request| {
+        request
+            .headers
+            .iter()
+            .any(|(name, value)| name.eq_ignore_ascii_case("content-type") && value == "application/json")
+    }));
+}
+
+#[tokio::test]
+async fn admin_routes_need_a_node() {
+    let client = Client::builder()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_http_client(AdminTransport::new(serde_json::Value::Null))
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        client.get_peer(PEER_ID).await,
+        Err(Error::HealthyNodePoolEmpty)
+    ));
+}
@@ -4,7 +4,11 @@
 // These are E2E test samples, so they are ignored by default.
 
 use iota_sdk::{
-    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Client, NodeInfoWrapper},
+    client::{
+        api::GetAddressesOptions,
+        node_api::{indexer::query_parameters::QueryParameter, plugin::Method},
+        Client, NodeInfoWrapper,
+    },
     types::block::{
         output::{Output, OutputId},
         payload::Payload,
@@ -124,6 +128,26 @@ async fn test_get_address_outputs() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_get_outputs_with_results() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (_block_id, transaction_id) = setup_transaction_block(&client).await;
+
+    let output_id = OutputId::new(transaction_id, 0).unwrap();
+    let missing_output_id = OutputId::new(transaction_id, 100).unwrap();
+    let results = client
+        .get_outputs_with_results(&[missing_output_id, output_id, missing_output_id])
+        .await;
+
+    // Duplicates are removed and the order is kept
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, missing_output_id);
+    assert!(results[0].1.is_err());
+    assert_eq!(results[1].0, output_id);
+    assert_eq!(results[1].1.as_ref().unwrap().metadata().output_id(), &output_id);
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_output() {
@@ -344,7 +368,7 @@ async fn test_call_plugin_route() {
 
     // we call the "custom" plugin "node info"
     let plugin_res: NodeInfoWrapper = c
-        .call_plugin_route("api/core/v2/", "GET", "info", vec![], None)
+        .call_plugin_route(Method::GET, "api/core/v2/info", None::<&()>)
         .await
         .unwrap();
 
@@ -1,17 +1,32 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::{Arc, Mutex};
+
+use futures::{future, StreamExt};
 use iota_sdk::{
-    client::{api::GetAddressesOptions, Result},
-    types::block::{
-        address::AliasAddress,
-        output::{
-            unlock_condition::{
-                AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
-                StateControllerAddressUnlockCondition, UnlockCondition,
+    client::{
+        api::GetAddressesOptions,
+        constants::SHIMMER_TESTNET_BECH32_HRP,
+        node_api::{error::Result as NodeApiResult, indexer::query_parameters::QueryParameter},
+        node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+        Client, Result,
+    },
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{AliasAddress, Bech32Address, ToBech32Ext},
+            output::{
+                dto::OutputDto,
+                unlock_condition::{
+                    AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                    StateControllerAddressUnlockCondition, UnlockCondition,
+                },
+                AliasId, AliasOutputBuilder, BasicOutputBuilder, FoundryId, FoundryOutputBuilder, NftId,
+                NftOutputBuilder, Output, OutputId, OutputMetadata, SimpleTokenScheme, TokenScheme,
             },
-            AliasId, AliasOutputBuilder, FoundryId, FoundryOutputBuilder, NftId, NftOutputBuilder, SimpleTokenScheme,
-            TokenScheme,
+            protocol::protocol_parameters,
+            rand::{address::rand_ed25519_address, block::rand_block_id, output::rand_output_id},
         },
     },
 };
@@ -149,3 +164,208 @@ async fn get_foundry_output_id_test() -> Result<()> {
 
     Ok(())
 }
+
+/// Serves two pages of output IDs, the second one with the cursor `c1`.
+struct PagesTransport {
+    output_ids: [OutputId; 3],
+    page_requests: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for PagesTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        if !request.url.path().starts_with("/api/indexer") {
+            return Ok(HttpResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: Vec::new(),
+            });
+        }
+
+        let cursor = request
+            .url
+            .query_pairs()
+            .find(|(name, _)| name == "cursor")
+            .map(|(_, cursor)| cursor.into_owned());
+        self.page_requests.lock().unwrap().push(cursor.clone());
+
+        let [first, second, third] = self.output_ids.map(|output_id| output_id.to_string());
+        let body = match cursor.as_deref() {
+            None => format!(r#"{{"ledgerIndex":1,"cursor":"c1","items":["{first}","{second}"]}}"#),
+            _ => format!(r#"{{"ledgerIndex":1,"cursor":null,"items":["{third}"]}}"#),
+        };
+
+        Ok(HttpResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into_bytes(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn output_id_stream() {
+    let output_ids = [rand_output_id(), rand_output_id(), rand_output_id()];
+    let transport = PagesTransport {
+        output_ids,
+        page_requests: Default::default(),
+    };
+    let page_requests = transport.page_requests.clone();
+
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    let streamed = client
+        .basic_output_ids_pages(Vec::new())
+        .unwrap()
+        .into_stream()
+        .map(|output_id| output_id.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(streamed, output_ids);
+    assert_eq!(*page_requests.lock().unwrap(), [None, Some("c1".to_owned())]);
+
+    // Stopping after the first page doesn't request the second one
+    page_requests.lock().unwrap().clear();
+    let mut stream = client.basic_output_ids_pages(Vec::new()).unwrap().into_stream();
+    let streamed = stream
+        .by_ref()
+        .take_while(|output_id| future::ready(output_id.as_ref().unwrap() != &output_ids[1]))
+        .map(|output_id| output_id.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(streamed, output_ids[..1]);
+    assert_eq!(stream.cursor(), Some("c1"));
+    assert!(!stream.is_finished());
+    assert_eq!(*page_requests.lock().unwrap(), [None]);
+
+    // Resuming from the persisted cursor continues at the second page
+    let resumed = client
+        .basic_output_ids_pages(vec![QueryParameter::Cursor(stream.cursor().unwrap().to_owned())])
+        .unwrap()
+        .into_stream()
+        .map(|output_id| output_id.unwrap())
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(resumed, output_ids[2..]);
+}
+
+/// Answers the indexer and output routes for the outputs of two addresses.
+struct BalancesTransport {
+    has_indexer: bool,
+    // address, route and output id of every output
+    outputs: Vec<(String, &'static str, OutputId, Output)>,
+    output_requests: Arc<Mutex<Vec<OutputId>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for BalancesTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        let path = request.url.path();
+        let body = if path == "/api/routes" {
+            let routes = if self.has_indexer {
+                vec!["core/v2", "indexer/v1"]
+            } else {
+                vec!["core/v2"]
+            };
+            serde_json::json!({ "routes": routes }).to_string()
+        } else if let Some(route) = path.strip_prefix("/api/indexer/v1/outputs/") {
+            let (_, address) = request
+                .url
+                .query_pairs()
+                .find(|(name, _)| name == "address" || name == "stateController")
+                .unwrap();
+            let items = self
+                .outputs
+                .iter()
+                .filter(|(output_address, output_route, ..)| output_address == &address && output_route == &route)
+                .map(|(_, _, output_id, _)| output_id.to_string())
+                .collect::<Vec<_>>();
+            serde_json::json!({ "ledgerIndex": 7, "cursor": null, "items": items }).to_string()
+        } else if let Some(output_id) = path.strip_prefix("/api/core/v2/outputs/") {
+            let output_id = output_id.parse::<OutputId>().unwrap();
+            self.output_requests.lock().unwrap().push(output_id);
+            let (_, _, _, output) = self.outputs.iter().find(|(_, _, id, _)| id == &output_id).unwrap();
+            serde_json::to_string(&OutputWithMetadataResponse {
+                metadata: OutputMetadata::new(rand_block_id(), output_id, false, None, None, None, 0, 0, 0),
+                output: OutputDto::from(output),
+            })
+            .unwrap()
+        } else {
+            return Ok(HttpResponse {
+                status: 404,
+                headers: Vec::new(),
+                body: Vec::new(),
+            });
+        };
+
+        Ok(HttpResponse {
+            status: 200,
+            headers: Vec::new(),
+            body: body.into_bytes(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn get_address_balances() -> Result<()> {
+    let token_supply = protocol_parameters().token_supply();
+    let first = rand_ed25519_address().to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let second = rand_ed25519_address().to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let basic = |address: Bech32Address, amount| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)
+            .unwrap()
+    };
+    let nft = NftOutputBuilder::new_with_amount(500_000, NftId::null())
+        .add_unlock_condition(AddressUnlockCondition::new(first))
+        .finish_output(token_supply)?;
+    let outputs = vec![
+        (first.to_string(), "basic", rand_output_id(), basic(first, 1_000_000)),
+        (first.to_string(), "nft", rand_output_id(), nft),
+        (second.to_string(), "basic", rand_output_id(), basic(second, 2_000_000)),
+    ];
+
+    for has_indexer in [true, false] {
+        let output_requests = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::builder()
+            .with_node("http://localhost:14265")?
+            .with_ignore_node_health()
+            .with_http_client(BalancesTransport {
+                has_indexer,
+                outputs: outputs.clone(),
+                output_requests: output_requests.clone(),
+            })
+            .finish()
+            .await?;
+
+        let balances = client.get_address_balances(&[first, second]).await?;
+        assert_eq!(balances.ledger_index, 7);
+        assert_eq!(balances.balances.len(), 2);
+        let [first_balance, second_balance] = [&balances.balances[0], &balances.balances[1]];
+        assert_eq!(first_balance.address, first);
+        assert_eq!(first_balance.base_coin, 1_500_000);
+        assert_eq!(
+            (
+                first_balance.basic_outputs,
+                first_balance.alias_outputs,
+                first_balance.nft_outputs
+            ),
+            (1, 0, 1)
+        );
+        assert_eq!(second_balance.address, second);
+        assert_eq!(second_balance.base_coin, 2_000_000);
+        assert_eq!(second_balance.basic_outputs, 1);
+        // Every output is requested once
+        assert_eq!(output_requests.lock().unwrap().len(), 3);
+    }
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod admin;
 mod core;
 mod indexer;
 #[cfg(feature = "mqtt")]
 mod mqtt;
+mod plugin;
 
 use iota_sdk::{
     client::{
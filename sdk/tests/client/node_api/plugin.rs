@@ -0,0 +1,130 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use iota_sdk::client::{
+    node_api::{
+        error::{Error as NodeApiError, Result as NodeApiResult},
+        plugin::Method,
+    },
+    node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+    Client, Error,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+struct ItemRequest {
+    name: String,
+}
+
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+struct ItemResponse {
+    id: u32,
+    name: String,
+}
+
+/// Answers the routes of a custom plugin and records the requests to them.
+#[derive(Default)]
+struct PluginTransport {
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for PluginTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        let (status, body) = match request.url.path() {
+            "/api/my-plugin/v1/items" => {
+                let name = request
+                    .body
+                    .as_deref()
+                    .map(|body| serde_json::from_slice::<serde_json::Value>(body).unwrap()["name"].clone())
+                    .unwrap_or_default();
+                (
+                    200,
+                    serde_json::json!({ "id": 1, "name": name }).to_string().into_bytes(),
+                )
+            }
+            "/api/my-plugin/v1/items/1" => (204, Vec::new()),
+            _ => (404, Vec::new()),
+        };
+        if request.url.path().starts_with("/api/my-plugin") {
+            self.requests.lock().unwrap().push(request);
+        }
+
+        Ok(HttpResponse {
+            status,
+            headers: Vec::new(),
+            body,
+        })
+    }
+}
+
+async fn plugin_client() -> (Client, Arc<Mutex<Vec<HttpRequest>>>) {
+    let transport = PluginTransport::default();
+    let requests = transport.requests.clone();
+    let client = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap();
+
+    (client, requests)
+}
+
+#[tokio::test]
+async fn call_plugin_route() {
+    let (client, requests) = plugin_client().await;
+
+    let item: ItemResponse = client
+        .call_plugin_route(
+            Method::POST,
+            "api/my-plugin/v1/items",
+            Some(&ItemRequest {
+                name: "first".to_owned(),
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        item,
+        ItemResponse {
+            id: 1,
+            name: "first".to_owned()
+        }
+    );
+
+    // The query is kept and an empty response can be received as `()`
+    client
+        .call_plugin_route::<(), ()>(Method::DELETE, "api/my-plugin/v1/items/1?force=true", None)
+        .await
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0].method, Method::POST);
+    assert!(
+        requests[0]
+            .headers
+            .iter()
+            .any(|(name, value)| name == "Content-Type" && value == "application/json")
+    );
+    assert_eq!(requests[1].method, Method::DELETE);
+    assert_eq!(requests[1].url.path(), "/api/my-plugin/v1/items/1");
+    assert_eq!(requests[1].url.query(), Some("force=true"));
+    assert_eq!(requests[1].body, None);
+}
+
+#[tokio::test]
+async fn call_unknown_plugin_route() {
+    let (client, _) = plugin_client().await;
+
+    assert!(matches!(
+        client
+            .call_plugin_route::<(), ItemResponse>(Method::GET, "api/other-plugin/v1/items", None)
+            .await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+}
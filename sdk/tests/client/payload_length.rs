@@ -0,0 +1,59 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{api::pow::validate_payload_length, Client, Error},
+    types::block::{
+        parent::Parents,
+        payload::{Payload, TaggedDataPayload},
+        protocol::protocol_parameters,
+        rand::block::rand_block_ids,
+        Block, BlockId,
+    },
+};
+
+fn tagged_data(data_length: usize) -> Payload {
+    Payload::from(TaggedDataPayload::new(Vec::new(), vec![0; data_length]).unwrap())
+}
+
+#[test]
+fn payload_length_depends_on_parents() {
+    // The largest tagged data payload only fits into a block with a single parent
+    let payload = tagged_data(*TaggedDataPayload::DATA_LENGTH_RANGE.end() as usize);
+
+    let parents = Parents::from_vec(rand_block_ids(1)).unwrap();
+    assert!(validate_payload_length(&payload, Some(&parents)).is_ok());
+
+    let parents = Parents::from_vec(rand_block_ids(2)).unwrap();
+    assert!(matches!(
+        validate_payload_length(&payload, Some(&parents)),
+        Err(Error::PayloadTooLarge { max, actual })
+            if max == Block::LENGTH_MAX - Block::LENGTH_MIN - BlockId::LENGTH
+                && actual == Block::LENGTH_MAX - Block::LENGTH_MIN
+    ));
+
+    // Without parents the maximum number of parents is assumed
+    assert!(matches!(
+        validate_payload_length(&payload, None),
+        Err(Error::PayloadTooLarge { max, .. }) if max == Block::LENGTH_MAX - Block::LENGTH_MIN - 7 * BlockId::LENGTH
+    ));
+}
+
+#[tokio::test]
+async fn too_large_payload_is_rejected_before_pow() {
+    let client = Client::builder()
+        .with_protocol_parameters(protocol_parameters())
+        .with_offline_mode()
+        .with_local_pow(false)
+        .finish()
+        .await
+        .unwrap();
+    let parents = Parents::from_vec(rand_block_ids(8)).unwrap();
+
+    let error = client
+        .finish_block_builder(Some(parents), Some(tagged_data(32_600)))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::PayloadTooLarge { .. }));
+    assert_eq!(error.code(), 5013);
+}
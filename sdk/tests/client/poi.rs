@@ -0,0 +1,169 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::signatures::ed25519::SecretKey;
+use iota_sdk::{
+    client::poi::{MerkleProof, OutputWithMetadataProof, ProofOfInclusion},
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            output::{dto::OutputDto, Output, OutputId, OutputMetadata, OutputWithMetadata},
+            payload::{
+                milestone::{
+                    dto::MilestonePayloadDto, MerkleRoot, MilestoneEssence, MilestoneIndex, MilestoneKeyRange,
+                    MilestoneOptions, MilestonePayload,
+                },
+                transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
+            },
+            protocol::protocol_parameters,
+            rand::{
+                block::{rand_block, rand_block_id},
+                input::rand_input,
+                milestone::{rand_merkle_root, rand_milestone_id},
+                output::{rand_basic_output, rand_inputs_commitment},
+                parents::rand_parents,
+                signature::rand_signature,
+            },
+            signature::{Ed25519Signature, Signature},
+            unlock::{SignatureUnlock, Unlock, Unlocks},
+            Block, BlockDto,
+        },
+    },
+};
+
+#[test]
+fn validate_proof_of_inclusion() {
+    let block = rand_block();
+    let proof = MerkleProof::Node {
+        left: Box::new(MerkleProof::Hash {
+            hash: *rand_merkle_root(),
+        }),
+        right: Box::new(MerkleProof::Value { value: block.id() }),
+    };
+
+    let essence = MilestoneEssence::new(
+        MilestoneIndex(10),
+        0,
+        protocol_parameters().protocol_version(),
+        rand_milestone_id(),
+        rand_parents(),
+        MerkleRoot::new(proof.hash()),
+        rand_merkle_root(),
+        [],
+        MilestoneOptions::from_vec(vec![]).unwrap(),
+    )
+    .unwrap();
+    let secret_key = SecretKey::from_bytes(&[1; SecretKey::LENGTH]);
+    let public_key = secret_key.public_key();
+    let signature = Signature::from(Ed25519Signature::new(public_key, secret_key.sign(&essence.hash())));
+    let milestone = MilestonePayload::new(essence, [signature]).unwrap();
+
+    let poi = ProofOfInclusion {
+        milestone: MilestonePayloadDto::from(&milestone),
+        block: BlockDto::from(&block),
+        proof,
+    };
+    let key_ranges = [MilestoneKeyRange::new(
+        hex::encode(public_key),
+        MilestoneIndex(0),
+        MilestoneIndex(0),
+    )];
+
+    // The proof survives the portable JSON format
+    let json = serde_json::to_string(&poi).unwrap();
+    let poi = serde_json::from_str::<ProofOfInclusion>(&json).unwrap();
+
+    assert_eq!(poi.block_id().unwrap(), block.id());
+    assert!(poi.validate(&key_ranges, 1).is_ok());
+
+    let mut other_block = poi.clone();
+    other_block.proof = MerkleProof::Node {
+        left: Box::new(MerkleProof::Hash {
+            hash: *rand_merkle_root(),
+        }),
+        right: Box::new(MerkleProof::Value { value: rand_block_id() }),
+    };
+    assert!(other_block.validate(&key_ranges, 1).is_err());
+
+    let expired_key_ranges = [MilestoneKeyRange::new(
+        hex::encode(public_key),
+        MilestoneIndex(0),
+        MilestoneIndex(9),
+    )];
+    assert!(poi.validate(&expired_key_ranges, 1).is_err());
+}
+
+#[test]
+fn validate_output_with_metadata_proof() {
+    let protocol_parameters = protocol_parameters();
+    let output = Output::from(rand_basic_output(protocol_parameters.token_supply()));
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs([rand_input()])
+            .add_output(output.clone())
+            .finish_with_params(&protocol_parameters)
+            .unwrap(),
+    );
+    let unlocks = Unlocks::new([Unlock::Signature(SignatureUnlock::from(rand_signature()))]).unwrap();
+    let transaction = TransactionPayload::new(essence, unlocks).unwrap();
+    let output_id = OutputId::new(transaction.id(), 0).unwrap();
+    let block = Block::build(rand_parents()).with_payload(transaction).finish().unwrap();
+    let proof = MerkleProof::Node {
+        left: Box::new(MerkleProof::Value { value: block.id() }),
+        right: Box::new(MerkleProof::Hash {
+            hash: *rand_merkle_root(),
+        }),
+    };
+
+    let essence = MilestoneEssence::new(
+        MilestoneIndex(20),
+        1_690_000_000,
+        protocol_parameters.protocol_version(),
+        rand_milestone_id(),
+        rand_parents(),
+        MerkleRoot::new(proof.hash()),
+        rand_merkle_root(),
+        [],
+        MilestoneOptions::from_vec(vec![]).unwrap(),
+    )
+    .unwrap();
+    let secret_key = SecretKey::from_bytes(&[2; SecretKey::LENGTH]);
+    let public_key = secret_key.public_key();
+    let signature = Signature::from(Ed25519Signature::new(public_key, secret_key.sign(&essence.hash())));
+    let milestone = MilestonePayload::new(essence, [signature]).unwrap();
+    let key_ranges = [MilestoneKeyRange::new(
+        hex::encode(public_key),
+        MilestoneIndex(0),
+        MilestoneIndex(0),
+    )];
+
+    let metadata = OutputMetadata::new(block.id(), output_id, false, None, None, None, 20, 1_690_000_000, 30);
+    let output_with_metadata = OutputWithMetadata::new(output, metadata);
+    assert!(output_with_metadata.verify_inclusion(&block, &milestone).is_ok());
+
+    let output_proof = OutputWithMetadataProof {
+        output: OutputWithMetadataResponse::from(&output_with_metadata),
+        block: BlockDto::from(&block),
+        milestone: MilestonePayloadDto::from(&milestone),
+        proof: Some(proof),
+    };
+
+    let json = serde_json::to_string(&output_proof).unwrap();
+    let output_proof = serde_json::from_str::<OutputWithMetadataProof>(&json).unwrap();
+    assert!(output_proof.validate(&key_ranges, 1).is_ok());
+
+    // Without the audit path, only the output can be verified against the block and the milestone
+    let mut without_proof = output_proof.clone();
+    without_proof.proof = None;
+    assert!(without_proof.verify_output().is_ok());
+    assert!(without_proof.validate(&key_ranges, 1).is_err());
+
+    let mut other_output = output_proof.clone();
+    other_output.output.output = OutputDto::from(&Output::from(rand_basic_output(protocol_parameters.token_supply())));
+    assert!(other_output.validate(&key_ranges, 1).is_err());
+
+    let mut other_milestone = output_proof;
+    other_milestone.output.metadata =
+        OutputMetadata::new(block.id(), output_id, false, None, None, None, 21, 1_690_000_000, 30);
+    assert!(other_milestone.validate(&key_ranges, 1).is_err());
+}
@@ -0,0 +1,170 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::PreparedTransactionData,
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{
+            hardware::{
+                Error as HardwareError, HardwareDeviceStatus, HardwareSecretManager, SimulatedUserResponse,
+                SimulatorSigner,
+            },
+            mnemonic::MnemonicSecretManager,
+            SecretManage,
+        },
+        Error, Result,
+    },
+    types::block::{
+        address::ToBech32Ext,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+#[tokio::test]
+async fn simulator_addresses() -> Result<()> {
+    let secret_manager = HardwareSecretManager::new(SimulatorSigner::try_from_mnemonic(MNEMONIC, false)?);
+    let mnemonic_secret_manager = MnemonicSecretManager::try_from_mnemonic(MNEMONIC)?;
+
+    let addresses = secret_manager
+        .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..2, None)
+        .await?;
+
+    assert_eq!(
+        addresses[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP),
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a"
+    );
+    assert_eq!(
+        addresses,
+        mnemonic_secret_manager
+            .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..2, None)
+            .await?
+    );
+
+    secret_manager
+        .signer()
+        .set_status(HardwareDeviceStatus {
+            connected: true,
+            locked: true,
+            ..Default::default()
+        })
+        .await;
+
+    assert!(matches!(
+        secret_manager
+            .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+            .await,
+        Err(Error::HardwareSigner(HardwareError::DeviceLocked))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn simulator_signing() -> Result<()> {
+    let secret_manager = HardwareSecretManager::new(SimulatorSigner::try_from_mnemonic(MNEMONIC, false)?);
+
+    let bech32_address = secret_manager
+        .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+        .to_string();
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        &bech32_address,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let outputs = build_outputs([Basic(1_000_000, &bech32_address, None, None, None, None, None, None)]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(100))
+        .await?;
+    assert_eq!(unlocks.len(), 1);
+    // Clear signing signs the same essence hash
+    let clear_signing_secret_manager = HardwareSecretManager::new(SimulatorSigner::try_from_mnemonic(MNEMONIC, true)?);
+    assert_eq!(
+        clear_signing_secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(100))
+            .await?,
+        unlocks
+    );
+    assert_eq!(
+        MnemonicSecretManager::try_from_mnemonic(MNEMONIC)?
+            .sign_transaction_essence(&prepared_transaction_data, Some(100))
+            .await?,
+        unlocks
+    );
+
+    // Only transactions confirmed on the device are signed
+    assert!(matches!(
+        secret_manager
+            .sign_ed25519(&prepared_transaction_data.essence.hash(), Bip44::new(SHIMMER_COIN_TYPE))
+            .await,
+        Err(Error::HardwareSigner(HardwareError::UnsupportedOperation))
+    ));
+
+    secret_manager
+        .signer()
+        .set_user_response(SimulatedUserResponse::Deny)
+        .await;
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(100))
+            .await,
+        Err(Error::HardwareSigner(HardwareError::DeniedByUser))
+    ));
+
+    secret_manager
+        .signer()
+        .set_status(HardwareDeviceStatus {
+            connected: true,
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(100))
+            .await,
+        Err(Error::HardwareSigner(HardwareError::BlindSigningDisabled))
+    ));
+
+    Ok(())
+}
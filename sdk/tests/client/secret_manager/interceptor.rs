@@ -0,0 +1,223 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::PreparedTransactionData,
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{
+            interceptor::{
+                MessageSignatureScheme, MessageSigningRequest, SigningInterceptor, TransactionSigningRequest,
+            },
+            SecretManage, SecretManager,
+        },
+        Error, Result,
+    },
+    types::block::{
+        address::ToBech32Ext,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+// Limits the amount of transactions and rejects all messages, which could be the essence hash of any transaction
+struct AmountLimit(u64);
+
+#[async_trait::async_trait]
+impl SigningInterceptor for AmountLimit {
+    async fn intercept_transaction(&self, request: &TransactionSigningRequest) -> Result<()> {
+        if request.sent_amount() > self.0 {
+            return Err(Error::SigningRejected(format!("amount exceeds {}", self.0)));
+        }
+        Ok(())
+    }
+
+    async fn intercept_message(&self, _request: &MessageSigningRequest) -> Result<()> {
+        Err(Error::SigningRejected("messages aren't signed".to_string()))
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    transactions: Arc<Mutex<Vec<TransactionSigningRequest>>>,
+    messages: Arc<Mutex<Vec<MessageSigningRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl SigningInterceptor for Recorder {
+    async fn intercept_transaction(&self, request: &TransactionSigningRequest) -> Result<()> {
+        self.transactions.lock().unwrap().push(request.clone());
+        Ok(())
+    }
+
+    async fn intercept_message(&self, request: &MessageSigningRequest) -> Result<()> {
+        self.messages.lock().unwrap().push(request.clone());
+        Ok(())
+    }
+}
+
+fn prepared_transaction_data(bech32_address: &str, amount: u64) -> Result<PreparedTransactionData> {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        amount,
+        bech32_address,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+    let outputs = build_outputs([Basic(amount, bech32_address, None, None, None, None, None, None)]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[tokio::test]
+async fn interceptors() -> Result<()> {
+    let recorder = Recorder::default();
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?
+        .with_interceptor(recorder.clone())
+        .with_interceptor(AmountLimit(1_000_000));
+
+    let bech32_address = secret_manager
+        .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+        .to_string();
+
+    let prepared_transaction_data = prepared_transaction_data(&bech32_address, 1_000_000)?;
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(100))
+        .await?;
+    assert_eq!(unlocks.len(), 1);
+
+    {
+        let requests = recorder.transactions.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.time, 100);
+        assert_eq!(request.essence_hash, prepared_transaction_data.essence.hash());
+        assert_eq!(request.inputs.len(), 1);
+        assert_eq!(
+            request.inputs[0].address.to_bech32(SHIMMER_TESTNET_BECH32_HRP),
+            bech32_address
+        );
+        assert_eq!(request.input_amount(), 1_000_000);
+        assert_eq!(request.sent_amount(), 1_000_000);
+    }
+
+    let prepared_transaction_data = prepared_transaction_data(&bech32_address, 2_000_000)?;
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(100))
+            .await,
+        Err(Error::SigningRejected(_))
+    ));
+    assert_eq!(recorder.transactions.lock().unwrap().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interceptors_without_time() -> Result<()> {
+    let recorder = Recorder::default();
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?.with_interceptor(recorder.clone());
+    let bech32_address = secret_manager
+        .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+        .to_string();
+
+    // The current time is used and shown to the interceptors
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32;
+    secret_manager
+        .sign_transaction_essence(&prepared_transaction_data(&bech32_address, 1_000_000)?, None)
+        .await?;
+    assert!(recorder.transactions.lock().unwrap()[0].time >= now);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn message_interceptors() -> Result<()> {
+    let chain = Bip44::new(SHIMMER_COIN_TYPE);
+
+    let recorder = Recorder::default();
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?.with_interceptor(recorder.clone());
+    secret_manager.sign_ed25519(b"sign in", chain).await?;
+    secret_manager.sign_secp256k1_ecdsa(&[1; 32], chain).await?;
+    {
+        let messages = recorder.messages.lock().unwrap();
+        assert_eq!(
+            *messages,
+            [
+                MessageSigningRequest {
+                    message: b"sign in".to_vec(),
+                    chain,
+                    scheme: MessageSignatureScheme::Ed25519,
+                },
+                MessageSigningRequest {
+                    message: vec![1; 32],
+                    chain,
+                    scheme: MessageSignatureScheme::Secp256k1Ecdsa,
+                },
+            ]
+        );
+    }
+
+    // Signing an essence hash directly doesn't bypass the interceptors
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?.with_interceptor(AmountLimit(1_000_000));
+    let essence_hash = prepared_transaction_data(
+        &secret_manager
+            .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+            .await?[0]
+            .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+            .to_string(),
+        2_000_000,
+    )?
+    .essence
+    .hash();
+    assert!(matches!(
+        secret_manager.sign_ed25519(&essence_hash, chain).await,
+        Err(Error::SigningRejected(_))
+    ));
+    assert!(matches!(
+        secret_manager.sign_secp256k1_ecdsa(&essence_hash, chain).await,
+        Err(Error::SigningRejected(_))
+    ));
+
+    Ok(())
+}
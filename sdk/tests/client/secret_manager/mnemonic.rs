@@ -1,8 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::keys::bip44::Bip44;
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+    api::GetAddressesOptions,
+    constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+    hex_public_key_to_bech32_address,
+    secret::{SecretManage, SecretManager, SecretManagerDto},
+    verify_ed25519_signature, Error, Result,
 };
 
 #[tokio::test]
@@ -27,3 +32,72 @@ async fn mnemonic_secret_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_secret_manager_sign_data() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(
+        "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned(),
+    )?;
+    let chain = Bip44::new(SHIMMER_COIN_TYPE);
+    let challenge = b"proof of ownership challenge";
+
+    let signature = secret_manager.sign_data(chain, challenge).await?;
+
+    assert_eq!(
+        hex_public_key_to_bech32_address(
+            &prefix_hex::encode(signature.public_key().to_bytes()),
+            SHIMMER_TESTNET_BECH32_HRP
+        )?
+        .to_string(),
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a"
+    );
+    assert!(verify_ed25519_signature(
+        signature.public_key(),
+        signature.signature(),
+        challenge
+    ));
+    assert!(!verify_ed25519_signature(
+        signature.public_key(),
+        signature.signature(),
+        b"another challenge"
+    ));
+    // The signature isn't valid for the raw data, so it can't be used as a transaction signature
+    assert!(!signature.verify(challenge));
+    // Signing the raw data directly doesn't produce a valid data signature
+    let raw_signature = secret_manager.sign_ed25519(challenge, chain).await?;
+    assert!(!verify_ed25519_signature(
+        raw_signature.public_key(),
+        raw_signature.signature(),
+        challenge
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mnemonic_secret_manager_lock() -> Result<()> {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let dto = format!(r#"{{"mnemonic": "{mnemonic}"}}"#);
+    assert!(!format!("{:?}", serde_json::from_str::<SecretManagerDto>(&dto)?).contains("acoustic"));
+
+    let secret_manager: SecretManager = dto.parse()?;
+    let options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_range(0..1);
+
+    secret_manager.lock().await;
+    assert!(secret_manager.is_locked().await);
+    assert!(matches!(
+        secret_manager.generate_ed25519_addresses(options.clone()).await,
+        Err(Error::SecretManagerLocked)
+    ));
+
+    secret_manager.unlock(mnemonic.to_owned()).await?;
+    assert!(!secret_manager.is_locked().await);
+    assert_eq!(
+        secret_manager.generate_ed25519_addresses(options).await?[0],
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a"
+    );
+
+    Ok(())
+}
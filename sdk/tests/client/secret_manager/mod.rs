@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod hardware;
+mod interceptor;
 mod mnemonic;
 #[cfg(feature = "private_key_secret_manager")]
 mod private_key;
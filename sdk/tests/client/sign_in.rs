@@ -0,0 +1,109 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::SecretManager,
+        sign_in::{SignInChallenge, SignInError, SignInResponse, SignInResponseDto},
+        Result,
+    },
+    types::block::address::Bech32Address,
+};
+
+const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+const ADDRESS: &str = "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a";
+const DOMAIN: &str = "example.com";
+
+async fn sign(challenge: SignInChallenge, chain: Bip44) -> Result<SignInResponse> {
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC.to_owned())?;
+    let signature = secret_manager.sign_data(chain, challenge.message().as_bytes()).await?;
+
+    Ok(SignInResponse { challenge, signature })
+}
+
+#[tokio::test]
+async fn sign_in_challenge_message() -> Result<()> {
+    let challenge = SignInChallenge {
+        domain: DOMAIN.to_owned(),
+        address: Bech32Address::try_from_str(ADDRESS)?,
+        statement: Some("I accept the terms of service.".to_owned()),
+        nonce: "0x0123456789abcdef0123456789abcdef".to_owned(),
+        issued_at: 1_700_000_000,
+        expires_at: 1_700_000_300,
+    };
+
+    assert_eq!(
+        challenge.message(),
+        "example.com wants you to sign in with your IOTA address:\n\
+        rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a\n\n\
+        I accept the terms of service.\n\n\
+        Nonce: 0x0123456789abcdef0123456789abcdef\n\
+        Issued At: 1700000000\n\
+        Expiration Time: 1700000300"
+    );
+
+    let other = SignInChallenge::new(DOMAIN, ADDRESS, Duration::from_secs(300))?;
+    assert_ne!(challenge.nonce, other.nonce);
+    assert_eq!(other.expires_at - other.issued_at, 300);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_in_verify() -> Result<()> {
+    let challenge = SignInChallenge::new(DOMAIN, ADDRESS, Duration::from_secs(300))?.with_statement("Sign in");
+    let nonce = challenge.nonce.clone();
+    let now = challenge.issued_at;
+    let response = sign(challenge, Bip44::new(SHIMMER_COIN_TYPE)).await?;
+
+    assert_eq!(response.verify(DOMAIN, &nonce, now), Ok(()));
+    assert_eq!(
+        response.verify("evil.com", &nonce, now),
+        Err(SignInError::DomainMismatch(DOMAIN.to_owned()))
+    );
+    assert_eq!(response.verify(DOMAIN, "0x00", now), Err(SignInError::NonceMismatch));
+    assert_eq!(
+        response.verify(DOMAIN, &nonce, now - 1),
+        Err(SignInError::NotYetValid(now))
+    );
+    assert_eq!(
+        response.verify(DOMAIN, &nonce, now + 300),
+        Err(SignInError::Expired(now + 300))
+    );
+
+    // The response survives a round trip through its dto
+    let dto = SignInResponseDto::from(&response);
+    let json = serde_json::to_string(&dto)?;
+    let response_from_dto = SignInResponse::try_from(serde_json::from_str::<SignInResponseDto>(&json)?)?;
+    assert_eq!(response_from_dto, response);
+
+    // A changed challenge invalidates the signature
+    let mut tampered = response.clone();
+    tampered.challenge.statement.replace("Send all funds".to_owned());
+    assert!(matches!(
+        tampered.verify(DOMAIN, &nonce, now),
+        Err(SignInError::InvalidSignature(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sign_in_with_other_key() -> Result<()> {
+    let challenge = SignInChallenge::new(DOMAIN, ADDRESS, Duration::from_secs(300))?;
+    let nonce = challenge.nonce.clone();
+    let now = challenge.issued_at;
+    // Sign with the key of another address
+    let response = sign(challenge, Bip44::new(SHIMMER_COIN_TYPE).with_address_index(1)).await?;
+
+    assert!(matches!(
+        response.verify(DOMAIN, &nonce, now),
+        Err(SignInError::InvalidSignature(_))
+    ));
+
+    Ok(())
+}
@@ -5,12 +5,12 @@ use crypto::keys::bip44::Bip44;
 use iota_sdk::{
     client::{
         api::{
-            transaction::validate_transaction_payload_length, verify_semantic, GetAddressesOptions,
-            PreparedTransactionData,
+            transaction::{validate_transaction_payload_length, verify_prepared_transaction},
+            verify_semantic, GetAddressesOptions, PreparedTransactionData,
         },
         constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{SecretManage, SecretManager},
-        Client, Result,
+        Client, Error, Result,
     },
     types::block::{
         address::ToBech32Ext,
@@ -26,7 +26,7 @@ use iota_sdk::{
     },
 };
 
-use crate::client::{build_inputs, build_outputs, Build::Basic};
+use crate::client::{build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0};
 
 #[tokio::test]
 async fn single_ed25519_unlock() -> Result<()> {
@@ -322,3 +322,65 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn verify_prepared_transaction_without_signing() -> Result<()> {
+    let protocol_parameters = protocol_parameters();
+
+    let prepared_transaction_data = |chain: Option<Bip44>| -> Result<PreparedTransactionData> {
+        let inputs = build_inputs([Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            chain,
+        )]);
+        let outputs = build_outputs([Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )]);
+
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(
+                protocol_parameters.network_id(),
+                InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+            )
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                    .collect::<Vec<_>>(),
+            )
+            .with_outputs(outputs)
+            .finish_with_params(protocol_parameters.clone())?,
+        );
+
+        Ok(PreparedTransactionData {
+            essence,
+            inputs_data: inputs,
+            remainder: None,
+        })
+    };
+
+    verify_prepared_transaction(
+        &prepared_transaction_data(Some(Bip44::new(SHIMMER_COIN_TYPE)))?,
+        &protocol_parameters,
+        100,
+    )?;
+
+    assert!(matches!(
+        verify_prepared_transaction(&prepared_transaction_data(None)?, &protocol_parameters, 100),
+        Err(Error::MissingBip32Chain)
+    ));
+
+    Ok(())
+}
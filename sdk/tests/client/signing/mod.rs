@@ -3,6 +3,7 @@
 
 mod alias;
 mod basic;
+mod multi;
 mod nft;
 
 use std::str::FromStr;
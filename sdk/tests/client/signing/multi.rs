@@ -0,0 +1,276 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{input_selection::Error as InputSelectionError, GetAddressesOptions, PreparedTransactionData},
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{
+            multi::{
+                sign_request, PartialSignature, PartialSignatureDto, SigningCoordinator, SigningRequest,
+                SigningRequestDto,
+            },
+            SecretManager,
+        },
+        Client, Error, Result,
+    },
+    types::{
+        block::{
+            address::{Address, AliasAddress, ToBech32Ext},
+            input::{Input, UtxoInput},
+            output::{AliasId, InputsCommitment},
+            payload::transaction::{RegularTransactionEssence, TransactionEssence},
+            protocol::protocol_parameters,
+            unlock::{SignatureUnlock, Unlock},
+        },
+        TryFromDto,
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Alias, Basic},
+    ALIAS_ID_1,
+};
+
+#[tokio::test]
+async fn two_signers() -> Result<()> {
+    let secret_manager_0 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let secret_manager_1 = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let options = GetAddressesOptions::default()
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_range(0..1);
+    let bech32_address_0 =
+        &secret_manager_0.generate_ed25519_addresses(options.clone()).await?[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let bech32_address_1 =
+        &secret_manager_1.generate_ed25519_addresses(options).await?[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Bip44::new(SHIMMER_COIN_TYPE)),
+        ),
+        Basic(
+            1_000_000,
+            &bech32_address_1.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Bip44::new(SHIMMER_COIN_TYPE)),
+        ),
+    ]);
+
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let mut coordinator = SigningCoordinator::new(prepared_transaction_data, 100)?;
+    assert_eq!(coordinator.required_count(), 2);
+
+    // The request is sent to the participants in its serialized form.
+    let request = SigningRequest::try_from_dto(serde_json::from_str::<SigningRequestDto>(&serde_json::to_string(
+        &SigningRequestDto::from(&coordinator.signing_request()),
+    )?)?)?;
+    assert_eq!(request.essence_hash(), request.prepared_transaction_data.essence.hash());
+
+    // Participants don't sign requests whose resolved inputs don't match the transaction
+    let mut tampered_request = request.clone();
+    tampered_request.prepared_transaction_data.inputs_data.swap(0, 1);
+    assert!(matches!(
+        sign_request(&secret_manager_0, &tampered_request).await,
+        Err(Error::InvalidSigningRequest(_))
+    ));
+    let mut tampered_request = request.clone();
+    tampered_request.prepared_transaction_data.inputs_data.pop();
+    assert!(matches!(
+        sign_request(&secret_manager_0, &tampered_request).await,
+        Err(Error::InvalidSigningRequest(_))
+    ));
+
+    let partial_signatures_0 = sign_request(&secret_manager_0, &request).await?;
+    assert_eq!(partial_signatures_0.len(), 1);
+    let partial_signatures_0 = partial_signatures_0
+        .iter()
+        .map(|p| serde_json::to_string(&PartialSignatureDto::from(p)))
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    for partial_signature in partial_signatures_0 {
+        coordinator.add_partial_signature(PartialSignature::try_from(
+            serde_json::from_str::<PartialSignatureDto>(&partial_signature)?,
+        )?)?;
+    }
+
+    assert!(!coordinator.is_complete());
+    assert_eq!(
+        coordinator.missing_signers(),
+        vec![*bech32_address_1.inner().as_ed25519()]
+    );
+    assert!(coordinator.unlocks().is_err());
+
+    coordinator.add_partial_signatures(sign_request(&secret_manager_1, &request).await?)?;
+    assert!(coordinator.is_complete());
+
+    let unlocks = coordinator.unlocks()?;
+    assert_eq!(unlocks.len(), 2);
+    assert!(unlocks.iter().all(|unlock| unlock.kind() == SignatureUnlock::KIND));
+
+    coordinator.finish()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn alias_unlocks() -> Result<()> {
+    let state_controller = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let governor = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+
+    let options = GetAddressesOptions::default()
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_range(0..1);
+    let state_controller_address =
+        &state_controller.generate_ed25519_addresses(options.clone()).await?[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let governor_address =
+        &governor.generate_ed25519_addresses(options).await?[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+    let alias_id = AliasId::from_str(ALIAS_ID_1)?;
+    let alias_address = &Address::Alias(AliasAddress::new(alias_id)).to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    // An alias input with an input owned by the alias, the state index of the alias output determines the transition
+    let prepared_transaction_data = |state_index: u32, new_governor_address: &str| -> Result<PreparedTransactionData> {
+        let inputs = build_inputs([
+            Alias(
+                1_000_000,
+                alias_id,
+                0,
+                &state_controller_address.to_string(),
+                &governor_address.to_string(),
+                None,
+                None,
+                None,
+                Some(Bip44::new(SHIMMER_COIN_TYPE)),
+            ),
+            Basic(
+                1_000_000,
+                &alias_address.to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+        ]);
+        let outputs = build_outputs([
+            Alias(
+                1_000_000,
+                alias_id,
+                state_index,
+                &state_controller_address.to_string(),
+                new_governor_address,
+                None,
+                None,
+                None,
+                None,
+            ),
+            Basic(
+                1_000_000,
+                &alias_address.to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+        ]);
+
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(
+                protocol_parameters.network_id(),
+                InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+            )
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                    .collect::<Vec<_>>(),
+            )
+            .with_outputs(outputs)
+            .finish_with_params(protocol_parameters.clone())?,
+        );
+
+        Ok(PreparedTransactionData {
+            essence,
+            inputs_data: inputs,
+            remainder: None,
+        })
+    };
+
+    // The state controller unlocks the alias and the output it owns in a state transition
+    let mut coordinator = SigningCoordinator::new(prepared_transaction_data(1, &governor_address.to_string())?, 100)?;
+    assert_eq!(
+        coordinator.missing_signers(),
+        vec![*state_controller_address.inner().as_ed25519()]
+    );
+    coordinator.add_partial_signatures(sign_request(&state_controller, &coordinator.signing_request()).await?)?;
+    let unlocks = coordinator.unlocks()?;
+    assert_eq!(unlocks.len(), 2);
+    assert_eq!(unlocks[0].kind(), SignatureUnlock::KIND);
+    assert!(matches!(&unlocks[1], Unlock::Alias(unlock) if unlock.index() == 0));
+    coordinator.finish()?;
+
+    // Changing the governor is a governance transition, which doesn't unlock the alias address
+    assert!(matches!(
+        SigningCoordinator::new(
+            prepared_transaction_data(0, &state_controller_address.to_string())?,
+            100
+        ),
+        Err(Error::InputSelection(
+            InputSelectionError::MissingInputWithEd25519Address
+        ))
+    ));
+
+    Ok(())
+}
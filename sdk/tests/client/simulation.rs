@@ -0,0 +1,142 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{api::simulate_transaction, secret::types::InputSigningData, Result},
+    types::block::{
+        input::{Input, UtxoInput},
+        output::{InputsCommitment, Output},
+        payload::transaction::RegularTransactionEssence,
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{self, Basic},
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+};
+
+fn simulate<'a>(
+    inputs: impl IntoIterator<Item = Build<'a>>,
+    outputs: impl IntoIterator<Item = Build<'a>>,
+    current_time: u32,
+) -> Result<ConflictReason> {
+    let inputs = build_inputs(inputs);
+    let essence = RegularTransactionEssence::builder(
+        protocol_parameters().network_id(),
+        InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+    )
+    .with_inputs(
+        inputs
+            .iter()
+            .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+            .collect::<Vec<_>>(),
+    )
+    .with_outputs(build_outputs(outputs))
+    .finish_with_params(protocol_parameters())?;
+
+    simulate_transaction(
+        &essence,
+        &inputs
+            .into_iter()
+            .map(
+                |InputSigningData {
+                     output,
+                     output_metadata,
+                     ..
+                 }| (*output_metadata.output_id(), output),
+            )
+            .collect::<Vec<(_, Output)>>(),
+        current_time,
+    )
+}
+
+#[test]
+fn simulate_valid_transaction() -> Result<()> {
+    let conflict = simulate(
+        [
+            Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+            Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+            Basic(1_000_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+        ],
+        [Basic(
+            3_000_000,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )],
+        100,
+    )?;
+
+    assert_eq!(conflict, ConflictReason::None);
+
+    Ok(())
+}
+
+#[test]
+fn simulate_amount_mismatch() -> Result<()> {
+    let conflict = simulate(
+        [Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )],
+        [Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )],
+        100,
+    )?;
+
+    assert_eq!(conflict, ConflictReason::CreatedConsumedAmountMismatch);
+
+    Ok(())
+}
+
+#[test]
+fn simulate_timelocked_input() -> Result<()> {
+    let conflict = simulate(
+        [Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            Some(200),
+            None,
+            None,
+        )],
+        [Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )],
+        100,
+    )?;
+
+    assert_eq!(conflict, ConflictReason::TimelockNotExpired);
+
+    Ok(())
+}
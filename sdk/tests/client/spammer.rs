@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
+use iota_sdk::client::{
+    node_api::error::Result as NodeApiResult,
+    node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+    spammer::{SpamStatistics, SpammerOptions},
+    Client,
+};
+
+// A node that rejects every request, so every block fails without waiting for a network
+struct RejectingTransport;
+
+#[async_trait::async_trait]
+impl HttpTransport for RejectingTransport {
+    async fn send(&self, _request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        Ok(HttpResponse {
+            status: 400,
+            headers: Vec::new(),
+            body: br#"{"error":{"code":"400","message":"rejected"}}"#.to_vec(),
+        })
+    }
+}
+
+async fn rejecting_client() -> Client {
+    Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_http_client(RejectingTransport)
+        .finish()
+        .await
+        .unwrap()
+}
+
+#[test]
+fn spam_statistics_blocks_per_second() {
+    let mut statistics = SpamStatistics {
+        data_blocks: 10,
+        value_blocks: 5,
+        failed_blocks: 1,
+        elapsed: Duration::from_secs(5),
+    };
+    // Failed blocks aren't counted
+    assert_eq!(statistics.blocks_per_second(), 3.0);
+
+    statistics.elapsed = Duration::ZERO;
+    assert_eq!(statistics.blocks_per_second(), 0.0);
+}
+
+#[tokio::test]
+async fn spam_stops_after_block_count() {
+    let client = rejecting_client().await;
+
+    let statistics = client
+        .spam(
+            SpammerOptions {
+                blocks_per_second: NonZeroU32::new(1000).unwrap(),
+                workers: NonZeroUsize::new(3).unwrap(),
+                block_count: Some(5),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Failed blocks count towards the block count, but don't stop the spammer earlier
+    assert_eq!(statistics.failed_blocks, 5);
+    assert_eq!(statistics.data_blocks, 0);
+    assert_eq!(statistics.value_blocks, 0);
+}
+
+#[tokio::test]
+async fn spam_stops_after_duration() {
+    let client = rejecting_client().await;
+
+    let statistics = client
+        .spam(
+            SpammerOptions {
+                blocks_per_second: NonZeroU32::new(10).unwrap(),
+                workers: NonZeroUsize::new(4).unwrap(),
+                duration: Some(Duration::from_millis(250)),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(statistics.elapsed >= Duration::from_millis(250));
+    // The workers share the rate limit, which allows one block immediately and then one every 100 ms
+    assert!((1..=3).contains(&statistics.failed_blocks));
+}
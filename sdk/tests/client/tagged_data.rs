@@ -0,0 +1,84 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        api::{JsonTagCodec, TagCodec},
+        Client, Error, Result,
+    },
+    types::block::{payload::TaggedDataPayload, protocol::protocol_parameters},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    name: String,
+    count: u32,
+}
+
+/// Prefixes the JSON with a version byte.
+struct VersionedCodec(u8);
+
+impl TagCodec for VersionedCodec {
+    fn encode(&self, value: &Value) -> Result<Vec<u8>> {
+        let mut data = vec![self.0];
+        data.extend(JsonTagCodec.encode(value)?);
+        Ok(data)
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<Value> {
+        match data.split_first() {
+            Some((version, json)) if *version == self.0 => JsonTagCodec.decode(json),
+            _ => Err(Error::TagCodec("unknown version".to_string())),
+        }
+    }
+}
+
+async fn offline_client() -> Client {
+    Client::builder()
+        .with_protocol_parameters(protocol_parameters())
+        .with_offline_mode()
+        .finish()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn longest_namespace_wins() {
+    let client = offline_client().await;
+    let event = Event {
+        name: "test".to_string(),
+        count: 3,
+    };
+
+    client.register_tag_codec("app.", VersionedCodec(1)).await;
+    client.register_tag_codec("app.events.", VersionedCodec(2)).await;
+
+    for (tag, first_byte) in [
+        (&b"other"[..], b'{'),
+        (&b"app.metrics"[..], 1),
+        (&b"app.events.created"[..], 2),
+    ] {
+        let data = client.encode_tagged_data(tag, &event).await.unwrap();
+        assert_eq!(data[0], first_byte);
+
+        let payload = TaggedDataPayload::new(tag.to_vec(), data).unwrap();
+        assert_eq!(client.decode_tagged_data::<Event>(&payload).await.unwrap(), event);
+    }
+
+    client.unregister_tag_codec(b"app.events.").await;
+    let data = client.encode_tagged_data(b"app.events.created", &event).await.unwrap();
+    assert_eq!(data[0], 1);
+}
+
+#[tokio::test]
+async fn decode_wrong_type() {
+    let client = offline_client().await;
+
+    let payload = TaggedDataPayload::new(b"tag".to_vec(), b"{\"name\":\"test\"}".to_vec()).unwrap();
+    assert!(matches!(
+        client.decode_tagged_data::<Event>(&payload).await,
+        Err(Error::TagCodec(_))
+    ));
+}
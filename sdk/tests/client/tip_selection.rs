@@ -0,0 +1,226 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use iota_sdk::{
+    client::{
+        api::block_builder::TipSelectionStrategy,
+        node_api::error::Result as NodeApiResult,
+        node_manager::http_client::{HttpRequest, HttpResponse, HttpTransport},
+        Client,
+    },
+    types::block::{protocol::ProtocolParameters, rand::block::rand_block_id, BlockId},
+};
+
+#[test]
+fn tip_selection_strategy_serde() {
+    let strategy = serde_json::from_str::<TipSelectionStrategy>(r#"{"type":"semiLazyAvoidance"}"#).unwrap();
+    assert_eq!(strategy, TipSelectionStrategy::SemiLazyAvoidance);
+    assert_eq!(
+        serde_json::from_str::<TipSelectionStrategy>(r#"{"type":"default"}"#).unwrap(),
+        TipSelectionStrategy::default()
+    );
+
+    let previous_block_id = rand_block_id();
+    let strategy = TipSelectionStrategy::OwnChain { previous_block_id };
+    let json = serde_json::to_value(&strategy).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "type": "ownChain", "previousBlockId": previous_block_id.to_string() })
+    );
+    assert_eq!(serde_json::from_value::<TipSelectionStrategy>(json).unwrap(), strategy);
+}
+
+/// Serves the tips and the `(should_promote, should_reattach)` flags of their metadata, tips without metadata are
+/// unknown to the node.
+#[derive(Default)]
+struct TipsTransport {
+    tips: Vec<BlockId>,
+    metadata: HashMap<BlockId, (bool, bool)>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpTransport for TipsTransport {
+    async fn send(&self, request: HttpRequest) -> NodeApiResult<HttpResponse> {
+        let path = request.url.path().to_owned();
+        self.requests.lock().unwrap().push(request);
+
+        let body = if path.ends_with("/api/core/v2/tips") {
+            Some(serde_json::json!({ "tips": self.tips }))
+        } else {
+            path.strip_prefix("/api/core/v2/blocks/")
+                .and_then(|path| path.strip_suffix("/metadata"))
+                .and_then(|block_id| block_id.parse::<BlockId>().ok())
+                .and_then(|block_id| {
+                    let (should_promote, should_reattach) = self.metadata.get(&block_id)?;
+                    Some(serde_json::json!({
+                        "blockId": block_id,
+                        "parents": [],
+                        "isSolid": true,
+                        "shouldPromote": should_promote,
+                        "shouldReattach": should_reattach,
+                    }))
+                })
+        };
+
+        Ok(match body {
+            Some(body) => HttpResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: serde_json::to_vec(&body).unwrap(),
+            },
+            None => HttpResponse {
+                status: 404,
+                headers: Vec::new(),
+                body: Vec::new(),
+            },
+        })
+    }
+}
+
+async fn tips_client(transport: TipsTransport) -> Client {
+    Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_protocol_parameters(ProtocolParameters::default())
+        .with_http_client(transport)
+        .finish()
+        .await
+        .unwrap()
+}
+
+fn sorted(mut block_ids: Vec<BlockId>) -> Vec<BlockId> {
+    block_ids.sort_unstable();
+    block_ids
+}
+
+#[tokio::test]
+async fn semi_lazy_avoidance() {
+    let (non_lazy, should_promote, should_reattach, unknown) =
+        (rand_block_id(), rand_block_id(), rand_block_id(), rand_block_id());
+    let client = tips_client(TipsTransport {
+        tips: vec![non_lazy, should_promote, should_reattach, unknown],
+        metadata: HashMap::from([
+            (non_lazy, (false, false)),
+            (should_promote, (true, false)),
+            (should_reattach, (false, true)),
+        ]),
+        ..Default::default()
+    })
+    .await;
+
+    let parents = client
+        .select_parents(&TipSelectionStrategy::Default, None)
+        .await
+        .unwrap();
+    assert_eq!(parents.len(), 4);
+
+    // Tips of which the metadata is unknown are kept
+    let parents = client
+        .select_parents(&TipSelectionStrategy::SemiLazyAvoidance, None)
+        .await
+        .unwrap();
+    assert_eq!(parents.to_vec(), sorted(vec![non_lazy, unknown]));
+}
+
+#[tokio::test]
+async fn semi_lazy_avoidance_falls_back_to_all_tips() {
+    let tips = vec![rand_block_id(), rand_block_id()];
+    let client = tips_client(TipsTransport {
+        metadata: tips.iter().map(|tip| (*tip, (true, false))).collect(),
+        tips: tips.clone(),
+        ..Default::default()
+    })
+    .await;
+
+    let parents = client
+        .select_parents(&TipSelectionStrategy::SemiLazyAvoidance, None)
+        .await
+        .unwrap();
+    assert_eq!(parents.to_vec(), sorted(tips));
+}
+
+#[tokio::test]
+async fn own_chain() {
+    let previous_block_id = rand_block_id();
+    let tips = std::iter::repeat_with(rand_block_id).take(8).collect::<Vec<_>>();
+    let client = tips_client(TipsTransport {
+        tips: tips.clone(),
+        ..Default::default()
+    })
+    .await;
+
+    // The previous block is referenced and only as many tips as still fit are added
+    let parents = client
+        .select_parents(&TipSelectionStrategy::OwnChain { previous_block_id }, None)
+        .await
+        .unwrap();
+    assert_eq!(parents.len(), 8);
+    assert!(parents.contains(&previous_block_id));
+    assert!(
+        parents
+            .iter()
+            .all(|parent| *parent == previous_block_id || tips.contains(parent))
+    );
+
+    // The previous block isn't referenced twice if it's a tip
+    let tip = rand_block_id();
+    let client = tips_client(TipsTransport {
+        tips: vec![previous_block_id, tip],
+        ..Default::default()
+    })
+    .await;
+    let parents = client
+        .select_parents(&TipSelectionStrategy::OwnChain { previous_block_id }, None)
+        .await
+        .unwrap();
+    assert_eq!(parents.to_vec(), sorted(vec![previous_block_id, tip]));
+}
+
+#[tokio::test]
+async fn tips_from_node_behind_path_prefix() {
+    let tip = rand_block_id();
+    let transport = TipsTransport {
+        tips: vec![tip],
+        ..Default::default()
+    };
+    let requests = transport.requests.clone();
+    let client = tips_client(transport).await;
+
+    for url in ["http://localhost:8080/node", "http://localhost:8080/node/"] {
+        let parents = client
+            .select_parents(&TipSelectionStrategy::Default, Some(&url.parse().unwrap()))
+            .await
+            .unwrap();
+        assert_eq!(parents.to_vec(), vec![tip]);
+    }
+    assert_eq!(
+        client
+            .get_tips_from(&"http://localhost:8080".parse().unwrap())
+            .await
+            .unwrap(),
+        vec![tip]
+    );
+
+    let requests = requests.lock().unwrap();
+    // Without the node info requested from the node pool when the client was built
+    let urls = requests
+        .iter()
+        .map(|request| request.url.as_str())
+        .filter(|url| url.starts_with("http://localhost:8080"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        urls,
+        [
+            "http://localhost:8080/node/api/core/v2/tips",
+            "http://localhost:8080/node/api/core/v2/tips",
+            "http://localhost:8080/api/core/v2/tips",
+        ]
+    );
+}
@@ -4,14 +4,19 @@
 // These are E2E test samples, so they are ignored by default.
 
 use iota_sdk::{
-    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Result},
+    client::{
+        api::{GetAddressesOptions, SendAmountOptions},
+        node_api::indexer::query_parameters::QueryParameter,
+        Result,
+    },
     types::block::{
-        address::ToBech32Ext,
+        address::{Address, ToBech32Ext},
         input::{Input, UtxoInput},
         output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
         payload::Payload,
     },
 };
+use packable::PackableExt;
 
 use crate::client::common::create_client_and_secret_manager_with_funds;
 
@@ -70,6 +75,53 @@ async fn send_basic_output() -> Result<()> {
     Ok(())
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_amount() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let second_address = Address::from(
+        secret_manager
+            .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(1..2))
+            .await?[0],
+    );
+
+    let result = client
+        .send_amount(
+            &secret_manager,
+            second_address.to_bech32(client.get_bech32_hrp().await?),
+            1_000_000,
+            SendAmountOptions {
+                input_range: Some(0..1),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    // The block is included, so the outputs can be fetched right away
+    let output = client.get_output(&result.output_id).await?;
+    assert_eq!(output.output().amount(), 1_000_000);
+    assert_eq!(
+        output
+            .output()
+            .unlock_conditions()
+            .unwrap()
+            .address()
+            .unwrap()
+            .address(),
+        &second_address
+    );
+    assert_eq!(*output.metadata().transaction_id(), result.transaction_id);
+    // The faucet funds are more than the amount
+    assert!(result.remainder_output_id.is_some());
+    assert!(!result.inputs.is_empty());
+    for input in &result.inputs {
+        assert!(client.get_output_metadata(input).await?.is_spent());
+    }
+
+    Ok(())
+}
+
 #[ignore]
 #[tokio::test]
 async fn custom_input() -> Result<()> {
@@ -135,3 +187,44 @@ async fn custom_input() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn estimate_transaction() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let token_supply = client.get_token_supply().await?;
+    let rent_structure = client.get_rent_structure().await?;
+
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(1..2))
+        .await?[0];
+
+    let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(token_supply)?;
+
+    let prepared_transaction = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output.clone()])?
+        .prepare_transaction()
+        .await?;
+    let estimate = client.estimate_transaction(&prepared_transaction.essence).await?;
+
+    let essence = prepared_transaction.essence.as_regular();
+    assert_eq!(estimate.storage_deposits.len(), essence.outputs().len());
+    // the output was built with the minimum storage deposit
+    assert_eq!(estimate.storage_deposits[0], output.amount());
+    assert!(!estimate.exceeds_max_block_size);
+
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs([output])?
+        .finish()
+        .await?;
+    assert!(block.packed_len() <= estimate.block_size);
+
+    Ok(())
+}
@@ -31,6 +31,30 @@ fn get_miner_num_workers_score() {
     assert!(PowScorer::new().score(&bytes) >= 100f64);
 }
 
+#[test]
+fn miner_niceness_score() {
+    let miner = MinerBuilder::new().with_num_workers(2).with_niceness(10).finish();
+    let mut bytes = rand_bytes(256);
+
+    let nonce = miner.nonce(&bytes[0..248], 100).unwrap();
+    bytes[248..].copy_from_slice(&nonce.to_le_bytes());
+
+    assert!(PowScorer::new().score(&bytes) >= 100f64);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn miner_thread_pool_score() {
+    let thread_pool = std::sync::Arc::new(rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+    let miner = MinerBuilder::new().with_thread_pool(thread_pool).finish();
+    let mut bytes = rand_bytes(256);
+
+    let nonce = miner.nonce(&bytes[0..248], 100).unwrap();
+    bytes[248..].copy_from_slice(&nonce.to_le_bytes());
+
+    assert!(PowScorer::new().score(&bytes) >= 100f64);
+}
+
 #[test]
 fn get_miner_score_4000() {
     let miner = get_miner(4000);
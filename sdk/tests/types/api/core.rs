@@ -0,0 +1,115 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+
+use iota_sdk::types::{
+    api::core::response::{BlockMetadataResponse, LedgerInclusionState, Receipt, ReceiptResponse},
+    block::{
+        address::{Address, Ed25519Address},
+        input::TreasuryInput,
+        output::TreasuryOutput,
+        payload::{
+            milestone::{
+                option::{MigratedFundsEntry, ReceiptMilestoneOption, TailTransactionHash},
+                MilestoneId, MilestoneIndex,
+            },
+            TreasuryTransactionPayload,
+        },
+        protocol::protocol_parameters,
+        semantic::{ConflictError, ConflictReason},
+    },
+    TryFromDto,
+};
+
+#[test]
+fn block_metadata_conflict_reason() {
+    let json = serde_json::json!({
+        "blockId": "0x3a1d3a4c5e8e5b9e1b4a1b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80",
+        "parents": ["0x3a1d3a4c5e8e5b9e1b4a1b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f81"],
+        "isSolid": true,
+        "referencedByMilestoneIndex": 1000,
+        "ledgerInclusionState": "conflicting",
+        "conflictReason": 1,
+    });
+
+    let metadata = serde_json::from_value::<BlockMetadataResponse>(json.clone()).unwrap();
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionState::Conflicting));
+    assert_eq!(metadata.conflict_reason, Some(1));
+    assert_eq!(
+        metadata.conflict_reason().unwrap(),
+        Some(ConflictReason::InputUtxoAlreadySpent)
+    );
+    assert_eq!(serde_json::to_value(&metadata).unwrap(), json);
+
+    // Reasons added by newer node versions don't break the response
+    let json = serde_json::json!({
+        "blockId": "0x3a1d3a4c5e8e5b9e1b4a1b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80",
+        "parents": [],
+        "isSolid": true,
+        "conflictReason": 13,
+    });
+    let metadata = serde_json::from_value::<BlockMetadataResponse>(json).unwrap();
+    assert_eq!(metadata.conflict_reason, Some(13));
+    assert!(matches!(
+        metadata.conflict_reason(),
+        Err(ConflictError::InvalidConflict(13))
+    ));
+
+    let json = serde_json::json!({
+        "blockId": "0x3a1d3a4c5e8e5b9e1b4a1b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80",
+        "parents": [],
+        "isSolid": true,
+    });
+    let metadata = serde_json::from_value::<BlockMetadataResponse>(json).unwrap();
+    assert_eq!(metadata.conflict_reason().unwrap(), None);
+}
+
+#[test]
+fn receipt() {
+    let token_supply = protocol_parameters().token_supply();
+    let milestone_id =
+        MilestoneId::from_str("0x40498d437a95fe67c1ed467e6ee85567833c36bf91e71742ea2c71e0633146b9").unwrap();
+    let receipt = Receipt {
+        milestone_index: 10,
+        receipt: ReceiptMilestoneOption::new(
+            MilestoneIndex::new(3),
+            true,
+            [MigratedFundsEntry::new(
+                TailTransactionHash::new([
+                    222, 235, 107, 67, 2, 173, 253, 93, 165, 90, 166, 45, 102, 91, 19, 137, 71, 146, 156, 180, 248, 31,
+                    56, 25, 68, 154, 98, 100, 64, 108, 203, 48, 76, 75, 114, 150, 34, 153, 203, 35, 225, 120, 194, 175,
+                    169, 207, 80, 229, 10,
+                ])
+                .unwrap(),
+                Address::from(
+                    Ed25519Address::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649")
+                        .unwrap(),
+                ),
+                1_000_000,
+                token_supply,
+            )
+            .unwrap()],
+            TreasuryTransactionPayload::new(
+                TreasuryInput::new(milestone_id),
+                TreasuryOutput::new(2_000_000, token_supply).unwrap(),
+            )
+            .unwrap(),
+            token_supply,
+        )
+        .unwrap(),
+    };
+
+    assert_eq!(receipt.migrated_at(), MilestoneIndex::new(3));
+    assert_eq!(receipt.consumed_treasury_milestone_id(), &milestone_id);
+    assert_eq!(receipt.remaining_treasury(), 2_000_000);
+
+    let response =
+        serde_json::from_value::<ReceiptResponse>(serde_json::to_value(ReceiptResponse::from(&receipt)).unwrap())
+            .unwrap();
+    assert_eq!(response.milestone_index, 10);
+    assert_eq!(
+        Receipt::try_from_dto_with_params(response, protocol_parameters()).unwrap(),
+        receipt
+    );
+}
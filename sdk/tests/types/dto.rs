@@ -0,0 +1,91 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::{
+    block::{
+        output::{Output, OutputId, OutputMetadata},
+        payload::Payload,
+        protocol::protocol_parameters,
+    },
+    dto::{response::OutputWithMetadataResponse, OutputDto, PayloadDto},
+    TryFromDto,
+};
+
+const OUTPUT_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000";
+
+fn basic_output_json() -> serde_json::Value {
+    serde_json::json!({
+        "type": 3,
+        "amount": "1000000",
+        "unlockConditions": [{
+            "type": 0,
+            "address": {
+                "type": 0,
+                "pubKeyHash": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649",
+            },
+        }],
+        "features": [{ "type": 3, "tag": "0x48656c6c6f" }],
+    })
+}
+
+#[test]
+fn output_json_round_trip() {
+    let json = basic_output_json();
+
+    let output = Output::try_from_dto_with_params(
+        serde_json::from_value::<OutputDto>(json.clone()).unwrap(),
+        protocol_parameters(),
+    )
+    .unwrap();
+    assert_eq!(output.amount(), 1_000_000);
+    assert_eq!(serde_json::to_value(OutputDto::from(&output)).unwrap(), json);
+
+    // Invalid outputs are rejected when converting the DTO
+    let mut json = json;
+    json["amount"] = "0".into();
+    let dto = serde_json::from_value::<OutputDto>(json).unwrap();
+    assert!(Output::try_from_dto_with_params(dto, protocol_parameters()).is_err());
+}
+
+#[test]
+fn payload_json_round_trip() {
+    let json = serde_json::json!({
+        "type": 5,
+        "tag": "0x48656c6c6f",
+        "data": "0x54616e676c65",
+    });
+
+    let payload = Payload::try_from_dto_with_params(
+        serde_json::from_value::<PayloadDto>(json.clone()).unwrap(),
+        protocol_parameters(),
+    )
+    .unwrap();
+    let Payload::TaggedData(tagged_data) = &payload else {
+        panic!("expected a tagged data payload");
+    };
+    assert_eq!(tagged_data.tag(), b"Hello");
+    assert_eq!(tagged_data.data(), b"Tangle");
+    assert_eq!(serde_json::to_value(PayloadDto::from(&payload)).unwrap(), json);
+}
+
+#[test]
+fn response_json_round_trip() {
+    let json = serde_json::json!({
+        "metadata": {
+            "blockId": "0x3a1d3a4c5e8e5b9e1b4a1b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f80",
+            "transactionId": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649",
+            "outputIndex": 0,
+            "isSpent": false,
+            "milestoneIndexBooked": 1,
+            "milestoneTimestampBooked": 1_690_000_000,
+            "ledgerIndex": 2,
+        },
+        "output": basic_output_json(),
+    });
+
+    let response = serde_json::from_value::<OutputWithMetadataResponse>(json.clone()).unwrap();
+    let metadata: &OutputMetadata = &response.metadata;
+    assert_eq!(metadata.output_id(), &OUTPUT_ID.parse::<OutputId>().unwrap());
+    assert!(!metadata.is_spent());
+    assert_eq!(serde_json::to_value(&response).unwrap(), json);
+}
@@ -3,7 +3,10 @@
 
 use iota_sdk::types::block::{
     parent::Parents,
-    payload::milestone::{MilestoneEssence, MilestoneIndex, MilestoneOptions, MilestonePayload},
+    payload::milestone::{
+        MilestoneEssence, MilestoneIndex, MilestoneKeyRange, MilestoneOptions, MilestonePayload,
+        MilestoneValidationError,
+    },
     protocol::protocol_parameters,
     rand::{
         block::rand_block_ids,
@@ -12,6 +15,7 @@ use iota_sdk::types::block::{
         parents::rand_parents,
         signature::rand_signature,
     },
+    signature::Signature,
     Error,
 };
 use packable::{bounded::TryIntoBoundedU8Error, PackableExt};
@@ -165,3 +169,38 @@ fn getters() {
         assert_eq!(s1, s2);
     }
 }
+
+#[test]
+fn verify_key_ranges() {
+    let essence = MilestoneEssence::new(
+        MilestoneIndex(10),
+        rand_number::<u32>(),
+        protocol_parameters().protocol_version(),
+        rand_milestone_id(),
+        rand_parents(),
+        rand_merkle_root(),
+        rand_merkle_root(),
+        [],
+        MilestoneOptions::from_vec(vec![]).unwrap(),
+    )
+    .unwrap();
+    let signature = rand_signature();
+    let Signature::Ed25519(ed25519_signature) = &signature;
+    let public_key = hex::encode(ed25519_signature.public_key());
+    let milestone = MilestonePayload::new(essence, [signature.clone()]).unwrap();
+
+    let expired = MilestoneKeyRange::new(public_key.clone(), MilestoneIndex(0), MilestoneIndex(9));
+    assert!(!expired.is_applicable(MilestoneIndex(10)));
+    assert!(matches!(
+        milestone.verify(&[expired], 1),
+        Err(MilestoneValidationError::InsufficientApplicablePublicKeys(0, 1))
+    ));
+
+    // The key is applicable, but the random signature doesn't match the essence
+    let unbounded = MilestoneKeyRange::new(public_key, MilestoneIndex(10), MilestoneIndex(0));
+    assert!(unbounded.is_applicable(MilestoneIndex(u32::MAX)));
+    assert!(matches!(
+        milestone.verify(&[unbounded], 1),
+        Err(MilestoneValidationError::InvalidSignature(0, _))
+    ));
+}
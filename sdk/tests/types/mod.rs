@@ -6,6 +6,7 @@ mod api;
 #[cfg(feature = "pow")]
 mod block;
 mod block_id;
+mod dto;
 mod ed25519_signature;
 mod foundry_id;
 mod input;
@@ -28,3 +29,4 @@ mod transaction_regular_essence;
 mod treasury_output;
 mod treasury_transaction_payload;
 mod unlock;
+mod unlockability;
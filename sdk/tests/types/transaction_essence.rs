@@ -2,10 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::types::block::{
-    address::{Address, Ed25519Address},
+    address::{Address, Ed25519Address, ToBech32Ext},
     input::{Input, UtxoInput},
-    output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output},
-    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+    output::{
+        feature::MetadataFeature,
+        unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+        BasicOutput, Output, OutputId,
+    },
+    payload::{
+        transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+        TaggedDataPayload,
+    },
     protocol::protocol_parameters,
     rand::output::rand_inputs_commitment,
     Error,
@@ -48,3 +55,53 @@ fn essence_unpack_invalid_kind() {
         Err(UnpackError::Packable(Error::InvalidEssenceKind(2)))
     ));
 }
+
+#[test]
+fn essence_describe() {
+    let protocol_parameters = protocol_parameters();
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let input = Input::Utxo(UtxoInput::new(transaction_id, 0).unwrap());
+    let bytes: [u8; 32] = prefix_hex::decode(ED25519_ADDRESS).unwrap();
+    let address = Address::from(Ed25519Address::new(bytes));
+    let output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(address, 50_000, protocol_parameters.token_supply()).unwrap(),
+            )
+            .add_feature(MetadataFeature::new(b"hello".to_vec()).unwrap())
+            .finish_with_params(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs([input])
+            .add_output(output)
+            .with_payload(TaggedDataPayload::new(b"tag".to_vec(), vec![0xff]).unwrap())
+            .finish_with_params(&protocol_parameters)
+            .unwrap(),
+    );
+
+    let description = essence.describe(&protocol_parameters);
+    let bech32_address = address.to_bech32(*protocol_parameters.bech32_hrp());
+
+    assert!(description.is_network_matching);
+    assert_eq!(description.inputs, [OutputId::new(transaction_id, 0).unwrap()]);
+    assert!(description.consumed_outputs.is_empty());
+    assert_eq!(description.outputs.len(), 1);
+
+    let output = &description.outputs[0];
+    assert_eq!(output.kind, "Basic");
+    assert!(!output.is_change);
+    assert_eq!(output.recipient, Some(bech32_address));
+    assert_eq!(output.amount, 1_000_000);
+    let storage_deposit_return = output.storage_deposit_return.as_ref().unwrap();
+    assert_eq!(storage_deposit_return.return_address, bech32_address);
+    assert_eq!(storage_deposit_return.amount, 50_000);
+    assert_eq!(output.metadata.as_ref().unwrap().utf8.as_deref(), Some("hello"));
+
+    assert_eq!(description.tag.as_ref().unwrap().utf8.as_deref(), Some("tag"));
+    let data = description.data.as_ref().unwrap();
+    assert_eq!(data.hex, "0xff");
+    assert_eq!(data.utf8, None);
+}
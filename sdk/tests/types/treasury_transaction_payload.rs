@@ -4,7 +4,11 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    input::TreasuryInput, output::TreasuryOutput, payload::TreasuryTransactionPayload, protocol::protocol_parameters,
+    input::TreasuryInput,
+    output::TreasuryOutput,
+    payload::{milestone::MilestoneId, TreasuryTransactionPayload},
+    protocol::protocol_parameters,
+    Error,
 };
 use packable::PackableExt;
 
@@ -64,3 +68,39 @@ fn pack_unpack_invalid() {
     // `InvalidOutputKind` but something related to an invalid address, so not really relevant for this test.
     assert!(TreasuryTransactionPayload::unpack_verified(bytes.as_slice(), &protocol_parameters()).is_err());
 }
+
+#[test]
+fn new_migration_valid() {
+    let milestone_id = MilestoneId::from_str(BLOCK_ID).unwrap();
+    let transaction =
+        TreasuryTransactionPayload::new_migration(milestone_id, 1_000, 400, protocol_parameters().token_supply())
+            .unwrap();
+
+    assert_eq!(transaction.input().milestone_id(), &milestone_id);
+    assert_eq!(transaction.output().amount(), 600);
+    assert!(transaction.verify_migration(&milestone_id, 1_000, 400).is_ok());
+}
+
+#[test]
+fn verify_migration_invalid() {
+    let milestone_id = MilestoneId::from_str(BLOCK_ID).unwrap();
+    let transaction =
+        TreasuryTransactionPayload::new_migration(milestone_id, 1_000, 400, protocol_parameters().token_supply())
+            .unwrap();
+
+    assert_eq!(
+        transaction.verify_migration(&milestone_id, 1_000, 300),
+        Err(Error::TreasuryAmountMismatch {
+            expected: 700,
+            actual: 600
+        })
+    );
+    assert!(matches!(
+        transaction.verify_migration(&MilestoneId::null(), 1_000, 400),
+        Err(Error::TreasuryInputMismatch { .. })
+    ));
+    assert!(matches!(
+        TreasuryTransactionPayload::new_migration(milestone_id, 400, 1_000, protocol_parameters().token_supply()),
+        Err(Error::InvalidReceiptFundsSum(1_000))
+    ));
+}
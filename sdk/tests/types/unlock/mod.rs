@@ -3,6 +3,7 @@
 
 mod alias;
 mod nft;
+mod plan;
 mod reference;
 mod signature;
 
@@ -0,0 +1,64 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::{Address, NftAddress},
+    output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, NftOutputBuilder, Output},
+    protocol::protocol_parameters,
+    rand::{
+        address::{rand_alias_address, rand_ed25519_address},
+        output::rand_output_id,
+    },
+    unlock::{plan_unlocks, InputUnlock, NftUnlock, ReferenceUnlock, Unlock},
+    Error,
+};
+
+fn basic_output(owner: impl Into<Address>) -> Output {
+    BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(owner))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+#[test]
+fn plan() {
+    let owner = rand_ed25519_address();
+    let nft_output_id = rand_output_id();
+    let nft_output = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+        .add_unlock_condition(AddressUnlockCondition::new(owner))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap();
+    let owned_output_id = rand_output_id();
+    let owned_output = basic_output(owner);
+    let nft_owned_output_id = rand_output_id();
+    let nft_owned_output = basic_output(NftAddress::new(NftId::from(&nft_output_id)));
+
+    assert_eq!(
+        plan_unlocks(
+            [
+                (&nft_output_id, &nft_output),
+                (&owned_output_id, &owned_output),
+                (&nft_owned_output_id, &nft_owned_output),
+            ],
+            &[],
+            0,
+        )
+        .unwrap(),
+        [
+            InputUnlock::Signature(owner),
+            InputUnlock::Unlock(Unlock::Reference(ReferenceUnlock::new(0).unwrap())),
+            InputUnlock::Unlock(Unlock::Nft(NftUnlock::new(0).unwrap())),
+        ]
+    );
+}
+
+#[test]
+fn plan_missing_unlocking_input() {
+    let output_id = rand_output_id();
+    let output = basic_output(rand_alias_address());
+
+    assert_eq!(
+        plan_unlocks([(&output_id, &output)], &[], 0),
+        Err(Error::MissingInputWithEd25519Address)
+    );
+}
@@ -0,0 +1,72 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::Address,
+    output::{
+        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, TimelockUnlockCondition},
+        BasicOutputBuilder, Output, Unlockability,
+    },
+    protocol::protocol_parameters,
+    rand::address::rand_ed25519_address,
+};
+
+fn output(owner: Address, return_address: Address, timelock: u32, expiration: u32) -> Output {
+    BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(owner))
+        .add_unlock_condition(TimelockUnlockCondition::new(timelock).unwrap())
+        .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration).unwrap())
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+#[test]
+fn unlockable_by() {
+    let owner = Address::from(rand_ed25519_address());
+    let sender = Address::from(rand_ed25519_address());
+    let output = output(owner, sender, 100, 200);
+
+    assert_eq!(
+        output.unlockable_by(&owner, 50),
+        Unlockability::Later {
+            unlockable_at: 100,
+            expires_at: Some(200)
+        }
+    );
+    assert_eq!(
+        output.unlockable_by(&owner, 150),
+        Unlockability::Now { expires_at: Some(200) }
+    );
+    assert_eq!(output.unlockable_by(&owner, 200), Unlockability::Never);
+
+    assert_eq!(
+        output.unlockable_by(&sender, 150),
+        Unlockability::Later {
+            unlockable_at: 200,
+            expires_at: None
+        }
+    );
+    assert_eq!(
+        output.unlockable_by(&sender, 200),
+        Unlockability::Now { expires_at: None }
+    );
+
+    let other = Address::from(rand_ed25519_address());
+    assert_eq!(output.unlockable_by(&other, 150), Unlockability::Never);
+}
+
+#[test]
+fn unlockable_by_timelock_after_expiration() {
+    let owner = Address::from(rand_ed25519_address());
+    let sender = Address::from(rand_ed25519_address());
+    let output = output(owner, sender, 300, 200);
+
+    assert_eq!(output.unlockable_by(&owner, 50), Unlockability::Never);
+    assert_eq!(
+        output.unlockable_by(&sender, 50),
+        Unlockability::Later {
+            unlockable_at: 300,
+            expires_at: None
+        }
+    );
+}
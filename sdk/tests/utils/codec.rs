@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    types::block::{
+        payload::Payload,
+        protocol::protocol_parameters,
+        rand::{block::rand_block, payload::rand_tagged_data_payload},
+    },
+    utils::codec::{block_to_json, json_to_block, payload_hex_roundtrip, Error},
+};
+use packable::PackableExt;
+
+#[test]
+fn block_roundtrip() {
+    let protocol_parameters = protocol_parameters();
+    let bytes = rand_block().pack_to_vec();
+
+    let json = block_to_json(&bytes, &protocol_parameters).unwrap();
+
+    assert_eq!(json_to_block(&json, &protocol_parameters).unwrap(), bytes);
+}
+
+#[test]
+fn block_trailing_bytes() {
+    let protocol_parameters = protocol_parameters();
+    let mut bytes = rand_block().pack_to_vec();
+    bytes.push(0);
+
+    assert!(matches!(
+        block_to_json(&bytes, &protocol_parameters),
+        Err(Error::Unpack(_))
+    ));
+}
+
+#[test]
+fn block_json_other_protocol_version() {
+    let protocol_parameters = protocol_parameters();
+    let bytes = rand_block().pack_to_vec();
+    let mut json =
+        serde_json::from_str::<serde_json::Value>(&block_to_json(&bytes, &protocol_parameters).unwrap()).unwrap();
+    json["protocolVersion"] = serde_json::json!(protocol_parameters.protocol_version() + 1);
+
+    assert!(json_to_block(&json.to_string(), &protocol_parameters).is_err());
+}
+
+#[test]
+fn payload_roundtrip() {
+    let protocol_parameters = protocol_parameters();
+    let payload = Payload::from(rand_tagged_data_payload());
+    let hex = prefix_hex::encode(payload.pack_to_vec());
+
+    let json = payload_hex_roundtrip(&hex, &protocol_parameters).unwrap();
+    let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+
+    assert_eq!(value["type"], 5);
+    assert!(matches!(
+        payload_hex_roundtrip("0x05", &protocol_parameters),
+        Err(Error::Unpack(_))
+    ));
+}
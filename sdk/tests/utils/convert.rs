@@ -0,0 +1,103 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::NetworkInfo,
+    types::block::{
+        address::{AliasAddress, Bech32Address, Ed25519Address, Hrp},
+        output::AliasId,
+        protocol::protocol_parameters,
+    },
+    utils::convert::{
+        alias_id_to_did, bech32_to_hex, convert_units, did_to_alias_id, format_amount, hex_to_bech32, parse_amount,
+        Error, IotaUnit,
+    },
+};
+
+const ED25519_ADDRESS: &str = "0xebe40a263480190dcd7939447ee01aefa73d6f3cc33c90ef7bf905abf8728655";
+const ED25519_BECH32: &str = "rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt";
+const ALIAS_ID: &str = "0x1505ec099896ab05d9e08fbc7101ae4dff0093b3943b28f789ed2ca728bcc8d6";
+
+#[test]
+fn units() {
+    assert_eq!(convert_units(1, IotaUnit::Mi, IotaUnit::I).unwrap(), 1_000_000);
+    assert_eq!(convert_units(2_000_000_000, IotaUnit::I, IotaUnit::Gi).unwrap(), 2);
+    assert!(matches!(
+        convert_units(1_500_000, IotaUnit::I, IotaUnit::Gi),
+        Err(Error::PrecisionLoss { .. })
+    ));
+    assert!(matches!(
+        convert_units(u64::MAX, IotaUnit::Ki, IotaUnit::I),
+        Err(Error::AmountOverflow)
+    ));
+
+    assert_eq!(parse_amount("1.5 Mi").unwrap(), 1_500_000);
+    assert_eq!(parse_amount("42").unwrap(), 42);
+    assert_eq!(parse_amount(".25Gi").unwrap(), 250_000_000);
+    assert!(parse_amount("1.5 i").is_err());
+    assert!(parse_amount("1.0000001 Mi").is_err());
+    assert!(parse_amount("1 Xi").is_err());
+    assert!(matches!(parse_amount("20000 Pi"), Err(Error::AmountOverflow)));
+
+    assert_eq!(format_amount(1_500_000, IotaUnit::Mi), "1.5 Mi");
+    assert_eq!(format_amount(1_000_000, IotaUnit::Mi), "1 Mi");
+    assert_eq!(format_amount(1_000_001, IotaUnit::Gi), "0.001000001 Gi");
+}
+
+#[test]
+fn addresses() {
+    let network_info = NetworkInfo {
+        protocol_parameters: protocol_parameters(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        bech32_to_hex(ED25519_BECH32, &network_info).unwrap(),
+        (Ed25519Address::KIND, ED25519_ADDRESS.to_string())
+    );
+    assert_eq!(
+        hex_to_bech32(Ed25519Address::KIND, ED25519_ADDRESS, &network_info).unwrap(),
+        ED25519_BECH32
+    );
+    assert!(matches!(
+        hex_to_bech32(2, ED25519_ADDRESS, &network_info),
+        Err(Error::InvalidAddressKind(2))
+    ));
+
+    let alias_bech32 = hex_to_bech32(AliasAddress::KIND, ALIAS_ID, &network_info).unwrap();
+    assert_eq!(
+        bech32_to_hex(&alias_bech32.to_string(), &network_info).unwrap(),
+        (AliasAddress::KIND, ALIAS_ID.to_string())
+    );
+
+    let smr_address = Bech32Address::new(
+        Hrp::from_str_unchecked("smr"),
+        ED25519_ADDRESS.parse::<Ed25519Address>().unwrap(),
+    );
+    assert!(matches!(
+        bech32_to_hex(&smr_address.to_string(), &network_info),
+        Err(Error::HrpMismatch { .. })
+    ));
+}
+
+#[test]
+fn did() {
+    let alias_id = ALIAS_ID.parse::<AliasId>().unwrap();
+
+    let did = alias_id_to_did(&alias_id, &Hrp::from_str_unchecked("smr"));
+    assert_eq!(did, format!("did:iota:smr:{ALIAS_ID}"));
+    assert_eq!(
+        did_to_alias_id(&did).unwrap(),
+        (Hrp::from_str_unchecked("smr"), alias_id)
+    );
+
+    let did = alias_id_to_did(&alias_id, &Hrp::from_str_unchecked("iota"));
+    assert_eq!(did, format!("did:iota:{ALIAS_ID}"));
+    assert_eq!(
+        did_to_alias_id(&did).unwrap(),
+        (Hrp::from_str_unchecked("iota"), alias_id)
+    );
+
+    assert!(did_to_alias_id("did:web:example.com").is_err());
+    assert!(did_to_alias_id("did:iota:smr:0x1234").is_err());
+}
@@ -0,0 +1,57 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip39::{Mnemonic, Passphrase};
+use iota_sdk::{
+    client::Error,
+    utils::mnemonic::{generate_mnemonic, mnemonic_to_seed, verify_mnemonic, MnemonicLanguage, MNEMONIC_WORD_COUNTS},
+};
+
+// Test vector of BIP39, with the passphrase "TREZOR"
+const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+const SEED: &str = "0xc55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+
+#[test]
+fn generate_word_counts() {
+    for word_count in MNEMONIC_WORD_COUNTS {
+        let mnemonic = generate_mnemonic(word_count, MnemonicLanguage::English).unwrap();
+        assert_eq!(mnemonic.to_string().split_whitespace().count(), word_count);
+        verify_mnemonic(mnemonic, MnemonicLanguage::English).unwrap();
+    }
+
+    assert!(matches!(
+        generate_mnemonic(13, MnemonicLanguage::English),
+        Err(Error::InvalidMnemonic(_))
+    ));
+}
+
+#[test]
+fn languages() {
+    let mnemonic = generate_mnemonic(12, MnemonicLanguage::Japanese).unwrap();
+    verify_mnemonic(&*mnemonic, MnemonicLanguage::Japanese).unwrap();
+    assert!(verify_mnemonic(mnemonic, MnemonicLanguage::English).is_err());
+}
+
+#[test]
+fn checksum() {
+    let mnemonic = Mnemonic::from(MNEMONIC.replace("about", "abandon"));
+    assert!(matches!(
+        verify_mnemonic(mnemonic, MnemonicLanguage::English),
+        Err(Error::InvalidMnemonic(_))
+    ));
+}
+
+#[test]
+fn seed_with_passphrase() {
+    let passphrase = Passphrase::from("TREZOR".to_owned());
+    let seed = mnemonic_to_seed(
+        Mnemonic::from(MNEMONIC.to_owned()),
+        MnemonicLanguage::English,
+        Some(&passphrase),
+    )
+    .unwrap();
+    assert_eq!(prefix_hex::encode(seed.as_ref()), SEED);
+
+    let seed = mnemonic_to_seed(Mnemonic::from(MNEMONIC.to_owned()), MnemonicLanguage::English, None).unwrap();
+    assert_ne!(prefix_hex::encode(seed.as_ref()), SEED);
+}
@@ -1,4 +1,12 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "client")]
+mod codec;
+#[cfg(feature = "client")]
+mod convert;
+#[cfg(feature = "client")]
+mod mnemonic;
+#[cfg(feature = "client")]
+mod parse;
 mod serde;
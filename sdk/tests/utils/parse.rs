@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::{
+        address::{Bech32Address, Hrp},
+        BlockId,
+    },
+    utils::parse::{InputParser, ParseError, ParseMode},
+};
+
+const BLOCK_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+const ED25519_ADDRESS: &str = "0x7ffec9e1233204d9c6dce6812b1539ee96af691ca2e4d9065daa85907d33e5d3";
+const BECH32_ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+#[test]
+fn lenient_hex() {
+    let parser = InputParser::new(ParseMode::Lenient);
+    let block_id = BlockId::from_str(BLOCK_ID).unwrap();
+
+    assert_eq!(parser.parse_id::<BlockId>(BLOCK_ID).unwrap(), block_id);
+    assert_eq!(parser.parse_id::<BlockId>(&BLOCK_ID[2..]).unwrap(), block_id);
+    assert_eq!(
+        parser
+            .parse_id::<BlockId>(&format!(" {} ", BLOCK_ID.to_uppercase()))
+            .unwrap(),
+        block_id
+    );
+    assert_eq!(
+        parser.parse_id::<BlockId>("0x52fd-c07"),
+        Err(ParseError::InvalidHexCharacter {
+            input: "0x52fd-c07".to_owned(),
+            character: '-',
+            position: 6,
+        })
+    );
+    assert!(matches!(
+        parser.parse_id::<BlockId>("0x52fd"),
+        Err(ParseError::Invalid { .. })
+    ));
+}
+
+#[test]
+fn strict_hex() {
+    let parser = InputParser::new(ParseMode::Strict);
+
+    assert!(parser.parse_id::<BlockId>(BLOCK_ID).is_ok());
+    assert!(matches!(
+        parser.parse_id::<BlockId>(&BLOCK_ID[2..]),
+        Err(ParseError::MissingHexPrefix { .. })
+    ));
+    assert!(matches!(
+        parser.parse_id::<BlockId>(&format!("{BLOCK_ID} ")),
+        Err(ParseError::Whitespace { .. })
+    ));
+    assert!(matches!(
+        parser.parse_id::<BlockId>(&format!("0x{}", BLOCK_ID[2..].to_uppercase())),
+        Err(ParseError::Uppercase { .. })
+    ));
+}
+
+#[test]
+fn addresses() {
+    let rms = Hrp::from_str_unchecked("rms");
+    let lenient = InputParser::new(ParseMode::Lenient).with_bech32_hrp(rms);
+    let strict = InputParser::new(ParseMode::Strict).with_bech32_hrp(rms);
+    let address = Bech32Address::try_from_str(BECH32_ADDRESS).unwrap();
+
+    assert_eq!(lenient.parse_address(BECH32_ADDRESS).unwrap(), address);
+    assert_eq!(lenient.parse_address(&BECH32_ADDRESS.to_uppercase()).unwrap(), address);
+    assert_eq!(lenient.parse_address(ED25519_ADDRESS).unwrap(), address);
+    assert_eq!(lenient.parse_address(&ED25519_ADDRESS[2..]).unwrap(), address);
+    assert!(matches!(
+        InputParser::new(ParseMode::Lenient).parse_address(ED25519_ADDRESS),
+        Err(ParseError::MissingBech32Hrp { .. })
+    ));
+
+    assert_eq!(strict.parse_address(BECH32_ADDRESS).unwrap(), address);
+    assert!(matches!(
+        strict.parse_address(ED25519_ADDRESS),
+        Err(ParseError::HexAddress { .. })
+    ));
+    assert!(matches!(
+        strict.parse_address(&BECH32_ADDRESS.to_uppercase()),
+        Err(ParseError::Uppercase { .. })
+    ));
+
+    let smr = InputParser::new(ParseMode::Lenient).with_bech32_hrp(Hrp::from_str_unchecked("smr"));
+    assert_eq!(
+        smr.parse_address(BECH32_ADDRESS),
+        Err(ParseError::Bech32HrpMismatch {
+            input: BECH32_ADDRESS.to_owned(),
+            provided: "rms".to_owned(),
+            expected: "smr".to_owned(),
+        })
+    );
+}
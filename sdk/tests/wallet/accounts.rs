@@ -1,7 +1,13 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Result;
+use iota_sdk::wallet::{Error, RemoveAccountOptions, Result};
+#[cfg(feature = "storage")]
+use iota_sdk::{
+    client::ClientBuilder,
+    types::block::{output::RentStructure, protocol::ProtocolParameters},
+    wallet::account::AccountFilterOptions,
+};
 #[cfg(feature = "stronghold")]
 use {
     iota_sdk::client::{
@@ -11,7 +17,7 @@ use {
     iota_sdk::wallet::{ClientOptions, Wallet},
 };
 
-use crate::wallet::common::{make_wallet, setup, tear_down};
+use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[tokio::test]
 async fn account_ordering() -> Result<()> {
@@ -103,6 +109,76 @@ async fn remove_latest_account() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn remove_account_keeps_indexes() -> Result<()> {
+    let storage_path = "test-storage/remove_account_keeps_indexes";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+
+        for _ in 0..3 {
+            wallet.create_account().finish().await?;
+        }
+        let options = RemoveAccountOptions {
+            force: true,
+            ..Default::default()
+        };
+        wallet.remove_account(1, options).await?;
+        assert!(matches!(wallet.get_account(1).await, Err(Error::AccountNotFound(_))));
+
+        // The index of the removed account isn't reused
+        let new_account = wallet.create_account().finish().await?;
+        assert_eq!(*new_account.details().await.index(), 3);
+        assert_eq!(new_account.alias().await, "3");
+    }
+
+    // Restore dropped `Wallet` from above.
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let mut indexes = Vec::new();
+    for account in wallet.get_accounts().await? {
+        indexes.push(*account.details().await.index());
+    }
+    assert_eq!(indexes, [0, 2, 3]);
+    assert_eq!(*wallet.create_account().finish().await?.details().await.index(), 4);
+    #[cfg(debug_assertions)]
+    wallet.verify_integrity().await?;
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn remove_account_with_funds() -> Result<()> {
+    let storage_path = "test-storage/remove_account_with_funds";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_index = *account.details().await.index();
+
+    // Funds could only be accessed again by recreating the account
+    assert!(matches!(
+        wallet.remove_account(account_index, None).await,
+        Err(Error::AccountNotRemovable {
+            reason: "it holds funds",
+            ..
+        })
+    ));
+    assert!(wallet.get_account(account_index).await.is_ok());
+
+    let options = RemoveAccountOptions {
+        force: true,
+        ..Default::default()
+    };
+    wallet.remove_account(account_index, options).await?;
+    assert!(wallet.get_accounts().await?.is_empty());
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_alias_already_exists() -> Result<()> {
     let storage_path = "test-storage/account_alias_already_exists";
@@ -139,6 +215,142 @@ async fn account_rename_alias() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn account_metadata_and_archiving() -> Result<()> {
+    let storage_path = "test-storage/account_metadata_and_archiving";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let alice = wallet.create_account().with_alias("Alice").finish().await?;
+    wallet.create_account().with_alias("Bob").finish().await?;
+
+    alice.set_metadata("purpose", "savings").await?;
+    alice.set_metadata("color", "blue").await?;
+    assert_eq!(alice.remove_metadata("color").await?, Some("blue".to_string()));
+    alice.set_archived(true).await?;
+
+    // Archived accounts are hidden by default, but still returned by `get_accounts()`
+    assert_eq!(wallet.filter_accounts(AccountFilterOptions::default()).await?.len(), 1);
+    assert_eq!(wallet.get_accounts().await?.len(), 2);
+
+    // Metadata and the archived state are persisted
+    drop(alice);
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = wallet
+        .filter_accounts(AccountFilterOptions {
+            include_archived: true,
+            metadata: [("purpose".to_string(), "savings".to_string())].into(),
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(accounts.len(), 1);
+    let alice = accounts[0].details().await;
+    assert_eq!(alice.alias(), "Alice");
+    assert!(*alice.archived());
+    assert_eq!(alice.metadata().len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn address_label_and_freezing() -> Result<()> {
+    let storage_path = "test-storage/address_label_and_freezing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice").finish().await?;
+    let address = account.addresses().await?[0].clone().into_bech32();
+
+    account
+        .set_address_label(address, "deposit of customer 42".to_string())
+        .await?;
+    account.set_address_frozen(address, true).await?;
+    // Only addresses of the account can be labeled
+    assert!(
+        account
+            .set_address_frozen("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy", true)
+            .await
+            .is_err()
+    );
+
+    // The label and the frozen state are persisted
+    drop(account);
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account("Alice").await?;
+    let account_address = account.addresses().await?[0].clone();
+    assert_eq!(account_address.label().as_deref(), Some("deposit of customer 42"));
+    assert!(*account_address.frozen());
+
+    account.set_address_frozen(address, false).await?;
+    account.set_address_label(address, None).await?;
+    let account_address = account.addresses().await?[0].clone();
+    assert_eq!(account_address.label(), &None);
+    assert!(!*account_address.frozen());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn account_bound_to_another_network() -> Result<()> {
+    let storage_path = "test-storage/account_bound_to_another_network";
+    setup(storage_path)?;
+
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("private-tangle"),
+        "tst",
+        0,
+        15,
+        RentStructure::new(250, 10, 1),
+        2_779_530_283_277_761,
+    )?;
+    let client_options = ClientBuilder::new()
+        .with_node("http://localhost:14265")?
+        .with_protocol_parameters(protocol_parameters)
+        .with_offline_mode();
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let alice = wallet.create_account().with_alias("Alice").finish().await?;
+    let bob = wallet
+        .create_account()
+        .with_alias("Bob")
+        .with_client_options(client_options.clone())
+        .finish()
+        .await?;
+
+    assert!(alice.uses_wallet_client());
+    assert!(!bob.uses_wallet_client());
+    assert_eq!(bob.client().get_bech32_hrp().await?, "tst");
+    // The addresses of the bound account get the HRP of its network
+    assert_eq!(bob.addresses().await?[0].address().hrp(), "tst");
+    assert_ne!(alice.addresses().await?[0].address().hrp(), "tst");
+
+    // Only bound accounts have their own client options
+    assert!(matches!(
+        alice.set_client_options(client_options.clone()).await,
+        Err(Error::AccountUsesWalletClient(0))
+    ));
+    bob.set_client_options(client_options).await?;
+
+    // The binding is persisted
+    drop(alice);
+    drop(bob);
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let bob = wallet.get_account("Bob").await?;
+    assert!(!bob.uses_wallet_client());
+    assert!(bob.details().await.client_options().is_some());
+    assert_eq!(bob.addresses().await?[0].address().hrp(), "tst");
+    assert!(wallet.get_account("Alice").await?.uses_wallet_client());
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_first_address_exists() -> Result<()> {
     let storage_path = "test-storage/account_first_address_exists";
@@ -96,6 +96,8 @@ async fn balance_expiration() -> Result<()> {
     let account_2 = wallet.create_account().finish().await?;
 
     let seconds_until_expired = 20;
+    // Current time + 20s
+    let expiration_time = account_0.client().get_time_checked().await? + seconds_until_expired;
     let token_supply = account_0.client().get_token_supply().await?;
     let outputs = [BasicOutputBuilder::new_with_amount(1_000_000)
         // Send to account 1 with expiration to account 2, both have no amount yet
@@ -105,8 +107,7 @@ async fn balance_expiration() -> Result<()> {
             )),
             UnlockCondition::Expiration(ExpirationUnlockCondition::new(
                 *account_2.addresses().await?[0].address().as_ref(),
-                // Current time + 20s
-                account_0.client().get_time_checked().await? + seconds_until_expired,
+                expiration_time,
             )?),
         ])
         .with_features([SenderFeature::new(*account_0.addresses().await?[0].address().as_ref())])
@@ -131,12 +132,17 @@ async fn balance_expiration() -> Result<()> {
     assert_eq!(balance.potentially_locked_outputs().len(), 1);
     assert_eq!(balance.base_coin().total(), 0);
     assert_eq!(balance.base_coin().available(), 0);
+    assert_eq!(balance.base_coin().expiring_soon(), 1_000_000);
+    assert_eq!(balance.time_bound_amounts().len(), 1);
+    assert_eq!(balance.available_at(expiration_time + 1), 0);
 
     // Account 2 balance before expiration
     let balance = account_2.sync(None).await?;
     assert_eq!(balance.potentially_locked_outputs().len(), 1);
     assert_eq!(balance.base_coin().total(), 0);
     assert_eq!(balance.base_coin().available(), 0);
+    assert_eq!(balance.base_coin().timelocked(), 1_000_000);
+    assert_eq!(balance.available_at(expiration_time + 1), 1_000_000);
 
     // Wait until expired
     tokio::time::sleep(std::time::Duration::from_secs(seconds_until_expired.into())).await;
@@ -277,3 +283,42 @@ async fn balance_voting_power() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn balance_at() -> Result<()> {
+    let storage_path = "test-storage/balance_at";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let balance = account.sync(None).await?;
+    let milestone_index = account
+        .client()
+        .get_info()
+        .await?
+        .node_info
+        .status
+        .confirmed_milestone
+        .index;
+
+    // Nothing changed since the funds were received
+    let historical_balance = account.balance_at(milestone_index).await?;
+    assert_eq!(historical_balance.milestone_index(), milestone_index);
+    assert_eq!(historical_balance.base_coin(), balance.base_coin().total());
+
+    // Sending to the account itself keeps the total, but spends the outputs
+    let outputs_before = historical_balance.outputs().clone();
+    let address = account.addresses().await?[0].clone().into_bech32();
+    let tx = account.send(1_000_000, address, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let historical_balance = account.balance_at(milestone_index).await?;
+    assert_eq!(historical_balance.base_coin(), balance.base_coin().total());
+    assert_eq!(historical_balance.outputs(), &outputs_before);
+
+    tear_down(storage_path)
+}
@@ -11,6 +11,7 @@ use iota_sdk::{
 };
 use iota_sdk::{
     client::{
+        api::GetAddressesOptions,
         constants::IOTA_COIN_TYPE,
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
     },
@@ -123,6 +124,65 @@ async fn changed_coin_type() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn custom_derivation_path() -> Result<()> {
+    let storage_path = "test-storage/custom_derivation_path";
+    setup(storage_path)?;
+
+    let mnemonic = Mnemonic::from(DEFAULT_MNEMONIC.to_owned());
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), None).await?;
+    let _account = wallet.create_account().with_alias("Alice").finish().await?;
+
+    let account = wallet
+        .create_account()
+        .with_alias("Bob")
+        .with_coin_type(IOTA_COIN_TYPE)
+        .with_bip44_account_index(0)
+        .finish()
+        .await?;
+    let first_address = *account.addresses().await?[0].address();
+    let details = account.details().await;
+    assert_eq!(*details.index(), 1);
+    assert_eq!(*details.coin_type(), IOTA_COIN_TYPE);
+    assert_eq!(*details.bip44_account_index(), 0);
+    drop(details);
+
+    let expected_address = SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?)
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(IOTA_COIN_TYPE)
+                .with_bech32_hrp(first_address.hrp)
+                .with_range(0..1),
+        )
+        .await?;
+    assert_eq!(first_address, expected_address[0]);
+
+    // The same path can't be used by two accounts
+    assert!(matches!(
+        wallet
+            .create_account()
+            .with_alias("Carol")
+            .with_coin_type(IOTA_COIN_TYPE)
+            .with_bip44_account_index(0)
+            .finish()
+            .await,
+        Err(Error::InvalidDerivationPath(_))
+    ));
+    // Segments are hardened, so they can't be hardened already
+    assert!(matches!(
+        wallet
+            .create_account()
+            .with_alias("Carol")
+            .with_coin_type(1 << 31)
+            .finish()
+            .await,
+        Err(Error::InvalidDerivationPath(_))
+    ));
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn shimmer_coin_type() -> Result<()> {
     let storage_path = "test-storage/shimmer_coin_type";
@@ -181,10 +241,7 @@ async fn update_node_auth() -> Result<()> {
 
     let wallet = make_wallet(storage_path, None, Some(NODE_OTHER)).await?;
 
-    let node_auth = iota_sdk::client::node_manager::node::NodeAuth {
-        jwt: Some("jwt".to_string()),
-        basic_auth_name_pwd: None,
-    };
+    let node_auth = iota_sdk::client::node_manager::node::NodeAuth::new().with_jwt("jwt");
     wallet
         .update_node_auth(Url::parse(NODE_OTHER).unwrap(), Some(node_auth.clone()))
         .await?;
@@ -9,13 +9,16 @@ use iota_sdk::{
         output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
         payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
         protocol::protocol_parameters,
-        rand::output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
+        rand::{
+            block::rand_block_id,
+            output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
+        },
     },
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AddressData, NewOutputEvent, ReorgEvent, SpentOutputEvent, TransactionBlockEvent,
+            TransactionInclusionEvent, TransactionProgressEvent, WalletEvent,
         },
     },
 };
@@ -58,7 +61,13 @@ fn wallet_events_serde() {
     })));
 
     assert_serde_eq(WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
-        output: output_data_dto,
+        output: output_data_dto.clone(),
+    })));
+
+    assert_serde_eq(WalletEvent::Reorg(Box::new(ReorgEvent {
+        transaction_id: TransactionId::null(),
+        removed_outputs: vec![output_data_dto.clone()],
+        restored_outputs: vec![output_data_dto],
     })));
 
     assert_serde_eq(WalletEvent::TransactionInclusion(TransactionInclusionEvent {
@@ -121,4 +130,17 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    let transaction_block_event = TransactionBlockEvent {
+        transaction_id: TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap()),
+        block_id: rand_block_id(),
+    };
+
+    assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Reattached(
+        transaction_block_event,
+    )));
+
+    assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Promoted(
+        transaction_block_event,
+    )));
 }
@@ -0,0 +1,62 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::Client,
+    wallet::{Error, Result},
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[ignore]
+#[tokio::test]
+async fn export_import_interchange() -> Result<()> {
+    let storage_path = "test-storage/export_import_interchange";
+    setup(storage_path)?;
+    let import_storage_path = "test-storage/export_import_interchange_import";
+    setup(import_storage_path)?;
+    let file_path = format!("{storage_path}/wallet.iwif");
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), None).await?;
+    let alice = wallet.create_account().with_alias("Alice").finish().await?;
+    alice.generate_ed25519_addresses(2, None).await?;
+    alice.set_metadata("purpose", "savings").await?;
+    wallet.create_account().with_alias("Bob").finish().await?;
+    wallet.export_interchange(&file_path).await?;
+
+    let imported_wallet = make_wallet(import_storage_path, Some(mnemonic), None).await?;
+    imported_wallet.import_interchange(&file_path).await?;
+    let accounts = imported_wallet.get_accounts().await?;
+    assert_eq!(accounts.len(), 2);
+    let imported_alice = accounts[0].details().await;
+    assert_eq!(imported_alice.alias(), "Alice");
+    assert_eq!(
+        imported_alice.public_addresses(),
+        alice.details().await.public_addresses()
+    );
+    assert_eq!(
+        imported_alice.metadata().get("purpose").map(String::as_str),
+        Some("savings")
+    );
+    drop(imported_alice);
+
+    // Accounts can only be imported into a wallet without accounts
+    assert!(matches!(
+        imported_wallet.import_interchange(&file_path).await,
+        Err(Error::InvalidInterchange(_))
+    ));
+
+    // Accounts of another seed are rejected
+    let other_storage_path = "test-storage/export_import_interchange_other";
+    setup(other_storage_path)?;
+    let other_wallet = make_wallet(other_storage_path, None, None).await?;
+    assert!(matches!(
+        other_wallet.import_interchange(&file_path).await,
+        Err(Error::InvalidMnemonic(_))
+    ));
+
+    tear_down(other_storage_path)?;
+    tear_down(import_storage_path)?;
+    tear_down(storage_path)
+}
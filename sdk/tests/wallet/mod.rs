@@ -19,10 +19,17 @@ mod core;
 mod error;
 #[cfg(feature = "events")]
 mod events;
+#[cfg(feature = "storage")]
+mod interchange;
 #[cfg(feature = "stronghold")]
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
 mod output_preparation;
+mod ownership_proof;
+mod public_account;
+mod snapshot;
+#[cfg(feature = "sqlite")]
+mod sqlite_storage;
 mod syncing;
 mod transactions;
 #[cfg(not(target_os = "windows"))]
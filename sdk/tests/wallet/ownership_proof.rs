@@ -0,0 +1,67 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{SecretManage, SecretManager},
+        Client,
+    },
+    types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutput},
+        protocol::protocol_parameters,
+        rand::{address::rand_ed25519_address, output::rand_output_id},
+    },
+    wallet::{
+        account::{OwnershipProof, OwnershipProofDto},
+        Result,
+    },
+};
+
+#[tokio::test]
+async fn verify_ownership_proof() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let address = secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::default()
+                .with_coin_type(SHIMMER_COIN_TYPE)
+                .with_range(0..1),
+        )
+        .await?[0];
+
+    let output_id = rand_output_id();
+    let output = BasicOutput::build_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(protocol_parameters().token_supply())?;
+    let challenge = b"reserves 2023-07-31";
+
+    let proof = OwnershipProof {
+        output_id,
+        signature: secret_manager
+            .sign_ed25519(
+                &OwnershipProof::statement(&output_id, challenge),
+                Bip44::new(SHIMMER_COIN_TYPE),
+            )
+            .await?,
+    };
+
+    proof.verify(challenge, &output, 0)?;
+    assert!(proof.verify(b"another challenge", &output, 0).is_err());
+
+    let other_output = BasicOutput::build_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_ed25519_address()))
+        .finish_output(protocol_parameters().token_supply())?;
+    assert!(proof.verify(challenge, &other_output, 0).is_err());
+
+    let dto = OwnershipProofDto::from(&proof);
+    assert_eq!(
+        OwnershipProof::try_from(serde_json::from_str::<OwnershipProofDto>(&serde_json::to_string(
+            &dto
+        )?)?)?,
+        proof
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
+        Client,
+    },
+    types::block::{
+        address::{Address, ToBech32Ext},
+        rand::address::rand_ed25519_address,
+    },
+    wallet::{
+        account::{AddressDerivationProof, PublicAccount, PublicAccountDto},
+        Error, Result,
+    },
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn verify_public_account() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    let address = Address::from(
+        secret_manager
+            .generate_ed25519_addresses(
+                GetAddressesOptions::default()
+                    .with_coin_type(SHIMMER_COIN_TYPE)
+                    .with_range(1..2)
+                    .with_options(GenerateAddressOptions::internal()),
+            )
+            .await?[0],
+    );
+
+    let signature = secret_manager
+        .sign_ed25519(
+            &AddressDerivationProof::statement(SHIMMER_COIN_TYPE, 0, true, 1, &address),
+            Bip44::new(SHIMMER_COIN_TYPE).with_change(1).with_address_index(1),
+        )
+        .await?;
+    let public_account = PublicAccount {
+        coin_type: SHIMMER_COIN_TYPE,
+        account_index: 0,
+        addresses: vec![AddressDerivationProof {
+            address: address.to_bech32_unchecked("smr"),
+            key_index: 1,
+            internal: true,
+            signature,
+        }],
+    };
+
+    public_account.verify()?;
+    assert!(public_account.derivation_proof(&address).is_some());
+    assert!(
+        public_account
+            .derivation_proof(&rand_ed25519_address().into())
+            .is_none()
+    );
+
+    // The proof is only valid for the path it was signed for
+    let mut other_path = public_account.clone();
+    other_path.account_index = 1;
+    assert!(other_path.verify().is_err());
+    other_path = public_account.clone();
+    other_path.addresses[0].internal = false;
+    assert!(other_path.verify().is_err());
+
+    let dto = PublicAccountDto::from(&public_account);
+    assert_eq!(
+        PublicAccount::try_from(serde_json::from_str::<PublicAccountDto>(&serde_json::to_string(&dto)?)?)?,
+        public_account
+    );
+
+    Ok(())
+}
+
+#[ignore]
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    let storage_path_0 = "test-storage/watch_only_account_0";
+    let storage_path_1 = "test-storage/watch_only_account_1";
+    setup(storage_path_0)?;
+    setup(storage_path_1)?;
+
+    let wallet_0 = make_wallet(storage_path_0, None, None).await?;
+    let account = wallet_0.create_account().finish().await?;
+    account.generate_ed25519_addresses(1, None).await?;
+    let public_account = account.export_public_account().await?;
+
+    // The watch-only account is from another seed, so it doesn't prevent creating accounts afterwards
+    let wallet_1 = make_wallet(storage_path_1, None, None).await?;
+    let watch_only = wallet_1
+        .create_watch_only_account(public_account.clone(), "treasury".to_string())
+        .await?;
+    wallet_1.create_account().finish().await?;
+    assert!(matches!(
+        wallet_1.create_watch_only_account(public_account, None).await,
+        Err(Error::InvalidDerivationPath(_))
+    ));
+
+    assert!(*watch_only.details().await.watch_only());
+    assert_eq!(watch_only.addresses().await?, account.addresses().await?);
+    watch_only.sync(None).await?;
+    watch_only.balance().await?;
+
+    assert!(matches!(
+        watch_only.generate_ed25519_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(alias)) if alias == "treasury"
+    ));
+    assert!(matches!(
+        watch_only.export_public_account().await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+
+    tear_down(storage_path_0)?;
+    tear_down(storage_path_1)
+}
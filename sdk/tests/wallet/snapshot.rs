@@ -0,0 +1,215 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId, OutputMetadata, OutputWithMetadata,
+        },
+        payload::milestone::MilestoneId,
+        protocol::protocol_parameters,
+        rand::{
+            address::rand_ed25519_address, block::rand_block_id, milestone::rand_milestone_id, output::rand_output_id,
+        },
+    },
+    wallet::snapshot::{LedgerSnapshot, MilestoneDiff, SnapshotHeader, SnapshotKind},
+};
+use packable::PackableExt;
+
+fn basic_output(address: Ed25519Address) -> Output {
+    BasicOutput::build_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+fn header(kind: u8, target_milestone_id: MilestoneId) -> Vec<u8> {
+    let mut bytes = vec![2, kind];
+    // genesis milestone index, target milestone index and timestamp
+    bytes.extend(0u32.to_le_bytes());
+    bytes.extend(100u32.to_le_bytes());
+    bytes.extend(1_690_000_000u32.to_le_bytes());
+    bytes.extend(*target_milestone_id);
+    bytes
+}
+
+fn output_entry(output_id: OutputId, output: &Output) -> Vec<u8> {
+    let mut bytes = output_id.pack_to_vec();
+    bytes.extend(*rand_block_id());
+    bytes.extend(90u32.to_le_bytes());
+    bytes.extend(1_689_999_000u32.to_le_bytes());
+    let output_bytes = output.pack_to_vec();
+    bytes.extend((output_bytes.len() as u32).to_le_bytes());
+    bytes.extend(output_bytes);
+    bytes
+}
+
+#[test]
+fn read_ledger_snapshot() {
+    let protocol_parameters = protocol_parameters();
+    let own_address = rand_ed25519_address();
+    let own_output = (rand_output_id(), basic_output(own_address));
+    let other_output = (rand_output_id(), basic_output(rand_ed25519_address()));
+    let full_target_milestone_id = rand_milestone_id();
+
+    let mut full = header(0, full_target_milestone_id);
+    // ledger milestone index, treasury output milestone id and amount
+    full.extend(100u32.to_le_bytes());
+    full.extend([0u8; 40]);
+    // empty protocol parameters milestone option
+    full.extend(0u16.to_le_bytes());
+    // output count, milestone diff count, SEP count
+    full.extend(2u64.to_le_bytes());
+    full.extend(0u32.to_le_bytes());
+    full.extend(0u16.to_le_bytes());
+    full.extend(output_entry(own_output.0, &own_output.1));
+    full.extend(output_entry(other_output.0, &other_output.1));
+
+    let filter = |output: &Output| {
+        output
+            .unlock_conditions()
+            .and_then(|u| u.address())
+            .map_or(false, |u| u.address() == &Address::from(own_address))
+    };
+
+    let full_snapshot = LedgerSnapshot::read(&mut full.as_slice(), &protocol_parameters, filter).unwrap();
+    assert_eq!(full_snapshot.header.kind, SnapshotKind::Full);
+    assert_eq!(full_snapshot.header.ledger_milestone_index, Some(100));
+    assert_eq!(full_snapshot.outputs.len(), 1);
+    assert_eq!(full_snapshot.outputs[0].output(), &own_output.1);
+    assert_eq!(full_snapshot.outputs[0].metadata().output_id(), &own_output.0);
+    assert!(!full_snapshot.outputs[0].metadata().is_spent());
+
+    let mut delta = header(1, rand_milestone_id());
+    delta.extend(*full_target_milestone_id);
+    // SEP file offset, milestone diff count, SEP count
+    delta.extend(0u64.to_le_bytes());
+    delta.extend(0u32.to_le_bytes());
+    delta.extend(0u16.to_le_bytes());
+
+    let delta_snapshot = LedgerSnapshot::read(&mut delta.as_slice(), &protocol_parameters, filter).unwrap();
+    assert_eq!(delta_snapshot.header.kind, SnapshotKind::Delta);
+    assert_eq!(
+        delta_snapshot.header.full_snapshot_target_milestone_id,
+        Some(full_target_milestone_id)
+    );
+    assert_eq!(full_snapshot.unspent_outputs(Some(&delta_snapshot)).len(), 1);
+
+    // Truncated files can't be read
+    assert!(LedgerSnapshot::read(&mut &full[..full.len() - 1], &protocol_parameters, filter).is_err());
+    // Unsupported versions are rejected
+    full[0] = 1;
+    assert!(LedgerSnapshot::read(&mut full.as_slice(), &protocol_parameters, filter).is_err());
+}
+
+fn output_with_metadata(output_id: OutputId, spent: bool) -> OutputWithMetadata {
+    OutputWithMetadata::new(
+        basic_output(rand_ed25519_address()),
+        OutputMetadata::new(
+            rand_block_id(),
+            output_id,
+            spent,
+            spent.then_some(101),
+            spent.then_some(1_690_000_010),
+            None,
+            90,
+            1_689_999_000,
+            101,
+        ),
+    )
+}
+
+fn snapshot_with_diffs(
+    kind: SnapshotKind,
+    outputs: Vec<OutputWithMetadata>,
+    diffs: Vec<MilestoneDiff>,
+) -> LedgerSnapshot {
+    LedgerSnapshot {
+        header: SnapshotHeader {
+            kind,
+            genesis_milestone_index: 0,
+            target_milestone_index: 100,
+            target_milestone_timestamp: 1_690_000_000,
+            target_milestone_id: rand_milestone_id(),
+            ledger_milestone_index: (kind == SnapshotKind::Full).then_some(101),
+            full_snapshot_target_milestone_id: None,
+        },
+        outputs,
+        milestone_diffs: diffs,
+    }
+}
+
+#[test]
+fn ledger_snapshot_milestone_diffs() {
+    let unspent = rand_output_id();
+    let created_after_target = rand_output_id();
+    let consumed_after_target = rand_output_id();
+    let created_in_delta = rand_output_id();
+
+    // The full snapshot has the ledger state of milestone 101, its diff leads back to the target milestone 100
+    let full_snapshot = snapshot_with_diffs(
+        SnapshotKind::Full,
+        vec![
+            output_with_metadata(unspent, false),
+            output_with_metadata(created_after_target, false),
+        ],
+        vec![MilestoneDiff {
+            milestone_index: 101,
+            milestone_timestamp: 1_690_000_010,
+            created: vec![output_with_metadata(created_after_target, false)],
+            consumed: vec![output_with_metadata(consumed_after_target, true)],
+        }],
+    );
+    let mut unspent_outputs = full_snapshot
+        .unspent_outputs(None)
+        .iter()
+        .map(|output| (*output.metadata().output_id(), output.metadata().is_spent()))
+        .collect::<Vec<_>>();
+    unspent_outputs.sort();
+    let mut expected = vec![(unspent, false), (consumed_after_target, false)];
+    expected.sort();
+    assert_eq!(unspent_outputs, expected);
+
+    // The diffs of a delta snapshot lead forward from the target milestone of the full snapshot
+    let delta_snapshot = snapshot_with_diffs(
+        SnapshotKind::Delta,
+        Vec::new(),
+        vec![MilestoneDiff {
+            milestone_index: 101,
+            milestone_timestamp: 1_690_000_010,
+            created: vec![output_with_metadata(created_in_delta, false)],
+            consumed: vec![output_with_metadata(unspent, true)],
+        }],
+    );
+    let mut unspent_outputs = full_snapshot
+        .unspent_outputs(Some(&delta_snapshot))
+        .iter()
+        .map(|output| *output.metadata().output_id())
+        .collect::<Vec<_>>();
+    unspent_outputs.sort();
+    let mut expected = vec![consumed_after_target, created_in_delta];
+    expected.sort();
+    assert_eq!(unspent_outputs, expected);
+}
+
+#[test]
+fn ledger_snapshot_untrusted_lengths() {
+    let protocol_parameters = protocol_parameters();
+    let mut full = header(0, rand_milestone_id());
+    full.extend(100u32.to_le_bytes());
+    full.extend([0u8; 40]);
+    full.extend(0u16.to_le_bytes());
+    // An output count and milestone diff count without entries
+    full.extend(u64::MAX.to_le_bytes());
+    full.extend(u32::MAX.to_le_bytes());
+    full.extend(0u16.to_le_bytes());
+    assert!(LedgerSnapshot::read(&mut full.as_slice(), &protocol_parameters, |_| true).is_err());
+
+    // An output length larger than a block
+    let mut entry = output_entry(rand_output_id(), &basic_output(rand_ed25519_address()));
+    let length_offset = OutputId::LENGTH + 32 + 8;
+    entry[length_offset..length_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    full.extend(entry);
+    assert!(LedgerSnapshot::read(&mut full.as_slice(), &protocol_parameters, |_| true).is_err());
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::storage::StorageAdapter,
+    wallet::{storage::adapter::sqlite::SqliteStorageAdapter, Result},
+};
+
+use crate::wallet::common::{setup, tear_down};
+
+#[tokio::test]
+async fn sqlite_get_set_delete() -> Result<()> {
+    let storage_path = "test-storage/sqlite_get_set_delete";
+    setup(storage_path)?;
+
+    let storage = SqliteStorageAdapter::new(format!("{storage_path}/wallet.sqlite"), None)?;
+    assert_eq!(storage.get_bytes("key").await?, None);
+
+    storage.set_bytes("key", b"value").await?;
+    assert_eq!(storage.get_bytes("key").await?, Some(b"value".to_vec()));
+
+    storage.set_bytes("key", b"other value").await?;
+    assert_eq!(storage.get_bytes("key").await?, Some(b"other value".to_vec()));
+
+    storage.delete("key").await?;
+    assert_eq!(storage.get_bytes("key").await?, None);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn sqlite_encrypted() -> Result<()> {
+    let storage_path = "test-storage/sqlite_encrypted";
+    setup(storage_path)?;
+    let db_path = format!("{storage_path}/wallet.sqlite");
+
+    let storage = SqliteStorageAdapter::new(&db_path, Some(&[1; 32]))?;
+    storage.set_bytes("key", b"value").await?;
+    drop(storage);
+
+    // The content can't be read without the key
+    assert!(!std::fs::read(&db_path)?.windows(5).any(|window| window == b"value"));
+    assert!(SqliteStorageAdapter::new(&db_path, Some(&[2; 32])).is_err());
+
+    let storage = SqliteStorageAdapter::new(&db_path, Some(&[1; 32]))?;
+    assert_eq!(storage.get_bytes("key").await?, Some(b"value".to_vec()));
+
+    tear_down(storage_path)
+}
@@ -45,6 +45,61 @@ async fn updated_default_sync_options() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+#[cfg(feature = "events")]
+async fn sync_all_reports_progress() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use iota_sdk::wallet::events::{
+        types::{SyncProgressEvent, WalletEvent},
+        WalletEventType,
+    };
+
+    let storage_path = "test-storage/sync_all_reports_progress";
+    setup(storage_path)?;
+
+    // No node is reachable, so syncing the accounts fails
+    let wallet = make_wallet(storage_path, None, Some("http://127.0.0.1:1")).await?;
+    // Nothing to sync
+    assert_eq!(wallet.sync_all(None).await?, Default::default());
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let received_events = events.clone();
+    wallet
+        .listen([WalletEventType::SyncProgress], move |event| {
+            if let WalletEvent::SyncProgress(progress) = &event.event {
+                received_events.lock().unwrap().push(progress.clone());
+            }
+        })
+        .await;
+
+    for _ in 0..3 {
+        wallet.create_account().finish().await?;
+    }
+    // Archived accounts aren't synced
+    wallet.get_account(1).await?.set_archived(true).await?;
+
+    assert!(wallet.sync_all(None).await.is_err());
+    // One event per account, even if it failed to sync
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            SyncProgressEvent {
+                synced_accounts: 0,
+                failed_accounts: 1,
+                total_accounts: 2,
+            },
+            SyncProgressEvent {
+                synced_accounts: 0,
+                failed_accounts: 2,
+                total_accounts: 2,
+            },
+        ]
+    );
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn sync_only_most_basic_outputs() -> Result<()> {
@@ -233,3 +288,54 @@ async fn background_syncing() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn sync_checkpoint() -> Result<()> {
+    let storage_path = "test-storage/sync_checkpoint";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let checkpoint = account_0.sync_checkpoint().await.unwrap();
+    let address = *account_0.addresses().await?[0].address();
+    assert!(checkpoint.addresses.contains_key(&address));
+
+    // Send to another account, so the next sync has to detect the spent output and the new remainder
+    let tx = account_0
+        .send(1_000_000, *account_1.addresses().await?[0].address(), None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    let full_sync_balance = account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            force_full_sync: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance, full_sync_balance);
+    assert!(account_0.sync_checkpoint().await.unwrap().milestone_index >= checkpoint.milestone_index);
+
+    drop(account_1);
+    drop(wallet);
+
+    // The checkpoint is persisted
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account_0 = wallet.get_account(0).await?;
+    assert!(account_0.sync_checkpoint().await.is_some());
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::{
+    types::block::payload::transaction::TransactionEssence,
+    wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams},
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -34,6 +37,38 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_all() -> Result<()> {
+    let storage_path = "test-storage/send_all";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let total = account_0.balance().await?.base_coin().available();
+    let tx = account_0
+        .send_all(*account_1.addresses().await?[0].address(), None)
+        .await?;
+
+    // Everything is sent, so there is no remainder
+    let TransactionEssence::Regular(essence) = tx.payload.essence();
+    assert_eq!(essence.outputs().len(), 1);
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 0);
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), total);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -115,6 +150,65 @@ async fn send_amount_custom_input() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_with_excluded_outputs() -> Result<()> {
+    let storage_path = "test-storage/send_with_excluded_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Send 2 outputs to account_1
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            vec![SendParams::new(amount, *account_1.addresses().await?[0].address())?; 2],
+            None,
+        )
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), 2 * amount);
+
+    // Send back without the excluded output
+    let unspent_outputs = account_1.unspent_outputs(None).await?;
+    let excluded_output = unspent_outputs[0].output_id;
+    let tx = account_1
+        .send_with_params(
+            [SendParams::new(amount, *account_0.addresses().await?[0].address())?],
+            Some(TransactionOptions {
+                excluded_outputs: Some(vec![excluded_output]),
+                ..Default::default()
+            }),
+        )
+        .await?;
+
+    assert_eq!(tx.inputs.len(), 1);
+    assert_ne!(tx.inputs.first().unwrap().metadata.output_id(), &excluded_output);
+
+    // An excluded output can't be a mandatory input
+    let result = account_1
+        .send_with_params(
+            [SendParams::new(amount, *account_0.addresses().await?[0].address())?],
+            Some(TransactionOptions {
+                mandatory_inputs: Some(vec![excluded_output]),
+                excluded_outputs: Some(vec![excluded_output]),
+                ..Default::default()
+            }),
+        )
+        .await;
+    assert!(matches!(result, Err(iota_sdk::wallet::Error::CustomInput(_))));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_nft() -> Result<()> {
@@ -184,6 +278,73 @@ async fn send_with_note() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_with_request_id() -> Result<()> {
+    let storage_path = "test-storage/send_with_request_id";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let params = [SendParams::new(1_000_000, *account_1.addresses().await?[0].address())?];
+    let options = TransactionOptions {
+        request_id: Some(String::from("send_with_request_id")),
+        ..Default::default()
+    };
+    // Only one transaction is created for concurrent sends with the same request ID
+    let (tx_0, tx_1) = tokio::try_join!(
+        account_0.send_with_params(params.clone(), options.clone()),
+        account_0.send_with_params(params.clone(), options.clone())
+    )?;
+    assert_eq!(tx_0.transaction_id, tx_1.transaction_id);
+    assert_eq!(account_0.pending_transactions().await.len(), 1);
+
+    let tx_2 = account_0.send_with_params(params, options).await?;
+    assert_eq!(tx_0.transaction_id, tx_2.transaction_id);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_batch_with_request_id() -> Result<()> {
+    let storage_path = "test-storage/send_batch_with_request_id";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // More params than fit into one transaction
+    let params = vec![SendParams::new(100_000, *account_1.addresses().await?[0].address())?; 130];
+    let options = TransactionOptions {
+        request_id: Some(String::from("send_batch_with_request_id")),
+        ..Default::default()
+    };
+    let report = account_0.send_batch(params, options).await?;
+    assert!(report.unsent.is_empty());
+    assert_eq!(report.transactions.len(), 2);
+    // Each batch is its own transaction
+    assert_ne!(
+        report.transactions[0].transaction_id,
+        report.transactions[1].transaction_id
+    );
+    assert_eq!(account_0.pending_transactions().await.len(), 2);
+    for (batch_index, transaction) in report.transactions.iter().enumerate() {
+        let journaled_transaction = account_0
+            .transaction_by_request_id(&format!("send_batch_with_request_id-{batch_index}"))
+            .await
+            .unwrap();
+        assert_eq!(journaled_transaction.transaction_id, transaction.transaction_id);
+    }
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn conflicting_transaction() -> Result<()> {
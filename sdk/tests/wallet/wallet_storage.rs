@@ -3,10 +3,19 @@
 
 use std::{fs, io, path::Path};
 
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{stronghold::StrongholdSecretManager, SecretManager},
+        stronghold::StrongholdAdapter,
+    },
+    wallet::ClientOptions,
+};
 #[cfg(feature = "stronghold")]
-use iota_sdk::client::stronghold::StrongholdAdapter;
 use iota_sdk::{wallet::Result, Wallet};
 
+#[cfg(feature = "stronghold")]
+use crate::wallet::common::NODE_LOCAL;
 use crate::wallet::common::{setup, tear_down};
 
 // Db created with wallet.rs commit 8dd389ddeed0d95bb493c38f376b41a6a9127148
@@ -351,6 +360,102 @@ async fn check_existing_db_5() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn stronghold_storage_encryption() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let storage_path = "test-storage/stronghold_storage_encryption";
+    setup(storage_path)?;
+    std::fs::create_dir_all(storage_path)?;
+
+    let snapshot_path = "test-storage/stronghold_storage_encryption/wallet.stronghold";
+    let wallet_path = "test-storage/stronghold_storage_encryption/db";
+
+    let make_wallet = || async {
+        let stronghold = StrongholdSecretManager::builder()
+            .password("some_hopefully_secure_password".to_owned())
+            .build(snapshot_path)?;
+        Wallet::builder()
+            .with_secret_manager(SecretManager::Stronghold(stronghold))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(wallet_path)
+            .with_stronghold_storage_encryption()
+            .await?
+            .finish()
+            .await
+    };
+
+    let wallet = make_wallet().await?;
+    wallet.create_account().with_alias("Alice").finish().await?;
+    drop(wallet);
+
+    // The records can only be read with the unwrapped key
+    assert!(Wallet::builder().with_storage_path(wallet_path).finish().await.is_err());
+
+    let wallet = make_wallet().await?;
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+
+    wallet.rotate_storage_key_encryption_key().await?;
+    drop(wallet);
+
+    let wallet = make_wallet().await?;
+    assert_eq!(wallet.get_account("Alice").await?.alias().await, "Alice");
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn stronghold_storage_encryption_of_existing_folder() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let storage_path = "test-storage/stronghold_storage_encryption_of_existing_folder";
+    setup(storage_path)?;
+
+    let snapshot_path = "test-storage/stronghold_storage_encryption_of_existing_folder/wallet.stronghold";
+    let wallet_path = "test-storage/stronghold_storage_encryption_of_existing_folder/db";
+    let unencrypted_wallet_path = "test-storage/stronghold_storage_encryption_of_existing_folder/unencrypted_db";
+
+    let make_wallet = |wallet_path| async move {
+        let stronghold = StrongholdSecretManager::builder()
+            .password("some_hopefully_secure_password".to_owned())
+            .build(snapshot_path)?;
+        Wallet::builder()
+            .with_secret_manager(SecretManager::Stronghold(stronghold))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(wallet_path)
+            .with_stronghold_storage_encryption()
+            .await?
+            .finish()
+            .await
+    };
+
+    // Files without wallet records don't prevent creating a new storage in the folder
+    fs::create_dir_all(Path::new(wallet_path).join("empty"))?;
+    fs::write(Path::new(wallet_path).join("notes.txt"), "not a record")?;
+    let wallet = make_wallet(wallet_path).await?;
+    wallet.create_account().with_alias("Alice").finish().await?;
+    drop(wallet);
+
+    let wallet = make_wallet(wallet_path).await?;
+    assert_eq!(wallet.get_account("Alice").await?.alias().await, "Alice");
+    drop(wallet);
+
+    // A storage with records, but without a storage key, can't be encrypted afterwards
+    let wallet = crate::wallet::common::make_wallet(unencrypted_wallet_path, None, None).await?;
+    wallet.create_account().finish().await?;
+    drop(wallet);
+    assert!(matches!(
+        make_wallet(unencrypted_wallet_path).await,
+        Err(iota_sdk::wallet::Error::Storage(message)) if message.contains("wasn't created with a storage key")
+    ));
+
+    tear_down(storage_path)
+}
+
 fn copy_folder(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> io::Result<()> {
     fs::create_dir_all(&dest)?;
     for entry in fs::read_dir(src)? {